@@ -1,4 +1,4 @@
 __krnl_cache!("0.1.0-alpha", "
-abZy8000000@)b6zbTwm5xwZXCOSRsmkD?uWxi5f5pt@:^3xC?mo2{eT./.T5qp:W41Ppv/baw}@s57]EKdji=/slS:)WfsJcw(P=I&4ZZKbXxj^j-}}hywu9uc8Wd!SfS1#KFaS{OsE56A<yXMWYth(rD}{P6M#gB?^TT6qr-{P9zkZ(vMRE/90O8i/}:>NpuYh4XmW8vUQ>Jp!ZLjNjM:!1E]F4ly1^vqf<#Tz$E4Cj2do-}GZ{cBWHL2#X6zY1I^P(HyWa&*?o0b5c1[jmo[7Dp-%G}Ig+ap5Rr39HpD<-ZcwlomB9dSNJ&3o1qhxXvr3?)}}%f.*okV377mtZ2?G]XH*#z>Fa*$U]3P>u#-z6Gc!tf43?2?yOeLuHGH$UWb&/]{x:$:H/st5<ko>GFa-axiFi[&ksG%jyOb.COqynAYWsu}:bB{61k7o2/qqS>EoLZc<KMbV6zUsrGy5oMo6g2qgt0{RDERuWLFFTk6=N}!q#voXHP0Je5+qB{j3#lYu%itKG4KI*rLKDMYftt{>qkt>cvZQ=jB-9boP}XjkcH(ykfO#W8ncAa0ZtHyT)6[E4vRVEx29.BSCfryha:AY[giyTm4IvK:k1s>:lKK4HU6o-)UXk:*)p=iXeY/9tN4@hT^q4r8Vp2JR+I1yiq)1U+Ulfz(Pqe0rGFfFY&gBWv3UbRhx(}d{Y0bl{4*71E<&72<ePT7@%1})a25ecAoV#c!t6Z(mSg9boRe&0waLddJ9>fh?nFEOzw*i}-{T<.?FZNiOIL-z/-4kaWFeNwl?bKP7qb:cjze)<f(0r)NwB&kF5Rx+LkCbvXv%+7%4jp(s5fHA:un9Su7Yj}TksDi^*N$qRK/*kC{y!hE!g!t0@cl<J+O7fLv>wNP?O)g^*Llx/QP1A/pzj=VPDHmf0D%W7X2#O@(w{4VO5Em^WHrKqqTDOpzoV4)#)Y6Y?#J<w>M{Jc:Cq]t&9.N{5RAeH<-W/))Da6N0#i9{Pa0/
-KU{PHp3+V]?zzGa6ry&0mB)sC@A<{WZCaXv++PZxZJaKfU<k0FXbuP4}SP%sO0&8>[+Tt@UKr1%)D/O8kdbr+mawXO(F7x?VgTx((Ovav?ySL(vPya%X}uCej&g&3[%uPTjDEPsvp?6^P6dz3xX@R1&Y0l#H$QgZz/+[J++e$<&Ae4K9XkadU@/)V-ibP1F08-o>q}xG[t%4Zd#5l:)JPqVHw8+Amr*#wgcA:)gV^0pZyNHktJ$&k%iFHXl1]9r9YM=@C%180-5@uZ=@ZVIVax$G-]xjf@lUcM3JHeb
+abZy8000000@)b6zbTwm5xwZXCOSRsmkD?uWxi5f5pt@:/P}p{mo2{eT./.T5qp:W41Ppv>l]4QPf=]XMhzgSKe6rmZK[P=[66{:@Qof7{W3NWc/48W86cmEgccyP(kJOexQDhb*PH2jMCZaJWx@!GoIxo&r4D{hrn5*6/f}jUrlD2c8{%ck&7pN0L.+@C4]M3I7T%O)58])l]-%4:13$oOV]L=MZCFg6/J/PfBA{<:DXj.I+DVc)doR{+jkc=ZjsN1HYgvYFtdANLj8*Y@qSszHvD:.{)>)2uqMXW9z8uaK=:FQ]*fdCZh}FcF/xh>wzkT*zz-x(rA{*o=*Y]2Zmc&{Dximqj14%K-4+t6)1xZ-3L3&1>Lx.fYTm&YZD<f47b5ncq(FDyPD+XHxE+=g}=@oeGH&kJkc/3$0vPtTf]ByjxQG-6!hM}5d>V8TB7DA$Q]#%s4Z7BNlDWSh.XS-SSW[8/aBVPLk[d*q?21wLgZxBlhyexqljv^j.^9BKJMvo>#C96u3B[6F6R>0J4R!qjOpF=Yb=V1[o[*zMx]{0&eNEIt[CC72k>LInpEm{.(Lp$8/C(n)B4vK{S(2Nt-<R%qW!a&3(N#^b+@vEd3}GG>-5o:6g?SUukl/m!j&od6ijM/ngu.Eb1SJul1kDyAAAHy<XVNP5XXGY&>-^4U#(5xvzVGwZ$a33KRHW1Q$}pcD3+$4mT/CF>9[F^lRPi[Ao)FOtSN{/}1!o@j7{Q:]jVC#t.hT+>m{)<l<kvKej6uoH9[TEF<tX.<D%4A{w]rzA!tb7OT&HN]=Em&MYEBFd0s[y<mkzenr.$v0]:HMR={/V:}-{iy#RDJ>Dw1GMNXO*Y2RK)Jq4Eb)&*%7C%9E.H!YFFSpmXsLB>uvGVXHYVayu{6aY20:UfSRKYBkNjHWzfEj*Ct}qTAj3hkYtfDcFT*6y5Rt$ZEr7<z>tUgNR[3mH}F[.XX*PN{aAQ]=hDGz@?!@yQpBTsfnRj[YjtyJ
+aOI*(eT/!H%0+luTNmh2f8SXE=OH^YzVv$<d$.buqS3)s/WJZO}g3SPw@LSZ/NebE.eA%Bh1^?@rv1rMrA4=[)#jxL-j4vK?re7:!*1kC+zj#Fw>&3&NuEE](]@.$A/uz73-LJI0V4kKE[oF!gzzM9DId/[E#UyCJV]C[HsY{7CR%Vd{(KbyLK<p{Hu8P<DL8>h7jBQ1zOYRiCIhGxHpW.{>uisFFJD^uO=7UdX{/-UuLvCV4G4r3..nOa{7Il-Je]BK[DjA:44Z{J%8<sEcSSYj*6Oh/00000
 ");
\ No newline at end of file
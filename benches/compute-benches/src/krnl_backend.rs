@@ -6,10 +6,12 @@ use crate::saxpy_host;
 use approx::assert_relative_eq;
 use krnl::{
     anyhow::Result,
+    bench::time_dispatch,
     buffer::{Buffer, Slice, SliceMut},
     device::Device,
     macros::module,
 };
+use std::time::Duration;
 
 #[derive(Clone)]
 pub struct KrnlBackend {
@@ -142,7 +144,7 @@ pub struct Saxpy {
 }
 
 impl Saxpy {
-    pub fn run(&mut self) -> Result<()> {
+    fn dispatch(&mut self) -> Result<()> {
         kernels::saxpy::builder()?
             .with_threads(256)
             .build(self.device.clone())?
@@ -150,7 +152,14 @@ impl Saxpy {
                 self.x_device.as_slice(),
                 self.alpha,
                 self.y_device.as_slice_mut(),
-            )?;
+            )
+    }
+    pub fn time(&mut self, iters: u64) -> Result<Duration> {
+        let device = self.device.clone();
+        time_dispatch(&device, iters, || self.dispatch())
+    }
+    pub fn run(&mut self) -> Result<()> {
+        self.dispatch()?;
         self.device.wait()?;
         #[cfg(debug_assertions)]
         {
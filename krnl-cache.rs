@@ -1,76 +1,81 @@
 __krnl_cache!("0.1.0-alpha", "
-abZy8000000@}Rn2B(I$/8oC]-q<J51RzpD.gTHk5ht.KsP8GFkMy2DPAF?F+=w2NKqS6GL7L1(HmyU@PHqV8hdB&GA$j2GF9#Sz(c]rKu$Hl{FA2:kuzS1GYxTKh8(^U53icMvlCj4cjIHi9h^Ll.7{Zg1[3LaiAnwR-X9Gcz*5yb$pRKpo+@r2A)V%5j=DJ(/Y4d(ufaL<5Rs(%IJqMlX2tevgjxRru0dT:X?#i8O0@:m2ZS(oxh2I^@fP=Ekr46*OBZK@y?D#K(ECp?xg>dfyMcPo[O+Cb)[bBLqsKffXK$%2FTY$b0W7-JSeMYA}&(h{PX+hn)Tw&ex!PA*VyKovMTsCQEzTS*ae4+R%4MAjb:$G3%YcD==pd^>F)<OawYA0iT<)v=mY^Q5#[>8+=y2>LRYGWMTPs&I6Da0w&iR$^:i6jt9@<TPZ(x!vzwFstVZp721BzmMJG0-VYLiM?]i&g12+Hj7{BrwP8Nd&54M-=r-5V1:N@JiIt{u!6P>gXaU4N9)i@BF(zo3n@mFa[VfkJ!(7mzhOEkmS94hUvxYpc-b%*]GeITaCYakIBYk}=:&dl?x:7Ik*wGo6UTKc?2H#{<nXBcCI:ER77[b0NGh<dp0:1rw@wWy&x5D}/0fU/cf@0a&z-/fH(R@(Q+P[yL3-qKV<T1&K{!cS[f8@A$2^PLen=nLgLU6{D&k*z-k3wDa?^Q*6q]p[m!owLm}Wd]<mP!/rrz.*&dUz.ACf@!WRi2<*w9EyMUK{X*cV8<D(S//f+(6j0Q{[DARk4C*%=Zykdh{H+=2P6v*>^{5o@Rw<EKU]j)6t@{eC-[[aDzXDQSio$fnki]>Zr+XNk2EaJ(4{boqdZW}3)TOqKlWeS6sE-/noCC9hlC}#=8=B=}yW7Nc3/758{ExFR{(V?ouglZtyK^zTk::}a]DWbO4&j^$F44ORGf^2.R@m0&Ou4DCKX$Nw>BE$PYEOJjoxs-ry^lnJvoZtH2l!3Fi1nwYx
->a*8e12q[#>@Q1^*X0$?=s>*([nnzwxw/hF4V]cu{EO@W82<<p}UdFis>}UwVq6Kmu*Nk4D/mRM-ZeI#?x0zjeY-pDuC^1(KR./(u.gFG8aMvhG1NiuG}*re0$[}U2HFxNfuS4)DfDwnfxgAAsk4@:9.pqYoN8ynGNpcz(k@Z)4]f7rT^wtDYHie8b20r[74#it/Q4@sTqZ5eQ<(gW+CV0]0qnfe1>RuSh6m/6LU3<iVWr4gJ)v:&.(q>qFQ3b}GM^7aknXBG)Rc]q9/PzDM0S(MqeO45YpH?x9@=%$N<3PcB:6e*(ko+MP/gW$fvSFC[K0g&j$>z$-Rq=pZlq{5R{C*V5XR1p-=4>1fvSFp3:]k1uz%^*M>%G^DUp]BNd9Ow^Gm9WgWp2}C+$c7UHGX*qv=uzR5?$b!y6O<M?djtLWn=[cCof+.[vET}SjY9}69E3DR7*F:/&&K}QIIY4E+<Cq?d@CZ8v%I!U)$!&mlD>kv]g0hCH)4.ze1HYAm=ual6@04-G%/fX0BgvegHG@fpn6/yq@971+Uc6)I7<sH$B4ITfxp&{uu?6qbheAQA1QMG=6wU5W&*/u*O0jh7u<AB9Upk]dk{Ubx)!3h0I]0Cbs2mYHUV!W(c0{l+?r}U$FWy#bP!{it9AIDs3/E79e%kq8QzR^>e{*ttr4Q$+d0Bq]X(CHAnw}Ri*:6&$TzW#I:5e>okXYI!9sw3q)L*)sHY^eZvcD=ta871SQa!#*KStUB?Qe{-k.D3vVH(Xtcbi)bC)T%4auX8xn^gvBb*]S49D)u30jUEA}A4wePa:8[9@+9]4#<>Hh6X8GQA0pwYCuTWqY8tgGjIP.@9PJtyo29Y0oUf:wZ3ji]C3IokgHW%^Dr#e0vkHRdG=K3!RZU&CJe3QqGUECK=&!)t)>dT)*}P^mXDPI131.hOs]ubK4R#Vg<F=fk]]8smq{r6B(D7/xb.miK:B!d{j]3i}gP4laxwOifm9)h!0UFp!B3oQnt
-skWl2{ZfdfZxg[g71/+b!)r[p8YVAYskT*gz[d:Ka^@8ZT:u[V1zv7.M/9tED<Mbm173U&ozDgeY+FTZ*.T7Mupr(/uprK]I5$P/l>r7rI5[qlDG9(@/aP:tL>ev^o-q2Z/1ZYn3*K6fGyTMvtT3zTSzlllSJ}xN80rh(t<rQJY>0gVYYnG9^6yj}}5i0cTbArviEmV{I0%D{lAV7WA^+OeoKm}pKDjP(:J3v}wuysYY]Th)-Dvbc>2.c?MFI9!=efIw)m%v?NcA8F{tSfphXH(FHD!=)Zj#*+alVHi]-32UI$YR=[MJI.N0@jxt3#ZV]G*$a+>(k4}!bey&Ej@aF2QfQdC8aq!96kGt<rEK>h>P$fy0<uz%Dx0Cmv>f:kE5aIQHb7I#1p6-ib<K]!v8EnE}Fu{oH[sBWFoU{RG5l0lc:rF.&-^i-9J3rl[mrr{}@DCq!0-9C5pu4YTZS!W(Gaw@=l:J63py*kfdq9R04(oFnzOV2(YR)f}7UcrZhJyK}*/hPCAtOWL9U0=9u6[&$aqTNMm-kv8Weo)FK%3TN{oxsYt52GBk2<ApS2serC&>]JQhc+W.QUwpdR}>{P$7K/rs[h]@@ZDEXo6lPupq9k28pEi/nq1)hMTm]9H{jqM(^n6le0(3zRJN)AJXUT-tPz6i6a#Q1&JFF3g*D^WgGvdyz?oSYEE6[t)?E!m<4XZtG^dPYNB#V[W[!3etK.u/T<vPkh3uJ>Lme(Y6CS]Vh*@OW@LO@)>WbTe(58?p0Xv*dj&eP5q3hu8a)Nff)geLb:zZ6e5?6xx6h{E56aB!=9?ArXV{OdQCJp?BLo.%@)j-5K*4JcG.Uh{aV:Gf>k*tvK>bmez#Api?&nxwH:A!VTtYgIA&:q$&4lRhfB:q$&4ct9tt48rZU44Tc}KV$KbbdT4k6^BKb1C])0yGS[DNBMK}0*f2qIr2ZJb-=]EY:z(:L5P*yba^m7&Yj)&=K@(kX0H=e-@6Kp{H0ac5:myw
-Z]mz43Y9>U}gXR2G06r^BfzG6Q&=>N/j*?-DEz7I(Me=7fh9H<O:-er1z/r/LsT0j*D/o@QKr=iY(b#6(>D*5bc+76&1?S%<}pJ=bc+5OkW[!U5q!TQ5)J4Sb^{7NH9F<t?kI@a^*5Jd3Ij))E[h:iX1{ro=TI8bI/>^$d}vA)QIz{]Zy#S1jTtNVcQ<C?BvH8eW8NbHTm-NBVS+W&3^ipOc:qrO<jSZ2zolv%VgdfF^+llFyRsZCpd86yL!d<QU9K4]P4nkFTwI&iF02y-*@q.A^)V8mi<@5oW#m.Op1U!<c2P/$1M7)6WarYfpISa}eDS5/b.sL6JouC6Wi.D@w!/)Fx4y+cSb$85jTxK7!(#{F:69*^&9pS!x%1>}7t-k0a*iL=vS2rFtzt<[ezR^Hw)tbq6JT{[}J{loQKu[!s>kJaX553KBx.NXyU}[wCz:LDZ*ahqL[Hm$V2>u7hCE&zCv*uZrwMTt+5%[v@IR?K.vxZLSRCR!KVX4#4Au7$4i0PY[qJJl}VzGCYTuT]SrA7L!vU[N:XKkDl[k#TJ.mWKq3?7i2=!9pS!svNtm[.4l63y)0]29r(H&p&c9U]{r<bp.dNvBas?PpWS095@x7P<sf.#EY8C6G[+lJP48R%h8sh7KpLs/^M:UGb?2>zaAva]0r.bg6j2bfpH(D.dZ35j56><JA)[<^epm?H.UOma/=ix+!ZK0l5=DbAf07(-g.A6iOU&o%j$K0o&%44.(pP*/OZ(Y5GM5@.Lh@+dSu-t<Vzh4oa$0-T=d{??N$b^:ag<7)d^ezXT]g}@Zow>>UD}(5)YWcZO8Be=pcpM[PU/s6d:qQrb#a4E/%oI#geKv?hmwRJn=)rzyKaZuQ>O2NN&=l.ow{:)t=Zb3K^n$+o8<&L)j[CQe<GX%^o<uiYI4<n54<T&/9e6rhID6=]%&uuY/mC1=hU0Hl$<B9uN8KsjOwSSrCBlc&>xw:/m3FpAjPkBc.K0nH5]4N3#3GKAB
-zc3/43AyMxBYRh@5M>3u}&9c@Po>.xhpJeWDLDkjo59{8P07O%J-UL@jQ*@Inr4td@F@hre-12^>?Z4HY1DvM>/k:H%5nbOjl(&}K-ghF0{#NMJDZiOg+^U$<$?W&<<i7qO<RmBq{UHk8Lj#@yI^?OHvOUoEKrfvpX7)<-K%I&KOo%({HNPZ](&N:YHpxE3DzG-+Tp^rTh5KAwagA4ZdOuL9Q?K^!B<vjW[Qp^zqSpz>NB0Dz:AlHg*/cfEG<9zj9mPd@+*PQjZu4tCWw7o]N3jpEim{Uh{(0S}VcCmSuijRa0mqI9BRe@lvGc{jhVqka@f(.jH:ip8]Zz)z>GpfOLO/uPq8oi]O&OO{Q8Tjy^tWfAC.2#k*O#D^E[lX%cgK{3YK]Ag.mq*Zb(>Rl-eDg3IivF!zyo7xhFs.DCj/!iD0)O4F3W0}sla26yo4PHRbeR]y4*/wbVO^ZWCa&d7#/)kyM2nEN9-SLk^u0uQFtl5=1LP/7Gs!+u(@?kCDy#7%tU67P)qfSehZm%0#}1?/mL*P$FT>%cyVf3c3OoXS6w+-OQE23g-M<s:Htp[>fc{@<KeNBp^PDVnQ9jT7:{QE)%s[OuWJ$(H2WCB7mw!z9?JP{b$Y:+ll=WAVJ<%GsZ=DuRCRp3bVCM60$N$yeOn^gp{]<2h}UN8&-.{:EwJTh?#&f}VQB9-O:I.bR(v^ab6Mxo>}k2f}Exeum%G/Vo^b(4+:V9+Yl19i%Re4a:V09oMwHAn:VQBc{5k=R&YZ7KEV=&ct9zvoU.SF8oR+4b7Ds3K4y{!o>[n./[J82u0:ow5wQii%dW6kSD<#!{s!2/BGUM>[ISmyq.7Rjt28InWuw4VTqgxmGq!!ha[=XvOatK!SK$}$t:eoHYG55ARjf^5&dZZ!OoXH-?e34^a-ww+}pRYA)KlrLN<z%EX<+QpS[SQ.-hgZM+R(E.awi#=72psXhMz==VWee//*YL1EP<CgL5c]omW./IKZ]zh7NgsJ
-.1NY)lr{&o[W>}p*dcDW*Y=Fca@vGN!$aegy/E<j5zKd+z8Wq/rj$1ZYq57h(w$rHRzY{a/ba4?am/qsE9E@c7Du&8)=QzyjlV^:z7I(Kl]I5@CVKEQ]p)Ab{db8ZIeldz[gr*ZZY0VhkKM+@Ir>.8a?FcocaK+#?vN).)uun/HdM6N8JKorWJ^66:>uDHLYSzLc(MCAs3b7LV#$iBl^Up@Xq@.CjUGR<8}t7Fy/DP{-=!:k=yAWQucNt^&<Ss9}v7Vm:vr2/w$$!0(}Ok@gMuG73XuYr/EVVicH$ws?!2:yV%kR#AQROR%ilng+sD0dHY2-0UGPIJ=b<bb<e)kcaGC$I@{Aou(X#pKz^6q!Q^Zyt0oRJ)Q.=RT)LV9i<c:C4DR&ZFQQsw#Op9bv7y%5ETja^lP$UgXWq^^ZBBhSSBF4]>jC4@3xhbYM=IB*HVx*SO6:.fDbhWmDGMKF0Z/VG<+w:U3em}=n!FLTCxTXa<tq16s:C@gZ)*^lE9MVG{oWwkV^s8>}i&ox*-CZI7WT%fmg)k{Hi2MON6xXt<OH^Uk-ywILrN8Bej=nq:0OFk$f]1:0G>}d4fGFFw=]E*Q]F%#o9nvl$a^tpDwFMB8Qc?y#ZYhf@/w1CvD@Gemb!(Vfi[*:pX9.D:Zp9SBs[DYDHwU8(juwk1WsT73nVR+BX9b]=J?gA>{)2OTQ$py]}JfNdHXde-:T+[TQC9!rtF:Ew>}qvSQ{j5u{5dGuZFZ59>Acm[U1(p6cTsn<SPEoZ[K*iP61F2)v)mq@[U?GzqvLmgVy0ptK(?{zM/AA=pISa}]%Ar0*9j[$+o.Cgt1.UbCA4(5mwd5R8tcHAti/(ORh]Ugz4hUl.{.^+yQVSCiR614>f*7.7bW^&SV*}tf(9B7TSp3*7t*!rL?5=ppIz$*eDR+=N)pWtIP5JFL22^FI:g#rg&h84Wl3RhPRxw=:Gd/2e9fcxodybsL>HfiWb(o&>JRhCwZ4h@>?mMCkhjFQ
-IEcXO]n#kmGWO[T>^7^+[u*=}t2e{(n]8+t<n:<OjGWA%0UC0.*^oDyekH5{R8z7oyDvY(7rJ.AAp6mFDp]*{aHzm3W^?-u!q?Ko>tj[B)f.!5lsl)SXv5+dZpiZv/3^!#yDX^K0<cq):PhXmjGWA%0UykOyCM[/[bS/^!?NW$*m+{ZnP[@DyDX!+TP]$VG4r#xt/@>E^d}-t}GiJbyOI$MiG#Af?.NA(]{13at2e<<kXWLIirgkKq}+Je^W[Bx{q^qi*n@qtTAEy@[w5cSV!Kq%^qCB@9]Qvx6{qTfi#LtpV)zu(MmjdItn.HY1(jW0ks.w17xyuu^IBa-0!4Rf/Tkv%P2D0>Yx==P3?E/ieP@?azizXzjjbx%P2E0j7mSn]hLNTlcB3Zkz+qdSgXL=2tHD8S&yJdunnNlQ>}[fWpiPm[{k)T(nI0HwK>=wy^tf0okmo!6BdEUt+]@ltb6iqAma8-upBbWtb6^O#KU(5SHt2wQ4=RZ^*9WGM:>G93)&#(p62#DpeEDG/3FCj!:0?fmJ-gckAM&f5ietjKkKgi0>fJB8JWs.XJx-.yzP?%*j2a#=)qd+D*t6*kAcQ#Lxrv=kDE/WvTHkO:fsLWy?UZJt=7wjatnu>:0>0J78Omvlk=V}5YbGk6>4Q[QM>>^fd!Vpz^SyXHBl+3kRdiTyz7=UXW1^gLVbzT9M/d:fP2M6{(UiC#VbF$rfoLtSrtP{0YgZJ6TrM3z+Z6ZmLUOcYHxKi*Dtvu!^aA%L>uXtG8TBJgG/G(JAXc2@oL#(-{{9<-VTY)S(=2dfEZdC!X?@kjQeNpl?4(mf*uRvu<YUY/Ybl^c}D6MRxQ0yg{EiM0{*6o1Ths<NdzE}jVMCE#[^>bHRiL!lUa5bHtJn<z7#%3.UL(:CzwpQY9t(-TXCWgq1<(e3<@GmNEKWPgQwPi}dNmSp1)y?arSP=PPh*BfKI@XmK9<]SO*Z{>Z4b23DzwZH]#PYc>.?i%/dOiXPG*OT
-zvDOnF1*cHIeE56aw+U8MMbEL/ySDG0Q.pqRude(kc1VS6heMDIR)RunK4]N7]WH5k$yNRjoCs6f$%AshT@k&bB[x%cQ0u=7Orl[d.]ZW7L?3NirBX2b7yra0aUJNg%dUbc%pGrh5fHIwCqCOwyz!@isZaC80H2-R#NI3?bF[@xGF/BcNs-PrZxILWZGbZWL$RsRm6L>o*UaH*Fe$2jP/+M9h@]]nD>FEd.)Q18szsF<2GbPAj77@GEm&()P^S1KkCMF(ICe]Sq{aJbLG3*aj$+p]fRJ>:&j&lUo&oZls8g0PmBM==*zV)0}EL+VUJVp/p.kK}Tmssp87dB+oPbi*2*C{:J}T-.a(Oh1PrcY^mI-k&{&^=Y76*ZV9(flA%p8#G$n=XVIpQ)ZwQIpozRl7yP5tv{BbECMaj)hjys1]9K13bOHP1Syd#LhE&Oo3(e1M?{Fe]-<:AZ*kH@ZiPf=]squcVOEcZ!Cx.M4LYHf]vF.uRGPcl(WE(u=!9niPCDJ5YK[i2MiyG2pkL>LmIgFcCHLbEy{>6ZM/YCEMZfxi$LZW1+Wca.x6fM{Ja9+)]C1.H%SGv-@]rQf5sC^1oDWR)q6z3&vv}Zge*z{R:s3?AeI}t92u}84R=>VUy(?mR6XXq>!6YM%?E{VPr!khGvVQq9e>0RkLOkr.F1k9k0T[Se^TR&z.oi$!K9alf{gf4}./{iu=ks)HD#{XtJ<kCq-lxsLc/31<w9EtR*D]AGS4E#Z$fP-7=BO)L)HkC@!@cgY(qUWj4q-LooqC[GZd0ri}^Eu6[quT]bJ{<N>dT7/p}ov0:M/wv-8I]LWw<NX=sk><:A!h{p>yUjW&^xsTN@!Y.o4eFJ4sz*I<Keon.FYvl%Jocal]aPb:6zj/W@3(DA2&2wK{X/r>{IRD#gXeqSf&@$i8dNNA6f}X?@+Wn+?:(s8laV$(l74W7:TOr:}l3![g$DA:p%roz%2jO(Pp@&y2JsDuK9m0?/pEy^p#9()
-{(5EkCf=?:j)9RW?f<[AExS>wrf0A]7ne[t/s<MC4^(*DhWw{ocfisA?]Qsk/NtM+^(-EXx.@2Tkvtdde[vT6XT5NPBerW:WX5hX^*ru=5Ap$o>5!Xo3]}mR]KKbikOh8-H1[a5CrxB0lAXB#067YDm]+p:Zko}6d8/W.IYDhz29A+&^dC&+]b@N2x-yIxz:TaOWc+n{j)y+5R]mupYBy$%hNG1v[&dz(8UHAK]SS$D@]EO6X7Gt$[19qozbPw/V#U9H/*daz47.bpKTdBdi+M:<*+$UKq]&f#pKUj3L!dly@BQ]vQ&8s8p3KT$32%Q01g7.!4-/oty^SzsVq4tz+b![%nW.jDG!/ZXs^0S1/^KgMiG!ix(vH!u%gnuc3VTvc-ojZ7xB<tJAtJKLIcNZkq*TVUVQ%*Rkhh)mz8Wq/qOs*FYq57h(q5I]m@qdcUp>tz@Gb[U<7>*6Zqe#=Bu.ee?:[t3e[ofPb^G#J-b?!eO<05wW)fA*nmW]5GY=fs@oYoO@C=-WnmVuI4b1}kf3d$e][D<(x}J)4h+VMc!nP^KV(HrL4Bh$qQ[(=um:.0CX.t3xPSNJ39!F?S-xz#*)rJMe/}Y@=wrSEngpA}!APVt<($zbn6#6{&!-RdVl]DFCh>eqNv0wsRMUAlCs+}#1^}kp(gM*NT}zKodRSC%*JCD&!EqZ+0t$jXjjMJ%#@Bwa.zbew2?FN:C09Kg5{JF<)sX&o[EBAkA8.DVO2qy6ibe3LbCdcTWkf>Go*K>JTxL!K#m{?kj-rc+a>cM^mS4RsAzcCad?m&Lo?6Y/ZK04/Is<}hhV+v#wTySU)ms}Ra:AMw<a(c]GfQwEjgtBx0I=<26ho6C<*av(EMw-3*VFb&8YtH<Q>w*8u^>/]VW4x^#JP>d)[TA@ITSO=3Ju>!opdB1dG.WPDcRCdWU[n)wL@3AKK0qbgk^(wyJ3k-0a>]sioB*}4BDipdEgypN-}FRg2VV$fNmh{O>-wU>KpQ42
-zAUbm-lS}nkZDmXD##USmnCC#a?JRK67j<oVsumDd}t.4lWzEX!o}Qx=Fke2)4eKRNC}qp}4c*NQu+f*I.vG)bUqrRw{U-%5zW#k^YxXo!-]=kJqw11*3VS6({##Z(GRu-Dp04{^-!)K+wTjWonQ)<L}/:KLc0@o57POtd2W]F6LQ(/B#^pC6I&j4JRbcV!7B5=VhddZdcSnb]CMX6([kJ<ToZTvgO:{XpH!P}8HEYov@1{t8Lj@{8E2DB+Ajuvf%rhQiw&HKSV+nD*TT7%W00<ltysinq=(?7j+-LyWIQAe*xm>0q7n9NMv64esP/8^P<X4wIqwdhF@gg9ZQjXK-7G<L*MClQj+.YD6JRBi90}mU1ViC)y8!AospaZ>}/amdXdWlMbyN<Pk.>&*}G$LVR)h&kDkZ0)h1(q*4K:rS!7v}JPN+vXeOEz2R)gP2caoWrVUJCptaS-Worn*&PR9rsXxigf!Sah8E5-4isd<yZt/NM(X5L>%{JAdFDk=9-TF-txkX5LwAZh]LpH!QdFpX#(/VY6VD{CKopH/DexL.K=u!:4ipH*lV0r[):V6v5Egd^IKRk8DadZVmoyQ?JXUVZ:)>{-ZiU+5ZT3r)]MhCohn3nK+#?6BGos+SYR9QkH#?j:vfpHRZ2y/#JqMlTKNe}JPXzADbgoBs#eEu!omW3rv?B+<7-bIEk-Zl^P@]JgTN0k1oTP1Aub-R?V.Y1I7$C9.:b/3^!#yJ>hg*DlMY*Ivbz*9R$5u@j[)*la^r>[)W+Wn%y%<hfp0TJ0DtZ&b5bn77OQa[zkfa*LlT//CRfl9^z[P2M6{(Ugfjy3ZLp9amdg!7v@U-+47X*}+3X(UKm?W<HQ%8j[({B89$LBEEpr^(/wuzfS4b/HytBgs%7*^&rmtYFpoB=N?q?]XsGk<^ad8Xtsh!g1p9?C5UMI^lryWptMEkC]#MBMRg6OzC^}8rlivCohl]oMZ?mmt-%anpBrBkht1SZ?jEwwB]2g0
-(q#-R<Kk5pCvf{u)jVuHNVQOj*c{a%i<@5oMY5/>Qg4yYMBJ7H5}4C+YPHXSsX&M>K>>EgDR1p3)s:U*h<fFmbK7I9svBywNr=-VBulV&b>e+6ts>3n(oP#x)suT+Yq}hN%09vD:i}9b<D.3Cfh2.+:bRB@4WLe$UgoE?NR<v$5YQnS3NfAOBS4zsas(4}3cDczd]V3<o1gZl^@V@0W-Mch)c*nhTmFKLqmS<>=<<hZ3gN8*:WA-#O/-P8z^hmk*)MrA7[e8tGzmNp?&!T[@xj(KF+Xp@+SuyGZSN!.OGhg@6)SG%Yci/6D3vtsv+@sKv+}=fr<bN*-jO4KTx}JDH<ru(s?PpW5Cb(HgzhIA82Yo]4i#NZ<+2?/JpZ#9I=XwWIj0f{Vw+dYk%t%6ET8>!abX$eaw1/*CnH}-P=:WrDMmRmKR($Ml$Vi+=jJ/$Q(#&*GOUYVg?5S!=:I!S?I]tdK&ORk-c.#+Emv:p5k?.LX<<]:luW<nJUa#fDGcY*HjOzW05pa4))+IGQ3TaZa4FB6soQ<F/Xf7?*UQBrM!DiIgZK[9z99-k)f}keAyw2-5&31A-c-uy40KR-[TMapXlq6$.EFrnCp}>1:8uJG7z5sM[%=<^)FT&ebA@#*=YeP^0c{dBzXqLzn=l9SG)m^!PYp+yP}kMcv.8:}/q0IQn{2D:WoUcbqn>B{9.7O.?u!u[[fvdf8&oO^>hRdqRk5ZglYOMW37JJA?yv2XdBBo}Tc4tnK&rGj)c#1VHVHWRDVgq0U%cH=b45x>Wm0KW?Jn-9[Cjj%EP%ycpTD/7[]YkIc^^#$!C(C[MV*)U%03v%@@?4sNhUJ9]j.Qvc^lieq{g/T}-GUx=.^rl&wA-U{TX6F*ZSwUFC-97X1qU{}hoJ<MMU-=(Hh{a2j7-7!oM[N]a^kurHwM}K5{[=MD4&u!p0RU}-rPmyPlXu:zWM+4+[ZOCHmu^/wzg)h!*1uJEi}3}YimGeQ].F]b+6{t%bsE
-lhA-<UFc7(!TL^a>6.^TY3#=K^t[!-i]Z2Y<NS#vHvHo(IS&99zOI8/IM.D+kZ?iAPx7z:mF/)A8s[:lyHa*gFo)<E*ao{*P5VDr)i=PEduiHb81V6/9^&/(Coi&L>>KOHfKFcO@scL(26DMk>IaQQ<2jTeaN:6lfw>hl.s3#u:JHqj@#Q$#>0dk>}-:Xk^!:%R<45+c2C=?[}9dmCh8HQrK<vJKzgwj-}#{QabIJ6GVd}-}^k]418vBvPZTvMg9RYHK+g?)Mz!WBXu#i&I*Xp[FUc0hyaPBx-Wo5CxZF>^bE[N7t2vUs%yzhYnNIMXfsjEn{8s7}l)+Qk?h}B+WcE/Co?Xe$#oB%xj/p?1RSb6-P-z?5NaBCmZk%7vbHogRcV?}dG5wA!e^Fg$qYM<*eBlE?NuL6UM4dk@Fbe-1hlc6O?U:2Ol^[c.7<<Ex.8?^RwPZu<reeJ#HBaum>VIxsNQ#Q&x)G2Q4i#q}nKLGw/OH-an{Bo!S<v)uert&%Pcl^@l6Q8@QC3IW2l:FwZMl4/ha%:y7(+vc4eNd8q<YY9:BGUM>[S{pu=:5?1t]>wcrWjXD^2FQg}J(#&tp:d7lqKJIhySSytaaTq((.Nh28o[2<>x3<AKU:TMzgpsW*]5dejz2t4cD)261nOhb5{o6zo9x1Wb%bPovj=wb[:u%)V.lPqaD<NY?T-bdy])#r<e?Ptz/Z-x7Zf&/&3r6wU$-p1X6qqEbfP&B^prC^l({Y=#6GblYBn5bW1.d(v<R]6J%gd5)p9rw8qx0N&Z7KkiO5hINlX%+<Q24)#M#@W]cO>]G*6XLKv[n2n1!SYGM%hIu-6y^hgb&E!.Ut}2.[F6B?{=Q+z-v.@7E6hV>tN@S+/l7QM?QV1Z({M%nn(WuIb:=@8Xu?VW<!nmSQA[F%7yMBxZFZfmX<SXFhMX(arR?:IKYC0{Xq2@Cr51C[V#LtGoITS+#W*)i9aAhRZ8Z{{]/s=fPwejF3r?hf0mRJz6)
-&um$<N$XP3m1d!]*BRSVMmFdR20wxZU^]<t8efMKKL=IlHww{1ixr2IWdOnpwvnb*?#gSe)qj3tp0q7QVK3ayZ}r*a]DGb@ZOZ5!jD(<N4)5BlWoVtEC}<::4[Hj=!4NxbZgXK{g+B]kTja$dPx@diF{XOKo%V.3}MZmfMxwl-39)OQ?!/&Qg[[0=sC(%#AiB7ew(mym7{?[eW/>z-f3njQNL>rq4-q)M-lB=B=WXKkEIMSqRAI62z?+V$F}L71Le{JW8eWzc>hTmFekW?jmP!kw=km](ISu@4/+ODVHu5?#yZSfQiC+LziIFq3iMQd/*OE6HT1S&}IXl&Ma!#^e1Z{IdpG>JC-1cmTk>MU*QGM#:*eZ=QCVxY^AbQ=&G%X5aM}bhUTo{A2U<N]-g[Z2jSHUG)uA92*tZ.d64@?gxT{2nNjXp!}V:ea:kesNV2E[%nq^P{BAX0{0u+9PwY8^}lH&WZsuWSCjVFGD5EjI^go5{tTJjEJ5Ud3EOm(v^.+3]/RmvEp}u5[w9j*BW#!!qm-y(r>%um%vOtEBawB@aq(rtn]iNnFD!c:{SIU%3kMWL+1j4nQ!6U}mf8<[Lg+M$I=lWBSdaS!ND@Y{oP2m@9[fW-d#lj/OaI!@%okykWU)WOm)L7r+Ye)jHUsgMaOmj2y^iT*HkP)4y8>K7>Z:]^=pc66@hb:TsGNB=IaQ^1UKPhkQ%^cerc#BUmi8lI=89=Ny1uXHEys.W[@n]#bz{*U%A=>sY?j.A1ju)JM6BD@+6m.W[@n?4M($h60(DI%w{+-mwddRrzs[)lE*a3{<5WjgX:?uP3k$d@WUV^3DmdzN15t[9Jlx]#bz{ttG6[F.mz$<s(mgP4JDPvf/pBWWvNz{+mxSPv+jL5zL]rZQtI#P4DV..W[N?y+61!wZX0K*=uwLXc*?=8#Wp?a*>FFP6n?Gc:/L}DqW@QQ=7(e.W[N^SOGB5)o9a$S#3row[-8Gv@FCy>D[*FP8Y>l9c5=m
-2]{f69jU5#6+{u#U&s=KB-qg}3((-i-&Y*wS=D4Epq()EjdcQ{EIlbvdiH&#iQR{=V}UKoJ9DpzR**1-{i0U6WvU#@uB%Ch*dR1}WhnjruFv(hQy=Znur+<5zb#REtd1<13R$MA8V8XhW:d}In6]:g9njXL(q#VTxZLj#S[!K(BBg01wZ3+Zl]xbj90bylfB$h:}VqRzr4(h:7m@J(8TzasR)dLb-:g7nf:xo)JOME9y/YPkG=D[&V?kk.]x-5$VwmRFkiewYp6f%#/7i7>yDX<MnXzGS0<y#VcY01Nts9P!1Ts.M/YEQ1o^3(2jH3G=2hl{a^C*dBO-QHl(anVE?4Zv3*E0rEjHb0@aMLrfX.2pIA+SDE7AVB9j?pjCA)D4#MN4U=bA(So?k6AXLLp/15$bo59C1.8b8!dxOc4GuKJBF-OGBn&queEjBW}OoTFg/#+Z6ZmL$&Y<HxKhxDmJ.1sv<2gOo>#}e2*1vzRE^L%jLOUw9E35A)9dUM.<LH9xOQJfsB1pQPE2qSP=qNOZ<?aW=<i*/hY@nWYA.Tngm13Ut0i]dbyG.LCQeaj281ZH5G?9ES:HWkpUosb?:zme7@=:h<@g8-<oCm-&g?4@gf?xnt%S(fO[52}!.=mPUBjCi$O4ZLZ(H%}TnD?Fx<e/=>Q&c1AW0{d/[DHI<n/l}6Vnbqd/GE<!z$JmW./)LAcv67IcTJuP}7EF0p&f8ut-}m7rBl&Nmo.aD]nGh@ZWq(a%d#*!C7F2]FnwrSXTFM>z7!ZU7]oEZX=2quzP8PY*rv1Ob5(D}a^h&<hcBio=M2Dk8F@kcJF2FyeQp4-+[%1PBAH@WpUC-]5<(bX^EAWbd-%o2aMI)K#$kSyXvR9oL*a5x>CS0W={sdfkTVC9o!&t]<lLF$xO<zkTaBz!++mzma+WGO)mEOKPA32iLXubi7EJ37a1JZ:$Y/)RGn<ixq>lWs2cg>p4}Ht/94)??6?L}!2{U0A%{%{sD#1/MHr/
-lGD*B:QYI$cflZBumQ(8TgP1&)IQbH[/(O&yGKoZPYHG%[kta*dyMXdbS.dx>e$P.=+ISU=o=%.@Ci.a}o%.K[$Qqs[]z*RWKV]I1>u?hX*]6/tx@9%)kIsM^:9bzl&Bm4m@Y6x-68Ym}c8>BC^S>3TL#rtv&wXN<h=di{Wev0CuRZk[ad?3V5XXX6-cmr}U%{-tMprXYc*Lq9B.hw+>u.=3$i]DVW5ha^dVex]fo)x>KEzjO0{%.YCDW4n+gC@js#4q[adgMY?59I8p@o{u5}31^rifPVaywmq2x=jfX+1)YOs23qH-5]DVZ{FEfopZq}LKkh$nIDGxA2mKOp9D/sc)*xoZd#/q^%3>6=a7B?AT}!Jlspnak15[Xfq.@NFK>KyavJ[GE=0FKBdsE$Shk+Ij/dKW]/T7%?O[^miVl=L@?etiz9Z=.>ER2%z=Z.*OTWi2+Y.q^t/*h{=TzBf$(G)lvDq}#QyciN9gfK)*OF]+9/]@UsOsI(sw>{%kS3Bm:>S8GTYN{z-g0p4NEJDm4pT@TKiRh0iWuPu3<mX{@Rn?CFPa.*.JtE/j/?pBb%M:IiFHAWgd2euOpIg)U):epQ6SPd(O2S4Ruqs5yhh/-1%-[4W[%E)XHJK]E1)XE%>C7&{T*0e!F.zXIjimZzZ{^=D/5.U!szr{p:JCv+S=U#Ytdv/znYZD2*54aTe}2<oiNWa*nB{uWcT(xR:[xxhaXDJ[SWiLQ%D)H$oxGc+ieA[44@0a*t*{6[(.A5OYIiGMfMa-v>?pwb7j{ku+T>1f8.*DL#t0uoNfa.9NPWy/+kd}Kvq@t-hnpmgc{5o!QrR:He*:Eh4-cZir/F(3Ry<w3(e&#{G2A.T*Z:EdMsSXP?go*[$Gdjg/[cl7I4b+n=&!.ob)y5[SSrR[D6D-JQAS-qYQs2^nmLG{</o1XfGtH*g5S*n&O9.ht[QKE1PgkS8yw(W3^J4F6?wX+ixD7g5dd6oN}B9rTq(PGeR/o$Z1
-uGYJQO9KW.Dj1@-[x60GM2YD8)+YNne7Di:W]h4pjPe]e*fzKiRmD=ml)uA]OSW/=(wdi@/gzdcrcL4W(uez6d]!HITx{]jtDbib+Q-*3FUz[VhOm]^]Zgsiljlu}ImXhFn-YO5>gV2fNu:=*]4uSDzs$8nSE0O-=(D$Hp>etmkwE4yDvtr/(4/io7*?uZ]-X8jR29gp@.v=&mc}}:}BCd[8I)MKuP:ny&CTkb50j>8h3?K8ZwcB[Fbwo.43nN3fC}acPK0Z@qr[Xc*%Ox=L$[CYynz&e)$d$)b^LOC{(pOXTktrzuObtL/+?8sub/mU[1NsJjea)IJlK%Lk{v&fb:tMBzho$}W}eX#*]p627{l6GB0Xa&x$mxCRuapx-z*Tfv}oqyJ+v5%]LC@Ctt<u<qqX-=s*UCL@CALit/+xbRFdQMKGV#4^]+-S?@-#JIi$%Gz2[gPQ2j=a:y&+qa[XNM!SNoB[/CPVs+#X]IcF&l<m$*3en(4>tYWoX3NGoHI$aA7kQ70m{gjN6mEc[qyNH#5YDi%EwCtc%<M!^vNK@KgMNd:3Jax4YKHk.%zO4hcx>ah]U[V>$&A>ht++zXHf>N=tpuv#MI%+tGN}<YhzZPwLG512X]Bb%U)pP1^)u)4A7qy:r*ZjWa^mtDi&BGqopNEgBCD%QW7-M]ls2<=X]Eb[11y$-7-tB+16Txsm.Sm}Rk<9%C^b#KbACR07Hg*^9MSYL$HZ>r}@Q6{BUEP{+{<6jYnB@*xKcWjghmC!97}lrA8DhPUA4^om9ugIM8fs)R^QT&6)N8!KcI*PkGLCnvY^&wxI615asbD-rulvnO19}v6KOp1(?O^P?CL%1@Ld<L&UXi3]r/7zq{Uc!R@&r$$BCL9.S:ye+c:Fh3s<+u&+4-w/e1!VWt1+2U9Rl3A*YRgykBJ!ve7uh({M?A6DsDh%TTyM@kX@9u<zzOlDsBH45zKpX5zLA/}U?[F?jJsv<Q*e+rkjenWW#c]{q:{E
--YQ>$<Ky#TS!MsRzc3?>!y(7NzsNN5pIJ4(]}Zf5*f#hjz4/Ex-J<.^Y9<.O++yiM4H<w4yWH?J9Ano#}lMuxg/?+O[rNO$(}cP8a*(Tf>suC%efGJVq@XA3.aIi@*k*qf-*8SHal^&&lXs8c/xZr=jHaN$!!u/$bduVcAU#UNP4JDPvf?lIzH]XpjH9YQTHf]a&:4Gc/yD(htwH80vf(l=!j(mOWrr/*>t$x+Y-J4^&KVgNXw+)tZpiZL!!qm-y^23P24Ds[Lev<+D1ayzGDR4-/K^3T&klaHE}0=ilU{Kp@O0<}Dswx[4[1zvV8@$gMpVaszxjZWsd@]pt*(W.SM&rOoIYy^SR97rLIfE*WonUjHlgT>)xe-&=TG^RY-s&^q]od+kt@]1^#Jh24$=o*@SId#<+K28ow>tE!&4-a<fSr!.YXw!4UHZjZdHb0*@ZFCi(F8w5PoYx?]>yjk5x^dP]C(/R@?m9MZ:TNq$Ng4{&v9hm#>BWx2Hrq-L1rb^ZNpVo{i0bj44D9:zjMB!IZ.[JMRocng%)qHL#g@XoH%cF8ohMuK/1N)QL3S^?-MO/yY8gEDw}^BSzLd.g$yh*TY{L>R9p%WVg(FxEAt/afH.2&sC!O/:(XTHqQ&618(Cp?&+ZsUh}IfrOGC2h1g:/kmG.$Be=9/X2iAk:Myw.]M?Cp+{GN)4C]0D5xpouxrP[tjc!w>Brr)ajl+DXQw{LEGN4$%zcs):wPT/-!D6)4yPwaPr+.-{v}*>jEjr$!BhuqXfC=p@ue=92]UroQsum1B]{]zCr(M^6Q&WYnan<I5DwN[aF3QZr}{UkB*qj@rN{k?C=CpAeOR5/(iJ4dj[x1Fv8C6.Oav<f+)c4J*KJmi.z5pm?!D<l9@C2>b}QE0Us?SkU>Vt+!T>)fW{JJSY56z0gii7P2(wXr4A[I!$N}4K#tY}rh/:lPc{EYKv.:XFuTlB#8uO}(08:/2zuLn[vB]!fT*&:(^*}QU[@UFYh
-0NHP#MgRaa&dH)cNUDjsV1DRmNx>*CMfu0*peu8ZgL<T^?C&YjDr&t%9<A[KR+an>AX(TxEL?J4IcP$HHqhhSz!qLou^jt([O1oDm:(AkX28^cMec&Kr$1JN]Sd(p{i)}qG>JYu)=KWeZfmWz:#}f2WSQf{a*-mm1Ws0z]PJ<N<8V}5]/-46>#V4Zc?Vea*Tc]bA<T<!XQ[!dE5hIJ.ZU1C.D&eUG5M1(EgtBxT0ML!pJmDW{anaR)CJ+73hVD]!]GO:+@c#1t+T}R!<k)AtYi&AWVLJkm17VWMz#>29*pojBl?Cca/tB:EJ52.>nz!B57L}v}bVVs!vyO7jDB)4?wsL053:^r0t9&QwYS)-5)}uvtcn8>YOr>#^.f]D/vio:)hLCiHS![gY[}cGu-#-CyB5kF1x/{2.%HB(9RtWe9c&uy?YZkrKNDU)G5rxPC]}DY74>}Kz&+HCsvirXjwCAj??93gZotL)7iRaAM2}HumJGjT*Dbn>?$P]]Hhjicv23^+aO/v!PxD6qbWX-qBn&u^%0}ur+khXtZ9KgHy>jr&kT5YQneLIK9>&gaXl{c)a=!vbk%w^{loX]Sn/g:mHy<wo^zl6xU]i9H.WYKK3{c>dg4>}#I#T8c>Z-$@q7vwQ@SEI9P:8AFk4A!x1:-rF}W=jm95$eAf.M/K@umjR((VtGd2gd&7#>Rkd2d]D4G:U3GMeZbf[{-SD9z{vxB%qZ-:KK]<(M7$6[xeMf-zGaJpAkS+X8((l72$nL)x+256$bL2fbRPArLtSmWkauxn&&RN9MS12T=^@+>>uA-/U{]6YZ#rJuDCTJg951=LT4}l^7Lo}{U?&^s+v4[!*zOaK1FSv$MFcf]^E!eK8C/A@[4QcYCS#-#3fdvlOhJ3=mqhS81mlO@@+Y0e}u6i0]c)QHs.@gE!bZ+X8)OGRvH4[&!A6Qx$u=G/)vmb):8WQGFe<gSDzEQ2&ilEng3!x[QEg<t1UYGd@]LNG)m+wbIru
-WewdX=SO4yLq7ppjJoud2>F}]))is<eaH(9Gd$6^7ezC+S{UeNz)>wKHv$MK}kl?}%nK5mQ<.pg@X.AoEH]$VImd5<oTjNeGM{Wz3ybtq7d0+QwT#U{7]EC}BshF^WF:.H&@fyORXwjcF!mV{Z=Xgw@CJnbyfriOdmN/POB03<q}?Xi5CGdiO>LKKf9(L[1)i}R).Q9+!V$+{q&HN*xYbYLq})jZ5vTwF8XzeJl5snjM<{1I@@<NGMI0IhvJkSi6p!Ym!B]4F-h%M]6a1iTaJgw)+Oc{X+oj:NVHfbtJ9s2})waI{*G3Q@DMj.P6xS<(H<H9}yG4<*GaIbcX$z.KSSw)aLDEw(tl=r?eNRQb+R?+ytml?GSA[m3-mqehc@V6%Z]lQ}d]C5Al5n.ON3w0HIxG<t2f+lt)-DV<!V#taq&FRAqnC*CmS3(+a-nPlrV]SgaJgw++Nh>/SVYmgVw{tiJ01PIKDR>U*fL!MXq7aRaI#tflFPFeC[vx}cj1R0Ttd<iv&]aB>Cm<CW:$Zb)G7bUK1^:BlFIV[XGwk>j{VeoTp-O?v>RkOCg7.m*z+.QCopjUcD)AY5o/j7MzO*:1Tg4mhaMs?90h#OZE{Y/e7>gb9Fy}H0RpI(fMdB/*}wmljghWD2YWos)<M}Dl^MLcxd9T5Wy[B6AEU%t<y=u@5qmNd&^HwNWFJ-h&@foDMTLmFJiw/AM^>-qaJji<M%QJ?)lMN9bqu)fC6s6j)Vk.>ZnubfOK7[e(!AmzHXV63&nUm<j*4Y{>3HBFHp-5wpb!3r}%LIT}fd@hM(Q>RN3H)*Ofl4<OB>hYl8VB^MGrk#.U/]D^OWQWvsDUvES/yo{Am%o}:$@YlYSwlW%)#U.Ap1MUB1{E}RKvu]i3q9Gtd5PZv&2M@nb@PENfv&{h{G4!bs{*N.zh*pAs>fj>gL:G.>A[xLi8bNL0RQSYI)uz7Z)AOYXF:W^(ZIsItSxx!X0lq*bn%)@4jtq6T!jnd:v%
-H78lE8IE^8p!gGTyMJGawMPI$RIpp.vfMIvdJ@Zl=eW+G/-0RH%5E1@mG/Bv(>F7$?2*HflS1VGz!AO(WQjP]o9]Vs5Ao?)>2foH0cfc5^HRC{^lPgcg2$I?A{2:Zv-Qz!]c4:gXuKaLtJ=dHfowJ$)k5m3D-L.>APb:Es=D=XAnagv.}>3#N&ztjIh9lNQ54+4(kcwMEW]Dba0o&MP@ncqZ3*#:{u^18sh%4)7HrVUW<cAKa]kKH/CvdxjK}qRWy{!kMMd@$J!ZI^9gu%&:LjX&]Sd1&}RRr/{3V[Q]Y3]IY/$48)&D>?O8g^wY{<F%B<<XG?C9E{]XJsh?sPL#[7)S)p4c{g?C9E{]XJsh?sPL#[7)S)p4c{g?C7+.X-[3.T?l61DtP2fRQ{jEo$}k{Hc-*AS+Tkhzf7m@f8:+&^Jt.me}4/Oc+n>tzhg+>?r7H6AP@KntyInu?LEzG(QC*iR9y*Sf8-4p+b7-XXnZg}Rb(QcyD9&XR*<^4(hdsILi!}=<m}&UDlr8b<d!LuW!X:)Pfq2RUdOQQE!EX9Eck]f-PH(nX.i%e9Hk!U*]]N6&^QN668eCJLEK#kYtTWkjTxC%)<Sm>Dm$cL!Q6%AUDx=7{n^}MXl(]>JM&k6}@WmKT]r1OUrG$@Tj#7%^Fh!=s05MEaao*2@PY1X)PgFWK?XK<}pZP@*]&)YVH<^+W)@#{uWTixTLLu%{n:lzNBJl3::S]/d6[l]nu#PJ9lMGXiux3$:I{e8a@^eupN6k-zn6#Jg.Re9VZSMnuxO)$^y9j/ZQ*?zaDZ4fk0jDAQrK$G=Ll1w@PWN8dXoUJ:Hr+:RC2$-sdyYL{P[7INV<srt*&hunAr<AF-)2iYBixb?vc{Rsd+uz.C<b3UA8dU!9gEg{bGAT@mO(To$UGR>9CZPU7O]o@P:1Auc[]kQ(B<wxG}By>rZ7]eMWzkH}6Q}EgQl=q})8aT^gSa^6)]<*Jn4zNb-lk[#BBc:GJrSber#(
-c]oTG^2NqIA(cw$6+Zw]o}Kb{rROd1)RmQR(z(hon3q}K}{==Skhi:46858BzA*(SOCxmUFiqpH/cdtSl]}jv*hjNaMb(gd@DX/>/SUoY<xFgMqhtbn=MIcgjC#n!sap.QCd(j%9Saw]PZgcG!Kr&fnwzNvff{VREp5>WtctE9S$Ll8)CtHgyIC8Rpx0rB/@]qcCdR9mos*h-+(?f/a!O.#z>>6-8*zQg?ZlM>K)=+[erNWC9@tNq(C5kl&0Qgp9#KCy!iAM9E$mAlE!skl:a0Yxp(1Jd:8(*stX%?unmXRLZXT>rr*%F?a=?48>4<d<@SQA}A<Y$oc6shg@5-!5?zZ9@k5!H}uPR=}kP5xKxZ+^?bhnU%@0W>fU[u!<vzl{8/HcMWU6dFnb^G#W-{Y2f7[lVl80kQDJdup:0@DHSVB7Q9*dlZqf-zg%jy:+ZOIwm#-[)@i)N]DG.}kH<7?n6<&2fBW)tK]5fV}n7D)4aZ4DwOLv#M60Gj+R:Wkk*@GMdp:<MjICzX%iiK1{Rxa3s52UBNx$62[o^%39338hq0a[!*x){#04cEc(>&L}YkNUw>&-xBHT%<t1UK[wc]n}pVZYcsLzOZVGk2jSc.a:yye&[wc]n}pV.DsI^34EYhqGR!c+[kPTB+G+6+f+?7bS]:6OC{$4pxs7&AWaDFlLF!aCc+?7bSJr(p5@dW}!2wL86lMP.]G+6+f+?7bS+)rtz{^m?4?(ZyT4TB1M27k{0vzl{8xIGy]G}F9k[(V{Ks=*VvxtT#280kQDZQ<l{DRrd$ELgg%ZXSk%knwHnZtCxyUB<p*:WY[vC>k{EnXMIxXTOVSoLOi7{RjLFzwZRDHjN*#5}6n{FQ5exN:nVcfI!D!>5UCJagCVnQsY(Hh+JOYhV)U9.zE)igHJbmCii<:1%pr.!t5xAps&ed:U%ok24r$DY+8}OSgA+t8]oNi7@]J94x2mY[x6n:>jaaskkfxsjF7n{=+gy$pkhSfvUuZD
-gR>C*ehibio.k!p3^txBO+.64G[3#]S-5f%R(Ift<za?*nwWiQDRE(?n:T:+uk2Iu.(c9Xu7w6+&fgtK+!<+oEc$0=C@Sh/Pl<OR4&ArW6SYmrlLB>r6[GIVfWn=!5T.WVO+.4>.MN?]<Tps]!8.i&[}Nqrk/bvI}>p!/KLlbG@zrpt4wMPd-tiH9oOc6O95dako.k/Y1lNtE=v26w&8.<@aC:FNiuoyu<JvLf6K^DRY:MO/q9jxWMW@J1fG8Gp3^dZdLj1IAFCCW5Nc5D2FCyd(gOo0]ZQ^>Kaw[7FR*<!IPzQ4Gomw6U]sn!R}@xbtxfM5:UYyY47zsZPA@3en{SrBn+=.r3Ec%i0]daM*kkcd(omom$ip<694=s[]!ZW:T7V/CN/m6TTsmlo:D@(g5^=%^1<OmoQPz/?M9Wt3sxudiE1d%ONaNT27ah3<p>4=)@]<-?/TiE&JE^dCLB2yZUCLFwzu/(0lZJ@YFMhQ1kq$5#C!Qa6{j+yhE?f!.ysm-&iEF]M9(3!B4{YyaMAReGib<{p^@RHfk}XTF2MA<MJkklHlV^A}C>x=vkdsTwN!DWnNpA)NIvc413Oy8JBj0aPywXJifxmG*mlfG?aJEmiGl/E[.m!RL$60hW:]oN&/hB4ay+(r%D&Nw$HH@s-64[Td+-PgjVl?Gbg%nc^g*m7VE=TW1MfQeb=gl]gxQieC=upY9b.mfBQ<sVR<ta}6bi#8rRxcccT0#B#Qy71O{}GzXSW&vebUvlyX<YRl{ZlNRcPxI<cYoP.nUXb)fR/Np0<6nkmO:gSYB6WM2PqnaR]NA3K)VTjkr1jn^mb!QltJio6{M28(n4/?cX8<N*4=(OMdlZnnJZ)<w}KzI1@gvxO}S$e[3THw8(E?rSp2.ih)H<:8{Mh)fZO/0<}mO6w:NpZ2m$8Lpjm-nv*(X==I5(A<cgn<{XKRY-i]}jUEE*QZiT:%mC@1k@<M*)k7rIS(Ix^Rj-23v#bMzUrjmXdK
-pB6#wK3C(<[<=rVKuQ=L=Ym}9iQ2dUZCMlVjmZSZz$RayVlK&2+fjMX5F#fbgaD4Z:F=60(wh5-^sRdMAnUIn]uzJ8aiuodZmxbVJ^LR7nG{X7r6oTqmvRAGa9l8nbvx+h./G/U[[huTxg*@RPdxBn[S]^9Q?ps/Wy/+39qM@gJ]tYZR+2[bPdb)EF3#daJ6w51:d/Z=K(*5zFoD/m7X&4$9W4F7d&i>t9RzUl3blGmZ:mC/Z7l[n9j?=2xNEi)9R8X?aeDu6^rm7Gt%-zotPJyUbNQXiH2(:FV+EYt}xDRk[/KgsK?R(EHpWJOCUUy!tRf3j@a.7uG:&l=/AI)gzC7qb]xab3bAS1OeWi]<@$$sW:O[ecyQ(H?/gAuN?jiL4ly]TvWIV-rl}TNw<zzRlk!]Bqjl-88*(Xk&Q*C-]n4}1FT<lKp.&BThKz&:.9{}Qx@#$:LMA9yfOy]L)*GbIU9Ng==tp&{%@oGJWSqNfgqYyNL0jA-o9R[&VXbf-50K2eKlgq%x2L)-cE&55{w5J.)}Z#r>K9?=nrF.a!ae0LQQ5KxM4o*M++y]:o{0jSNak%@6bMzomU{j:JQZ6ItlIBN8!TuA8m2JqlK]ECA!-IDnHg+eBM=Ov2@UrMbAT:(6mXW<LyIkE2>uAgaM$Iuo8rjlFgSAjz5Iy^L2yT9gxalCE%k7O@-QF&ReaKOrh]r{ZB!&@wxTb@6s}7B?GjfX%8dLrpjq<[diP6KZbMgABH?WwJHf5UVvt<7Zv&(Ld8dN3</AWDe@B{o78D)M:W$waE&tHUQ7]Otjk/bFXJ}v%r^C2Ny3$4P4U/l9<AtPbMJ8uf24UD28=:o6T4k0r0-keW%[qHiJ4H6<iOg(CeK:YXfeMb?vI@BY}vaX}k-&68i31B@D7$ELnkpRE8u-{9w[kQCptmw*{WCR3]Jh3YO798NYF[O3C8D)LR.[&N@[qIub99XwwjyGimm%-#ls$Epu7q?lOvaX}k-&6A69B&Q>
-mTWnApyr*+Z?WQr@d$]reRW[%-=as3mIATL3}CS)ax)NkH8dV=BtdI=jy:(*2p<qOmI@5uK:Y+he.TI#I@vDKfL&EawbD^@w5ZG(f-zo%@mKxy7<#&BHx1rbvgER6U/la#:uHhg]U7@Z<O]+Yt$qQ??a@-hW?f9$)Bf-OjE$L{kmqtOcteS8*%pNZ(fGKvK]5p2<qS*So}pgrLn%e4de%Z)g(5W{+0dawu/VU.0VRS^F<C?^>{h5Zx#1KSNc5uL4vx>x]HngK!^D4d&LnJNLBM-Tl+S<XpA&FNXhKDJnKrg%{A*eFfBOuCSQ3vz9p:#.Tv[@yAl!I@?5}cdDJ<J!48WWW(nX2NHY3XTTv5xqA6CzhB+olO)-ikQw/8q1B^e7+A(Ysi++CD)9pur0]qA^WK]9JiK$WpLb0iUkcPQmG1M*qHx+6>ZFQ6UBZr3mz1w6C+1&{O{nHDKV4*Rl?3dx)ZHxEd]lhz/1.@nV}3>8[[/HWCJ<JvU%v8s:QDWf0u9hCldfaQN!votkz@.tI@a+7Q*{A@TQXe#12@E:-h)GZQ>zX%m=}6NLX<DemnN13qNBgyVD+S=o}I}[5XbI$ga{&J<X^[75BqO.6s5VdOzo96auQ+u:w1XlpnswXxOc.osW@%!BWJF80Q{UUE7/^M9y*<:%E+p>FH{.$PK<DfxFNbnELeRs0eNbp-Ljk)pv3a!RB]P5kyn4ZBr)[a8^@WqdlioY4TUA5yr{$-K(DuOxjZ>[NQJ^L]{VM)jnOBpV:BkdLGCoMdViPJ[8Wp5BGbY.#uFrNQqm2(U05BRWz/]+ehEpU{.iP%Q&)U!*MQq2t<{=^heB!FF4><6PeCEV@@EtdJ&iR@xa5)$pl}Ud47xhi@.R94H}!-3Hm/LE{=+ddLY]1/p^fq=.Tdqcv.J@g6w8&cIH)q9}[hE]}7i>N?TEosJ9:pB5+k#=Ea>FE<!Rb44XK-bql9O{r{yQOP064:SOa-Bi}{N*7hKk)ZPN!SEb
-A9BG9R[T?6sW)A-zWDx/y)0HBV%ud*X}>kr+1+yUmMyn%C.1n3nuKM+sj{j?{AtXYaf}j#Uf2TRotxz^zNC#8mXN/X]V]1A^)9rIB(fzMjn(}*pjH2BuxQ(O?-sKg[4os>UAfNL[H=&*CXoJ]yy83KMtRrWF+DjF-2XDrzypM/r)%Ux/.I4-0cBav*E{R]!PvVRJ^sAbmC1B3T1<uyurk.oe->OP*$v7SGDmw<B:uh[]lpbk62GXepk2?nYf:1V(csxltunC7IM.s+:g}tKI]lUoymLnZ[>zOPI97X}IEruNU-6Q>)d*34AMrLt>IC@P}QOs0J@m-DCJwHXXfF+(B8Q}E*(ZG3&/r{q5{JyEL^?@/Ktx$/^leurtgmYQ@-TQN).tPv!Jk>(hiNF]L(wWx)0[sX{vRN!DeELzPjp9[N?hfJbp8Zfa1qiI@-Flrm2(YIx}uvx&#?1{b@%ShV2pa}T2A[s9S83%x7+OSwxhA^GTVp4nWq<-9pq(ROEgr8Li$>xVsF#qtM7eOg=F}743CgZ@H7-t:9#/?9Y<1c&tBQH+>SpLxeYQYJ^X2*2HyM6:E!mTWj6rX5)$2&/gBqf^^@st--HlpJ@oca[65RWxFfqfl&IE&9q/G<Y!/$z>I>})*)!bnr4-<<dqk6Pe0OQ?GT)}cgai0XAid/KD6:uw{+22jG(/LC^FdE!i[UPvtxZtGmh1GfvfK6{!^?r=^b2=tyB0@!UA)nmz(.)X^EcQ:Oh[cZ?re&)rS3wj{EQhY}%lN$3Flwk4X8H(6jBa1/JLBM*gM0nJkM+5&KeOjW-:4WIiIm^TLnbH8GKENZAdB-bh1Q[?%d>ci?rm}+GddNUB[SG!P?]9Cd>8M?iA8}UHAeVXJnH5l{GO4WR[CMjd+ubWP-V<GIDP9}WRCK{I-&yc[{CM<-Fm3k!=6TW&$6yI960*s*<Jw4+pB[@Ctv6hLxGUG.U@BucG=7g8Ly@Zp/f}[8uotk[4VA)Q$4:pi+9k
-J@b#tEnci>@tHIO?D$D][3Tk8>gGXH/xPJgZF00R=*$x{><4$JaAa3Jjky^(OBhtksF[k<Na4PX+<0y&G(%%.Hb[6c.<{dNqco.Tw0>-og0F[c7>)azgLN{C8n&0jJ8rYPj7BVbOIwvM=kqmTX?/73?5jb2)U>1BR-OyQ!15kq(cpc1B?fw-iO+N<OBhtksF[k<Na4PX+<0y&G(%%.Hb[6c.<{dNqco.Tw0>-og0F[c7>)azgLN{C8n&0jJ8rYPj7BVbOIwvM=ktZR-<{5qPau[Xa=H[o9+hNcq>2Jv31[Ae&*Y$16mk?V9Sb>{EaLTrwOlkJDhq$K4$cG6H]nM(AcRx[*mB}{[CTH?N:aYFC(Fo]cfCHp4<&#={JW7Y1M]hqfcMZ#9nY>{PKj6]0=@Pl1RWovj1lMj9nUtBC#Qes0=@Pl1RWovj1lMj9nT(Ipym[iu>hU3u&>y]cJ$fJ.[ckbnEub8u>hU3u&>y]cJ$fJ.-E@^6nl-tfO:<IfO8l%Ly:a+&JlWtQ&scM>i#aMcnpj0qzrUZYPkJEn[0qT+&b9boKFw0Q*KmBCO.V[Z-qi5.35]&p6@s4HXG+D(>.1oZ-qi5.35]&p6@s4HJ!26{6RKcu>hU3u&>y]cJ$fJ.$#XU{6RKcu>hU3u&>y]cJ$fJ.*t-I{6RKc=>hrcQ*KmdWvO7au]#tysZu(o2[0%*Fh#1eN1fw::Ceg1F^Byxl7Ng.P!3pS{kS6:C@uVeOYOduJ}u:du/Kb#gS!I29s?*c5&)%G!Q4*-)>B+>:@UR](A8wD(ISID6>U#T2s^=tR=ms97Qf)cw8A*y/00P*B6K}4yASGcLLPjf-iX>]7>0z&En#6iiN1Rel/nk]}$.5(^)BYU[b}%9<lj%}q^BK2Tn-5Q*s-Rsw>NAr(jcsC.zt)1i]7f+U0kyoBJ}BR)Ud+#ZNT<:bD&#{TUB^fuRWbeeo9Z5si6[(@c[sJWh/=<C)qI^tCd<szbJp25pkPv{h65Z
-6OMo-qR]8MN6gLi)pteA7#u6ZB*YCf2ucjd?]oB.}Af^Go@iqvum$UicFpzR1.2c&:(#9Uh-Lv/H21Y]]rj*lh<rW2ME0.J+TK*e^r}z%GNHy]y-^.JfYhZ>6yAg9@Lj{d+=q1<k4ej40<dJ(3?d#VyGIBbEHJ]I/%VzX2>LkDD5Cj+MoQ=hC)J%ZR2d?x/i[L.GGoT>BUpI.wNMS{kB8xx3l1Ldl0esW{4Nz/]S}j0Qpc9=vG42l8^!Rr[*0^@soiY<x$*.U.He^+T!GQY]40O}.f8<0DU0@uyL6S*[*Jvs+JWY(hve{u^R[Ld]H3/lQ92B3vswBl(i}eqY-T)SzLNeP9r1qHUCjo%OHs1)pOE-J6KZK&aIs[231>pzj+WWT]G4rkj@]q[Pg5NOxGR{6M{jsp&mPl%CJV^^:v@dp8IEYJ>A1h32Mjy@DW=O&?>4>dXaa>&2>Li)}lDP-33JHVmbCuzE0WpF]jbzq^/4U=sm<gSE.pnYY]qP:g]BVj@oCQD+H.w2Pu2ylZd-5j2f6N5ZyAx[+Bu#$T[bPI>wvOQI%*E7XBh7E?N=7vZN&EyY=+L5ov5)$Gd#iV{A[$oo%M@NKPe-2>c7X/@Wn6zu0h9]sBRrzNa?O3A6ltm]qRejaE<xisg3!MSb6.[Nn&{AaBDVzB5Z]sm{QF!KeoBW2i2CA?=FJHw!kfuj=7@au.mH}=/DitkgOofDYm:M>rW:Hev::OwsJur2(N4eGTKjQEFMQVEJBgfa4a^.G.XQV&{$hB8XH-1AVPkG+sI6Mk}46-<ut[Jab5$}Kzw(Yxb)FqdNOSiE7A1R1FGyE5lYLv?bU7<Ys!f]?6YqEqd{t=6Q>7HLJjd01WS7u[aPW)j{>3-}0zB&EF-IqW=1h!AYPF-Qr)}PV[?y2D8pO1/VFlq!R#5<ue!Z)CHj4eTZi#!TrR2imwj.ZrW[DoWV/@b:>K{VMuV}zE+R$PofuWyQSQesjGq71=]w5bO71>m5%1Kd
-a[qIpO!v8l(/B6]z&3#H]]TF#sZN1QMgZt=r9sZczX5ReDx5Rp)/Vdq=3b]27c2f2{D#MZKb@h$-C+!oW$07LW%}ogzeGq[YqfxBA!<O.*RqI)*UR}aQILqOMRK<HzVvND[zj[{@My:{ZwOC={2sT6G!WAv7SE>-)oZZFbgdm5ijsrB42WI-Jn&H?G!Ua>Jwlr2}XNnRnhOsmJ{Qf<.Y)FBQD2Yivgn5Zv#PB1@!i6T5@!S6wbv7ZUT*Q<d30X47@EMB7]670E{&+zl$qhn/-3NVee0{%IEVJ1-&}#<-WD(muL{W8^hnSrWTRY]I:UfA^ZK.<fnnr.RjWbf)o^DC=FOR$UK8hOu&4<??#oEY)ellT3lclI9nwkIMZ$[&ohBmp*RrxxOXLa@J}&0G@pR!Q{X3lxRqQay]PY1nu+e>K/-2Dj6M9-lzNaRb%6>-tQs*Pebhy[I(#HIEflXTY+^=w6&+zTX4aeWAj=yO:mNgo0X[q!nMpXW0zpJa&xddqg1LWRhX>p$Ax}}luJCRaYXLVYXE(#!0eM7Nkn2<9iv}j=lhwa:m9Z(&:l/=lj(DqOMmOzA%>%0][{Sny]+k]UOIFdX{(DpD]NJfl<!M=k)SPHTUm9#N{5-4BWQsZ*MuFeA?RmFC]4l1X)ifO@c}<[VtiIv31mCUW<79UI*2i<[p+NPJAEA@)3*Pu.j)rZy8wtG#?}<@7?+/:@4P1hXCX9)fi-KZE[x):SYs^n(ii+l-&eAa%EDWK[)4Vu>7}SgO?h0Oen}w2K%YlZo(7:):vyZD3bp(HPSz)Xu*5{Ws(]xWrSruW5l!&^j*}K}B?x{t$(DcRZ7vX/!t^I5^K?$!GV&C3ww?qQF>c-3<<i9IKJm{f}@C:7S#IapY<Lx-rXCzO*)]PjrZmnF+gs[E(4LTPIEm{h!vIk8S#o8Gl(EL-@i1%qc!7fk{(6nnfXInET5N6Cb/tzZilaw{Uck-50t43C&H-.+-Ml@s/sKK3=v.Y.XM
-sV155nSHU8Il-{<4uwrTtc[G7vqEJ0GqgaKteJFV<ae}BNev8z/T3(t6XK9Vf+B?eF<+.nRwm(VVmDv2{7lji3#m{<Q9$#$[c>+whJ$77q{VRC.Q9Qcf#>>bv&Aws{7J(4Ogb7trb^eLLB+K[!:/Od6OP#y7e%+W:I)4J:E+7Z6VJ?Vl-e<YU0=b8QFYR]0j9!{9nmmT6&X^rW1=<T)BbqDNy#6#+/s{0v6}]b{E=j/vFs*^JJXT-b.!5t2Dm3xxGIWpk16m}tQ7$Z)jT+@16HHwk1ztXH?dh/vjWV(3GCMV8sw..:8=nY&Cee}KUbqg3C{85??6*U1p-wBfJH^aY&UwMk7{#I4Dm&//aN!LCY61<%cMg^4O]vD8RNdl35IeXjC!DPz/#iG&$ylp>Wxq&.EH$j9i5Fj-1g/#IQV(M&!N29Q>Sgz]jVfw7+:neypM/yM]ezqWs7S{QxB]2WPY?@7-79W3F?i6A7$f{Vqwh]E2=6*1nMfLz+7MOV4!#itV?&Q.h4J)eZ-AD9iF-D:{^(E<HZ^jojV>74$e^d]<s0V(aX>:)!+G#I]bk?{UzL$k13CZvsi.?E1awT}(w/%Id6C!4h8!+ke<}-t>!.MD@d-z:MHbk1<bwpJL)Je>:8{c1hxM5zZo-}aJySl3DsHTEN3a+9+f1Ok4Ak]k8=+6fTL7fg0CmFd}et=D5P0C}hz12N9uagkVPi-?Wpip-N{8hRY7Y/YmYS3l#sS$?Iyf0J536W<[(?>UnEcF-^T2&82CK)Ge7H)II0Ua5R=O[01T-(96+tCk]%IQJhNi9i0r7#JbZmOx.=9aTsh7%GI.^v7#@}#*f/(OaC$!adIvG4saXE-f!9=8wYOwubm}o07/.c^.5ceUl^8KD6q=R%2+%Dwf#^7D*f/<TH#I*3NUmx@WCZ1xJ9p)i8$B&v4X+&#&$$g7fVHwJQ2<thBeZ1%5YLcs-#5}hWzrXcb8R1c(jF=p/m#<%g3PAw1is#hLmUdr
-7.47@.5edql^3B^!2TyC3fbNqv#Npv*f/>r5xhE5+)lXWpLx3Vf?%3N^&<=b=oP>-wbE6s9e@pnfTQ:@HZWasZy!E*KJehJbxwt&=oScHahRKn8OEBrG(F:o?6!lNKBF+Li^[+2R(f$#hZ:WC@#.y7z!KFAs)N-C<8sz]cpI>JeEq2Q.!qIp7O2wjz7St]iwskNoPesL4:p:jJPP-a8aC<dY5mVPyhqiq=4aG2fFf(h88dnGh>4Nnzw-3{(vNHTSi5HjgY?m[voN+ybvz&!z.%!5F)/S(q5t-^MFbI:m)gl3pNz{Nq4G3=^hMou4p$z]LCfV?u6A#CBJpV4jEN@QY5M[2cpI?)I+0(^S7G]6OutF3eXmY7fPNFMI%^II++TDZN^^%dmN-iC&b?z5yTBe5FQ6j-t69nmu)XrXr7j-*vh})p7O0WN*feO*mpqAeoPetq2*xad4k3[4gq$Uiq5t-Pm@o&?<4%b8nsggGAvrJ8QbJMJ6OZ4XP@nt]M.y&6?baVH}X2+{CA-hwqUiBsl$3{4kV5t/4YDG#CA-budz&EQJXXyzIRY+lc>a2CbVi4{R-f-^Z[J)(u%nkrvP)>z0l?n5Jq#/)HeB4XB1B[jLCEVpw6(r^bZ[L&I@97?jc%x6aYl.)R[X[k7tXG.fUX/9I%*uvM}tWAkr$OiIR-t%avtail#=ry-*}}dYmRpyiWd:WQCcH>QokbOV[kElr@=]k-=ALHQCU1rl.=A[2IbUBV{(@xhDS}k(8kW%6XM>>7#k-H^mo}L1b(2r<lOqmq5zEzs3zqTQbu4%)V1%O2NX5AKOeoR^X(%=^=p!=%hrr.u.B$GRj)+W+v{S8(y6qoT&c$@O}Lv=kCy/NW?TuTsz&]-3{}K$m2gmumQyOkgJ(>@24Q<n5juIeQMfV.sbO9y16Y^S*noQ!EpoVOuu!zEiNcO)[xd@N@9J2np9RUrK*]5Xr-Q^}XX8N9@Dr26Quc72GFk[u*f=]{MQ(Iy/ci^[
-0Clv?Pqde:Cm?@/3!V4RUf07V^sgx6G&J(YltW?r}bf[k8SZ.D3?6Fe+bCtzF[6n7CG/IRtn!Fg[J.S&rki-C@O?}yK9qPn6h1T4?FW$H}-Az-^I!pUamu?/*TZGnT79^FEAxBvT=ApwduOaD+DF}W!@)!yux2Y^Sp901*2wyBI96>Mqj(MaFT>YT{6D>R@.u5w@[{Ldz(F&Q{6hP:)WdSS@AQ4W!gk/Nm)d%?^(Ld2!yvWjI]r8<>QHO)Ek^0A)Z(cEAO<dyJ@byXCII}QXz$rj-2XP2b<@hhd@&RyK%k88{M@zIEN5cv=SCH3CS(8h}R@No@DMw5zY$+k[BtlX}0w:){6D9l{6B*zWZP5xrNOuL>PCxM{6GF]:$G8(R[xS&.TjU(zL7)6(@@c>W3I/BEzQEVEFTes(=3bS0+o)R35?.3*B1Xfzj!PX-J:*)w7ZFYJ8pOHcOQo(Pthq.V3=W-/.bSgq5[srfOTCpZ.>4}Ef9{h{6DWP9nq?{HhAM#7@-+d0+o)R35?.3*B1Xfzj!PX-J:*)w7ZFYJ8pOHcOQo(Pthq.V3=W-/.bSgq5[srfOTCpZ.>4}Ef9{h{6z-+EFXYiqUYR)}Pc$rkh!cE}p^p?MyU^slnd3/*B8STOXGOt583^kcV>SAZKnB)*4<wI6.Yti&[cC*LLBIU@pQDe{X1{(RqQ8QBEd98juHpS:N6VqnZT$x:D>U6}?b]YGwl8a>d0v9YXo3B^dN^B1bnHxW4fn7))ZZq&2{fz2LO=jqFzXQKtd.g22OVEMtq%uc0C:ZF$h=7/E(EWD!T.xAy<I^Um65+nyr:X*OhiyLd{<27Tm{DINJB?7P1h0@fDl34yS$Fus:&p[{wN8dVfAOgO:@B1sKj6[8SxdOCU(G*@S]Q(EOO9uZ%)<a=.+oScv/Vc&Uo)G1:0?2D^h@/tb4iR*P3fHPq-?!v#jBts(}tExOy}zlpLLqBle^7Gggi[7zAEbxoSf7L$lvYgQ<)?+A99
-}%C8v*v{Ys!4R-#O+x-x)Zc6k2S}CJnC(elYb^*E5xuj:g(g>0X{C@5}mWF4&RMj4dC[H(pwI-E6n-mICo--J[<sXR4zXH]lNyzmz(-Ag?F=5f+a51W(w.3sR#d%cs?SjC)35Op<$3@F<5/YFaP{W^5/y$.t+dzBAO[=!5z-0hCRPy%ax^&>Pnm(r+3iREbY.46.T?BX*kI%e*(8fgq^O=:-=HDf<wiR!pl^k7u)tZUg<#0WP5MMz5!RK:DNiZP>I^Ho8sJpR[oo:TTl!7Wz{Fd1{<jaZpqKSin^:K0:r2$S8XB=iFDFwlxjt:)Pvw.34T-Aw=+T*/BCEO&)(W<u06o83--Ht15rV7t{ZtF)1HSVd*g[eh(J-nSN)MSR>j?SbOBRN:A({P(KYcZ=so7QxYa<TSpa^+@eMX>HbFX.{Af]Q]v7u6CuM>LgEZ}>^)3kxG!+2]7=NW*L6zr)Z9S7)0DYpA6[3Ol&IMw&k*EOckk8$2WzX=EAdd#)j:L>XwWLD+9jC=b=XviNXTmGYFvAnt}:HyF-2jp4(%14DVMAaJ<DgZX*ywO7[Vd0}XWBGp$F)TYk73mgfN+yrd^7+fWCfmNSE7J+Kb)>duMhdNltBA*r(ea:Q/%6@)(B[Cq2lC+8DwN)+X6.V3qu4%u2a[uu>lZ(6(yQ8#?]g15R#g[.<ji!<QyMhs59T1cXF^[20{kmJiPSF>CO4[1<NFbJpMS[z@zO(.<.t!htQ1/YiFUt2YE%PKyVEp>h]]S<zWw!O!XQ(vfUO=<a0uR0/Vw4GkoVTNEKp1)-zkSAh!g>^A}om*v6C<LfN=wMwbG7stcps?2{HRV8GLy!AXMd/zY0je!Qkv+p0QsFAqG2$-]e/o<x3R=.a]D%p]0Fy4-S%JU}ITDwY:eHHmFfM91m2Y8?]oq4@:ijb8=S.]GO1/}d[6[u^uF}c+<8*K]v3tQxj>*HTVnR<Fk)Bpx0Q340IgXHb{zait!1/<8v0RTb#{:4lskm
-iVdkAhW<&erc6:[6uleN}QV</^I^e%s9ljH:.@3Yeum/t!fZcH&Q/u?OmMGS:ZrJku)&Y$S1XIS2JC%7i37&{6uHM<HmFgZiVdkAhW<&%!PSX=5jM>]@TD#WXRuTL]=ax-[/9rAeGl+*cLF@.iq(R@R%Lyy[&7Oy/Vw4GdjqoJAxmJ#2b?Gd:R?^DOCZRK40IgXHb$+57q%q6P9sz0fnR^L=QLUmLS%}[*=+gRi#@+Cyy<plp7Beo.)aT{*wWuSq%bvS&0.P4@Z#M]{5k?F}wEq{Z=oq8Pb:Vg&6elHRV=lm{!IdSdg31TR]9H<7.82y=}ce4^Pt}g49TC(}u)j=F.q.2Fz:@$A$47iv4uCRpybxI[hyvfI9Z$B@SZe2f^h7u0I3VOq5Zv)io/c.}u)inFo:?IZjMv/u(>HykYu=A<C3IL7=v?9]=OU^E@ePA2*#/z/9k&DGA869+speO1z62K6^)oEnmfhu9HG&U]CdqOFMToxFs[}ygknTb7>lLN6[V5)@fq<eMMQXWE}r3!b{v/igmQZ^As:/lANC6}>{#JV0p#o{EfC97u(>Exk+IWeqmm7MFR)sQn:^E>UI.RJM$p{x75OpqVUR@d.9J4.8+aKH)cQPw2K9=l{xfNmlhy@/@EzC5lia&JAloct[8fn^Q<[:675T[Vdm9!q@)FPDGAcRLAloct=+PrA&{06YEfGVtC=}5<>{#KU:fUZXnE(%s9KWlh{.B.7jlZVfEfGU%.2dJ!smDj#+<M&vFz+7)XEvH%v0}yrkB7<Q@ndOEB4G-P%1b]?M?gofz>P*LpvCZ)av@dc0TW71Utk0Vc]TrSB#YlHdr035t{KD9?W8!qUCLyw@NifwJ2.<r9JcLh<kf)*QVziVFb[4}=gbZk(iB?aa4+KTsbl*5U=j6&?u0-ep.k(d:0])PEDva63J:.(XD?#+Mhkk<%l!xrBnST#@EBJOB[>nWEtW>yiR@xOr97atS^?ixYam-&y:(A:zEkx2
-/pZ%a5<0*fPE+>I31?5SyYgSVcxxCkU}$9=4PVkA*p.FR4h@s#Ar(mv5?OR*XT8%&=1QzHZyj%3bUSvI{EA]odbfNd>h}=/]N8?5FDNF10M.%D<J-wz2o.TV[gHuQqb@w8dcz.5JUEVVjHlBC[d2)DjEs$aalKW5j9pw}IM1o2(q%.RM7mY.}U6I<9s8v5?t$t?9n(Y%U7ROY8<YvLA%X(W-Zahm4}FWbX={KBIG+{BzaBQ<qU4n>sekn[9ob<F>T+IsIPWgxR{(ZdMGT$5<Zq[IOba-ICBS(xXz$k>-2X3%cgi+Ya-ULFpL?fUDR91j?DP01]f9!hOu22D9Q0wDZs{m+JHu[$=ZOBDK9d[cX97?m:fz![jkl=:+Vk.H10$VkjV?j1owD^ooRLLN?dW5<fO})xm[y1TEuC?B9Qu{JGTkgA9n%}pTrY)U))iw(8Z&:/{3(H(FG[?57(ptWc/M5ekXZ3tKU47anG.gIc2#nuf(F??grolt:y&M8WvF.dv%[YQo{r@^uH2<o]Hx}/s$ZY-D(zNNv%(JIzqiHdQKwO{P6&y!5w++pBc=cm=bvkFX2lYn[6cw#J}DLrZcffiDlm^JRQS>B)d1+#5D{TudSsMZpi*#nd[J^Z}t#3XWb&YiC8<(LrZAqp/7Hn]wE4%syocEYXlQNYridI#+p}01o5%97sr+/ZgkN1pKd:ZoM.LOE:RxGf<TRT0F)jFy&Jygs.eJQzSwqJ<ykAwEJ@>^>(UzTem^[w6tGZ2zDQsEFyiC4<b9sS}tHH0ynxAZKX6qz5bW97K4!i4m)PqA6XyC<eB>A$#eB]{*BeW0p{2?E-=HCJ[)PwwjBkkG-40?jmEtcx&XJlu&LK#0su=L4iKW{}ge:p@[zy:VjE.u@&e9C!ZKO7K5/%gI^}PD)fCtX8P.zATHI$@k#3J6.:*PdpM8H^rgJ@GS*F96(k5CL02(Mmph9Qs/x4=udMBBQY6O.[4j}A#KF}E^rsN*VUJjV^1!
-1W7c7fg&)zT7h3xIb{jHP!kH[*RN)674y9v7}6Hqf[7wReE$}il[r36^3BsYit/gJj.9aKC/GJzfTL79?!0e)Jw9mT9+i&*]O^ie&2c2TA!T:1hb{2Bk={.i>@gWvWK17D/A#(y/Oqf6u]uC.W6BDRzaT=9aDx(m^LDSWFm</y2+@wuC]Yp6/4eil].QY!WK17D/A#(yie/K3-P<&rV>vo^zaPkYGje0jgLS2Zxn/Doq4Y(1d:[:xhmaXdPb!u{LaByA1W3o]bAeT8zd[%6bmixU:ytUkLaByA1W3qAa}c4/8JD1C5Xr.9Q^92rUR28S.=BMe-=lW9<&tmtw?dfs^3BrD91pO+O?U3+jy=>*3[Y!Qw6XiBUaWDi(Pb(>b%t+<3v6N7oC*%&1p!7eNy[o.Q^8#qUR28S.=BMe-&jS+<!]{byB=Nt^3Bq#9sQX=O?U3+jy!qGfQ/[MEo3]YbWS]y^X2Ga/P#e*vjQo?V2h8I}:/IEEJF5=:gspipLAb:5NjzL3vCRGJHSA0:tP%]s(-*yn4bJZkp:MEEi]%1Hh!X6)(t/hKIjSHW-gL5fWk.%tJquE&hDR]0UbTlf/!X.?8E@jdsS*3pBn+Rws<jMLm[}^tgKu#8/Y$LiQX$lks/cg?X.Z&2?6^DJE/+9.bo290r!c%969EFEo.(FqOCsGpAStLwy?7G2)(TS}uOh$J9tqP>x9*GEwM<YZ]Qm$>eYZ@N6^OE!YkIVwF&$sDc+.&lhBjHtemjmNc8F)1<DlrJ{o^Ns$>[c<H<U$R67h.D]kqjcqnF>r%96@1v)O(1t7FgvDt8:]Kg5Zo8h8{Tu-fn:GtV&3jh:Tn]Q<J-Stek>KX^v}BZO3Fo.YmNJ}X-u#=*eiJrQxw8e<ja)h1^{x/nH:@ZSc@rQ<(/-}IvG8<a)Ee>nMHoRKP)(w6.KIjRwa7gxYoMK+k156gafH$!1Ai71#Um->:iSl1OUAW]LNc9QloMK+k1XYycfH@Kp@t/C*
-AA5e.)(yuwQ{33.74mrT3dx)EZV6WUk(!ta@-v:.4c70qCgtKzMY/6X@ZQvBo{&v4@ZQvBp3{g2@uDA-UJW6uTk4N7}Mw(+)$ZD>JW75m(*0#N6tKB+97I$I/41HjjJX.u74x[pw4-l&-HRRQ3v3SFN#Rkl&7V.$aw<nkiO7*WletP^:gspA/eO!fCc7CzZeB#F(V}6L}>gbQGzk^#tn.vcWh5gGuPgJ6(?LA780?FjDWXoF(tfJ1}XuG)FvYt=C$^iF<dy}Fm+:]j(/1(Z1paKc*lb^>@uUUmEMc*4v9SBE?J3C[UXMCaF/+RU4/wi9Xa#+RO-^>sz1?B[m7Uv*C}iLH*V[NOf-Lh^HTPn]+o=O(uO6OSLQt2M7sNg(sh!$L6>>iU&&zi%QWg%AXXOsx1Qsg<{(N>LxVpF&M<qr5b@)U!kdE&tp*kuKy+Iw%(kISf.z.5@8[+HCSO^8AU?ba:Z^6GKuN}?:?>]nlvbG!g)KFlu2)h7E*<x09tUB/$E*XbNu-}qWT+DT)OY1Qp?*>gEo388h*CX.eBkVTF]#l4d/lY:)@2A]6KU47vy%hbS!STo^3&vA7e:Ld+tIe*8)emZ[EHD4&zEF3Ey<MLpCiW/?Cq=#hqcRXVq:n2G)aFwg?XL}L!zF--UXf)kYhFjeUXf)kJ[zju9y3#^j<S/X/UTjmy&}*H6VR0.Ja5^w8kxg1UXawG?oD>RV4UtJ/Y)U1d2][C8n/H:gE=w2vB2!0X$7Nkv*^U6U*@c1q5&/)gLS2Hxn><5.K-F#BVT7E-$[xcvxnc2QD2Y^xae22=ksK9G/@}#(upe}JXfPn.=Bl5mSAlM=LTa4OIu1i3WY(E2r>lxCCM[TjbI>i?FV&>X3dJ+tIaclZLfwU.RPitZQkVW/@q.pzEH<^@Oqq#D:PgRwEA<4xu{x<ImfUuEa#I4n4cc=Z1ho%)7n8h.OfM0vpbl0FxS-S(G/f+<P6?0j]/013ii4?&Sf4!SEBk/}O:rL
-fX)3nNbFbE@/v()b{sx@*G#pEc+(Kzt:Q.?Y*LRwB.>?%u:@4&O^2{uP?Ygy)jt?#i?IF0{{P!4Av]^k*>/@A@W=gGIgrjSO+Ld<Wq2FbK^D!?9MNJ+sbjBG[H}!5U*-OM!vw-wFDLnC%4L?T=-X+6Va:k$pwUJgpwX#l@El9?xzy+9it83&>qh5NmTsCH<H{XqW#Q1/]letFlh@?hBm/F*LW1cl:Bz*-E?RS@iqDt]E&X7?]a6L%P$AE@t(018ME*aH?P%>#krv+EvaRi)&ORZn*+BQ.!uW&d)Uu%i{APt2l*yC(&K+SSQiQav)Pg*Ck6oSJIeXiApN}/81O%$g>#m57X{Wz:Cm.Jc/<T$22Gy%[c(70{Lc%QxCRvPb{{FNvruM-w6+x2%n-!A>PP5bgp>(YJg&Bo@}2$srKsgxAPU#Er21Z{9w2I^Sd0hxy7LUKAc$tkE>%2Mo7:m0!86-1A6UImF8cWH9&gM4h1-FuRH6qH.1aii+-Z%X-O}7PMQo0w?myAs%oAVJgaw(K28g}r6sR^kR8tLnpvqNw.6QiOV?AIX3w!K=H3I{I-8cQ@d>dp!joVcT2MUq.sJx0}*Cv4JCg:+CS6CnJTb2If>1Z:}bfw)&!NGq2(gg(a.oQkk<7E>g583BLq{bGB2W*B{/wdE:/{9&a}apMzWKmem8Q$CF0fh.j]?oQ^iZfmW<8kX/BkY4Ph*TjKAa.[7t8KK%Q*Hu0N+b7G=B0Ianssc)w-.}UBg9e]M&xz<pjsuEeU9Mm5/}KCdaLY#(eF$ThGvHOrwVR6oNxV$G/v7Z*6.%W0{fbsZ6WI7Y06zQ4bv3>KpE9+PjCfm)?JFM<d3Qvlk]BC:g9**n-(3ZWcoAU8<6?A>TcjlZ[I5*prWUUPJ60<0k]GY+.uBH:kI<mmY^YTvnaQLvGGFBEY[umXF?<Z?}&HE(:[=2k9Jv]o><I2vzV/oNoQJn@E%%W3tiw&R)krztfdogxZcIYZ2zP$&Zly2]
-l^%{n)}YIH?>yRr?%Y!dyi^rLxTD)11.EPW[T{rB>kN]l%1<j>U5tPYkt8&$UT$NarlWC#jlluf7[lVl7}ahXv*7>y6S*{Rmxs#FlJXoRCqf2H&0moat)?HA3$nHZ6(r=}ycoz^5aQMEE/}$&Hv5fW8083#elp)Nnuidd&#(AMJdR-uZ.<H}0B)9w7(8Irvr(3tx=4NcK1{S)&0moat)?HAb4pxMd>e.]reApUlMP.SF-3^MUW4RNQBGX=C@mNCy{*rLreiPQi10FjJa6slfH!^N^dgvVL?vJ).(a2&J8o?@4#:@e7bi+kGRvIF.2XkILmuKP:yygu2.3d&*QhL^(jF=p/n0>+r:uUl1is#hLmWVc7}ahXv(bwrePh5MT/S{eJftACf}6rYFIy#)0(![y7*qwr9mqh!?y}YWjJot..4hGkYb07&BhDq{ZRN:7Tn-T?exzsWVK)hM7}ahXwbI(K=oXbuDb8zafF7>bg0So20+qcg.3t}DF?WgPk.jy=<dz1{ZMuFM7A-Zdg5-mm%2gIDYBUN3CC*dMKNAx+so5u&W9O(5}D1k7W*pz-{5tXx@$RtFkMsIs3icpG@qObCoBP$IZ=oUi%2&[W!.ZB}>lpb8=:J]pC)R^@76{0QJog*<.S*hG}L]20JPee8EF-&P1m%.t>L%AKayMbida<j4=4tN/@MP[st9cF%D#&X!MF?WVJSoy6xHGV-OnG4pUJ*3g+QbTL{vDKV/^aAf?WAmM.(c8wcy?jPQ{6641K8/EEF-?fZ%2t*@X]Cn+p[%Mu>VoS8(fX5UlSqiGy[M](JcRL1M$m$1v%w0o:S/qBJ>}RrSxttjuu3<E/rCS9?zl!knI6.HeBj/o/MAonmf[O6pqn](d:Lo1sswQNdGi!2O0KM1!fFxvVXs7ZBy]F57m}VUl)@ks#2h&rrM>I-?s8..S:Bu]I$&8FMXM#eJxyEfGA*=OC%roLWLA<da<j4<+0b8@F7ECk/f[^Fj1[fk}r@/
-6MtELg7o]^1!fFx47kFQZ>cQx*)oZicXclM<E^j}c[6>z48Su0/O4YOWARf>lk:f%b1@C5Xc((Cp)?FhBvGzsLX&!*H*>g-C:XZ}g+.jn17l2f2^UDK90fI=Qitu8^4B}/d6[b54*j?kn8M:o*jt[s+1W8*00Z{rh29ZW0UxPb@2MdcUYZ!D[ef>[avl2Is$(Q{0$+![FA>/.0njfKy7Oe%Z{Vum5u(cp^{.RXsjpP).#oGlWI4aJhe}E)Ifru8sSeLtL=/0K0Tzg>ZEB{2iN&L1:D93z?gJC4Q=.{hxs)bauU{yC*T>)+?>2vGHMenjGcQXsxKa{sUkm%{x.JLjNYQcM!TWAb4WHLn7BoGKsDb6P/l-d%RzKm#0)q>%Hh=:I!bkWPGZExyCDAdUBZCZlYeS6)7dZ22XT8$3=g@!O/Nt<me*nDrl5rb)M}FZsAbgPifxs[0XJ}Uva&)}m[M/I1Qu><zSHOl}gy>EawVc64CtoML0eX2?8Z9JXB&D-cZD.=7nutbGjVbH0eCZ1:2:v}wz.dMOOo6Qj:af6b43jPMt(en)2t0uguQ.y/v4]A@U8U)W]kUE7-81r}=d:bL6=GiZ2(dhBt$q+t3E7!r.Eel<dCmML-vnqk+{kDtQm0niq>bi0.4ZLeEqs(lw(Kk/6Mb<-E[hXD&Vw^ppC*5?We+)Ji3%iedkXdc7zYYr(3}W*eD9r@)xgyS>7p-<dE>gXo$cqX)G@e-j@]5ip2-#0DFN(UUWvXRHWbnU4VEVZSLfnXA.{cuAUE0rr6**R9T#?3{:.[Xvrps@U=x6h3Tb^<iBM&(:MX2e-gscwo56-1Yf[.lM>Pf6<G/J[prj68Os{@H/:lytwMFmLnP}%]mW./IRG#(NY=rntY!ipM464[]w:n^k?:xzhBr#MoCzM.{tcpFApU-A2C<fxwX*E[JdYTxcNVsI&DN4B1E7j3qYXG]3ai-EXxW-gw^??%Wc->[+HXU^SbmIx.4USR^.0c=0
-E<Wy{nE)8Lv%JFGBGB+I4z]DDad:>U[PL072tj6g9GjS7kM6]fZxnB[29G4bh!mb3jh&oy@2[EQHgk5[1QOd-Tu6^bClQv/K.u?t-=&cJ?+A&^g(XY8TmH=>*k.(n{N(woycdEfypMy&=8A)kbgnH:B9]y#Q=].Bn-UL}H=IDXFo$d9XrCs2>J45ATl5SdWXBea5n<aH3X>.JGLq5Vvri.]cD{::fp(sMFis2ghXL*T.K.{Al!!c)VM@Z%j{QTfn{&nZe%vZ2D(?&.lgIG1m0fM}legvbhPYn9/^s:.mpna-K)*CXImGB89SU4F!sOtK{ht7sSi}1c).%/H?Y#TaXAk@cYNKAj/oaz(@#^{aIZT5Y(FA.)TY.TMsN-N3oefE>p}#E.d44(@Yn}yS+G$X+EQ=rcT]iR:]xo-.mPEC/IgN@=Uh$T}tY1n%fwIt>69@UtApi[>1JRR[iB!SLxP/$eVD2o0sDU-OVD2nl:R+NG.Zj1Klz1YiKuYF$+1VO$x=2xsTx{X4mnN)c8DPTfzR1mm+fxvKTq&y6wVVr&UD&!CN(eH8Dq]6&>X<x@C1ml%DLIT+M%hfa}Ga[w0QHiv%0>*#k&Qhy7VG!1OUjilZZz=IUiMpN)RKvdTk?vD)%^j)!Y]dr<0VY05W&P1UGRl5-W-&(T$>6(s7X[u+?N.YB5wf&7lU=yW2/xvw*7XoG]unnT%>X<[t[s.xplYEJW}c?v*U?>&<j1]6RuaNPqqm-1A46JPh*#zPw)h.0e/a59J5QxK(xrmPO=K2Djq?3M9f@H(24KgRPAhe/{nbEyCJJ8csW%Ex^U#C8!*Ts/gE<iNkO$oQ?:K.{sXrg&<xx4JD/roU?zS?Uz<mqWBhdj*dj1>!<=9+CDHiIU9yOe(^PYL1qJeZuC&JKOgxUC6>sZJf@^qkT48=.)o(juqrIn/]^X73QMPr/I/uw(y%/!p5NzP2zb{k&vLL[}/Q4Hx)RGAp?.iW&(DI+{?SfvwD6b6+
-rdpQX!T@rJBTIpt:yyJpWm2G3EaCM.V=h7VQMh{(rW4^)NYSIIW(ATZT2)[SJmBEXKF&b&Lwl(*TNr4-vRR489zlc<!sBiFx7XhTVBVlVgol-^:)8RqLV+Y5Lc17[lZZ/{).Eg%QJX>u4F>z3A-Cn4wIJ<&bH^=eyRD=fY?aJ?jaijp(w&p5ZOZAEMh-ko}t!>r^*#yOBW$+%3zCiXOx3*>a-@8pnNe%[cVO?0^Gu6jaC]ry<[j*d?bdgFSL)tSLy.E5XtFX)th=FwR>v]=(ybpu1HtUkO-gd-@.OCIa!q&>c-5%#AA7=0eNX^0n1]q#8Se}QkznE/7-D:ks88(sm0cYcJZIMqyce3vypR^aI4XibQP{1p:a)lZB%{SPB.u>9(w/-SvZ#fa9Nz?RGLK/6/M/CP33Nqp3X<1+GZaF{j6DK!=j19!X?+2}hXC:m.Q-:c5-#S}n?}jN9^7B3r^dlBvkRP%[[!(ZG6UGy}*MQAiI$JmOcLeA+9:MrY0zV!VR+6:zOSQOl6K2H!N<}%=TY%#79&oW+&sQbqFSB<49J1n}zU7ciBw7r-V?A#tE[F!EmHkJg-=xIWVs*3[09FiCxb1AeQnJ$<Q?*HjLM4trhgg}Bf3ptMUumuLwtE7b#OeLQwU!kOK/cGFi9}46cRYh<Ln^>:b]9bKuBU9)&/lVa:VujNJbvVEU1F4PcFdRdiv1/T4ol/?SGp)x4u9oZW4&)ZdtMfq}oL&WaWdvI(oZ1ISPt(@<Al8>2/h{N9ognm2}@wv[0!T1VYQ.^}{yWYRPG?=C$[e/5Ie4>-(=Bx0:ZbO2d$@W[UZVvsL+JuOg^*5{lTxCf5a->>iC+^SP.DE^iol3FA5vq6Hd.B:bt<7?p@iL=MXl/sSL!sh&&AV8UcmQ8=r{1!U.xT87XiAWm!]B-C{i9UGF7yu!!{<G<f.3:J)F(yA#ZwON1J(ZJ@Y!EMj(Uyw+3!C$?pY[c0Tsa:sYrcx@H1}^AsO9n{vp&&Ag
-l*#OuO74BpUzee<q)g{2PvCDcDPd{9bK&q+F&k#}ko&+k^T4XT}.+N#7(W]=qzlXmgtkrXANFuNlnoif{39OX3RfgKi2Wp!2Wr2S?)ai.dx4O2(yJQs@zqa}Tmqom2<)^#K#gGUfvlH^p^]?^sli:^UH}&L<2WCi:j70So5?#prc5#9rbjMoztNh+8pS*<!dFFCgL(E+-VgQw(Eu>LUv-#AwNlg<ORIwJc*8fhjG5K]rS90ou5mwt<32-[!K0fq76jqcqM&^k>QlR(a<%t.Ri2uPM8LeyL3hyhl(P3Z+ZnrKJ9!]SMQwj6s+eY3v]LXc750#CMoP$psC=wcl#*bMO1#ETr]UikD5<eM73hL^?EVsgCNO.0nX{C?Wye<$w^nim(]ru2YHb:3}[Iuc8[LGlbCg4KU?ZT#QpewH>l!l-9o].CD:054De{s[j8h6cPNH21DFBc6b%5TdmDAhTbqgjzq!*Y$(WG/FzrY]<lIBl#BTJ0%b?*k+}V1b{:8>I+?:1{G@$QxlUE>%AzVWmdvzkf$tctFrZjS(<Y^{nAua=}to-qaYDg8[vLJozM]0zUCSR^s#ka}[7GLN*>Zk>q%^SH1$Vaywmq2vG#M#cKCk0!0l!zB6rd{/3WPcp%3@L?4wYzagJ(T8{l>qlMkc@r9xBx.m^Y2THYULn[4(^3)hX-C{l/qi}zKa8FyDiPf6UP(K(>?-<u0x33VM=(n&^*$Uzl5(.*xy4pYUxpeHaJCLR[Unsb9xK{]>Dt-]o/9xfl+UR)?Ij!b]e(9T2DmUo[zuN*CJ!>h8fsPM/ll.$N=WlOm9=1F/lXU]I]JyzCkphNfhjK+Mbj35DK67JUsZ4MCm.>H1nQM}sLv+(jEjNbBp:Cd)R[$i}6UImS=CH2}+Z.]-zi4pGxQ@vYyj<=]nMOr9DJdCzzqx9h{/b)j{LIuq0W1/Kyaq:9H[nfB{etbnr$oI(Dm*lH@PoB(<gf!C9KJ@-k?ZaNIU7<!9u*zW&:[?
-V*OTeT2><CFOh0>DRoJ.maeVT!9cyFCwfd7QO+*ip?GC{Co9k5qb(4m(><k5(:Rli(33PbWMUh?C!VUzk&6a}P2suOgBa-JNQ149lbirmU[MzIW4fTMM9AB6D%>lKy(u@l>a[]r*-%5n(i$+8mSG>x{6=!37/@-(lE9oiQBFR>){$GPMF%U6x0J>&cJm]i6Sz5zjT0IUBQmGBkYm3LzgO(K/z.j-tI#8=3^uZBKe8PL5MQBCm&Y9VD5feMr&wdMBsIi)5/myfN@lHYW+f3ZiEL%=z(Bz0:O6!-m7b4t{LH:Wz2P>P)kr$rF@ZBx[sZgMraj8ZrCj2t4PSqkQ>ZCPex5B9=%1<+6!UZf2#$v[mgAlJudnd@RSf*)YVYNsejL7YX<5<K@+e<@50)rzdE0/xl/8x@KiATpNSHv]d@ZK}t$flD0A<p6}d3<3!]aR!?)v^sGF>FBqDq?ZlvaTfzh5OX@BL&cG#73yyI^dnzT!Nn3-?VA<RC!2x.Q:.X>^GB+:aSGXOAOBW3dSD{)$lxwP*IK9QwWgn{33WgW5G15WtKliGhS?V<R}/4mr<YQU>k9qUi^0j27x8Q>+zuM.Gn7M#aSd!z}}l7a4F>wYSdI!z}}eCbQnudbE=l=mWmi?&dypcfQX@^CBhQ-JJS-@QM)]8UCQS@r=r#u0OKT(d&stqNk{(-w!g#<}#=]]Wy%In5{Z)3mTi](9?z6wA{FP8!KO%m0-/r.Tpv2lqVMQrwxoVBj!gdd:y$LtuH+q64Zc2zKW/uIA3Jy]3ZRseS<{%V=41pto{wL?x{Un&o)$T{+)#{S={Z]ntF5#dAPeon0Cs:W5*rCo@zMdB.:2&(-c&hNQjtQ7*R%PBT6Viryhb+]VMVEUxDa2?..H*LvQ8:*6^.TT6jm}66VBff(KX0o+lI+am@6CrJT<?Bi5EQ@UTI[60hdmOivM)rCZ}0pi({g8o.s<K/&BWx<S#rCR%Ft&f{/f2*Y6QLd)yWf4q::BUEuI
-la-}W7$ihwzUj3Oqne%tS(rEU0[TZE9vWaCz1?#+JJXUGZg@4hg{[uZWS+Q6D-j6jJ%1NJM6/M:cT.@BKW%bPd3pU[k{xPS63pTSuBn-MiHK<P1VFqlDMRiw-C-OipxEZSu3ienY6qaWB6ex.<NSWU*LQbdEr4mbNi1l>C]P>6f!KBQz-U2^V/8Uw]c8(HN10k0y?mQnZ8{M[jp*4.kD:3)v3[9jvFbLuZepSe}uJdb6u$1{ZT50PPq2*vZpK7.>R6G/Xa9TNVauI5j^*ybh/yFIe]h4z]c)xS@BD(e5:IaAKGJ5k)&/xZ]BcV5Nt!Pmcb8s^qK0-7gpv<QK-Xi$:Z2zf7dQnL&YvraF?&k$q-R-rxbG^6+d5z!J&:]^RD7LL]]3bkihuEIEIIx<)&BNsqj1pY@PVEDEETv]CvbL$vo5zPC+6-g?dJUCuG<N!n+YkC>2Ku)?iC$mykK<KkYhIGeW}F1tU<SUov9/c/NDMbd:sl/L#U-F[t+DW}V35=Hp]a.Yw*ENUct7sWZ6:K?1v%.r$eH?c4BD:odwO{&G+bl4cZ72W]Hs8+goS6X0lV/&MGfy8zp0HHkR&mwOfD(g?1.YRcT[<TYh0?995{w!VVlgrIvySY&%z}P/(A>vSa}K+/-Vz?QGt6wyWKY}Rx.1ExHxWoqMR8./uEIl^uVN)5hKfb-lX?xE*aa4wZ2V>+{9)iJyo8674+@S.iPTV*d96I9aCdlFN(/(sct9T9m5$S/9&En=cGj(AW?@vP^&U70YnZVW]@tC&/Mq+lwZFIU>%dUGm=WYoqEcJBXEx^Jed+X(^YbqEaPR4TghT?Rkv:Sv*ub/63xcJR>%S?[C)Yzg[vO(e2gDKcx08]Rr>lxR@xk./IN?O*z8uW#S]#8=H3ow:a]-Z45N)m0(qf(m@vBjepR3S8YZ38c4McXZ.v[=Fqr5HtYV?S6f-=MgMhb*5h)=<2WXm=QZU4(wO6:jeH>Z.Y3!j2xLn7Sv)j4B?9ob
-M*(EW*ZdVmB8&F-plod2VF[R6*@Ysy815ciRky*$cE(VmU?p/*75uC{B$Ni0H59PhQlNj9uD^{Zq^A6av:AmH<G(#-8#hw%o^MnY[X5CMow}#}XKt:TB^S=Mx@BlpyqLxE+otiTauX*X?B9i3^ysD}*08)GI[ar<Sr&}!Zn@WJ?L.+Wx[68X+{D6V@?$:9j5Ignm&:5y}>Y-^*SaE]<C>H@Gcw#I!%^VMPXr1P@MT{j+/c@-S7pU&HLul/}4Hw:DCAPJ!zyJ7d]Dmqb%XQ4m)Sw!h9[H)0P=kvIM5s&UQXP}Te{v=S$J>zbyN<PPMh)d]+xN?(}:RDWQQS2@!bLQPUzD-r/(t/[/fl-@QyK1CNn(jGG&!qU/xzbfAciMF/DB-JUH(9Ie%%rC.{HWa1%)S9wt*Ak#vZ38A6N{2zRTt?Jjg^t2*HC/v[5QJT(M&?Jf2kmc!ip)@*Kw=EjrduG{mDIr!&oDR>g5^o+??K/O0I:]XO6sh@^X9lPM:c![if6JQ0$8z}e@6eT)y4&U49v)jOI2AoDRxZvksm[<WyxZwtutf$]!L6>=$L#vaDcAX5j!wJ%Pg/+>VdyiDni@t.NnI*rx6t6J#BHm!&!U-hJCD^I)?8Q{k-X8A>G#[a-hKnlJNM3]U!DxR^6A4=?.s}MqmXbC5jX:wPSmgrmQS#zbQNGVBU{GyEqtG8}[!N=DK*?oZysLJ?WD:xAtj<KsTUH/eyl+UHaEbpx%4/v/}.176M2Y>JrLbA1d5Y&5)Buq%BGMWAERdM)yONI8J[OObZf=(<gAr*!g(hE5OHi>]:4%P*^ZD)446I$:xm)<V/yAqsd..50*)0cox}q}o(e9=wkiM7j//n{H@JV1tN1IAc(a0e4-}<-]rgazJUxr3YOlEDh?gSB167TQJO)=/TtOuWo]fbXAksr0yZLz-hO+$Be%aV:qb>xLg?.t8p<TO3<W.gw6<O=.WrLs$1x7$tCL(f:lH6MV{?9FK}F!h2bi-aU?
-33DcrzTO.8:2?mt>l5hLgAN=XNUmXJhNQ76<>Kiy8&{)XJ7a)IqY<2L52kY{EKKD6kF/OZAlyxNAKbasxN>E773nNQ*g]G@x.@Lj)xL-S*N27r3!+{q>)EmbK{X/iRbY$C<6c&/@B$I/]GfA8ouHbyh?1cm^Li4:yeHn5RBoLRrL@&H-RVR0uy$a)Dyw[=+xh{*7jv&I?zxSJgGg7J>j1@Xc#?5ZaC(NpH9xzsge!PGkas0QlZynNe/k+Nw^RD>T>>D$hDbM7NBJlz{5m.V-?HYx?9Z>7q[Wf0q<BM[Sp1uWN{4.3!uXRq/oq30]u!+JklFjVc0vkH@7wjG]A8V&L8Z<:7U1cr1>EkxXHKRFdnigXu:f!5o#D:f^b4:WUNnqjx?&#2ZadCcSQ2#@aQsxbdVOvgzjS{Eq{kwmN2nU?{=w@Vx1yxxIDM374^=ev)ksN*!rh?VtIu0=5#@GSRzaop=#%$dn0}%7?Jdxp>ZCdW[ZRUWr#mN$1jW3gV(H.4kFz!lzZiUo>Cic*wFgyWMsrROgUkP9.*IWPCUP>stRfqaP=f&sd*QtlEHy[h@9^03w^QR.l#*/U}-pg-x5.>K^y1Vad4W]Rhq.)RNIblg}VL#Z.87*m<uj@=dm2$Mz4ysKzllig@8{!aHmxhNWRVE!B!h3)J-tg4<xFhrqkU8TVf?zODE[]jz{N$#u9xNWMuU6TPd:6@s0l@EywPG*!PfHt{JqsM2^F:F7oV)@3c+J1{CRQxl?/)!<LfSV6%.c[s)[-eDNd[V<LJD1k1p2^QiW+P{Hz8r1O<XjTn2z7OLNUx/hhG&@03H@?iODx3?S[evcCk<owGo(C>VSsYSZP0EV3mmF4}=QTl5kiC<He]EVFFjo#py2uza<#6y{2-}9c9@>LvVh%fEwGy&1.3uVnZ?)@#>IJw9mnPe2Xm48@Opi0)BG7O#7sGmk+r^L#A3g1rO7}hzNqJ(LK^gk8[>J<EvO82B(:EVRO!}@rFq-WD+u
-a@E6{vu!-[76:{30kZY3[!*y{96.mRC9Aeu97?5KUe:t52Gyh9q33k8lYWtc&9++C5pX<SdIx:dAic$oZ]fTrL{8hy]R^F^4U=3&Cz7PCL}YNIKjDx97$w[!i]&3nq9*O}7=u[0Pd$6[Hl*Dl}Y367t7/-([!!b%5AU^*f*RhM7@)nzMlhE/iux3T5YLcs-#5@KfRp6{48NziS7dXduse+h0y?HOxAispAz^wggE/n8+X8(7GRvH!]fb)0^u(Q.HciE?8IE{BAy{/8gE:gq^dgcbkbOCwrkHQn[!*zCaK1FSZxOagv(bLwe=P5i>s/pYJweUfjJoux2>F}]Ofl3*Jk33XMy<Vvis=$&62%@p9+gYZG.ZO%K08UVJ248u<]rN4@0Kwwb-I+SjJs(N2>F}]@@(<g]#5^x48R{a&g#*BBUu7ggW[OGE>*H&G!xp(W?!hRZK*Zwmg/1t@%Ie{F1(tBp]muY1r<w5OKc]w):rk@!uUMvRwK^/-^7tQ[6[^S5Apa#>6qTkdHp2#0p@r/CuCHpyfriOdmOw^OAV&sq}]GF5CLUejD?E62o&J)FQ5PIyzhxhtgKu#IQq?HR-B0N!v6i9&X?57x[Ud(2YVso]e7iamg!D[sR3-CmK8p$ANG&B)uG$[*yfDz2^X{<&X*)J6kA<KGT+jp9C7a94&ufVX/On)dHp2k0w/uZUmr[Om.)lvC3da!*B9tAaCK>!fPHw9:5pVcF!kyjfPNQ9yL9hc9X-UMCgM)vU9Kh$NpsUG-)EL)cBHJJ2YU[e>fX<clLek#3^txB?hKZRT7tNlRwJr:AIO/3n]nFNlFO3BjD.z#9Fy{WFQ5JGYeZdpyR[}t7.}QwXrB6^zeP.E*]wdLOg[<<lFOPRjE>bF2TR=FyM73/i20j>O+eS9{cg<0tm)m:3g>OF-h%r?]3)a3aJh*omKRh]A><VYnTDiXClx8SP$&^LfZ/Ap*^uTraHQILGT+jp9a+181@Nj#>}Z+%qXm@O
-1lNsJC#>NpMilo-!ui<7&+Ef7qkLfhfIjv&FzaPua-nf9MlKLyaJb&l+U(@[oaZ8$sZvne-8h2wU$r%tJBSpU8ICQ3fGhNR/!0FH17l2vChm=ZsiAu}x79kJld/l7BpB%O5{<?R)xGd)(cu$]fYhJlZcOdrcY4a1@ERmFu^=HZ2vWQwxG44t]e.$[i4$ThWe<lBp.q^Dn=4-gaK?+N*W:+x.HeF7i{.}J+R{Nojkwd7u[k9BB7=cIO=bjFS&PR{M#z{w8h9nWS9=sD/dLOMU%B:wHwD36}z%/g-SY/IsNbkEaK26b}w[?P6n2.xhLQfYJ>UDo!TWLg}Uvg9{z@K/TZ%9mOFl-nNA9Xxmf#sbr?))U{1**#dgEJlZ&EI2Nuj[F>hkkxy}*:#yB9#7}?0hKBQz@w)VCiU9LXT(RVZD9<.hnVfSb9#)S8X/F:w8LV=CY>b!pDKdeLget%@.@lIr^dyJwM6=oc5FM7Z9fk9Ac{ll%>o5d9zPz.jwKlQ}499u2C./J2$TuHjS(5[PE.fHhpg+6vE$1)6Ywig#}G0tP$f}3tDuyO8^u0eS=2Th%#toJo3:!SE}LuqqYx}ha)HD4j]d3yY&P)DNlUv:%i[]fr80GR(nK7l?SnxAaIK[Gn3]Uz+F1-C]Y^W6c#*[]FRNbATkxt8ts0UAMFa]cSL.kxyVu8#n<MYCX1ya{WcWLi*&l6>HBHGcSJp%avRMOPD<<DttRbAqWb.JA-G1m{$&Ocy@k/T(X<u>-2yZ7HyTyKNsU%@b<{tVIr-9<A7mbWE2kp@b&?1JL0L(MPGvVF7g:5]$1Mw/+h%V7**F**ylcJttGC<?nq?QDbk/#rIGoOuIKFw./8?x@}nQ>)ug#8@8lC^6X2nHB-3<>bH)MxEKu1[&.9SCgslRNoz+I}tB>uI/C].oG0(CAk8vj5fXVn{}/$bWUA&%uW-9U9K[=7t@M$1+)WvSNA1}CVkz^U&7+H>WA$()vf^#7*5C!zdVm]]m
-*!LxQt+9p9v@?X=4T(R$0}@RCs+?jFXFE42e:re:z/5o%*Knl>IiX:lMNHCVk3PhI}TT59co2T]MmlG=D!O63eMmjoD!M7[V<-kXgCqZBe[tGFt-O^K581&z/w$PW9%mFyn#P#7=p[D/VA#5A[Mq<yP(G49y/YNZP2LU(OnQ#%RaiILYB9h)[^0T@?t9CwL14QzMUGNvtXVO6/zfE<UReowS@S1#Io1EVI0(]Tzs$o^^]GmGI?eryM{s>]-=GtL!)i*mw!w(yS^}E:l+Olh@oxuv]$n/%pRi}[UX*!:i$cAR(V]O{V7x0N507MW:(.h!P2Wdk9>J-cuC<R[/^]{f{xgOmrFLl?QX-I6K[w}h0*lt@F02A5*ZHFHW>sAv^xP^gZfAj3D>2%[Vbv9SM/d:fP2=j4(XRX<VC/7tfoG^$({WI!)IXFKT0*v]VTgJMRpgp/a+4D=z&(Q#b72ATw7XQzy@XkFKU4B^s(b$c}R9=wNQ+O.vksrhy2lG.DRssX[LESEWu*Om=ZF.@^AUaFzvs)f)B#vLpeOLyd{4+@uZWvTqmX[@O2e1iYd!IiH5G?9T$9j>cNEQgoGM^0cej{Pcx[qSCVCo[Y>]vGf]RzR5]c$EBAQkp:K}(YB@@WPPzT}7pdj%EA*ha1JUoXND8}e3RPt?wBCzzF0^-}51Ot9I!:5GY5[6[rsIl]]c&[-[x7=2ZQxHiVNgVO5.01NEyAKiweGU9Jx-%SXDMkZKZ$*oVLMi/gK^b}vSk].%7YKS7ze%E>8aEllp.jorj>-xBbqD{cH=u<efE0A+9{GE<5=5-BvwB4U[P@ayy-F2vbAUiFLZ>Gkp8W*+l8pOv(y5tt7p9?ju4eO&9oL*aIo.8Tc14}hN?vzDQmFu3E2T8BRtAov-}J^JowpGLk59d]zf[{(KczEljP/+M94p[AnEp+IBjc8mg#c+$:8=nh&Cb{<7nXJZ33ONZ3X>.JMJ:e/x.+xP@p>.GCs1JgGxg5}+qI>d
-nQlkQ/nxbumbzIEtD<9GtHtqVRFBL0uaEyyQ7}V7xTu.7^o&v[WV:U{?u*k@Gx0ja9<wwmzr6S0L^Z.&4].kFZ}26ZT=^u5dZP0a/<KH)s=tJ$c0t2Zac#TH).Lf#:nd{c=gVp)t1=v^Av{2HB8?etbFL?^Yc(?0y56^@CC0rjTFt+VG$:{?@<$l<j+m=LRP7ZoD(wyt3g/4K!n<w@(n=k1r(bPVDjwiXY%P:QCscU:8=XlbttK*%tVUEIb*!8q(*J3b2j7-7Ift&6(We>CCnonl{tYdXWXUZ64rV3O@>sA!(rm%12j*IvV%&:0CO!4VBnWYiDZ0P))qwgP/zk$.TcQHJ&vXeS9Q&0k]:YBs^r>LmYSZgRxVmpACVyab[GE=0lf^bn{tWyUxvasFJcuKBDoYz<}^3NaEc{If(A/6??lf1h}+Mz[]bl$iubCoBDy%iJewlRa<dAalcUSJeJPR8{]uRa(E2ik{9&$b<T[9p&+zSsl0R>0l)Sn2FVMg]uE7}#7!IHTERdUzo(*G}4mpVrW[{eDeP.[F%GU7{@GNS&PE+x-/kAU(oa]-D0+z<QaV9Z@7=@*p0VhthJE.]i8Qp$pY?qd=-WeVAV}%R5-?+Y{i{vQ1%X:x8<M=pz6b5^B^?nB:!s(DI<ARVDngUxeZT2a#bB7Gi.VhLzq}U@4)=r-S:=RN{68bfkkDBg3m2KHdr1L28RMe.bR)ITJZ>LO!?T2(ox->a)#St)^&eKk-WfwQRROj0Pqy@ConWVYv?N)T@O@JJ6IvSnk<5/G9<:lEB#QvCIq}5HSXzeDhSiCpHBfW0(LVPhFR1+<lfoseEYRZEWk?4rv)>rZ^ya$$7zzd=Ob.-JOq990)0}6F.v8!(L]^k6yhr/?+7!RH7g!xZ=EP6Tj9[2ZoWINHi0V)Rz>]]%XKNZY1a-!{7CTYRXJzwu{<o]O&bZc6u<vR5GTDPEC3nR@wN+7qM+2v0idyBrs[&>wgErW<J)w%EVHDf:$w
-=NLi2iBP8Feod-ueFtJ+?6QI<@n3RQ<>z4#Mav76l)y%zOSW/O(wh:wQSz7qWy8^J/EBqpQSz7q!DGI@0Q6(Hf2PxO5U[UwP*=[W1V58Qh&lt5--dqFevd$wloXRITzG[MTwV.^z[z=s59=}j]HD2YtcxQwW&ES.alde8VCacsbK%lGe-2qQ4<i=uD3{%zo4^6JEETz$q<J)O/q}(5[z9mU(8c8&G$p(g7.286=[rB.xH!iH*%Ox=rC[gSr&L6Me%pX4KD5Z1nW[nIQvD=OWt-=[n3m+{H@x4ge@UZ#bIFR!yna9%Sr>yec.VFJx*XyJXH*Ey&zdvg.eP6)OHY?5o3tEeh4wied9Mlvx?Gl7Qr+W<2^P:][fu1P^p{!M[YV9oa.%^<IHj5ja30}Ytl4/y>>wZqf0?B+T:O{V@HzJ*hxycbNpH2U^9SM-Y}sTdVzLz)(ZWVW3h3:MS8WS3Q*7tIRU:]#-7&Cu.sqFXD9+lq9}EGB!}%Pke0oqetsd5g9s]N)]{sSzXYqdD!OWN<EhaPEq^)HFo(>v+>h>#-=03f$h<{lrWX%3O^R.-Stz}wEyIB1VQI7{nrD6.EJWJ5>FJE2e5dpXAAeZOmzd)%ZFntQ*7:FO1&VBHnJkBUWm)Ml[+fYq]HIpN>RPcl16>4nHm/w!?0^wp4p0ERuM5>5+=aiMjDYWx$Y9mh.mZxc@v7zqLgr[iyJaI8f<ay#)[t)d(?e!iYo/7!!sQQJobK[c<IYac[fwm=S+tav$2G$%Kq9.i4eis3Bmg2oJ3FCjU+uu4Q!}dEk-/si{L#kp4Tg*vO}=d1nky2xt(0FOkuWSfyo#2$e{GEmuqx*gewZ4oxBuOeeaf9c>uL-mbdBL(](Vdw>dsP*lc:65JDG8Km-p-W7&[sZ+eX*N^s6kb}6E63mqgBxHVB-nZj<91@4xr^M6iOaw3Rj!*RLD0f!UNvth878>a.>q^R3u!z*99.r[+(uYvM/Ovb?wgivJ.{)6g/jz
-HJ<Dz3Y%2+Qla1q4i6Lc)9l]Zte8oD)$pF4Sd7MQkZCOIeQnQx[6%Ah1}.kNrznxXb=.NCyC^7%SJ)#%ElM9xLN{gp6N/Ry::be%Il58g-yjs-M/Mz4=*:zD7UIGF=.JdisB?<9DXab-RBc>?qt<W&:A?T66G&E&9MUQw73KB/n7TKcSv4EZ1*j:s}F}qdeWB^lY}3KRP2=j4(XPBj5zu=uYQrN$pH[WiU.5OX*02fWD%1kMpH]JfH?{}du!Vk%pH{rWvfJ5wVxWeFgu?$:e//}2t.M%)JECc]KK#ai=?5HlLupQ&j=7-{5$ph?vfJ6<-o[J?{adL}!EaSKVOX+R[KQ#m^(qeD]D%SKd[ubktMT7s4cGi/q38CS16i5OWHPDmIH<kS-[n/+ur92g^#0<aU9/05lhvXChVX8)bj:Ri.d/y#O/1%mbIdC(T[=.[D9cnqP4AxCY.p]zCX$RA+XJXsA*e<g=UDe(UG.%{tc3kFAP7RGr+0jYUZJz4kz2:N&N40xrklNdmR&3q!9J8K-G&{-ygURY7dp35=kV(K3OS9(0q=?ON]z5V{zfvuDk=9-TF-rHJk/fL:Ur#KjDneOxLZ-f.I5&5TH4NrDH{HGj..*6xlNY7Ycyb5/Cdf8[vWuztf*$EUpStUWZ^VMxpBfMtE5LwMsbx.9..W3=W66jd&J94iZ*K$!VXa(TVaRH!)!!mL8$5g}pbjZ5S]<fKe?A!-Bu<uURc!=**CEnZf/@TTTyMG/M}1h.IEO9L5dwo:p)rlGv<=JH1H1*yQ*3Vi<y*[[wJ)Zy(s839UZQdd!1kd?pk#7%m%V$w9E35A)ao$M.*{(w9j!D2G{Rb*g]a@o$cnGjy?f}1QRWiX@6SdLA>D]3GXk=M8P3ARq6Fbx/V]G*VhSP{ifg9!P)!=S9ydkxOOdvGN4T{H$5]VHTYX%KOXp9zJjKtTWzwT<5[kR<i!&0@)1FXb?hWhu>w%<{ffv1k.1YXCox9Bdfk#}]XX:U
-0I7kSPEYOn3fWa>rSY<17aPzG[>/EmQrI]}+0#^4JwvM^cM:UcfuQw5ZKhn@@WoOth2[G(IeS[H.LMvDkQIE#zP2gO^$pi#Xsm2%5?<E:TopjOfEp9PEXRJv@P@GKQY.8fa<]ir2&}5+{(lMT-aKZ.Amb#4]E7[<E4En=0I/f&9faW[2(CZ1@86l)JkoO:nyE?*wXy%)LZ{32?cV]FoP@3eilXHkaE0Lj1wD:TqVXqt)iEKXI-.FK1W9WP*d>8>?QBDI*g{Bu36/yBjb(/649XlYN3mv$M}o]=49RrwJN6}V&M:[OO%aj3siG=0C&2Z+=l.ow{:)t=ZhB^G5$[tBvZ1U=FO/3Wfgqz5xz.=2t2Ki3<H7fCXYk9F*[c)}9XP&yYn1pD4o/]E@W%G19arS{oRKtbzlu0W@0Zkl)5Yhp^9BP.OE:0d96W5d8zu<=yR7iss5uOgSjL}KftIt-*IZScQ[tAmHwCt7Kk.i=If(slY+ZA8uACImozBK%N1<k)ip+?LY)Cw0OTcF5}-GZ:TFt+VG$:{?@@i/g/8EbA)-A$6HNp<tVPR.XlZR6Ry#jtnW0:>9@130&DaZSqRSI+A)/?=+LdZDkpS)a?}[95Sfh^pb-X*>F/q3R:BE/Qy)5[h/!pb4q+^GtjUC7x+!zF)Fur{iv?-#UB6N5d5o$VzvPT>4q<H[dR]>&^Y8rgbiWqbJHD7T?I9Q&0k]:YBs^r>LmYSZfWE(lV%<KZkSE29l0aKKuLa0Eo58AiR0bVn}M-=I2.V@8kr]9jG^/7*PW]c@yylEh4BA9}]z=iz!}6Xu%Y4FgUu9bN]a}+H]*ZRDj^pv)yp<H+1>Y*R4tE$^Z?bi<7D@XHII96Jh[-VhFT@rvKG^%Q$SO^xOzZE8ryJRSs&>q00/)+^=rCA*$oUPD97uQ2Xi6uQm([#[:(*>Zt0okBzbJPz/g+obc]cYEdl(wKl?rN-AeW/qSX}%R5-?+-fw<Bo*jT]@k<-[XMYFfF}g
-*xvt!K/VlFBOR++f-LvurxpASu0iUgTKSMeN7]x{z{zDHlV@bk.NV]))kyVvj]p?zX[cG.8mZmAO<uUe=m=6a}VL)Pu<UL^95TDxk:*DiEs}2f*(or(4=>(c:EtMiLL{9q@Bf-zC[I1x{76GsZ^+Xo:E2.yeIAEMo4+t6kl(566k1=$-gm1O(5X4CA&7?X:T3RhaE5A8XL(5!C/Xf:t:3@AAWCgOSTO0p6oLHRIPHU&jgs0v&N%Ieo+j<24?^JL{fUi3rY1.-*G(z:LG{</o1U)Q=:fwcws57-W&eX.F&m-.!Xsk+?Ep4{!{oxxW[hBm^-M8Vx9UzTgIu{TNR<B[rXi1u!t#=XuUI3E{a{?%!9AI:v@{zaqCODO9x%p4Rbw][+>mCKzK4!4&-r5kKsd#r+[XD6Sxhj}b^E{odALR&JWRkWOG#(rBR&i4us#<rn[B^w(G7NxrJlCH+Muca7*R@6B%xGdryhQm4#E]zYgI8ChkY8>wslC$7XllewO]4Jwr(CtiO$&]DqGa/PG^gR^T3ui*VTB<@UP0-M?pISaeF=](hBC70i)A+(M.J(y9o97EEVW*HRJ!Ikr{A[!f3(e}R(AT@oYL(gE9cS.^HNNF{6.*l-&oIB4=zar>hSKtbu#lYp!J%Il7UOZ9s@W:#ti$siGbHhu[277X57eYc]q>J+D=oHmhaS0{lrT!*EeIeONOxiK{.mN=d2Xz]3z=}jf3#rz7DVdM4W/OHzY[8FI?W>aZhts)jQl<Hp@0=7.{^XW2*S*P%GiN*TIdK+LR4}k)jQNu8-+LE37QtI*hYrVy]l?h=%m6C<W8V%kgMIY?D?+:TqSfoS&wz91oQgS!8sPRA@t*L9O]7VP-fFjd%G)AldcI}g+a!?P?aA6l[?:)Nm}:K+B.g*w?rfh0B68oD6AazSp]?f8BZ}=TeZRkRcq{SyG+7s=YIf}jeCoq5oCQaz6JCsg(/fL]r^*}p=340SGNxovvEN+y%yw(}$ZneK=u
-Il8q)^rmSXj}uw&5}%C7GCv>QJb^pvnBvdMzdab*nBxr}WLe5L.Yw&HbpV6MGb*+[u78Xa6@kbEqq@PSwyQune:SFa&qbYBZx[^$1P-XqU5dGXNWK=x])%.n&SByM&o?W6Xo<ym2KX3s<o6QIG+XrQjZQdQa$YO?TQdysH9%nMDpq2@H?DmH=m)G^RQFfPg2iHAM>:-UN2%)Bvo9}WND@U]x+4WgvWSdncZQAfDucNVLfq2Z:FC3vg1p9?C96*)^lryXptMEoC])R/MRpcPzC!HorlivCoIN2pNvH<-:yFsG:yF)L@sN)oY4-B<QN.frSd6ZDoG@BG1%@..fn?Ll=G$(!w1D%#zeiJ=ryo-$!#:HsyvdbnW!LrJCNHF#V7w)W61!8/WRV85WFgtLa(9qMQ)TT{Wh*ez[oYs}ZPkbz-lTTHm5m7=k7{x<Lvdg3CmpF3(PE+Pay+A:TE6]YHZm4#Z0Rq0I.Rw/].z4Iq:H+gAQRp$e!Iw+y^25@-mtQR)iPj7oy=Y4>xHEFI[5=Z4bp]@@YNKx6rFP09Pyv.(EUQB!}TODK=lrwB%c5%oxPFuLbm=JRO(Nh!SKPma6SL#-A$Y5y^25@-mtQRvJ-bDc3.r2CaNvObaD%}H1G#}y#AhxC7i$FV{OUBq#LBw/M}1hl$ihu4vFuEL}*PunSI8#QTwGVW5Yg4Y2(h7s[[PMnV:wFP456:tmvq=ooNg7gcIR)haWaqoLQQAFyFgC7^^m]37Q.*j!f#!msLwTXn]H*+o.byLfOS/WC:yflt)mLy3dNFC7ELB(!{G)7=VzAJ9NE-rPP2}ORV:@B$$e<@3GjZq?B^:q}@3I^ZDW5B{!%#(Xr$iI*opK&Nv)v*:(roe{2(xIf&C5}CX@RX.T(y=BzbyipN6bWNMZ{6&6bUAO}=RpjenyIeL*7Bk^XJxhnvD:Dy{gy^[B=y#2j7yXo:J.?*EG+<a2H4>3!7Vu:w#)@QK54!kP@[kXIaugpRka)%3l
-XeSKBptu(=+.-Ds4SQFHN4)%lRyRtqcVOb!&e{6!DN3oo-[f#@fw)Wq)^l?Eio!e}ms2OxhCS3rZWkw5Z4YL7j}.-dw$lv}E/3?F/eN^)*)=3@I3Dp?xs7q)Jkq@XgD}]X{hj?UjiUJ2[LFjf+XEB:TdWbR*kXH7>ba]M)i(}@U[y{DwsSii+<^$iG#/o0pj9Pm<Fy^[&%o&g*vpY6nvPtGu*<ozFcA&YJnGlChuw<}pcpMfoBmsWq?hMG4P<Nexv4pL<S:etJgmnBHpU#cW8D5^33C=yjMAq&yU%[{k8rRUO/Y(NcGm8Oo#@!{]t4/1N$J!AflcwZXnPQ0m)>o#L52urI4!i8}?X#FMn4F4CoO-YcH**diq^459?ya92sFjGD]R5W80!-</*wqyd<S-(Jb-Zn!$E9xSDIt5!D]jLFJYJrMVRIuGRF(NQ54+Y7$DvTQ1P)[N#^Ou]kOu=Pb6?CB<p%c(<CGTS@S1#Ijn=z-p>4^oS.KS]]?+{A4QqehMlrlijn+:UxF!tiaEuJl-]-rO^1[lyve1dR}chEWz1tCthK<yqbKomI*REC3RvnE3N60:wq)B$mW6+gW{.avcsa/@)v-AcMY(aTOB90dBj0gdHr/%tqe2-L:J/<3=NO#8PtddmCVJ>wG(J[t?T}!kLEA:=<=qlz}{Ow+=^L.0MZoT{Xxhu2?Dusb7T?xTiS0Sh]7G-K]k-g23^l..or#WKsRk}8VWv1ygW#*XubCWowIEO3541)XYZ4C5gj:l{[)]v28[&pk<H#L.Uu5JY{cKKJkD(8<)oI2I}xujX0YbC]E7Cb8V5RsO]YoKpGlL$mIZrlzZxABEdRt#mK=/43[r$%+VyJ23uQ[Q@m7#2kXiax/!i&A[=1w6.7@tK!Q4z3))niVR[Yu&t?@{escG1&D:AE)Ig5nogxEqSC5Lj[]E0X6r}WO&p9xzj4DUVaYgwP!XK+luw)z}NriYU0%czF$yH!XCoA5O#}8:Rs)?R2$]
-CAMK#Y9E[{E0l.Tz@!ITEH/-iRw]ia7M[fTaHI0SU{fFIvTO5kwv[f}WTgQzS6K8Jbxzj<2!Vprj2h)?{Spi/3s1?k0$[el9wb[QB=y+VGZrJZBk%i!Sn4OzE(AgtLjbatzSEpF&<e7{f42/nC0wHz{ZNM08aw8U*kXUSJ)@jXWh/YiB}oY$bBNNC-%ipN9-7<z9fGW@c]oUnxVCgm65=N*[TJpG!O%#{z]3xC0ynTmEv(fZ[RYV9cLla0mF.rO?{WWh1ifZLIaEs$Kyv@vq2:.GsXcNzpqB{n=lt2(9F<La<Ld8^-=.im>i11DJ*T$Wcom(EhTY3yXk#eH>I^KsqmAqC)&/kKnu1vhzatWylr/EkPatg94^]FO5TPmr@M4S}Y8YdSk<*4-DPbF@FN1zRG.#S61[^8Sn[+Ev]@K(0]#}Z>>AFZ?(ivM=Sc+N&[EUu}Gzrbvddypcj[1tG:*2@*fUcoN[xjZi/%9uIWs^NbZn>b*g#gS}gSqlc7d%p*/vxVsgoB/91$r7nUt0iEdbyF$p1bI$*A#x?U!Kz+UG)4in9dsjn&{M$EC]7DYkp0#21IL3n-C7<?@2[7T=oz&tV%^hmyGMPmZP(H5x9+w@t59t^CHXX9E{}mIBY9)jNNk7K<n$h^Im5eH&=0Fd<A^)IXRu}}6WBIqd/GE<!EU7mW./)LAc{o7IcTJuP%urF0p&n8VU&@mev92>]ket}^pR?y][:LIPBQ0+m>L{ri)!dtdN{*jP2<{)IT/$nd=ZDA&)8/bH^})Zda#Pjh=J1Loe$:m?LMagh&5asUUY(sVDLkn5!7#Se8o[VC*(fb^IE:mQn}X-Zj&#Io+=*t3K2%B88P#}VFq>6y<()5HKr3kkm9B74=[yD1mTiHKJEZtXVtn-cTM0plSh}Yc(s#Ov/fD*z2+/Q>/<ph@981:8=nh&M.SBO^j5YGGyo<1&Cf!L52@@E2gR=Yc2WGJ</(Wt2KhV:zjj&I&-c2=hv(AW?XPi
-a)OVK5$X=k>vVDBWO4=1Gxyfe<y^AH.rlk1-5>(ZmyR)5*FKMk}r:^xY:j6qTg5Z:WAM}]uwi!7o/%YGNCPubht7C]O.r@w?BBda@i&@wB:M3V7i$b1-{=^eBg6=JY)DpqOTeQq/6yXzc-E5vQa+]j%4:/}}U@LeTXjTTI1$y=WKop%2#/3t:[[hUW:g+v{U^$(HXGq[SKS}&DMJ0Jb<$]1R@^moj$/mVDG%@(Xczo/3-JUvufxxFYq)Q@Wi8NLVN}fMTeL[5{4HtRm<1OoUoQ/0yj9[bz@xEyi)EsRa[2(eUwUNGGrB(:X(Hzjc?3TVUhZJ+PwA6lu3.NCHXGmp>=:cZKnMPsJ$oP#{FYy}UPB@hkzex{vz1gOLkt&.!9&@?&+LeJRAO^^4saNw^0=6Wq:efGTXgR}QUWxdhCnJ[=*x69B{3DFlGv(4-@4SY2vwRhOD*/kRQ>LQ]jp8IE*(mMqtjjtZi$X}zSyQK[#!>=[^N.}(%tg1YET#))#U>9@YP8L)]1O-{FPU8ajdjWK]yH7@@&eH=@*paD(+{@}-P@2mVuBtx.9E*[!ZPm-UqF=}4-oQ^s837{X!)I}YkH&^3O-vEQ9<lb]JhKF4[nFUoV:I7oWOGF?WizL2S$q]iCIl=@=S?P<OM0%ltNo^]oz[96c2+k!yx1/pEzKq@bct[eq38*c7Cb{mcR*(xR:[{RysCq-OOHtyXGvu&6#+jguf+Pe?#(fcLlz@z94}.UaH>e6Tp0xTRmV[=pUq<Wc$eL52uNg/%wY.Ue5$B#<5$4mn&gZ)>[/x55{oQ:IwYAZq9n7JMBn?0{F%3+mP%0{04<990)0[G{(?)jPlIsTC9j+*J:FQGLjwr+B=3tG@:aDV7!kr<Rh3NYQV0!SY0{w{Zc1egn4(W!V{)sGOk<9yPMN53-PC!S^Fkpuus<&?A!n*0ei^MuZ(461Nzsx0]^CXyG>VIcE8qc4eB(c.9hbBk9=)pA]llEdBQgRm4qVt!:C3
-r&xjFzItUhC%Z(JvObkWBQ8Dlb!HRu3BI-Ih^q#:>h{naL!1ubuxcpnP$l]vh2:U[>TaTw8.[}b5)vLDnpk+qBc35rRRmkANh8.r]4svYzm-wIH3BTT?YlMWp>cZRTYu<L/b8hYE-Q#s[Wij%+?.8Bb6L#z}-B3y-ayN7K3)3EFFb}((A^xg@*Gs7Fj9Cpk[Z-ypEi/nq1(P(ha@OjSK[*e^W0:lzWtG>{dqx9.Z8PQ4AAWPBt]$O)){3V(w.Ld!Pe6JND@tT8F/YQu6IFci?<sk:/Am/+Nci[a$Id*L^fs^H+*A/wJqN4SRw4ZU0fQwc9/<ib}S%LUUvi+TQK)#h&Dmho#/6t}zaohgdax3![<$&7hn2maN.8lnnKn=yTL+$-CxQmcs>w#ZrICmhUqv5Xo(^n[29)lXMT>iFv{P6c?!>:Fw:3P!9[]XnTu+MJ+h%w)<zfn5pK&(c9>K8uLVNEW2Os{Mp!.Syz>}QYc!alu=Mf}y?Zk&eqym5bX}WSTqe*2*BT:?yB7W2P-d<Ad&TzmrcG7%y.>.RHgV:fVk*g14z/V[UVYd?]cR}8EHU2SbYgKTVQ:K8vA>>@*VrcNVJr(>*WP%)7&Q&}W3xGqsM}y*G43F=)=fyC*VszrXKdK{W-ZD!z1laqQb8wK:8PwURi@FBle2(=c7Z!$m/w2EFYwehw(Zt8TH$sYr3oC.loXP9O$.T$BmdPR5#I6C-.0Xb:h<nIm6qGVATVT&6&K=H-5R)%HeWV?N@jb.NPu(gFYp]60RllK7F5{9]#*ze<TCg3TO(x]Bdho9pv<s0+?XfsBuKtgh-NnPJGidyI:TSqY3[*oto7[!17XX0]Q*H/R}U4l6Mzo+!7aOX=N$>qC1d6gw?ptCfQJ>[6xwsdP@8zqZD3M8wLiZCjCg0{LvZhD-w$f+wnOUABlX=CNU9JxNTpdU!e+0B=liMH)V3-]T/0K=oXX){Q<Ch/keYugUlO=p7bjIS=JmB$/a+-(iHM+]
-q{[+sNF7cVq?8)53*(.zA/CtM-n6S3q7NAopiU:gx?/TIy2.oOQju/l9]W$sbp=b0l2qm.U7t!Xla5sPJBKY-2tbX3D>f{^v[y.WHCD:/K9iSkcLkY<xJ=gPfJ3&0fN1!$LFvq}BjfTB[MSHl/Cm1u1)G!G3m94%Hu&Kdz1zcAoTYsP?iOHcjSj5QQ[4$k]txZX*4D^t<v#p)u%qtN+=agLRF1]5f}h^{yJtRvUF<phnmocqFJC1%!&L7b1!jpcKuA}>m[w2s(thX/OxByu16F(<J<W1D07H/vH2MmOsj<<Vq(/WG(IBOeYz]#2EIFFZ-G@#G?bvdgmy<k/zTgn^+oPa-R76<9.P@Hf+l0^)hv!zT>Ab7%5T*}HBv-n/xe-oRD@/=2.(JO6(I[=9&}6kC@3]A^CnjED}d0E}Wu/(ND[zgdTn2<<E5&j9LY9R$U9k#>&+H1krEy{!UI}7=Tn4Ms!BL(kbOoR^O#v#?QfY/*ua-Vqpihl=BwGLx4dL%e<>hidoSV[WON^17S$a4Ix+4WhZJ}H@@*k%2oRsP%[q/^XU=Pe)6l{Iy/KC0qo8pn[B$s^8xe-ztEK%A<xRfw$&aaqkV4hNuBO8iqZUfyW7uFVlSGrXdV4d1d&mr[(OgrowempV7)l@gY9u3?X-by*K?qG1Zc4qe4b{EI$[/:AF3H#]nhC8:v[ZM>/n5vJd0R9jT8I:dzrL(C%EW(rJU7VDynt0kDLH}eeDm>ulwaO&Vuzzw2er>ug}Ni^=I>[QX]9&Ks@BTf2VT**NVFNjzU:V@hfuGXfPcD!VelQZNzi/Nf{%1$CT<948eBAN{sU%%:V>6H})x9&P9I]zA!VVxknW/kp/GDsHe(slZp(9NDtd{{bTr2BhXM6qWUrZ&CXty=4zAhP}[9JxA*R(:dJ]Z(q=BgyKQi+OFiqEzs<L>h4A]xwrE8}30ijVmp7WSIe<F5gVe--T%bYxjdp71ZPAY-!gc*$jZ7EYImNgg8zpAY3X
-j.-/QnC*^Ax%gn7yWUVVkhvyHgDYyeeL9H@:PMsYJ5^g}A=+RdJDOx%u2r&)rsSD-.Ul:CkH:higHwJJqF=!URS3M5j::h-5b1N@lP}.2j>eHcz0:4=/Cst1r}pQl>+f8Zce9+14Gi!jIxqQG1)}HukC?3!7e&/i4{G:ou*bKf/((Om+gE(ha7)s4TZg#]N}GfE?j:vf=U7-4y*3xT0dMH?@i6ub%j=uNmrQ2IT+jtWg4r@u7}R&ETi82)iUyn))pm&J}aVxBm%ribLbt+7--4!9YtO+NUL2MV]v&2L/pFPBUruI6]v+I$Y.plc)%5eyQiHI@aTHDg!JbJtjGV>yjK?<l?9dIc2-2oUQ54^*u%q<#NS)Or>e%5}eVwXgPn&{B1)x>tu%+3@]x.ha?ON-zpH=LE]!:8]xZyAVh1>JzW(/Va1..ZM<hcG?Q]Z]}8Hz2w=olf!N}IXRgSsv[+<JGc?p3d4UL2MV]ifEQX0LY8MT]Y7gTx&fhM$@1tI63tqUBl?z1!5mzb(vosekSxjHaL-*9hXSzgvX3mbwuXi[D0U(^q#tIOrsDw$ftcA<rK(WWk}Hmv@dTv^w(Mc:{#Vy+H]YGy8a0/ZoV@hMFmAhNu-cmwXE&)id3dW++E$jJc@^Z}0J$X7D1+)E)5e9V*]D(f%^}OgS]XuZwNKJ?5bi9w0muxvS@0NR<v$}q^#Z3LEvAB^ottfdUUBd9tA=d]QHEo1gZl^@V{=cNOqZU!Kz+{3yYpS9ydDxOQ4A]vlLZ}k%hN8Jo3$tb0Q&en.ij<q/8Q>E*:Ko!1DDRSF[PpKU]4cSg3lA*hg3J-ajsD8}.j33rl.BCzzF3vqTI{o3M*S{n(Mtou*}/7@yXUqNP3!>k/AL{0F6ZQzQ@<8?foh}6jD8wE#N7^$tEdIUUTDb2FiCQAX^CM{HIlp0]43tvl.rULZ+6!E6gr0pEjB<B#q%l!1ta?m{L1#XnY:x<j%WF5p2Pm8g=CLoYu:!Af5[#QS5
-b-vnp:G]]ZK$l@fbHsI(nWo-YI-.8H<ktLmXS4%pRUH#F=hYjlBVbu$1^v2[eEH<igHp=GK4FTsU<)kj6]{y0G+:?csX!AQjaQ*3}Nq09rbH43RIAO61Jz60JY117.j-W.@UdS)R%%2^VjvYO{i161Sa=y.mXV$o3tdcFsAa@1QcN&XJiaJeNdEKgd8+2KdbU:Ei9#w1{]nbx[d?SBBT6F!GMG8^rdWMG(t/q7-tESRxI(0!76PRgqdcpYNbqG4<1X9m@aZXG1sCj*?.K=m68d1N!-PZ*eEBumpX.Cr6){K^q/CYFALH(POgEl<1ZV+9*HMvhB#d}ZE)I+9BpCd9}e9.evkBa!IPbIs-52ksDoWBo{7e3g@rZMKnWYl44wZPxER#05pONh5AC(1slTA#k[U-rbcvlUF[jf<<=Gt%MtL>Fk1Hket&5KR*(WGcfP44{/SQhJ(tH^TN(AMul%19C#p4-#CA&Yjv>XVyPCI@8Y[y-.E)Jwc<ZQ9k1jAL!!>L%DU@^<m-MIx8w)7AyA4+YP9{b4ny!:C@V*Fdwtc:7qJY9&gRR0AymEB/B#q0Uv>k6{fHYO%dySj]E[XW)Gp{D)Z))Mn^z70&R/g$D2^Y&O{X)T)S=U5#%mTl7I5}N}{PidQ*tkHM::Bm/#8Uu<UCrRv^2)wRdBP=Oc(>z(hRz:Q9/zd=6aI^3t:3?K5)>+%0k]fGy.Mn}}qDK6z5xPz)E*o<9tiF..A&mY-gKMM8J=]?KgCC):-mv$W0AQU4g&C(7fhNQ^a7+-G#@VIdck?UsmE!#wYJ^Y<Sl=:eu[P6rB1z^s:aJ{PBkmfBB}7VT0QW.#YWRFZ2K5r8<npJ#1kzx51!r!i0>E@!ew7l<qP!wS+=l}&06i2=1B5Fl#2>Q[<YmkxP%bZ=RKrpeWsaBe8kdyJFvps9Ei%2kJLDz4byt7)17rzL#T-F06jz:r^]%EWp(HMpzE&^[v9Aq+gsthTRlgj%0*t6f5:#kdYBl2my
-)lB*z>HSR+UF#gS.8*!V9vomUK.s67k}El+52Gnf4TPYIwxTj3XIa/.Ulx8Ma=YJq9T?DrLwhzFV54+nTC1O=I==T!9j?6HxuKAnCr746F]9N&yE6vOoM[e^3V8fB)GfWQ7Q=$A/x^D4ulb!QlOR@wXt9KMG&Dl7qQ7lUitFJVu8u6P7mn9G4/h+%]q(a.s*^T<WT7$pTl9M)HoLB*DfRD1b^H$pURJ?D+hi<bU4DI3QRekmbw$/66+%*j-P3dFVau[3LLG6?f>s!IsGl0S*Lf5<Mmz(a3s<9NnO1*%ocN2Ka7NYK*fLHIoeNo?m5514wTov8B5OH6S{OOvw#UdLr4Bp*^jA*Q<qxf@1u)26*8JY<z?[J8:Ulql4q+jf4Kp3}Ono6oh3d/gSz4Jmif[q8-{HW<8aKq7B5XB3^Sh9qZq&G57x53L!9N1Zqr2>%<[dh4Dk@Q$Lz{i}qJhFe={l=*g5fUKt]+qMRj5K{jK@:]{bXB^5m{&DUYAOBlOE-PQl.pcV88OvMtRM.ey^.$eMhh}o6+y0qJ{=7P-iF?wY<u<=g0mA:/6zkUAf&RZoHT>&-Jja&-Ji8<t0#0iw-6U-JkG+G$4@yUG(qS&-EXPh<QDTZ6A4zM@m9J/!%Xr!0wA+SF-F:thtGx?85FoW4V@1V&R!hY{&-Pv=%21RXL9[yoYI7L-SMdTSj=}^eyAXA:y+bp#9%nNwqZAW&jq70{IO9:H<aw@jEYIX5P+l<.hjuGXC5qZe#$ET<:>.QQ>aRwX3w+o#Z@JWuF2u<b!4vyNQUJTiiMRt2hN+m+Dz$D01#9T&u@=53sCg!rx=sdv5.Fk3Fl8BY=htatovOYVCzwM#upmG>njW2rSE]Rl[%YMd)8fgRv+e+qLn2I^)6KP/XP*j{eNs&<Jkm2DI!a)Sb)?(#4I]*H-g7(]>H9W-#XQ[ezAHbwGQeleGSePP{EtgE#W:d/gv[woNC&f/jptC8mE=vb3MtmY>+ruB={fmQL?d
-4CG}HA^xXzw)?.rFl2:N&{vrLXUju1A^1Llzv(&!N+#=-h$)9cY]xIa{zO[/v:i)P}&eqN@(55?v<GafsJ*6?ZGrO?ZGywd2XZYz[[(Y[aNu^AyUfCss(J->wmhGI^E<(={?kri1t8q>iWq)RTLFGyVPLF+Z$5P(4@rf=/XN?1u-W*:vb18-sw5?I>45U5>7h[q4@yLJ0)H*K4@t3OCR/!j-?nTnRfL]6l9gy19[i7Z!B[?3WyhBvGw4TLyi9nNWn<$*b0+K84@cJ.VlQN2g<5byjv?SgWGuwgpq4ynb?gzwa-IehViix5e<l-p&pujrM+OvSMJ!^HL3Q#/Z#RoqJ@>J:4fhQ-2@4aI<o29J1XZL4)poQBk])=4.QwVn2xkTSi>$KW!x#5ckL8.L{D[L+LfdcSN.+[mnycT7%jK[6D?BNLeT@[k:jH5ZMS)t!d]zTqc6oIvG$<$kV.OU-DlzK8y@p*uUlVmj2>Ku{nT)O$%5sI=pn:cK!=h3Fy}?6(?=iUPdI3tUU:9ZB@PA9+gbd0]>VS7AEindCJK&FJDH}X#*fBMD<3x^LWi0?zp$(nGhDMRW@PwA4*Czg.UnC=$TPLJD!Mq!5DA%$aRFQ[X-dl/I5^Ad&FDt7?FZ:8jE%]C]-(ksL&8Z/$<7s$M*7yG5nX11P{}bTq7.rXBc?b@{K))v2va@[t6t{bycv3L0/52XiT&E*</pAO:WPs8DN%*-B)n2270d]wn/qoH&+$V0Yr=2NV][P@=/zoM}8B&na88g-?5Bspe<Y:U#sBh@6WDP@Ww)>KUa/IwI<$=-heYIjm}2mxvqMvHH?^5v6]!s$mspX]<495owQk{nS&:DVLFu=Vp!]e.$OsNv8LC-6[VYNvjj)Ktrg!5:KBD0@)AJNo?9[LZyN[*G!ML93suHI(JDGi-A+)r$KBp^RFj*n^X1NUp]aL?^{Xadn+6spWm?HI:wFnUKZi?A[}{%/qM9cI7<7=-L+<[B{e/.$]3CLe=5
-16VpLj*mTpveyg=ctZquvb0I*zeH&shS%v2yTf3*y+H/]vcXY+-SrH:-x[zR]nJ78+gF54MN}@#)Im7G<00q1{M.6RfiG/I3c[SHlV{>14@}c8hqF]*?1pn9SFFW!6=:YU[{=QVgNEI#/qv^LJbt]Mje^7u@+UVI3N]BEm9#uAs]&XQ}>i}jms+rFL7rFxlM(yIR0*Si?Yh^]?7:aFl{C/05o%344u$5LZ8/]!]lb6aQcOfwM3<W/wq=49V<p[gV)ZeEV)V:)87#tKlE7HvHHD8S1&DGO3wG!K+8F@$c(v!6d(O%1Qf]?GTLxf*I?njPgxoryke[in8=3Dc]cTJy8Si-rHRxSk^XpaZ/%j5.wxo3[Z}^=41=}PkOGG!18=9d@.6n}dDt=PiD]?bkFpFuO9?s6spy<of2-J=oqD<<VZ=TQ(BltyTkoop@s}]i0jzD0N8IbnTc#j-)s*g4&t!FMmKu0LQyyg77js**D[Wy7SILaf0Ie!)BZ/K?4e$qn}>-c}A64ktdqvab$S-@8*DniCm9e)EW3ZPelu[]V%=j9BexmugkKh{s4[#IN$62WZHZH=FIcgg%dTiWzUs}>U0kB!{?3CF8)BoMg<fl]A=sTmhNsVn{hT6yksZ*-h4YAWG:8Ris]xUIF2@&rK*-IQmNtUF-Gd&PRS-pH@SaWq0k{7e3&N=t!Xo!?<7Ff0a{1v6v[XHgl$LEO76N<2m/wuBvu[b9)I.^(Mr4Gqew/7<X3<L0$%-L<qF81k@i}K-tKc:?=hycfX$r}UjcDtGwMkA09-q#Jtv?0hS?DFvGU9R/K]Ed!+RjijtZ[2VvG58A/D}9hxzF8E(Bb%DFODOmTQl?UX=j<z>&])mqAC2J??Iefu()N0rT>@Jfr?$s#G!oZFj=Z@R>fqE>PS{.D.U5kAsY].#E}WNZP-J%khJRg[h)]ppf}YL5s<[[pChub}()@.)Q0NaTxK:-<)T8D{w]^YmcVnS}BpXw@u](%zueC3s]
->uc)ew52Jx[v{U}aaPyF/e+1Hi+$cJrLJ:JE#9lOF1{r7LB)EQ{ysvJ^G:FckmDvn^0{&E]iI1:vhYytk]}6LYtRVqE&)YZh{9j}@#AJ+jK4^^v2Os&EHaRzg*Vi?Om3jj5OeD4>slYUk0IK7CqPqT^SM8+[IfrK4cZNe@?)0THX*N3<Ll90DYm*(gXx!H^d)/)D4LsQwLObC6OllPywfyd)JmQ[67No%>l-))^Dx7Bf!zW[EKwL6Bf+F7swwXQ.#/-DVup4oKCvXzPUsDwlW+K]}oIARY[sF09$c9df6F&>s!2q%qRtV!v2<1DY=wIp]zz=w}{!*rjxI?KWwfK3iR6n+e@fXS&gXUI+vbrTGLSW8cL%=7@K6Boe5WM+=0{=ru@B9fhwy}lu6BKMhDB210!6:rX@jJ5y-9SUytK9Oq-?$5VMLF+cC9Sy1?46iClcx/3!(#iyJ!<RJ@Dj(rbB5Cd$?gczXM83HE<vYQ&O1-eXsSQ3Oj0j{8JY/Tl9M)Ho+NGlV8H$CVKj#w7yewe<YoVhSNJWh&zg/J8={0ucP@fyw*fO{LtqWx*]s)D9IP$*BlvZyW>Ve:.G{fg]]d%5)quJnAX$[}:!$k/v@S4:I$/@rl)[t4zMB8rS59@-@s!S:wRX$m3^a4:N(9#x)S>7ey^2r5]?:nPcY@5Dh@j>zJEn)r1yh0:#}2$el9+ZTx-FcVS8Ntxn<Rq{.UK(z4sJx>*KQe1pSyanQH$frnqm%l:1tGMA:ylazm1.BCC--SyC>*CO&GVS6l$Ma7.BU*?tE[v&#>K@<yu(QV2&1^QLSdV7Z!Hln{kgc61/uK9Lv{>aTQ9E>oWv-iAnPfFwB9%6$M#sHusjAM(r*/D7EmS8[)XzzGj$!>619wVI5(NU[&-Y3[Q5A[n1OR6+Ifk(E+V+9pe7zUK$qwyJYasi7[qu=MX:atvr8>3Z%GY<uvOx4wq:-]Ka^hs[A>{K-j>}mG9noqRE7qKbb[aLE3{VCd12
-WG#G+qQ/K&<xkD>RbPy#r$]JntMqbDu(ws}@a^*G6{d:KyB+ZoKLudcdH%N8?o6fb2I<)gaM]211>uusL$tcYD-F(sdV^%YIoKY#+>jR+D3-i$sb%dZl8Yh-)U/SP/GcdTi&Xa7qi(1h!muiIp=?*HjpX{}V.<dEV+0k<T[ujuv$Z%UKCAIWIcC%52{jT=}sOzK(X?PsB#e#h8t8C7%anUOoK)VyJlzauh)m?IVsom8O}^kxtj6$+z42+HuB!P$O5HDcV4Q9yOL8esF.%{Rtqh7&!Mj**l*jVTYa}+>O5F7b}V}2)hvD>ezWpDzS12p5C]Gnar=lNmaLw=O)1VBt/$<r0^d0sPc6xaaWIt](s%WsFOH(w3BL(q.kcvgQ@Y<lE-[}h-L][xrP&F-C0ZwRyUyL5BRfmH^fv{6vuCA+%z%QiAJLi}m]CqoUY3b9Vp4{r((kH%b@(55M2MI+D!b9Rwj0ALqMDDYfk?pB&cBFudMl>%0kNbkb7j</u>*ICmp0-@&*8jS7v^K%eO-LB82EUYaDsijO.1FL2@:EF51ktf[-AB+bhyC?-4@o+*FBdLF.1F9+E$Xco9[T9LP#.6Io9Sz:o:0OZkWR1pe{OF3sd*R<l.H>n@>uHbs}tvhSp=/w+%lc(2Vbq-sV&T10?.C06&l#+q+bf+P82Tqj/F4UDqAcJGKoU{HtWS+2EUYaRyeRDo:4WvGN8hL*ORg:F-DBOC]G8FJ}U{w*R>k^RzRNZkoLn4u%t<*s@EExI{t!8NL9t4qu-l*9*}OY3+d.]Wrlf?tPf*$.oW:b4>Z2PjBs[QUlY8Yu#^QU6/t$+j{}lpfJ)fhfGC:9v&d((FzTtw<u?G]1%$eo0zCadu0]AD6!(68SIs$oF98y9)$Y4)mDHZl=ev6SQ?phj41?pgmVWZR85N0DwjKX>{O3gTDro6]Dws+4f8<cd=YAC:(P}PSU)*@yXF1(aksW?6*9vJ-uOOG@tl^#]QP:W2ElmOWT#:-X
-z41&v1}oaDfKS6f44cD1{/8hh(Xx=KHIo?AfL#7v!3:)ox-%x:FXC[&Xt*>lRxs^=MJ7wQTN-10CE]YG:dpQ8@n$.!tv*:HXm58dlYu{!<FK1Lv<LL)HK4j7(LYygLhfL#@R.g.zmy!tE>9[sXlN/3nH[L(-=&4h)IA:WE*PSADMV*mjofAw-}Nuob/c^OB&pPMbE1nmDMSRM9!VTa{H1RI@1eB15t5&w2-E*U4-[/<hoX2%==gvHKCn:IK$BO>4[1T{/MuY>8Xo?*9sOFZrg&c5JFvKg(x&R0}6+)s&Mq=4v{11!c{o5A9.&2jin[C^-:-$GKhcfd69119CL4wk<fs%Nm=n^l+a)rwQx})2GyaC!7zS:ZJ/t&1TYP-VU^1vSfL{T)&uldH6x2{]{%%J{PVXf{2i=)6tH0>9<DB2}/+&-.2=2V*y=&UDg6=DK4/8U2jSY/v<AzU:)4-LwfppTa*ZAosc+$}2JF(&*n?.RAFBx(WCWSk!QihM.kZ0zep4.aQWVv}[W+srScnihur%)ema94>rfAH^2JK.wX:d<lLlk*8LYJ=5*!t4YcM.JpMBi/w@s.cb{gya{MGmj$=xoyK4[llf<VM!)^gaV=p*Cp4T@6YheKP-<6lsl5Wz+?dTCb)1M@-63ZWQGuwTV$)(Z4QW@:I4B3HURb7P!u^930Hp6![Cu0sa}=vG)jKi+CpmRfN*OA6Xk0W8gqD1<fgy*%5G=%?RWqXe@ZpAYwSjypUfLE&m$pPC8}RuaOJ?y!EYtP!yQ-/5zMjqL&H{IWUJGRvXpsB}/-R-}+CvbSx//5nG-f)Sr]*Wu$g{c++.PHMLXSqfGu!1U4qqH&jcR=c]!@N0DtWW}CziV1]Lk0:5*c3gc01x4SD*S(4m%a/vB>An1oQ{X%65cEybj(LlgHaqQhvSduf5cUA}S:@cyGidL1C).1Q{A)<Sjp=PKF^{}kFU@fF3<FNt2[kefn/kegz^1(pHM[AHLQ(kO}h=vGuU
-(a48DY#CY)V)JQx4$TTprk87}F:Oyb>W5VwGykRxM+6j23J?S)BV:^)Aif1TiQlfZf-<Fo+Kl:t6-Opm)+k:PYq{IJ@ns3P8TbE/}f/l6QD<5YaUJU)n+a}^/&U!*8ICp3JFil32!7@ITme:^.UY3Lh?QRillHTQwdls()>/W^}V%nx7}@iv2P[(nSe#Vv.FoJJ:rnd7KC14VeHm1<O{ZsObFVa508C=U[Lxqy-@Tg%kEu1hHSL<k[l}zm2P(-<?AZ<P*.StJgNdeXF<x9I09^c^ecK{B]ZwdU>Pgrq?6jg16RRwz5j^W8k&a=oG+O7!@zSHU48i1-:3>I%3Qam+:@gKc58hxR3?7AlZ&yJE@uIvQ1(UK2zV!@rS($l.S<[dB!$gu?F2rtF6+Er0?5jN/FokB!)HjSK/-.0Sy%G<lPgy8Le}JHL1J6sB=?[LBKh>=y}7hZHeHG$4E=]-$fU-8qp=n>GuJMz6d$EmO0]UB@?:5x$6Tx*Fz^ytk)lm:.]2i2bjiyJ0C/1+3s>PROS}#>4fm.&XwF}Vhuk/C8&><5@wKN^iYBsgH9OOToDL$L80Ls5OOk1ft^=eA1Ws}73F?DH^uKsGQnsg#=aUTq1w>kR4tmPyzvyH^Npj?(3UXOz0N12s6q12ufJ5z2J=RjOE>uM*(J?O)>Y(?K3Cf=sg4Q^Rscaqung0=1w21{13zy#@j]tFe#L5GP5XCsXj=5@]Rt*{eMvW}B3z=@i$<[C.Iu5nvJ7ww#Ie/nEpeF@w3Vs8(ns$[-*]Zg.G9#lEo{iND(7ryv@>(8DVWz4?0^wCH?zD{Ww>=xJ}?eRczAAJ&u/x3cQG81pDrl5aQc/bUDUElOpk1n/#))S!3wCnUb@zpP6@QFxa!OgMVe6&n$3G%c7sY(TmDMVmG{1quZWIveYi49bEW{$o?MDYDs0mA>)w-7GX/-Hw6tjsS*s]^Vr7c>kg&@$KH(+0io23X.u8LgEuwhhfc*@H-GPI-!pA?!*H
-Fw@.T+8Gc302*RP1dOufGivEC1uaqUw4]wbcwE6EbW7gxcr)[Uglx=?PFjEsA-jUdoOyXpSMyQ{0)1F+9xRZ[ff]M.ayFg*q^6-8=YzVV2l>LU17/}nm]dh91^44wW/z>J}fFF?Cgw>jd3Ray=$}tQcYW2NbKSDx1aUCEWX:9]7H^zQg0jtl]E8o%TBDe4kBZwFkj@?G76>q!-E7#7v=m4:/hy3GKPsO#HaY?1oeaxGvOCR+AbMbSb]z&PI^%PF)@ETZM[fF%+oWeie8+WQ}7oU2v1d=eW#7}^<(hr<S$qm<G%6WXax{6y&?jmbW&1gwa<h7.i(}eY)5#Jbl?!SEL<In#WsLFN]eh0(6s+i=nP#y}AVB:DQ}oGQinZj0}Y]E+q[aD^wPtsxx8i>rzn]X-]D)+qy>q2r826Ilel*u^oGW}V/7?anVS>w!M!@c}CAM!R7OxD7X8S{7p}pjoCdUop(czUk/rLB1E$zInO:6T?9Q5K+5^mjwxu<n[]yiln9X@dQM7!fQ0xCUx@q#UC>i]tD)m6lZqt<&&uaJ#k@%ul^@@}SNJ/S2i>s+suc?s*6D$@V7rLIRdTa7U@hserNB.u9m2LIFT@64BX8RJQqEo15XU^YXUDJqV9])EoX{s1e#J>Sb$(Ht7I>@SP}Z6Rvah/z1kKnw9T/N5LG@!pir?A/lV-M9cAja}nhk}qQsy-PnE&OP:*Y(/Z@lI69p}N}WID(8K#vwI^9t<%}GW$!}Ud/fkp{eE7-qi8mEB74[abARIBx*93QEFsAxZJVxD@<y3wSM(Q8+J<i[={70v<r[S&{t>x++6/j:V?dav4T!Yuw6>my}UJm(-htB!k7+%Wpf@N$(/ls-u{9*z}j)C]2w4nok0K)J.20!D:A(in}gv-qyWjQlX(27Iv5KIbXSE(=EWZq4S]2?wPDr%s!*(GZ+fx{@Vb3u7(pW%nrl/]aY86hm<[VfOfv*5E[tyzymS)9lu-<fg0dB?2*wO(<^c^J8
-uO)/E[?l@/tF{O*D*mk?U%rj1yx<ULKO#vK/-cmGKBGN8e@x&6U=RH(/vO]o1Wou[7VxXIULlzNXhUm.JN%&P3$To[E1xDts1G.aCr{cT-/Js*hC&7B-!9x)cG}AHb{chi=!vYFsB.LoRV58#Y{fhngp*m+-rb#EZ<W/MK=PI.3?aQ?Keo7=NcZoyL<XCGI3r-!=%oseZzddOMCI-[/Q-Qn3Q:gOwCsIOv7Na@7N0Q1UW4QFw#wFXe^SL[yc%ZaLD*9lkisM{xPY=0mlUr+x<zZ{kdpdJQH$WBAOeq&a[>B/O{-.p/#D.%J<]LK/yWtE/V4/hEybjI}/[k@{T5=zCJ>Yj2EP5Jb^JLXc6shNe75g]9G[Gk{cVzA!K9yM-ZX/i)SDp2v<vmo@28[rh({LVP)=u<m0t<Pr>)un-.MHpW^K*GpoG<&!+o)PUSrkHDVFGlZ2EX^zDKC%LJ)Lg!mo:do3>vrQ=(A!C#0J6PHf>:X<BH^C2z[]7lgv4V79N$<3QcETlZycD3LjJy.HneB(2%F<[HcieC3?nL.goAU]B%AJob31GJoCti5j#761ggFB2V^^8oZBGVVoL3NZ0?.)UALGh+{7+oflRc+1W$h-=ro]J2!t&9$[%>pO%zr<%#6$*x3UyeQH+:G&OjM:^aVE^&-qyL3+Qnv(UO3:0n%0lIh=)Eu.1^AX/z}/.f(0>Wi79fAV=u8]x/}Dr8DsUC>kiYFo%l+q[NHnUaEC&@re#UDso}VY0B(*yF55Np&jgw@yeA>e3:V>Q1$ptzfnTB*waR+2)xJ.2&A@qJ(ld2(P4#O*z[3NQmJB]jOzP1:#CkGNfcQ64krTwG3[3M:W[+-#3FDNacz!oDmMM9aMpTXnqldCo#)aoQp(H=drX11]LIEZPhucM%Un1T}wYRT}]5Wj{G]:sGWo)T*?y4uM(]e(3yBrVd]uj0fPFP/$zGSjB%^u>^&X2BV>qv!rw[AlET:0O4MQ!IGyZ./#bF?Evvs$
-z[4Ba]:CxzNTLP&tqhu]@s(wIJa)UT<9?w}wN=Yo[TKgwAxP]H+SoTZ]ttaUi=pcHMHO@^wik1d)bb^:Mw]E.yUpa@Wkzgmq:0?F5a)[<5?]TF+Y%lWD{048k@ImNw4rL0?PCEm][zL]3I(6#<dxrJj/DL@SS1LrzUnF>Dm$a+emi$OYu6ew[#qDcg8.XnSSXoGuC!@?B6/H0sNH$xEZjfF($Kf?yhZ]A(R#+2AsC$a11r:BuftCQYmA}xXab#rk[YQaX1$Cy{:tv8l:g=@y}P{#Z{YhEg*t.Uq^mmZY]h7tvjI0))7Vi/:OR7<@Q{o1Kld$8CM=T$a)$*gKuM0k&uHEq?XBgv9!*a!+WWPb<@9^-g30.=NVw0y?UWJJ<Ch8xa{4rddB[kc2]HRbPNSG]+[G7NuI0+q+6/1WV?gf/y=R)U<iW*+->Z:OcNSS{D&XIq((lOSdPG@pY]IVQ.V9}ZJhMuVkkot0%hEqb]R.kOy[P2$/4f1H!$8WntRH]/>JrB#SqkRej>1.E4&jK{v=$#ms7e&G{2Mu+(:OPx{Dh[tf9EhUk3kHgu[[cHt}Mom/B[3jlm?f*xP1N/{.vo1JaDJn]bz6JT:XJ6ZA}NAikSznE&32p/?@4zZANFKcL/TGY(h?jZkqgmZtqJyQ7{!)1jg?zVnM77nm!7tDL#BV(G#LcVs?6AdYWywp6]!pSH?*v7io@QTI6>ed*2BNyBUaMIu)uB?6ZweSS2xB.3UfkRTyJ/dtYRr64*qQC1]z#)T9YVq4zz[<wQnvt-chWKF^m]-jYQwBp3SqJH.d:v0v.U->]j]DdKkdijs$6C2xuQynjPJFHbdAI*l@O:J^v*59nt=4R)mLDno3}s<jEZN3TGB>sSLXWgZHYWHdf0/#vH72^@hPnIp&TEo.Akrk3=R5Mw$OID@O+JfN5]>lwgA{s5+DFG9XxOgHt+^G/Co{7rF(xB>Gxf0J8.(?$gTOIA=E-b2&2bES+o/.OmXH$%km
-0Lqny8l[TN:CxH9Cs69.BH<.!nO2:zI2lX:N5LAUNI0dxC#kUBf^x-N:4<<m!+llYgH*6.LD+Se.n?gY0}MW^kp172pwiTzkaWjG&YY:=l]/{p86.3NA+rl-h+$zr{aTH3k6d0cEtjBV+7%=x&ttkYANNFJ)<JgN031G]2%IIc^J.czqg{#7hCt8nw/&Jj:df{)3uR2fw5Mw{EI:W{pzsU4VC[8EH3Ybs7E2T(5xG9PeV>kxNg*(O-3TCYK^X$s{3Tc+k9&IFOi)gP}n#*[HB2><Crf*Jn><FbB=z%0K6MwA3tQA%{&{^2g0HGOH%lOb5Z}-XNCUN4<1SMP@aZXOXC9%@K@bt30Riq.F3y$5o.m.*Y!yLx)iwFinB6J/JU].}N2w66ehhZ86LhqGvw0+4X!]E*?Y*Oq9Vxa>-j+)P+JvhiU-()gf@x*lrwcUIf@!?PigxwOr^+saEq4dG@McBweK(r^Y.]R3g]ZoKO.moU*QtZRNK1F+U)+]^>K*?d2?@V]*PNQJ0?Rf[i422yC1yNqFY[S6b.q?pLe8odaIGB)z<$vi*mGTLEKtCjn-G5Yo)n#242?wv0(]f9:8gr2oBHGjxqCulF:Sp{3.UaQ2Z2/o0>JpTF:LY){OG$MroEF%z/A%DOUsvLYTGeRb^ty30IFmJRmb?e@V?I]L7k+*PY@3[={%*a66H{B{AUsT5}b{dMzwX!k:AVp*Qid7C9k9$SR[8[ej?@}PQU.u1K^e)FM8R@pg/oXk8:JC1mj[O0RO4x@1S]I/NjQ#]Aav^QhJw/xj9pDl)hsk]FIo&d]qq{a-MoKF8qJh:b5tTZ<MG1a41Uop&roRLYFciGMr9y?vN*)RdH1{@-7<]5*6C2+%A*a}8Q8xkS+Sq:bJokm1.%>D[bs9fc@(dx+EVEP9h}[FjaX-*vbtU85LB]67AHEzkl!0htW8CQ<[3?JCeDOjem*}ZS!1#Sk5E92*3%O?d>ZzKT#OZP.npqEin?rnwUQ{
-[pq>I8+w%E5/<K5mObg[GZ7#fLdjwpaC(%gnto566T&Hk(BtqY%7DB?]7LEU}arb^wTXf*ZX]zQWTR?Cj3s.?<0*D.Vw$TEP!r/-tWAn#*:XetAXEuY?n[Ioi$V(6N#CdbP2?*=/cd9hw!3p5Na?pcZzjX91[t!.D@bW@zuYnJ^ow]xcaf&$/+br8b3TsNr3CK]p(h6S6^KK-uIiRa0P1St[sFj?Y):w^rn+Dsv7Xr0-US[O@?TM2ncHW^:yMG2MN%73P(@3$FM{(?qg+B$LhS(X{:)v@[0n]qSKTs)WQp^Jul(H*.fuW<qcL-icg@&bCd.2d7k@B^O5#I9G%ma:3X&JASbh{Zlod+AfN*#a]8w-6+554p(ivp?
+abZy8000000@}Rn2B[^K{w.WX4mH)aILt]:Xt$<lweQ*w:ss^4UZY:Mdz<lGd0xPO6>cstd.Oywrx@@r6B2aq(%.R-){ej+{%jlYP1i:FCl->hrlBs.B6.R8]uy22A>/ZRnE=EeUJ^1Q=zZPqMtHLT^@CZZ!:Stftw[sZT5=oA*1AGCTYsXVh@eDAT/VJNT[sMVyf/-[!/d&5Y@+tqWS=t<XtDV5!J7AR*<uk*{lf]R@bmwid$J)8dTK:qZWAc{A<j]$^XY[.deS99!+y7!>g({aY+1l{%fDUyxtsw<@}Sg(}5#VRRmj=m}6!c@IXYH.7b@.mTXB.6?Gm^{X8luc{73b=zf]iZMtG<!tQcj}}>=4#):^(Y?f-B0umHUQq*d>KT[d?ALDNo}[B6ui?L)*Uc&.Tvkzm7yLKNgm0C52$WIJGKLwCfU4Kb48:JxVZaij{t/$JK*o%TZue1=&4fjf6u&a^TNUDl{GbB9?KqB(o>R9u{^Y{wAd0@X@[}3]!gh2>n[?^4m]no)6$U]@z#!y9=uV&L>hqR}u:ZzzO:@T!d#[p&57EMqxejsp8Hm1j](}f#g9@pfOuQJMsKzg4vlEiaG)tt1)F)<ZyGkyrW-)tZ(%q6}N24:nWHL$fV1)0d3d8rv]Wkb2p1[$iG.(IpguJqQD!jQ.4iP:W*#eiD&D[B)cUG?CpMi[<MQ3H^ei{EwueBt*&-piCF$Y>-0&L&W%9O<f0e7oSuDsVu!2Vt:WMBQM!!87/8fwJS7ZyDW<jm{dA>px4c)G=x95WXi3lmCw66/kK9X9f6D7R@:mCAt[1&S]i=1SL0OQ^LSf*FapXJ68FoKMg-lq}a)/mlI)O3uCX6C%h/4MkI0p1Ne-YzqUw#A/bZ!O3&ZQ@ZbOuXvZH[:%8<4ZzPAuZBrd2EqGYx=S{I<wT<fnB!J{-!5OHt?qmY}}T#25*t]cb1mD}xc=Ec<gBHrM6CF)T}9*A/qRr-%$ov]9I!UNx35OHw8p}A$Y
+u0>L22)e!APq0BYlh#za?$]l8F7gg2=4b-r.2hH?}s$oOYz*o(Goomn=DAzr)lys/E{VQYNbsUjbf%6XiY7eI:u#8l2:GVLMam/#C@T^(v*1FD6uiOW^d*GlPv7B>M1<%-h].Jn}Vx]H{HA.PjpyXlDMRk#)(oyemR9CFiHspUL%%ZX>6[.Kee7RjwrZTBM1pq/wIPJZd=NL!pEwotq^i04-=L$s8aZ*rgXi&qc#H@mqrc^Yptp4DZfrXBGwH[C?1cUTdN0L8]Xpw1aPF+VC]9pMYES>6<JO(%4)Y0x]ERzr8VKjAx9[h[nCh-?ES{ZG9V@y7DktIx9*X7N7XfhNVrk{D0^T+x=L<(<l-%}/3DKoxZu7QEf#$qg}bNG<8S+mYV</@J<MW&#[<VN-TeDH+ESV1v!XM1c=/ko}phNPUX?}UB}jx2-zxl(}AzE-M*Khl0tF09eY?.OYj+JW/Cf-I2ztb3ntJqv4%gwtW=R@VjH{?U+*u{n4YbdElhxZr0)QW(aWCru{BkawniK^*W^dY2^DMvcAYeLmqJBV$4EQx1YDKBobj3AQ7rq5lhJQZsPX-OwD[&c90htI.BE$M*Z>uVfPGdY*%9]{4*+JebQ@0*3{BeX%LxUl4LBlBN2]-rkljN=WIE!9Y+@YCpz*/2Soae.d:-52u@@0pQXR$jyX3==$(Pg3kZme$W<yU@Ff}fF&vH430lYO:(93cGZ#B?ry$ksLz0<f#%DPp{U#[MTB3)F0kKZGok#<Bs(D)Ivp(r%TpA}O0f9RUIOs]dv}fHJilILQ):E1iQg63G5?!z*rOw*I.uJfGk&FCgQwR0}6j-YzXeuUKPx*FbUkoZTa%A8q44Yy>na%0v8#(pVI5=Vrb#R(3920)h-YRu]9=ARgJUa^0cGmBpbf<hv/6)GK}Adv86I0C]UE3Ab)084HwN*JK9-611e1Lw6GNQyx}<sc#!+Nr4?/hOZ>{1FS]&9Do3VzmDqONE?1]XL+&WP7Dq[b
+cj5@0U!UnGVK?6FA^lK4(]y7itP7<VY<42[niAlUj=T[W1X@$hJT?NiM0p$j0-}4zzXV/j2WyL)Tdfb.IV0=f}(%/J9Y}MuEYxk7LAN16XpdLt-?(a17G@i2o-Fq@kr:5ZWA-(Mif-zL1p^7q)U5xvKk>5?jUZ[)x5*se3:xx1[%Aa5A6g9pXCPJ1=as^2O!OZ99S/2}S$-8m-(jF*XBKWg&@s${<]nlR=gOFyq0l6CpnLX4[S&YgR>JExQ3CWhrC)k}n%62>krS$a@/c@u]Lsw%[&{+L?>c&iOqDcF73-$x>@j5r{2P}tI)IqF]{BSYJLJWih>yQviSEaB3y[*s(F[p-LK(A=z0Fi82q(Ph>q@xV&n!bFWwTM(j$DUi8*dlhBX&r=p)pgqf4AldS!kRGI)CEypI3^(i45V=U7D}fLxd+m8-SlPS16ZIptQJvHEM&U(WmPs>zYNBuYBnf=o>[oNq!R#J)/)guI6P1:^HRHpJ-J77VZ7mQolalF4MuK3*z>O5P?-QZVH4BmSxjHU1P:T3f7Ou/BlW8j>H:2N]qMKD/jEo8#)W*:*)x:Re*wHkK}49leK]9zz1A#%dd)s3tORASG3{hAb<EGs5Jh5JJSXVOzgLx&n&Xkr%IGc3U[Z/FS>dtzV{K<M&v@k}QLk?CgN>XOviN#<uxG^HwWVfL*-}DD8&7gy1>]vBj}RPO1JC4ZMdxS={]YS?cSSYVDj.}g5pWYMhqVsYnVPC{m(DxhsYXka6/*0R-JMp)IU2a?vNiWH%{SV&gtU.Wa{c#X+if1fd<K7@BRcIl+q>3zT8@4R)ta)XBIHAj2FtZN/=:ax80@$*+17cuO?RCg/0@DT<(/t{IG.#*KTX<{4@]-!1o7$[Jh<24-n3y&l(w%F9hsXlVEs4a86CnH%$m3DWJrW[.IM)E#R=$2+k!{VMl4i8LrJ0(4D.C?8E%*Z*Pg0AIl4-<ub5p4v&%0tSoKD}0N%I9(c#m{0TNVT{Zi%Y[<(8
+Q&A8L&nOMT>Te{^AImse<ub6!3.i:cH5MUL/n)}90FcB<-#P%%v2Ny5Lqyl1oZ8]n@q[wfDBYycmDof:E$<X7HetL(l+J]9DkPGJgk$HfmbW7>iyTjQJlDoi&KIz<-#MfWX-B+zi9&qo<bKNNY]jj%DTCZVJS)E6)e9:F<4{Moxuc+?gm#k@=PK9QM!+skE)S8:55F2{L&s1]4V/T^jE)g@hEK?:4F>z.RPDA%CRIi$}r#p8!}kVO1G>C)0*zVw2cHStRX*C9^A8=ub*o$N.%EVLpny$iGEIZ)&(geHfX88/i*Z$#f#epp+Z^tj-UT&+b(62u(WI:Z%6M?$A{zrc9}G1}CpQLtD/nh[8d%bQoZLYO*VZ.g2D?t0FZnsS0-}puq&B[}S7(U[Vrz]yh/NAU0>g8LO!<xMtCmLD<{8x=&$QKmZ=l%=p4?8]Y*3F*WsAPd(0s]X(@}=XAsh(7=2C&[iH8ollatcz6Z}9U>k<U}Xcli]vV1xCio@Hl25-qoZA5!F@y*#}o9d5!6o}DP^/f^sCle+A}YtvvXvrRnZW{F1)&*R5Or/q$9&uCok#^2B>*D%CYTRI!W?%bYYX?Z]ZR$D1=*68XOo#ND<-u9&]UNk0jV%B/[[*^yEO5<9+l7?WJABV&XjlT5k0I!+j@gW8v4Ag&82c:qTtOWu+d/ct<[Y$)MK@PcZ)jkb&bS7e&l7f4SM[H{d&mIJ@qh5W=<O-bC#>lNoPb3SaZ}IWt:VufLJ9X{t:+HH!WWOSwM>5.zZ0WSPp>}.RG<@%VKT:Lc*s}grn.N+Ct?^hFPQk8pB#zD]@mRn+LJNY!&JUAjSR4M[Q9T:=P(}2(y}VW-=A$5j^ZQb:SB2Qw$-Kz&N%!]Ri^CBo67a>@$t%nm9/CI2#(A22cLQWqpXrr[6qg9iP)c[cQEF$>OWaao/weRkoz(-6qfXe(3:^{}x[M5xZ0eDDwl+8DR9W:FZV:{DwvWu0NJ$xDqhZJ>OYh5qYPSM9r=hh
+!s7rqz<9Sgf@3)VO7{Xr%g%Sm-o?qs>J8T}me!o>T86A({R(2<zeYpl]@LISn3]=T>G.S)C%5/g?mr}#XW3qgWR:A{>3iCy4{80Rwfm%MR7=nV1gEEqWkx5e)F28-N+%F6mF-5V-C-(]&-L3S}97j<@jh@)No{^co{]wu?xT[(U2lJ3ZPNKO}9Vk&DjGF/PYE..Djk]mp!DjJo.$)*v3KvWiK2bPuY1RJrGUT7(l%<&SZ#vl]E5YUPc64GZBeXUSWu2fSxePseLMYrg^rtXq#hYhEOedM?R7EOgSxecruV^6ox:c=&HlYH]+k^Dh$=kbV:/*k6[0hp=w$GA/tm7fmo!#<<my318}BQBrQ-0&)r7b9rs!gLm4w:ds![m}x+7%w!2c{CI$r=a6WH2Wlsl(D-g7XhRF?0{X^j{^XvRsRY$DFkWYLbPGzz}E-Z/#!S.@T{:jMX5&AU}m3h]&uE7+jjmV8ggwp<SY+5h*z45HMNY7zSuu:QtY.86Xd}{!0yz:*g)Yn+m?Q&:6<=Fy2qb>NCK[M<S00C)fh][#Y&pf/yC55c!4:^{I1c*6:GkKH(q*IayUup7l57KegI<H(Hz/0:<mwV*Ot/oKZH+jG&E]R))B7WggzWz%=R!>f<HC[-T2Zz)2u4AdO/YbJBPuW7OwIr.5N]MMdOB!VFkF=88e}@!90Qij]#]]>9!K)lP<9uz!iD*7ylir7IN*x)Wp5z>fL/:h]2>IKUTz}70oxOS&Qh4MKcAs^yi2J1wr/Lv<1+]/Mr@=Yh+P^T<6ymdIswGFw!Y/KuQYGjl-T8/}S)D82d+H9w%6V&%)UGsWM=TwMiY#9YWDM&t1L(uF4@583]{NiUvGrE&Qh]I=wY/bQTWopyluyxIN<XS@^dc1-XwGD>V{r2G:OxFKwzh.MM5QspA?yC}q{Svr7Xp$p?1-U0nslroL+4%$%e3&T<Zb2.KYFwIONzz{hNfz6Xymhu}oO$HKos4aX0wMmLZa?p=ufJUX
+PHwL497hDIGjndD*XT(iguLAVQ[)Dni-qZHJi>caqcw]e-(>M{{WWSGF?K[fSjKJo>Po%aNZT:J)sO3{mx5t]G1-.?}qQfqfuP-dqr)4xCV05g58gAPWnUAh*m%M10[oa/L3Q-=R0ui&]!ke{TT.3lTsR5$MSw4[RdzrYuICld^Rqc5St$x6B!eoPX}afSufK!3Gzi*93a/]dBU:G[xvs#!7q%v!tmk.!?g^)dCYcaFz(ne<9Kapa[CTkgtk%[ckG>C.D]p#&oh14bpuofHunu-LogE%@<hCOZVZ56*+Ua#zurwjsu-{rg6j8sB0<O7/7T#8tv4q]!Ma:Mo&ha%s7).uA]4V!eLUiU#uZsucxzVJi1>atxe.+t/c&e97(HlALD2@:k>=uk(O$66DYw1LE?4rZcAJnhe^dn{uq+)@hEWOfUXFCg)+3Nr/(pXfE]=?8Rgbzxl[UHx^f8k1>?J#Tqf!Yq%(1dj^:)rOX5md&D^97TuN3Vhxp-5&@i*OhoYox}vE^rAR{p=7H}ox/tM)c+#x}7TYY]XmutpYyJZ=?@Q>ry95Y{#Ft@^.vI^xpKHXI0wTuqh.bbFak3>oG[a-grKk*&*n}*El?e*%dN?M!L(hMS>Q^X1DnBX6f/kZUyO2ptCY?)vL$GYfk6vM&C@=D.[Hlx2?Nv3syO1u8hJYE^p79O.pm8Cd^kM{fFR(o4r07pniVl@&tK#ls*72)kNW46xh0dO^z9n-$Nt1x%kttiDFS->pB<HO{=}0X&!!}uh=ogBCE6ygM.Sj7$7pAd.NEn<8[Gbl:-=jPX5oy?S%.v)@+R]52N$u/<XOpG./BQC<Z9p@%UE{}Mbz5xd:3HTkFsBYGPacW}H3&(#GLYsSS0+9?IRv]O2UfJ=SMVWZ+04&D?Gg}>&9vBNKok*v)O/XDHl0jF8G:[0eiVm9/Wen5v53W7KTW?t>)8&Px%de=2z$@c7n:H5Sl>/d$Ru(uT!<G0!(%k@F/!EfAYIEP3to
+*6@pr@0t:+?w0XXuCSM1](H>XF>}.LVgqo{xrIt.1!Q+$Cq5PZ3$bP=sk$r=)+:nidY6F9o?PWTRk)hL)N0CuyFTi]oZg]R1:{Na.Mik(68A3kE6h.0/-wpmpw1xP}gNfDBoV&fcscD^yLHCMxsd18]yv7yTeM$yWAToM1:yjI/^Lg)}8$&guszM2Do*s2)B@1{Y91@I.ACLJpCpHYuEHm!MmyTuSPSMa?^pQ9cvsV?pSN(a)DHo]B=<9+^:*>v@#!C{JI=7J?fg8J*<6x#U0q=GMt7Y>Bi}ie>r60)i$cAmlWsC=ux9Ny8Q8M61zA{-C[*a+OZTm(=1J4D8hLFGf(doT40O^v@N.?UkeL>BAPknYp#35zi%081-*}{9c<f0i<0GUOce[z?ly#@hfwD*h<6#S]qtT3R@=8Sq[N#AXMmm6I*up5!w4mi>wV16&.>yf6*l&MEa[4$sOcE^CFh{I+>5TM@Or6la9Ub>Jn:=Vy9Ub(q}jaBA.HfU6Cj94>3k:X!Nm.!/z#iQ7L9/gOr$4V<LAr-sJWdV{P5*a6El%y<ziJaBur$/JnAAPtdTdaBMpH%?m:E&DtlerCrbs.svp8Rfr]U<J{HWyJgRE$2<Q]}]l}R8sGFT<F2{Fc13f=1QGTr[ZSy:(-F&X+XoX]/4iTbut2<P[WaGi+JkAqyic?s<Z1A:{h=]uRrY:a:--&?<dB0p:{v:LD@Yk0-oIPwS6)t)U?rzd*{m13IBR1vgBv:3-sS>P6yjP+RKwL5lZT45]Jno8wmN!F@9h8*@{5Pj$tVFEc%/{DP)tODm*fXp#*zzc5]kzD-9G0vauUd>M&4t@5djVzS)Q](Y:B>$HZsX[W6BW:.:f[$Ag!hpxthm/^.fbEcRzp8wX-]V&u713x}y/ZRCbfB}Wl(fdj-p}B*(y7M3GT@IWWhvA^:@6z&e5[MwY6Y{Phk:Nahm)5OOkOR3][T-:Uf0XV^wVwtla0/!vd&urY6uRjn+zN5*ymzs
+SbiMHWSNCX*dDbU7&0o&5nG$nQQDc[T}a/$c4e!4VbA&*hJ^j6(&^UN?vu1f++Jq8jEU6vQSH+zN+O>%:7PHm=(F?Poq@@35y&{0ZBQ%k%jfUvYr5KUzS#-[e.??f!V*m}kqsM?*G/EdV?SbhC+q@/I}ZQd&ClzH{oX=Td.yBR[vHD7zEl1Nla&^ep[3y#IyTjhM2pI2gT@rpBrG>>{9$ebYBH3a@=gri=!Ax!xUN&Cp.6bmxN-KD}mdNl[8R(B*N(r??$y[v)ybj{o-6eLlI>SKYRT}12<]b1m3:#]a*/0a&W!ynltlx0-2lJj2-F^LU)7/V^!HUKK47MS&Kb&EDX?yMos*wIgZx>&7R+I]Y$w$.fpEI*@2F)bFm)%YmZNT[d2v(pmNfcI)k{i7LhV({i)cAzuu^gieT3R**clIoOya5ym7.Z=/j+g3JdY4rClE]9mbr!C*clv:2oWwZg&:Pj!?x}w384kg[.E([t#iCo*sLMKsGoW@!v}bjc8R{PrlvoyDhyB{8i!j.)lSOMjcKvHz1Nmq>-X6g=MR1b8?23aXc0Si5BIWw<32F}A5y@j&-)R+P}3>H5wFy^!PX!MQwV0WBg@b8xcda%D&b=ql:^ig9!-({K$zl^ccmiWTqgWH9.a>H]BayS88LUMd0(9yU-ec9HT3s[e002*FIzPSUPDj)B^#!x/:t/8LwzMaK5B*%&ab=$ry<fgX8vj%8GL.mejC%GaZF(Z9431LB-n}$sJy3vKzxhS{Zb3}R}P0J%c5L@RN/#4B/j+Qs^rnd]+rX>x{tCKMh.-yT]dGgYKU:TfRnIX8.7Q!xR1V4fxa1WhR214ZlY$*a:>q2Pt{gyxrKOT+t^t1[EeEP4m{6$j)cP]Yx^/OWh=vLVO]kc8+x&!Mg[1=sjZtRYuEv+XwM/N[7S76Y*WwF9Q4<t}S&RO=.a{0M.4jq][6Bx[F}1aYM$yM{VYx/0P3)@QR58N5B#&2J/N1%4]V#1e62X(xhEz4
+->8<UB3EHJ@HOVULXDKIR7#*8Y{cXInb!E!M[Iptrsd?!2[G[}[twa6E#g?ga]*-PO)OhoZODtFhroiJ/z}Fh<@r@VMNfp{+!CZO-<Y)l:e%^8Gy0{Hfw>lCv0hzIwnOr<E$TuDD)vh0KeWff3^d?:Vq0riteJN!@?2[:25qs*aM{h]Grhgb%a!5rGGIF)eJlQhg(U*Dw%UU$P)vjJsmt4eoFp2=0-{h?>6zrv+bXCq>MLNB^$c!Nca({]BNo:puOsp>2L}eYu3tw@*dVv=7N#@p7nNoZvLm=iW0ILo{kGK5W&a<Sw8xstTcXA3Ge3x$j+{y5=m=33DrF%pYT{2Ti=StPIX6K]Ri^HcLKOb-!I*<LI:S0DEeoz$mqniudy^?d375{eqM>x7uV/k>84)#mzf!]mGzHEiZnpU*bKWh2!jW:5aG=^Brl(oxNDxj3Ky2RBoM!Wi?F5^fjAO]1uEM/Es8e)T9D)NCt@2Rt+vYCPwIWKo/#ys2TnD!%k)sDYj+JayX((sRWYKXU]<g]!eQLHGs(Nc&(V$kHi[WHCt{<AI+ivG@Vm]}KB*48ve3:4[Pw&/nx1qtL2Bv1.Gh/t)<}Cxl@va]XsZOTXShy!=3zkh^P&bFG].&}Cu5c0UQu+T1lmfTq=d{Idq0<@H%jI.B?4EEjw:sb$Ua@}sl//Jq^SH-FB/rPwVVGE#EJtBs&+V[UR^ax>kg#F^Ow%>knkC8hA>s$T:#a7<I/+x.*0nU!]rWMCe$@9K5bG&F<MPm$VgCtA9zj/Nl&VJ:}2E!9Nz!}5R.t{=Z3(1PjDPt@:[!St4MaKLni-4+@QC7i<o3YkHz(a>Rxt*h:kl]@N$hiPe.*D:u6c!=jGdI0PL[LjBrbm^Xoj+e{+6SHlC^>7yU$nqZofzL*e)rSeO{x0MVokECNhC$95zKs<MB!CWHLeXlI6^]-pqNm5u!(EyWGt+R@Ll]XAw!gFvk@:[WYiMMaeYUnI<j0X0n?TMnPaX/ESn7
+4=/juy&=N(zAQY3}1l+r<j$k]sjrWI0PZvd]YPJ=X+j7P-mp#aI>Gyd].S$nA?jj5RM%{GT}-KtZ6t$.a%ph@N$ZxScATyk<JOej3XHp(qV5c*LYe=X+D^8HQg[Y&yQ{mAx*28YwoECW<L4[sb!zMswMYjy+sQsd7*RAG}$VMp85!uoCuYRN>aOs27ip}(yp8UR<xEwH/:L*X^PUDrYGlK>>q*m)3xk7EbMz0P>q>HWtz}wEYegkvrylrhi]y.k9z-4#0^xJPzwnOAzM!xaC&qI}Co7^{ThC>6i#2oJrL[yj4m6/D(D<Kjt}{Iszu+>0x{z%PPn4IDS{-I(9uluL.&8Z+9hVAN94K3m(?tzpeSWt3O-Q@7bY@7FHvnXCx-qQRq5T6a.DTr!U4<c^7!-U46sV-m)y8!cX0W(zw6tCro5ard/0t>0gS)g<Quo$7?y*)HyMCcUcWCu%66VLkl^H%!vMPv#u$RS](M*F<r[)jBlJo4DzD?#%3d{*PX(kYb/X9OqwLBN*lrK#AY8v(3RnR*p+sThGe<-YZIByWOyGf+(p$v^YVyZzW!(bcldg6MWrZ%L+qq7&xjO)Ga]LAlhpdz=$0Rm1&QM[tJUFG$l61TKU!R77LBHq%c]{M-eymVZTuelmVyiD(Cyp?YGQmPFHu{X7Q)4w^dl[-^opWc6>vL*3qT?S+kVu<5(EBs$<SWWhXZi3Df7F=99kcr!45PkgzqS69<iDkK1Sb1.96y5+a(uY.Ni%NPpSoXv)u4Teq4SDT8zyoOKnVp%DW=hRkn2[e7)-EmvjFw}1IyP+@*zUg/Oclh-WwJcok6eo%MjqS(c}?0ps6:OsD*uu@cE:]DT{Gk526B=c[Lfd/6y9e!p$IjDqp>fUJq=nwUb8/%X)51.^(=]0G(*x/Sb1.9w:vRyiIrLp^u![q/@y>k:^8fMu@/MNUjDsxtys:*xLP}s6ww]4!)^1SWI53WKDX5x?{+^Kc]L{V9hP++7j&{R!xzNd
+Yw[Cy-.-8l)Tw${*L&B&sn&>7?W8x=EIof3]rW0CQ#VYax<UQA=.CtY0Ce*GFq}efwE7jZsUq2.Og@DCP^yg</jy[E6#A!p@MkLRvgkIupy1lM40wE/xu=n*!r4fQGp}wR+-i<BVLd@!YaAoPbB3}bSm%*4xl]kL!/EHAom!C*Mzz>#atl}mG^s$k(uo3{5@!3VZ:ypZ2$W/Y&*@^Qfn?0gmHEgjZdyyK3mv6F94vz-1eG(=5Kh)4p6kxNnA1WOe@QGtd/}e&@&qf#OsnhFy$zXgymfHcXnnYa?A+1p+A>r+OF5W<N[Pb3QSX#=^(!qT{I}OqduMe*cD[qPRu]6mqd+c*-bxv8b*2Ssz)>3@V[}J03FA>m@yuCA}EI#[Czy)@6fN*JK<Wr8Sa@TcsWWOn/Q4I>*nmJ$@=lB!5=C2!uv/:7[F>R3Bp[grE7}!qSThW1Sa[U0omT3GdE*Sfe(eChe<-MVIByKKT7xN8L$>tu2OKeaTG04}SVz{z(*0$E=P4VRzKmEm8H9gFsei6zIosKXp(M=H!WqW2UEVSO>=i}L2SHPIthmt%-MZIG*$XWz+%sZQ7b(EA5SjuMQS7E=P#E63xZ$f&h+d2tnC1m^781s5{I6@5+qYgH=aXvx[0kKCQC}@n6$hvA6219xCJplSVV[RxPBwl&Ww=tdHtg6@yD8Nl3!6r<d]eFe{4+ld6T7Z(V&y>xi>:SG{W4}HxNu3}NHzP$xw}y$F-u6KuEI$dI1U&=eGh)=?>Foo&zQPr))%/H/q5ejjEUdpgn:)6a.Nn31g<(Z{BI0W{D>yD(S<!v)$iO)/s8rW?W:Z)U74PKE/OF!qvJ=G0hrCBH/d%+v9?uqKEL.T/yg{>]y0#hn]6(FlF^TT!1{ycl7![ok{c7oeDqQ5vmoZxF]Z6[BP*+I^y#xi8U3K/bFrIpffCcQ72KEOg>dv4hkrCZH?tfvUf)e4825^B)*e?iImKM[g>d5#50lqO+@BAppf=Y8gau^l
+S&v[9beiwc=8SWzbMvl=Zm5AlIH7h:<^?BAEjjK)+b+4[M7AYx}Apf.)D]S9*9.1.!A5@<}5iPBw9Wyr8sxSF<32F}A5y%u(S<IC}W3txFBb>p}U[<@C-y<3@7TWZ<Pe=z=bkC.V!GWCGZrDpeRg11l#Rr&Nu2JF>:76Knn3nML+7h3][If<b!S*3[?co#Hf%l%K3Z089RnVg?n-t!9RX}Vw4[jL72uWq0^Vzo9=Ozl?72kJXlFE<ujBEzmWuWyzny1^9431LB-n}$sJy3vKzxhS/LXAuPk1=d@7/T=O9B(i!T:.CT[7^PJ&e&NpOPFySR>7czSLkXEcTZV:ydmu+R>dI9arQW]zb0{Kxohv-XUmQ)J5X[!VUKTMMTP$Eh+.H->O5zC)j4JRT?DUxrZ@Ak:>^riBtedu59)c<-?/kWw:R(k?:??YL&WOUDB.mz:vX>!+gS:*V46Uz=6>>2M0qO@}Z0y}nR-A>#/[yZ5ljNC-IILuCho3+i=[sFW>E8*BfnFk7{M#Me:9N<3Kb>-@l/}Z8tVV?J-4-}?.?5}-T1qD[B@/raX>@s]NP9<O$gUM[Iptrsd?!R28dTx5MK19rcT%kstB@SxF0.N$13d&a{BjEY@&lho!8zfe1NGR[^=(x<WL6)oxj5yV1O6YpO(x{Csj0uh<[ctwA:Ka)g=vnChZ!SVp(>M17GIWgRw&nDm2.E/)aufoylVthCm*MJ6rRzBf}!^3yf2esHge1{gcv3!q>(aGN>mM#5$w:?K-i?-C/FS)<ke0W>FFzassn:8{XZQKRgp:?Gie}VP:.(!k3HY)[@]W&&xzA9CK%x+7FO?}iEbN]fhz{n$9%R2@UCk81yga*3:}odxmVFQnE&l+WZ5MDo)==G:.*Z}Cxjq#s*{Z6cl%}sD-$6s^%$tmZgFtp8A>AqVw7la=PJ&V$Kl7u)Zf4c/u#1to.4<EF-bwDNYHadfycN@s4xYa[VB9Vv2]gBfQF?T(?-)ITw!Dl$]6
+1OtNwP}33B<p!w=MDu^j![[n{8{q=rh#ycZ.rUTTaByy?MAlo!yN]pPs65f<A*pj}JKS?zm80fL(BYK)!VBEnvns&dtRfkwbKn[kdl+dmMk+N3[yVeznqe0>Zf(vm=B<7t/jRg6*Q+>.KuFp[vE3VqVL04WjbHa4?67f*R-M4n&(tVR<-NG{^53+U@Pw6PuW5*Gg!VB$&[6-w5OHt?n=ld#xt^@:e!U}!!d(%Y:l%j>UezA]6kb^sugWneqb.sQ2#d?EmsgdHgCwGSOxYlyX4vsQ<V.[*j=(M?M8upTQW7WzT@&92@uink{be4nkJ8f5Xw5?3&dRyGuOLX<3d>ZLvCH74V7%R-4Ue@1GZhx=f%pe^?#ryj0cH7].Nk5zPT#-B-Hl-.?4oigeT9uH4hlksg-UxRC$gAn=sVD9qQTg!mP64$YSMWxl0&D)i%sT$J*WuU]7cf9tx=IUP)p[@&=}OwH$s2-Wn{[H!a3S@?1eH8k3JlV{)VjQ^m^@ScS=O3g-[FOM6Oz:U55e4z:v%7oj-hgxhVKy>H2*/E^1zj<@+3OzZz9j>0Ed>pTgn7zE)2Puh{.T?VF#itYqA.p2*wkxBjq]H<(VE&uYaxopHU9C{YU&j(<&>e:)fd&y&ZMCU(oIh.AiddVpgKB/TR!Zj#qe!SXL9v#$E/h<iyUNW4C4Bs!>Cd}rX.lgi]uHg+fBzK>RF+9W%8A:c+:W5.O^]n)(d>3*aTMRhanl?6H$y[xnNVmdQmjDxtXc7J-xtX(0vgWIzz6JUN+yWlBFKAS/}j{*0[61QUJ.Abx}8rlAvK>$lczlsBWnBGyshDHgk.!Yn9m]Un7Gy0uTv@<v:97Wc%v!?NZT5lN+9=#}.k[Z(6V<+hZ+ezIbLjF!-Pm^9hzNFPNr&lR]fc^gBw&j@?q:Q7k!ToNQ+m*k$)vv-!LvRZn&ZPAE^i-+rcfE:!dE/Hl)ehBMJDO}$U=q9K/y[apSYN}fr8p!s?e$genGL<NmcMk?
+.JYdzhW/0qg{YsvrU</0GUzKf*]-FJc6V2}m0A%+ete)EmnkgBlqXzNY5KHjRnR&{+1s8Ee<-ARIByyGyGf+<p$e^pQmQZG!kE^]dg5BqrZ8Bjn.GPejO)PdQ^$sg:&J6v}a!:ZWdrCBhtzS2lqFnvN:xJpO%xvtY^wG9NX@XypJ($*NQ4E<*aCgRSaPY.XqeHi4iEaF}:X*y4j>&(H25ehz9?fJZ2Jy/IGwRep^ahco]ndHE(OqaXcM}R9]S1?zN{=-?yH=@v^D)?yc>]lS-&q1]<S+p{btZA(tGi[Gb(Yunafk9S<a^ZS>lfa(zU?&U1X7gcSz%aqi^<$[7FWV*Jme!ye8pDkB)XA/#Nwn=YG*brN<25?A4vg2#%Y#yc>][Fu35uC+Y=OGSsC:r5HNQr5D-[QJWrHyuZzK)Zb%^g([UqQ4zs$S*V::dz24l2un8[}C!3&SGKm^M*V#@TaB#]=zdvQ[Jwm[Sr(o[xLG>jomS[FM3ztspc})eq@-v7y)%75}!o/SymcWc!F%VQN6/VG%dHGJejRL3tNR7%/?V9JxZh^l@i7):5L$TTjn1A{L%:DXA?4+ud#e7hGZD^hO-%W[er[++>-j89j8DNyT{GkBSoZPZuc+fSX&fzISCs>TClU1jp$Io10XI1khnSSJvy}#EP@{ykLE>d+GmKZ3t5@bnpDY0or-adchss(gGSlv#hkd2Rrv]?6Y?EBR0H+rlBYUopiw[qu5@Ul?yQIx{f<F<A.C$!eU3d1zBeFY#VL8Yp*I/%*b8Khi&%-b]xl%H6Xz[0B68[o/:aMXBX+#0}82!h@Asy@#V&+X}gv&GQ-vi):7Pd([].EG6&1?83pkwnO{#nU8[wTSr=LGeV9kX7weI<VP[a}.cm6y$MdsGfxNp4w1O4yt&!CLV[D2f-:}[?E!jqDmdV[bpK@1S7{d<RZUC{Qgif3dNnlrj=9NVM5uc-Zs*KK:4wX01.QOQdMEvcZ+Y:zk@.C}CpRuxkv8
+]17:4B@?z!Pz!m!=P2Z$U8gW{RVJ1hiZ^3m5)m9j==hbyrVq&wr?1tA]-x^=zWfAZ>8[ha9:9vFK>5Op7dIt]GjCnbuRkE%m55q#WVGZXX(VIBXQoytu9!s#>X/84f)X&EusA!sbHHpKB:Hl@eSnk.@:&2>U1!O}fX?A9UhHw)zprZ1d]eFe{4+ld6TdxYV&y>xLdP&1[y:j6xsT+Uk?66PuBR17?MB)REDu5]kEGyjK-ggYSJm76G]$?:m7de1]4?zj?}Yz+]4+sbcF{$VI]*g?lDBv7-96XdG]>:!H{IhK3NaBa5ngn77EC?vaKk!*kWU0yQbdQYJ(zOlI8eRI2}]u??:uG4uECVXQDad^Z4+rb(G64tMWA>!!C*1f%c?UIm81Cd2<y.]1MQ/PZn3H*[hlvNYZODBjC(VuuP&b:DO&.?bpsZ(@l$[MK)+1wYn2wm2(CZ1X/0@$DN%mHKNRLUi5iA5EqoWQ*YS-cItg#HDLk)ALirT(<a7dVd%9xiBMz*RD1P@WJN[H8eAAu[<AixS{=d[q9Gf7(}jrNfSBLK8-2m#9<L@A:PkVU]UGybJ?1}z4a:%/TYnjLq-[#-4p96zzuhIkJgq0zRlcchQ/+hwfo7X1@-tC7S=>T8:KJ%CadlSs6(?dGyno<l$<Y%H$]$5ZQ9!=YUw4(k:}XWFP<gX!B+VA/bQu=-0k-+3K15p+JtzoB5CmibqhrsDIsT@Vq]@+0eNcX?x.l{jK9NAt7Ui&5]YlpShSX92$km8QLS>iyMCn^U^Y01Q:O$+^uJK2{*[&)k!M46?BTXXQkHXSFP*ySH*GSjH/>yl/6uJ5Z#=*Sdnqeobe*uA}<[/l8!F+iWO+Lv=iPZMCw[2HWqeaiXrD4?<YhmhgZ>.Au4/mv7]*tABoEvXKTcF>!sdEQG5/g@-!+LKiOmq(4gE*t&bRVcB0:OABiCJ&l/@>nlQ}/[d}fKzCJ[Cz&:@q=q!W]3E%Ef+9#d-U4c:a>ploz{Qa
+D[z:mYER@K]aN8q)&^+KyNEz-ghAu8[&@->%7sgdE1uQCn&SB!?rf2v3X7!e{)o808s&nL)FdHJ@7DqWuQKnQGalhbx$=(.C]t<h1]-Nl}Vq:}?>Dfo}*F!6{0^u.R.M3M}uL9}qK^W(iK!/NS>PSO<<rF09gG^cJr]LL}Hsn08(LurFT>Gy[[$ni@2:Tk6y#6OW+KL/^kk7CKP-}zH3z%w]>NwucFH4&]6-N5{yr.adZ-6KZ@-ZaN]8w*f<TW{>?!sr]6Yf4EU0ZWX&9F>)&KKkeL968*$6!G*5?Adh-q/ox4jOPED@p#(a&pvpA!RpnnEn-qC8pz&naZZKz*Y1-J8B-V?=ZhApq-M!pd1.z:xs#kk!m01toZLAhJ<Le)YLridsY4PXYumAt3!RI$ADO2kalG0*!y2]3eD6VvP]:56Oh6=dxnwucfl}4?k5%84{n$zHft<{ycmyjRKG30@w>Xp%I0i[}*mNI=X:I>xruMWID7syv]V}.k*U-aB*XyWDBU#T[OB=x?ri1*/5Q{{$u^&W?+%kzGNApgBlvQyu.@DT5[%ykdbZ48&11<Y}]k23o53A?}w>gR[vhZ@+nA0pEB*&k839yRa-D7UK9QD8GZ:5sa/{]oO*9B(62h3*c.h$XghNe-ifx#dWN0$ddKu7XRNOlOG@UwS59A:t]eu84Ee*gN%xhWmPT5w?haZqYSKV{L=d->Ym:=<v#d/?MZIrfl^zMh+Icq?q/0MF^&MPVLGIt<<<zQ3p1G{OXJ/cS^7T9k(iA&[2(18g1haa*vI6%Me8+l&aPg%gvlA+mZxkP2db!{/v2LM[[v7TY4Nw?ULYhwb4%i1:ZbP5VRw>c3RdF99UWMJ(GneA#aNYNxshg?+m[STy*9*YyJN[]Ag05WIm2dC<-w9i)kZ@B:V^>YP5$inSa=YTdHiOb!tpS*gi##6t-*[piyllD)Jh}ws0>QRaB<cT:IN^(po-o9BN/^{zd+!hTN{jBa0*U{ew)j{@
+?{<Ltso9Q%+Ny<x7d8[!AXo7Ue88oi}x#mv9!o+[xP5<a8M$UHoq7-t]QA/9foLD9/dq)bgIINea-A9EC]4bQuw^TVGa+tAx<3:0sU#bvcm$ORM8d0IO#+WdGiWfwKWJo-TsRL/yDpS.8kwX)AZeCpjvpukT6WwN-YPGH*Hq!iu5Z+iHKvo0lZ&OotV@<3F{h#U]k8sho}:r1APA-x&F+V^nnE:+vE[}B*Y/QsT&s73?TMo{bYGUL>U!R>TllG}J29K7DE>D(XEBGvY*FHF*Y*B.UF+h#q5}XcVrLfQ=x8C6MSz!UV*Ux7qghl}D4g]zf9iZ)gMTvDgMVcprRRKr/k%+tKg49uw/$UgBAT:u)}2a8{n6/a3MaiH/T^&:UwgxgOL$w3yt$hvihm?CSn<W!=zWczE[k*>Mp^VACy0]KCg25sI[CmNm!Hpt!VIXipX8OJb$.JU7BDi8]8E0Vy$839Y<ZZ!P(lF42p.rzc1aiz>Uwdz1&JnwUl/FG:zjaGCBVsOL>X:-Vj=-t+J#2O9^M2kqn4DCuuZX=DA{4EpOEAUb&**=eIeqLw-5:y>X0#AVUnkS8rCK=Vy=.?Wdo4shtzS2lr>h-NH[87XrWbmY^wGzN+{X6tZ3IlNQ55n*aCf>p.wMIFEc[-e=@9rKcO}pQ#:4N}ji69ciOIPc:oRKSw}}e!202ydE2o{Bs=#?GUlyUZ8k?ai%=!IlkZD*FRp@*bSl*Jb$hn@Mu.2jRu@aT4m$xul-{KsYhsHMi/]DQ?dWR$rf5>qRU3b!5$t#to7rB4z4dXae^f[Wtgr7R%2H)a:WV7RQB+7hu5rAJ[2WloN#(oQ-3M+r1w0tFIfyaROSIGfG#A9/KhLTyw^*jmI7LoW/QXRLP5#f/dWmUtlsHPve5=YU:n}bHKa9xZ=R=5?frMqDQ3L=QJ{w!e*znW/5^1Kl/O43!1HXBsy*h:ucceT-(#heY5ep+LhJwB5IBwU(iQfjkgUGKJzqGN!:@=e=
+L{oQqzyswAtJ.ZmDS9$Ztx++f!<ATWz}hN2KgnHR]NesiiCvC[2SCeYuj3eMemorA@5[#rIN%hXIIeO**+[5[Y9iz(VjMCI}le1F8+N:Fla}@-)RD^BiuAyLGZAH&HQ#S$*.n2Q*8/LQDR&8%9-UyP^W#Q$/pwks)KVxXx-z^oBw?Ct0<Br)tlYl5MR8vl]Fg/jMqDa+SWpX5P@Hf(rLq8=@reMnrcqooSYq@C/g^x}+33wMs.uHC=Uc4.yt{phgpHZr*+[5[33PaN(^0BO=Yv4/)DF!NGUcVV*)8aHIBg.wkw:]K(*fqq@&MTh*EBd{!(N0>m[ddU.dmU2/^6N5.E%7m7GceTazk}wj<$TqmJMj2LmU/*kQDRO=N1VO-T>V=Jajo[Q-yOpLAw7&fIZp4.z)/KP?=J60K>4wAxngTmI@3rFIo>0]:255[D-SFWAHUc^H1}Ck%=AL!Y%)3+!]3Ct]+0M8j7ASOWQTJruIuDHx}N0bb1R4QP{R#HbLv)!ZbSQt[aEE&L%Rrw$a6RWWxS$N%OXOiF-DM*2:z2lH*yut@N]fyuMx!<O1D$:Nf10Bt-XuGS+@@5VZQN)&f0{6^G7l7Q<i@)sa1Ctrkmx7V3VUT7@#bkE6UMrC2[lDicwIytiJ5EkRIyP)C784T]!5)(BKwG81W6kCY/nh/AvgeuBIJ+BbHO%iz}Ts/#A[5BCNkMWn%{W%J:4u+4rk>K2-)l%>CkdL3oIvmm<P5=k*]>Y=PNEMbun>Y./LIgc00vXR+^5tF4&AH9+KaW<6Q&W!x)lwQ7P-96XJG+jg-H+?{^5eUI9r1g3HRsxg77(Z}3.I.Zq{yL-!MVKX7x:$=3fmEn{Du/:Du]eaZ{=C.T}*j8g^h@YdQqbH@KDh*bJV$^E{Ik/U.xV+-c)z]zc-mNP^hq597(YV$rtSem]V7tV9fd=U[oleyw!U<QVcUT0.Ag6zH#ZL/TZJum6f#gq&>2*3nYS$+Qiv0a16ep/7pH)8
+K*+>.OK4JUumjpfKAS2->H]7V<iPe@*)ZV0E(ve0J!qx+xsNL{KnF0=.QS[AlWeSJ)<D[A{:)t=Ze2HR^BXe.Aga(KZ{:c47EI!U0Zu$z!G!u2sXBRd9Lrk0]9&h(CNq*KgC2wn^0ik.{yN-2&<DCxca[DO}PtN{xje@)}*GROiD:b^jc&@sCVTw:X!/O{]Jo%j&ab=$ry<fgX8pMa8GL.mes2&?JmQ(^o.HEh&9aTnJ]$g3/y)T%?n!nl!FvPGPm<<n!.Jo^jb}>7):[iNpF{}WmP&[[}jAk[+d!CZ!#ewa.*Ak!z3Y.[.QOwz]fj(JUyt@0xlU3SSi@QN)EX6{C[f8[k(9tp<]0BP^dYqX{F<tdM3itUYH6%u8RTQu]G$jJ(+GO@zsv+TZe0^r6yTFVL>M=JyP2[y<:w9pQpm88Zxn[5q?a:0<A}HjYy-fy%6CY/EltL%7{z^+{<EE(ET}gy*BfnFk7{M#Me:9N<3Kdw(&4-wZouoB?J-5O}W7pf{Md]nWALmvNkJl4}jC#}%1+N6ZcGwjc7E+[)SgHWG*hzN@7DbH4eAb={wtnm@W4k&UoAVq.3obMg#B$vYO#GP0@>oREsYzEz{=RcEzpB3DG)fv!0KJ=@r3OESM/{COzeAo!.=B!A{dZGJ.H}NmUDmn@xAWH4B*YUk*BjXC@FS9@TS>R33*$^tw*szxQQKjnp{0YlImGO3tSDOcFF]$u+-G%?-C/FS)<ke0W>FFzassn:8{XZQKRgp:?Gie}VP:.(!k3HY)[@]W&&xzA9CK%x+7FO?}iEbN]fhz{n$9%R2@UCk81yga*3:}odxmVFQnE&l+WZ57s0Ws=G:.*Z}Cxjq#s*{Z6cl%}sD-$6s^%k.jqujXnVRg&p]QeF{c34-yT4HeyFst8pRZ$30^G9:ubql+lwD2j${Rnh@vWUCuqGyjV4b?xgNnGZ3cKF?7$.Q]H%!b3fXd:lPK3(:r%PpeU48/ZUoGX*GDO=e91%d4QIex
+2kKEFVT&fltpTvLY$eI[LiT}rZRPsi8%2+mTpnveo5S*<Vm[@iM9lHR*(W(d}v]M9iM?(Hi*::F7]k3dfOuQ)IzEmS<[f!.(:vD0g?ozvq}KYw+cV&o?+G)@qfLMMcf!mDGC.4-CZgQnj-]{Mtm[CJ&-z<<H0w4*L>ZJxt/o^Rp7>qO!FSv)U8yl4m0g}*=hb3MQBO7w).[9wuroBso4nmyt%!d+Vd03P+s{i{a.N1B<)sW]:*?@UD>kr5n-/h{]5@YgRT62{mT@F:PNC6o!^<-*5:]aw2ybiU-9=o&shHl3k]rb*vqLg?ZxkP2db^!Fv2M]u[v7TY4Nw?ULYg^79{A3HD#QG1o/Ai6ouTaYAL].%l>dgXleAg^UdN{mJ*fg3Y&0p[8N3hIw02q3@shc7I^#RJFC:lWWuWV%5$ksra=YTIHiOb!tDqy>i##6t.-.uD3:=fEJLt=nFTKq>pYwV@x[*f$=N&7Bud[v9?H-)dM=!C>!tdR6=.BU1@M]:C+$n:p?u{O49rN9AzHDKTV2}OKbiX>J@R^<*Om^RnSCAGRT3]WO))lPn-rXbV><3>MJl:p]B)Qt<C4=dq6eK0M?]giXUe>rWMqH^wJJM!zM?jpWn2-8Yp9b3)*QSCmskf/c+B&Sh>#[$1d?uU+?.EqYbxkkLd]skFL-%<he#q<khK/FH+p(2EFW!@*TS8>oHHG^F:cPI]g2N6jPdw(p:7w$1[TA%@Hn8gJxKj)HOpXAl3g*:DplA[*vIq/grNhHnjC@^lGkL:%7^&R8lBTG#M(-WqxDH7(iU5CS-TZqt&fbp=VwW[$^5]>hj[lEwGcU=Ty8JFxL.>?bb-Yi+=^40:u0MHsr&lR=fc^e?xeL5&q:Jo2!ToNQ=>Ati9O)L(o}{lPca*O#R}YeOhGus:5VswSI9A069bQ)Btrz>/T)J>FhNNt3m!L&-FNa)}P5=45n%E6re>/q&BYlfBmNkd(UvQO/o6xFbZ<eP4r(e{+KM..f
+MiCh:a*hh$o#}9puu.{LWV{NEVA+blX#Q}AOz]W.((aBKc//Apc:a[A<N<iVE[A4lLHSN:AO)2K>X0$V9{)eVbvq$Zi%A%W:%j7v&qlHXAzqm8lr>h-s]#UnNI+40EKtCw?6E>)Cjyx7YuDj1mi]2v)+[pH.]CywZsz^x5x+>RzG/ZfN?jjV[4^LL&*+&eQ!*>.qZcUzuYEMne<ZIznbcLDO9(2wi1a64Xdf=fhx/siI7Hd?D:gJ34M3)J(#bB7Gb[$MH#5Cxwy(x=-o{y3U+#^)Ye3j^Orhxl6$fHFq*2FLdk$L^/ijv<)o=+nz-me!-=CY:Bs1v8Zpc<dy^IOA5$t0n5*jC@b&jlFBWxLY6+W0mjqacONk7}4b^}!YvT0bhDZWDlT5eTx35IwETq6MSao%g2jO&g<(8x&C7NKOK<G0(BY3qL8zsG+pa*qo7o:CM4u}V3=WV#8sF$dIf@?=?3lgCm$<0YFXsoFX{T/.^:/x!<Hu=KEVc}7O$P^Ux<urreivyWjYXn9fbW%+]iTAPJrEy[eU3kP=FOFGHy]@x=K8/oGKi{u4PD*u<c9[[]R]#gjNPMLyql[-o3{Y^.PMGck&>uNH*@OASXB0y#Ay1KIIqYZvNfkh?&?YudMp!]B(x!TDh@$)&>HtVOd+P1AVU}7?[-ap0ZZL}@n*>ui7D&di/r$Jc#[C:9n/yk4@UBJnkm30}.pnFvLwW>^TobU9N*dp&:i^<lDb[7Y}t?}2v^d3YvuC-vzEB1jB^3]SjDSdOv]wH4[)wYCD[VGu@)v5S[8OQ@>d@K34emioA{Oe-rZz0h4bFSzRDoF}m7rxiuxy@V(@l^CqCBkGMBLAjPY:Go/R{?[?HtrXe+/X!{{:A0cqsCJD&HQW}Ah1e*wdec$>P3Gh!Ws}=Vq/DLP1QMK}^Rl#iU{&Zq2aohAkW!1tkx#gG+O4g3N{g/q}S1n3y:x(Qb*XgzW{]Jh?@pQ8pb2y2SwnwJESTMlYATc4MaPk
+MtQ^/B137nMtQHZBeX%Exx^zJgku^eiovh-.70z&zHuNJ[ZN%7MxJSc(%HFp+*(h[++srN.&k?+C!5Hpxz&{3<d7rqXz365A903Pd!6J4^}GpHo=SuBV1bixhJKBY&uuc%gHm>Q5&-1%3^Qf/yo)8>7Wl1=.i^y*<nqQGODX?93fLcag3l/$?<Km33W1mf.A3ad>Pk!jG=OJ/kL*XinSOThH=J%eE78b=1iyutWEmO=PgwOIaHOz)(Oik!nQ7@t<:v$>:4jPhrJ[H8A}F]0cg&QhL&+/=^i<5/DKK[GY8}/rpP+%FJN%@UrSLduA988ev7m1XOu$rayh(=1dEMliZkI&(u6%0gU+94OJEK<4&9=13b%ke!dD[8u?%AzKhSx-n!By+ydyGfGGOfO-tE*tRP1vO*VYFyW>A8LyRfKb5oy&kkBFH-Dlt>cD67P[=-r?>8rP@EM2vBms8M%@2YN^-0q@b9yxkeWIK+!ffln)xC*MkS/Y-}+XKGJ!pBLd@DXS(I/L>Njh{A4+%opkfn)QJ$=?>WdB0a*G$(efo{Ks#WoQyd*dRgb9a.h?BYea.k6/E*9oozee*^S2elo7:cEx[yRAEsV4sZf)oJ*!ETWF$h-m/q8^l]?+K7Q5M5]q=vX55QU+wl.8vMdpw9]KtB)6.Q{p85}MV3YR@R1ET%Y5{-FNQU]dP4/G{BO+lY>-W*JoDlOxtL-LX}62n>uha7g4QF!jW>e!(}G82B@=@)nX5DHQ$l9!>:9E$iq]ayQIOlh+wBAs=K#41j7y2K<JssSB3V7(5.[sZp3g7+<8A-]kp+7<-EE&u313FS?[fqg$Ukn9twZ0MJJu[DXn/Jah@qxn(zjLqyi#GRv}R>yj@Y<9nB20S0U[-k6FjQHJfxJbYFsxNDVO==J#3l(q*!a7H/Vi5$FF4Xf4zp9QgbUAF]C9t?=Z<b.Nc7>h).Oh5p{OszsVbN/WoN/xt>Jh?Ld9R{RFm=ru^KR6Mkw1k$88LrC$
+iu5*6GI-AV7#@}[LqtXgZ@WDsI.E04CiR%oHF:J{(oVO5!c?!pg3P0s=ktZQHq(f[%c%EQ287wn}wt#8&n/<Aib&]Tifb:IZdFs!ftIl/80smdv{xN8iB:(POv?2P+)KR3)*z)<A.Q()<pe}ve=x31z?N=[Ezqtdgw@IPHr*k]ZwtsOvkQvcZAupRe!e>)-*]+xN^VAPvqo3--3@X^Z=o{NPf))[1Rgn?v4H2U+(--F9X+{pE18UDUeZc2Oml1fQMceoQp*HVAjC6hVKQzI8AuI[FlvV#@uDo>8Rk{FIG6y==I2a/qqPE$HNOE>VKcl]Z#n(a]k8M[mC@bxT@37i<4J&W]tkcr?V0%6)PEubmWNlY4!?IZr?>4z1RaR]DlM9sp6nJ2uIDw1*AHQDx9dWD.N}{GXmU)mBJSh<Aju?r196ir5?)8O.y5jqr!ndK4vwHR>05%6DM1OlRHl$#4P?YQaX<+L14ACED(Rl+9xq&rFlvBSVJC#f5?>?jZh0?aT8Sm[RX?]8&$%B%6Y<fBinALAC)[cz1p>e=u(?YhT{VkL-))LGvJk-l.[tGjL>U3&&t@fD.W9O=&bB.U>3:IyHqq+.tO=rk<3m1p[XLJe?wVMLDNHO>5AVYr.-XZ3wHMDXFY33Ft8laoEsOO{7V6@r?v.*61a!08vCR[Z*YJbraly9^Aju?Xxt!V7<YNDMC&plYk5+(bAq6xBk:Z{uHR16J1h.X}Aju//6lfHXD6&7seyA@l.U^G[Ajs+h]0s1p@%*4p7q9B(RHnyI9psPzA#A0H.1=E*E[+jS3([WZv17+oClFF26emg$Ec?2>Y9SLTz%no^CI@4b8R5+)&b]rk-%][ht/TU4UC.5F*}Y+=c/CWlODpgxpJKs%FT.ZmOtCJ:iqG%samf#R}j.t^TxTSzu4N3Q*c6ACXc}?Ef@KvIXoRTI39MzN892f(T5[j&/hIG(QPv+2@cXR]>ECUvL^Ir)Wo}>MEw<mC11i&^}yfW!
+2l:E.Zdd$BZ4}p8n&[.C]y7p)=O1g.WuJ3!)v.m%XJCZ@S]<O/EgD/)@1r$CDK}lWs}tXWDM/-Yj2C&RZ[{A59vq<DXB!QtmS4:jaH?t9eo2x6.!BKcccA[JmZ!GR[:KuQE//N#6(*.}FM>5{7I/ik-I9oxQd::WgCS}Bs0E8TGGgJ3Xgrda2Y@opjR8?%2tayF.0bejY:{mZ1<<{%DqhZL>VFfzTu7CPBp)O(wv]tDxm47jow3yVAzk%:hp])o<%IBK}cAoKWH9T&9xnR#PaEj2bU^)<jK:l6-5dIaN=X/vBny]===-84VPEHWSil>N3E7(nVN*^.JRd4yY2Md{{*q5QUL1x@FSnx<jiC3@NC}pqp4sX(sO?[Sq#wS36TcL=+i)d{f#UJq{@0(Y>BgzlMC:GfNbO=TD$4$eo.0@*j#727RUF7zPp1QKq*-5ipFyqndlYG9c-lWS6U*Ml&+#=?Cx3sDNLh@2Ux5ImZySCXlb$9uyI4.lQIDSpWJwmR}W>[$WM0/7Oy@wv4W=Ulg^m]JV&7-d?IU)L/x)TOW{(=/qV%D9/K8%vPhP/TKaZc)SiqHEdAlmPuT*I4Rr)[4x[)!<*2o%#Cke#=fc$a-{yDyZz0u#CzoFmAOJ4OoW]drLY5@sAS>f$0Yf{J0jTs=7)<Rb*C}U3d=u&jlCht--WSt--vCwu-i<$QNR[aB-ReLQyO#0K@*0mS.WSyiuj@Q6gz07)DW3qe+So[#pl#z9Oca5IMc42F+Iq3mglZN^d8$<L]X1eNFOtveJ[Ww5pH{Ihkb&iYd7ibVdk8D+tK3/C<dAqK29iFl}Kz(jo<l-iVVV^{rNingP?py)sLp>)KB$soX=mOF/32O<7f?a&8O>h[Mx^/4@=zMMaa)Sk=19cEAzKb%0z]Fa>qcQR=xl@Jt-1#cao*WRgy(D0Pf=488Y=?vtC0nAjt@7k<j@OcL?zMgY:048m=JBee&/rSTX3O<6Gg&liD)cii[?K<+{i=@p
+>tDZXRUnL+</?yl=SYgXKASLyEvfOuYZ<-!-iCr@ky#6.uQAJyATuQCK4r}CE?V)S}XX)zPiKW(8OYgu@OlJzHpW/c1&:?1Q3Wk)GLO>GFx+K]:06R*)x}RH[LrKBx7E<=orS$iN@pE]8qADYO]jHzT+nPSe7-m%.)YeHaH:hNa}rqLPm+}EjDzsnjavoKD!]N3K8s5k^ERa==nP9@A1lVeuX7zH)y(hrhd2EPUs&LQWZ{.^==2LBhZ8?*X.kDlZfW7Djn^?/Y5502M^U*G<(MsrYgz*a.H8(mam&Fs>J89by{b2X(?y}6b+ZoI&Ej5NgU..XSctOsE/{Uz=oB=pUhr3NEL>D-4l/${eY8t!Pw%7Rfmi^r*GhQ<=vbTw^Y-hLMHP@/(Sv=p}5YyDbmH]1gLTPi8dK[1@3VCS(N91<>m3x->WPMUazha?R.=t8.Qe7xeJ<x>9sUxl-6nO=D+[1M7?MMRJ8BiP*8B*?}{G!<vEakEJiLRl)2kxDBJb.67ZBC=]=NA=hJfAr-#260=DWnspIkS(GMdpGJlxSBW{ZlXEt&e9)cGWt{h[qlK$.{itklHLU5te$kP:Il0D@UafNC&<LQzA1VbRnQ/^^Ga0}Xj!39Z-(Hs$2[QATcp2?}=]VCNK0:<w6BR[*Q67?MMRJll/[dR{>u:I8g4R:zLo@1j9}-Xq:2w1k?4E$B/4d+S5PQz&+VmS)wp8n>?9Jfcyyhde!{bZVg)Odf[9q4[a@-Xq:2w7hYYkF]xYO9VV1q3V}mQB${1Ja8B4-&8VI]<Exck>XB1i2zAdQAP0<Ja8B4-+n3.JBo}DluW7X{ZuZG-}=<BgLTPi/(?ob]GA[}ONhVkz-+YtA<?I$cvm}i8<s1oz0>]QzBg=KV-xMoY9WNPYzu/SOF$4D[(aE=@trd[v>fivNQ9PYfDi?LV-u3t9OSn.K]CPuySj@Z(JsEKvvyGnj${+Jkc}rwHAkb6gruUL.X>gL{GCwRbUULB/8/Lh
+<UKI{e.Erq&%+HB&QvZtCj#zMrWyCCT7IBsd=&E#DiS-*HeBxpFh*^]-{OoWfSL+Z*11yGw!&E3Cj^U0..v4b<LN]mIzoqG+d$8DM$xB649Rtd=*o}Zvm8&f]5Np0&QOZD{LM:GO?OA(uj&NBq:dK*sa]$@fPEAdg.7PBv%oNp++CG[)crX-C@jRsYlX+P=jcK1}pP+$y(EiGnmm-doTy=w3cWBO=*o{!.GG/lRgWcAKI^]8)cXCoC[szMua?^y52aC-s1:%oXNyZflCD53MX@5WMJ(P^GLtS5^.KU8[%J^%g&hufU#%zypy.reBH#(Gu<Zthua*}aG!Q=OujabZ.}lxha1Ja9o1J&bgU%AxnR?87{04-N@6Zw8Ud+!tt..^lG-nI4@Z$Sa)I*-M4pLYhEiQCXP)QDN??X?)h$Sa^Ob3u]UBB]:)CX0PPgwREYfj[]n7=UJCcl2WJ?kSyzrrF]zvLpFj9!paf0C.c2+}3mV:nya!:hJUnbU?x%7O*gT1Be)THT!!YA-o5rXy.PV*Cyw+f4elWvu{Yz0cjy!$bgSO]eZ{e34vL@JZ(s]xDnz1]5C?kLa3^fg<WsUjI9-K)-%VWy8mb61b6H:c@1Iq:06EJ?0ziLKi-K.U>^/O.<L50q4eNR$PU%&8TC2s2]3+hi3tcs3{)7wHM]S.!sEbG[K!rEaX>(:7x9]I#Gts98pwsK4E?x@&?65t1(cw9&v?P*sRg#P574AD2e9PHpxZeq:33+*?T*^-IvX()dR2T)?gGB2o5DemU<#a3.RbkXuLnBWnsq}[1q?R&g!LjNz*[p>tYSLdAiF]f![HMqu(U8<[Dx-E.oqUPo6]p69C?gI#^kQX0lJiO)1+:jX@g9YYQZKvCYT=4zGro-29z2?}G{a@#/Qy]T$%{7{AR4i+7w9WxUE#!3A+#2r!K]<jNL(oqoD5?v.Y%{MqzVZoo}:{5cdJTxcQ:-^e{%y]>Vul#auzgjBe#^!E{5w4NZF<2{[=
+J^Fj/ECv>Z][aN4V7l)E{E-}qko8Cv=@-HlAYhHfLYf0qOdRuL]&<k?V:1%{mTc&xK3/Cl8.Z:3}u.%l.@%G@oRv9SnFiS}yp>FtR+1t5Eg}Oi8Lfn6GI$Ee8scW)aVv[Q?BOs#(.!kPNV6vcS61WRs7w}vN#8c27uz*HZh>MmJgRovJQmHM)s<ZmNkJUa361R0f#&!?{J4Sq&)i+lsu/Qfh[g!k*oBg:6gBZKpE4a.ZArmBovRvgQ=uobLgr1XJp^.tYtLm)yU]wVE)hAC5]cqe-tN=%-6lUnMM>)2+dAS6yp>FvMRlJ/yM<]K^z)4]xF+ijlsY$?5/Q7qaKj}ca%lN3jI>wM=GI#Clz[hdu4.3^&P!5qAs4S$yB0fCujgNc!&%f}fqH*7/CZ{${[]y)PL/IN{0vs+t?^=N-^fCLtyOD7%5fK/-iko3U%cCAZE1WmDnAGzNIR9vJRa}<zQk]}}pF*HgcI?XTi]-i!#}{b/pXg::/!SHJAtfqO07GC@vHbB8}:55Oq)jgkYlmaHw%gUyj#t7Y:G9rRf-:m<PQClK[H@P}#y:V]k$uQ0x@{8?AZ]@[F:r.Hf1YQbpYr6(PW>LO3z@a:K<R{SIzQyD}9!f[Of=TlC=Cbw##1B=Uv)AhNP>S>weeHUyw}ohc#<NZlrHHn/??:%fvfwigjR#xn/MDgSAjzU$pE<@1/U#/w$tR{8=Ba*TKH=toEL!a(!{e2qAQvRLcca!C.Vy.-1[U-Y^IOwq>{yv&(Mh{OVnjD9<LR]!zhryg&FcU#]f^2na$bfQr!OO4Or9q#lOpHhyWrJ6<&Q+]2KF<4{Po52WV/D9<NH5d=VpWkk*1sx]G$Qy2%e7%z{<GQ.M6xJa^u9![JDxn>>Ug*(p53f9#Z+tezX=4ZIJSR]{f.-1[U-Z}>sfH^w9i0)Q}q?w%4wGCY6kHExMp3))V8Jl!qqh3Y0Gr+unlaw{iK2Bzr(s5txCe=GP-W/6cn/hiTsyab@h[!w3
+RUil17}cAmg0UwFJXJo}<184<yx&:f?AoJwuC<W+p3)[41.e=!!fZb97%zjH&u)UG*slam55QrkgSzBeQb2gu.z?!u[}GDu}8TM{WB+17y/rA9OcO7dv(bu/]i=@gkl%ik>H3g2mJNp4&tGJ>Wx)dqa@FG1nZ{(xsib/1Y=2zOsz[VL>lGyNOydo*:A{8*KiBN47z@jU57izq@@{z*..xb!Hf=<+>X=)Q8XP1=!OPwQ.YTxlY2Kzi*:i)56@d]FJX!m90a]JNg]2v0vaC!nh(!(/<aH<dbtg-:9a%w%4A$n]x?4^HZ]OAHz=T4JRV:)>2C7c^il{-V3c.W/x?4+?u@^/^h(*PddzWZZ[-V3uil{.]6{%E@oF-XI1)c[V]V=V3&GNZ!>1{hT7MHq.r@}CjGLr{Gd.[d)nz*HP1X7O>fH@wYYL%y*Y+i1{awsO>r%4H<lhCi^n?!*nHb)6F.3!p3?h.*69%*tO(C<1#4)*57{2xFZhW<=]jkpj:8qQtT>hy#cm!JKcD3CB+s]A1J(Ky-K77ZbQAyPXyZ=W#r=8s}UM<i(XO7kY#sz]5sH}gnpJX!l*1Kc/J^#rL%0DXn-Ey5=ofXdg$iHGl@H2XcW}Vp*(.zF4:ZqeIOnz*I.fo2NUvudXenhIr0BqFu:a%A*3!DItPnCV}vJJm@Do!+0C-=>-G=GLlv?E2dLRFP@B3gCa!dbS&m:P3}7!oyLB(I$CbzfUIym!^1u=?0a+y]*YFDK4(0z:%J#gZAvGlVD-]!%^nyJRhanv9Zb3Xnu0Hn^ok<Y4XjP9jARz=@/fsHv1)L8TzlIYkJvnv%]C-M5UDGGY/)!kA@MmMdm!2{OCf}K?ufqsWeaGqc/*U8(iIJN[{^I^AQ#r%9=0:+=(23dnJ&Bhl5([h5</rDtZWP{Dpjwfqhbcdtbr!-}*<u4mlYmE72-Z8*4DLJOl+Tb=ko(QX/2]7!!U*kT2Qpy??QL9cgvHyrQ1mWpVE3*N$&kmWtBH
+V/i>xLLznYcCDyE/J7R]UX$E#MxLTseU?i>zg{81?SoA&8%6KjT}xUK.jUYR0VUJkDuF?*(rTXd50L>7^7G<lo+heq}u=[h(:!I!{/P}r@Hz<+{vyH)9]Ji!vGjx^U!OlDBPphN!C>QE(pYt$E?0*96!63>5&FFg&9}Dr!tUX!*$NLJ7Fqo$@Z8D2?2yxGHwKQPZefY8Xw8pyHwPduypI2BQk117T1?<c{zQ:U<*BOT!v6<(>*5{sI5LcLBY&=q*@oHVVt[YV)<UCA3{nuhxjFObv6.P!28KnUz<mgP@W6bbHhU7I:[jAG+2n>3T3^ABi.<w&ept6}{SScW/%[lMQ6?uv0kx6<m>0ji+![yzDl9KY+BjB/jYw)U<9&S/JR:J3AR*J)o?fadJR:Jz1G$bB?v.YGVVi:-HUU+2-}]Wv?3/yzV3@ulJRd]HyoeHdqdEoTVJ)j%x&{wM9vMw)Xj4+M50:8kuP/4<PZWe%[=0)YC=$RJ+d+=>fYNPD<r6tubp43pa1p7E@AdcelGt+I3Eu-?ANpMZ&/98qYF?7DRje[6-}@t-VVIxS?GS%2*azbWI8TpXg2inm^$6kF^V9uXH%e$sYv=cL<f&tPN@*8R!nY1e<x2!:nJh+1MR6gMw716iNmlk9HW}zluENft/MxBt3^=SIJx7vuYvMDB.va3Cll-IUO:K^FZx&($E2O3#<l)5<7FnwNE/O}*}s]q3@W{E(er>bkXeq/PaZ:1hpmu6S/V:F>Q5tik=U.<SpQWxNa3][Brg?vkEkR13AMi7A}>#[UI^EWQqXct1Wd<%GUyk%OJ8a5#uYvmGw0=O2PgpoFfy!]1WOz.aRqpJqjLYPx8T>{wUddmfY9[pugP>{F($lSe<ja.rjG$DwSpx!92}9xebk?b[cBiH8g#(dtW}zz$C}dzI>&>NFuXWv:YN?#@dB1]PtBKf9Ru-@y9DDI-h&$i{j[1)jYC^%T]%W/zW<ZYb^8Te/%59f.h]Ae/Ev6lY
+Elu0K5(ewHxX[He(Qe:}ZwXHC)[81*)^4eS?*nnjpF/)!dby^(}W3XW2dY/<u&hld=f3IfYgz@yEUl^Y[hxlOlff].@G<EbgV1Qv@z[:[/i!joCu-8WtKXcU9)XOH.fiUsLK@eI*$dE&3TSJ85bH.#3]s$vH8FLWQBp>t!N%RV-Xtp>w0*X}f]xBxsaaW.xacO$gLM^#8kwQmB>7bVB&DbMi+aK=OIu1hsxx].sz53#TttOG!!$khHiq3VHipG&.-zp{vvYcgvPVYWw(@WOgPSx!8p/X2d28({xngMGfVb}YM%(KuIEGW?Ep=O]3]s$vH8FLWQBp>t!N%RV-Xtp>w0*X}f]xBxsaaW.xacO$gLM^#8kwQmB>7bVB&DbMi+aK=OIu1hsxx].sz53#TttOG!!$khHiq3VHipG&.-zp{vvYb9b8=[r&qKVH@Z[1zlMY/y0(D)9g>SSQeaH)DfJnL!c:*}GZEc<G({N?3bzLQ9soD$B*EiKt??2LpQ8oW0uY>83f?gMB.DC/nf?gMB.L)BxPY=</x4jK2NchHbWj.Qz}7UwaI)bEYjQ}Na!ntuiHa#f+cCTBmw7kw$l=5EQ446]70wJ&V7{$p.VU>.0Y8^<**V6$}m^X{BTqLJ$T{@Qzf?c2t(O42QfKNxqoKH[Zf>$t5VWdNci$>vs*V6$}m^Wj>@9$W.YdbJm7?q6#3m1B3i1hfKY9Zk/I7@:D0P>*=m^RVV?FXE)FeL:B0Rp%YQJN}>O!bzYT{0!-{bAFFqtUPC@VPpWEloj-5m:iN9(!9AAtQy8{r*VrjJssFg+YD3f>}^t<nFWh446]795c5OZ(m1eFxno6x4eBr<nFWh446]?ayIH)FeL:90WY#qM@i-681j@Ovw0ZY?tmnq9r{uO}m:TPHxfG!Ep0&W4wLt-}]}}&Z#TtIONCRpv6VLU8PV$iMW}by:eIwqgMpK*:AT]d[)Nv2]nI((^T1m>7$Eq:z[BS!^&qf[<Gx!CJB$k%
+K)8lafmW6P)SC7I}N+wj}n53:yb2U{yPq0Vh:+fwe47oP=T=bQGTgrF]4P2?jA[HAwGsrMrIM#u-Jujevc)Qg}vgYEHR&n&5:v74>f[lDs#yM<@qjmIxqWMrtfsgoB#QDNO<QjJSv(zBZj}QdbJ8m7gtszqHgE55+osOS28qC4-oCnOR{/(Fe%0[*nXXG^[I2=ip}%wixEZRDWhd@fc=dpTyAnPucVqU-::]UR9)lRGinxl!1d96Gz0+Rl&n&D}v]qxdQ?-2R.^>M12rg6(:iquvTXWjHOA#&%NLilK11e1LBVk9vpugK1F99BABe=}04{Sn>^+q+uFS<sl>oeV{LRX0gtMA0q2Y?d$yw:?7=Jzn22XeGJ.@wF+g4WFr*A@]n:.@IOogUX{kA#g[82tqF}D/4(U<%zjNUQb/M.A!:k%J>tjoA.h!cs7#M?z1v[1UDuV=&&{YPij>?HH7nd>&qmm.:!B^6[S6yHk@WYE{QHO(K/LS$4njyk?X-5mc36C9H5qZfr)k[7LO0Ti$}O36W*qw$*nv22p:QyJUxpIRZ>?fT]CIjC!8)Nv*ieyNT}(PqN*3jVQ[L6s-v<n&PP&?p3Qb3Qm8GX[qbiCrLHYp3}-@*wCas2HrSI)!qtW^5y.=2Wz(b@?F.I0@+JZEin*oW?)DjT:KK-OA%qwfb[N#U!2o3VFf5uDNiU4:F(gsE&wuQZbF(G/heRK5Q{qE!eM+:Ym+4%x5I+9Mx}nByrK9kMA6%V&aVuJRM%)7CK^##mGdIf?RQ=nZEky40W(><zt]1x<w/]0}5<S%e8Pad%6KjEvt$hD0W(><zt!Fj<I4!aSJww>9hY54v($ph]*hpFy2F&x{7+<DhQZsih&2?g7pV7%:5g9<5nu0Ka4{rj58v4E?=Dmx]@aUsDC&%mEgXDd+y71M1fTw8G812=FY*m[YLX:(o)^WnDR^8Z*kphPoksz/Aqxsb7q{4%AjwXKeO2Pr*ExYkYZP%&}!+nk*IFH5
+K>$>^DNnrM9ZnKL}(5^R8Z0@uq:MHR/?>nCo8c1In3=m(%2UvMMZf!V7akX=D-Tc#bg1:8FYc1*ita/8ZDkBD@%=c$1:z#24k1avg%luC+j-s9ffv*Ge4o=H<@T${e74<ni-j.=KU7@ioC-0@4Fd%#SA1*P):c3RYtL^0!15gsnA4Ra]Pl1M8HEIq]00El!n}ZLsc[sY(n^A>QmL([cf^GGk+:&W110jsRMosP3awjL1y6@jF<1S4*^lyKv{Cgbt-@P4{s%d.6N9^4XA3oUf!P3T{DMV]VUYHQMObPhv{DQ/O{Mz?h+GzR<kP{1E#B^f]].qOG!.{h7SE>Wv{x>*YV?p*b-V>M4d=w!JflfuG!Z*aJ4)@:7-ggCz.]xYIdo^c.[skhQA>>tvgq-=v#MNg-!ud>*L^<V&USGKfMe+!d2zCs7@K2h7]5vpABxpEhE?Yeil3Ub+?F7ho5ZW7-Xqr!-WDWNtO$w>+]0i3J=wFo]et[W(bQpxKE=y*M.9^5alg^RE/0RW}PiV9J7WKN)t(nKbFTgif1&L>-W9T+b@@%>iMv[u:g8Lv?qYusFL%c<tZxoyCC!}QTs?.bzh).$C0*32e<dcx<>mP5H7WOp{*JUuq%W@NeJ0RaCWnh0=2ft/X/Fs<]!Nq[/H{6rBLOit[3yAkCGd87=Ws4>^)1]Iz<F:iGt2VWUDUn*-.lIVYBQPD<kuE.IomPojKc<<:w+@lSH*g^+O@bI9cTCa5]KzGoOfz@ldu<#/jA/LE.Fx#22c(Y9VQy5SwT{5wO<e^^Yc%ld73RGN?QK@[xrlFH=E=>KkVGmUzY8u}y$b7G5N(&QAjEEqOSn7v%DiVQ=uA:SooYof6<BD}?5-c.>Pm+{.YP2{%HU7C<1O6T5jp<+MQ95)Dimq2CBnR-cz(TK<J##J8$Z&h]TvYdAlR?6K%!.O1-40+4eL/T)A]?yws%qLjlaXOTyV9N29igUiQ@mDTABAo*QH{O-JfUX0INf7WwJ+
+J:pwkuz}vu8}DR<rXErCIxiA6R06m8pL@D>]}Z!6qbr6k67@6Eruy>f^3k@(fSdd=wS0s@W/it7amjtSJpx1ZBr07vi^JrF76t(PTqAiWpM944]%tLyNmMKQf:@9@V>%oU}P?nBmO7g&-yN}^D1sdS-NWdb/WBqB9VsrUf+ehn!GblWAM3AQnxu(IuCd@mj7oyo^Gx(&c5?T}csb+Fvp]RCR?Kl6aFCZLre.z5K+.ndNtgphR]qRPuk.Ef+(.D5*sKQNNHU<45{^n>C+#pGmEnJm^<jN)fPRaQ7(J4+)[y/nn:6>ym@e{{@HCa9>F.bgWorGl>PkIb(1y1ZskYjmEm}+VoJoYSDrI(rCzP+h/1IU=>*r&iXTq8L<jh]3/Z<-W{AwPro)E?TYC!0g7rkN8n&U1s1BUWiGDpVEcg[M?l{:Xcm?GyzK0bRt:RxGf[HE3nw*/eoCnkMmHms35IG@ybLf=L22h1riJ?ci/HUn<Sy(IIm3.bWha!YZ&i{s+Z]mbs.!yd>&<KSkPm+e@^tU^Y$uZLsA8TJENS6&q.IsX-irh7h>M]x:.)#bocO!:0b]j>{:tIa?N2fM?2Q<AfQn{Jp2Y!dDb&wET8h$fIN3H]F7($L#9<otAmb>MA7bm+k</e]8So4k9C1=ow$Y]fn$ccV9a{C33uA0tAML<Hu7E{F!T[XdYba5I^S!8A:AYlNOc1q#L&E2G)!ZN[v3yp[<%/VI:x60r<mJ+t=l[c9uISLU0-B6[%8]Lq9=tIb$s/aIo+Zk6C>2/B%Xvgq:(v2Q?I(^F?62GDGV-XiAfHmMJ97[g?0<jh]3r&JLtHebHk{aQQcs(>QEh-@@mQzf*Qy5yd%Wn@lm:u3)O]Faw+[xtp01k{e{7]mKa&$67yYKz<K7?Gw3f+e}J24FROpyUq(obFN%d69&mAvtln27qCIi6n#l&%o)AfVGldQ47T?DzhlD5YMML-#6qRx9I&=3R}kKc@gV#E&LKRIG6RArdEfL
+i02P*JbZ47h5J8jvqbNgUY.FnnRz7s]Faum[xtp0q@KZ(7)Ly{<4>pxpCCP?JmjFYg5.E84955Qjt^#o48Qx^gHV?NJ57^n8Ut:NB:dHLmH:PSfH=mPx-82qX*>Z5GMe{xf?a(Zw?.k%&I<TgWfPdWVTuHdJ4z&A1aub97OhI6q4GlpJ8ui3h2XE%:RA?Q.z&wd7]5lpgz((0jt^$Jf>1+[D9En#-^9mc7Wbv/]wMmQ7-f$^Gd>C6KbMmn]%zjuA]kv9bfnD^Qa{{]AjvS9[CS+FKmOEx39zvt{R}G..O{!lxJ#v(dmO:RTL!5C+4&4rJJG<Dx=XmNFF)TA^hnf{.(c1}6=:wBDcH#pj${:=RG)dg2wfGYHq@)MOml2<x9B5Dd6E]n!b<kevvvA>eNVOzxW?RII^-bwO!SkeGyUDom*pVF5#G}[wb[BhlQP)WawKTvLv>5Od]P^C&5y?ars45oil?IoOqh<sBW}%hr3Y=%4P/Es>d%[jN3v%ajua!)gTnv.gaYO.qjY)2i9-6CNG(tKu(&ah>d%[bM#Ke=72->n7!o:41=5emYYN4c9*/ChRG)cB2wfGYmTokZ7!dW0TL!52+4&4rm/{1pvvvCwK?=}E=]qVf7ktXPjs-x@N^#)Lm{fjvm40bMq5kKs9VwRGx<?<P:G{GoR6@J)AfHN?ZjZhw9hOi7>B>->O!^6=sWHBw4Swlq0Rtj$1XZ7k]wz22+S3b)qT?4EJg#?(v&=RV[H&!(z{i&zgU%H09VyJM=sE&1n:6r[&JBVkD2NN5<#m%c9VMbI8rWY#5P:{&>Pem@ukN[5q]pZH)UhJ.D?6!)0fmMZW6odQ/WEY1gv0sdp^3Erk+dq9.jzCcDgp{tr^t-7OYMk{jtgJh}Ix)Z3*8fJ9I9*<cg&/z4#&ZuD)5c&>.ltkG/OpT.e[^6c#me4[BIjrTP52Xr[p5WkhvsqbWR.[xwO4jb$+kf7=}XC75p3Vt3BT5d]u?q]&OKr01Uki
+y.{Ep&{BQM.3G!4[wpYxar9N]-Xb&:Fdv+NFT9$?^X#?!hrrs}!n^.{(=i3Fd<RHe})3%^Kb7NxZ83ViK2SUQwJRqp(:#LQt1i*-[4X*A)cHzX:H^x+n5z3e1U=omBxv6bn-eSvDHnwtHOn[#(aFgIabjaJVP#xG^uL9u4QxEI]vaNGIjEw5z:=st[JA^t{2/!y)?o[^<nnmRoG#ay=ifyXEwJt6c24-G)?$1g1J:HBI2&4RhLt%@fuCV5Y42S:iAhug[vNa{XdJ<+3h5njGVnj}ZzU}PK&0aFY1(e4{}9HR1&*q(SpuBr[WPn&H<8FM4VQ1Hch[:l=jSnUP%e2nX>7uC=C]S%GQ<r6Uirzi)JG$5aBlVfinWx6-}!E7ho$@USbYLPm2**pbX&&J&>@=Yb%u7malZ(wW1O:}[)b!<:9CY2[T::StSoZZLn]jp9d%s)GrTIWGp(-0WViT!fx{I1*+3qvf:MI?YSWAw.[gyblAYW=-#]F!uf-tX8%2Ql2bcWBaa}vSY^eR7u<]a3FIx^R7Zd<.v?oGLb-Ue#3{aVAf?QBx1JEVQ5e.e/JZm4Yi%Hl<o5ZV<-&@}:7]5vpl0$9.27XH3&UR*rUWXjYQA><=u<]a3FIx^R7Zd<.v?oGLb-Ue#3{aVAf?QBx1JEVQ5e.e/JZm4Yi%Hl<o5ZV<-&@}:7]5vpl0$9.2h%C>kl^*N)ej)DBD$+.7>/S]*Ah6{m$gtB5phJrE*!T%}r:ke>QBRd2*1!b:Us7yf:L-W24r5G9(/z}M/o3rZ^!W#praHjxQucA:zP1ygRa9Y=]sk)Vo^XgNOKyN{RM:(O/<n#5QK^6^ko9m>OK^IGdi]u-5iRfhF:uh2R$Fw[W=l+><r5qcNBfWY(417)LYyC{.Ht#un](-3w#rLun[gJ).VAWJ$5LhJB3:([htPR>m(m7PiXk*H.@IUU/8TrobWp@Mz+#b6Ir<Eo.%]e<py+?}9L/.EQZx+u4jkHDhK*Gq@cXR
+(12R?>1&*m?Z?Tx@Jk>Kt6uGy]TZB7TZgNaI.^XFcelg)O^>qEc2e?oaYld8pL*1LDR8Zv?=(?fD0Mkl.[mQR[CJE-DwqeqP5C9h5s%+Z)]x>=@uKkb{M}&qEsKY{[aP6]5/PS}G}5RvlNpEqzWbZ85]S37ug<I3XwPuy=a[nZmOptLEh7V(?dRT7YPkvG89b.sv*<{<?y4naPVi3axrj[1sQ0k{ahW5LV2oZdgq&#S:L362Dgc%z[idfs-w(]@pVX16[#gIM^[NV*g8ov]xY54!iS<hILyOy=DF@NACSH%YLtdv&[ic}:[dZ&8q$k72GUcu<[nnE8dFL$tF2w#TRB@]kN*4RTKz+/50)AWuI02=IeIkBRAyLmiYWaSxK-WwiWEjgTPgeB-KLi^@7zf*i9RCtMClE2QKN^e:5]&UMbHOF:CO><RJ!AeYR4KZ6ac$pyZ6]1o}V#+jPiLd38VM-}w4*>=o1lzvtqfq5Pi1S#CWzSMF]@csC#%[[:A+?WyqKqhAR1fTUAvhgY+ZDXVt8<RhOqlXX+d[59^Bi$WgmIcsg)}8W(0AkHk>!p>^Jy{apPpMPm#6({C}qA.#65$YC^#[?XPen7BX-B^8Q[14u%@OB!X+<R0/1E70<?r]4b0%.#5+YtyyvI=&FVv/.-l@HKDr[JS4f}BN!paQ<U(yh$!iM1{(WH%3+QEm/3iQ8*&YvuNjAJSEer5E?hi&HwgA=<6zf%apV@>HG82bUl#A]1m)-z4EY]W!ktm8:AV[exomgw%d5Z%YE[.ZdDd1Y8gYCLZom>6tGvYxjier1D=2LMOdU0QveJnb8>&rE/Pp1*ks9Nikne^>(Jk#zbWsf&bszD14$+t!Hm{n/eGNwD-XJ9NE@wHs+{bQPw!fI6}g+NJP.!<?JqT2(uL@FEPATmogutG1-:zfFvD+O@FGvn#.z?*Q@x>mbjc6*CR9bkB<qS?asrD*p97y!bvaWX}7vpUlKycORu{?Up>TbhA9(Y{/
+AwOa3:c4O&J%$?ODkHFthW?L$9oQ:faDkt?/GUGbeo$^EfyJtNa(t06Ab}a%Awy%4F)[WUg92zra?%+b.g)uPp>Nbe9(^C$q3zZMkAOk.J%$&HjQ:mf8?]ox=H-/<5K]+q{g]+-folLqxlUnf:tTI+&gn$rOj%&*fZO?c448rJ:J%uf/Pp1*ym9lofZoisz5A0jKZQee4eEp[3]We=H8Ksy[I5lmJ0q05hZi(}9VsLmDL*OuhW?L?Pbf[t-S38C>h3.dYQ].Bz*o3E4b2lqknN)C6HY>4L0L#{JopUjW?fHfe}=OTlL%wMq9J-*Uw)?VW*rJUIC.Xc/9P(H&?3R5Do}y5@bWA(fucPwtO@k9<7@c3/:Bz)R0(m91i-$m>@XC30q1I>p6bMC95-:<Z]ljCS%d-H[Y}zKJuAS%@[k8*5-UcQhvGXxAxk/M<B{wu^S:XL1lMkc]QU^cFCD8WcXl1^qw^A}Z=o<LPb:V}-6kofRV#xE[LozLSwdcH8RkBrO8wK/7:<T*3pjI-@f8XSvVXqq%72Je7#((Xg7nbRAssKY]$[-L>@Xz=0q1?=Ej6EEu(*oEbV1I@CF=xG7!n&f.?dJK{@ryaH-?9[@XqhJbf0jj7-q9sQyn*^[XO<?]QU=j0Rqx}Ejb25Z=S)Zn-hot?vi%Vu(X9U{y2z[&*fYd0yuMS@4=5IaJv0C3>hPV5DDrVfb$bVjmS4E>h5A2pw>6vXP:0@Z<W#I8]*jZTPaF${?9gQI5#-?F0dIVklf<Cb&83mFz]rrZ6zVsuIF>s???v??x+xU}Cc%X[JM+C^WZI&-YxC{D>88*Z3$iFZ7ZJ[v16M?jmQ5<>9In0b>9$VqEv<l)MUT[{=1YD+<Z6e9i]Ggm^vP[Z?pM%8t@gSEM9kY]^dhA@-Ngwa(!1R=*X7w{JIg@IJ4BA]Tu&X8tw0d}xtd&v*JRkysE.PyPp#>h!dsU8tZ%>Hwg9:eanuOlQe[/D.NQ$Sh%Q-))gIx<gX)K
+4@1kfmTYYZ=YOipz:aaZRb:q8=C8pX??K:ryBMqkz9F(f/c3ZJq6U*c-o0f1Z-oDws#(yVDsaU@xlbRae-B$D-&W-B&1p!:XGv5PLz!V./[m!Lzp!=c<B8?r!RDA&AG7Ks3a[j4>/&1tQ/T8<rqh3(}Vb(K3/8sWUJ+2{i*DFu4QC<15byP4f^y)=[R(n<gbCIXf(&tkf(!u:IlRzOJno%4sFT*@(c034[)qe#@Ma=sP0wgb-mR+c7}S^HgNcjcx$[KPY0KajI[BKf?TQ%L1l0<P7%YD:krEQi]17Hbe-VQ1aoZN[qh%GgQ$SE={/n(aBW]Wa^EGBCy9-l%q6QutI5KG.ovMI?n1@GzZ7Jq^TmqTr<z5/0?rLfwJ+{.4EJJlyX{6yKJu.{@bF5[VCd^:uTR/B<n+sARhLP7Nq6U]>33Sj&R5!%vbX/Z?a(K49AyK>8QhOru+%(!hDrW$=5]QgM4@)[MOwVw]:0kEy8nscZ!m.0x.uA8ZshRA-MNvt-o{B2SyZUC=ns7jwR:wV#BJV:yll(s-J2!azxek-+i)ai!]L9p[=!r2AloU!Ayx!Dlo5tJ(gRJoeffC-BTJwWuUfO%gv(>+Yz[BTv0[Sy{B0Z=QDe3q425d]QhXiHubI%&fq6R]+43IN!!-:b*sDhK5[lIcxY/aKPEM$5]y6.qY4<J.DVZjM@JAmv(bnbsk*n5mt}Up148QPT(^1]zvll(tpH+)*3:J5ZHce(AQ&)cF0uB$%78OVD[@rHtngOR7sApJ:zv6ZC[e-:NW@rcLAYIuS2n:4RFHvi8cOoS:x[[ZYMl:#-$cNvEJ==kB]bX/.a<f$>g4R:NK@YGnc<]}L?bL)sWVMYl(I5K^JwEb=TUr<rID0^tV8N1[ThZFtY{=4.pz1$CG<K]v*hSN[CUsXN={&*g?@tk3fI5JZcL&$DHDR12^vvxHp:9ZQ#:[Ve&*V}Ag27zhAm/A{$D$C!D2>-78]P7e9bL&=U.$A)Q!VV#[
+Q1ZuMk9KuH@SO[z}HP.j=Xrj&^AWgsyTPARyq*9L.KR@$BD3blvTGDR^F!=NvlrfXv?So!6LvMT-LFeL&$&2.T6XjQ*jeSDy!3M%y:1+!g4OG#W4fBbBD3bJ9!>WW=LT9=OIx*Y.=FqN0[@YUh09at[{pKq3{c}If(fuwv&O2RRlNUi4dhM!QHSxV{B$}JDb^6e)tM3kvu!:lT&1/Ss@[XeF3sYp:h*5>)pZc>Qx])J({-!a.Qj5bHRO8pG=1#(jy^37Ce=I4fF@=N7-vegu(*sk@%67LJ/^@.*Tjp-cLq9MsaY$XC0+usg4DYp7<p<2w7V/+-Ye@ig}<rQlBK=/Kb/t!-LX]&<qC<UV[]Z&GRv{ovEgp<T9F/C@2X6gj4t!@y!3M%OjankP9V:l)A0LEw8%Xq7<p<2w7V/+-(GtjJJS/KR0Q1:QHSw2DkH*:jy^37Ce=H%fF{GAJioQwi//q-UJ]SHg4Hoijk7OjjI>+u<mM=hAa:zp9v6E*.)0Ui8n*T8VK#2pWd/&:5=A]{EiVrvRRc=Ouj=L6>Kr+c5hH2*8q$JYH9OMU*FmsO-2btuZ>=>B>A8ye.@?f!jkYA+}/r7)JmJfx@KBTLx]RChXj%G0ZZP(Q=i&0zZmLIKi!Rip(VmOVc=NM.4JDw}<18$nBnh*VEDL})fWz7tgG[z(]xWr?BqywI4c72p!n$AeaBSCIs?}adM$AY?3Z/ENJm8vPs?(Z^bssRHpyCP{ZmL.Q^*sGZ![k6Q]CBv}Nq-]}JfzsV=/77t]QUDG0Ro<sg]6.TZ=TS%d191)x[*QE8r%kft-=X>@uL=KawsN{P9ivBf?gz>u{(@jQSuoT&Ih1%O:TP1]bbt3QXeSxjsn5@Eh[jfHh!QV(n:l9KIpt+c$18=M$AY?3yDQ/7]rpB5-2bJbTT.I4?c)gZ8)Zagk6vh(QclB27FB39%%m7vEcvoDjGxSR0&W}5sAtbRwVGD1st8=+{W#.JoD23mm6v8HQP?J
+=I8:q84-U.T[I&y8#N@eY:{7=HoTrplP{h-n=3:beQiRHqc#65xUsPr6e]Tu=H@?(>q511b%iue.^u7k=I75G@fx3NxzT2-EOoHX^7G:bSC?2wxs(:ZI22tb]Zm!njKrlomSZI9?%zre=feD9>74g?EI3NnxH^zZ=XQA)m=xv[BvS}XZIP0CjkaLi}L/cy2wcx)0B{jo361dX&lH}M?FtQGJK2&4?hWARXPqbxWo0{}^qz54T/O$a?7O8(5<m>YjdDAI.<B+$1g]FqB]IsT[{Mz5WWcGqm0DjbJwRW1R*rlheOcR{:Xg?mC)$pCAqfjxKpsQI^r{s{YZnl>iQZ.mg?%a01g!7v?md?%!@M{%r.vPzQC2?y@N=q9ky@*GIA4c]Ds4*)wbPpipN9=Icr<9S9O]?W}bX^ebne/okoaW55A=aR6Y]K>YwcAXqB#6lsodeIceF@oa?j=w^6VZbSu8#zGb)0Rj#n%on6tKSYJh$C<R8e5=C8glreATvrhPqMw6#.{+QpBbwm$+&4Evo(*T5a+(qAaAW]3C@z%HxL.%Kzm+by9IcaW4N4T=2=B@>eb9wLq^v)7[*uSpKGj%H%hB4]cz}I(]*5Vg+.)d*y=YYngUn@/JtJ?oacPhgGt?})zRB.]:Z[:*OjX1h&$XC**}kHfMi.J7m31aBX0=:9U4pDK1?s9y.a>H>y=yAbN+mJKTuP8i?996.:^WRIA*o5/R>-&@+mJ8pkd=OHsyt4-!SidvCNy$yZTL/ia-Ja6uH8kwEqPjXO!WAYQnAR}}e/SGwpd2E0E8n*T1gE:}QkHggPyN)gL&}N/sUWcNNq4*{}gLUp2xn&0kEX5og/e^Aa-prCTvy$8dQA>>(xaiL5=km<E@5!FRV7qB1Yvclvt{IBVj#[6QiETdLEM/=vj#[6QiEZ#4idvCd{}bqJ]sB{bOA5=&PbD}anQgfC+i/01)Y#JC>qs?kkD<zx:)[#q{vm-owBdR>P0pHwgVNM{t#APO
+=XTyIMZkONfs2vg@zeSQ^z33+Um^5kb-R/fjFR@y-opLNQRCwOEmVx*PcEhTZFn)KKIP?k@0/vW0.CuwuM!(d@Tg2!<O{go7ElN#a{y70I!.[:HEvd3%b:hb%1YAH@wyUXhz+hB{Zgzj}2q+QMpCqLw@7vQP=):4/bAkb]SpVZJSIc:o)MT{zL)oeLb../{fm4:rz-x[bX<o)4C#7[@dL+v!n8:%AO?h$8G56>Qg2}ON4W<7@$l+m!l^.X:QE>nf<-Ltu4x]PtoHyqCs(LoZx0.ZhaS6w]}/ps4d<v(*o#C8-WFTm8*du}g*ZmKzJ.WHbyp}g{7UQSVJH6pPU8=KqE1HZk!Lj@HX%qh@X&?QDgHS&klXPaC9IG8o{P2M<x)3^s/htO-/s7wOZwLCNuQ$i0>EoIl49JN2tj(QQ]jedw=dwbSc#ajs1=627hpJHYP]E%N8dUr3}D.%63Z=Ex$dlwj#3^Gb5J}{t5SlnOh7N6KAB7[6P3cF[AVQA&oA=F1I:HYB</=%?CD!5Lpp%T>zg>oBhVD1Lfdy@q<X/HkNwC:21^qRw3wuwf[@qBlpa:mu16W19wJpoMQ{rKf*:>uf?9ut=s8tCf$ckG.hYvpBj:><Jb:UfQXx1vJfwiTCMKE5:/SN-gLN[=oAUV[aFyPBe=FG(t$T%oJgQgE6=uy1)/X.?o/A*QI&2IhIomQ-<XXKbSP6BaWd*2w-.r=ded29r/O12B{*:Ko=?b8Xx.2Pqx+AEo+OBAQCjjVk{@!Wn>7)Dze>?S3]Axr}7(+]h2RW7a:0478)3yBm>a=<eNrkf%Lqvv1sCVzUgCMfdElZbO53a3}!v(<8=knD1DxvXYGUj6e[57w><jb3+ctVs3Oj2L)k0y6pqAq*s2]$-wl7=W3*m%DW*@CIq}@wa3!P^SQ*5#Hz&Jy4o.eP86Zfi&[40Om)D/}Kj{u*v+abvGHvxQ$[JAo:3OaF]*=IjIK>Sm}J.<HoSwB4h^n*}Ez9wMbq
+XoIAyDkp?{aL(yuP>Q8*NTXfMCeR-:fcXVQdnslh7-/EFJU(OedVbE9MG5#F!7-<$AA6@Qa6r@VlQ+Bz7[f)}>Z5>yEf+9Co7@Um}.s@A6LP=PoGPb^fH(KC]eT$!2gssIH[LU)P)QhGl/ir0ox.h9&Qm]^MG[i>Q#zgGF:cs%?7!]/[U9CIh)(jzm#N*3jHj$%^/hb:*$d30@tI>m3y-t75&yi@*]-f/Q!W={XHIk2jc+sJn%dIvz%VMsMV/hy-^VpP82IkfFIDH<0(=S.7ZBD7*cJa2=m)yaa7Do0Hx>&6qWBqoemxJIOP<ge7OLmS27qCIi2&$R.+IH=J3wU?2<-@@<h$kLAszE]-VKddZ.?kA0B}uw42)EY-UNt4x=4OTK1<yW.VKA3SrweSKUwS>bP8UWh(*wS41j7O3lg97.+IH=Jll*E[U:h.x1Jc]<8<G{J6ChrFIDH<0(^iLfF@=582XpBV0*Q:ke%JhHx>&6qWBo=Gd]dYeeQfWsg8Fb4sKgj6aLLivw:.(mLhH7{:^m@zmv2-!px*6S0!v=-&@wF7]6o6x-6>AkhdYm.-eFe7:BfdY[Z3]5?(^+.Qe8Hu@TR>@F6?U>WPOyvF1gvjc+sJNI9QzA89[G7-9*tit:D&?@5:{7-g9GFh5V1JeMTm=(r[E2&>y2-#a*!mT@B/6VQb}-5EX%byXIKJ>feBK[a<QxX4ZZO6/0AXB?t1ZG(BDh4MCuom(!w}]$kiD60GtYV8?4i5ZLE**b.mJhBz2:o%{o^S+7=0)DNO]RpF20P(*92lduO6K.&r:?-UFJF3>e}/SrnLox/L}kg@z5[yAH3A1fES80)I9iHxV[7IW*+MZxfqsYY$/=Xq)?XY92HeA<GoGlocnz**ZadfD*]RpHk1g9ff.m@g7a}bYG985tZ5V1)HUlAd]aPi.:tenvvg5fvLFY/:)ao@L&PaN)/*rP+h!GB/s}3ZUiJwpivZ-WzfMc3<}.<b(DuhFmmVR3sd
+L(8AmTp/4jklD].HeB6MoGloinz**Z8jlXv]RpE$1g7):.m}-Bf:%*tiHB=}E2#6TZALv)57lJ4UlW*Br562Pml1-[-/Ys&.R(.(]J?vQFQ67XNNot=v6VL+8t}W}5YlrBL7K6@+lEjy{kYt*iL)RdFfPS!JW=7c2YI4ef:%*liHB=}h0a9gxm^C8jCUXdR*?&SVzipq5Tm$]Hgcq<2nmPlQp^5QLU-o=}X2+!7VV^y0MyF#k{%PJmCGjHEji9EIh6Yul.a/6W?HIPonImwp7s4IXTA3YFd)NPwGYpPAxSpc=&.M6O-$/U@!O4]VPfR&YIoESDC!#$Kg1A.cL1sjJTfAZpN}Z{u}]i]4tah?kUtVJ@KZE8rsO$n0O.eI6KTVthIXW<s-1VC1l%}L!>g5fSM>O5nQeWn(qCVMQ4Bo*&vDWm:#6Jq)m#+4bM5xd/l]7knrSl1i6lJU+4.)U@iDq!]x=<6A}/?5h+H-THnJG})k^EY9R>dHt3C=Bokb=&qr4#zq:2ny1)41A/u5OFqYnc>0XkKrFBEb2Sq/M#t(Id?u3Tx4RuQ}KS@Z=:h]e}s93v}U:Svrykp{<I)f5bOGGhhmXgr9uoRO2:MpQ(meZ(^aAjNcC?x-^$!PA}j8x}@Hep:q}&iojG/d^2h1A]UdITInbC(a3QtbyTqS96(=/EpnsVA)GFP6MP1d9z1G2kjv65&{[{]qWgtAgegDcX$=eu9fBG+lZIa7&52jVlTJ}p:VBie9T9^)@Y)$qMbHo>jx!QJpLX?(pCVNfnFD43d$lmzcdqbH6Vy-U11Px[x:.=9uJH&SkDKG2SE{vj2*E?q3R>HbY[1Dlv3*&Z]/L5Hx!nEM^BXS.*+hLe=tR]jMUGJ2#rOdX]5XsqW//A>IheM>vonIExG4+Bv:+tYp2[*I8buOQ4ERfK=e<?Eh/3m[A)hK:8.XO=*aJX{S.6@teE[v]&4lKhmpIR?[*xDhmiuQQk4mhaK+*LxL/z@Gm*2j
+8[FB7Gj+BCh)^-@OtBz]CTDl]-96W%5]REJF&DAPrPjE&?4lFNTY=&uOGLyga3f={uZI+nLJl5GX1m&H{cub77i-.Vz{&jqiq4>2[91zOW*cl$NYXsux&0*OmDJ=?[#elZ!aDjXW^awbQj0njz?3ZqlWja$9{wp.5UQ{ZkxjXwExHy6snDBd8gYE7aS?#6Q4GE=jn[X[0r9D@?3+G<E>L[/lQ5Eh0@KifzIB)7]-JPuQPkqp)Bhq9?Jt(vLg>9]]50akiHXV6<jcyV7j0}%?USzz6xHl8C@p5-np/-EYOajBbkR7h]HQ82cp}(:ORPFew58S[8swj1<32F*A5A9knwT%2^*gd4EHy)CmS72?yp)pU([<so7{^/PDP6@]680rZ/7=^11QsIc67d9s4$iQsElRmzoxXyI0p*[)*/$1rX}6TIO.L]WBt5k-^{]o@U+5*}}*dy$fkl<WFJ1zf72]HS+$D0l)YFe-lIJDU9]FI{7{.]zK2t/)Q}ETboUH@1z:tqjZ%4F44E#>jg:V$FR+leb&MFaD){XrF=+TKoi<u2ZKd)-D-=1?0JfzS8.$oyl9q115:b>^2[>HsK6#lvE+N8SV8HxzH&NaDBc3ki2?M-(27<7*>uEHXIDwk7q*jLy{boO6KmzrYx6UZ%Bxe2TSucM?Mu#*066/gzzN[Nj2smUvNaKHID36+BxuWhZV-&DeXLl<][0<:WSi^bRdq67dB)][ipR#VfS-xo!l{n5z{IOq^3YvPV3.Qn&CV0qQntr(-cf>1i5OV({Fvec!okd5t0U:x7Pyj8fJoeo{Ecm<JiGYPHI.M}z)+z^vdI.!^Qr(/@<s<hLga-]*F9s(4KN%V^8syEz.<Q%-IT*<MlN$OZOzDubt<yP.e:4ZExFlOL=+{}{fy&U.vGVRGsuVyaI6pESPd^D8(ua4[W{E.7lb<7I2yJ+]etAWViAB{ZVyUk?-OfZnSxE3B*nH7(@WRTR(6uMM2>oRErwx6+QifCf1
+{rCUypI7OnglByy>8u6jaV2cFL9!^-6+:fPh8<5vI-3HnSG{Wg-Z]U*gl6}.Rv+h4</p@peb)YuJ(O&AtanhECh3.^?b^JQlzGzhJMZNidX:W>s/$3=e21N@nyRr[cb9:=d[<w7W<+0*C-Qc4uI{}Z6Z4w)o5><SVC%+Xv@a5wx)^H8g{GmrXhQl!e1[a$+#QX#Cx^46P3)L>O6[W0JhFHRl^wS04IzJ+y??w5Ksr)R&W!xklz=Pg5OHvt6F=JHIA1nXT=K#aCF>wE(&A7kmb-C8H3KrOmq[Tiy@jW>.[x+R^$-4#L+{a{jF?pl^<7vg]UUQw7Xmi}]7n?eo*qq+]TxDmAz>ns0mI4w.U0P1f-tX).@nB1AR!d>:FH/*v4uhPoL@.s0J]dh)V0TS!j59pnnzd)a</3=}4Orb[p#siRVS!/.:d{Sn[gw#/PY$Z[@xEr9Gx$:S6nl#}&g(eX@Onc<Sfb?OHK2L:#Z8$zx.}DQPfK8:a@6(vLQU(cEF6$J:KP%SMrbdAVi]<5I2U[}wO=}@1Y.nn>-8#n&8Wdg{.+Tq<].d?:Vmt344Yxx#6Ulx#*h0X5$hOtUOceO4!w>wd^[N2J[CFEa4@[BEKtvZoBgla[$VNR76U4&lsSn-KDH&]fXsvlERnE7)w/eVLkgHWTg2eu2CxbLrTV>W&*UFqi&3RGLqK8dAX=W@(/#H*v^nd(!N.M9l[%w)1?fJdX=+4&}CA+RAVPU>xM?VuC+-QoS#NT^>&uY}kkz8m8Agj*?BNhQLV#Q=WxbA4c.4b?q+j6kghIjY2Ngw(euv4pGSUdAFfVtn]1i^q{sG4@]ZW!(c(m%k@ez(q(}C.Y]$JN*rAyXenNvSLV@{l3n/C}l^}Kog}*eNcYGB2DEGx=>$I?fDufb7JKJzz!6arrh1NO6=aSvoBi)7t?ztGg6n<L?(BI*uTTU[FOrzIsl@E8AX]ppUevYP7M4W+aRCn!WkW:niJw)YY^(D*Roo4LsWAqr)
+tR1!aN&lfiw.g(h}NkU2=xUpI@^6A)lz#$GWeZ#RlcBQ!7}#cfswuBSr+5/gyZOYfrm4Hos^RU!l%TCZQG[[sYQ=#o.Gp2IDm6zc]Ic*w(WqKDR)4QHxppO#[UPb*Y^V]R:AO6x3V.?9][C8<lIh^r3t0czov{wedo7/ns?jS/oRzUm=E9dKwc$ghAyK3#0MFJwJq0@@YA!8-SqNpuUj0Wpz=rPyQ!x@zBg*{@&wU>TM?K?kG65Bc@2F[SFOj6ZnMd3ihWWq}VU>Z/}&mH41y1C!D/lX=CR4k/Rw?BEX^@r7d2-8t[PP}Be4vG>8I+On6-Kcj?.z70U[x*9-ZxGd<CMak^ju&Zl4{T]Y72)7bA(*G-vHc5Zsef[jK@RpytS:+>&tBiQlZ0s]7F#]VNLt]oGw6i=MP>Y4$e*H}S4*M(O:D=HD:x[lHfG!e<IR*Lm4Xv0J2)vb(w>6u5WUx<331jSkUb<h3O=HalgJ}Vv<^B7/Y0-8eU&l2>V^:+bbq2^:WCS:GJCfR?G!82.a/fgsA&qX>OmYkD#o8]m=NDzY$c!kzA*(./Jgja74aC^uZ?l&#I:q:02j&VBb!4QoSR.Q?}LHZJd/]U7$ph}MnzTN&cDvT]OeKdcAD![6stSNQ*PU<i%m!DeJjiX!*F4DdoL3Lnty6Ywz1UY*Q%o/-]%xWwWO-Ps>Vz{e.Zr0:iN[[5NV(Tb$@gX{GC4puh3tG/pntiNM1HcD:=9+kSmrr@QX@jty<SfY97D8]u#TfG0fjIHlj=])psl.*yz1*XI[@Q3)AJ}3LKXnt0]/:=EPg??Lg/KmrSV?vC.gbymTh^013ma8i/11.IQ^Gu]UMq.4n0&1W[z?y*1Po#0}^[H</)}UBpu{A/GlYk<as]Xw2.ObS7u-W!^z}$-}(pFpG:[#tVHa5O4uqE^f^HH5C#l3qu$XL(eR:=Z^zRJ009qlGz}g4{9}/q:H5L<s4&N:mQ*@$/B@eOK+2!dqhFZ9PS#D&P.a
+m^6p-QC3uTPmD}K^.nxmO1t(u3BN7dO{rG(MR{aecrKNx16F&y]l>R:cB-/1BJSyMi5QqTaBp-JEH42bG.!te@%^FzC5nUpRrcI^2?@lM71fLCae:7Tum!!/d:7aqugTd2YK5!DY^#{F/{=I}OOfDkP}@-7&h/MrA#mWkWdoGk@Zfld&w7h@b*GpNSlqs-](VJa.NqSnR/iYoLBJ9?Qhx&s]:r].o8DQk:x5NWzr#Re^^0*3z^=t&Ed6S7!Kb^apXPkG)>h(VNZ?E[2n7RwR6*W^L*E3R6hAnDd@oQ^lI^n9!U(n}j3B!CA?w{uN^}Pqz*h}#kdvv^^}=nx]T^seR&JC#?NOqx0vI[t1M&$MhQv>Xuu!J}jT1^Qxg8bY^Mm=Dxg5(!NjNsE]v=YmHJl.kL75pM*&u+BBH*<J@n3HQPz[rw7<y-]P=^Ib*W<1[uIy5&4n2s>{eFlW!odZ?OnHFy3V^R2Uwrybbi%WIdl+e@MM7XL2FD%zp#C*f*ae9I.Owa[xT<Q^(UAJ?hlQv?%f.K9hZ0CRPtY?lAxcM{4!:d]1i68Z+FAOe)ScavzssBDk}tJJiK&l+-]R:z6XzDZ>99kOx.e-?a.%WxVLq%Cr6ON8EL+x.2:UU<5T[Rw3vL#O9OBR8d)dO(3eCZYd)xp#/&yC>kwy52]*km/Ua&jo@aZlqt6r6d(r/nm8I$Q8k7@V%=0!>/JPWKiCnnvAP1}>u8Hkq2qpVbSP4l?6s!jh>vj}zV?3?+oW.v^@y(x#Jjm@c$YIN6tfx5h6=.l>G/r5/w7TM=PaM5v}X@so!ZS^N>{+0!m6u@p7CnY6R-DJi8]#/sYKYN6q9g8^?P$r1BKYM8Fe*Ex>Q*lCHQiF+ryGfgQw!8u&}fgBJ<uUg&dA]6t=(xeY+P5j5Bb1#siCj@T)5M@y>a7LXr&sy^T=d]=/vxv@wHf5}rH]JYBc{HLt9wHc)ran?cGi:*c[+7&8X]q5W-+1y&mQMQCkpn@Fb:oV
+s4WM#yjj>0QPoiq>+>VX><o2dkIF+Kk6?#L>C9k[XlDA))EX7<QvV!a65C-^]tn9=EM(D^ADgOJO@-2a&QZ9wK8w*>+jwG1W&k2f28].6&KPXv^S?sD*8Ny6?KSEGOkQsF({XH4kh3@@O@W7i)H)/n}>fOPk+sMDP1VRl=po-$kLca*RW+#?4Wp{5UhY8s>Q{iI5aEBrIl&OJUy#HAN%jOzC!*}r(J2M0QK$oV]PO}6Y$3WH]u?]TYdrD>}l[}*rR]*7[dT]9iV*lp7(vic/1JvPOCcinJ?Vl$C+73pRY5oP:0bBO<?l-CywZ6Xd++?P*&AL[{Z^+{G+C=5K4$^Qj#%0&Hc3pGXkb<y*@XGD--]tN)Swvx3].98CEvngBp4c9gL9!6[uTmLmt*JtvSPLm=$vB4}h!(l&xkLorOhDI>b(w+G)9}UGL]mGB6d16jadHy&N(Dk1:C+b[eKv!.@!q/0(1(%tp1xxE*AvmS2}IfE1)q!1f4/m}-Y%0:{=v[Y{tv])Xj.$Q4%G$N)K<$j9O^jm:(4hVk&?0>p38LE8LWlm@]6cofwafq&(jom9^971AwPLfrULsK[u48!4kW))##b#I*aONczvSg>[%W*.{FA.:gW>?/LYNV*TPJ6DHVgK!#j4lVA5hoPk{CE^j5cyn)**:O$WHxDxS5jsQFIgDcD:#aj2z0Xxz[U/^&+6ypNNcsU3i3TT7EW{)M*0o5SZmN}Z3d1U7Tl.oZ0:=}FE:/#r?B[8zO/E]iIfh.?oTcBH^/W.j2}yH(fDWvupf?nObDCk+^WsYh[rYJC>gFAOet*B?t!w:u%MVN0bu{I9oy[:u4YC-aPr:/XItZCfw{k9wP-Cb:-K:>?d56Ir>$UrpSz(EBB<wv^$brQ^9zfr.9.!Xlplh*)W&<?eQ5?ELWzrKd5oUcMa9.^jA!(p>6un:8Dg[k=zlX6PlTr=rq8N(rIaMXsLj*vTE-KN@b9kn4dQy)]rQa>4ah=@^)MG?LoU
+8wEx9d]e%HB}c2#pWx64QK]4/.dNn3aV!gS9nP}nDH3C&vhBQe^E:j@(9xLB56f3xvTkPj]Rr(hy//YRgC:y9]Rr>if50/ik&9sA*Mej9N>QbQA33:*/9?r$3ET<fvkZpkK8Z*K-!DXL*Gc.+I+M#-tgavt}}#y13c$/y(b=iO)U2$SfM<VIL*LSf>V+.OOW/M=LoGYNf&KM!V$<}vU=H3zq#m6x6LBkqtw3zG[7*[lgGPMd?IjoUN5hm>xNjXvo&5+N=tUcNanr)lGGzGpOX*mCIeYxJW{V-0PQ[58Nl]w^x#1r*+xg*zcHqR6jSNE>sRycxU{)Rc>^fJgo)9Rox#Kt#D0h{kA{Fuo)v2uGgG?=w{e#cx5oUFD:Wj}gmey5N3qr([e)ib$w1ku6hvVh%bv:[{MyX#T}&+.zUO==JqVP76!BZ+Kj8ilFIirtQ>N#/GL>Yq&m[SRrQ]8+?HQAYwbt4t!=]&p[o+gC{zrFq9x8glx65hwz+=9>t+-6C$CX=#6:={hBRuAv!sFT*@(c034[&KV4bdQDS/p9zKUbm(DRC3!?q{}H<(SAkY2ZKPrD[&GjCFSJj7m}C/pfWQ>P7z8-xE]n3G7uf5s5Dbl:tYS@nuMR%U4QwexH]pt/XOa%e%+NQHn4@2=@t)Dmt6-XYp}QM:Rto1]+v}LJYze(w!-NVd(i}#5UlE}dB3dR<V7!Wlrf]PM4d)Ap2:V/sukxra=py]Zs%XM)wFN:VUOCX73fs=xrvbkW#)St<2RO5WUN>JyOK*%t:9N1RLzmJ6}Unr!-2*C=/ah&-nJj^{^#Ku5@iWTB[&KyL4#1]&{.8)6WL+7t8y25&AvYbWgg.WA=5Zj[9).{q>/^>pkMnjgFV^>Em=sz>gUNr:<+0<NhRTUo]VeH}yonJ!m+d()zFqD)aIWL/mD:9xqLD87{n&RUY7SPCB.{/c3.UXLH-DRIc.ADDG}]qZA>&i^ThGT{0Sr(n&VGU2PTZL}QB?.lz!69
+Dq?2Za::rjt{t$1SI&+2E%s[%E%s[%+oPa-!9#DQ71qT!g+EbA%4dDJ?T@.s^Y7[]5&#zJ2kR-Ns%Wf!U3r{C/@yYIh*XG9tK?Us-9..5WjRB*f:C2).S:]-ijhgs/m*RaCC7Dz}8}a9X8u}a2J+:$*bxa7Vp<bMn)q<t@OVO%VPrJD1ML<XZt^$9xu@&ifmXl]:>?@rY+I=sIE.D!7Y7h9YEJ!RtZs7HYVG5^@5)<PhSO!4]7w8e1?w6Ifl>Tyi?2B60[>OT)0qI0Dr#2w6R1x#E8u6XoDsB-1NojK)Xm7pv::%s*cd*fJjAAD(Q83khdWw{Zs0[gekE82B9Uxb*{n+{mJ5^n8oerTQRlkEb<x2cmXb0cp]s/3tla[KB[VtUun{}B/%/61JFb]z/P=4eDx64>e$>9.Me[#zDF%o!&OKjs^Y=Fr+ttZLm3*$%NT3UxUGbcyCKD@SpvSU$9FOiM/p*BVU0ZRhLfdy@q}PJKV.1-4!f6P2o:)uK^.[#6m8C^Svuy9e-bK]M36$]!nC{[?o!d*Kd2zr17(ZHFh+8![cDmmP^+Mqvob3s%R{^]=4N7!KN#f}kSN#C((pBy]}pZ<2&*7}nSMPxj)y#uiy3ciF[8HOGXc#q*+yIIOC(C6HMNlJLS>ReE.KDzQh3Z!UZ#n#nHI7}(s+PQ^4OP4qy}L$f<2]KcE}I8iotWJZiE2gj:km^fi$(-)otWJZXZiFH}8hVYyG1MeIP!PF^Ly5^seSHrg?1<rleC3yujQJj}VcikRtsB<)w]^5(GmU3no(pz?Qbl[T3Lyzhwk?[pBVPLf5+l%bJ$sLHcyFO.*I%?XkfmIt-Ew1u(<n*67dmE77so+ZTS@Hp1TOg7HOkg6Z{Lq.)VT(j7krsy(IJB^V.E}lFRc.J*YjafaRNR^#ZWFW%T?*-e-@=@<C>AQXgY[=Q7z?^RRIBs^[JoRw2!K0DH<8bgpOgg<hM:j)@Qo(Q5}{gcI?X(RR=cQmwz-?Q!B?
+kA$[++b+5U?0QTAuCx]>]a4wRze&ied/g)W)wW?RH1$$VB)adnBBsc{)#%)+a?%I{Es?3soZa-f*d@1CgSU0*/ytuWg[QKVzY]1F{ZK@@W67bgs18%)Z%EqY/qOCeC>VQ>/%:}}?Y&PZ)*&yj8VMOxd>IFbn3}<>&&Y}sItC7+KHltiksr.A@ti[=[P]S5x6xmpO>nOUFZU#jH{m=0ayCR&A!l2r^nZT5<64p/AS7Dqh&NZdK1{pXHY6t]190]ow0ws018}nUlbkEwfV(T5v&QD[6bY:vt/jt]wEmxdtO*lE7(5:zvBi4iHVraO-&%k57#[f7^0imT&B(QsmhT6ga7KKc499PfDD<qGjDe&:Av!m^i35{S5K(@/v*{5<PN}@)/u*8Dvn[GXJN}$idGOMP.z&Cf7#{<@:R<c*Z@WE?ggs>]y5awJZ=mrcpkq3M{*-myJpA*Y:R<aE.Qe6l][<q*OjKNFPPp?c2tSLYh3bdrJ10OI{{lj90]>&Gi2?YhQH@DBJpD8D:R*&?vq73?@??UdViWin]F5?>Z@WE?uF%[6qj/K0@*-)Z17mS^80sjcv(aQ:Og$]=pr<Yo2$9+0+L%y1-^9mc7Wbv/]8*vMJ0/Xmxn&ZvQ45@YE>y/xG[O$$>WPN^1Y0tW7y9ZX7)+!*-Y89vgZuGa.Uuj+f#<k3Oszq^4v)ut&*Y@NOv&+vn8la?{a<.n1*tuaMQxRa*<km1?.4i.NG}mfb%/QNUlVaGgVSzkik$:eZ#o0j1b+MPr%xdk<nL2$-B]QqI[B1c+6NdDA4.mOmtftrAjzkm]cht3B(mi@T}fIaQCjD>C&uVbW}jGpN/Y]5vyuH.zC%}3P)V>G9iNrOmX0@q2sX!Gt+yN[6fa^!Z?=o6TPKvk7)>:XuV$vDhlE+P6R=A$yj1]yL>S[E&NOlCznH95inBwCTweIh0/9*@jRsOe8nKt=.wOQjY1)pQ0+J=/ulqeZtenvTD#e$vT8V@cRX<4DQPba]
+GyW>gins{IrWC%e1bAuD+=l]@ROIe9tS{1OmRb$b[XQLu[6cB3jP?6K2>Kv$&HQ7]dWozMZ#o3k9Mfx@mpo9=.waJ*zgPQb49Rs%D3!E*d*99<<.CU(t-C!zik)oRJn}P<DcgF7&bygF]lwdIskX3F0}QVV{Wt]RyfRLl6YR.(9pnDvQ}DZl9bZ4f9.&2C1}Fa8fPS8UClFET<7Y-jg0fM)Oei!JDd@Ml{EFXTbH0+4HKgp2+tC$-FY33Fko&]Tn^18@fi<$pB{]vdsZSpsN7m^=eEP^Pc%@)/&C8Q(-3[e8vc)ikohTM>FX%5G+Zx/7ow<+Z19m?(/emJS8DNU[N@BvUvR?uz{.N9s>[VnlqO.6sa.HF:&G:Mcx3eZIAju*MFJe1%]:C8io{s1z/bU9l)xHpn=yGp!phYWmEU0ZE}MWd!]BCh#EL*je5ifF7<qO{}/<cB892b{U*cwm?ib[B[NY>MYXCq}fO9Y1E:U*=Rvts$XzGBCLp[FYir?:VXP[eXS{q:*BBQX.G*@.GfAiZ6PU2cP.4>vRiZ@:R[X:z*/n0mW[&n!yETM6yg{SJyn=9]56)WnI&}I<uV}^&wYf(V:Q/obIbQ{WQ@WuL&j&mqCgkT9.[zv@]Yxyd-uAbe7-xKb0H]Ys6kr-5*=61E<P.mPn]DRB$8DrgiIchMlL7iW95(Ie-w<ZiOK+lSfn&bz-$A#x6MHmPX$CRODN5JFOSl*>VoDP#Qp485JJ0Rv]yHGbwaHrn6CV0fS218XZgQIZVQRRW+Cm7Tw/tEpD:e9mJ=*G}Y>5Y]C7*JF(x6-4{}fgU(ZDhq:TT//1yxp*=$EfvnWC#o1h+}RMO)aNJ=^x/gS))+Gu4:4]:&F4S&LALCV=Jn?Y43XZeDdsh9u%@$%5U(gEmOs6l[ZZGAm-#.@g/AfUqkrkE5&A>H2atDDQVH)8V^D60BCUW<.-Gd2x.:s1s?o8QYWupmH(jv+Hk?H6jmO5+MGwA&Zn&o8.kAxp
+Q>OaA&Z=#}!lsLiqxlwqb+WCBxvpw<!v7{k@I.w!m*Yc[Yp%l8a}FP()1U8f^nFCom<257TV+7CWZAI8Ao{L{9:VvO7V{6/p1A39F9L[=@Dle0ViQy5GXPtef**v{Q]/MZB=>lINk8M{E=CR%u&4ihm?>/{z!US7nuoNjJH2j^u69Xo:P>=w?H>-OSxP^E{sjAXWkHnqaRD@]ROtJUcFHPq-Z[@m]ZR[[P2A]$lzJS<(xtY?:9e6^{XJ$A*=YP)&P-xYq9}5t1wyd:*)XDkX&o.CpR8@59K!YrDgx{>[h6d)L:]&dzug.gQ3}zGjrKE*[*M5=h%}@Rx#>j9:N.$M^$8pE:ONu:43*PHctFw[TGg^F^96f31e702]Ql&kz!2D5rb&Nek4gBvQ+Tq(c-Ig<Et1w0.GE!{lS4]tC1Tk#IbqZVGk$2ZddERd@!b%0)H*jRxv@UP9^$19!WI$)1Z>Xvq1>E%qI>m.uwv{[5>XP?^U9=gL^yMw(18Pl]J3]P2{[yNc$#CfLaCSvo4{BKw$Hn/^=rEv<t$[CObQ1xR[py&9cQ@TPye4+!R6$ItS7GujhL1zM?YW>mHre{)yl[ceuSe0UA&rhVyl#s!.KTS]*B&XsetE66sa5TQ.Y82yw#z}5Ow7[ztRIQ@WTZcxJ.G6t#:o}U8}>:(xRc[C%UKBVyl)9GSHEts?N.)7GJg+:M/]{r=:c*r<O&r=m1@Mr/WI0Vym>@7lbyp>afUqC-X3qz5)!oHYcGrsaOCe9u}?jIoKL6J(El@]4Ej9s6?v!BMJe%GD1?mf(Da=<7ot!zTFa%<@=xFJgkED2/QdyB(d>[T!95MlC*T4)EjP$Cz<b&xS(4/s?4DsT7aD(E}3^f}N]v)R#r$r:lUuvtF!<ug<<euKa#QKrcw-l&ceSaU5&XNH$rU0D]+beArSOJ66.5Y-Un7$s2p!JY34$lY-Vk$FxWUJ]g*F<+SyW5CkdAwR.B>5K+!B*naX(*m86WKo.v.R
+vj7?lPO[UQ=Q9Cpft}28?H#I?YgZb>]^$<X{D$EE(T6}BaaMr}rrE!bFjOikb2]KC7>*pcbWVQS4?X+!nTy6V<@//X&X$>R-9YU090Co&Z{p>gTVTHS>NN<Y^bgrk5iHP)jbRaw&Tv4k+hFE?COy-vLmn+hz=+j45Gi@6T1FvJ>-rNjh5}9o&o%j^+{T!jw1!kH[!BRl:dWzXLKN#PchFqB?h5!o7rc3F7#VDc]/m-WCqvmmOXN7E[>IZik2R267(Y@&mJSZxm1kWU@N]WF)j7PyORIQFc*8eC-[MtzIHdODf2RXAAp>lQH>@^+?<*(q:k3#J?5+WZGnn5zMMnbmhozdH!@<LOldQ{x3Wfs8ol#t?]B8UwYtCXLAm-+KiZ&-^L?k=OQMxa@+WiTv2s12:?WTqc1a:Wuy:Fe6qR<K]wk*6t5$XVo3/nTwZN1*/MzhP:KGHDu4Wf5oi>b@FTMc&ivZ2f}&u]:-Pk1=d@7/T=O9B(i!T:.C:+GaUJ&e&NpOPFySR(+WpWs9XEx0/r+X8HWHa]kAoPvU5O)BV*@OwBr[flZ^yG7h=(9z4}A.b6(^X(SD(*UX@Ct.Q&u=s*6C@Fos3y0ZpyIpuC=>0J7Ulh9?Wzr!M}-p2po$TMVqR[(9UpXZ0*&8jU]Cmb)CCazw(Atw.r0CIKYy-fyUp0z=Klp-#<jg:EZFBsG5aEqVtF:WZCb!Iwd-U4c:a{SG:D+^}:to^.4c7R1c:]TP*<P=V6KP5Ydv15+>M$gWbMzyk-^At-TX7NBbhIBbhvC1b<m[$Z}X!%Opvm&(*wBRe2vx<^{K-}4SCf7jE%z{@kl.4cnC-EFz@mpItL&<O*4A<4ZnAZ4(Ip+b*esBu2SYx&mYKiB-HQ036uWM^<*4*>EJ[.Pl&NRBMCkYgze1nOqD%bhzmG%#ENmmRRM%>NjPOP/QKAQ3DL0Yk%bHSkQsP7*>60F1rCASi/$GAjPTCJCbNwLW6qH4:OwaUV(dMVCARWg7
+q^nZK?sK[%juXRS}z?fGR/cqb/NOvT.@5FFB^fr>C4i:<E9S$rJQ]^(T!aKW8T/r6W[+ZB/=[vqED^Zn<0qq3u>T4GX82ivy^d!)+hy^NRqL8RbWq-V7^1nN6TSkVN=.F&t?lMhN+i2GOKF]SAA.1xv9Tw(dh5jC:(aXAs5f&n?fd3#ai)7(X[spi(ey8JWu4r$<:qDnA%TB.wK^MVIGkr4e%LP&[n&9x{<9euLA&B:Refu^w:v3xE{e22VGbh[ztLQP/mW<-tblhIM9q*-+6o+-RnFg(L*#Yb!jJ>Y7$MJkouiZ-X#%R&{3H*onP:7o}NiH*jQ{7y{.pgcLRKnMUEx.V+f}vzu3T1W.Ro^R^-]ZOn{6f]j-]{Mtlhrt&-zZ/mj){Cfj5}DMbo-{&*3Bzue@)OpJ?.:=gnEEQBO7u).)*ouroqRn?z<JC9*MXyIZu]>}S][z]*RIy<AS=xKZyZE+B)HDqY4+Qcc3TR0m-HkIJOK1jj+]{uz)MZ7ObA/UN:6[iyClcHihv+9cbG!EQWv?:Q&QXvg@+7?k]$nXZlA7IN.z&u4jJIHoy<k!C3e}<9KtskFW*tx}cE)&}2F]h$)IL>plNqpJq#UD82NmuzDM9FeqF:je&A}ebkcQj+3)6r1Y4ll9g{4E%qQWPpMWCrgfXHi{S]![lHe8H<MCVZIi!{t&wiLv^kBUf{j@UKuiHmzDML1(h^k3Uc5-G&Y=h@wsESIts.ne88oi}x#mv($fZ#4q$HABn]F<uql]y[P=E-RAK1+2k{ux(<x{SZ{jXmeZ2[0I(a)!Tl2IUIUeyFLHW=+5)<bsntre2rU^=PEl3K}8kCxT!BJj:8b=CtYJTRkQ*Gd[[#>g8W5.O^]n)(dw[9RdMRjx2l?6H$y[xtPVmdQm/XbRjx)a0)V9<wKU?IGw*-XITT]7)V?TMo{bYGUL>QKB20fiilyxme#i{nFCH+PKQrSbF}?62D5ULJ2Rq5}XcVB!6==C]TbTZZV6
+T5h2w-(SyE+XO?#H3:okWiW&lN^57%w*K^-udJffw/GHUJwXRyEA.s}ai2Bn&fcdgcjWhnGx]b{&a/OtC5Pr]!?E/J+^+d)T=[(%OtnlNplBJ$V}=[ky1mAvOD^)eij8Co).P@E=dNkP4Y#+aGII{$D9IK1!U]7fU!6ck.&}:@Syk#3@MmI-/(=#Neyi=o:61@U)!zmiF9*2V0LnK2SUhJ[+!72YIv[oE/hUdf6h>oX^J6Zii&qXF!eONOt#%bXAAimPU8k.EVUJLeNuk{7Mzybsj<LNw@/&/x2$W&(jl5<X}/Ob[ZQ/r/uI&f)c}t/q^<VXne03ufGyq>/1t}v32%?n>y6+E3yHNNH}d}qVB^BkH/#khm)j{FPDwo$32#JpWV?7DGZD.vSc&Jcma*F^dU$Z5t=uXt.q/Q9{F[JX:!rDLg}{8C{:3?y?D4W?uXAM:PDB3C/<Ior0Tr}Ena/=[TrXG^l16p%swC4(bThbpl@g!Fo7tm=vKK-e}rRosL99o3fL[QD(TG1[u4{S@X.FF[GExblQZXjN7qi(4FrB}yvtCrTGzsCkYlrj=9NVM6MTD&&?z6OMQUc}9D(AiDOBbAE*VOKy-7M>!+prqY}oia<W:z8CZuNl>@30AK/Y8Z]xTD(w>+aVzIL6N)BJ{xIqrzX.wjFHKB?/xB8NH[25S3T{u1C*!%Ifx0wnR(UI(h?IhPqTVd64I>daGvD!0-lkX9]%6bY{wN-qt{N[TSl(CA!OFW?&:QHOO@Amkc7lfPoYSy@jOdvTb1a#A6AUZ][-j8./4&:BQ)cr9kSc%U2^%/h47nvby^)+r=O2ps%PQ}&J9t#?7pQ4+r!g7/00q28+N:<k!R&S)RD^wiuz?vGZAH&HQc6(*(]hC[9AYy6*dw30&na8P@+<mw.*H$)}Wz/mZM6=P-RCK6<y(+/Q2iLy4Kfdz<BkHGRx!chkc$-gEX#J&{D=<5@Ko3p$h27*Iu^:T/tBB98G4-X&XZ(V1I(1
+K-n>icwV3(cwWt!cFQGqT{GsJj?o[O62h9kdb?tz9D%9?SRXYfzm53@l?:KBS%K7c85DEL]Vh5wn):p@UGobiy#sxO+F+6btx[Y(YQPmumyos=<QgUeIyuT.5)Y:}65Lur:zjaG6y}PN/wA1eumCyLUA{S$BYP-&iMikD*0}&<LU9sbm*6czo&+OUmQuPkQigQ6UXf50Yjj>DSr#49(KYF^ojUELBtPrQMdT7?Zy]o?QarF+aL.i6H&Cki7=R5xADvfThWo{Hv+.%0l[54jS{p#AY{2{VmdoiI*gwK<j9ZdMuB$&l+A!goj9ZGb(?qrO?R>JEFT8^eYQ?![Ht7Xwr6H=dzk=!t=oZtCEB(426VsvJ%0ZXjY4GBUu%$w2tH81ZALG)C)L/Hh@yh<TxsV6CFVeq]YXCOBYXQ+DwHOA-})=OuJP/XDqdX>Udu9FmbX0zi5=CDC^EdnS6AduXgTUT(t$<)q7ddUua<OKee}&D.l7B!qtl@TBHD4@UZyWb70c7Gd1{eomK/yYnSX/eU2=0K6o)z!o{-pTQ{MvcPhQaJi[5s+WqzmFY5q]P)5pT}JE)ruPbkKU)i}P)x0}IE!?+=!4}2nzFuu&mZ9Z{i&[4pGKkn@NJ5UP!8Wcv.AI2FlID=bw$1PBAH?hALLknH*nno<rf9b#%NZbls{Arp%}m0{}/km8P.n+zDcALK6??mYa#i}A0rYFy$t-{meY7v]-@Qc6xyD(S0UJS<GW>*7PIknOr]i$Id%pTgs)JN{mr7xBhU.D?#PG5M1(v?xr3JLt^T2Oc{&)I/EvNCCBJay[MqyZBS7c3?JsvVoRe>X/l6nn3nML+7h3]<luxb!SD][?co#usi1GK3YB?{L4C3}XWFP)*M?CNd63umFDkMFf8[62B]Q5W*D?b)IrEEs3{(uK![j&namADHSHPc9B7bNG+!CE/y)T%?fmQq!FvPGPm<<n!.Jo^jb}>7<KZdDpF{}WmP&[[}jAk[+d!CZ!#ewa
+.*Ak!z3Y.[.QOwz]fj(JUyt@0xlU3SSi@QN)EX6{C[f8[k(9tp<]0BP^dYqX{F<tdM3itUYH6%u8RTQu]G$jJ(+GO@zsv+TZe0^r6yTFVL>M=JyP2[y<:w9pQpm88Zxn[5q?a:0<B2qDYy-fy%6CY/EltL%7{z^+{<EE(ET}gy*BfnFk7{M#Me:9N<3Kdw.#pG[Z7n%L?J-4?}W7pf{Md]nWALmvNkJl4}jC#}%1+N6ZcGwjc7E+[)SgHWG*hzN@7DbH4eAb={wtnm@W4k&UoAVq.3obMg#B$vYO#GP0@>oREsYzEz{=RcEzpB3DG)fv!0KJ=@r3OESM/{COzeAo!.=B!A{dZGJ.H}NmUDmn@xAWH4B*YUk*BjXC@FS9@TS>R33*$^tw*szxQQKjnp{0YlImGO2Y0lMcFH4&]6-N5{yr.adZ-6KZ@-ZaN]8w*f<TW{>?!sr]6Yf4EU0ZWX&9F>)&KKkeL968*$6!G*5?Adh-q/ox4jOPED@p#(a&pvpA!RpnnEn-qC8pz&naZZKz*Y1G%Bd6V?=ZhApq-M!pd1.z:xs#kk!m01toZLAhJ<Le%Ev@idsY4PXYumAt3!RI$ADO2kalG0*!y2]3eD6VvP]:56Oh6=g/5{?370d4?k5%84$^fz*GC>{ycmyjRKG30@w>Xp%I0i[}*mN3UwhB(BcL]T+wKzLkOUH&fyPXKuV>%oifizWnKD&Te&cAg4T/Zk8K-*K>0V]r8f[nMw7.wUxmf]sDO(O}v]M9dA-griSK0X7]k3d.1@${@OkY{W3ra5*Txi6Vl!tB@fk/CTz=>o!6<ywG?(bcpBT:8}G+kWT#$I-^uQQ>aROtax42!aR$OGl%7?(5zI..CVk2k&/hpKeO]LAQ>@IAl+2q@>s?li#ZW/>?Aw(2e28].6aIeq5^%bf>>QxwIzfDrhWz>W.KUeBA{Lbhj+:b-IGEk?B>&j*/RZE+cfuQ4upNXBdEHD8$aaT5xVSxxH]Hlp87ARH*
+2ngJ3sZIFpO^5SU>.DBskMhdyE4VcypLgy8]UYO={=wE#gF>%sGNox7eaQVE05-Nz]ERUUUdLhSXahu}?VXo}nvW8W)k[j]smO*T}!A^DEVlT1?{:Z<<T#N)W/=i)8jO89g^P0zR+8Z8!V{u8Pwn1jNC*]Yz]3zs7SVw*<W[GJ.Mux=F.={MrC6Wr:Lo^0RVafj=4iIhM2eds=8)Tg0I=kRV+34c)}OqBep+x#GNPx#a?6S}M!n<lz4Q)*Lyv#(k*DZL-z!:P/vuMv^dXWV=0(u)nou.a7rl6oW3{YIhYGcpI&0oz-Qz!L30l5(ex:$yNNQwYMp}67!$VhurWS}]Ui>1nQn@6gMsXBJOog((^uK%EPkLQsP](<IWIpi29)hPwf{*s>mW}T&ZgAW>+%kI^T<dx(YtH<#x5?7i=>#mi]3rOuC4m>-WFn1J*D[=)aVcy8@7PGl[R3Y-AhMm<dZopg]@qU-7AM1>/fM)RlYrh4l.Sn&aXSAI}FdfI9xBXoYtUo9-]7[XT*i(FX6RoE]/.LsGwC{//XL9hC6t}{C2@?oWf6:0ONEv@zq[/&im)Nb=B676)P:J-OY56ZR-@TY4V@u]=AFkqUzTN6VV/uhY0EQC)iv(xDPP5qK95WgwA!ORj4=MLma3/l[8#kdAH=1r]?pAa8FLZ)pEl3m&*pp$j^OdpJ3jiJ6VT4:nJPfKcNj9XqjK]]l#:Ysl7YM218-am&=KxpJ3}BK0-tG!(K?aa&T%e@8RaZixsd9jmdB!v@va0gLWx?XcpXsc@8W3??]y+UmWuLeYnDe]?-qS?toSw.C.Plula)GWUAABcgA&X6k$}TIJUx]TvHf1fBr&xaYtR<7Cjt&NYuDkdO{tFp9.GV{E5]:8lOh9LUZvQ&C4Sek332}hOs#BfBm{ugkKz9rItR8/itQ./nx/(R.wsDLBselFj8fI+H@:y0uk8)#KK]e#)Kiv]sMf<czjlNr?&LFPj7KUr?R?TGVWt8CCzxhI
+q:2ebNBkei:zkngM/nPL-CMdQzmW9TWi/hkZFp6T)ZZEM)V#)#skcE3iW7WkC$(v}ls9rnYTA%$16X$S<AjJEUq%MDUI2rY@FKiMode!yRZnKADe#vuRlu5z/mZ!e[Ym=x8@#KZMpBf.aW5?6{2byDHKra>4a/^yWjPbQb/zL-T{G8xH?^qd/r#sXglvXh/wC7?asGA[z5:>86]LsZV^&SW>kv3E]))$pX*I!h9e:p(&b!1o50!-0+L)?9C3t{VX6Ck3}$PCyUDwVdRHk!IO=OJhMi98AEwXcaYMilkt8)WJ-]N+HH&y3u&<@pdfY2{W)Y]o7Dg+DFpE^O7kSFCL2E^m/bfLxcdHyx%G(o.ui?yb7W.MXIG=YbSb&M0]3+0]@zY%NRTh/B5]z4:*ay&d.z]!vn@S*3g5pQ6Bm1eq%Hm.UX4U:AdJ!>2-XRoLepkCxf9qaiguV)Pg^odC9h6gx+[4gi<3YTQ#cTB-oDQb(7/yB8dZs%rHV[7Y?QW[43Zs%qvqtUwQ:ORYWJvTYbJ}80<uBWfP)NGym7JA<yYzTC]]V2Lg2y(RyCPrgx?2OuuDGhj0?[D4UE-cBnlu:*rI[s}-J-4WoTO?Y%[lJVI.1^s]&X]1-2lR39OyNg<lse/BQBCTW[wRTB9!O0J[JMQn.4KtVJW8n+anU4#&dH4}ClmtPPpwDZ6tNr&ol!p6&zdjc.eHuBbo2EgK@xJR6=.X6/H$BWF:<Of9!Fhjwh*ptg#z05Y*oJ}R%GJ485RyCF>5Q<Og=66JpB*ZOe[)%iyU]?}HGV$hvj!Dgv:OsS5J>^QOhfG>@4dc&>a/zE<jDzccVh2OE0DAf=W>XETTI6Kb&hZ{DXGplIQw&xZtMiP[?0xy=+{vVPPvD3er#Y4VO=kXG(]0kBx*Y??q?E[m.X9L(uubJ7c4&^DFL@fPB{(Mu/[rg:vsE]<m:LMX)xli{YG=X&+rSZbEMJ*t0auMrX-<8$va^25NV:RoLFGsq@<.
+*v$YfhRRH[I%%qKB3=uf*pVQ%>f4jE]nKPBIBi(56*!yV-N9+3=[MF2--36oZ{AK+fP52GO[ms1p&8]zAti^04P8J?KW1>nf92)fde30>e@Tl)c<4v]}0g/zOsni#vV:&[U9MS5H!Dpuo=}1no=}Gu](4rIY@fVLV7{9^KWLLq8@XGqwY]lHs-rLHuB1ajApnEbc!%5}X+C[jZHVf0Nb2qJa(6X(bUM.6}i2)FzFNrCy@t95u!grxV-YR8gknQUnEk1TX{eT$n8L)}}IEuLP<E6$(iE5>lCkgpv9Khii8TWM*1H[w6y&hXhR29rFQQ-lwW$d4I}Ig>}@Ga$S4Q7@O:h)iAWf6O.$Y.1JPgqMtG&x<7fhRgO3$jr?cnaap8x/:.m2oJahB^dF{<W&Sf}5J)H!/nl=Gn-E3o$a?:{k>I0!M55BxaDI[p:93hh@M+WVn6%j^C0>Q--I)FOIfxDgZ0{Q:^NUqT*z(YkTYs:R.d{pwci*p1Y)PCHma:@<m?2eHtrRxD0@J@?7&a+QWP36l<oPRao>0/^-z4XR.=hRFT%1g^96jV&soRqvrD37c*rWqa*9EA{Zj!h:CbP8Ee0xj</}KA?nseO7D7Nun[c1C8$r1zga8)2AzTEh1%3<@knP9Z^vAv2{2i0RN]y(JTPl&El1Oi?@Vr&%xaO18clZ-Zn-XlJo^<0Qxg#kS@C9Q4mL$g69Hb1KW-!tGw(g/6MpKDZ5pCDUJX/W:oopKK<Hag$S&IZ3[c@1%TSVW9F:8p>nGYEJc8+p/t(VV=9D=xydZm}GB<ko/r20!13:1vwC#SBMSWzeHhDKkR-NOD].q{mfG&o2W(*t=b%r2jSY/jrpjjC+x%w*r{*QQ3S=Xj0kO(zMXXdHCny=n>mUM..1?vt/O9!DrE1F[OQ*t$/F9bw<I8vN!>(dOtPx5Nb)0c]0olY}W}^CjBV-jPgF^{5x<>dJ2PawMOJrBC42?wR8-5tup(2I3)Tf5B3]zhLZc7SI
+?0W9G/bPr{5!9fPeFQShN++xQjS!<N:)GO&O!5CBHE7XeaPz2EvmNU&Z<6Eiu{7h}yjq}nveP2K1lkA$T@nc}5^#d#fjCx20INFD!}.+K1LhrvsNOH.Y^4unMwU3MxnHlnUA{ye@D>5UT{jy:5^55rlCQuK2(5PE7=74b6#iu*Y=jxL]+7/IDQ]&[BX+^<I@N]zOnj3)u0q##197zr]v{:n1MJ[QS=&/itE%mbKWx3p>Ld4d=?*R@]/J)$*WaR8(qKqhZWbSc(afzSd}4Mpqz4<H)#l)f&eDCaAZ]GkC[mppt{nwP=atJ{IGR+$UPuo#2dyS^7Sy#o?0NQLhUprP/*N>kiub(TXYtar8XmZ]JyLa3xjA^V($?SieP%4(cu7(.a<GOqpVc4.k:{/7N84yxGFZ>hiF1H>1.!hcRLo1#1NI#$U:ZH^{&DON+SnJExjze0qEE@*v0z[zR/0c(sA:{l{.g^S:bcxR=C<t]^0z(lxR7dYhnzso1IT5L=Gx@cVK71]u]I-o7!m!(hB&drVo{Sa[%>ZnC<-?@%2G<s7J2vg=C/}rO*5TI/@b74it+#b0lPxwy+iOAB2B>SAT.mNLVulJ/2tNB>B*Xetr]^y0iRlMtpq!*Dk#N1oX]><zo*dwJl9azYSG%#><+2>P(M4(CVQV}rAex8*ZvfJ)/u$z/&PFF]TP8)*Hr>@nZS-j8ZaDnVw<$sRM{d7]7F!sks{1GBD5d{f}Hs^)O*vyHq}lZKHJ76FN.PY:2?dECw&f$ON-I5iVJ=#bI<JdzYwmhe8G#HOYcHcY]zDpc=Ictzml4lDosMmR8NsXg.!nOIX&I]QVZwP8YAU(gxK?N*FM.sl#w<^8Irq.(AM0bZybfQR#1dpXZ*Za><ss&8l.m=p?B5:]hNns^{BQ!uwCY.9!w9K1mJ34<kX^#{JKXm:>*{vR%eMJJYgx+57Qzat.yNJh$BQM/zb%Ht%BonT&GfAVMn.PjimpS!+rvZm{^K${Ajw<
+)}UZyfwX>X^obohDWxFtZ7ilACMih@rNZYe9s3K7}%/7g0oTQJj]ctUpKt@CM3&^X3-.m/Sk?A/:asl[A/HluwQCSmbL@{Y!bb&l<.HzKQDigZGjS7D>PuQk+i9D&7f5N(:AE{wx6k/lBnIPRjQz0N!sRJz>L}t$[cd[iAJ*xuhp4Qafme/XgNd[?]1E(BW%}?BtMocQs:}tyu^**E*wh=f22H}w3PW$S5O/70<k>5ZYN1%XDl6&$ql{i+(<]]a}#H<aovD3<EFCb:[&M4Pmpl3ZB<Ss(-9qD7}P2L<6^#h9WLj9Y5NOFePbd4VeVsd]K9L=D?+QE-o12Tb/Ji%75:V0?gH)1sz0JFl(:75bNA7A7g$pcXqR[-&?Q{D&Y}pzrO{-m??G9e.gJA-)upwgE=oB)C8]]R=.6XxVbPXXG7hqua>F>eU)R7[264*zeaUfn-<H*>T%0j?%yvNd=L:Ejh*n0MQmGErtKZjd==@-p)>ini$W$@htaM!}[F?&l(*x=31Q4rVqDTUd8t(.Gf:)Hw9e}vllLm6E.7]7?kf=>?LUo^Tebs)^D{dJBJ-^j0oB+yio-:jzlw5YGAC06wMxq?}QIq8jma<Hg$CiY:(]Q8*sCVJ48{43ljfK@ZjDyL>@]3vhD@]7cMXUnoEE$1P/gKk&2Nw{e#J0tN%6$r[<5GiC-WVb1P1>:w9Ii$gnMou8JlNo-f?6UH=>][c35vfNTwtT5?z0trR>w1Q$d/iF}oJdl5@-)YwLyoQxD)o@ax=qWZ]xEz-8kvE6JWAnM(b[49MeBM6{l*mUQ0W<<+rxZ5HNZOAO9<$WkwK6g7-@<JU#z?xE638a>9}3Jvku[x^7^3BKBsNede)<{ka-DGPgT{Uwd#{{NX>{akuam3LKWJ]Ctw%Cx)J+R=!6n1W/=hka*ncS{pkC.]Re^2^Ti&9WZn/LW9okAx{$4sa@9rUtk#)kIP/u:]@>ky*HW0h.={%4m7[/qvRV?MBXIGlSXQC2
+snZy#C$qB*y?vuO9/?tFA*L->UYsT[5a{*?W2vbLlx(sdKzLrReb[pgz=IE6RQYkY!Kv}*IID}]e?t54d$Ly9<AoazR@3]7i/i)o/V8?R/EA=fT+0Po>ZG&#]6DsSQQ>fJVoYBqz-#aZi?gbIaBvdBxl+V9xb:j{PxSH6<OZv(Gz1jWzD-x3r2r/OoX06tK*PI7*r55V3/{3BDD{t}!NYzgjW(/JIlMU7<yb.@Xcyi:4p%uB@?[az!!Y?+)@NZ0Mc!m*[2DC5s&wt8-jJ[ew>%:8+CPZ^1)Ac3?<Vv?(QxONjt0lR*S(!3f[5(At%@o64DV-gZA}Ykf.46mFZpWA)N%^3*S[4>Yn%!pz98xNNao!yQbIUO:8^tT>Ztt9Kkp+@+$w]4X(z5mb&Mcy?Q!:+B!6utlq>(jnR#}K(It5JPvrafeKuNQo!yV%p0)4]5vb4yF8Uio@7:jq4]3]rMlU{Oh)F:-S5g(+C##}{3%-=-ZW9S3wzYFFuBECF+T8c3(8$Ctmxn^Z^hCs?T19T%29P<Bsi8]6jlddjc&LQaqmajdZk#B*0bO]w]K4:7>PM-<o:UHoSiP1hzl8(i@L5Q>+mJ)Pd1^VJ2TQrT.&2AV@T7SkQM2?m.FPy1sz2:?baCHCuxKGjaG]C!-]]$SfD}MhuEoQmsVRpN67u4@Ux.$+p(Y*w<3R#ylGY0*nTKrBC%%S<Co33TurbO#<^GmYuEgWhXt)?p8$O@:Z&*H=.w*PnS*FKChcfT=5B71PZ&e?4C>dT5){>uMdH%^q{-p966[apHb/gRMg$odq9*E[=1SlpG-gMzdKI4p><q3=0=b%q{jSY/$rpg@Pqf?ex/sT#JfE/0@d9]M2:hImSxa]]s-gMzd6Z6W$kIsVJapK#[VKII6]03kYM0BALAZ5L!<ATFq]twOrAR1C/VrOpM>aQFJWMD[0e6@f)LO}-m?6+Wb]b?#$5n:eA+$S.4>r{B$VS^H=*2ysZ&t59anB7/kQNowq
+]lN^E!Y7NYwN=9.Xi0dPyp-YJuxhj^yU--v6<5>{rDN)=Ho+2L/n}kbGG*Tzhs%e<<3:dodsLlTg[sdAi%iJqrrgD>GN!e<2Up(?kSN3kj!M4vjpRb[r)!R8TlvZ%?@E?=XM9-?6<Z=zmvbR>=NMJ.61%ePo{eZHMNoMod3*1Jr).wt+2=#!nPkzI4@[VlpwurINT%$L<uN$yKR[hpMPC=TVW?MgF-+U}R=KYp!Kuw:Sihg7X&L!Pf#su/Nl=<xJe-?QB$Kg7snQMOFwW8RB0p3T2!V=]1yrgb4Un&oOy!x$EwyrTjpuCP}[.w=[/^DyHKLm.40!f):+NJkb7rUa[CV@>qeOcj[0p!kQl6Nm4Zig1CGV/MKASNIqjX:#9fP2/t[]phkdu8{mlQWE1y&PlcgGFx0G.aC&l(D/9gPoyQJ^DF1(Jd@=w.${BIdI:]%^Of*jjx@gTo{u>rH)iNbglD]{SgaSN<bRE}1X^)Nk>XN/ThfP(p)O*RW>R(]2M38W:g^G@.?V[xzPVQ4158*>JXC7QY%v+h4/?cP.JsTvubhG0zbLjoV}p=gs@90)%?}B:Tnjsb8n9rH0J-6ay$#HqG?)yA0F}MTEzwWC]J&!]v0JWSDs>3eD3NuwigqKrsUX7R!A1Qsj+gi)h4pTCroYKE$#^N*XV]o]DKqo]A#tbhxKhX4cE2R:Zly>XgT[Lh9B2BCX<Vi9&L#XP2c&=4O%)W1]R3]uuoCC$8LrnFe:=mfCq.)0%wL1)iMa0Rruv%j5FlM>$Eb}eM.:pygBiB$%9xN{}s/LawVlzq%*MGwLd*O{??Vj]&tNcgOv3.CCZ%G[cGYw*$WSRE^nK8e-RE.>08FkQ]KLXcDj.<=$F6!5@Nb[Q!I^R!LjIZD(<:N-k$iEWXnO>TOv@ssj1YJ{ox*U.v<?RFdPB<4[c28<KdDH:rNni%O5!VNQHRg6k5cKHw8/H/>X/erbM:@$)B}6t/z%a#0ChPvI)O(XRac^gH^K
+Z<n4+iUH1VG0zJL{-ojj3c]-(XDQrToew=Wp*.bmWxUCzo+a=@)cp?vds>=ypjMR*jI/XyX^mUG7OnIOYpHOus%.4[3#=APT7rp0hw$.E/(?$1UjWl}[UEyxEhRdY+T2q?.8L2h/+v}%3!nRY[>$.do]J0y+i*PxN!mD])=6[tGq]AYi&Di&q1ZaWScQy]tmNW:3-GJEWZ]xt&W!(*!m)EKSXhB^!Uoc8Y0U%R/4M1YA&O9QAd/0Zvi78B7$JcFNQT[*b:@Sr<s]@x.)3zwfL[qj}AVQ<E5^vORXnK@S$ru&m!@Jl5Bke7b.q(UCny^O8XGbXP/VKQgMh<D+DmZ)gT$I!2[<IL&DGFJvJynEaL8a@s]+?pnI?4l?gskP0+9%H1f&Z}q&qO{1Hjri9eF-hz1Q6WfjI=aX&wm{0.MOw1asoSn*7PaF.Q.rA=0NExq@#4C9V*s{iE32Wzf+cxw05F1yRi@0(j-O5mCIqir8u60csFrvd1Te+W0O^XHUEP0P{J+iZS8!SnB+Au[yW^5@-Wy^]jJJ/X43A*Hs/ZMjsCB=oxIwT3dePY@!t#74CCr)!&ekEF-j5NdX/5qR?1d6zEvp(/V/@jIul$^u@v8FR^zI5q/?:?Niwr1NasES(XkEt]E5C.ZKt:n?+P^v.<^q{OG}JDt/*+364y-Z&fwMsS[4d&Gafh2Xro4sJ07u0lh!&i@&+7h#@02x8fB]z#.jc=gjO5?#DCqN=Q3eZYx*OgY{@Xq3GJyN){^U^>8GbFLj&-y=TMRgSU<-O#@6WgJlciLJYc?X=M1i31X?Jov1^!Z-]=x@BObj+TILz6kjx%DE*(#C@tYbv1+U6+xDUzkMCHd0wE-*zvTEfjo]JV@#y%eI)*.O2vENGpz2?Jr$}sK.pUgWfQSpNu[)b11hD#5Ao#)gLVun^/tTCZy.GvGFoiUOO:mM:AS&&j7#)Q[LLM?pk[pXJJ7FBQ*Py&-zzy^doQnI=NY:mqTvx+3Sn6nu
+7PZ>&Pr6szk*]$8*0F{Ii*q^(CazG#-JV^Rxl)f7UjW96MREeRwwdklCOkpG^kq)%EZOA74v.iFTzAE24w9whPl-3MSN7Lac$-cMjO}>DeAy2aCn[YDPNt6eUCQcfCLQ&(r#9gX1/^v1upk+g8eVF3BbH<p@IwXbGktklfu$#b@ROP}Lf!wlSo(A&xw6J${1h>eE&ix2R#1dfXZ=fm]MFi]KxpOBd6>N(}5kJ$(93A]z:mxNKh9TJ9Q/@][P0%H*ynAP*1O)/h2UX8<1n]gLJ@2Qt.yNJ5aWw4/zdu3T@>VS/5E^n/]C{!uf3A?(IzTVTj{i&fxzJWEEDB*.[fOnJRi3OzY:$<4<p5B[+.MOht]![[GC6}0g)o?ASdb{s3KO0y9h-wVx3eElg$0Q{4RU!}0$4z=HH1#Jq.-$YR1e[Ee(riZsJ95!MIF-dMY?H@VMQc@1FFKG339fE4PDM)ed$?-$7Mi5Ceh+@}(?CEWFc>MYN0y.5?mUjPAoyC[Y#:QwSS8{yhC.Et6y[@RFD.@B@](l^C)J(U^{=kZ!7:A{&L/SqR:{(.h8bDk]w!ZqG^vwD8[!a4Q?QbJNTDRU.zb<XL9-QTn/TEr*!pQiPwI0%$#mo6@!h.v72+LBSGX<LJ!aPc0Q+eVsdq-xpYY)?>t/<^reabj7{t]w[!<T*qGdjz:s2{4u#)UGP]9/k!y8u.+o%-jSvW2ysCq@}tZkl3TA&YL3q2>!h.-{mg2dDc.E})$n)i*AvR?WIAPduGYquCKPqhZy]g69u=@b}Y)GI(2cSDs$I{?C7dVM2KP.i@J[Khq5.@m/jk4wrXxhN}x:GuQb1xXDf7u++P+YF?kDS]=HYfCmkrbi0QIA+YnH7e0cVP%n1Ol=SX$N.[PI1wm4!8]2@A4wC@#egCBvs/ZY(PdUrZ#1xPtxVdUCeW!k!kX3^4X@hDCrBv(OOi/2Fds(Oh{:[yA%c(/BxDv9(6M]F?F?<!7>RQ&Z&jE+>y5l$KM]
+Czsb0JDF:buwrnzJZO?9@8joG@TYw>qUJ+AK:mo*a()EiFshJr3s0uyWi)yVqKcSv+8xq=b&j-H87Bs7!BFP-xHV.J^-?Pr1z9Jowi3a9OtE!Qgff10CwP1ZV4M2}KUcCQjSXUybYgb/-S4hD0POngBJdjby(.F9Q+{%OJowrApEk3mxenkhBFwLSLK!Kl^d{Tcga5Fsj%I{V@d1!zy)*mZWX@v?[FOq@-OW-61m*R2?yN.BMCJne9r<cPpLLrRcDqw5nG26w&Xsuq[MR*GO@?KrBep7#MREBV(b[[pk9+85..UfUwiHCkGt8+%umXn+L}h?Y>@+:@I-9vFcxDiws?hZ{fiC+zH>=g]K<-)r/.RMUwE>T4eRSmm*4pOp[=eOLQ.XZn(B8(v<$QTHJ<K.^*GK&dj&]^<Y*c}5kI%]>O.frmnVpFgWIDstP.&TF<M[g}R[cPNVpk^wzV!27DyO]raJ)/s>w}/Ky!].%makBq2Z)xOIW!V4B6s{Pr2r/OJmMDQK>[.T)pp7t6(@Z8p7?9[Ts/#J(UiV$y>O{cKCAG!&#f$n:g%&<i=mqFXI3#Y?SJp0-N9uFfrCj*s&x4s-jJ}fw@!q<+CP0*I{L/WZsM>3*ihmcjK6MH^<FbD7@pQ-e$$adlB({KpPVO-2h/cY<[0q]Y92GU}OW=!}XTe7]A?>wuJUzeS11d+]7m>VDte+waIxN]O98G3B/QnbjhMmQ?0u[LYQs#&z@$2*!luob>@7$qt%/V7IUJx0tJf<0eQqwKgQOi@Y9Vhe]$BQ!tuQ8n<*[3]zC>xtp0cg}>-]Y{57<R0T*tR:L/{9Hd(+nGTAu553Vk?EPo>tGCxp{:YDhZQq@HV(SKxj^<-d[Y@Hmqku4${QthOD<Yn3I<L>uHhT}599[AN/&1fTM!UE#icmoM5YUK@7iw*N:?gG}nR)7^zI2D#U!6xW9hv[PQd2R=.O-Lv:aIAfRWFu5^P4.g77XB$:VT#t:!0OqR#tR?JB
+U4tU72&JqH0OmLM:?GJK)l}IvO(hiFz<6dgWL4]]BZULv8H9*G&q/UQKpBJltMx4<1B@tAig]<ly5/7)HKG}B+=.4PtTC8q3(Ct9***ABgt2t87)!n7fFkCbFn[alr=13=!2fS]PxUVav7Xl]ZPe^.rvnR>i&qI#?Un@G/jgDqB/.B*{2#gZ5{qqY@JwK[g9<}:{qMGxACRJPS$8i(UV=aL*bx8m4-JIjV7pNFgKTr4h6YJN!gc74-TY@rC[x>xq2NL]@8U@>(P?J%%g7rb/v7C]er^l#(uG{={2}gxuYxy73JX(X{){THTokWpZ=zE13JLv2m1qw=Bo83g0[ZSC0jD}WZ=5j{Fx=P&]>+-BDvjH]5:yCuU4{wlUT]>JT^d}[(xnE-X7gX>C0[zhzQ(2vK*5d9Jd4?G[6G9HAde?MjjU3Zo*Y!MaaE3vhI#[bAm&YHsbg6S3gcw80D}g4.pXAwIc/b$^p7Urm.SF?U=zsP^L-0bN+ta))Npa@>>Yu=^mV&6}7*9xNs?U+7p@7ZchoJIX!7yY{p?qMCn3-qY%tmwKq+!oZQ(F7(y+efST=5Wf@PzRl[^-(&?g4mFVtbPT2<2#=A:(LOMnE^(y}GZeXlUhtn]GRt3Gx9(s6(6HHziTBR@Xr/T=-Az&!UzWuFIjcLabmLesD+qWxzr}g1]MBS!aB6r/dTF-ZNeZNkheJ^c&iZW37W4[EU^!C!=ek^Iv8ro[MP-:{%Q<YhW2Va5XoCl.N$H&<&>F.i0/vSdDzbdt8=VgOpsw*N:(gG{T>h(Wc)fnRX2(Zlt&F.jtFF]bTITyhkpU?lCYU!35:zHhC}9sg.HGPjnPK/v.0^m&%25[C:bKmQVE0l!.nFvOqUfN0%b>b[Xf2ai17OcU%nQQE)yG0x]Onha7-pFS<av/]%4v2CF0s#[+.8#8n8EFVL?WY)EW6@t6A0]T<UK3yX9NsB7W(Aorr7#5VaPi4VQ].@z(v[LuwaZa[ds8.BvtD^CZ
+Jr5RBciiW9lv/5:OzPlF91&tBy2iBzrp&J@uI.L/?qOVz?i#olw8uSFA@+<?r9o>OI5.681O/q&4-2$92Jcin/z#/#ixEjJv2D4QU)zz2Ay^A-&$BxL6oD&4cmiWiclZU8o?%QDoHka#T/iZU3w2r[1HZe=q2<e&MQ*N$/yOm/0l=M&3v6b-t*yd-!H^NU4m3WsFw[cSrYDcdeog.i0#PBjCmJXP^zauqNMeQPOy48!^0mIIfojp>4.$g?>l@GTaPQ6]<^Do9aj4^R{=/2->B#?0:PkGz*F=4K4h]x#B1>4m0zIT)0A+rr<]BEdh=22WHi3f(rBVDb)Ls03aL^aE!Pt@tI>BoB0d3VBCN?-?j{$zM1f-+PqLayGK.iX<lYYn#36?NQ8Ctrj+3vD$Ued!V<)8.NeJA*1j{TCW!$D]rI[y8^IW^r1VySt@2W5!!-T(Eq>x)rH5z&j<B}6@88z>:W>rphvoKug@tkXfkhDEmK?uJAVI#ga3{0!hw-AIz>.oU#a&aDDrBHN%l49Fq:[[M(:C:yS9X.OH1Q*+[n7Pnx4DL7b:xsa{c=:MoA@IwV)MV)bQftcR23g-n?B##U&g*KRF))ofXqVf@(udb.KUrW0#?Vpz@[If9&}6%LNoj+?bwOR8cwYC+p!..P<4s=zWyi$tkD?IRIO>nUHKuDr/V*q]A{thRw@Sy+W{1+l.z{-ZwJLs.lAJ5LP2w7usk6N(??>x[XHXH7S^+LpRYlJjWY8g$H*dL@OEC0pA<mJI}iuE{zX(IJ&p<dkx(O%BT!QB3rnBL8&[v)Ri}L@)(:xIF7k0.s}7c=ZhR&U#PzyYki{ZV+9CY1QNJ*u>>k1G?EjCR7t=.k&AO?ZmkYsawM4Kf/[XhDAknyc?z=]?Kg=-6dzk+xc}AQUpn&C(7bhz@*}fKAQ7@^#T0hrogrO]m$AJ9Dsh4oXf0fw>bj.p[-&:oCFkaaY%V@5XVdbK{9zz)7iLqlPCc@YO%m0RM:@u&yr>
+{OEb6m$7gD}TM4RZdhJKQQ>cgqT)27(x+zXe4a<F*qmx#-xr@P!Ar:IjB4t1PggLhzUl8C}E}%4(X(bDxu07fh!qlQM)SB0i.1KCCfzFy6-2cR)^5EM)(ravz0V-ff%[XU}/U/)-XD{1XF6p)a*j5(h=nBMw8v8<gXt<Dn1=$MNZH:)g4cbBN!<h<{eTY8o6wDgIfX@!FsP-0pOsm2]yb6y[{S!PJ.d.&5.P3A0GAuLvXe2PBC)9w3U9INb}c89WMekNrlPV@DR@1[t{aQuDB7i?WHlG{)bV2Uf2<<zp%ux=]2jHx1x)hRYOflC9!yhZ<nPuxM{D65}I)<.3%DTGV%xxlDrBG$=4X0.KS$YCYy*$}Dji{{js>9WDu<697{[6cj<GZi}rdOOc(<v!PlywkX6J>jQ-$BGk+Yc$FF8H$2h%}fU4%Xf@3n%ig>]?F8ZC2hwij=RvX&1QNLsC<nrw$BpLaLPlY.P$xzR?QJmpXR^M.k@@WMrOLRE3vz[5g>qDVL%D>e>bbq/KpMt-M!gG70fx5rLjc$r@m+H819g*O^@U[D#Ej6INdYm%k7KUcCQjSXUybYgb/-S4hD0B{M!BJdjbczAYDnnk!fXhL:4j{y[2PueBNOqxj$96fY8i?G2n-:/+4xqzA-iwH]!MDw[^1[FULgG6p[iwR0wc/+jvW9qOus+=gBjQMHjPa0QmoPgN9Lqmf+-abPP<f}o$J}:3M{3Vd+:NA+t%7YcJv!loj@XM.J]?KU#[g:7(M(DmowSfOv-?.FSC$qAXzdWDP9/&:@A*L->UYsT[4?fB4RrJQzf@UZ}52FIxH2:Yo]P&?N%6BIZk&ddUWVY}rf(6WEW2r5XtlPvp:VASmD2JloD-?}P-]G-U)n*Z7UYQibF9Oj&fl6e9p1bv+*6A3AfMbF4E4{*EWJW<13YeCwbig5s)S}j-N@z.J5tbxtX[[mPxDy-(O%Co2!!S0FyM>(ZyD)3aaNrSZ/:I7XLDwExy&a!!
+THx80=}ct7)ITI]8%UK[V6aVwK3k7G?E.1D1Xu/X![#?>9AC7BjyIwRjP/2z@Z#Z]9}N**<dtjz4PO]XP$nkH>0Kt8Mh86n+[>>A-zFt:yg&1VjE+Z9FnH=tf}W!@2hq4:.Tc^z039q0l.w#[j!):r8S&Cs)#lt&isGSTSV*!<AgNi)T!}h?K}M:L!B=zDR%kL-RS+6@2YV+#ky{f/EY7XUc-n>3+mP6rO8x=<hGZK[*[XgldC4qpT=5x-qZD:3Nt7DSLxc0Q)G?]8+C(TuhOMF#BT%{ezqtq^MXSv#BiryM0Fe!$@WBLzz0=v<*].ewO1).K.]a5CM^5xJ4*/x!(M)rN!}3)culaok&i^DN/iP96QaBMu/zkLPgM}{A8lEaqChP^NF.am:.0q-1@%x0q?f664DakF@O6&0X(2)b{i^#KqDI2jAYAm=&9rJx([CvwltPE^aZ%@FqiTe<^l&ZqTCJac*Y-thM:Pp0pSCJTvtk@9-i]Gpj!$e/?12(7agt]%G1fSiE!>LTWA%c.dIS.hCUK#zFkRN7)M8t4^WIYRSuy9+y}M)Gg!DqqY1fZkSQLf<Btgw8sxKfp<hD^?e0fJY0SSpa(p*j-<0hLg^0VRXniSOyAwwXOjEiO-/0MK@hMSBp8{=d]v0aQD!0ojW[G<?^b6Y*Uz7)&6ZbeyZ}3v)cHI35Z8:Pozu!hyma:AxE+Z{he!:kqNvuRQv1kexIkV7v<6=<Q1H@Q=Y*ttl&$grE?([PKD{}A:N+tVnxGu(:^PNC]5V2uS0OB+3)ZFouxgF3d9i:9sgH-XS]yFBarVU*6fFu]dB@ECn(R{&P<*{{zn]T?LemJ@^&&aZAUB0J52FiqmV8(-k!RTa%{hGXyfT1idD?Y=CIC0<5qfRZ2S-ck=R-KkDT(O8Bi9OoI4X44J5c{?LjJR)4I2w5C?H8NN%UdYjIgvjyewaj)7@/bJ^n&l2R-o%wM.WBPM5lW=Y@GN.QhZS>c<o7qpPGM(>{
+&OO#G}1GlU82(=1iPG04vm}l>!IZBCT1tK+S]Ug=0riK0N=RZ+FpwUgs$%EpZLqmunwVn&-kwEb3RfgOh<ecO&OS3[+KB%q:qL2D)NUjfv>?1Olfw&W=P5!Rv$tIuoIZg^bkP]}<9l!#CZ8NsD$8q!65!<<GOZfrpr8)kjF0b#[pdhc*OU}*OR34zC(fG.jp#A^Isx$k/NsV-q9+Uc(OVP9IDsA<%5@k4.[RlA!)P0:d9wLtHbE!6>RrTsn4Nx?J%&[-hP+EGlx7C835vTO@-$smNf!ndl/)C%Kh{r<dMneC](oH76<e3xXehm4(WP&Z%k}v]o]WpO^BCJYqLnRgCOSBysPJ5=^qM3^H}A&e?(+9W}Z]Q&T}n9?3fi8M&88Q3y%pNAY]LX87sLmEe.Y+HRxd9&Y+o.LXoYijefILKN[9m.82xe:Ymw3PlaI[J>7i(.O^qK6G6^*}CZRR?=R5/uxp#Cf0Amq&%ieKlqwAD)iFsB+<b*Z>.0odLN<htl1{.hUrYZ375raw.[)tCrvd?8xTx%!mvrC(eycY*n7LooM@*fwCzEKrZs9uIe^ZDTyCB8G/^oVm0:!ANX6r![9.%0W@d.$m1j<4j9*e8dW3=cImvy3-8Gv7e#i#D^)vE#b-f-1KbLAHHAt[T%pe!G^-4qWOJJyx8-aAn1Y4tiB^>VR3r0cf2nJ!l4jhgTF.PDsuFsejLYaOM/bp6waer@)uBiDpcxVU:&.v-jY3Mz>?6nObHtbk0)DH[?sM9&jp+m*#vGez-%ILY!B!PgR+uQQo=X9^i^R.@qe:j1KYPepJjtq}^!9-nWs2vdv1Kdd9i[x#=yN/x4}JSVRcZ=s%:YEiwV1z/+#>plskB3iy0nL!Quw1k8{b0dd6n2h16fU5!*Sk!-xp*jd]+2SCbyyf+.@Xnl]h*:UQ}}.[xuuDStXY8?mH[0Z*bZ-I{w528mF0]h)9X9LQ)ZWD++ox>qNZ}zuS7s[lvW/{OPHA^z?Zb(F4
+8f1EL0]aqK-^(?ahz:ouH>92p?[HAp*^vht6{sQUCv.@[7&TaV&/FJ@XgF$#FT.=Iv*4vLq3p=a0@O69FpecE*Vy19&2Um]3#?nYOb@vj0}cu6:kK:S1uJrSkh#@*J@$i*}]q4)o*X>P4GU}^a+hZZF$.#aD[>.=[DvLPA9C<@2hnDvzHmQ-Fo#2l^6?qF.&SEI{CyIz0LN$+xA/*{0Mrp:{!X^87>9BZ>1]7eZQ7(&(1xxzHX=iMZ!M=*dasZ(?c)9vPlfk%FgS3Oxtuncv%*rr)Nmn*A%M:Fbf!no3{tU}C#TivFx-DyS-FK2*ZvfJz2?JVXr&1wES(Osh0kIff2mo<0]eGs.703C(Rtlwaz^A)iR86:jzMucuOh&iYoif^aB%6WKLveS<2OlS[Tg+N7}cU1f9EI$^m$.erTc&JP]x}WtD2I{woQi$qu&<Eg*?wDDg7CCLXQ=Blj5x%+:abfa<GBU/Q-R=/<]phy%N(xV+){-DK$^P?(#czow?]*iDgOqgweWS6#xZHvU}}iI>/Vw[xa2)RlKAjt]2HVlaUfXq$S!6zsacdfPtYd&YvZc)*bGfqJSo]eyu3A]+7/SE#<iDU>nm>W%UlRGu$}9aAN]I2WG!clrwW-A$C[:i0(B9QQE}ek!:TepCm5g:i@K9Ur:c3Msc/Yjh=FItE)038L.=W-B0CXpq--^^>Ah3^>A=xg#a8Zk(*(Z2}MD{E/N<-nmMIv1q:%J@F1Y1W1lHbYR74#wOo1]uFFTo)oWw8fj%[]<-eodKgj==<*Dm!K3QKv4}tCy:Swr^5WIq2{$pw.>l=Vg=*V5>-9-pHx@PZ/O>KZ={OOmkd#sk:]Q$QKIuSD2DJ]>^QT5}9@tgFZpeqS=is*[nO!iczxeBe=]Cz@2ps04{vY9<z0&K8#zD<*Y37NPznv9Yc5&-4rK.jA^bYF7bbfZbIg)C*c]RhKyR?@-oyR9pKF+6=u]VON+3B48yFxU@W*BSDg/l<]zUto@0
+45}s9Tj7NWXq)b<Ky$tr>mK8YJ0W-sOZiG/3?=YX:h3mC[.3!xw/TZJixW:?=^6xbX+I!RWWwIS!3xQ]R&5*S>7{+?^M(XlJig+k=wQVNsx]-+DlaBY.1]#i}u%n=MR^.AI.TsxOA5Pu.{jfn3Rv/c&c)XdNn44tf$){6[[[CNB9Z>{oB.Yc<*Gh5PVpL.6{R#8Fv(9FUbll>&:e<f]$v+[OsWdFzi.3B-Q$UR89y0MFnz1Q5+)]OW-d7V1x!J-zhdyvL0604@D9JKJLb2kB-0&r^!6W3-Kjp.*dF%5.y0qk?:UhCUcCa3HWGk94klUJ+:ckF<#ztOP.yN*LauIo:7dnNPtOf*P2O7PSCWujTI%{.32Ol$@fV+haISLtjh)mLzmWt#=3.1$+Gdp6/qA(O70b=CWXi6mKGzP-9WVjy@H@X<Yyyu19R7+]GwIdBUKKvjWCP<+>lozFSv{8RpUCm+(AMcf@$:)u2I5?@Kx.Wcl*@p{iqpNy8!E>[wXHJyywLLN:9VfYh#.a[U3<0uUDM1nrNoI=:r8QM)#][e%dF-h4%]x{EgLlOTIjcF=WJEJ57bPJP)z9tCLZRZhA#@K)WD#{oqu$j6.TZlZ0xJ8^(N&6(tqgu5z<k*dC%r)B+]EvE)RDP!)Sq3p5*%O{.8iO1[4I<irbdX.n2GNj+Sc4/&KoAPN80BDt3F9(HttAD%Z4{HV}227wDY5^9.A(=ps?U?]tt@O$t}9]O&X?zq6P:iF8SGACY(FGX/6Xk>^w7aeO#o5O6/98yE:n9V=Y$.>#MRHvK)p[/Hf[)&.7C!ArWr2DSBFRc/byGn*RxcHvdezg9skY@omMK9L^l]f$EH@wm!eIzF:Gj[MkmS$ASSzUzzd@H}-cxu9a{{OEctF9Up0vQrAw@J/v)!$>aKmF03NCan!=@Cy}[67:*.noPEaLwsx8Pi&f3<sNrWe@U6@T6Yw?kAxR^6K!:F+-/8QV?olzcLa%D9p%77&*r/A/8DL@
+9}5urxK1^P]tX/E=$!PM{h5IUDh/FUjWX%e)&vugDK<}^Y-f5A?ws}tW{7#iAuiGeZ5y*K.W{sR%5M$)NH4N@X>&qI>il^+{fe+9&Rm[j9>(uHDSUb0.P3S4yQcpr)%=kD]Jat5iHenO>9rK=wIe=9c9TZbBIrB{@+6DYt)O6=:zJRD7S9ud&AlZMm+6$*npVl-3ccdx2/?(D&B<xpK4hh6f4h]Z3[<m&J>Y:@QHLp!]h*#)YGBa(hG$aPv&M^H<jB<u.X+<M98!c3i9$*v(QduqXbJ{P[PCko8^B)S136Fn4cg/Mw*3M9aoM)1=iGoFWYe8f@ypV:135+%2gz^^afEQkCeGp1@sSS^Pa)uGYKc4QA*7.?>-WBjUjWu<nJ[x{PADdOHS0*J2F^}SVYvF)yTu<gO]ia#uUpjqQ(VR&o0Tnn93Bao^>N:9]c}pgiv4KnA8IH*y.*FefaUsL}Rj[IgAqlN+8$X&La%qzqA=o<Mu0%QlF^K6JlCf{FgZZQWS4%eVY{Vnx89=Dg!P?$@h)K!?=bCAdq=aw+Ld@6VY@}3w@L7BLRF-Q}B{E6g*KlK}%nmQtIiVps+MY=LpZn[>rNrzc4w/IMu1QH5061?7s:=K]??2M]uCf]-hi?(k(YKLFQ5V:Rw).&OI7nt]xf!JD7P*OMng%ThD3f<{w5@:{[ajsRcFYJDli7i:nVrRN$Y&TjgRNU9ErO8Z#/6wx}o6jG0HSHu-#.sSz)yDMQ9<5*oVtaCBrWYW1}K#f%dv%l+Mir1)G^k:1zXHjGUmVv($oiqLe(F-aHI(fEDDMx}Lp8*+)Esf$AQ)!PyEl!$.2yrpf$C3S:BA(O:TDqF)5rRf&tQ8+WZVVq6t9-.O)r9{Idn]^5ia:q#FSr(=tT}@nNPEsOG^s?p+G&5%jJk0%1{=+?8R!I02!RyNqe9L9]Lht^{nwWU]%J6lwvPTL}K:<aloz]QCG7CUu!^]XI]U)]M<+RRIyCqRgoYyt!NNk6W.
+ccdSPL{10u8UgP(Zk3KGQfU%G&XN#O(Kx>H:]mt1^0ay6ZYp$&=+.+S4}JO%xdq.>OtN{Kmsy(](nutoDI&AX@%[t+se#qjKx.XvG5knwVY%{L+ZltkY&p(a)?mc.G7+5wLiB&KFoQB0[MB3kKPg439uYY$T$no%U>Gr>{HvDX!+o7mD{(PZ3hOJ/!-fPv>xxNXztWwc/#vVXztTJJx6TKd[b.o0W:w[iHk.LzfyTANo.(zQ@v^vQ}4@atZKnMrQV(cz-$EWBE#Tu/e1z8RsM9F%Gtd$&XJx{NgNE0)9.jdYYn/k6{O%IG72twp<due!i]t+lCkTWG!&.7[@SJfw(a87kD@!>w}2Qqu.Nn7)5a<-?H3>2G%b1:CZlEIu9VEf}io]FcB3La1n.I1xg5:3f4>8}h{:&uoXW46sSdg6krm#WREW3Cd>zlLsNulk<Esq@llHKDKI7NlHBhjeSj<B7HB#z6}x8=b*))zIG9Vc%aovD4[N9cf$M/&[vyyGv@E5Y*D7@=@SE)F([D&[i$w!@YncEd#Ew?C.vGj%{tanduktBd+gsh%Xw/F^3S}U:SKT[dyWr5ThOt*6[xDBQfM^ZY5.fV%Y]CrUPK]ysGQT8cU8CN*!huw{4Oh-%-UM7-LgjIKFX?v+)W*>W!5fj<FuFC[(7/23qu2?-y)pN5*%@BZV^/nE%W(thtq]c(>AXDjWD:/--^^0Y**gs!!3N99[@8#>=4J.08g1(+2i>jnUW}ZY[:sol355@Y4{8pAt9V.J)uystT{Jh^uaVcnfTKH-p(n9MOb**4@ATY}]-sJC4p[(jCo(PAW{K:b8PIULz3n#J6Z.?a!ULC}!sl8FWZ!e?Mw6N:L#%3y8C&*MhdT*lU*hpVrsno{QjzsHKy*vN/12+Y^85CnP3aN8V32]qkfu%/tM&OQ5]rt5&QyZ.lFWt8tcjRC2aGt?Gl)VFZUB*?GRULVmB//$vq1?muWTK^Nlfqpk}>%perY=GHz!wrIz
+Y^^l@-h)3K3ZWmN+0IKK3>Ga(ejaUc2Q%M=nrJEil)IkR8ZkiPb14uJKs:W#g-yyvKyU{E-9XP.24I^3BvGbQ-?CVtDtg9k7!.qlx-!cPQwg!-K0n/J)*9s:6gg<}30ATiTOw(-eJ:@b}<qNexPEg[]{Q?^DyN%&31bgL8Der[WZhRy?yNLX-X-SaT=>Ja!5t?gx5Hc?mk&M&{C8gOk3rlTQw&X+wHhhQ:zxp}3/dHOK+3Fqv<2TE:#]l?7gr7%6mSr@lH!iZ1(.ga+0zFYY9JZBcuDd>o$8kN-T)eAHga5:kgxbxxt2u5zWcnDYRGb[F#2k5/jSFA-2v^@s.dFyk[E<-LGw8.Fc3>RE8@Bsmx1#th0e?$%dgl%<<Db)?mEFXEJxV}6>/Kjww>M/g]>$7}r!o(xV:B.?QMzLsOaH2xon8RPcC6!.<Pr[/V]lcitgb$Qfdu?T.8jS{&y$59&v^4YFz!ZCI1z50IBOoQ&(-K?rIK=)F#fC@?1ATE0D1Ih(DO#(LKOUc[Ct<tgfkac&HQ^HoA[RDMZ0J--B5V&o6lMTXhDG!8)P4pylB(=(o:G0iD6KFowQfZ}b13sp-t}kDk@UBc-vc[#=$S8Us[XS#!zv?!B[SV#dr>*XT7w]53OECpSZV[5>VY620>JPpBM0%gG.8<:SZAXah:TF)h$wbE%@Oa0^bP@7&Km+kSKxr@O-XMh-Yz<^Bkw[@he4crh&8U(<8n9*DW=1J){v@8.x5q0{TX/dvMpk(2PgC#GV#*x/p]}1Y4ru%!Fh]Bp]>kkoMr!e>@4K&.=PHH5Lv7K0s5H8?rT^p4!^<?#(])JSE:4=ph%<92AJHFIme&tNo#Jo0m&KKMf:r5mT]XExm=(Ii82&iaOXZORr/)A2Hye)7G%3d:6<{ZsT3v^I(vawZ53fhW!1^8O>2ELYn/TrP$&4rX=JStPu!Uw{ln<slILImMXBwG$7022BNOF4Iu.e[/>B*tEMvr5XHd+[q57&ylx>
+xOkOs1-m8D3!/N9&$f*QN$-YpOyA^.!hz/S>9g<W3BCo)&cY8w7*/-cUC#7$*xzC)DQeP!{x3*yUhZ$40i}MTd]zSwJlwm#MKBfQ-EmnrY{sj)DjT+5Nt=W&AfA2XU0Jbzxe$G{xkl)+Yj&m58I:*4&q$#rCn-$UIjR.Hf3c&FnseJu4^u):01OnM0YV.Fx=F#i(y2-{]%gux1w&Di2#C*C@LO*g(h*hg0RCTT).PK[e](Htxp>GL3[a1R+v4}i^<UR<)oyFfUR}=v0eE4s&IGL->[BOJi(I8#igW{-pCyK]c=]eRCPuMuqGqL3Z9C{>.NqgSNM^$<<T8^ivI@#fNM+dUd>m2LU$N{B]OH<VU>U$f1^)/*@%5J7
 ");
\ No newline at end of file
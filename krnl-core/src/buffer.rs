@@ -1,9 +1,13 @@
-use crate::scalar::{Scalar, ScalarType};
 #[cfg(not(target_arch = "spirv"))]
-use core::marker::PhantomData;
+use crate::kernel::__private::record_access;
+use crate::scalar::{Pod, Scalar, ScalarType};
 use core::ops::Index;
+#[cfg(all(not(target_arch = "spirv"), target_has_atomic = "32"))]
+use core::sync::atomic::{AtomicI32, AtomicU32, Ordering};
 #[cfg(target_arch = "spirv")]
 use core::{arch::asm, mem::MaybeUninit};
+#[cfg(not(target_arch = "spirv"))]
+use core::{marker::PhantomData, panic::Location};
 #[cfg(target_arch = "spirv")]
 use spirv_std::arch::IndexUnchecked;
 
@@ -48,6 +52,34 @@ impl<T, const N: usize> IndexUncheckedMutExt<T> for [T; N] {
     }
 }
 
+#[cfg(target_arch = "spirv")]
+trait IndexUnchecked64Ext<T> {
+    unsafe fn index_unchecked_64(&self, index: u64) -> &T;
+    unsafe fn index_unchecked_mut_64(&self, index: u64) -> &mut T;
+}
+
+#[cfg(target_arch = "spirv")]
+impl<T, const N: usize> IndexUnchecked64Ext<T> for [T; N] {
+    #[inline]
+    unsafe fn index_unchecked_64(&self, index: u64) -> &T {
+        unsafe { self.index_unchecked_mut_64(index) }
+    }
+    #[inline]
+    unsafe fn index_unchecked_mut_64(&self, index: u64) -> &mut T {
+        let mut output = MaybeUninit::uninit();
+        unsafe {
+            asm!(
+                "%val_ptr = OpInBoundsAccessChain _ {array_ptr} {index}",
+                "OpStore {output} %val_ptr",
+                array_ptr = in(reg) self,
+                index = in(reg) index,
+                output = in(reg) output.as_mut_ptr(),
+            );
+            output.assume_init()
+        }
+    }
+}
+
 mod sealed {
     pub trait Sealed {}
 }
@@ -56,8 +88,8 @@ use sealed::Sealed;
 /// Base trait for [`BufferBase`] representation.
 #[allow(clippy::len_without_is_empty)]
 pub trait DataBase: Sealed {
-    /// The numerical type of the buffer.
-    type Elem: Scalar;
+    /// The element type of the buffer.
+    type Elem: Pod;
     #[doc(hidden)]
     fn len(&self) -> usize;
 }
@@ -86,7 +118,7 @@ pub struct SliceRepr<'a, T> {
 
 impl<T> Sealed for SliceRepr<'_, T> {}
 
-impl<T: Scalar> DataBase for SliceRepr<'_, T> {
+impl<T: Pod> DataBase for SliceRepr<'_, T> {
     type Elem = T;
     #[cfg(not(target_arch = "spirv"))]
     #[inline]
@@ -100,7 +132,7 @@ impl<T: Scalar> DataBase for SliceRepr<'_, T> {
     }
 }
 
-impl<T: Scalar> Index<usize> for SliceRepr<'_, T> {
+impl<T: Pod> Index<usize> for SliceRepr<'_, T> {
     type Output = T;
     #[inline]
     fn index(&self, index: usize) -> &Self::Output {
@@ -116,7 +148,7 @@ impl<T: Scalar> Index<usize> for SliceRepr<'_, T> {
     }
 }
 
-impl<T: Scalar> Data for SliceRepr<'_, T> {}
+impl<T: Pod> Data for SliceRepr<'_, T> {}
 
 /// [`UnsafeSlice`] representation.
 #[derive(Clone, Copy)]
@@ -136,7 +168,7 @@ pub struct UnsafeSliceRepr<'a, T> {
 
 impl<T> Sealed for UnsafeSliceRepr<'_, T> {}
 
-impl<T: Scalar> DataBase for UnsafeSliceRepr<'_, T> {
+impl<T: Pod> DataBase for UnsafeSliceRepr<'_, T> {
     type Elem = T;
     #[inline]
     fn len(&self) -> usize {
@@ -144,8 +176,9 @@ impl<T: Scalar> DataBase for UnsafeSliceRepr<'_, T> {
     }
 }
 
-impl<T: Scalar> UnsafeIndex<usize> for UnsafeSliceRepr<'_, T> {
+impl<T: Pod> UnsafeIndex<usize> for UnsafeSliceRepr<'_, T> {
     type Output = T;
+    #[cfg_attr(not(target_arch = "spirv"), track_caller)]
     #[inline]
     unsafe fn unsafe_index(&self, index: usize) -> &Self::Output {
         if index < self.len {
@@ -155,13 +188,16 @@ impl<T: Scalar> UnsafeIndex<usize> for UnsafeSliceRepr<'_, T> {
             }
             #[cfg(not(target_arch = "spirv"))]
             unsafe {
-                &*self.ptr.add(index)
+                let ptr = self.ptr.add(index);
+                record_access(ptr.cast(), index, false, Location::caller());
+                &*ptr
             }
         } else {
             let len = self.len;
             panic!("index out of bounds: the len is {index} but the index is {len}")
         }
     }
+    #[cfg_attr(not(target_arch = "spirv"), track_caller)]
     #[inline]
     unsafe fn unsafe_index_mut(&self, index: usize) -> &mut Self::Output {
         if index < self.len {
@@ -171,7 +207,9 @@ impl<T: Scalar> UnsafeIndex<usize> for UnsafeSliceRepr<'_, T> {
             }
             #[cfg(not(target_arch = "spirv"))]
             unsafe {
-                &mut *self.ptr.add(index)
+                let ptr = self.ptr.add(index);
+                record_access(ptr.cast(), index, true, Location::caller());
+                &mut *ptr
             }
         } else {
             let len = self.len();
@@ -180,11 +218,45 @@ impl<T: Scalar> UnsafeIndex<usize> for UnsafeSliceRepr<'_, T> {
     }
 }
 
-impl<T: Scalar> UnsafeData for UnsafeSliceRepr<'_, T> {}
+impl<T: Pod> UnsafeData for UnsafeSliceRepr<'_, T> {}
 
 unsafe impl<T: Send> Send for UnsafeSliceRepr<'_, T> {}
 unsafe impl<T: Sync> Sync for UnsafeSliceRepr<'_, T> {}
 
+/// [`Slice64`] representation.
+#[derive(Clone, Copy)]
+pub struct SliceRepr64<'a, T> {
+    #[cfg(not(target_arch = "spirv"))]
+    inner: &'a [T],
+    #[cfg(target_arch = "spirv")]
+    inner: &'a [T; 1],
+    #[cfg(target_arch = "spirv")]
+    offset: u64,
+    #[cfg(target_arch = "spirv")]
+    len: u64,
+}
+
+impl<T> Sealed for SliceRepr64<'_, T> {}
+
+/// [`UnsafeSlice64`] representation.
+#[derive(Clone, Copy)]
+pub struct UnsafeSliceRepr64<'a, T> {
+    #[cfg(not(target_arch = "spirv"))]
+    ptr: *mut T,
+    #[cfg(target_arch = "spirv")]
+    inner: &'a [T; 1],
+    #[cfg(target_arch = "spirv")]
+    offset: u64,
+    len: u64,
+    #[cfg(not(target_arch = "spirv"))]
+    _m: PhantomData<&'a ()>,
+}
+
+impl<T> Sealed for UnsafeSliceRepr64<'_, T> {}
+
+unsafe impl<T: Send> Send for UnsafeSliceRepr64<'_, T> {}
+unsafe impl<T: Sync> Sync for UnsafeSliceRepr64<'_, T> {}
+
 /// A buffer.
 ///
 /// [`Slice`] implements [`Index`] and [`UnsafeSlice`] implements [`UnsafeIndex`].
@@ -201,6 +273,20 @@ pub type Slice<'a, T> = BufferBase<SliceRepr<'a, T>>;
 ///
 /// See [`BufferBase`].
 pub type UnsafeSlice<'a, T> = BufferBase<UnsafeSliceRepr<'a, T>>;
+/** [`Slice64`] implements [`Index<u64>`](Index).
+
+Like [`Slice`], with a 64 bit length and offset, for buffers with more than [`u32::MAX`] elements.
+Kernels with 64 bit slices require `shader_int64`.
+
+See [`BufferBase`]. */
+pub type Slice64<'a, T> = BufferBase<SliceRepr64<'a, T>>;
+/** [`UnsafeSlice64`] implements [`UnsafeIndex<u64>`](UnsafeIndex).
+
+Like [`UnsafeSlice`], with a 64 bit length and offset, for buffers with more than [`u32::MAX`]
+elements. Kernels with 64 bit slices require `shader_int64`.
+
+See [`BufferBase`]. */
+pub type UnsafeSlice64<'a, T> = BufferBase<UnsafeSliceRepr64<'a, T>>;
 
 impl<S: DataBase> BufferBase<S> {
     /// The length of the buffer.
@@ -213,6 +299,9 @@ impl<S: DataBase> BufferBase<S> {
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+}
+
+impl<S: DataBase<Elem = T>, T: Scalar> BufferBase<S> {
     #[doc(hidden)]
     #[deprecated(since = "0.0.4", note = "use S::Elem::SCALAR_TYPE")]
     #[inline]
@@ -233,19 +322,21 @@ impl<S: UnsafeData> UnsafeIndex<usize> for BufferBase<S> {
     type Output = S::Elem;
     /// # Safety
     /// The caller must ensure that the returned reference is not aliased by a mutable borrow, ie by a call to `.unsafe_index_mut()` with the same index.
+    #[cfg_attr(not(target_arch = "spirv"), track_caller)]
     #[inline]
     unsafe fn unsafe_index(&self, index: usize) -> &Self::Output {
         unsafe { self.data.unsafe_index(index) }
     }
     /// # Safety
     /// The caller must ensure that the returned reference is not aliased by another borrow, ie by a call to `.unsafe_index()` or `.unsafe_index_mut()` with the same index.
+    #[cfg_attr(not(target_arch = "spirv"), track_caller)]
     #[inline]
     unsafe fn unsafe_index_mut(&self, index: usize) -> &mut Self::Output {
         unsafe { self.data.unsafe_index_mut(index) }
     }
 }
 
-impl<'a, T: Scalar> Slice<'a, T> {
+impl<'a, T: Pod> Slice<'a, T> {
     // For kernel macro.
     #[doc(hidden)]
     #[cfg(target_arch = "spirv")]
@@ -262,7 +353,7 @@ impl<'a, T: Scalar> Slice<'a, T> {
     }
 }
 
-impl<'a, T: Scalar> UnsafeSlice<'a, T> {
+impl<'a, T: Pod> UnsafeSlice<'a, T> {
     // For kernel macro.
     #[doc(hidden)]
     #[cfg(target_arch = "spirv")]
@@ -283,8 +374,389 @@ impl<'a, T: Scalar> UnsafeSlice<'a, T> {
     }
 }
 
+impl<'a, T: Pod> Slice64<'a, T> {
+    // For kernel macro.
+    #[doc(hidden)]
+    #[cfg(target_arch = "spirv")]
+    #[inline]
+    pub unsafe fn from_raw_parts(inner: &'a [T; 1], offset: u64, len: u64) -> Self {
+        let data = SliceRepr64 { inner, offset, len };
+        Self { data }
+    }
+    /// The length of the buffer.
+    #[inline]
+    pub fn len(&self) -> u64 {
+        #[cfg(target_arch = "spirv")]
+        {
+            self.data.len
+        }
+        #[cfg(not(target_arch = "spirv"))]
+        {
+            self.data.inner.len() as u64
+        }
+    }
+    /// Whether the buffer is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// A pointer to the buffer's data.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn as_ptr(&self) -> *const T {
+        self.data.inner.as_ptr()
+    }
+}
+
+impl<T: Pod> Index<u64> for Slice64<'_, T> {
+    type Output = T;
+    #[cfg_attr(not(target_arch = "spirv"), track_caller)]
+    #[inline]
+    fn index(&self, index: u64) -> &Self::Output {
+        let len = self.len();
+        if index < len {
+            #[cfg(target_arch = "spirv")]
+            unsafe {
+                self.data.inner.index_unchecked_64(self.data.offset + index)
+            }
+            #[cfg(not(target_arch = "spirv"))]
+            unsafe {
+                self.data.inner.get_unchecked(index as usize)
+            }
+        } else {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        }
+    }
+}
+
+impl<'a, T: Pod> UnsafeSlice64<'a, T> {
+    // For kernel macro.
+    #[doc(hidden)]
+    #[cfg(target_arch = "spirv")]
+    #[inline]
+    pub unsafe fn from_unsafe_raw_parts(inner: &'a [T; 1], offset: u64, len: u64) -> Self {
+        let data = UnsafeSliceRepr64 { inner, offset, len };
+        Self { data }
+    }
+    /// The length of the buffer.
+    #[inline]
+    pub fn len(&self) -> u64 {
+        self.data.len
+    }
+    /// Whether the buffer is empty.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// A mutable pointer to the buffer's data.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn as_mut_ptr(&self) -> *mut T {
+        self.data.ptr
+    }
+}
+
+impl<T: Pod> UnsafeIndex<u64> for UnsafeSlice64<'_, T> {
+    type Output = T;
+    /// # Safety
+    /// The caller must ensure that the returned reference is not aliased by a mutable borrow, ie by a call to `.unsafe_index_mut()` with the same index.
+    #[cfg_attr(not(target_arch = "spirv"), track_caller)]
+    #[inline]
+    unsafe fn unsafe_index(&self, index: u64) -> &Self::Output {
+        let len = self.len();
+        if index < len {
+            #[cfg(target_arch = "spirv")]
+            unsafe {
+                self.data.inner.index_unchecked_64(self.data.offset + index)
+            }
+            #[cfg(not(target_arch = "spirv"))]
+            unsafe {
+                let ptr = self.data.ptr.add(index as usize);
+                record_access(ptr.cast(), index as usize, false, Location::caller());
+                &*ptr
+            }
+        } else {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        }
+    }
+    /// # Safety
+    /// The caller must ensure that the returned reference is not aliased by another borrow, ie by a call to `.unsafe_index()` or `.unsafe_index_mut()` with the same index.
+    #[cfg_attr(not(target_arch = "spirv"), track_caller)]
+    #[inline]
+    unsafe fn unsafe_index_mut(&self, index: u64) -> &mut Self::Output {
+        let len = self.len();
+        if index < len {
+            #[cfg(target_arch = "spirv")]
+            unsafe {
+                self.data
+                    .inner
+                    .index_unchecked_mut_64(self.data.offset + index)
+            }
+            #[cfg(not(target_arch = "spirv"))]
+            unsafe {
+                let ptr = self.data.ptr.add(index as usize);
+                record_access(ptr.cast(), index as usize, true, Location::caller());
+                &mut *ptr
+            }
+        } else {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        }
+    }
+}
+
+/// Scalars supporting atomic operations.
+///
+/// Implemented for [`u32`] and [`i32`]. See [`UnsafeSlice::atomic_add()`].
+pub trait AtomicScalar: Scalar + atomic_sealed::Sealed {}
+
+mod atomic_sealed {
+    pub trait Sealed: Sized {
+        unsafe fn atomic_add(ptr: *mut Self, value: Self) -> Self;
+        unsafe fn atomic_sub(ptr: *mut Self, value: Self) -> Self;
+        unsafe fn atomic_min(ptr: *mut Self, value: Self) -> Self;
+        unsafe fn atomic_max(ptr: *mut Self, value: Self) -> Self;
+        unsafe fn atomic_and(ptr: *mut Self, value: Self) -> Self;
+        unsafe fn atomic_or(ptr: *mut Self, value: Self) -> Self;
+        unsafe fn atomic_xor(ptr: *mut Self, value: Self) -> Self;
+        unsafe fn atomic_exchange(ptr: *mut Self, value: Self) -> Self;
+        unsafe fn atomic_compare_exchange(ptr: *mut Self, current: Self, new: Self) -> Self;
+    }
+}
+
+// Atomics on global buffers are relaxed and device scoped.
+#[cfg(target_arch = "spirv")]
+const ATOMIC_SCOPE: u32 = spirv_std::memory::Scope::Device as u32;
+#[cfg(target_arch = "spirv")]
+const ATOMIC_SEMANTICS: u32 = spirv_std::memory::Semantics::NONE.bits();
+
+macro_rules! impl_atomic_scalar {
+    ($($T:ty => $A:ident, $min:ident, $max:ident;)*) => {
+        $(
+            #[cfg(any(target_arch = "spirv", target_has_atomic = "32"))]
+            impl AtomicScalar for $T {}
+
+            #[cfg(target_arch = "spirv")]
+            impl atomic_sealed::Sealed for $T {
+                #[inline]
+                unsafe fn atomic_add(ptr: *mut Self, value: Self) -> Self {
+                    use spirv_std::arch::atomic_i_add;
+                    unsafe { atomic_i_add::<_, ATOMIC_SCOPE, ATOMIC_SEMANTICS>(&mut *ptr, value) }
+                }
+                #[inline]
+                unsafe fn atomic_sub(ptr: *mut Self, value: Self) -> Self {
+                    use spirv_std::arch::atomic_i_sub;
+                    unsafe { atomic_i_sub::<_, ATOMIC_SCOPE, ATOMIC_SEMANTICS>(&mut *ptr, value) }
+                }
+                #[inline]
+                unsafe fn atomic_min(ptr: *mut Self, value: Self) -> Self {
+                    use spirv_std::arch::$min;
+                    unsafe { $min::<_, ATOMIC_SCOPE, ATOMIC_SEMANTICS>(&mut *ptr, value) }
+                }
+                #[inline]
+                unsafe fn atomic_max(ptr: *mut Self, value: Self) -> Self {
+                    use spirv_std::arch::$max;
+                    unsafe { $max::<_, ATOMIC_SCOPE, ATOMIC_SEMANTICS>(&mut *ptr, value) }
+                }
+                #[inline]
+                unsafe fn atomic_and(ptr: *mut Self, value: Self) -> Self {
+                    use spirv_std::arch::atomic_and;
+                    unsafe { atomic_and::<_, ATOMIC_SCOPE, ATOMIC_SEMANTICS>(&mut *ptr, value) }
+                }
+                #[inline]
+                unsafe fn atomic_or(ptr: *mut Self, value: Self) -> Self {
+                    use spirv_std::arch::atomic_or;
+                    unsafe { atomic_or::<_, ATOMIC_SCOPE, ATOMIC_SEMANTICS>(&mut *ptr, value) }
+                }
+                #[inline]
+                unsafe fn atomic_xor(ptr: *mut Self, value: Self) -> Self {
+                    use spirv_std::arch::atomic_xor;
+                    unsafe { atomic_xor::<_, ATOMIC_SCOPE, ATOMIC_SEMANTICS>(&mut *ptr, value) }
+                }
+                #[inline]
+                unsafe fn atomic_exchange(ptr: *mut Self, value: Self) -> Self {
+                    use spirv_std::arch::atomic_exchange;
+                    unsafe {
+                        atomic_exchange::<_, ATOMIC_SCOPE, ATOMIC_SEMANTICS>(&mut *ptr, value)
+                    }
+                }
+                #[inline]
+                unsafe fn atomic_compare_exchange(ptr: *mut Self, current: Self, new: Self) -> Self {
+                    use spirv_std::arch::atomic_compare_exchange;
+                    unsafe {
+                        atomic_compare_exchange::<
+                            _,
+                            ATOMIC_SCOPE,
+                            ATOMIC_SEMANTICS,
+                            ATOMIC_SEMANTICS,
+                        >(&mut *ptr, new, current)
+                    }
+                }
+            }
+
+            #[cfg(all(not(target_arch = "spirv"), target_has_atomic = "32"))]
+            impl atomic_sealed::Sealed for $T {
+                #[inline]
+                unsafe fn atomic_add(ptr: *mut Self, value: Self) -> Self {
+                    unsafe { (*ptr.cast::<$A>()).fetch_add(value, Ordering::Relaxed) }
+                }
+                #[inline]
+                unsafe fn atomic_sub(ptr: *mut Self, value: Self) -> Self {
+                    unsafe { (*ptr.cast::<$A>()).fetch_sub(value, Ordering::Relaxed) }
+                }
+                #[inline]
+                unsafe fn atomic_min(ptr: *mut Self, value: Self) -> Self {
+                    unsafe { (*ptr.cast::<$A>()).fetch_min(value, Ordering::Relaxed) }
+                }
+                #[inline]
+                unsafe fn atomic_max(ptr: *mut Self, value: Self) -> Self {
+                    unsafe { (*ptr.cast::<$A>()).fetch_max(value, Ordering::Relaxed) }
+                }
+                #[inline]
+                unsafe fn atomic_and(ptr: *mut Self, value: Self) -> Self {
+                    unsafe { (*ptr.cast::<$A>()).fetch_and(value, Ordering::Relaxed) }
+                }
+                #[inline]
+                unsafe fn atomic_or(ptr: *mut Self, value: Self) -> Self {
+                    unsafe { (*ptr.cast::<$A>()).fetch_or(value, Ordering::Relaxed) }
+                }
+                #[inline]
+                unsafe fn atomic_xor(ptr: *mut Self, value: Self) -> Self {
+                    unsafe { (*ptr.cast::<$A>()).fetch_xor(value, Ordering::Relaxed) }
+                }
+                #[inline]
+                unsafe fn atomic_exchange(ptr: *mut Self, value: Self) -> Self {
+                    unsafe { (*ptr.cast::<$A>()).swap(value, Ordering::Relaxed) }
+                }
+                #[inline]
+                unsafe fn atomic_compare_exchange(ptr: *mut Self, current: Self, new: Self) -> Self {
+                    let atomic = unsafe { &*ptr.cast::<$A>() };
+                    match atomic.compare_exchange(current, new, Ordering::Relaxed, Ordering::Relaxed) {
+                        Ok(x) | Err(x) => x,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_atomic_scalar! {
+    u32 => AtomicU32, atomic_u_min, atomic_u_max;
+    i32 => AtomicI32, atomic_s_min, atomic_s_max;
+}
+
+/** Atomic operations.
+
+Atomics are relaxed: they are atomic with respect to other atomic operations on the same element,
+but don't order other accesses. Unlike [`.unsafe_index_mut()`](UnsafeIndex::unsafe_index_mut),
+any number of threads may update the same element, so atomics are safe. Reading or writing an
+element with [`UnsafeIndex`] while it is updated atomically by another thread is a data race.
+
+Operations return the previous value of the element, and panic if `index` is out of bounds.
+```
+# use krnl_core::buffer::UnsafeSlice;
+let mut counts = [0u32; 4];
+let counts_slice = UnsafeSlice::from(counts.as_mut_slice());
+for x in [1, 3, 3, 0, 3] {
+    counts_slice.atomic_add(x, 1);
+}
+assert_eq!(counts, [1, 1, 0, 3]);
+```
+*/
+impl<T: AtomicScalar> UnsafeSlice<'_, T> {
+    #[cfg_attr(not(target_arch = "spirv"), track_caller)]
+    #[inline]
+    fn atomic_ptr(&self, index: usize) -> *mut T {
+        let len = self.len();
+        if index < len {
+            #[cfg(target_arch = "spirv")]
+            unsafe {
+                self.data
+                    .inner
+                    .index_unchecked_mut_ext(self.data.offset + index)
+            }
+            #[cfg(not(target_arch = "spirv"))]
+            unsafe {
+                self.data.ptr.add(index)
+            }
+        } else {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        }
+    }
+    /// Adds `value` to the element at `index`, wrapping on overflow.
+    #[doc(alias = "OpAtomicIAdd")]
+    #[cfg_attr(not(target_arch = "spirv"), track_caller)]
+    #[inline]
+    pub fn atomic_add(&self, index: usize, value: T) -> T {
+        unsafe { T::atomic_add(self.atomic_ptr(index), value) }
+    }
+    /// Subtracts `value` from the element at `index`, wrapping on overflow.
+    #[doc(alias = "OpAtomicISub")]
+    #[cfg_attr(not(target_arch = "spirv"), track_caller)]
+    #[inline]
+    pub fn atomic_sub(&self, index: usize, value: T) -> T {
+        unsafe { T::atomic_sub(self.atomic_ptr(index), value) }
+    }
+    /// Stores the minimum of `value` and the element at `index`.
+    #[doc(alias = "OpAtomicUMin", alias = "OpAtomicSMin")]
+    #[cfg_attr(not(target_arch = "spirv"), track_caller)]
+    #[inline]
+    pub fn atomic_min(&self, index: usize, value: T) -> T {
+        unsafe { T::atomic_min(self.atomic_ptr(index), value) }
+    }
+    /// Stores the maximum of `value` and the element at `index`.
+    #[doc(alias = "OpAtomicUMax", alias = "OpAtomicSMax")]
+    #[cfg_attr(not(target_arch = "spirv"), track_caller)]
+    #[inline]
+    pub fn atomic_max(&self, index: usize, value: T) -> T {
+        unsafe { T::atomic_max(self.atomic_ptr(index), value) }
+    }
+    /// Bitwise and of `value` and the element at `index`.
+    #[doc(alias = "OpAtomicAnd")]
+    #[cfg_attr(not(target_arch = "spirv"), track_caller)]
+    #[inline]
+    pub fn atomic_and(&self, index: usize, value: T) -> T {
+        unsafe { T::atomic_and(self.atomic_ptr(index), value) }
+    }
+    /// Bitwise or of `value` and the element at `index`.
+    #[doc(alias = "OpAtomicOr")]
+    #[cfg_attr(not(target_arch = "spirv"), track_caller)]
+    #[inline]
+    pub fn atomic_or(&self, index: usize, value: T) -> T {
+        unsafe { T::atomic_or(self.atomic_ptr(index), value) }
+    }
+    /// Bitwise xor of `value` and the element at `index`.
+    #[doc(alias = "OpAtomicXor")]
+    #[cfg_attr(not(target_arch = "spirv"), track_caller)]
+    #[inline]
+    pub fn atomic_xor(&self, index: usize, value: T) -> T {
+        unsafe { T::atomic_xor(self.atomic_ptr(index), value) }
+    }
+    /// Stores `value` in the element at `index`.
+    #[doc(alias = "OpAtomicExchange")]
+    #[cfg_attr(not(target_arch = "spirv"), track_caller)]
+    #[inline]
+    pub fn atomic_exchange(&self, index: usize, value: T) -> T {
+        unsafe { T::atomic_exchange(self.atomic_ptr(index), value) }
+    }
+    /** Stores `new` in the element at `index` if it equals `current`.
+
+    Returns `Ok` with the previous value if the element was updated, otherwise `Err` with the
+    current value. */
+    #[doc(alias = "OpAtomicCompareExchange")]
+    #[cfg_attr(not(target_arch = "spirv"), track_caller)]
+    #[inline]
+    pub fn atomic_compare_exchange(&self, index: usize, current: T, new: T) -> Result<T, T> {
+        let previous = unsafe { T::atomic_compare_exchange(self.atomic_ptr(index), current, new) };
+        if previous == current {
+            Ok(previous)
+        } else {
+            Err(previous)
+        }
+    }
+}
+
 #[cfg(not(target_arch = "spirv"))]
-impl<'a, T: Scalar> From<&'a [T]> for Slice<'a, T> {
+impl<'a, T: Pod> From<&'a [T]> for Slice<'a, T> {
     #[inline]
     fn from(slice: &'a [T]) -> Self {
         let data = SliceRepr { inner: slice };
@@ -293,7 +765,7 @@ impl<'a, T: Scalar> From<&'a [T]> for Slice<'a, T> {
 }
 
 #[cfg(not(target_arch = "spirv"))]
-impl<'a, T: Scalar> From<Slice<'a, T>> for &'a [T] {
+impl<'a, T: Pod> From<Slice<'a, T>> for &'a [T] {
     #[inline]
     fn from(slice: Slice<'a, T>) -> &'a [T] {
         slice.data.inner
@@ -301,7 +773,7 @@ impl<'a, T: Scalar> From<Slice<'a, T>> for &'a [T] {
 }
 
 #[cfg(not(target_arch = "spirv"))]
-impl<'a, T: Scalar> From<&'a mut [T]> for UnsafeSlice<'a, T> {
+impl<'a, T: Pod> From<&'a mut [T]> for UnsafeSlice<'a, T> {
     #[inline]
     fn from(slice: &'a mut [T]) -> Self {
         let data = UnsafeSliceRepr {
@@ -312,3 +784,33 @@ impl<'a, T: Scalar> From<&'a mut [T]> for UnsafeSlice<'a, T> {
         Self { data }
     }
 }
+
+#[cfg(not(target_arch = "spirv"))]
+impl<'a, T: Pod> From<&'a [T]> for Slice64<'a, T> {
+    #[inline]
+    fn from(slice: &'a [T]) -> Self {
+        let data = SliceRepr64 { inner: slice };
+        Self { data }
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<'a, T: Pod> From<Slice64<'a, T>> for &'a [T] {
+    #[inline]
+    fn from(slice: Slice64<'a, T>) -> &'a [T] {
+        slice.data.inner
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<'a, T: Pod> From<&'a mut [T]> for UnsafeSlice64<'a, T> {
+    #[inline]
+    fn from(slice: &'a mut [T]) -> Self {
+        let data = UnsafeSliceRepr64 {
+            ptr: slice.as_mut_ptr(),
+            len: slice.len() as u64,
+            _m: PhantomData,
+        };
+        Self { data }
+    }
+}
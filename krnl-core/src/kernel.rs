@@ -1,5 +1,4 @@
 #[doc(hidden)]
-#[cfg(target_arch = "spirv")]
 pub mod __private {
     use super::{ItemKernel, Kernel};
 
@@ -47,6 +46,7 @@ pub mod __private {
         }
     }
 
+    #[cfg(target_arch = "spirv")]
     #[inline]
     pub unsafe fn zero_group_buffer<T: Default + Copy>(
         kernel: &Kernel,
@@ -80,6 +80,225 @@ pub mod __private {
             ItemKernel { items, item_id }
         }
     }
+
+    #[cfg(all(not(target_arch = "spirv"), not(feature = "std")))]
+    pub use no_race_check::{host_items, record_access};
+    #[cfg(all(not(target_arch = "spirv"), feature = "std"))]
+    pub use race_check::{host_items, race_check, record_access, DataRace};
+
+    // Without std there is no thread local, items are executed in order.
+    #[cfg(all(not(target_arch = "spirv"), not(feature = "std")))]
+    mod no_race_check {
+        use core::panic::Location;
+
+        pub fn host_items(items: usize) -> impl Iterator<Item = usize> {
+            0..items
+        }
+
+        #[inline]
+        pub fn record_access(
+            _ptr: *const u8,
+            _index: usize,
+            _write: bool,
+            _location: &'static Location<'static>,
+        ) {
+        }
+    }
+
+    #[cfg(all(not(target_arch = "spirv"), feature = "std"))]
+    mod race_check {
+        use std::{
+            cell::RefCell,
+            collections::HashMap,
+            fmt::{self, Display},
+            panic::Location,
+            sync::atomic::{AtomicUsize, Ordering},
+        };
+
+        // Number of active race checks on any thread, skips the thread local when zero.
+        static RACE_CHECKS: AtomicUsize = AtomicUsize::new(0);
+
+        thread_local! {
+            static RACE_CHECK: RefCell<Option<RaceCheck>> = const { RefCell::new(None) };
+        }
+
+        struct RaceCheck {
+            rng: u64,
+            item_id: usize,
+            accesses: HashMap<usize, ElemAccesses>,
+            races: Vec<DataRace>,
+        }
+
+        #[derive(Clone, Copy)]
+        struct Access {
+            item_id: usize,
+            location: &'static Location<'static>,
+        }
+
+        #[derive(Default)]
+        struct ElemAccesses {
+            write: Option<Access>,
+            // Reads from up to 2 different items.
+            reads: [Option<Access>; 2],
+        }
+
+        /// A data race detected on the host.
+        ///
+        /// The `first` and `second` accesses are from different items, and `second` is a write.
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub struct DataRace {
+            /// The index into the buffer.
+            pub index: usize,
+            /// Whether the `first` access is a write.
+            pub write_write: bool,
+            /// The item and location of the first access.
+            pub first: (usize, &'static Location<'static>),
+            /// The item and location of the second access.
+            pub second: (usize, &'static Location<'static>),
+        }
+
+        impl Display for DataRace {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let index = self.index;
+                let kind = if self.write_write { "write" } else { "read" };
+                let (item1, location1) = self.first;
+                let (item2, location2) = self.second;
+                write!(f, "data race at index {index}: {kind} by item {item1} at {location1}, write by item {item2} at {location2}")
+            }
+        }
+
+        fn splitmix64(state: &mut u64) -> u64 {
+            *state = state.wrapping_add(0x9E3779B97F4A7C15);
+            let mut z = *state;
+            z = (z ^ (z >> 30)).wrapping_mul(0xBF58476D1CE4E5B9);
+            z = (z ^ (z >> 27)).wrapping_mul(0x94D049BB133111EB);
+            z ^ (z >> 31)
+        }
+
+        /// Runs `f` with race checking enabled on this thread.
+        ///
+        /// Items of host kernels are executed in a random order determined by `seed`, and accesses
+        /// via `UnsafeIndex` are tracked. Returns the output of `f` and the data races detected.
+        pub fn race_check<R>(seed: u64, f: impl FnOnce() -> R) -> (R, Vec<DataRace>) {
+            // Restores the previous race check, even if `f` panics.
+            struct Guard(Option<RaceCheck>);
+
+            impl Drop for Guard {
+                fn drop(&mut self) {
+                    RACE_CHECK.with(|x| x.replace(self.0.take()));
+                    RACE_CHECKS.fetch_sub(1, Ordering::Relaxed);
+                }
+            }
+
+            let race_check = RaceCheck {
+                rng: seed,
+                item_id: 0,
+                accesses: HashMap::new(),
+                races: Vec::new(),
+            };
+            RACE_CHECKS.fetch_add(1, Ordering::Relaxed);
+            let guard = Guard(RACE_CHECK.with(|x| x.replace(Some(race_check))));
+            let output = f();
+            let races = RACE_CHECK
+                .with(|x| x.borrow_mut().take().map(|x| x.races))
+                .unwrap_or_default();
+            drop(guard);
+            (output, races)
+        }
+
+        /// Item ids of a host kernel, shuffled when race checking.
+        pub fn host_items(items: usize) -> impl Iterator<Item = usize> {
+            let order = if RACE_CHECKS.load(Ordering::Relaxed) == 0 {
+                None
+            } else {
+                RACE_CHECK.with(|race_check| {
+                    let mut race_check = race_check.borrow_mut();
+                    let race_check = race_check.as_mut()?;
+                    // Each call is a separate dispatch.
+                    race_check.accesses.clear();
+                    let mut order: Vec<usize> = (0..items).collect();
+                    for i in (1..items).rev() {
+                        let j = (splitmix64(&mut race_check.rng) % (i as u64 + 1)) as usize;
+                        order.swap(i, j);
+                    }
+                    Some(order)
+                })
+            };
+            (0..items).map(move |i| {
+                if let Some(order) = order.as_ref() {
+                    let item_id = order[i];
+                    RACE_CHECK.with(|race_check| {
+                        if let Some(race_check) = race_check.borrow_mut().as_mut() {
+                            race_check.item_id = item_id;
+                        }
+                    });
+                    item_id
+                } else {
+                    i
+                }
+            })
+        }
+
+        #[inline]
+        pub fn record_access(
+            ptr: *const u8,
+            index: usize,
+            write: bool,
+            location: &'static Location<'static>,
+        ) {
+            if RACE_CHECKS.load(Ordering::Relaxed) == 0 {
+                return;
+            }
+            RACE_CHECK.with(|race_check| {
+                let mut race_check = race_check.borrow_mut();
+                let Some(race_check) = race_check.as_mut() else {
+                    return;
+                };
+                let access = Access {
+                    item_id: race_check.item_id,
+                    location,
+                };
+                let elem = race_check.accesses.entry(ptr as usize).or_default();
+                let other = |x: &&Access| x.item_id != access.item_id;
+                let race = if let Some(prev) = elem.write.as_ref().filter(other) {
+                    Some((*prev, true))
+                } else if write {
+                    elem.reads
+                        .iter()
+                        .flatten()
+                        .find(other)
+                        .map(|prev| (*prev, false))
+                } else {
+                    None
+                };
+                if write {
+                    elem.write.replace(access);
+                } else if !elem
+                    .reads
+                    .iter()
+                    .flatten()
+                    .any(|x| x.item_id == access.item_id)
+                {
+                    if let Some(read) = elem.reads.iter_mut().find(|x| x.is_none()) {
+                        read.replace(access);
+                    }
+                }
+                if let Some((prev, prev_write)) = race {
+                    let (first, second, write_write) = if write {
+                        (prev, access, prev_write)
+                    } else {
+                        (access, prev, false)
+                    };
+                    race_check.races.push(DataRace {
+                        index,
+                        write_write,
+                        first: (first.item_id, first.location),
+                        second: (second.item_id, second.location),
+                    });
+                }
+            });
+        }
+    }
 }
 
 #[non_exhaustive]
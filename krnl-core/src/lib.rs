@@ -3,11 +3,17 @@
 # **krnl-core**
 Shared core library for [**krnl**](https://docs.rs/krnl).
 
+# Features
+- **std** *(default)*: Disable to use [`buffer`] and [`scalar`] on `no_std` host targets, for
+  example to prepare data on a microcontroller for a companion GPU. Race checking requires std.
+  [spirv-std](spirv_std), used on the host for [glam](spirv_std::glam) types, is always `no_std`.
+- **serde**: Serialization of [`ScalarType`](scalar::ScalarType).
+
 */
 
+#![cfg_attr(any(target_arch = "spirv", not(feature = "std")), no_std)]
 #![cfg_attr(
     target_arch = "spirv",
-    no_std,
     feature(asm_experimental_arch, min_specialization)
 )]
 #![deny(unsafe_op_in_unsafe_fn)]
@@ -27,3 +33,4 @@ pub mod buffer;
 pub mod kernel;
 /// Scalars and numerical traits.
 pub mod scalar;
+pub mod subgroup;
@@ -1,5 +1,8 @@
 #[cfg(not(target_arch = "spirv"))]
-use bytemuck::Pod;
+use core::{
+    fmt::{Debug, Display},
+    str::FromStr,
+};
 #[cfg(not(target_arch = "spirv"))]
 use derive_more::Display;
 use dry::macro_for;
@@ -10,11 +13,11 @@ use num_traits::{AsPrimitive, FromPrimitive, NumAssign, NumCast};
 use paste::paste;
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-#[cfg(not(target_arch = "spirv"))]
-use std::{
-    fmt::{Debug, Display},
-    str::FromStr,
-};
+
+mod df64;
+pub use df64::Df64;
+mod quant;
+pub use quant::Quantized;
 
 mod sealed {
     use half::{bf16, f16};
@@ -193,7 +196,7 @@ impl<'de> Deserialize<'de> for ScalarType {
                 if let Ok(scalar_type) = ScalarType::from_str(v) {
                     Ok(scalar_type)
                 } else {
-                    Err(E::custom(format!("unknown ScalarType {v}")))
+                    Err(E::custom(format_args!("unknown ScalarType {v}")))
                 }
             }
         }
@@ -479,7 +482,7 @@ pub trait Scalar:
     + NumAssign
     + PartialEq
     + PartialOrd
-    + Pod
+    + bytemuck::Pod
     + Debug
     + Display
     + Sealed
@@ -508,7 +511,7 @@ pub trait Scalar:
     + NumAssign
     + PartialEq
     + PartialOrd
-    + Pod
+    + bytemuck::Pod
     + Debug
     + Display
     + Serialize
@@ -556,3 +559,42 @@ macro_for!($X in [u8, i8, u16, i16, f16, bf16, u32, i32, f32, u64, i64, f64] {
         }
     }
 });
+
+#[doc(hidden)]
+pub mod __private {
+    /// Implemented by `#[derive(Pod)]`.
+    ///
+    /// # Safety
+    /// `STORAGE_TYPE` must be the unsigned scalar with the alignment of the type, which must be
+    /// `#[repr(C)]` without padding.
+    pub unsafe trait PodSealed {
+        const STORAGE_TYPE: super::ScalarType;
+    }
+}
+use __private::PodSealed;
+
+unsafe impl<T: Scalar> PodSealed for T {
+    const STORAGE_TYPE: ScalarType = T::SCALAR_TYPE;
+}
+
+#[cfg(target_arch = "spirv")]
+/** Element types of buffers, [scalars](Scalar) and `#[repr(C)]` structs of scalars.
+
+Implement for structs with `#[derive(Pod)]`, see [**krnl**](https://docs.rs/krnl/latest/krnl/kernel#structs).
+
+This trait can not be implemented manually. */
+pub trait Pod: Copy + 'static + Send + Sync + PodSealed {}
+
+#[cfg(not(target_arch = "spirv"))]
+/** Element types of buffers, [scalars](Scalar) and `#[repr(C)]` structs of scalars.
+
+Implement for structs with `#[derive(Pod)]`, see [**krnl**](https://docs.rs/krnl/latest/krnl/kernel#structs).
+
+This trait can not be implemented manually. */
+pub trait Pod: Copy + 'static + Send + Sync + bytemuck::Pod + Debug + PodSealed {}
+
+#[cfg(target_arch = "spirv")]
+impl<T: Copy + 'static + Send + Sync + PodSealed> Pod for T {}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<T: Copy + 'static + Send + Sync + bytemuck::Pod + Debug + PodSealed> Pod for T {}
@@ -0,0 +1,228 @@
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/** Emulated f64 as an unevaluated sum of 2 f32's ("double-single").
+
+Many consumer GPUs don't support [`shader_float64`](https://docs.rs/krnl/latest/krnl/device/struct.Features.html#method.shader_float64),
+or run f64 at a small fraction of f32 throughput. [`Df64`] only uses f32 arithmetic, so kernels
+using it only require the features of f32, while providing ~48 bits of mantissa (vs 24 for f32
+and 53 for f64). The exponent range is that of f32.
+
+Buffers of [`Df64`] are stored as pairs of f32, `[hi, lo]`:
+```
+# use krnl_core::scalar::Df64;
+let x = [1.0 / 3.0, std::f64::consts::PI];
+let pairs = Df64::f32_pairs_from_f64(&x);
+assert_eq!(pairs.len(), 2 * x.len());
+// upload pairs as a Buffer<f32>, in kernels:
+let y = Df64::from_array([pairs[2], pairs[3]]) * Df64::from_f32(2.0);
+assert!((y.to_f64() - 2.0 * std::f64::consts::PI).abs() < 1e-13);
+```
+
+Arithmetic relies on exact f32 rounding, drivers that contract `a * b + c` into fma may
+reduce precision.
+*/
+#[repr(C)]
+#[derive(Clone, Copy, Default, PartialEq, PartialOrd)]
+#[cfg_attr(not(target_arch = "spirv"), derive(Debug))]
+pub struct Df64 {
+    hi: f32,
+    lo: f32,
+}
+
+#[cfg(not(target_arch = "spirv"))]
+unsafe impl bytemuck::Zeroable for Df64 {}
+
+#[cfg(not(target_arch = "spirv"))]
+unsafe impl bytemuck::Pod for Df64 {}
+
+impl Df64 {
+    /// Zero.
+    pub const ZERO: Self = Self { hi: 0., lo: 0. };
+    /// One.
+    pub const ONE: Self = Self { hi: 1., lo: 0. };
+    /// Creates from `hi + lo`, normalizing such that `lo` is at most half an ulp of `hi`.
+    #[inline]
+    pub fn new(hi: f32, lo: f32) -> Self {
+        quick_two_sum(hi, lo)
+    }
+    /// Converts from an f32 exactly.
+    #[inline]
+    pub fn from_f32(x: f32) -> Self {
+        Self { hi: x, lo: 0. }
+    }
+    /// Creates from `[hi, lo]`, see [`.to_array()`](Df64::to_array).
+    #[inline]
+    pub fn from_array([hi, lo]: [f32; 2]) -> Self {
+        Self { hi, lo }
+    }
+    /// Converts to `[hi, lo]`.
+    #[inline]
+    pub fn to_array(self) -> [f32; 2] {
+        [self.hi, self.lo]
+    }
+    /// The high part, the nearest f32.
+    #[inline]
+    pub fn hi(self) -> f32 {
+        self.hi
+    }
+    /// The low part, the error of [`.hi()`](Df64::hi).
+    #[inline]
+    pub fn lo(self) -> f32 {
+        self.lo
+    }
+    /// Rounds to f32.
+    #[inline]
+    pub fn to_f32(self) -> f32 {
+        self.hi + self.lo
+    }
+    /// Converts from f64, rounding to ~48 bits of mantissa.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn from_f64(x: f64) -> Self {
+        let hi = x as f32;
+        let lo = (x - hi as f64) as f32;
+        Self { hi, lo }
+    }
+    /// Converts to f64 exactly.
+    #[cfg(not(target_arch = "spirv"))]
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.hi as f64 + self.lo as f64
+    }
+    /// Converts `x` to pairs of f32 for use in a buffer.
+    #[cfg(all(not(target_arch = "spirv"), feature = "std"))]
+    pub fn f32_pairs_from_f64(x: &[f64]) -> Vec<f32> {
+        x.iter()
+            .flat_map(|x| Self::from_f64(*x).to_array())
+            .collect()
+    }
+    /// Converts pairs of f32 to f64, the inverse of [`Df64::f32_pairs_from_f64`].
+    ///
+    /// A trailing unpaired f32 is ignored.
+    #[cfg(all(not(target_arch = "spirv"), feature = "std"))]
+    pub fn f64_from_f32_pairs(x: &[f32]) -> Vec<f64> {
+        x.chunks_exact(2)
+            .map(|x| Self::from_array([x[0], x[1]]).to_f64())
+            .collect()
+    }
+}
+
+impl From<f32> for Df64 {
+    #[inline]
+    fn from(x: f32) -> Self {
+        Self::from_f32(x)
+    }
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl From<f64> for Df64 {
+    #[inline]
+    fn from(x: f64) -> Self {
+        Self::from_f64(x)
+    }
+}
+
+#[inline]
+fn quick_two_sum(a: f32, b: f32) -> Df64 {
+    let hi = a + b;
+    let lo = b - (hi - a);
+    Df64 { hi, lo }
+}
+
+#[inline]
+fn two_sum(a: f32, b: f32) -> Df64 {
+    let hi = a + b;
+    let b_virtual = hi - a;
+    let lo = (a - (hi - b_virtual)) + (b - b_virtual);
+    Df64 { hi, lo }
+}
+
+// Veltkamp split into 2 halves of 12 bits.
+#[inline]
+fn split(a: f32) -> (f32, f32) {
+    let t = 4097. * a;
+    let hi = t - (t - a);
+    (hi, a - hi)
+}
+
+// Dekker product, avoids fma which may not be exact or available.
+#[inline]
+fn two_prod(a: f32, b: f32) -> Df64 {
+    let hi = a * b;
+    let (a_hi, a_lo) = split(a);
+    let (b_hi, b_lo) = split(b);
+    let lo = ((a_hi * b_hi - hi) + a_hi * b_lo + a_lo * b_hi) + a_lo * b_lo;
+    Df64 { hi, lo }
+}
+
+impl Add for Df64 {
+    type Output = Self;
+    #[inline]
+    fn add(self, rhs: Self) -> Self {
+        let s = two_sum(self.hi, rhs.hi);
+        let t = two_sum(self.lo, rhs.lo);
+        let s = quick_two_sum(s.hi, s.lo + t.hi);
+        quick_two_sum(s.hi, s.lo + t.lo)
+    }
+}
+
+impl Sub for Df64 {
+    type Output = Self;
+    #[inline]
+    fn sub(self, rhs: Self) -> Self {
+        self + -rhs
+    }
+}
+
+impl Mul for Df64 {
+    type Output = Self;
+    #[inline]
+    fn mul(self, rhs: Self) -> Self {
+        let p = two_prod(self.hi, rhs.hi);
+        quick_two_sum(p.hi, p.lo + (self.hi * rhs.lo + self.lo * rhs.hi))
+    }
+}
+
+impl Div for Df64 {
+    type Output = Self;
+    #[inline]
+    fn div(self, rhs: Self) -> Self {
+        let q1 = self.hi / rhs.hi;
+        let r = self - rhs * Self::from_f32(q1);
+        let q2 = r.hi / rhs.hi;
+        let r = r - rhs * Self::from_f32(q2);
+        let q3 = r.hi / rhs.hi;
+        quick_two_sum(q1, q2) + Self::from_f32(q3)
+    }
+}
+
+impl Neg for Df64 {
+    type Output = Self;
+    #[inline]
+    fn neg(self) -> Self {
+        Self {
+            hi: -self.hi,
+            lo: -self.lo,
+        }
+    }
+}
+
+macro_rules! impl_assign_op {
+    ($($trait:ident::$method:ident => $op:tt),+) => {
+        $(
+            impl $trait for Df64 {
+                #[inline]
+                fn $method(&mut self, rhs: Self) {
+                    *self = *self $op rhs;
+                }
+            }
+        )+
+    };
+}
+
+impl_assign_op!(
+    AddAssign::add_assign => +,
+    SubAssign::sub_assign => -,
+    MulAssign::mul_assign => *,
+    DivAssign::div_assign => /
+);
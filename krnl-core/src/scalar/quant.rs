@@ -0,0 +1,41 @@
+use super::{Scalar, Sealed};
+#[cfg(any(target_arch = "spirv", not(feature = "std")))]
+use num_traits::Float;
+
+/** Quantized integer types, ie i8 and u8.
+
+Values are mapped affinely from f32 with a `scale` and `zero_point`:
+```
+# use krnl_core::scalar::Quantized;
+let (scale, zero_point) = (0.5, 10);
+let q = u8::quantize(3.2, scale, zero_point);
+assert_eq!(q, 16);
+assert_eq!(q.dequantize(scale, zero_point), 3.0);
+```
+*/
+pub trait Quantized: Scalar + Sealed {
+    /// Quantizes `x` as `round(x / scale) + zero_point`, clamped to the range of the type.
+    fn quantize(x: f32, scale: f32, zero_point: i32) -> Self;
+    /// Dequantizes as `(self - zero_point) * scale`.
+    fn dequantize(self, scale: f32, zero_point: i32) -> f32;
+}
+
+macro_rules! impl_quantized {
+    ($($t:ty),+) => {
+        $(
+            impl Quantized for $t {
+                #[inline]
+                fn quantize(x: f32, scale: f32, zero_point: i32) -> Self {
+                    let q = (x / scale).round() + zero_point as f32;
+                    q.max(<$t>::MIN as f32).min(<$t>::MAX as f32) as $t
+                }
+                #[inline]
+                fn dequantize(self, scale: f32, zero_point: i32) -> f32 {
+                    (self as i32 - zero_point) as f32 * scale
+                }
+            }
+        )+
+    };
+}
+
+impl_quantized!(i8, u8);
@@ -0,0 +1,354 @@
+/*!
+Subgroup operations.
+
+Threads in a subgroup execute together, and can exchange values without
+[group buffers](https://docs.rs/krnl/latest/krnl/kernel/index.html#group-buffers) or barriers.
+Operations are performed by the active threads of the subgroup, so all threads in the subgroup
+should reach the call. Results for inactive threads are not included.
+
+Kernels using these operations require the corresponding subgroup features, which are detected
+from the compiled SPIR-V and checked when the kernel is built:
+- `subgroup_basic` for [`subgroup_elect`]
+- `subgroup_vote` for [`subgroup_all`], [`subgroup_any`], and [`subgroup_all_equal`]
+- `subgroup_ballot` for [`subgroup_broadcast`], [`subgroup_broadcast_first`], and [`subgroup_ballot`]
+- `subgroup_shuffle` for [`subgroup_shuffle`]
+- `subgroup_arithmetic` for [`subgroup_add`], [`subgroup_min`], and [`subgroup_max`]
+
+64 bit types require `shader_int64` or `shader_float64`.
+
+On the host, ie in [host](https://docs.rs/krnl/latest/krnl/kernel/index.html#host) kernels, a
+subgroup has a single thread. Reductions, broadcasts, and shuffles return `x`,
+[`subgroup_elect`] and [`subgroup_all_equal`] return true, [`subgroup_all`] and [`subgroup_any`]
+return `predicate`, and [`subgroup_ballot`] sets bit 0 if `predicate` is true.
+
+```ignore
+#[kernel]
+pub fn sum(#[global] x: Slice<f32>, #[global] y: UnsafeSlice<f32>) {
+    use krnl_core::subgroup::{subgroup_add, subgroup_elect};
+
+    let global_id = kernel.global_id();
+    let x = if global_id < x.len() { x[global_id] } else { 0. };
+    let x = subgroup_add(x);
+    if subgroup_elect() {
+        unsafe {
+            *y.unsafe_index_mut(global_id / kernel.subgroup_threads()) = x;
+        }
+    }
+}
+```
+*/
+
+use crate::scalar::Scalar;
+#[cfg(target_arch = "spirv")]
+use core::arch::asm;
+use spirv_std::glam::UVec4;
+
+mod sealed {
+    pub trait Sealed {}
+}
+
+/// Scalars supported by subgroup arithmetic.
+///
+/// Implemented for 32 and 64 bit scalars.
+pub trait SubgroupScalar: Scalar + sealed::Sealed {
+    #[doc(hidden)]
+    fn __subgroup_add(self) -> Self;
+    #[doc(hidden)]
+    fn __subgroup_min(self) -> Self;
+    #[doc(hidden)]
+    fn __subgroup_max(self) -> Self;
+}
+
+// Reduces `$x` over the subgroup with the instruction `$op`.
+macro_rules! subgroup_reduce {
+    ($x:expr, $op:literal) => {{
+        #[cfg(target_arch = "spirv")]
+        {
+            let x = $x;
+            let mut result = Self::default();
+            unsafe {
+                asm! {
+                    "%u32 = OpTypeInt 32 0",
+                    "%subgroup = OpConstant %u32 3",
+                    "%x = OpLoad _ {x}",
+                    concat!("%result = ", $op, " typeof*{result} %subgroup Reduce %x"),
+                    "OpStore {result} %result",
+                    x = in(reg) &x,
+                    result = in(reg) &mut result,
+                }
+            }
+            result
+        }
+        #[cfg(not(target_arch = "spirv"))]
+        {
+            $x
+        }
+    }};
+}
+
+macro_rules! impl_subgroup_scalar {
+    ($($T:ty => $add:literal, $min:literal, $max:literal;)*) => {
+        $(
+            impl sealed::Sealed for $T {}
+
+            impl SubgroupScalar for $T {
+                #[inline]
+                fn __subgroup_add(self) -> Self {
+                    subgroup_reduce!(self, $add)
+                }
+                #[inline]
+                fn __subgroup_min(self) -> Self {
+                    subgroup_reduce!(self, $min)
+                }
+                #[inline]
+                fn __subgroup_max(self) -> Self {
+                    subgroup_reduce!(self, $max)
+                }
+            }
+        )*
+    };
+}
+
+impl_subgroup_scalar! {
+    u32 => "OpGroupNonUniformIAdd", "OpGroupNonUniformUMin", "OpGroupNonUniformUMax";
+    i32 => "OpGroupNonUniformIAdd", "OpGroupNonUniformSMin", "OpGroupNonUniformSMax";
+    f32 => "OpGroupNonUniformFAdd", "OpGroupNonUniformFMin", "OpGroupNonUniformFMax";
+    u64 => "OpGroupNonUniformIAdd", "OpGroupNonUniformUMin", "OpGroupNonUniformUMax";
+    i64 => "OpGroupNonUniformIAdd", "OpGroupNonUniformSMin", "OpGroupNonUniformSMax";
+    f64 => "OpGroupNonUniformFAdd", "OpGroupNonUniformFMin", "OpGroupNonUniformFMax";
+}
+
+/// The sum of `x` over the subgroup.
+#[doc(alias = "OpGroupNonUniformIAdd", alias = "OpGroupNonUniformFAdd")]
+#[inline]
+pub fn subgroup_add<T: SubgroupScalar>(x: T) -> T {
+    x.__subgroup_add()
+}
+
+/// The minimum of `x` over the subgroup.
+#[doc(alias = "OpGroupNonUniformUMin", alias = "OpGroupNonUniformSMin")]
+#[doc(alias = "OpGroupNonUniformFMin")]
+#[inline]
+pub fn subgroup_min<T: SubgroupScalar>(x: T) -> T {
+    x.__subgroup_min()
+}
+
+/// The maximum of `x` over the subgroup.
+#[doc(alias = "OpGroupNonUniformUMax", alias = "OpGroupNonUniformSMax")]
+#[doc(alias = "OpGroupNonUniformFMax")]
+#[inline]
+pub fn subgroup_max<T: SubgroupScalar>(x: T) -> T {
+    x.__subgroup_max()
+}
+
+/// Whether this is the active thread with the lowest subgroup thread id.
+#[doc(alias = "OpGroupNonUniformElect")]
+#[inline]
+pub fn subgroup_elect() -> bool {
+    #[cfg(target_arch = "spirv")]
+    {
+        let mut result = false;
+        unsafe {
+            asm! {
+                "%bool = OpTypeBool",
+                "%u32 = OpTypeInt 32 0",
+                "%subgroup = OpConstant %u32 3",
+                "%result = OpGroupNonUniformElect %bool %subgroup",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+            }
+        }
+        result
+    }
+    #[cfg(not(target_arch = "spirv"))]
+    {
+        true
+    }
+}
+
+// Evaluates `$op` with `$predicate` over the subgroup.
+macro_rules! subgroup_vote {
+    ($predicate:expr, $op:literal) => {{
+        #[cfg(target_arch = "spirv")]
+        {
+            let predicate = $predicate;
+            let mut result = false;
+            unsafe {
+                asm! {
+                    "%bool = OpTypeBool",
+                    "%u32 = OpTypeInt 32 0",
+                    "%subgroup = OpConstant %u32 3",
+                    "%predicate = OpLoad _ {predicate}",
+                    concat!("%result = ", $op, " %bool %subgroup %predicate"),
+                    "OpStore {result} %result",
+                    predicate = in(reg) &predicate,
+                    result = in(reg) &mut result,
+                }
+            }
+            result
+        }
+        #[cfg(not(target_arch = "spirv"))]
+        {
+            $predicate
+        }
+    }};
+}
+
+/// Whether `predicate` is true for all active threads.
+#[doc(alias = "OpGroupNonUniformAll")]
+#[inline]
+pub fn subgroup_all(predicate: bool) -> bool {
+    subgroup_vote!(predicate, "OpGroupNonUniformAll")
+}
+
+/// Whether `predicate` is true for any active thread.
+#[doc(alias = "OpGroupNonUniformAny")]
+#[inline]
+pub fn subgroup_any(predicate: bool) -> bool {
+    subgroup_vote!(predicate, "OpGroupNonUniformAny")
+}
+
+/// Whether `x` is equal for all active threads.
+#[doc(alias = "OpGroupNonUniformAllEqual")]
+#[inline]
+pub fn subgroup_all_equal<T: Scalar>(x: T) -> bool {
+    #[cfg(target_arch = "spirv")]
+    {
+        let mut result = false;
+        unsafe {
+            asm! {
+                "%bool = OpTypeBool",
+                "%u32 = OpTypeInt 32 0",
+                "%subgroup = OpConstant %u32 3",
+                "%x = OpLoad _ {x}",
+                "%result = OpGroupNonUniformAllEqual %bool %subgroup %x",
+                "OpStore {result} %result",
+                x = in(reg) &x,
+                result = in(reg) &mut result,
+            }
+        }
+        result
+    }
+    #[cfg(not(target_arch = "spirv"))]
+    {
+        let _ = x;
+        true
+    }
+}
+
+/// `x` of the thread with subgroup thread id `id`.
+///
+/// `id` must be the same for all threads in the subgroup, otherwise the result is undefined. See
+/// [`subgroup_shuffle`].
+#[doc(alias = "OpGroupNonUniformBroadcast")]
+#[inline]
+pub fn subgroup_broadcast<T: Scalar>(x: T, id: u32) -> T {
+    #[cfg(target_arch = "spirv")]
+    {
+        let mut result = T::default();
+        unsafe {
+            asm! {
+                "%u32 = OpTypeInt 32 0",
+                "%subgroup = OpConstant %u32 3",
+                "%x = OpLoad _ {x}",
+                "%id = OpLoad _ {id}",
+                "%result = OpGroupNonUniformBroadcast typeof*{result} %subgroup %x %id",
+                "OpStore {result} %result",
+                x = in(reg) &x,
+                id = in(reg) &id,
+                result = in(reg) &mut result,
+            }
+        }
+        result
+    }
+    #[cfg(not(target_arch = "spirv"))]
+    {
+        let _ = id;
+        x
+    }
+}
+
+/// `x` of the active thread with the lowest subgroup thread id.
+#[doc(alias = "OpGroupNonUniformBroadcastFirst")]
+#[inline]
+pub fn subgroup_broadcast_first<T: Scalar>(x: T) -> T {
+    #[cfg(target_arch = "spirv")]
+    {
+        let mut result = T::default();
+        unsafe {
+            asm! {
+                "%u32 = OpTypeInt 32 0",
+                "%subgroup = OpConstant %u32 3",
+                "%x = OpLoad _ {x}",
+                "%result = OpGroupNonUniformBroadcastFirst typeof*{result} %subgroup %x",
+                "OpStore {result} %result",
+                x = in(reg) &x,
+                result = in(reg) &mut result,
+            }
+        }
+        result
+    }
+    #[cfg(not(target_arch = "spirv"))]
+    {
+        x
+    }
+}
+
+/// `x` of the thread with subgroup thread id `id`, which may differ between threads.
+///
+/// The result is undefined if that thread is inactive, or `id` is out of range.
+#[doc(alias = "OpGroupNonUniformShuffle")]
+#[inline]
+pub fn subgroup_shuffle<T: Scalar>(x: T, id: u32) -> T {
+    #[cfg(target_arch = "spirv")]
+    {
+        let mut result = T::default();
+        unsafe {
+            asm! {
+                "%u32 = OpTypeInt 32 0",
+                "%subgroup = OpConstant %u32 3",
+                "%x = OpLoad _ {x}",
+                "%id = OpLoad _ {id}",
+                "%result = OpGroupNonUniformShuffle typeof*{result} %subgroup %x %id",
+                "OpStore {result} %result",
+                x = in(reg) &x,
+                id = in(reg) &id,
+                result = in(reg) &mut result,
+            }
+        }
+        result
+    }
+    #[cfg(not(target_arch = "spirv"))]
+    {
+        let _ = id;
+        x
+    }
+}
+
+/// A mask of the active threads for which `predicate` is true.
+///
+/// Bit `i % 32` of component `i / 32` is set for subgroup thread id `i`.
+#[doc(alias = "OpGroupNonUniformBallot")]
+#[inline]
+pub fn subgroup_ballot(predicate: bool) -> UVec4 {
+    #[cfg(target_arch = "spirv")]
+    {
+        let mut result = UVec4::default();
+        unsafe {
+            asm! {
+                "%u32 = OpTypeInt 32 0",
+                "%subgroup = OpConstant %u32 3",
+                "%predicate = OpLoad _ {predicate}",
+                "%result = OpGroupNonUniformBallot typeof*{result} %subgroup %predicate",
+                "OpStore {result} %result",
+                predicate = in(reg) &predicate,
+                result = in(reg) &mut result,
+            }
+        }
+        result
+    }
+    #[cfg(not(target_arch = "spirv"))]
+    {
+        UVec4::new(predicate.into(), 0, 0, 0)
+    }
+}
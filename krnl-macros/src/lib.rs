@@ -22,7 +22,7 @@ use syn::{
         And, Brace, Bracket, Colon, Comma, Const, Eq as SynEq, Fn, Gt, Lt, Mod, Mut, Paren, Pound,
         Unsafe,
     },
-    Attribute, Block, Error, Ident, LitInt, LitStr, Visibility,
+    Attribute, Block, Error, Expr, Ident, LitInt, LitStr, Type, Visibility,
 };
 
 type Result<T, E = Error> = std::result::Result<T, E>;
@@ -36,31 +36,18 @@ struct InsideBracket<T> {
     value: T,
 }
 
-#[derive(Parse, Debug)]
-struct InsideBrace<T> {
-    #[brace]
-    brace: Brace,
-    #[inside(brace)]
-    value: T,
-}
-
-impl<T: ToTokens> ToTokens for InsideBrace<T> {
-    fn to_tokens(&self, tokens: &mut TokenStream2) {
-        self.brace
-            .surround(tokens, |tokens| self.value.to_tokens(tokens));
-    }
-}
-
 #[proc_macro_attribute]
 pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
     if !attr.is_empty() {
-        return Error::new_spanned(&TokenStream2::from(attr), "unexpected tokens")
+        return Error::new_spanned(TokenStream2::from(attr), "unexpected tokens")
             .into_compile_error()
             .into();
     }
     let mut item = parse_macro_input!(item as ModuleItem);
-    let mut build = true;
+    // The "no-build" feature implies `#[krnl(no_build)]` for all modules.
+    let mut build = !cfg!(feature = "no-build");
     let mut krnl = quote! { ::krnl };
+    let mut variants = None;
     let new_attr = Vec::with_capacity(item.attr.len());
     for attr in std::mem::replace(&mut item.attr, new_attr) {
         if attr.path.segments.len() == 1
@@ -68,7 +55,7 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
                 .path
                 .segments
                 .first()
-                .map_or(false, |x| x.ident == "krnl")
+                .is_some_and(|x| x.ident == "krnl")
         {
             let tokens = attr.tokens.clone().into();
             let args = syn::parse_macro_input!(tokens as ModuleKrnlArgs);
@@ -91,10 +78,12 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
                 } else if let Some(ident) = &arg.ident {
                     if ident == "no_build" {
                         build = false;
+                    } else if let Some(module_variants) = arg.variants.as_ref() {
+                        variants.replace(module_variants.encode());
                     } else {
                         return Error::new_spanned(
                             ident,
-                            format!("unknown krnl arg `{ident}`, expected `crate` or `no_build`"),
+                            format!("unknown krnl arg `{ident}`, expected `crate`, `no_build`, or `variants`"),
                         )
                         .into_compile_error()
                         .into();
@@ -122,11 +111,18 @@ pub fn module(attr: TokenStream, item: TokenStream) -> TokenStream {
         let source = item.tokens.to_string();
         let ident = &item.ident;
         let tokens = item.tokens;
+        let variants = variants.map(|variants| {
+            quote! {
+                #[allow(non_upper_case_globals)]
+                const __krnl_module_variants: &'static str = #variants;
+            }
+        });
         item.tokens = quote! {
             #[doc(hidden)]
             mod __krnl_module_data {
                 #[allow(non_upper_case_globals)]
                 const __krnl_module_source: &'static str = #source;
+                #variants
             }
             #[cfg(not(krnlc))]
             #[doc(hidden)]
@@ -187,6 +183,56 @@ struct ModuleKrnlArg {
     krnl_crate: Option<syn::Path>,
     #[parse_if(crate_token.is_none())]
     ident: Option<Ident>,
+    #[parse_if(ident.as_ref().is_some_and(|x| x == "variants"))]
+    variants: Option<ModuleVariants>,
+}
+
+#[derive(Debug)]
+struct ModuleVariants {
+    variants: Vec<Vec<Ident>>,
+}
+
+impl Parse for ModuleVariants {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let content;
+        syn::parenthesized!(content in input);
+        let mut variants = Vec::new();
+        while !content.is_empty() {
+            let features;
+            syn::bracketed!(features in content);
+            let names = Punctuated::<Ident, Comma>::parse_terminated(&features)?;
+            let mut check = Features::default();
+            for name in names.iter() {
+                check.add_name(name)?;
+            }
+            variants.push(names.into_iter().collect());
+            if content.is_empty() {
+                break;
+            }
+            content.parse::<Comma>()?;
+        }
+        if variants.is_empty() {
+            return Err(content.error("expected at least one variant"));
+        }
+        Ok(Self { variants })
+    }
+}
+
+impl ModuleVariants {
+    // Encoded for krnlc, ie "shader_int64;" for `variants([shader_int64], [])`.
+    fn encode(&self) -> String {
+        self.variants
+            .iter()
+            .map(|names| {
+                names
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(",")
+            })
+            .collect::<Vec<_>>()
+            .join(";")
+    }
 }
 
 #[derive(Parse, Debug)]
@@ -215,19 +261,420 @@ impl ToTokens for ModuleItem {
     }
 }
 
+/// Declares a kernel in a `#[module]`.
+///
+/// Arrays of slices, like `#[global] xs: [Slice<f32>]`, are not supported, and are rejected with an
+/// error. Each slice must be a separate argument.
+///
+/// See [**krnl**](https://docs.rs/krnl/latest/krnl/kernel).
 #[proc_macro_attribute]
 pub fn kernel(attr: TokenStream, item: TokenStream) -> TokenStream {
-    if !attr.is_empty() {
-        return Error::new_spanned(&TokenStream2::from(attr), "unexpected tokens")
-            .into_compile_error()
-            .into();
+    let (generic, attr) = match split_kernel_generic(attr.into()) {
+        Ok(x) => x,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    let attr = match syn::parse2::<KernelAttrArgs>(attr) {
+        Ok(attr) => attr,
+        Err(e) => return e.into_compile_error().into(),
+    };
+    let result = if let Some(generic) = generic {
+        generic_kernel_impl(generic, attr, item.into())
+    } else {
+        kernel_impl(attr, item.into())
+    };
+    match result {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+/// Runs a test on the host and every available device, comparing outputs.
+///
+/// See [**krnl**](https://docs.rs/krnl/latest/krnl/testing).
+#[proc_macro_attribute]
+pub fn kernel_test(attr: TokenStream, item: TokenStream) -> TokenStream {
+    let attr = parse_macro_input!(attr as KernelTestArgs);
+    let item = parse_macro_input!(item as syn::ItemFn);
+    kernel_test_impl(attr, item).into()
+}
+
+/// Derives `Pod` for a `#[repr(C)]` struct of scalars, the elements of buffers and `#[global]` slices.
+///
+/// See [**krnl**](https://docs.rs/krnl/latest/krnl/kernel#structs).
+#[proc_macro_derive(Pod)]
+pub fn derive_pod(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    match pod_impl(input) {
+        Ok(tokens) => tokens.into(),
+        Err(e) => e.into_compile_error().into(),
+    }
+}
+
+fn pod_impl(input: syn::DeriveInput) -> Result<TokenStream2> {
+    let ident = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "`Pod` structs can't be generic",
+        ));
+    }
+    if !is_repr_c(&input.attrs) {
+        return Err(Error::new_spanned(ident, "expected `#[repr(C)]`"));
+    }
+    let fields = named_fields(&input)?;
+    if fields.is_empty() {
+        return Err(Error::new_spanned(ident, "expected at least one field"));
+    }
+    let field_tys: Vec<_> = fields.iter().map(|x| &x.ty).collect();
+    let storage_type = quote! {
+        match ::core::mem::align_of::<#ident>() {
+            1 => ScalarType::U8,
+            2 => ScalarType::U16,
+            4 => ScalarType::U32,
+            8 => ScalarType::U64,
+            _ => panic!(concat!("`Pod` struct `", stringify!(#ident), "` is aligned to more than 8 bytes")),
+        }
+    };
+    Ok(quote! {
+        #[cfg(not(target_arch = "spirv"))]
+        const _: () = {
+            __krnl_module_arg!(use crate as __krnl);
+            use ::core::mem::size_of;
+            use __krnl::{bytemuck::{Pod, Zeroable}, scalar::{ScalarType, __private::PodSealed}};
+
+            // Fields must be scalars or `Pod` structs, without padding.
+            #[allow(dead_code)]
+            fn assert_fields_pod() {
+                fn assert_pod<T: __krnl::scalar::Pod>() {}
+                #(assert_pod::<#field_tys>();)*
+            }
+            const _: () = assert!(
+                size_of::<#ident>() == 0 #(+ size_of::<#field_tys>())*,
+                concat!("`Pod` struct `", stringify!(#ident), "` has padding"),
+            );
+
+            unsafe impl Zeroable for #ident {}
+            unsafe impl Pod for #ident {}
+
+            unsafe impl PodSealed for #ident {
+                const STORAGE_TYPE: ScalarType = #storage_type;
+            }
+        };
+        #[cfg(target_arch = "spirv")]
+        const _: () = {
+            use ::krnl_core::scalar::{ScalarType, __private::PodSealed};
+
+            unsafe impl PodSealed for #ident {
+                const STORAGE_TYPE: ScalarType = #storage_type;
+            }
+        };
+    })
+}
+
+fn is_repr_c(attrs: &[syn::Attribute]) -> bool {
+    use syn::{Meta, NestedMeta};
+
+    attrs.iter().any(|attr| {
+        attr.path.is_ident("repr")
+            && attr
+                .parse_args_with(Punctuated::<NestedMeta, Comma>::parse_terminated)
+                .is_ok_and(|reprs| {
+                    reprs.iter().any(
+                        |x| matches!(x, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("C")),
+                    )
+                })
+    })
+}
+
+fn named_fields(input: &syn::DeriveInput) -> Result<&Punctuated<syn::Field, Comma>> {
+    use syn::{Data, Fields};
+
+    match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => Ok(&fields.named),
+            fields => Err(Error::new_spanned(fields, "expected named fields")),
+        },
+        _ => Err(Error::new_spanned(&input.ident, "expected a struct")),
     }
-    match kernel_impl(item.into()) {
+}
+
+/// Derives `PushConstants` and `Pod` for a `#[push]` struct.
+///
+/// See [**krnl**](https://docs.rs/krnl/latest/krnl/kernel#push-structs).
+#[proc_macro_derive(PushConstants)]
+pub fn derive_push_constants(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as syn::DeriveInput);
+    match push_constants_impl(input) {
         Ok(tokens) => tokens.into(),
         Err(e) => e.into_compile_error().into(),
     }
 }
 
+fn push_constants_impl(input: syn::DeriveInput) -> Result<TokenStream2> {
+    let ident = &input.ident;
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "push structs can't be generic",
+        ));
+    }
+    if !is_repr_c(&input.attrs) {
+        return Err(Error::new_spanned(ident, "expected `#[repr(C)]`"));
+    }
+    let fields = named_fields(&input)?;
+    let field_idents: Vec<_> = fields.iter().flat_map(|x| x.ident.as_ref()).collect();
+    let field_names = field_idents.iter().map(|x| x.to_string());
+    let field_tys: Vec<_> = fields.iter().map(|x| &x.ty).collect();
+    Ok(quote! {
+        #[cfg(not(target_arch = "spirv"))]
+        const _: () = {
+            __krnl_module_arg!(use crate as __krnl);
+            use ::core::mem::{size_of, MaybeUninit};
+            use __krnl::{bytemuck::{Pod, Zeroable}, kernel::PushConstants};
+
+            // Pod requires that all fields are Pod, without padding.
+            #[allow(dead_code)]
+            fn assert_fields_pod() {
+                fn assert_pod<T: Pod>() {}
+                #(assert_pod::<#field_tys>();)*
+            }
+            const _: () = assert!(
+                size_of::<#ident>() == 0 #(+ size_of::<#field_tys>())*,
+                concat!("push struct `", stringify!(#ident), "` has padding"),
+            );
+
+            unsafe impl Zeroable for #ident {}
+            unsafe impl Pod for #ident {}
+
+            impl PushConstants for #ident {
+                const FIELDS: &'static [(&'static str, usize)] = &[#(
+                    (#field_names, {
+                        let x = MaybeUninit::<#ident>::uninit();
+                        let ptr = x.as_ptr();
+                        unsafe {
+                            ::core::ptr::addr_of!((*ptr).#field_idents)
+                                .cast::<u8>()
+                                .offset_from(ptr.cast::<u8>()) as usize
+                        }
+                    })
+                ),*];
+            }
+        };
+    })
+}
+
+#[derive(Default, Debug)]
+struct KernelTestArgs {
+    tolerance: Option<Expr>,
+    features: Option<Features>,
+}
+
+impl Parse for KernelTestArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut args = Self::default();
+        while !input.is_empty() {
+            let ident = input.parse::<Ident>()?;
+            if ident == "tolerance" {
+                input.parse::<SynEq>()?;
+                args.tolerance.replace(input.parse()?);
+            } else if ident == "features" {
+                let content;
+                syn::parenthesized!(content in input);
+                let features = args.features.get_or_insert_with(Features::default);
+                for name in Punctuated::<Ident, Comma>::parse_terminated(&content)?.iter() {
+                    features.add_name(name)?;
+                }
+            } else {
+                return Err(Error::new_spanned(
+                    &ident,
+                    format!(
+                        "unknown kernel_test arg `{ident}`, expected `tolerance` or `features`"
+                    ),
+                ));
+            }
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Comma>()?;
+        }
+        Ok(args)
+    }
+}
+
+fn kernel_test_impl(args: KernelTestArgs, mut item: syn::ItemFn) -> TokenStream2 {
+    let attrs = std::mem::take(&mut item.attrs);
+    let vis = &item.vis;
+    let ident = &item.sig.ident;
+    let tolerance = args
+        .tolerance
+        .map_or_else(|| quote!(0.), |x| x.into_token_stream());
+    let features = args.features.unwrap_or_default();
+    quote! {
+        #[test]
+        #(#attrs)*
+        #vis fn #ident() {
+            #item
+            let features = {
+                use ::krnl::device::Features;
+                #features
+            };
+            ::krnl::testing::__private::kernel_test(::std::stringify!(#ident), #tolerance, features, #ident);
+        }
+    }
+}
+
+#[derive(Default, Clone, Debug)]
+struct KernelAttrArgs {
+    tokens: TokenStream2,
+    host: Option<Ident>,
+    items: Option<Ident>,
+    features: Option<Features>,
+    grid_stride: Option<Ident>,
+    requires: Vec<Expr>,
+}
+
+impl KernelAttrArgs {
+    fn exact_items(&self) -> bool {
+        self.items.as_ref().is_some_and(|x| x == "exact")
+    }
+}
+
+impl Parse for KernelAttrArgs {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let mut args = Self {
+            tokens: input.fork().parse()?,
+            ..Self::default()
+        };
+        while !input.is_empty() {
+            let ident: Ident = input.parse()?;
+            if ident == "host" {
+                args.host.replace(ident);
+            } else if ident == "items" {
+                input.parse::<SynEq>()?;
+                let value: Ident = input.parse()?;
+                if value != "zip" && value != "exact" {
+                    return Err(Error::new_spanned(&value, "expected `zip` or `exact`"));
+                }
+                args.items.replace(value);
+            } else if ident == "require" {
+                let content;
+                syn::parenthesized!(content in input);
+                let requires = Punctuated::<Expr, Comma>::parse_terminated(&content)?;
+                if requires.is_empty() {
+                    return Err(Error::new_spanned(
+                        &ident,
+                        "expected at least one expression",
+                    ));
+                }
+                args.requires.extend(requires);
+            } else if ident == "grid_stride" {
+                input.parse::<SynEq>()?;
+                args.grid_stride.replace(input.parse()?);
+            } else if ident == "features" {
+                let content;
+                syn::parenthesized!(content in input);
+                let names = Punctuated::<Ident, Comma>::parse_terminated(&content)?;
+                if names.is_empty() {
+                    return Err(Error::new_spanned(&ident, "expected at least one feature"));
+                }
+                let features = args.features.get_or_insert_with(Features::default);
+                for name in names.iter() {
+                    features.add_name(name)?;
+                }
+            } else {
+                return Err(Error::new_spanned(
+                    &ident,
+                    format!(
+                        "unknown kernel arg `{ident}`, expected `host`, `items`, `features`, `grid_stride`, `require`, or `generic`"
+                    ),
+                ));
+            }
+            if input.is_empty() {
+                break;
+            }
+            input.parse::<Comma>()?;
+        }
+        Ok(args)
+    }
+}
+
+// `generic(T = [f32, u32])`
+#[derive(Debug)]
+struct KernelGeneric {
+    ident: Ident,
+    types: Vec<(Ident, ScalarType)>,
+}
+
+impl Parse for KernelGeneric {
+    fn parse(input: ParseStream) -> Result<Self> {
+        let generic: Ident = input.parse()?;
+        if generic != "generic" {
+            return Err(Error::new_spanned(&generic, "expected `generic`"));
+        }
+        let content;
+        syn::parenthesized!(content in input);
+        let ident = content.parse()?;
+        content.parse::<SynEq>()?;
+        let types;
+        let bracket = syn::bracketed!(types in content);
+        let names = Punctuated::<Ident, Comma>::parse_terminated(&types)?;
+        if !content.is_empty() {
+            return Err(content.error("expected `)`"));
+        }
+        if names.is_empty() {
+            return Err(Error::new(bracket.span, "expected at least one type"));
+        }
+        let mut types: Vec<(Ident, ScalarType)> = Vec::with_capacity(names.len());
+        for name in names {
+            let Some(scalar_type) = ScalarType::iter().find(|x| name == x.name()) else {
+                return Err(Error::new_spanned(
+                    &name,
+                    format!("expected a scalar type, found `{name}`"),
+                ));
+            };
+            if types.iter().any(|(_, x)| *x == scalar_type) {
+                return Err(Error::new_spanned(
+                    &name,
+                    format!("duplicate type `{name}`"),
+                ));
+            }
+            types.push((name, scalar_type));
+        }
+        Ok(Self { ident, types })
+    }
+}
+
+// Removes `generic(..)` from the kernel args, which are otherwise passed to each instantiation.
+fn split_kernel_generic(attr: TokenStream2) -> Result<(Option<KernelGeneric>, TokenStream2)> {
+    use proc_macro2::TokenTree;
+
+    let mut args: Vec<Vec<TokenTree>> = vec![Vec::new()];
+    for tree in attr {
+        match &tree {
+            TokenTree::Punct(punct) if punct.as_char() == ',' => args.push(Vec::new()),
+            _ => args.last_mut().unwrap().push(tree),
+        }
+    }
+    let mut generic = None;
+    let mut tokens = TokenStream2::new();
+    for arg in args {
+        let is_generic = matches!(arg.first(), Some(TokenTree::Ident(ident)) if ident == "generic");
+        let arg: TokenStream2 = arg.into_iter().collect();
+        if is_generic {
+            if generic.is_some() {
+                return Err(Error::new_spanned(&arg, "expected at most one `generic`"));
+            }
+            generic.replace(syn::parse2::<KernelGeneric>(arg)?);
+        } else if !arg.is_empty() {
+            if !tokens.is_empty() {
+                tokens.extend(quote! { , });
+            }
+            tokens.extend(arg);
+        }
+    }
+    Ok((generic, tokens))
+}
+
 #[derive(Parse, Debug)]
 struct KernelItem {
     #[call(Attribute::parse_outer)]
@@ -258,6 +705,7 @@ impl KernelItem {
             arg_metas: Vec::with_capacity(self.args.len()),
             block: self.block.clone(),
             itemwise: false,
+            grid_stride: None,
             arrays: FxHashMap::default(),
         };
         let mut spec_id = 0;
@@ -280,6 +728,12 @@ impl KernelItem {
         let mut binding = 0;
         for arg in self.args.iter() {
             let mut arg_meta = arg.meta()?;
+            if arg_meta.kind.is_push_struct() && meta.push_struct().is_some() {
+                return Err(Error::new_spanned(
+                    &arg.ident,
+                    "expected at most one `#[push]` struct",
+                ));
+            }
             if arg_meta.kind.is_global() || arg_meta.kind.is_item() {
                 arg_meta.binding.replace(binding);
                 binding += 1;
@@ -287,7 +741,7 @@ impl KernelItem {
             meta.itemwise |= arg_meta.kind.is_item();
             if let Some(len) = arg_meta.len.as_ref() {
                 meta.arrays
-                    .entry(arg_meta.scalar_ty.scalar_type)
+                    .entry(arg_meta.scalar_type.unwrap())
                     .or_default()
                     .push((arg.ident.clone(), len.clone()));
             }
@@ -404,26 +858,47 @@ struct KernelArg {
     array_ty: Option<KernelTypeArray>,
     #[parse_if(kind.is_push())]
     push_ty: Option<KernelTypeScalar>,
+    #[parse_if(kind.is_push_struct())]
+    push_struct_ty: Option<Type>,
 }
 
 impl KernelArg {
     fn meta(&self) -> Result<KernelArgMeta> {
         let kind = self.kind;
+        let mut index64 = false;
         let (scalar_ty, mutable, len) = if let Some(slice_ty) = self.slice_ty.as_ref() {
             let slice_ty_ident = &slice_ty.ty;
             let mutable = if slice_ty.ty == "Slice" {
                 false
             } else if slice_ty.ty == "UnsafeSlice" {
                 true
+            } else if slice_ty.ty == "Slice64" {
+                index64 = true;
+                false
+            } else if slice_ty.ty == "UnsafeSlice64" {
+                index64 = true;
+                true
             } else if slice_ty.ty == "SliceMut" {
                 return Err(Error::new_spanned(slice_ty_ident, "try `UnsafeSlice`"));
             } else {
                 return Err(Error::new_spanned(
                     slice_ty_ident,
-                    "expected `Slice` or `UnsafeSlice`",
+                    "expected `Slice`, `UnsafeSlice`, `Slice64`, or `UnsafeSlice64`",
                 ));
             };
-            (slice_ty.scalar_ty.clone(), mutable, None)
+            let Some(scalar_ty) = slice_ty.scalar_ty.clone() else {
+                return Ok(KernelArgMeta {
+                    kind,
+                    ident: self.ident.clone(),
+                    ty: slice_ty.elem_ty.to_token_stream(),
+                    scalar_type: None,
+                    mutable,
+                    index64,
+                    binding: None,
+                    len: None,
+                });
+            };
+            (scalar_ty, mutable, None)
         } else if let Some(array_ty) = self.array_ty.as_ref() {
             let len = array_ty.len.to_token_stream();
             (array_ty.scalar_ty.clone(), true, Some(len))
@@ -431,14 +906,27 @@ impl KernelArg {
             (item_ty.scalar_ty.clone(), item_ty.mut_token.is_some(), None)
         } else if let Some(push_ty) = self.push_ty.as_ref() {
             (push_ty.clone(), false, None)
+        } else if let Some(push_struct_ty) = self.push_struct_ty.as_ref() {
+            return Ok(KernelArgMeta {
+                kind,
+                ident: self.ident.clone(),
+                ty: push_struct_ty.to_token_stream(),
+                scalar_type: None,
+                mutable: false,
+                index64,
+                binding: None,
+                len: None,
+            });
         } else {
             unreachable!("KernelArg::meta expected type!")
         };
         let meta = KernelArgMeta {
             kind,
             ident: self.ident.clone(),
-            scalar_ty,
+            ty: scalar_ty.ident.to_token_stream(),
+            scalar_type: Some(scalar_ty.scalar_type),
             mutable,
+            index64,
             binding: None,
             len,
         };
@@ -450,8 +938,12 @@ impl KernelArg {
 struct KernelArgMeta {
     kind: KernelArgKind,
     ident: Ident,
-    scalar_ty: KernelTypeScalar,
+    ty: TokenStream2,
+    // None for `#[push]` structs and slices of `Pod` structs.
+    scalar_type: Option<ScalarType>,
     mutable: bool,
+    // `Slice64` or `UnsafeSlice64`.
+    index64: bool,
     binding: Option<u32>,
     len: Option<TokenStream2>,
 }
@@ -459,7 +951,7 @@ struct KernelArgMeta {
 impl KernelArgMeta {
     fn compute_def_tokens(&self) -> Option<TokenStream2> {
         let ident = &self.ident;
-        let ty = &self.scalar_ty.ident;
+        let ty = &self.ty;
         if let Some(binding) = self.binding.as_ref() {
             let set = LitInt::new("0", Span2::call_site());
             let binding = LitInt::new(&binding.to_string(), Span2::call_site());
@@ -475,21 +967,24 @@ impl KernelArgMeta {
             None
         }
     }
+    fn slice_ident(&self) -> Ident {
+        let slice_ty = if self.mutable { "UnsafeSlice" } else { "Slice" };
+        if self.index64 {
+            format_ident!("{slice_ty}64")
+        } else {
+            format_ident!("{slice_ty}")
+        }
+    }
     fn device_fn_def_tokens(&self) -> TokenStream2 {
         let ident = &self.ident;
-        let ty = &self.scalar_ty.ident;
+        let ty = &self.ty;
         let mutable = self.mutable;
         use KernelArgKind::*;
         match self.kind {
             Global => {
-                if mutable {
-                    quote! {
-                        #ident: ::krnl_core::buffer::UnsafeSlice<#ty>
-                    }
-                } else {
-                    quote! {
-                        #ident: ::krnl_core::buffer::Slice<#ty>
-                    }
+                let slice_ident = self.slice_ident();
+                quote! {
+                    #ident: ::krnl_core::buffer::#slice_ident<#ty>
                 }
             }
             Item => {
@@ -506,11 +1001,76 @@ impl KernelArgMeta {
             Group => quote! {
                 #ident: ::krnl_core::buffer::UnsafeSlice<#ty>
             },
-            Push => quote! {
+            Push | PushStruct => quote! {
+                #ident: #ty
+            },
+        }
+    }
+    fn host_fn_arg_tokens(&self) -> TokenStream2 {
+        let ident = &self.ident;
+        let ty = &self.ty;
+        use KernelArgKind::*;
+        match self.kind {
+            Global | Item | Group => {
+                if self.mutable {
+                    quote! {
+                        #ident: &mut [#ty]
+                    }
+                } else {
+                    quote! {
+                        #ident: &[#ty]
+                    }
+                }
+            }
+            Push | PushStruct => quote! {
                 #ident: #ty
             },
         }
     }
+    fn host_fn_def_tokens(&self) -> TokenStream2 {
+        let ident = &self.ident;
+        let ty = &self.ty;
+        use KernelArgKind::*;
+        match self.kind {
+            Global | Group => {
+                let slice_ident = self.slice_ident();
+                quote! {
+                    #ident: __krnl::krnl_core::buffer::#slice_ident<#ty>
+                }
+            }
+            Item | Push | PushStruct => self.device_fn_def_tokens(),
+        }
+    }
+    fn host_fn_call_tokens(&self) -> TokenStream2 {
+        let ident = &self.ident;
+        use KernelArgKind::*;
+        match self.kind {
+            Global | Group => {
+                let slice_ident = self.slice_ident();
+                if self.mutable {
+                    quote! {
+                        __krnl::krnl_core::buffer::#slice_ident::from(&mut *#ident)
+                    }
+                } else {
+                    quote! {
+                        __krnl::krnl_core::buffer::#slice_ident::from(#ident)
+                    }
+                }
+            }
+            Item => {
+                if self.mutable {
+                    quote! {
+                        &mut #ident[__krnl_item_id]
+                    }
+                } else {
+                    quote! {
+                        #ident[__krnl_item_id]
+                    }
+                }
+            }
+            Push | PushStruct => ident.to_token_stream(),
+        }
+    }
     fn device_slices(&self) -> TokenStream2 {
         let ident = &self.ident;
         let mutable = self.mutable;
@@ -519,25 +1079,31 @@ impl KernelArgMeta {
             Global | Item => {
                 let offset = format_ident!("__krnl_offset_{ident}");
                 let len = format_ident!("__krnl_len_{ident}");
+                let slice_ident = self.slice_ident();
                 let slice_fn = if mutable {
                     quote! {
-                        ::krnl_core::buffer::UnsafeSlice::from_unsafe_raw_parts
+                        ::krnl_core::buffer::#slice_ident::from_unsafe_raw_parts
                     }
                 } else {
                     quote! {
-                        ::krnl_core::buffer::Slice::from_raw_parts
+                        ::krnl_core::buffer::#slice_ident::from_raw_parts
                     }
                 };
+                let index_ty = if self.index64 {
+                    quote! { u64 }
+                } else {
+                    quote! { usize }
+                };
                 quote! {
                     let #ident = unsafe {
-                        #slice_fn(#ident, __krnl_push_consts.#offset as usize, __krnl_push_consts.#len as usize)
+                        #slice_fn(#ident, __krnl_push_consts.#offset as #index_ty, __krnl_push_consts.#len as #index_ty)
                     };
                 }
             }
             Group => {
                 let offset = format_ident!("__krnl_offset_{ident}");
                 let len = format_ident!("__krnl_len_{ident}");
-                let scalar_name = self.scalar_ty.scalar_type.name();
+                let scalar_name = self.scalar_type.unwrap().name();
                 let array = format_ident!("__krnl_group_array_{scalar_name}");
                 quote! {
                     let #ident = {
@@ -547,7 +1113,7 @@ impl KernelArgMeta {
                     };
                 }
             }
-            Push => TokenStream2::new(),
+            Push | PushStruct => TokenStream2::new(),
         }
     }
     fn device_fn_call_tokens(&self) -> TokenStream2 {
@@ -572,7 +1138,7 @@ impl KernelArgMeta {
                     }
                 }
             }
-            Push => quote! {
+            Push | PushStruct => quote! {
                 __krnl_push_consts.#ident
             },
         }
@@ -601,10 +1167,12 @@ impl KernelArgAttr {
             Item
         } else if ident == "group" {
             Group
+        } else if ident == "push" {
+            PushStruct
         } else {
             return Err(Error::new_spanned(
                 ident,
-                "expected `global`, `item`, or `group`",
+                "expected `global`, `item`, `group`, or `push`",
             ));
         };
         Ok(kind)
@@ -617,6 +1185,7 @@ enum KernelArgKind {
     Item,
     Group,
     Push,
+    PushStruct,
 }
 
 impl Parse for KernelArgKind {
@@ -634,14 +1203,38 @@ struct KernelTypeItem {
     scalar_ty: KernelTypeScalar,
 }
 
-#[derive(Parse, Debug)]
+#[derive(Debug)]
 struct KernelTypeSlice {
     ty: Ident,
-    #[allow(unused)]
-    lt: Lt,
-    scalar_ty: KernelTypeScalar,
-    #[allow(unused)]
-    gt: Gt,
+    elem_ty: Type,
+    // None for `Pod` structs.
+    scalar_ty: Option<KernelTypeScalar>,
+}
+
+impl Parse for KernelTypeSlice {
+    fn parse(input: ParseStream) -> Result<Self> {
+        if input.peek(Bracket) {
+            // Arrays of slices require descriptor indexing of storage buffers, which rust-gpu
+            // can't express, as each storage buffer parameter is a single block.
+            let content;
+            let bracket = syn::bracketed!(content in input);
+            content.parse::<TokenStream2>()?;
+            return Err(Error::new(
+                bracket.span,
+                "arrays of slices are not supported, use a `#[global]` arg for each slice",
+            ));
+        }
+        let ty = input.parse()?;
+        input.parse::<Lt>()?;
+        let elem_ty: Type = input.parse()?;
+        input.parse::<Gt>()?;
+        let scalar_ty = syn::parse2(elem_ty.to_token_stream()).ok();
+        Ok(Self {
+            ty,
+            elem_ty,
+            scalar_ty,
+        })
+    }
 }
 
 #[derive(Parse, Debug)]
@@ -710,11 +1303,43 @@ struct KernelMeta {
     unsafe_token: Option<Unsafe>,
     arg_metas: Vec<KernelArgMeta>,
     itemwise: bool,
+    grid_stride: Option<Ident>,
     block: Block,
     arrays: FxHashMap<ScalarType, Vec<(Ident, TokenStream2)>>,
 }
 
 impl KernelMeta {
+    fn item_kernel(&self) -> bool {
+        self.itemwise || self.grid_stride.is_some()
+    }
+    fn validate_grid_stride(&self, grid_stride: &Ident) -> Result<()> {
+        if self.itemwise {
+            return Err(Error::new_spanned(
+                grid_stride,
+                "`grid_stride` can't be used with item kernels",
+            ));
+        }
+        let Some(arg) = self
+            .arg_metas
+            .iter()
+            .find(|arg| arg.kind.is_push() && arg.ident == *grid_stride)
+        else {
+            return Err(Error::new_spanned(
+                grid_stride,
+                format!("expected a push constant `{grid_stride}: u32`"),
+            ));
+        };
+        if arg.scalar_type != Some(ScalarType::U32) {
+            return Err(Error::new_spanned(
+                &arg.ty,
+                "`grid_stride` length must be `u32`",
+            ));
+        }
+        Ok(())
+    }
+    fn push_struct(&self) -> Option<&KernelArgMeta> {
+        self.arg_metas.iter().find(|arg| arg.kind.is_push_struct())
+    }
     fn desc(&self) -> Result<KernelDesc> {
         let mut kernel_desc = KernelDesc {
             name: self.ident.to_string(),
@@ -729,22 +1354,23 @@ impl KernelMeta {
         }
         for arg_meta in self.arg_metas.iter() {
             let kind = arg_meta.kind;
-            let scalar_type = arg_meta.scalar_ty.scalar_type;
             use KernelArgKind::*;
             match kind {
                 Global | Item => {
                     kernel_desc.slice_descs.push(SliceDesc {
                         name: arg_meta.ident.to_string(),
-                        scalar_type,
+                        scalar_type: arg_meta.scalar_type.unwrap_or(ScalarType::U8),
                         mutable: arg_meta.mutable,
                         item: kind.is_item(),
+                        index64: arg_meta.index64,
+                        pod: arg_meta.scalar_type.is_none(),
                     });
                 }
-                Group => (),
+                Group | PushStruct => (),
                 Push => {
                     kernel_desc.push_descs.push(PushDesc {
                         name: arg_meta.ident.to_string(),
-                        scalar_type,
+                        scalar_type: arg_meta.scalar_type.unwrap(),
                     });
                 }
             }
@@ -928,6 +1554,11 @@ impl KernelMeta {
             .collect()
     }
     fn device_items(&self) -> TokenStream2 {
+        if let Some(grid_stride) = self.grid_stride.as_ref() {
+            return quote! {
+                __krnl_push_consts.#grid_stride as usize
+            };
+        }
         let mut items = self
             .arg_metas
             .iter()
@@ -977,11 +1608,159 @@ impl KernelMeta {
             .chain(self.arg_metas.iter().map(|arg| arg.device_fn_call_tokens()))
             .collect()
     }
+    fn host_fn(&self, attr: &KernelAttrArgs, item_attrs: &[Attribute]) -> Result<TokenStream2> {
+        let Some(host) = attr.host.as_ref() else {
+            return Ok(TokenStream2::new());
+        };
+        if !self.item_kernel() || self.arg_metas.iter().any(|arg| arg.kind.is_group()) {
+            return Err(Error::new_spanned(
+                host,
+                "`host` requires an item or `grid_stride` kernel without group buffers",
+            ));
+        }
+        let ident = &self.ident;
+        let unsafe_token = &self.unsafe_token;
+        let block = &self.block;
+        let host_fn_args: Punctuated<_, Comma> = self
+            .spec_def_args()
+            .into_iter()
+            .chain(self.arg_metas.iter().map(|arg| arg.host_fn_arg_tokens()))
+            .collect();
+        let host_fn_def_args: Punctuated<_, Comma> = self
+            .spec_def_args()
+            .into_iter()
+            .chain(self.arg_metas.iter().map(|arg| {
+                let tokens = arg.host_fn_def_tokens();
+                // The length of `grid_stride` kernels is used by the loop.
+                if self.grid_stride.as_ref() == Some(&arg.ident) {
+                    quote! {
+                        #[allow(unused)]
+                        #tokens
+                    }
+                } else {
+                    tokens
+                }
+            }))
+            .collect();
+        let host_fn_call_args: Punctuated<_, Comma> = self
+            .spec_metas
+            .iter()
+            .map(|spec| spec.ident.to_token_stream())
+            .chain(self.arg_metas.iter().map(|arg| arg.host_fn_call_tokens()))
+            .collect();
+        let mut items = self
+            .arg_metas
+            .iter()
+            .filter(|arg| arg.kind.is_item())
+            .map(|arg| &arg.ident);
+        let (items, items_doc) = if let Some(grid_stride) = self.grid_stride.as_ref() {
+            let items = quote! {
+                #grid_stride as usize
+            };
+            let doc = format!(" Calls the kernel for each item sequentially, the number of items is `{grid_stride}`.");
+            let items_doc = quote! {
+                #[doc = #doc]
+            };
+            (items, items_doc)
+        } else if attr.exact_items() {
+            let first = items.next().unwrap();
+            let items = quote! {{
+                let __krnl_items = #first.len();
+                #(
+                    assert_eq!(
+                        #items.len(),
+                        __krnl_items,
+                        "Kernel `{}`.`{}` has {} items, expected {} (`items = exact`)!",
+                        stringify!(#ident),
+                        stringify!(#items),
+                        #items.len(),
+                        __krnl_items,
+                    );
+                )*
+                __krnl_items
+            }};
+            let items_doc = quote! {
+                /// Calls the kernel for each item sequentially, the number of items is the
+                /// minimum length of the item arguments.
+            };
+            (items, items_doc)
+        } else {
+            let first = items.next().unwrap();
+            let items = quote! {
+                #first.len() #(.min(#items.len()))*
+            };
+            let items_doc = quote! {
+                /// Calls the kernel for each item sequentially, the number of items is the
+                /// minimum length of the item arguments.
+            };
+            (items, items_doc)
+        };
+        let requires = &attr.requires;
+        let panics_doc = if attr.exact_items() || !requires.is_empty() {
+            let exact_items_doc = attr.exact_items().then(|| {
+                quote! {
+                    /// - The item arguments have different lengths (`items = exact`).
+                }
+            });
+            quote! {
+                ///
+                /// **Panics**
+                #exact_items_doc
+                #(
+                    #[doc = concat!(" - `", stringify!(#requires), "` is false.")]
+                )*
+            }
+        } else {
+            TokenStream2::new()
+        };
+        Ok(quote! {
+            /// Executes the kernel on the host.
+            ///
+            #items_doc
+            #panics_doc
+            #[allow(clippy::too_many_arguments)]
+            pub #unsafe_token fn host(#host_fn_args) {
+                #[allow(unused_imports)]
+                use super::*;
+
+                #(
+                    assert!(
+                        #requires,
+                        "Kernel `{}` requires `{}`!",
+                        stringify!(#ident),
+                        stringify!(#requires),
+                    );
+                )*
+
+                #(#item_attrs)*
+                #[allow(clippy::too_many_arguments)]
+                #unsafe_token fn #ident(
+                    #[allow(unused)]
+                    kernel: __krnl::krnl_core::kernel::ItemKernel,
+                    #host_fn_def_args
+                ) #block
+
+                let __krnl_items = #items;
+                for __krnl_item_id in __krnl::krnl_core::kernel::__private::host_items(__krnl_items) {
+                    let kernel = unsafe {
+                        __krnl::krnl_core::kernel::__private::ItemKernelArgs {
+                            item_id: __krnl_item_id as u32,
+                            items: __krnl_items as u32,
+                        }.into_item_kernel()
+                    };
+                    #[allow(unused_unsafe)]
+                    #unsafe_token {
+                        #ident(kernel, #host_fn_call_args);
+                    }
+                }
+            }
+        })
+    }
     fn dispatch_args(&self) -> TokenStream2 {
         let mut tokens = TokenStream2::new();
         for arg in self.arg_metas.iter() {
             let ident = &arg.ident;
-            let ty = &arg.scalar_ty.ident;
+            let ty = &arg.ty;
             if arg.binding.is_some() {
                 let slice_ty = if arg.mutable {
                     format_ident!("SliceMut")
@@ -991,7 +1770,7 @@ impl KernelMeta {
                 tokens.extend(quote! {
                     #ident: #slice_ty<#ty>,
                 });
-            } else if arg.kind.is_push() {
+            } else if arg.kind.is_push() || arg.kind.is_push_struct() {
                 tokens.extend(quote! {
                     #ident: #ty,
                 });
@@ -1151,9 +1930,19 @@ impl KernelDesc {
         let bytes = bincode2::serialize(self).map_err(|e| Error::new(Span2::call_site(), e))?;
         Ok(format!("__krnl_kernel_data_{}", hex::encode(bytes)))
     }
-    fn push_const_fields(&self) -> Punctuated<TokenStream2, Comma> {
+    fn push_const_fields(
+        &self,
+        push_struct: Option<&KernelArgMeta>,
+    ) -> Punctuated<TokenStream2, Comma> {
         let mut fields = Punctuated::new();
         let mut size = 0;
+        if let Some(push_struct) = push_struct {
+            let ident = &push_struct.ident;
+            let ty = &push_struct.ty;
+            fields.push(quote! {
+                #ident: #ty
+            });
+        }
         for push_desc in self.push_descs.iter() {
             let ident = format_ident!("{}", push_desc.name);
             let ty = format_ident!("{}", push_desc.scalar_type.name());
@@ -1162,8 +1951,9 @@ impl KernelDesc {
             });
             size += push_desc.scalar_type.size();
         }
+        // The size of the struct isn't known, so rely on repr(C) to align the u32 fields.
         for i in 0..4 {
-            if size % 4 == 0 {
+            if size % 4 == 0 || push_struct.is_some() {
                 break;
             }
             let ident = format_ident!("__krnl_pad{i}");
@@ -1172,14 +1962,20 @@ impl KernelDesc {
             });
             size += 1;
         }
+        // 64 bit slices are aligned to 8 by repr(C), the host pads them.
         for slice_desc in self.slice_descs.iter() {
             let offset_ident = format_ident!("__krnl_offset_{}", slice_desc.name);
             let len_ident = format_ident!("__krnl_len_{}", slice_desc.name);
+            let ty = if slice_desc.index64 {
+                format_ident!("u64")
+            } else {
+                format_ident!("u32")
+            };
             fields.push(quote! {
-                #offset_ident: u32
+                #offset_ident: #ty
             });
             fields.push(quote! {
-                #len_ident: u32
+                #len_ident: #ty
             });
         }
         fields
@@ -1192,7 +1988,7 @@ impl KernelDesc {
     }
 }
 
-#[derive(Default, Debug, Serialize, Deserialize)]
+#[derive(Default, Clone, Debug, Serialize, Deserialize)]
 struct Features {
     shader_int8: bool,
     shader_int16: bool,
@@ -1201,6 +1997,28 @@ struct Features {
     shader_float64: bool,
 }
 
+impl Features {
+    fn add_name(&mut self, name: &Ident) -> Result<()> {
+        if name == "shader_int8" {
+            self.shader_int8 = true;
+        } else if name == "shader_int16" {
+            self.shader_int16 = true;
+        } else if name == "shader_int64" {
+            self.shader_int64 = true;
+        } else if name == "shader_float16" {
+            self.shader_float16 = true;
+        } else if name == "shader_float64" {
+            self.shader_float64 = true;
+        } else {
+            return Err(Error::new_spanned(
+                name,
+                format!("unknown feature `{name}`, expected `shader_int8`, `shader_int16`, `shader_int64`, `shader_float16`, or `shader_float64`"),
+            ));
+        }
+        Ok(())
+    }
+}
+
 impl ToTokens for Features {
     fn to_tokens(&self, tokens: &mut TokenStream2) {
         let Self {
@@ -1250,6 +2068,9 @@ struct SliceDesc {
     scalar_type: ScalarType,
     mutable: bool,
     item: bool,
+    index64: bool,
+    // A slice of `Pod` structs, with `scalar_type` U8.
+    pod: bool,
 }
 
 impl ToTokens for SliceDesc {
@@ -1259,6 +2080,8 @@ impl ToTokens for SliceDesc {
             scalar_type,
             mutable,
             item,
+            index64,
+            pod,
         } = self;
         tokens.extend(quote! {
             SliceDesc {
@@ -1266,6 +2089,8 @@ impl ToTokens for SliceDesc {
                 scalar_type: #scalar_type,
                 mutable: #mutable,
                 item: #item,
+                index64: #index64,
+                pod: #pod,
             }
         })
     }
@@ -1289,10 +2114,22 @@ impl ToTokens for PushDesc {
     }
 }
 
-fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
+fn kernel_impl(attr: KernelAttrArgs, item_tokens: TokenStream2) -> Result<TokenStream2> {
     let item: KernelItem = syn::parse2(item_tokens.clone())?;
-    let kernel_meta = item.meta()?;
-    let kernel_desc = kernel_meta.desc()?;
+    let mut kernel_meta = item.meta()?;
+    if let Some(grid_stride) = attr.grid_stride.as_ref() {
+        kernel_meta.validate_grid_stride(grid_stride)?;
+        kernel_meta.grid_stride.replace(grid_stride.clone());
+    }
+    let mut kernel_desc = kernel_meta.desc()?;
+    if let Some(features) = attr.features.as_ref() {
+        // Checked and recorded by krnlc.
+        kernel_desc.features = features.clone();
+        // 64 bit slices imply `shader_int64`, otherwise krnlc infers features from the spirv.
+        if kernel_desc.slice_descs.iter().any(|x| x.index64) {
+            kernel_desc.features.shader_int64 = true;
+        }
+    }
     let item_attrs = &item.attrs;
     let unsafe_token = kernel_meta.unsafe_token;
     let ident = &kernel_meta.ident;
@@ -1308,7 +2145,7 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
         let device_fn_def_args = kernel_meta.device_fn_def_args();
         let device_fn_call_args = kernel_meta.device_fn_call_args();
         let push_consts_ident = format_ident!("__krnl_{ident}PushConsts");
-        let push_const_fields = kernel_desc.push_const_fields();
+        let push_const_fields = kernel_desc.push_const_fields(kernel_meta.push_struct());
         let push_struct_tokens = quote! {
             #[cfg(target_arch = "spirv")]
             #[automatically_derived]
@@ -1325,7 +2162,7 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                 );
             }
         };
-        if kernel_meta.itemwise {
+        if kernel_meta.item_kernel() {
             device_fn_call = quote! {
                 let __krnl_items = #items;
                 let mut __krnl_item_id = kernel.global_id();
@@ -1343,82 +2180,86 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                 }
             };
         }
-        let kernel_type = if kernel_meta.itemwise {
+        let kernel_type = if kernel_meta.item_kernel() {
             quote! { ItemKernel }
         } else {
             quote! {
                 Kernel
             }
         };
+        // krnlc defines `__krnl_kernel_entry` in each module, omitting entry points of modules
+        // that are not part of the build, see `#[krnl(variants(..))]`.
         quote! {
             #push_struct_tokens
             #[cfg(target_arch = "spirv")]
-            #[::krnl_core::spirv_std::spirv(compute(threads(1)))]
-            #[allow(unused)]
-            pub fn #ident(
-                #[allow(unused)]
-                #[spirv(push_constant)]
-                __krnl_push_consts: &#push_consts_ident,
-                #[allow(unused)]
-                #[spirv(global_invocation_id)]
-                __krnl_global_id: ::krnl_core::spirv_std::glam::UVec3,
-                #[allow(unused)]
-                #[spirv(num_workgroups)]
-                __krnl_groups: ::krnl_core::spirv_std::glam::UVec3,
-                #[allow(unused)]
-                #[spirv(workgroup_id)]
-                __krnl_group_id: ::krnl_core::spirv_std::glam::UVec3,
+            __krnl_kernel_entry! {
+                #[::krnl_core::spirv_std::spirv(compute(threads(1)))]
                 #[allow(unused)]
-                #[spirv(num_subgroups)]
-                __krnl_subgroups: u32,
-                #[allow(unused)]
-                #[spirv(subgroup_id)]
-                __krnl_subgroup_id: u32,
-                #[allow(unused)]
-                #[spirv(subgroup_size)]
-                __krnl_subgroup_threads: u32,
-                #[allow(unused)]
-                #[spirv(subgroup_local_invocation_id)]
-                __krnl_subgroup_thread_id: u32,
-                #[allow(unused)]
-                #[spirv(local_invocation_id)]
-                __krnl_thread_id: ::krnl_core::spirv_std::glam::UVec3,
-                #[allow(unused)]
-                #[spirv(storage_buffer, descriptor_set = 1, binding = 0)]
-                #kernel_data: &mut [u32],
-                #compute_def_args
-            ) {
-                #(#item_attrs)*
-                #unsafe_token fn #ident(
+                pub fn #ident(
                     #[allow(unused)]
-                    kernel: ::krnl_core::kernel::#kernel_type,
-                    #device_fn_def_args
-                ) #block
-                {
-                    let __krnl_kernel_data = #kernel_data;
-                    unsafe {
-                        use ::krnl_core::spirv_std::arch::IndexUnchecked as _;
-                        *__krnl_kernel_data.index_unchecked_mut(0) = 1;
+                    #[spirv(push_constant)]
+                    __krnl_push_consts: &#push_consts_ident,
+                    #[allow(unused)]
+                    #[spirv(global_invocation_id)]
+                    __krnl_global_id: ::krnl_core::spirv_std::glam::UVec3,
+                    #[allow(unused)]
+                    #[spirv(num_workgroups)]
+                    __krnl_groups: ::krnl_core::spirv_std::glam::UVec3,
+                    #[allow(unused)]
+                    #[spirv(workgroup_id)]
+                    __krnl_group_id: ::krnl_core::spirv_std::glam::UVec3,
+                    #[allow(unused)]
+                    #[spirv(num_subgroups)]
+                    __krnl_subgroups: u32,
+                    #[allow(unused)]
+                    #[spirv(subgroup_id)]
+                    __krnl_subgroup_id: u32,
+                    #[allow(unused)]
+                    #[spirv(subgroup_size)]
+                    __krnl_subgroup_threads: u32,
+                    #[allow(unused)]
+                    #[spirv(subgroup_local_invocation_id)]
+                    __krnl_subgroup_thread_id: u32,
+                    #[allow(unused)]
+                    #[spirv(local_invocation_id)]
+                    __krnl_thread_id: ::krnl_core::spirv_std::glam::UVec3,
+                    #[allow(unused)]
+                    #[spirv(storage_buffer, descriptor_set = 1, binding = 0)]
+                    #kernel_data: &mut [u32],
+                    #compute_def_args
+                ) {
+                    #(#item_attrs)*
+                    #unsafe_token fn #ident(
+                        #[allow(unused)]
+                        kernel: ::krnl_core::kernel::#kernel_type,
+                        #device_fn_def_args
+                    ) #block
+                    {
+                        let __krnl_kernel_data = #kernel_data;
+                        unsafe {
+                            use ::krnl_core::spirv_std::arch::IndexUnchecked as _;
+                            *__krnl_kernel_data.index_unchecked_mut(0) = 1;
+                        }
+                        #declare_specs
+                        #declare_threads
+                        let mut kernel = unsafe {
+                            ::krnl_core::kernel::__private::KernelArgs {
+                                global_threads: __krnl_groups.x * __krnl_threads,
+                                global_id: __krnl_global_id.x,
+                                groups: __krnl_groups.x,
+                                group_id: __krnl_group_id.x,
+                                subgroups: __krnl_subgroups,
+                                subgroup_id: __krnl_subgroup_id,
+                                subgroup_threads: __krnl_subgroup_threads,
+                                subgroup_thread_id: __krnl_subgroup_thread_id,
+                                threads: __krnl_threads,
+                                thread_id: __krnl_thread_id.x,
+                            }.into_kernel()
+                        };
+                        #device_arrays
+                        #device_slices
+                        #device_fn_call
                     }
-                    #declare_specs
-                    #declare_threads
-                    let mut kernel = unsafe {
-                        ::krnl_core::kernel::__private::KernelArgs {
-                            global_threads: __krnl_groups.x * __krnl_threads,
-                            global_id: __krnl_global_id.x,
-                            groups: __krnl_groups.x,
-                            group_id: __krnl_group_id.x,
-                            subgroups: __krnl_subgroups,
-                            subgroup_id: __krnl_subgroup_id,
-                            subgroup_threads: __krnl_subgroup_threads,
-                            subgroup_thread_id: __krnl_subgroup_thread_id,
-                            threads: __krnl_threads,
-                            thread_id: __krnl_thread_id.x,
-                        }.into_kernel()
-                    };
-                    #device_arrays
-                    #device_slices
-                    #device_fn_call
                 }
             }
         }
@@ -1441,6 +2282,102 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
             }
         };
         let host_array_length_checks = kernel_meta.host_array_length_checks();
+        let host_fn = kernel_meta.host_fn(&attr, item_attrs)?;
+        if let Some(items) = attr.items.as_ref() {
+            if !kernel_meta.itemwise {
+                return Err(Error::new_spanned(items, "`items` requires an item kernel"));
+            }
+        }
+        let exact_items = attr.exact_items();
+        // `#[push]` structs and slices of `Pod` structs are types of the module.
+        let use_super = if kernel_meta.push_struct().is_some()
+            || kernel_meta
+                .arg_metas
+                .iter()
+                .any(|arg| arg.kind.is_global() && arg.scalar_type.is_none())
+        {
+            quote! {
+                #[allow(unused_imports)]
+                use super::*;
+            }
+        } else {
+            TokenStream2::new()
+        };
+        let (with_push_struct, dispatch_push_struct) = if let Some(push_struct) =
+            kernel_meta.push_struct()
+        {
+            let ident = &push_struct.ident;
+            let ty = &push_struct.ty;
+            (
+                quote! {
+                    .with_push_struct(::std::mem::size_of::<#ty>(), ::std::mem::align_of::<#ty>())
+                    .with_push_struct_fields({
+                        use __krnl::kernel::__private::{
+                            PushStructFields, PushStructFieldsDerived as _, PushStructFieldsPod as _,
+                        };
+                        (&PushStructFields::<#ty>(::std::marker::PhantomData)).push_struct_fields()
+                    })
+                },
+                quote! {
+                    __krnl::bytemuck::bytes_of(&#ident)
+                },
+            )
+        } else {
+            (TokenStream2::new(), quote! { &[] })
+        };
+        let with_items = kernel_meta.grid_stride.as_ref().map(|grid_stride| {
+            quote! {
+                .clone().with_items(#grid_stride)
+            }
+        });
+        let with_features = attr.features.as_ref().map(|features| {
+            quote! {
+                .with_features({
+                    use __krnl::device::Features;
+                    #features
+                })
+            }
+        });
+        let dispatch_items_doc = if let Some(grid_stride) = kernel_meta.grid_stride.as_ref() {
+            let doc = format!(
+                " Groups, if not provided, are inferred from `{grid_stride}` (`grid_stride`)."
+            );
+            quote! {
+                ///
+                #[doc = #doc]
+            }
+        } else if !kernel_meta.itemwise {
+            TokenStream2::new()
+        } else if exact_items {
+            quote! {
+                ///
+                /// Item arguments must have the same length (`items = exact`).
+            }
+        } else {
+            quote! {
+                ///
+                /// Item arguments are zipped, the number of items is the minimum length (`items = zip`).
+            }
+        };
+        let dispatch_items_error_doc = if exact_items {
+            quote! {
+                /// - The item arguments have different lengths.
+            }
+        } else {
+            TokenStream2::new()
+        };
+        let requires = &attr.requires;
+        let dispatch_requires = quote! {
+            #(
+                if !(#requires) {
+                    return Err(format_err!(
+                        "Kernel `{}` requires `{}`!",
+                        stringify!(#ident),
+                        stringify!(#requires),
+                    ));
+                }
+            )*
+        };
         let kernel_builder_specialize_fn = if !kernel_desc.spec_descs.is_empty() {
             let spec_def_args = kernel_meta.spec_def_args();
             let spec_args = kernel_meta.spec_args();
@@ -1458,8 +2395,14 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
             TokenStream2::new()
         };
         let input_docs = {
+            let attr_tokens = &attr.tokens;
+            let kernel_attr = if attr_tokens.is_empty() {
+                quote! { #[kernel] }
+            } else {
+                quote! { #[kernel(#attr_tokens)] }
+            };
             let input_tokens_string = prettyplease::unparse(&syn::parse2(quote! {
-                #[kernel]
+                #kernel_attr
                 #item_tokens
             })?);
             let input_doc_string = format!("```\n{input_tokens_string}\n```");
@@ -1498,6 +2441,7 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                     anyhow::format_err,
                 };
                 use ::std::sync::OnceLock;
+                #use_super
                 #[cfg(not(krnlc))]
                 #[doc(hidden)]
                 use __krnl::macros::__krnl_cache;
@@ -1519,15 +2463,17 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                 /// The builder is lazily created on first call.
                 ///
                 /// **Errors**
-                /// - The kernel wasn't compiled (with `#[krnl(no_build)]` applied to `#[module]`).
+                /// - The kernel wasn't compiled (with `#[krnl(no_build)]` applied to `#[module]`,
+                ///   or with the "no-build" feature).
                 pub fn builder() -> Result<KernelBuilder> {
                     static BUILDER: OnceLock<Result<KernelBuilderBase, String>> = OnceLock::new();
                     let builder = BUILDER.get_or_init(|| {
                         const DESC: Option<KernelDesc> = validate_kernel(__krnl_kernel!(#ident), #safety, &[#(#spec_descs),*], &[#(#slice_descs),*], &[#(#push_descs),*]);
                         if let Some(desc) = DESC.as_ref() {
                             KernelBuilderBase::from_desc(desc.clone())
+                                .map(|builder| builder.with_exact_items(#exact_items)#with_push_struct #with_features)
                         } else {
-                            Err(format!("Kernel `{}` not compiled!", ::std::module_path!()))
+                            Err(format!("Kernel `{}` not compiled, module was built with `no_build`!", ::std::module_path!()))
                         }
                     });
                     match builder {
@@ -1548,6 +2494,10 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                         }
                     }
                     #kernel_builder_specialize_fn
+                    /// Metadata of the kernel, ie arguments and spec constants.
+                    pub fn metadata(&self) -> __krnl::kernel::KernelMetadata {
+                        self.inner.metadata()
+                    }
                     /// Builds the kernel for `device`.
                     ///
                     /// The kernel is cached, so subsequent calls to `.build()` with identical
@@ -1575,6 +2525,21 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                     pub fn threads(&self) -> u32 {
                         self.inner.threads()
                     }
+                    /// Max groups per dispatch, see [`DeviceInfo::max_groups()`](DeviceInfo::max_groups).
+                    pub fn max_groups(&self) -> u32 {
+                        self.inner.max_groups()
+                    }
+                    /// Global threads to process `len` elements.
+                    ///
+                    /// Rounds up to the next multiple of threads, limited to [`.max_groups()`](Self::max_groups).
+                    /// When limited, the kernel must loop over the remaining elements, ie with `grid_stride`.
+                    pub fn recommended_global_threads(&self, len: usize) -> u32 {
+                        self.inner.recommended_global_threads(len)
+                    }
+                    /// Metadata of the kernel, ie arguments and specialized spec constants.
+                    pub fn metadata(&self) -> __krnl::kernel::KernelMetadata {
+                        self.inner.metadata()
+                    }
                     /// Global threads to dispatch.
                     ///
                     /// Implicitly declares groups by rounding up to the next multiple of threads.
@@ -1591,19 +2556,66 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
                             inner: self.inner.with_groups(groups),
                         }
                     }
+                    /// Groups to dispatch, read by the device from the first element of `groups`.
+                    ///
+                    /// `groups` is `[x, 1, 1]`, and can be written by a previously dispatched kernel.
+                    ///
+                    /// **Errors**
+                    /// - `groups` has fewer than 3 elements.
+                    /// - `groups` is not on the device.
+                    pub fn with_groups_indirect(self, groups: Slice<u32>) -> Result<Self> {
+                        Ok(Self {
+                            inner: self.inner.with_groups_indirect(groups)?,
+                        })
+                    }
+                    /// The priority of dispatches.
+                    ///
+                    /// Defaults to [`Priority::Normal`](__krnl::device::Priority::Normal).
+                    pub fn with_priority(self, priority: __krnl::device::Priority) -> Self {
+                        Self {
+                            inner: self.inner.with_priority(priority),
+                        }
+                    }
+                    /// The [stream](__krnl::device::Stream) to dispatch on.
+                    ///
+                    /// Defaults to stream 0. Overrides the [priority](Self::with_priority).
+                    ///
+                    /// **Errors**
+                    /// - `stream` is not on the device.
+                    pub fn with_stream(self, stream: &__krnl::device::Stream) -> Result<Self> {
+                        Ok(Self {
+                            inner: self.inner.with_stream(stream)?,
+                        })
+                    }
                     /// Dispatches the kernel.
                     ///
                     /// - Waits for immutable access to slice arguments.
                     /// - Waits for mutable access to mutable slice arguments.
                     /// - Blocks until the kernel is queued.
+                    #dispatch_items_doc
                     ///
                     /// **Errors**
                     /// - DeviceLost: The device was lost.
                     /// - The kernel could not be queued.
+                    #dispatch_items_error_doc
+                    #(
+                        #[doc = concat!(" - `", stringify!(#requires), "` is false.")]
+                    )*
                     pub #unsafe_token fn dispatch(&self, #dispatch_args) -> Result<()> {
-                        unsafe { self.inner.dispatch(&[#dispatch_slice_args], &[#(#dispatch_push_args.into()),*]) }
+                        #dispatch_requires
+                        unsafe { self.inner #with_items .dispatch(&[#dispatch_slice_args], #dispatch_push_struct, &[#(#dispatch_push_args.into()),*]) }
+                    }
+                    /// Dispatches the kernel, returning a future that resolves when it is finished.
+                    ///
+                    /// The future also waits for work previously submitted to the same
+                    /// [stream](__krnl::device::Stream). See [`.dispatch(..)`](Self::dispatch).
+                    pub #unsafe_token fn dispatch_async(&self, #dispatch_args) -> Result<__krnl::device::WaitFuture> {
+                        #dispatch_requires
+                        unsafe { self.inner #with_items .dispatch_async(&[#dispatch_slice_args], #dispatch_push_struct, &[#(#dispatch_push_args.into()),*]) }
                     }
                 }
+
+                #host_fn
             }
         }
     };
@@ -1616,6 +2628,372 @@ fn kernel_impl(item_tokens: TokenStream2) -> Result<TokenStream2> {
     Ok(tokens)
 }
 
+fn contains_ident(tokens: TokenStream2, ident: &Ident) -> bool {
+    use proc_macro2::TokenTree;
+
+    tokens.into_iter().any(|tree| match tree {
+        TokenTree::Ident(x) => x == *ident,
+        TokenTree::Group(group) => contains_ident(group.stream(), ident),
+        _ => false,
+    })
+}
+
+// Replaces `generic` with `ty`, and renames the kernel to `ident`.
+fn instantiate_kernel(
+    tokens: TokenStream2,
+    kernel: &Ident,
+    ident: &Ident,
+    generic: &Ident,
+    ty: &Ident,
+) -> TokenStream2 {
+    use proc_macro2::{Group, TokenTree};
+
+    fn replace(tokens: TokenStream2, generic: &Ident, ty: &Ident) -> TokenStream2 {
+        tokens
+            .into_iter()
+            .map(|tree| match tree {
+                TokenTree::Ident(x) if x == *generic => {
+                    let mut ty = ty.clone();
+                    ty.set_span(x.span());
+                    TokenTree::Ident(ty)
+                }
+                TokenTree::Group(group) => {
+                    let mut new_group =
+                        Group::new(group.delimiter(), replace(group.stream(), generic, ty));
+                    new_group.set_span(group.span());
+                    TokenTree::Group(new_group)
+                }
+                tree => tree,
+            })
+            .collect()
+    }
+
+    let mut fn_token = false;
+    let mut renamed = false;
+    replace(tokens, generic, ty)
+        .into_iter()
+        .map(|tree| {
+            if let TokenTree::Ident(x) = &tree {
+                if fn_token && !renamed && x == kernel {
+                    renamed = true;
+                    fn_token = false;
+                    return TokenTree::Ident(ident.clone());
+                }
+            }
+            fn_token = matches!(&tree, TokenTree::Ident(x) if x == "fn");
+            tree
+        })
+        .collect()
+}
+
+fn generic_kernel_impl(
+    generic: KernelGeneric,
+    attr: KernelAttrArgs,
+    item_tokens: TokenStream2,
+) -> Result<TokenStream2> {
+    use syn::{FnArg, ItemFn};
+
+    let item: ItemFn = syn::parse2(item_tokens.clone())?;
+    if let Some(param) = item.sig.generics.type_params().next() {
+        return Err(Error::new_spanned(
+            param,
+            format!(
+                "type parameters are not supported, use `generic({} = [..])`",
+                generic.ident
+            ),
+        ));
+    }
+    let kernel = &item.sig.ident;
+    let unsafe_token = item.sig.unsafety;
+    let item_attrs = &item.attrs;
+    let generic_args: Vec<bool> = item
+        .sig
+        .inputs
+        .iter()
+        .map(|arg| match arg {
+            FnArg::Typed(arg) => contains_ident(arg.ty.to_token_stream(), &generic.ident),
+            FnArg::Receiver(_) => false,
+        })
+        .collect();
+    let mut instance_tokens = TokenStream2::new();
+    let mut instances = Vec::with_capacity(generic.types.len());
+    for (ty, scalar_type) in generic.types.iter() {
+        let ident = format_ident!("{kernel}_{ty}", span = kernel.span());
+        let tokens = instantiate_kernel(item_tokens.clone(), kernel, &ident, &generic.ident, ty);
+        let kernel_meta = syn::parse2::<KernelItem>(tokens.clone())?.meta()?;
+        instance_tokens.extend(kernel_impl(attr.clone(), tokens)?);
+        let variant = format_ident!("{}", scalar_type.name().to_uppercase());
+        instances.push((ident, variant, kernel_meta));
+    }
+    let idents: Vec<_> = instances.iter().map(|(ident, ..)| ident).collect();
+    let variants: Vec<_> = instances.iter().map(|(_, variant, _)| variant).collect();
+    let variant_docs = idents
+        .iter()
+        .map(|ident| format!(" [`{ident}`](super::{ident})."))
+        .collect::<Vec<_>>();
+    let types = generic
+        .types
+        .iter()
+        .map(|(ty, _)| ty.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let kernel_meta = &instances[0].2;
+    let specialize_fn = if !kernel_meta.spec_metas.is_empty()
+        && item
+            .sig
+            .generics
+            .const_params()
+            .all(|param| !contains_ident(param.ty.to_token_stream(), &generic.ident))
+    {
+        let spec_def_args = kernel_meta.spec_def_args();
+        let spec_args = kernel_meta.spec_args();
+        let spec_args = quote! { #(#spec_args),* };
+        quote! {
+            /// Specializes the kernel.
+            #[allow(clippy::too_many_arguments)]
+            pub fn specialize(self, #spec_def_args) -> Self {
+                match self {
+                    #(Self::#variants(x) => Self::#variants(x.specialize(#spec_args)),)*
+                }
+            }
+        }
+    } else {
+        TokenStream2::new()
+    };
+    let mut dispatch_args = TokenStream2::new();
+    for (arg, generic) in kernel_meta.arg_metas.iter().zip(generic_args.iter()) {
+        let ident = &arg.ident;
+        let ty = &arg.ty;
+        if arg.binding.is_some() {
+            let slice_ty = match (*generic, arg.mutable) {
+                (true, true) => quote! { ScalarSliceMut<'_> },
+                (true, false) => quote! { ScalarSlice<'_> },
+                (false, true) => quote! { SliceMut<#ty> },
+                (false, false) => quote! { Slice<#ty> },
+            };
+            dispatch_args.extend(quote! { #ident: #slice_ty, });
+        } else if arg.kind.is_push() {
+            let ty = if *generic {
+                quote! { ScalarElem }
+            } else {
+                ty.clone()
+            };
+            dispatch_args.extend(quote! { #ident: #ty, });
+        } else if arg.kind.is_push_struct() {
+            if *generic {
+                return Err(Error::new_spanned(
+                    ident,
+                    "generic `#[push]` structs are not supported",
+                ));
+            }
+            dispatch_args.extend(quote! { #ident: #ty, });
+        }
+    }
+    let dispatch_arms = instances.iter().map(|(_, variant, kernel_meta)| {
+        let mut conversions = TokenStream2::new();
+        let mut call_args = Vec::new();
+        for (arg, generic) in kernel_meta.arg_metas.iter().zip(generic_args.iter()) {
+            let ident = &arg.ident;
+            let ty = &arg.ty;
+            if arg.binding.is_none() && !arg.kind.is_push() && !arg.kind.is_push_struct() {
+                continue;
+            }
+            call_args.push(ident);
+            if !*generic {
+                continue;
+            }
+            if arg.binding.is_some() {
+                let (slice_ty, scalar_slice_ty) = if arg.mutable {
+                    (quote! { SliceMut }, quote! { ScalarSliceMut })
+                } else {
+                    (quote! { Slice }, quote! { ScalarSlice })
+                };
+                conversions.extend(quote! {
+                    let #ident: #slice_ty<#ty> = #ident.try_into().map_err(|x: #scalar_slice_ty| {
+                        format_err!(
+                            "Kernel `{}`.`{}` expected {:?}, found {:?}!",
+                            stringify!(#kernel),
+                            stringify!(#ident),
+                            ScalarType::#variant,
+                            x.scalar_type(),
+                        )
+                    })?;
+                });
+            } else {
+                conversions.extend(quote! {
+                    let #ident: #ty = {
+                        let scalar_type = #ident.scalar_type();
+                        #ident.try_into().map_err(|_| {
+                            format_err!(
+                                "Kernel `{}`.`{}` expected {:?}, found {:?}!",
+                                stringify!(#kernel),
+                                stringify!(#ident),
+                                ScalarType::#variant,
+                                scalar_type,
+                            )
+                        })?
+                    };
+                });
+            }
+        }
+        quote! {
+            Self::#variant(kernel) => {
+                #conversions
+                unsafe { kernel.dispatch(#(#call_args),*) }
+            }
+        }
+    });
+    let builder_doc = format!(
+        " Creates a builder for `scalar_type`.\n\n **Errors**\n - The kernel is not instantiated for `scalar_type`, expected one of {types}.\n - See `builder()` of each instantiation."
+    );
+    Ok(quote! {
+        #instance_tokens
+
+        #[cfg(not(target_arch = "spirv"))]
+        #(#item_attrs)*
+        #[automatically_derived]
+        pub mod #kernel {
+            __krnl_module_arg!(use crate as __krnl);
+            use __krnl::{
+                anyhow::{Result, format_err},
+                buffer::{ScalarSlice, ScalarSliceMut, Slice, SliceMut},
+                device::{Device, Priority},
+                kernel::KernelMetadata,
+                krnl_core::half::{f16, bf16},
+                scalar::{ScalarElem, ScalarType},
+            };
+            #[allow(unused_imports)]
+            use super::*;
+
+            #[doc = #builder_doc]
+            pub fn builder(scalar_type: ScalarType) -> Result<KernelBuilder> {
+                #[allow(unreachable_patterns)]
+                match scalar_type {
+                    #(ScalarType::#variants => Ok(KernelBuilder::#variants(super::#idents::builder()?)),)*
+                    _ => Err(format_err!(
+                        "Kernel `{}` is not instantiated for {:?}, expected one of {}!",
+                        stringify!(#kernel),
+                        scalar_type,
+                        #types,
+                    )),
+                }
+            }
+
+            /// Builder for creating a [`Kernel`], for each scalar type.
+            ///
+            /// Match on the builder for methods of an instantiation, ie `.specialize(..)`.
+            pub enum KernelBuilder {
+                #(
+                    #[doc = #variant_docs]
+                    #variants(super::#idents::KernelBuilder),
+                )*
+            }
+
+            impl KernelBuilder {
+                /// The scalar type.
+                pub fn scalar_type(&self) -> ScalarType {
+                    match self {
+                        #(Self::#variants(_) => ScalarType::#variants,)*
+                    }
+                }
+                /// Threads per group.
+                pub fn with_threads(self, threads: u32) -> Self {
+                    match self {
+                        #(Self::#variants(x) => Self::#variants(x.with_threads(threads)),)*
+                    }
+                }
+                #specialize_fn
+                /// Metadata of the kernel.
+                pub fn metadata(&self) -> KernelMetadata {
+                    match self {
+                        #(Self::#variants(x) => x.metadata(),)*
+                    }
+                }
+                /// Builds the kernel for `device`.
+                pub fn build(&self, device: Device) -> Result<Kernel> {
+                    match self {
+                        #(Self::#variants(x) => Ok(Kernel::#variants(x.build(device)?)),)*
+                    }
+                }
+            }
+
+            /// Kernel, for each scalar type.
+            ///
+            /// Match on the kernel for methods of an instantiation, ie `.dispatch_async(..)`.
+            pub enum Kernel {
+                #(
+                    #[doc = #variant_docs]
+                    #variants(super::#idents::Kernel),
+                )*
+            }
+
+            impl Kernel {
+                /// The scalar type.
+                pub fn scalar_type(&self) -> ScalarType {
+                    match self {
+                        #(Self::#variants(_) => ScalarType::#variants,)*
+                    }
+                }
+                /// Threads per group.
+                pub fn threads(&self) -> u32 {
+                    match self {
+                        #(Self::#variants(x) => x.threads(),)*
+                    }
+                }
+                /// Metadata of the kernel.
+                pub fn metadata(&self) -> KernelMetadata {
+                    match self {
+                        #(Self::#variants(x) => x.metadata(),)*
+                    }
+                }
+                /// Max groups per dispatch.
+                pub fn max_groups(&self) -> u32 {
+                    match self {
+                        #(Self::#variants(x) => x.max_groups(),)*
+                    }
+                }
+                /// Global threads to process `len` elements.
+                pub fn recommended_global_threads(&self, len: usize) -> u32 {
+                    match self {
+                        #(Self::#variants(x) => x.recommended_global_threads(len),)*
+                    }
+                }
+                /// Global threads to dispatch.
+                pub fn with_global_threads(self, global_threads: u32) -> Self {
+                    match self {
+                        #(Self::#variants(x) => Self::#variants(x.with_global_threads(global_threads)),)*
+                    }
+                }
+                /// Groups to dispatch.
+                pub fn with_groups(self, groups: u32) -> Self {
+                    match self {
+                        #(Self::#variants(x) => Self::#variants(x.with_groups(groups)),)*
+                    }
+                }
+                /// The priority of dispatches.
+                pub fn with_priority(self, priority: Priority) -> Self {
+                    match self {
+                        #(Self::#variants(x) => Self::#variants(x.with_priority(priority)),)*
+                    }
+                }
+                /// Dispatches the kernel.
+                ///
+                /// Generic arguments are converted to the scalar type of the kernel.
+                ///
+                /// **Errors**
+                /// - A generic argument has a different scalar type.
+                /// - See `.dispatch(..)` of each instantiation.
+                #[allow(unused_unsafe)]
+                pub #unsafe_token fn dispatch(&self, #dispatch_args) -> Result<()> {
+                    match self {
+                        #(#dispatch_arms)*
+                    }
+                }
+            }
+        }
+    })
+}
+
 #[doc(hidden)]
 #[proc_macro]
 pub fn __krnl_cache(input: TokenStream) -> TokenStream {
@@ -1720,7 +3098,9 @@ fn __krnl_cache_impl(input: TokenStream2) -> Result<TokenStream2> {
                 kernel::__private::{find_kernel, KernelDesc, KernelDescArgs, Safety, SpecDesc, SliceDesc, PushDesc},
             };
 
-            find_kernel(std::module_path!(), &[#(#kernels),*])
+            // Variants of a kernel have the same name.
+            const KERNELS: &[KernelDesc] = &[#(#kernels),*];
+            find_kernel(std::module_path!(), KERNELS)
         }
     };
     Ok(tokens)
@@ -1767,4 +3147,192 @@ mod tests {
         assert!(krnlc_version_compatible("0.1.1", "0.1.0"));
         assert!(!krnlc_version_compatible("0.1.1", "0.2.0"));
     }
+
+    #[test]
+    fn module_variants() {
+        let variants =
+            syn::parse_str::<ModuleVariants>("([shader_int64, shader_float64], [])").unwrap();
+        assert_eq!(variants.encode(), "shader_int64,shader_float64;");
+        assert!(syn::parse_str::<ModuleVariants>("()").is_err());
+        assert!(syn::parse_str::<ModuleVariants>("([shader_int32])").is_err());
+    }
+
+    #[test]
+    fn kernel_test_args() {
+        let args = syn::parse_str::<KernelTestArgs>("").unwrap();
+        assert!(args.tolerance.is_none());
+        let args = syn::parse_str::<KernelTestArgs>("tolerance = 1e-6").unwrap();
+        assert!(args.tolerance.is_some());
+        assert!(syn::parse_str::<KernelTestArgs>("epsilon = 1e-6").is_err());
+        let args =
+            syn::parse_str::<KernelTestArgs>("features(shader_int64), tolerance = 0.1").unwrap();
+        assert!(args.features.unwrap().shader_int64);
+        assert!(syn::parse_str::<KernelTestArgs>("features(int64)").is_err());
+    }
+
+    #[test]
+    fn kernel_attr_args() {
+        let args = syn::parse_str::<KernelAttrArgs>("").unwrap();
+        assert!(args.host.is_none());
+        assert!(!args.exact_items());
+        let args = syn::parse_str::<KernelAttrArgs>("host, items = exact").unwrap();
+        assert!(args.host.is_some());
+        assert!(args.exact_items());
+        let args = syn::parse_str::<KernelAttrArgs>("items = zip").unwrap();
+        assert!(!args.exact_items());
+        assert!(syn::parse_str::<KernelAttrArgs>("items = shortest").is_err());
+        assert!(syn::parse_str::<KernelAttrArgs>("foo").is_err());
+        let args =
+            syn::parse_str::<KernelAttrArgs>("features(shader_int8, shader_float64)").unwrap();
+        let features = args.features.unwrap();
+        assert!(features.shader_int8 && features.shader_float64);
+        assert!(!features.shader_int16);
+        assert!(syn::parse_str::<KernelAttrArgs>("features()").is_err());
+        assert!(syn::parse_str::<KernelAttrArgs>("features(shader_int32)").is_err());
+        let args = syn::parse_str::<KernelAttrArgs>("grid_stride = n").unwrap();
+        assert_eq!(args.grid_stride.unwrap(), "n");
+        let args =
+            syn::parse_str::<KernelAttrArgs>("require(x.len() == y.len(), n > 0), require(a)")
+                .unwrap();
+        assert_eq!(args.requires.len(), 3);
+        assert!(syn::parse_str::<KernelAttrArgs>("require()").is_err());
+    }
+
+    #[test]
+    fn kernel_generic() {
+        let split = |input: &str| split_kernel_generic(syn::parse_str(input).unwrap());
+        let (generic, tokens) = split("host, generic(T = [f32, u32]), items = exact").unwrap();
+        let generic = generic.unwrap();
+        assert_eq!(generic.ident, "T");
+        assert_eq!(generic.types.len(), 2);
+        assert_eq!(generic.types[1].1, ScalarType::U32);
+        let args = syn::parse2::<KernelAttrArgs>(tokens).unwrap();
+        assert!(args.host.is_some() && args.exact_items());
+        let (generic, _) = split("require(x.len() > 0)").unwrap();
+        assert!(generic.is_none());
+        assert!(split("generic(T = [])").is_err());
+        assert!(split("generic(T = [f32, f32])").is_err());
+        assert!(split("generic(T = [f128])").is_err());
+        assert!(split("generic(T = [f32]), generic(U = [u32])").is_err());
+        let item = "fn foo(#[item] x: T, #[item] y: &mut T) { *y = x; }";
+        let generic = split("generic(T = [f32])").unwrap().0.unwrap();
+        let tokens = generic_kernel_impl(
+            generic,
+            KernelAttrArgs::default(),
+            syn::parse_str(item).unwrap(),
+        )
+        .unwrap()
+        .to_string();
+        assert!(tokens.contains("foo_f32"));
+        let generic = split("generic(T = [f32])").unwrap().0.unwrap();
+        let item = "fn foo<T>(#[item] x: T, #[item] y: &mut T) { *y = x; }";
+        assert!(generic_kernel_impl(
+            generic,
+            KernelAttrArgs::default(),
+            syn::parse_str(item).unwrap()
+        )
+        .is_err());
+    }
+
+    #[test]
+    fn kernel_push_struct() {
+        let item = syn::parse_str::<KernelItem>(
+            "fn foo(#[push] params: Params, a: u32, #[item] x: &mut f32) {}",
+        )
+        .unwrap();
+        let meta = item.meta().unwrap();
+        let push_struct = meta.push_struct().unwrap();
+        assert_eq!(push_struct.ident, "params");
+        assert!(push_struct.scalar_type.is_none());
+        let desc = meta.desc().unwrap();
+        assert_eq!(desc.push_descs.len(), 1);
+        assert_eq!(desc.push_const_fields(Some(push_struct)).len(), 4);
+        let item = syn::parse_str::<KernelItem>(
+            "fn foo(#[push] a: A, #[push] b: B, #[item] x: &mut f32) {}",
+        )
+        .unwrap();
+        assert!(item.meta().is_err());
+    }
+
+    #[test]
+    fn push_constants_derive() {
+        let derive = |input: &str| push_constants_impl(syn::parse_str(input).unwrap());
+        derive("#[repr(C)] struct Params { alpha: f32, beta: f32 }").unwrap();
+        derive("#[repr(C, align(8))] struct Params { alpha: f32 }").unwrap();
+        let error = derive("struct Params { alpha: f32 }").unwrap_err();
+        assert_eq!(error.to_string(), "expected `#[repr(C)]`");
+        assert!(derive("#[repr(C)] struct Params<T> { alpha: T }").is_err());
+        assert!(derive("#[repr(C)] struct Params(f32);").is_err());
+        assert!(derive("#[repr(C)] enum Params { A }").is_err());
+    }
+
+    #[test]
+    fn pod_derive() {
+        let derive = |input: &str| pod_impl(syn::parse_str(input).unwrap());
+        derive("#[repr(C)] struct Particle { x: f32, v: f32 }").unwrap();
+        let error = derive("struct Particle { x: f32 }").unwrap_err();
+        assert_eq!(error.to_string(), "expected `#[repr(C)]`");
+        assert!(derive("#[repr(C)] struct Particle {}").is_err());
+        assert!(derive("#[repr(C)] struct Particle<T> { x: T }").is_err());
+        assert!(derive("#[repr(C)] struct Particle(f32);").is_err());
+    }
+
+    #[test]
+    fn kernel_slice_pod() {
+        let meta = syn::parse_str::<KernelItem>(
+            "fn foo(#[global] x: Slice<f32>, #[global] y: UnsafeSlice<Particle>) {}",
+        )
+        .unwrap()
+        .meta()
+        .unwrap();
+        let desc = meta.desc().unwrap();
+        assert!(!desc.slice_descs[0].pod);
+        assert!(desc.slice_descs[1].pod);
+    }
+
+    #[test]
+    fn kernel_slice_array() {
+        let error =
+            syn::parse_str::<KernelItem>("fn foo(#[global] xs: [Slice<f32>]) {}").unwrap_err();
+        assert!(error.to_string().starts_with("arrays of slices"));
+        syn::parse_str::<KernelItem>("fn foo(#[global] x: Slice<f32>) {}").unwrap();
+    }
+
+    #[test]
+    fn kernel_slice64() {
+        let meta = syn::parse_str::<KernelItem>(
+            "fn foo(#[global] x: Slice64<f32>, #[global] y: UnsafeSlice<f32>) {}",
+        )
+        .unwrap()
+        .meta()
+        .unwrap();
+        let desc = meta.desc().unwrap();
+        assert!(desc.slice_descs[0].index64);
+        assert!(!desc.slice_descs[1].index64);
+        let fields = desc.push_const_fields(None).to_token_stream().to_string();
+        assert_eq!(
+            fields,
+            "__krnl_offset_x : u64 , __krnl_len_x : u64 , __krnl_offset_y : u32 , __krnl_len_y : u32"
+        );
+    }
+
+    #[test]
+    fn kernel_grid_stride() {
+        let grid_stride = Ident::new("n", Span2::call_site());
+        let meta = syn::parse_str::<KernelItem>("fn foo(n: u32, #[global] y: UnsafeSlice<f32>) {}")
+            .unwrap()
+            .meta()
+            .unwrap();
+        meta.validate_grid_stride(&grid_stride).unwrap();
+        let meta = syn::parse_str::<KernelItem>("fn foo(n: i32, #[global] y: UnsafeSlice<f32>) {}")
+            .unwrap()
+            .meta()
+            .unwrap();
+        assert!(meta.validate_grid_stride(&grid_stride).is_err());
+        let meta = syn::parse_str::<KernelItem>("fn foo(n: u32, #[item] y: &mut f32) {}")
+            .unwrap()
+            .meta()
+            .unwrap();
+        assert!(meta.validate_grid_stride(&grid_stride).is_err());
+    }
 }
@@ -42,18 +42,36 @@ struct Cli {
     /// Check mode
     #[arg(long = "check")]
     check: bool,
+    /// Write normalized SPIR-V disassembly of each kernel to DIR, relative to the package, for
+    /// snapshot tests. In check mode, compares with the existing snapshots.
+    #[arg(long = "snapshot", value_name = "DIR")]
+    snapshot: Option<PathBuf>,
     /// Enable DebugPrintf
     #[arg(long = "debug-printf")]
     debug_printf: bool,
+    /// Instrument kernels with execution counters per basic block, see `krnl::kernel::coverage`
+    #[arg(long = "coverage")]
+    coverage: bool,
     /// Use verbose output
     #[arg(short = 'v', long = "verbose")]
     verbose: bool,
 }
 
+/// Options for compiling kernels, noted in the cache.
+#[derive(Clone, Copy, Debug)]
+struct CompileOptions {
+    debug_printf: bool,
+    coverage: bool,
+}
+
 fn main() -> Result<()> {
     let cli = Cli::parse();
     let metadata = cli.manifest.metadata().exec()?;
     let (selected, _) = cli.workspace.partition_packages(&metadata);
+    let options = CompileOptions {
+        debug_printf: cli.debug_printf,
+        coverage: cli.coverage,
+    };
     let target_dir = cli
         .target_dir
         .as_ref()
@@ -61,7 +79,8 @@ fn main() -> Result<()> {
         .unwrap_or(metadata.target_directory.as_str().into());
     for package in selected.iter().copied() {
         let krnlc_metadata = KrnlcMetadata::new(&metadata, package)?;
-        let module_sources = cargo_expand(package, &target_dir, &krnlc_metadata, cli.verbose)?;
+        let (module_sources, module_variants) =
+            cargo_expand(package, &target_dir, &krnlc_metadata, cli.verbose)?;
         if module_sources.is_empty() {
             continue;
         }
@@ -70,10 +89,14 @@ fn main() -> Result<()> {
             &target_dir,
             &krnlc_metadata.dependencies,
             module_sources,
-            cli.debug_printf,
+            module_variants,
+            options,
             cli.verbose,
         )?;
-        cache(package, modules, cli.check, cli.debug_printf)?;
+        if let Some(snapshot_dir) = cli.snapshot.as_ref() {
+            snapshot(package, &modules, snapshot_dir, cli.check)?;
+        }
+        cache(package, modules, cli.check, options)?;
     }
     Ok(())
 }
@@ -83,7 +106,7 @@ fn cargo_expand(
     target_dir: &str,
     krnlc_metadata: &KrnlcMetadata,
     verbose: bool,
-) -> Result<FxHashMap<String, String>> {
+) -> Result<(FxHashMap<String, String>, ModuleVariants)> {
     use std::env::var;
     let mut command = Command::new("cargo");
     if let Ok("stable" | "beta") | Err(_) = var("RUSTUP_TOOLCHAIN").as_deref() {
@@ -120,15 +143,17 @@ fn cargo_expand(
     let expanded = std::str::from_utf8(&output.stdout)?;
     let file: syn::File = syn::parse_str(expanded)?;
     let mut modules = FxHashMap::default();
+    let mut variants = FxHashMap::default();
     let mut result = Ok(());
     let mut visitor = ModuleVisitor {
         path: String::new(),
         modules: &mut modules,
+        variants: &mut variants,
         result: &mut result,
     };
     visitor.visit_file(&file);
     result?;
-    Ok(modules)
+    Ok((modules, variants))
 }
 
 // Should match krnl_macros
@@ -248,7 +273,8 @@ impl KrnlcMetadata {
                             == "krnl-core"
                         {
                             has_krnl_core = true;
-                            (krnl_core_source.clone(), true, Vec::new())
+                            // std is not available on spirv
+                            (krnl_core_source.clone(), false, Vec::new())
                         } else if let Some(dependency) = package
                             .dependencies
                             .iter()
@@ -369,7 +395,7 @@ impl KrnlcMetadata {
         if !has_krnl_core {
             writeln!(
                 &mut dependencies,
-                "\"krnl-core\" = {{ {krnl_core_source} }}"
+                "\"krnl-core\" = {{ {krnl_core_source}, default-features = false }}"
             )
             .unwrap();
         }
@@ -381,12 +407,29 @@ impl KrnlcMetadata {
     }
 }
 
+// Feature sets to compile each module with, see `#[krnl(variants(..))]`.
+type ModuleVariants = FxHashMap<String, Vec<Features>>;
+
 struct ModuleVisitor<'a> {
     path: String,
     modules: &'a mut FxHashMap<String, String>,
+    variants: &'a mut ModuleVariants,
     result: &'a mut Result<()>,
 }
 
+fn lit_str_const(item: &Item, ident: &str) -> Option<String> {
+    if let Item::Const(item_const) = item {
+        if item_const.ident == ident {
+            if let Expr::Lit(expr_lit) = item_const.expr.as_ref() {
+                if let Lit::Str(lit_str) = &expr_lit.lit {
+                    return Some(lit_str.value());
+                }
+            }
+        }
+    }
+    None
+}
+
 impl<'a, 'ast> Visit<'ast> for ModuleVisitor<'a> {
     fn visit_item_mod(&mut self, i: &'ast ItemMod) {
         if self.result.is_err() {
@@ -397,15 +440,31 @@ impl<'a, 'ast> Visit<'ast> for ModuleVisitor<'a> {
             && i.vis == Visibility::Inherited
         {
             if let Some((_, items)) = i.content.as_ref() {
-                if let [Item::Const(item_const)] = items.as_slice() {
-                    if item_const.ident == "__krnl_module_source" {
-                        if let Expr::Lit(expr_lit) = item_const.expr.as_ref() {
-                            if let Lit::Str(lit_str) = &expr_lit.lit {
-                                self.modules.insert(self.path.clone(), lit_str.value());
-                                return;
+                let (source, variants) = match items.as_slice() {
+                    [source] => (lit_str_const(source, "__krnl_module_source"), None),
+                    [source, variants] => (
+                        lit_str_const(source, "__krnl_module_source"),
+                        lit_str_const(variants, "__krnl_module_variants"),
+                    ),
+                    _ => (None, None),
+                };
+                if let Some(source) = source {
+                    if let Some(variants) = variants {
+                        let variants = variants
+                            .split(';')
+                            .map(Features::from_names)
+                            .collect::<Result<_>>();
+                        match variants {
+                            Ok(variants) => {
+                                self.variants.insert(self.path.clone(), variants);
+                            }
+                            Err(e) => {
+                                *self.result = Err(e.context(self.path.clone()));
                             }
                         }
                     }
+                    self.modules.insert(self.path.clone(), source);
+                    return;
                 }
             }
         }
@@ -417,6 +476,7 @@ impl<'a, 'ast> Visit<'ast> for ModuleVisitor<'a> {
         let mut visitor = ModuleVisitor {
             path,
             modules: self.modules,
+            variants: self.variants,
             result: self.result,
         };
         syn::visit::visit_item_mod(&mut visitor, i);
@@ -427,11 +487,15 @@ fn cache(
     package: &Package,
     kernels: Vec<KernelDesc>,
     check: bool,
-    debug_printf: bool,
+    options: CompileOptions,
 ) -> Result<()> {
     use flate2::{write::GzEncoder, Compression};
     use zero85::ToZ85;
 
+    let CompileOptions {
+        debug_printf,
+        coverage,
+    } = options;
     let version = env!("CARGO_PKG_VERSION");
     let cache = KrnlcCache {
         version: version.to_string(),
@@ -440,11 +504,13 @@ fn cache(
     let mut bytes = Vec::new();
     let encoder = GzEncoder::new(&mut bytes, Compression::best());
     bincode2::serialize_into(encoder, &cache)?;
-    let info = if debug_printf {
-        "/* debug-printf */\n"
-    } else {
-        ""
-    };
+    let info = [
+        (debug_printf, "/* debug-printf */\n"),
+        (coverage, "/* coverage */\n"),
+    ]
+    .into_iter()
+    .filter_map(|(enabled, info)| enabled.then_some(info))
+    .collect::<String>();
     let prefix = format!("{info}__krnl_cache!({version:?}, \"\n");
     let suffix = "\");";
     let mut chunks = bytes.chunks_exact(800);
@@ -484,18 +550,100 @@ fn cache(
     Ok(())
 }
 
+// Disassembles `spirv`, without debug info that depends on paths or the toolchain.
+fn normalized_disassembly(kernel_desc: &KernelDesc) -> Result<String> {
+    use rspirv::binary::Disassemble;
+
+    let mut module =
+        rspirv::dr::load_words(&kernel_desc.spirv).map_err(|e| Error::msg(e.to_string()))?;
+    module.header = None;
+    module.debug_string_source.clear();
+    module.debug_module_processed.clear();
+    let features: Vec<_> = kernel_desc.features.names().collect();
+    let mut output = format!(
+        "; {}\n; features: [{}]\n",
+        kernel_desc.name,
+        features.join(", ")
+    );
+    for line in module.disassemble().lines() {
+        let op = line
+            .split_once(" = ")
+            .map_or(line, |x| x.1)
+            .split_whitespace()
+            .next()
+            .unwrap_or_default();
+        if !matches!(op, "OpLine" | "OpNoLine") {
+            output.push_str(line);
+            output.push('\n');
+        }
+    }
+    Ok(output)
+}
+
+fn snapshot(
+    package: &Package,
+    kernels: &[KernelDesc],
+    snapshot_dir: &Path,
+    check: bool,
+) -> Result<()> {
+    let manifest_dir = package.manifest_path.parent().unwrap();
+    let snapshot_dir = manifest_dir.as_std_path().join(snapshot_dir);
+    let mut mismatches = Vec::new();
+    for kernel_desc in kernels {
+        let mut path = snapshot_dir.join(kernel_desc.name.replace("::", "/"));
+        // Variants have the same name.
+        if kernels
+            .iter()
+            .filter(|x| x.name == kernel_desc.name)
+            .count()
+            > 1
+        {
+            let features: Vec<_> = kernel_desc.features.names().collect();
+            let features = if features.is_empty() {
+                "none".to_string()
+            } else {
+                features.join("+")
+            };
+            let file_name = format!("{}-{features}", path.file_name().unwrap().to_string_lossy());
+            path.set_file_name(file_name);
+        }
+        let path = path.with_extension("spvasm");
+        let disassembly = normalized_disassembly(kernel_desc)?;
+        if check {
+            if std::fs::read_to_string(&path).ok().as_deref() != Some(disassembly.as_str()) {
+                let new_path = path.with_extension("spvasm.new");
+                std::fs::create_dir_all(new_path.parent().unwrap())?;
+                std::fs::write(&new_path, disassembly.as_bytes())?;
+                mismatches.push(new_path);
+            }
+        } else {
+            std::fs::create_dir_all(path.parent().unwrap())?;
+            std::fs::write(&path, disassembly.as_bytes())?;
+        }
+    }
+    if !mismatches.is_empty() {
+        for path in mismatches.iter() {
+            eprintln!("snapshot changed: {path:?}");
+        }
+        bail!("{} snapshot check failed!", mismatches.len());
+    }
+    Ok(())
+}
+
 fn compile(
     package: &Package,
     target_dir: &str,
     dependencies: &str,
     module_sources: FxHashMap<String, String>,
-    debug_printf: bool,
+    module_variants: ModuleVariants,
+    options: CompileOptions,
     verbose: bool,
 ) -> Result<Vec<KernelDesc>> {
     use std::{
         env::consts::{DLL_PREFIX, DLL_SUFFIX},
         sync::Once,
     };
+    let debug_printf = options.debug_printf;
     let target_krnl_dir = PathBuf::from(target_dir).join("krnlc");
 
     static INIT_LIB_DIR: Once = Once::new();
@@ -602,7 +750,6 @@ crate-type = ["dylib"]
         if !src_dir.exists() {
             std::fs::create_dir(&src_dir)?;
         }
-        write_device_source(&src_dir, &module_sources)?;
     }
     if update {
         let status = Command::new("cargo")
@@ -617,66 +764,184 @@ crate-type = ["dylib"]
         }
     }
     let crate_name_ident = crate_name.replace('-', "_");
-    let kernels_dir = device_crate_dir.join("kernels");
-    std::fs::create_dir_all(&kernels_dir)?;
-    let mut builder = SpirvBuilder::new(&device_crate_dir, "spirv-unknown-vulkan1.2")
-        .spirv_metadata(SpirvMetadata::NameVariables)
-        .print_metadata(MetadataPrintout::None);
-    if debug_printf {
-        builder = builder
-            .extension("SPV_KHR_non_semantic_info")
-            .shader_panic_strategy(ShaderPanicStrategy::DebugPrintfThenExit {
-                print_inputs: true,
-                print_backtrace: true,
-            })
-            .spirv_metadata(SpirvMetadata::Full);
+    let src_dir = device_crate_dir.join("src");
+    // Modules without variants are compiled with all features.
+    let mut builds = Vec::<Build>::new();
+    let modules: FxHashSet<&str> = module_sources
+        .keys()
+        .filter(|module| !module_variants.contains_key(*module))
+        .map(String::as_str)
+        .collect();
+    if !modules.is_empty() {
+        builds.push(Build {
+            variant: None,
+            modules,
+            kernels: None,
+        });
     }
-    let capabilites = {
-        use spirv_builder::Capability::*;
-        [
-            Int8,
-            Int16,
-            Int64,
-            Float16,
-            Float64,
-            GroupNonUniform,
-            GroupNonUniformArithmetic,
-        ]
-    };
-    for cap in capabilites {
-        builder = builder.capability(cap);
+    for (module, variants) in module_variants.iter() {
+        for features in variants {
+            if let Some(build) = builds.iter_mut().find(|x| x.variant == Some(*features)) {
+                build.modules.insert(module);
+            } else {
+                builds.push(Build {
+                    variant: Some(*features),
+                    modules: std::iter::once(module.as_str()).collect(),
+                    kernels: None,
+                });
+            }
+        }
     }
-    let output = builder.build()?;
-    let spirv_path = output.module.unwrap_single();
-    let mut spirv_module = rspirv::dr::load_bytes(std::fs::read(spirv_path)?)
-        .map_err(|e| Error::msg(e.to_string()))?;
-    if debug_printf {
-        spirv_module
-            .debug_string_source
-            .retain(|inst| inst.class.opcode == rspirv::spirv::Op::String);
+    let mut kernels = Vec::new();
+    let mut index = 0;
+    while let Some(build) = builds.get(index) {
+        let variant = build.variant;
+        write_device_source(&src_dir, &module_sources, &build.modules)?;
+        let kernels_dir = if let Some(features) = variant.as_ref() {
+            let names: Vec<_> = features.names().collect();
+            device_crate_dir
+                .join("kernels")
+                .join("variants")
+                .join(names.join("+"))
+        } else {
+            device_crate_dir.join("kernels")
+        };
+        std::fs::create_dir_all(&kernels_dir)?;
+        let mut builder = SpirvBuilder::new(&device_crate_dir, "spirv-unknown-vulkan1.2")
+            .spirv_metadata(SpirvMetadata::NameVariables)
+            .print_metadata(MetadataPrintout::None);
+        if debug_printf {
+            builder = builder
+                .extension("SPV_KHR_non_semantic_info")
+                .shader_panic_strategy(ShaderPanicStrategy::DebugPrintfThenExit {
+                    print_inputs: true,
+                    print_backtrace: true,
+                })
+                .spirv_metadata(SpirvMetadata::Full);
+        }
+        let capabilites = {
+            use spirv_builder::Capability::*;
+            let features = variant.unwrap_or(Features::all());
+            [
+                (Int8, features.shader_int8),
+                (Int16, features.shader_int16),
+                (Int64, features.shader_int64),
+                (Float16, features.shader_float16),
+                (Float64, features.shader_float64),
+                (GroupNonUniform, true),
+                (GroupNonUniformVote, true),
+                (GroupNonUniformArithmetic, true),
+                (GroupNonUniformBallot, true),
+                (GroupNonUniformShuffle, true),
+            ]
+        };
+        for (cap, enabled) in capabilites {
+            if enabled {
+                builder = builder.capability(cap);
+            }
+        }
+        let output = builder.build()?;
+        let spirv_path = output.module.unwrap_single();
+        let mut spirv_module = rspirv::dr::load_bytes(std::fs::read(spirv_path)?)
+            .map_err(|e| Error::msg(e.to_string()))?;
+        if debug_printf {
+            spirv_module
+                .debug_string_source
+                .retain(|inst| inst.class.opcode == rspirv::spirv::Op::String);
+        }
+        let entry_fns: FxHashSet<u32> = spirv_module
+            .entry_points
+            .iter()
+            .map(|inst| inst.operands[1].unwrap_id_ref())
+            .collect();
+        let entry_points: Vec<_> = spirv_module
+            .entry_points
+            .iter()
+            .filter(|entry_point| {
+                let kernel_name = entry_point.operands[2].unwrap_literal_string();
+                source_module(&module_sources, kernel_name)
+                    .is_some_and(|module| build.modules.contains(module))
+                    && build
+                        .kernels
+                        .as_ref()
+                        .map_or(true, |kernels| kernels.contains(kernel_name))
+            })
+            .collect();
+        let build_kernels = entry_points
+            .par_iter()
+            .map(|entry_point| {
+                kernel_post_process(
+                    &kernels_dir,
+                    &crate_name_ident,
+                    entry_point,
+                    &spirv_module,
+                    &entry_fns,
+                    variant.unwrap_or(Features::all()),
+                    options,
+                )
+            })
+            .collect::<Result<Vec<_>>>()?;
+        if variant.is_none() {
+            // Kernels using shader_float16 are also compiled without it, storing f16 as u16 and
+            // computing as f32, for devices that don't support it.
+            let float16_kernels: FxHashSet<String> = build_kernels
+                .iter()
+                .filter(|kernel_desc| kernel_desc.features.shader_float16)
+                .map(|kernel_desc| {
+                    let kernel_name = &kernel_desc.name[crate_name_ident.len() + "::".len()..];
+                    kernel_name.to_string()
+                })
+                .collect();
+            if !float16_kernels.is_empty() {
+                let modules = float16_kernels
+                    .iter()
+                    .filter_map(|kernel_name| source_module(&module_sources, kernel_name))
+                    .collect();
+                builds.push(Build {
+                    variant: Some(Features {
+                        shader_float16: false,
+                        ..Features::all()
+                    }),
+                    modules,
+                    kernels: Some(float16_kernels),
+                });
+            }
+        }
+        kernels.extend(build_kernels);
+        index += 1;
     }
-    let entry_fns: FxHashSet<u32> = spirv_module
-        .entry_points
-        .iter()
-        .map(|inst| inst.operands[1].unwrap_id_ref())
-        .collect();
-    spirv_module
-        .entry_points
-        .par_iter()
-        .map(|entry_point| {
-            kernel_post_process(
-                &kernels_dir,
-                &crate_name_ident,
-                entry_point,
-                &spirv_module,
-                &entry_fns,
-                debug_printf,
-            )
-        })
-        .collect()
+    Ok(kernels)
+}
+
+// The module containing `kernel_name`, ie "kernels" for "kernels::foo::bar".
+fn source_module<'a>(
+    module_sources: &'a FxHashMap<String, String>,
+    kernel_name: &str,
+) -> Option<&'a str> {
+    let mut path = kernel_name;
+    while let Some((parent, _)) = path.rsplit_once("::") {
+        if let Some((module, _)) = module_sources.get_key_value(parent) {
+            return Some(module);
+        }
+        path = parent;
+    }
+    None
+}
+
+struct Build<'a> {
+    // Features of the variant, or None for all features.
+    variant: Option<Features>,
+    // Modules with entry points.
+    modules: FxHashSet<&'a str>,
+    // Only these kernels, by name within the crate.
+    kernels: Option<FxHashSet<String>>,
 }
 
-fn write_device_source(src_dir: &Path, module_sources: &FxHashMap<String, String>) -> Result<()> {
+fn write_device_source(
+    src_dir: &Path,
+    module_sources: &FxHashMap<String, String>,
+    entry_modules: &FxHashSet<&str>,
+) -> Result<()> {
     if module_sources.is_empty() {
         return Ok(());
     }
@@ -699,6 +964,7 @@ fn write_device_source(src_dir: &Path, module_sources: &FxHashMap<String, String
         module: &str,
         tree: &FxHashMap<&str, FxHashSet<&str>>,
         module_sources: &FxHashMap<String, String>,
+        entry_modules: &FxHashSet<&str>,
         files: &mut FxHashSet<PathBuf>,
     ) -> Result<()> {
         use std::fmt::Write;
@@ -736,10 +1002,27 @@ extern crate krnl_core;
                 let child_dir = dir.join(module_name);
                 std::fs::create_dir_all(&child_dir)?;
                 files.insert(child_dir.clone());
-                visit_module(&child_dir, child, tree, module_sources, files)?;
+                visit_module(
+                    &child_dir,
+                    child,
+                    tree,
+                    module_sources,
+                    entry_modules,
+                    files,
+                )?;
             }
         } else {
-            let source = pretty_fmt(&module_sources[module])?;
+            // Expands the entry points of `#[kernel]`s, if the module is part of the build.
+            let kernel_entry = if entry_modules.contains(module) {
+                "($($t:tt)*) => { $($t)* };"
+            } else {
+                "($($t:tt)*) => {};"
+            };
+            let source = format!(
+                "#[allow(unused_macros)]\nmacro_rules! __krnl_kernel_entry {{ {kernel_entry} }}\n{}",
+                module_sources[module]
+            );
+            let source = pretty_fmt(&source)?;
             let current = std::fs::read_to_string(&file_path).unwrap_or_default();
             if source != current {
                 std::fs::write(&file_path, source)?;
@@ -748,7 +1031,14 @@ extern crate krnl_core;
         }
         Ok(())
     }
-    visit_module(src_dir, "", &tree, module_sources, &mut files)?;
+    visit_module(
+        src_dir,
+        "",
+        &tree,
+        module_sources,
+        entry_modules,
+        &mut files,
+    )?;
     fn cleanup_files(dir: &Path, keep: &FxHashSet<PathBuf>) -> Result<()> {
         assert!(dir.to_string_lossy().contains(
             format!(
@@ -780,7 +1070,8 @@ fn kernel_post_process(
     entry_point: &rspirv::dr::Instruction,
     spirv_module: &rspirv::dr::Module,
     entry_fns: &FxHashSet<u32>,
-    debug_printf: bool,
+    build_features: Features,
+    options: CompileOptions,
 ) -> Result<KernelDesc> {
     use rspirv::{
         binary::Assemble,
@@ -788,6 +1079,10 @@ fn kernel_post_process(
         spirv::{BuiltIn, Decoration, Op, StorageClass},
     };
 
+    let CompileOptions {
+        debug_printf,
+        coverage,
+    } = options;
     let entry_id = entry_point.operands[1].unwrap_id_ref();
     let kernel_name = entry_point.operands[2].unwrap_literal_string();
     let execution_mode = spirv_module
@@ -1066,6 +1361,15 @@ fn kernel_post_process(
         spirv_module.entry_points.first_mut().unwrap().operands[2] =
             Operand::LiteralString("main".to_string());
         kernel_desc.name = format!("{crate_name_ident}::{kernel_name}");
+        let spirv = spirv_module.assemble();
+        let spirv = if !debug_printf {
+            spirv_opt(&spirv, SpirvOptKind::Performance)?
+        } else {
+            spirv
+        };
+        // Unused types are only removed by optimization.
+        let mut spirv_module =
+            rspirv::dr::load_words(&spirv).map_err(|e| Error::msg(e.to_string()))?;
         let mut features = Features::default();
         for inst in spirv_module.types_global_values.iter() {
             let class = inst.class;
@@ -1089,6 +1393,28 @@ fn kernel_post_process(
                 _ => (),
             }
         }
+        if kernel_desc.features.names().next().is_some() {
+            let undeclared: Vec<_> = features.difference(&kernel_desc.features).names().collect();
+            if !undeclared.is_empty() {
+                bail!(
+                    "uses {}, which is not declared in `#[kernel(features(..))]`!",
+                    undeclared.join(", ")
+                );
+            }
+        }
+        // Declared features are limited to those of the variant.
+        kernel_desc.features = kernel_desc
+            .features
+            .union(&features)
+            .intersection(&build_features);
+        // Subgroup capabilities are enabled for all kernels, only declare those that are used.
+        let ops: FxHashSet<u32> = spirv_module
+            .functions
+            .iter()
+            .flat_map(|f| f.blocks.iter().flat_map(|b| b.instructions.iter()))
+            .map(|inst| inst.class.opcode as u32)
+            .collect();
+        let uses_ops = |first: Op, last: Op| (first as u32..=last as u32).any(|op| ops.contains(&op));
         spirv_module.capabilities.retain(|inst| {
             use rspirv::spirv::Capability::*;
             match inst.operands.first().unwrap().unwrap_capability() {
@@ -1097,12 +1423,23 @@ fn kernel_post_process(
                 Int64 => features.shader_int64,
                 Float16 => features.shader_float16,
                 Float64 => features.shader_float64,
+                GroupNonUniform => uses_ops(Op::GroupNonUniformElect, Op::GroupNonUniformQuadSwap),
+                GroupNonUniformVote => uses_ops(Op::GroupNonUniformAll, Op::GroupNonUniformAllEqual),
+                GroupNonUniformBallot => {
+                    uses_ops(Op::GroupNonUniformBroadcast, Op::GroupNonUniformBallotFindMSB)
+                }
+                GroupNonUniformShuffle => {
+                    uses_ops(Op::GroupNonUniformShuffle, Op::GroupNonUniformShuffleXor)
+                }
+                GroupNonUniformArithmetic => {
+                    uses_ops(Op::GroupNonUniformIAdd, Op::GroupNonUniformLogicalXor)
+                }
                 _ => true,
             }
         });
         let spirv = spirv_module.assemble();
-        let spirv = if !debug_printf {
-            spirv_opt(&spirv, SpirvOptKind::Performance)?
+        let spirv = if coverage {
+            instrument_coverage(&spirv, kernel_desc.slice_descs.len() as u32)?
         } else {
             spirv
         };
@@ -1124,6 +1461,211 @@ fn kernel_post_process(
     Ok(kernel_desc)
 }
 
+/// Inserts an atomic increment of `__krnl_coverage[block]` at the start of each block.
+///
+/// The counters are bound after the slices, see `krnl::kernel::coverage`.
+fn instrument_coverage(spirv: &[u32], binding: u32) -> Result<Vec<u32>> {
+    use rspirv::{
+        binary::Assemble,
+        dr::{Instruction, Operand},
+        spirv::{Decoration, MemoryModel, Op, Scope, StorageClass},
+    };
+    use spirv_tools::{val::Validator, TargetEnv};
+
+    let mut module = rspirv::dr::load_words(spirv).map_err(|e| Error::msg(e.to_string()))?;
+    let blocks: u32 = module.functions.iter().map(|f| f.blocks.len() as u32).sum();
+    if blocks == 0 {
+        return Ok(spirv.to_vec());
+    }
+    let header = module.header.as_mut().unwrap();
+    let mut id_counter = header.bound;
+    let version = header.version();
+    let mut next_id = || {
+        let id = id_counter;
+        id_counter += 1;
+        id
+    };
+    let vulkan_memory_model = module.memory_model.as_ref().map_or(false, |inst| {
+        inst.operands.get(1) == Some(&Operand::MemoryModel(MemoryModel::Vulkan))
+    });
+    // Non aggregate types must be unique.
+    let uint = module
+        .types_global_values
+        .iter()
+        .find(|inst| {
+            inst.class.opcode == Op::TypeInt
+                && inst.operands == [Operand::LiteralInt32(32), Operand::LiteralInt32(0)]
+        })
+        .and_then(|inst| inst.result_id);
+    let uint = if let Some(uint) = uint {
+        uint
+    } else {
+        let uint = next_id();
+        module.types_global_values.insert(
+            0,
+            Instruction::new(
+                Op::TypeInt,
+                None,
+                Some(uint),
+                vec![Operand::LiteralInt32(32), Operand::LiteralInt32(0)],
+            ),
+        );
+        uint
+    };
+    let mut constant = |value: u32| {
+        let id = next_id();
+        module.types_global_values.push(Instruction::new(
+            Op::Constant,
+            Some(uint),
+            Some(id),
+            vec![Operand::LiteralInt32(value)],
+        ));
+        id
+    };
+    let len = constant(blocks);
+    let zero = constant(0);
+    let scope = constant(if vulkan_memory_model {
+        Scope::QueueFamily
+    } else {
+        Scope::Device
+    } as u32);
+    let block_ids: Vec<u32> = (0..blocks).map(&mut constant).collect();
+    let array = next_id();
+    let block_struct = next_id();
+    let struct_ptr = next_id();
+    let uint_ptr = next_id();
+    let var = next_id();
+    module.types_global_values.extend([
+        Instruction::new(
+            Op::TypeArray,
+            None,
+            Some(array),
+            vec![Operand::IdRef(uint), Operand::IdRef(len)],
+        ),
+        Instruction::new(
+            Op::TypeStruct,
+            None,
+            Some(block_struct),
+            vec![Operand::IdRef(array)],
+        ),
+        Instruction::new(
+            Op::TypePointer,
+            None,
+            Some(struct_ptr),
+            vec![
+                Operand::StorageClass(StorageClass::StorageBuffer),
+                Operand::IdRef(block_struct),
+            ],
+        ),
+        Instruction::new(
+            Op::TypePointer,
+            None,
+            Some(uint_ptr),
+            vec![
+                Operand::StorageClass(StorageClass::StorageBuffer),
+                Operand::IdRef(uint),
+            ],
+        ),
+        Instruction::new(
+            Op::Variable,
+            Some(struct_ptr),
+            Some(var),
+            vec![Operand::StorageClass(StorageClass::StorageBuffer)],
+        ),
+    ]);
+    let decorate = |id: u32, decoration: Decoration, operands: &[Operand]| {
+        Instruction::new(
+            Op::Decorate,
+            None,
+            None,
+            [Operand::IdRef(id), Operand::Decoration(decoration)]
+                .into_iter()
+                .chain(operands.iter().cloned())
+                .collect(),
+        )
+    };
+    module.annotations.extend([
+        decorate(array, Decoration::ArrayStride, &[Operand::LiteralInt32(4)]),
+        decorate(block_struct, Decoration::Block, &[]),
+        Instruction::new(
+            Op::MemberDecorate,
+            None,
+            None,
+            vec![
+                Operand::IdRef(block_struct),
+                Operand::LiteralInt32(0),
+                Operand::Decoration(Decoration::Offset),
+                Operand::LiteralInt32(0),
+            ],
+        ),
+        decorate(var, Decoration::DescriptorSet, &[Operand::LiteralInt32(0)]),
+        decorate(var, Decoration::Binding, &[Operand::LiteralInt32(binding)]),
+    ]);
+    module.debug_names.push(Instruction::new(
+        Op::Name,
+        None,
+        None,
+        vec![
+            Operand::IdRef(var),
+            Operand::LiteralString("__krnl_coverage".to_string()),
+        ],
+    ));
+    // Since SPIR-V 1.4 the interface includes all global variables.
+    if version >= (1, 4) {
+        for entry_point in module.entry_points.iter_mut() {
+            entry_point.operands.push(Operand::IdRef(var));
+        }
+    }
+    let blocks = module
+        .functions
+        .iter_mut()
+        .flat_map(|f| f.blocks.iter_mut());
+    for (block, block_id) in blocks.zip(block_ids) {
+        let index = block
+            .instructions
+            .iter()
+            .position(|inst| {
+                !matches!(
+                    inst.class.opcode,
+                    Op::Phi | Op::Variable | Op::Line | Op::NoLine
+                )
+            })
+            .unwrap_or(block.instructions.len());
+        let ptr = next_id();
+        let count = next_id();
+        block.instructions.splice(
+            index..index,
+            [
+                Instruction::new(
+                    Op::AccessChain,
+                    Some(uint_ptr),
+                    Some(ptr),
+                    vec![
+                        Operand::IdRef(var),
+                        Operand::IdRef(zero),
+                        Operand::IdRef(block_id),
+                    ],
+                ),
+                Instruction::new(
+                    Op::AtomicIIncrement,
+                    Some(uint),
+                    Some(count),
+                    vec![
+                        Operand::IdRef(ptr),
+                        Operand::IdScope(scope),
+                        Operand::IdMemorySemantics(zero),
+                    ],
+                ),
+            ],
+        );
+    }
+    module.header.as_mut().unwrap().bound = id_counter;
+    let spirv = module.assemble();
+    let validator = spirv_tools::val::create(Some(TargetEnv::Vulkan_1_2));
+    validator.validate(&spirv, None)?;
+    Ok(spirv)
+}
+
 #[derive(Clone, Copy, Debug)]
 enum SpirvOptKind {
     DeadCodeElimination,
@@ -1553,7 +2095,7 @@ struct KernelDesc {
     push_descs: Vec<PushDesc>,
 }
 
-#[derive(Default, Debug, Serialize, Deserialize)]
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Features {
     shader_int8: bool,
     shader_int16: bool,
@@ -1562,6 +2104,71 @@ pub struct Features {
     shader_float64: bool,
 }
 
+impl Features {
+    fn all() -> Self {
+        Self {
+            shader_int8: true,
+            shader_int16: true,
+            shader_int64: true,
+            shader_float16: true,
+            shader_float64: true,
+        }
+    }
+    // Parses features encoded by krnl_macros, ie "shader_int8,shader_int64".
+    fn from_names(names: &str) -> Result<Self> {
+        let mut features = Self::default();
+        for name in names.split(',').filter(|x| !x.is_empty()) {
+            match name {
+                "shader_int8" => features.shader_int8 = true,
+                "shader_int16" => features.shader_int16 = true,
+                "shader_int64" => features.shader_int64 = true,
+                "shader_float16" => features.shader_float16 = true,
+                "shader_float64" => features.shader_float64 = true,
+                _ => bail!("unknown feature `{name}`!"),
+            }
+        }
+        Ok(features)
+    }
+    fn union(&self, other: &Self) -> Self {
+        Self {
+            shader_int8: self.shader_int8 || other.shader_int8,
+            shader_int16: self.shader_int16 || other.shader_int16,
+            shader_int64: self.shader_int64 || other.shader_int64,
+            shader_float16: self.shader_float16 || other.shader_float16,
+            shader_float64: self.shader_float64 || other.shader_float64,
+        }
+    }
+    fn intersection(&self, other: &Self) -> Self {
+        Self {
+            shader_int8: self.shader_int8 && other.shader_int8,
+            shader_int16: self.shader_int16 && other.shader_int16,
+            shader_int64: self.shader_int64 && other.shader_int64,
+            shader_float16: self.shader_float16 && other.shader_float16,
+            shader_float64: self.shader_float64 && other.shader_float64,
+        }
+    }
+    fn difference(&self, other: &Self) -> Self {
+        Self {
+            shader_int8: self.shader_int8 && !other.shader_int8,
+            shader_int16: self.shader_int16 && !other.shader_int16,
+            shader_int64: self.shader_int64 && !other.shader_int64,
+            shader_float16: self.shader_float16 && !other.shader_float16,
+            shader_float64: self.shader_float64 && !other.shader_float64,
+        }
+    }
+    fn names(&self) -> impl Iterator<Item = &'static str> {
+        [
+            (self.shader_int8, "shader_int8"),
+            (self.shader_int16, "shader_int16"),
+            (self.shader_int64, "shader_int64"),
+            (self.shader_float16, "shader_float16"),
+            (self.shader_float64, "shader_float64"),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, name)| enabled.then_some(name))
+    }
+}
+
 #[derive(Serialize, Deserialize, Debug)]
 struct SpecDesc {
     name: String,
@@ -1574,6 +2181,8 @@ struct SliceDesc {
     scalar_type: ScalarType,
     mutable: bool,
     item: bool,
+    index64: bool,
+    pod: bool,
 }
 
 /*
@@ -0,0 +1,297 @@
+/*!
+
+Utilities for benchmarking kernels.
+
+[`time_dispatch`](crate::bench::time_dispatch) times a closure that enqueues work on a device, and is intended to be used
+with [criterion](https://docs.rs/criterion)'s `iter_custom`:
+```no_run
+# use krnl::{anyhow::Result, buffer::Buffer, device::Device};
+# fn main() -> Result<()> {
+let device = Device::builder().build()?;
+let mut y = Buffer::<f32>::zeros(device.clone(), 1_000_000)?;
+let elapsed = krnl::bench::time_dispatch(&device, 100, || y.fill(1f32))?;
+println!("{:?}", elapsed / 100);
+# Ok(())
+# }
+```
+```ignore
+b.iter_custom(|iters| krnl::bench::time_dispatch(&device, iters, || saxpy.dispatch()).unwrap());
+```
+The closure should not wait on the device, so that dispatches are batched into fewer submissions,
+as they would be in an application.
+
+# Timing
+On devices that support timestamp queries, the elapsed time is measured on the device, from the
+start to the end of each submission. This excludes the time spent recording and submitting work on
+the host, as well as time the device is idle. Otherwise, the wall clock time is measured, including
+waiting for the device to finish.
+
+Device timing includes any other work submitted to the device while timing, for example from
+another thread.
+
+# Autotuning
+[`Autotune`](crate::bench::Autotune) selects the fastest of several candidates, like threads per group or
+algorithm variants, by timing each with [`time_dispatch`](crate::bench::time_dispatch). Results are saved to a file per
+device and driver version, so that tuning is only performed once per machine:
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# use krnl::macros::kernel;
+# #[kernel] pub fn fill(#[item] y: &mut f32) { *y = 1f32; }
+# }
+# use kernels::fill;
+# use krnl::{anyhow::Result, bench::Autotune, buffer::Buffer, device::Device};
+# fn main() -> Result<()> {
+let device = Device::builder().build()?;
+let mut y = Buffer::<f32>::zeros(device.clone(), 1_000_000)?;
+let mut autotune = Autotune::load(device.clone(), "target/krnl-autotune")?;
+let threads = *autotune.tune("fill/threads", &[64, 128, 256], |&threads| {
+    fill::builder()?
+        .with_threads(threads)
+        .build(device.clone())?
+        .dispatch(y.as_slice_mut())
+})?;
+# Ok(())
+# }
+```
+*/
+
+use crate::device::{Device, DeviceInner};
+use anyhow::{bail, Result};
+use std::{
+    collections::BTreeMap,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+/// Times `iters` calls to `f` on `device`.
+///
+/// `f` is called once to warm up, for example to compile kernels and allocate buffers,
+/// before the device is waited on. `f` is then called `iters` times without waiting, and the
+/// elapsed time is returned once the device is finished. See [Timing](self#timing).
+///
+/// **Errors**
+/// - Errors returned by `f`.
+/// - [`DeviceLost`](crate::device::error::DeviceLost): The device was lost.
+/// - `device` is the host on wasm, which doesn't provide a clock.
+pub fn time_dispatch<F>(device: &Device, iters: u64, mut f: F) -> Result<Duration>
+where
+    F: FnMut() -> Result<()>,
+{
+    f()?;
+    match device.inner() {
+        DeviceInner::Host => {
+            if cfg!(target_arch = "wasm32") {
+                bail!("Timing the host is not supported on wasm!");
+            }
+            let start = Instant::now();
+            for _ in 0..iters {
+                f()?;
+            }
+            Ok(start.elapsed())
+        }
+        #[cfg(feature = "device")]
+        DeviceInner::Device(raw) => {
+            let Some(start) = raw.gpu_time()? else {
+                let start = Instant::now();
+                for _ in 0..iters {
+                    f()?;
+                }
+                raw.wait()?;
+                return Ok(start.elapsed());
+            };
+            for _ in 0..iters {
+                f()?;
+            }
+            let end = raw.gpu_time()?.unwrap_or(start);
+            Ok(end.saturating_sub(start))
+        }
+    }
+}
+
+/** Persisted autotuning results for a device.
+
+Results are keyed by a name provided by the caller, and store the index of the fastest candidate.
+If the candidates change in number, they are tuned again. See [Autotuning](self#autotuning).
+*/
+#[derive(Debug)]
+pub struct Autotune {
+    device: Device,
+    path: Option<PathBuf>,
+    iters: u64,
+    results: BTreeMap<String, (usize, usize)>,
+}
+
+impl Autotune {
+    /// Creates an autotuner that doesn't save results.
+    pub fn new(device: Device) -> Self {
+        Self {
+            device,
+            path: None,
+            iters: 10,
+            results: BTreeMap::new(),
+        }
+    }
+    /** Loads results for `device` from `dir`.
+
+    The file is named with the device UUID and driver version, and is created when results are
+    first saved.
+
+    **Errors**
+    - The file could not be read or parsed.
+    */
+    pub fn load(device: Device, dir: impl AsRef<Path>) -> Result<Self> {
+        let file_name = match device.inner() {
+            DeviceInner::Host => "host".to_string(),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.info().cache_key(),
+        };
+        let path = dir.as_ref().join(file_name);
+        let mut results = BTreeMap::new();
+        match std::fs::read_to_string(&path) {
+            Ok(text) => {
+                for line in text.lines() {
+                    let mut parts = line.splitn(3, ' ');
+                    let (Some(index), Some(candidates), Some(key)) =
+                        (parts.next(), parts.next(), parts.next())
+                    else {
+                        bail!("Failed to parse autotune results {path:?}, found {line:?}!");
+                    };
+                    results.insert(key.to_string(), (index.parse()?, candidates.parse()?));
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => (),
+            Err(e) => return Err(e.into()),
+        }
+        Ok(Self {
+            path: Some(path),
+            results,
+            ..Self::new(device)
+        })
+    }
+    /// Iterations to time each candidate, defaults to 10.
+    pub fn with_iters(self, iters: u64) -> Self {
+        Self { iters, ..self }
+    }
+    /// The device.
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+    /// The file results are saved to.
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+    /// The index of the fastest candidate for `key`, if tuned.
+    pub fn get(&self, key: &str) -> Option<usize> {
+        self.results.get(key).map(|(index, _)| *index)
+    }
+    /** Returns the fastest of `candidates` for `key`.
+
+    If not already tuned, each candidate is timed with [`time_dispatch`], calling `f` with the
+    candidate, and the result is saved.
+
+    **Errors**
+    - `candidates` is empty.
+    - `key` contains a newline.
+    - Errors returned by `f`.
+    - The results could not be saved.
+    - See [`time_dispatch`].
+    */
+    pub fn tune<'a, T, F>(&mut self, key: &str, candidates: &'a [T], mut f: F) -> Result<&'a T>
+    where
+        F: FnMut(&T) -> Result<()>,
+    {
+        if candidates.is_empty() {
+            bail!("Autotune `{key}` has no candidates!");
+        }
+        if key.contains('\n') {
+            bail!("Autotune key {key:?} contains a newline!");
+        }
+        if let Some((index, len)) = self.results.get(key) {
+            if *len == candidates.len() {
+                return Ok(&candidates[*index]);
+            }
+        }
+        let mut best: Option<(usize, Duration)> = None;
+        for (index, candidate) in candidates.iter().enumerate() {
+            let elapsed = time_dispatch(&self.device, self.iters, || f(candidate))?;
+            if best.map_or(true, |(_, best)| elapsed < best) {
+                best.replace((index, elapsed));
+            }
+        }
+        let (index, _) = best.unwrap();
+        self.results
+            .insert(key.to_string(), (index, candidates.len()));
+        self.save()?;
+        Ok(&candidates[index])
+    }
+    fn save(&self) -> Result<()> {
+        let Some(path) = self.path.as_ref() else {
+            return Ok(());
+        };
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let text: String = self
+            .results
+            .iter()
+            .map(|(key, (index, len))| format!("{index} {len} {key}\n"))
+            .collect();
+        // Write to a temporary file and rename, so that other processes don't see partial results.
+        let tmp = path.with_extension(format!("tmp{}", std::process::id()));
+        std::fs::write(&tmp, text)?;
+        std::fs::rename(tmp, path)?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::buffer::Buffer;
+
+    #[test]
+    fn time_dispatch_host() {
+        let device = Device::host();
+        let mut y = Buffer::<u32>::zeros(device.clone(), 16).unwrap();
+        let mut calls = 0;
+        time_dispatch(&device, 4, || {
+            calls += 1;
+            y.fill(1)
+        })
+        .unwrap();
+        assert_eq!(calls, 5);
+        assert_eq!(y.into_vec().unwrap(), [1; 16]);
+    }
+    #[test]
+    fn autotune_host() {
+        let dir = std::env::temp_dir().join(format!("krnl-autotune-{}", std::process::id()));
+        let candidates = [4u64, 0, 2];
+        let mut autotune = Autotune::load(Device::host(), &dir).unwrap().with_iters(1);
+        let best = autotune
+            .tune("sleep", &candidates, |&millis| {
+                std::thread::sleep(Duration::from_millis(millis));
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(*best, 0);
+        assert_eq!(autotune.get("sleep"), Some(1));
+        assert!(autotune.tune("sleep\n", &candidates, |_| Ok(())).is_err());
+        assert!(autotune.tune::<u64, _>("empty", &[], |_| Ok(())).is_err());
+        let mut autotune = Autotune::load(Device::host(), &dir).unwrap();
+        assert_eq!(autotune.path(), Some(dir.join("host").as_path()));
+        let best = autotune
+            .tune("sleep", &candidates, |_| panic!("already tuned"))
+            .unwrap();
+        assert_eq!(*best, 0);
+        let mut calls = 0;
+        autotune
+            .with_iters(1)
+            .tune("sleep", &candidates[..2], |_| {
+                calls += 1;
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(calls, 4);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -5,6 +5,10 @@ store data inline, essentially as a [`Vec`] or [slice](https://doc.rust-lang.org
 
 [`BufferBase`](crate::buffer::BufferBase) is a generic buffer. [`ScalarBufferBase`](crate::buffer::ScalarBufferBase) is a dynamically typed buffer.
 
+Elements are [scalars](crate::scalar::Scalar), or [structs](crate::kernel#structs) of scalars that implement [`Pod`](crate::scalar::Pod).
+
+[`ChunkedMap`](crate::buffer::ChunkedMap) streams buffers larger than device memory through a device in chunks.
+
 # Example
 ```
 use krnl::{anyhow::Result, device::Device, buffer::{Buffer, Slice, SliceMut}};
@@ -41,7 +45,7 @@ fn main() -> Result<()> {
 use crate::device::error::DeviceLost;
 use crate::{
     device::{Device, DeviceInner},
-    scalar::{Scalar, ScalarElem, ScalarType},
+    scalar::{Pod, Scalar, ScalarElem, ScalarType},
 };
 use anyhow::{bail, Result};
 use bytemuck::PodCastError;
@@ -52,8 +56,11 @@ use krnl_macros::module;
 use paste::paste;
 #[cfg(feature = "serde")]
 use serde::{de::Deserializer, ser::Serializer, Deserialize, Serialize};
+#[cfg(feature = "mmap")]
+use std::path::Path;
 use std::{
     fmt::{self, Debug},
+    future::Future,
     marker::PhantomData,
     mem::{forget, size_of},
     ops::{Bound, RangeBounds},
@@ -83,7 +90,7 @@ pub mod error {
         }
     }
 
-    /// Device buffers are limited to [`i32::MAX`] (2147483647) bytes .
+    /// Device buffers are limited to [`isize::MAX`] bytes.
     #[derive(Clone, Copy, Debug, thiserror::Error)]
     pub struct DeviceBufferTooLarge {
         #[cfg(feature = "device")]
@@ -128,14 +135,22 @@ impl RawSlice {
             RawSliceInner::Device(buffer) => buffer.device().into(),
         }
     }
-    /*#[cfg(feature = "device")]
     fn offset(&self) -> usize {
         match &self.inner {
             RawSliceInner::Host(_) => 0,
             #[cfg(feature = "device")]
             RawSliceInner::Device(buffer) => buffer.offset(),
         }
-    }*/
+    }
+    // The alignment of the start in bytes, up to `MAX_ALIGNMENT`.
+    fn alignment(&self) -> usize {
+        let index = match &self.inner {
+            RawSliceInner::Host(raw) => raw.ptr as usize,
+            #[cfg(feature = "device")]
+            RawSliceInner::Device(buffer) => buffer.offset(),
+        };
+        1 << index.trailing_zeros().min(MAX_ALIGNMENT.trailing_zeros())
+    }
     fn len(&self) -> usize {
         match &self.inner {
             RawSliceInner::Host(raw) => raw.len,
@@ -158,8 +173,7 @@ impl RawSlice {
             Ok(self)
         }
     }
-    fn slice(mut self, range: impl RangeBounds<usize>, scalar_type: ScalarType) -> Option<Self> {
-        let width = scalar_type.size();
+    fn slice(mut self, range: impl RangeBounds<usize>, width: usize) -> Option<Self> {
         let start = match range.start_bound() {
             Bound::Included(x) => x.checked_mul(width)?,
             Bound::Excluded(x) => x.checked_mul(width)?.checked_add(width)?,
@@ -189,8 +203,16 @@ impl RawSlice {
         }
         Some(self)
     }
+    fn split_at(self, mid: usize, width: usize) -> Option<(Self, Self)> {
+        let a = self.clone().slice(..mid, width)?;
+        let b = self.slice(mid.., width)?;
+        Some((a, b))
+    }
 }
 
+// Device buffers are allocated with this alignment in bytes.
+const MAX_ALIGNMENT: usize = 256;
+
 #[derive(Clone, derive_more::Unwrap)]
 enum RawSliceInner {
     Host(RawHostSlice),
@@ -235,6 +257,15 @@ impl Drop for RawBuffer {
     }
 }
 
+mod chunked;
+pub use chunked::ChunkedMap;
+mod pool;
+pub use pool::{BufferPool, PooledBuffer};
+mod device_vec;
+pub use device_vec::DeviceVec;
+mod compressed;
+mod quant;
+
 mod sealed {
     pub trait Sealed {}
 }
@@ -257,9 +288,9 @@ macro_for!($S in [ScalarSliceRepr,ScalarSliceMutRepr, ScalarCowBufferRepr] {
 This trait can not be implemented outside the crate. */
 pub trait ScalarData: Sealed {
     #[doc(hidden)]
-    fn as_scalar_slice(&self) -> ScalarSliceRepr;
+    fn as_scalar_slice(&self) -> ScalarSliceRepr<'_>;
     #[doc(hidden)]
-    fn get_scalar_slice_mut(&mut self) -> Option<ScalarSliceMutRepr> {
+    fn get_scalar_slice_mut(&mut self) -> Option<ScalarSliceMutRepr<'_>> {
         None
     }
     #[doc(hidden)]
@@ -307,7 +338,7 @@ pub trait ScalarData: Sealed {
 /// Marker trait for mutable scalar buffers.
 pub trait ScalarDataMut: ScalarData {
     #[doc(hidden)]
-    fn as_scalar_slice_mut(&mut self) -> ScalarSliceMutRepr;
+    fn as_scalar_slice_mut(&mut self) -> ScalarSliceMutRepr<'_>;
 }
 
 /// Marker trait for owned scalar buffers.
@@ -317,7 +348,7 @@ pub trait ScalarDataOwned: ScalarData {
     where
         Self: Sized;
     #[doc(hidden)]
-    fn make_scalar_slice_mut(&mut self) -> Result<ScalarSliceMutRepr>;
+    fn make_scalar_slice_mut(&mut self) -> Result<ScalarSliceMutRepr<'_>>;
 }
 
 /// [`ScalarBuffer`] representation.
@@ -326,24 +357,34 @@ pub struct ScalarBufferRepr {
     scalar_type: ScalarType,
 }
 
-impl<T: Scalar> From<BufferRepr<T>> for ScalarBufferRepr {
+impl<T: Pod> From<BufferRepr<T>> for ScalarBufferRepr {
     fn from(buffer: BufferRepr<T>) -> Self {
         Self {
             raw: buffer.raw,
-            scalar_type: T::SCALAR_TYPE,
+            scalar_type: T::STORAGE_TYPE,
         }
     }
 }
 
+impl ScalarBufferRepr {
+    fn bitcast_into(self, scalar_type: ScalarType) -> Result<Self, PodCastError> {
+        self.raw.slice.clone().bitcast(scalar_type)?;
+        Ok(Self {
+            raw: self.raw,
+            scalar_type,
+        })
+    }
+}
+
 impl ScalarData for ScalarBufferRepr {
-    fn as_scalar_slice(&self) -> ScalarSliceRepr {
+    fn as_scalar_slice(&self) -> ScalarSliceRepr<'_> {
         ScalarSliceRepr {
             raw: self.raw.slice.clone(),
             scalar_type: self.scalar_type,
             _m: PhantomData,
         }
     }
-    fn get_scalar_slice_mut(&mut self) -> Option<ScalarSliceMutRepr> {
+    fn get_scalar_slice_mut(&mut self) -> Option<ScalarSliceMutRepr<'_>> {
         Some(self.as_scalar_slice_mut())
     }
     fn try_into_scalar_buffer(self) -> Result<Self, Self>
@@ -355,7 +396,7 @@ impl ScalarData for ScalarBufferRepr {
 }
 
 impl ScalarDataMut for ScalarBufferRepr {
-    fn as_scalar_slice_mut(&mut self) -> ScalarSliceMutRepr {
+    fn as_scalar_slice_mut(&mut self) -> ScalarSliceMutRepr<'_> {
         ScalarSliceMutRepr {
             raw: self.raw.slice.clone(),
             scalar_type: self.scalar_type,
@@ -371,7 +412,7 @@ impl ScalarDataOwned for ScalarBufferRepr {
     {
         buffer
     }
-    fn make_scalar_slice_mut(&mut self) -> Result<ScalarSliceMutRepr> {
+    fn make_scalar_slice_mut(&mut self) -> Result<ScalarSliceMutRepr<'_>> {
         Ok(self.as_scalar_slice_mut())
     }
 }
@@ -404,24 +445,28 @@ impl<'a> ScalarSliceRepr<'a> {
     }
     fn slice(self, range: impl RangeBounds<usize>) -> Option<Self> {
         Some(Self {
-            raw: self.raw.slice(range, self.scalar_type)?,
+            raw: self.raw.slice(range, self.scalar_type.size())?,
             ..self
         })
     }
+    fn split_at(self, mid: usize) -> Option<(Self, Self)> {
+        let (a, b) = self.raw.split_at(mid, self.scalar_type.size())?;
+        Some((Self { raw: a, ..self }, Self { raw: b, ..self }))
+    }
 }
 
-impl<'a, T: Scalar> From<SliceRepr<'a, T>> for ScalarSliceRepr<'a> {
+impl<'a, T: Pod> From<SliceRepr<'a, T>> for ScalarSliceRepr<'a> {
     fn from(slice: SliceRepr<'a, T>) -> Self {
         Self {
             raw: slice.raw,
-            scalar_type: T::SCALAR_TYPE,
+            scalar_type: T::STORAGE_TYPE,
             _m: PhantomData,
         }
     }
 }
 
 impl ScalarData for ScalarSliceRepr<'_> {
-    fn as_scalar_slice(&self) -> ScalarSliceRepr {
+    fn as_scalar_slice(&self) -> ScalarSliceRepr<'_> {
         ScalarSliceRepr {
             raw: self.raw.clone(),
             scalar_type: self.scalar_type,
@@ -541,6 +586,8 @@ impl<'de> Deserialize<'de> for ScalarBufferRepr {
                         break;
                     }
                 }
+                // The last item is padded to 8 bytes.
+                data.truncate(len);
                 if data.len() != len {
                     return Err(A::Error::invalid_length(
                         data.len(),
@@ -563,8 +610,11 @@ impl<'de> Deserialize<'de> for ScalarBufferRepr {
                 A: SeqAccess<'de>,
             {
                 match self.scalar_type.size() {
+                    1 => self.parse::<u8, _>(seq),
+                    2 => self.parse::<u16, _>(seq),
                     4 => self.parse::<u32, _>(seq),
-                    _ => todo!(),
+                    8 => self.parse::<u64, _>(seq),
+                    _ => unreachable!(),
                 }
             }
         }
@@ -605,10 +655,14 @@ impl<'a> ScalarSliceMutRepr<'a> {
     }
     fn slice(self, range: impl RangeBounds<usize>) -> Option<Self> {
         Some(Self {
-            raw: self.raw.slice(range, self.scalar_type)?,
+            raw: self.raw.slice(range, self.scalar_type.size())?,
             ..self
         })
     }
+    fn split_at(self, mid: usize) -> Option<(Self, Self)> {
+        let (a, b) = self.raw.split_at(mid, self.scalar_type.size())?;
+        Some((Self { raw: a, ..self }, Self { raw: b, ..self }))
+    }
     fn copy_from_scalar_slice(&mut self, src: &ScalarSliceRepr) -> Result<()> {
         if self.scalar_type() != src.scalar_type() {
             bail!(
@@ -633,18 +687,18 @@ impl<'a> ScalarSliceMutRepr<'a> {
     }
 }
 
-impl<'a, T: Scalar> From<SliceMutRepr<'a, T>> for ScalarSliceMutRepr<'a> {
+impl<'a, T: Pod> From<SliceMutRepr<'a, T>> for ScalarSliceMutRepr<'a> {
     fn from(slice: SliceMutRepr<'a, T>) -> Self {
         Self {
             raw: slice.raw,
-            scalar_type: T::SCALAR_TYPE,
+            scalar_type: T::STORAGE_TYPE,
             _m: PhantomData,
         }
     }
 }
 
 impl ScalarData for ScalarSliceMutRepr<'_> {
-    fn as_scalar_slice(&self) -> ScalarSliceRepr {
+    fn as_scalar_slice(&self) -> ScalarSliceRepr<'_> {
         ScalarSliceRepr {
             raw: self.raw.clone(),
             scalar_type: self.scalar_type,
@@ -654,7 +708,7 @@ impl ScalarData for ScalarSliceMutRepr<'_> {
 }
 
 impl ScalarDataMut for ScalarSliceMutRepr<'_> {
-    fn as_scalar_slice_mut(&mut self) -> ScalarSliceMutRepr {
+    fn as_scalar_slice_mut(&mut self) -> ScalarSliceMutRepr<'_> {
         ScalarSliceMutRepr {
             raw: self.raw.clone(),
             scalar_type: self.scalar_type,
@@ -676,24 +730,24 @@ impl From<ScalarBufferRepr> for ScalarArcBufferRepr {
     }
 }
 
-impl<T: Scalar> From<ArcBufferRepr<T>> for ScalarArcBufferRepr {
+impl<T: Pod> From<ArcBufferRepr<T>> for ScalarArcBufferRepr {
     fn from(buffer: ArcBufferRepr<T>) -> Self {
         Self {
             raw: buffer.raw,
-            scalar_type: T::SCALAR_TYPE,
+            scalar_type: T::STORAGE_TYPE,
         }
     }
 }
 
 impl ScalarData for ScalarArcBufferRepr {
-    fn as_scalar_slice(&self) -> ScalarSliceRepr {
+    fn as_scalar_slice(&self) -> ScalarSliceRepr<'_> {
         ScalarSliceRepr {
             raw: self.raw.slice.clone(),
             scalar_type: self.scalar_type,
             _m: PhantomData,
         }
     }
-    fn get_scalar_slice_mut(&mut self) -> Option<ScalarSliceMutRepr> {
+    fn get_scalar_slice_mut(&mut self) -> Option<ScalarSliceMutRepr<'_>> {
         let raw = Arc::get_mut(&mut self.raw)?;
         Some(ScalarSliceMutRepr {
             raw: raw.slice.clone(),
@@ -729,7 +783,7 @@ impl ScalarDataOwned for ScalarArcBufferRepr {
             scalar_type: buffer.scalar_type,
         }
     }
-    fn make_scalar_slice_mut(&mut self) -> Result<ScalarSliceMutRepr> {
+    fn make_scalar_slice_mut(&mut self) -> Result<ScalarSliceMutRepr<'_>> {
         if let Some(raw) = Arc::get_mut(&mut self.raw) {
             return Ok(ScalarSliceMutRepr {
                 raw: raw.slice.clone(),
@@ -769,7 +823,7 @@ impl<'a> From<ScalarBufferRepr> for ScalarCowBufferRepr<'a> {
     }
 }
 
-impl<'a, T: Scalar> From<CowBufferRepr<'a, T>> for ScalarCowBufferRepr<'a> {
+impl<'a, T: Pod> From<CowBufferRepr<'a, T>> for ScalarCowBufferRepr<'a> {
     fn from(buffer: CowBufferRepr<'a, T>) -> Self {
         match buffer {
             CowBufferRepr::Borrowed(slice) => Self::Borrowed(slice.into()),
@@ -779,7 +833,7 @@ impl<'a, T: Scalar> From<CowBufferRepr<'a, T>> for ScalarCowBufferRepr<'a> {
 }
 
 impl<'a> ScalarData for ScalarCowBufferRepr<'a> {
-    fn as_scalar_slice(&self) -> ScalarSliceRepr {
+    fn as_scalar_slice(&self) -> ScalarSliceRepr<'_> {
         match self {
             Self::Borrowed(slice) => slice.clone(),
             Self::Owned(buffer) => buffer.as_scalar_slice(),
@@ -794,7 +848,7 @@ impl<'a> ScalarData for ScalarCowBufferRepr<'a> {
             Self::Owned(buffer) => Ok(buffer),
         }
     }
-    fn get_scalar_slice_mut(&mut self) -> Option<ScalarSliceMutRepr> {
+    fn get_scalar_slice_mut(&mut self) -> Option<ScalarSliceMutRepr<'_>> {
         match self {
             Self::Borrowed(_) => None,
             Self::Owned(buffer) => buffer.get_scalar_slice_mut(),
@@ -809,7 +863,7 @@ impl<'a> ScalarDataOwned for ScalarCowBufferRepr<'a> {
     {
         Self::Owned(buffer)
     }
-    fn make_scalar_slice_mut(&mut self) -> Result<ScalarSliceMutRepr> {
+    fn make_scalar_slice_mut(&mut self) -> Result<ScalarSliceMutRepr<'_>> {
         match self {
             Self::Borrowed(slice) => {
                 *self = Self::Owned(slice.to_scalar_buffer()?);
@@ -855,10 +909,10 @@ See [`BufferBase`]. */
 pub type ScalarCowBuffer<'a> = ScalarBufferBase<ScalarCowBufferRepr<'a>>;
 
 impl<S: ScalarDataOwned> ScalarBufferBase<S> {
-    /// Allocate a scalar buffer.
+    /// Allocate a scalar buffer, without initializing it.
     ///
     /// # Safety
-    /// The buffer will not be initialized.
+    /// See [`Buffer::uninit()`](BufferBase::uninit).
     ///
     /// **Errors**
     /// - [`DeviceLost`]
@@ -914,6 +968,11 @@ impl<S: ScalarDataOwned> ScalarBufferBase<S> {
         let data = S::from_scalar_buffer(buffer.data);
         Self { data }
     }
+    /// Create a scalar buffer from a [`Buffer`].
+    pub fn from_buffer<T: Pod>(buffer: Buffer<T>) -> Self {
+        let data = S::from_scalar_buffer(buffer.data.into());
+        Self { data }
+    }
 }
 
 impl<S: ScalarData> ScalarBufferBase<S> {
@@ -934,12 +993,12 @@ impl<S: ScalarData> ScalarBufferBase<S> {
         self.len() == 0
     }
     /// Borrow as a scalar slice.
-    pub fn as_scalar_slice(&self) -> ScalarSlice {
+    pub fn as_scalar_slice(&self) -> ScalarSlice<'_> {
         let data = self.data.as_scalar_slice();
         ScalarSlice { data }
     }
     /// Borrow as a mutable scalar slice.
-    pub fn as_scalar_slice_mut(&mut self) -> ScalarSliceMut
+    pub fn as_scalar_slice_mut(&mut self) -> ScalarSliceMut<'_>
     where
         S: ScalarDataMut,
     {
@@ -947,13 +1006,13 @@ impl<S: ScalarData> ScalarBufferBase<S> {
         ScalarSliceMut { data }
     }
     /// Borrows as a mutable scalar slice if possible.
-    pub fn get_scalar_slice_mut(&mut self) -> Option<ScalarSliceMut> {
+    pub fn get_scalar_slice_mut(&mut self) -> Option<ScalarSliceMut<'_>> {
         Some(ScalarSliceMut {
             data: self.data.get_scalar_slice_mut()?,
         })
     }
     /// Borrows as a mutable scalar slice, cloning if necessary.
-    pub fn make_scalar_slice_mut(&mut self) -> Result<ScalarSliceMut>
+    pub fn make_scalar_slice_mut(&mut self) -> Result<ScalarSliceMut<'_>>
     where
         S: ScalarDataOwned,
     {
@@ -961,6 +1020,31 @@ impl<S: ScalarData> ScalarBufferBase<S> {
             data: self.data.make_scalar_slice_mut()?,
         })
     }
+    /** Borrow as a slice of `T`.
+
+    Returns [`None`] if the scalar type is not `T`. */
+    pub fn as_slice<T: Pod>(&self) -> Option<Slice<'_, T>> {
+        self.as_scalar_slice().try_into().ok()
+    }
+    /** Borrow as a mutable slice of `T`.
+
+    Returns [`None`] if the scalar type is not `T`. */
+    pub fn as_slice_mut<T: Pod>(&mut self) -> Option<SliceMut<'_, T>>
+    where
+        S: ScalarDataMut,
+    {
+        self.as_scalar_slice_mut().try_into().ok()
+    }
+    /** The raw Vulkan buffer, for interop with [ash].
+
+    See [`BufferBase::vulkan_buffer()`].
+
+    # Safety
+    See [`BufferBase::vulkan_buffer()`]. */
+    #[cfg(all(not(target_arch = "wasm32"), feature = "vulkan-interop"))]
+    pub unsafe fn vulkan_buffer(&self) -> Option<crate::device::VulkanBuffer> {
+        self.as_scalar_slice().device_buffer()?.vulkan_buffer()
+    }
     /** Move into an owned scalar buffer.
 
     Avoids copying if possible.
@@ -1124,14 +1208,17 @@ impl<S: ScalarData> ScalarBufferBase<S> {
     /** Reinterpret as a slice with `scalar_type`.
 
     See [`bytemuck::cast_slice`]. */
-    pub fn bitcast(&self, scalar_type: ScalarType) -> Result<ScalarSlice, PodCastError> {
+    pub fn bitcast(&self, scalar_type: ScalarType) -> Result<ScalarSlice<'_>, PodCastError> {
         let data = self.data.as_scalar_slice().bitcast(scalar_type)?;
         Ok(ScalarSlice { data })
     }
     /** Reinterpret as a mutable slice with `scalar_type`.
 
     See [`bytemuck::cast_slice_mut`]. */
-    pub fn bitcast_mut(&mut self, scalar_type: ScalarType) -> Result<ScalarSliceMut, PodCastError>
+    pub fn bitcast_mut(
+        &mut self,
+        scalar_type: ScalarType,
+    ) -> Result<ScalarSliceMut<'_>, PodCastError>
     where
         S: DataMut,
     {
@@ -1152,20 +1239,68 @@ impl<S: ScalarData> ScalarBufferBase<S> {
     /** A subslice with `range`.
 
     See [`BufferBase::slice`]. */
-    pub fn slice(&self, range: impl RangeBounds<usize>) -> Option<ScalarSlice> {
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Option<ScalarSlice<'_>> {
         let data = self.data.as_scalar_slice().slice(range)?;
         Some(ScalarSlice { data })
     }
     /** A mutable subslice with `range`.
 
     See [`BufferBase::slice_mut`]. */
-    pub fn slice_mut(&mut self, range: impl RangeBounds<usize>) -> Option<ScalarSliceMut>
+    pub fn slice_mut(&mut self, range: impl RangeBounds<usize>) -> Option<ScalarSliceMut<'_>>
     where
         S: ScalarDataMut,
     {
         let data = self.data.as_scalar_slice_mut().slice(range)?;
         Some(ScalarSliceMut { data })
     }
+    /** Divides into two slices at `mid`.
+
+    See [`BufferBase::split_at`]. */
+    pub fn split_at(&self, mid: usize) -> Option<(ScalarSlice<'_>, ScalarSlice<'_>)> {
+        let (a, b) = self.data.as_scalar_slice().split_at(mid)?;
+        Some((ScalarSlice { data: a }, ScalarSlice { data: b }))
+    }
+    /** Divides into two mutable slices at `mid`.
+
+    See [`BufferBase::split_at_mut`]. */
+    pub fn split_at_mut(&mut self, mid: usize) -> Option<(ScalarSliceMut<'_>, ScalarSliceMut<'_>)>
+    where
+        S: ScalarDataMut,
+    {
+        let (a, b) = self.data.as_scalar_slice_mut().split_at(mid)?;
+        Some((ScalarSliceMut { data: a }, ScalarSliceMut { data: b }))
+    }
+    /** Iterates over slices of `chunk_size` elements.
+
+    See [`BufferBase::chunks`]. */
+    pub fn chunks(&self, chunk_size: usize) -> impl Iterator<Item = ScalarSlice<'_>> + '_ {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        let mut rest = Some(self.data.as_scalar_slice());
+        std::iter::from_fn(move || {
+            let slice = rest.take().filter(|x| !x.is_empty())?;
+            let mid = chunk_size.min(slice.len());
+            let (a, b) = slice.split_at(mid)?;
+            rest.replace(b);
+            Some(ScalarSlice { data: a })
+        })
+    }
+    /** Iterates over mutable slices of `chunk_size` elements.
+
+    See [`BufferBase::chunks_mut`]. */
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> impl Iterator<Item = ScalarSliceMut<'_>> + '_
+    where
+        S: ScalarDataMut,
+    {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        let mut rest = Some(self.data.as_scalar_slice_mut());
+        std::iter::from_fn(move || {
+            let slice = rest.take().filter(|x| !x.is_empty())?;
+            let mid = chunk_size.min(slice.len());
+            let (a, b) = slice.split_at(mid)?;
+            rest.replace(b);
+            Some(ScalarSliceMut { data: a })
+        })
+    }
 }
 
 #[cfg(feature = "device")]
@@ -1190,29 +1325,39 @@ impl ScalarSliceMut<'_> {
     }
 }
 
-impl<T: Scalar, S: ScalarDataOwned> From<Buffer<T>> for ScalarBufferBase<S> {
+impl ScalarBuffer {
+    /** Reinterpret as a scalar buffer with `scalar_type`, without copying.
+
+    See [`.bitcast()`](ScalarBufferBase::bitcast). */
+    pub fn bitcast_into(self, scalar_type: ScalarType) -> Result<Self, PodCastError> {
+        let data = self.data.bitcast_into(scalar_type)?;
+        Ok(Self { data })
+    }
+}
+
+impl<T: Pod, S: ScalarDataOwned> From<Buffer<T>> for ScalarBufferBase<S> {
     fn from(buffer: Buffer<T>) -> Self {
         let data = S::from_scalar_buffer(ScalarBufferRepr::from(buffer.data));
         Self { data }
     }
 }
 
-impl<'a, T: Scalar> From<Slice<'a, T>> for ScalarSlice<'a> {
+impl<'a, T: Pod> From<Slice<'a, T>> for ScalarSlice<'a> {
     fn from(slice: Slice<'a, T>) -> Self {
         let data = ScalarSliceRepr {
             raw: slice.data.raw,
-            scalar_type: T::SCALAR_TYPE,
+            scalar_type: T::STORAGE_TYPE,
             _m: PhantomData,
         };
         Self { data }
     }
 }
 
-impl<'a, T: Scalar> From<SliceMut<'a, T>> for ScalarSliceMut<'a> {
+impl<'a, T: Pod> From<SliceMut<'a, T>> for ScalarSliceMut<'a> {
     fn from(slice: SliceMut<'a, T>) -> Self {
         let data = ScalarSliceMutRepr {
             raw: slice.data.raw,
-            scalar_type: T::SCALAR_TYPE,
+            scalar_type: T::STORAGE_TYPE,
             _m: PhantomData,
         };
         Self { data }
@@ -1227,7 +1372,7 @@ impl From<ScalarBuffer> for ScalarArcBuffer {
     }
 }
 
-impl<T: Scalar> From<ArcBuffer<T>> for ScalarArcBuffer {
+impl<T: Pod> From<ArcBuffer<T>> for ScalarArcBuffer {
     fn from(buffer: ArcBuffer<T>) -> Self {
         Self {
             data: buffer.data.into(),
@@ -1251,7 +1396,7 @@ impl<'a> From<ScalarSlice<'a>> for ScalarCowBuffer<'a> {
     }
 }
 
-impl<'a, T: Scalar> From<CowBuffer<'a, T>> for ScalarCowBuffer<'a> {
+impl<'a, T: Pod> From<CowBuffer<'a, T>> for ScalarCowBuffer<'a> {
     fn from(buffer: CowBuffer<'a, T>) -> Self {
         Self {
             data: buffer.data.into(),
@@ -1291,26 +1436,26 @@ impl<'de, S: ScalarDataOwned> Deserialize<'de> for ScalarBufferBase<S> {
     }
 }
 
-macro_for!($S in [BufferRepr, ArcBufferRepr] {
-    impl<T: Scalar> Sealed for $S<T> {}
-    unsafe impl<T: Scalar> Send for $S<T> {}
-    unsafe impl<T: Scalar> Sync for $S<T> {}
+macro_for!($S in [BufferRepr, ArcBufferRepr, ArcSliceRepr] {
+    impl<T: Pod> Sealed for $S<T> {}
+    unsafe impl<T: Pod> Send for $S<T> {}
+    unsafe impl<T: Pod> Sync for $S<T> {}
 });
 
 macro_for!($S in [SliceRepr, SliceMutRepr, CowBufferRepr] {
-    impl<T: Scalar> Sealed for $S<'_, T> {}
-    unsafe impl<T: Scalar> Send for $S<'_, T> {}
-    unsafe impl<T: Scalar> Sync for $S<'_, T> {}
+    impl<T: Pod> Sealed for $S<'_, T> {}
+    unsafe impl<T: Pod> Send for $S<'_, T> {}
+    unsafe impl<T: Pod> Sync for $S<'_, T> {}
 });
 
 /// Marker trait for buffers.
 pub trait Data: ScalarData {
-    /// The type of the buffer.
-    type Elem: Scalar;
+    /// The element type of the buffer.
+    type Elem: Pod;
     #[doc(hidden)]
-    fn as_slice(&self) -> SliceRepr<Self::Elem>;
+    fn as_slice(&self) -> SliceRepr<'_, Self::Elem>;
     #[doc(hidden)]
-    fn get_slice_mut(&mut self) -> Option<SliceMutRepr<Self::Elem>> {
+    fn get_slice_mut(&mut self) -> Option<SliceMutRepr<'_, Self::Elem>> {
         None
     }
     #[doc(hidden)]
@@ -1346,7 +1491,7 @@ pub trait Data: ScalarData {
 /// Marker trait for mutable buffers.
 pub trait DataMut: Data + ScalarDataMut {
     #[doc(hidden)]
-    fn as_slice_mut(&mut self) -> SliceMutRepr<Self::Elem>;
+    fn as_slice_mut(&mut self) -> SliceMutRepr<'_, Self::Elem>;
     #[doc(hidden)]
     fn as_host_slice_mut(&mut self) -> Option<&mut [Self::Elem]> {
         self.as_slice_mut().into_host_slice_mut()
@@ -1358,7 +1503,7 @@ pub trait DataOwned: Data {
     #[doc(hidden)]
     fn from_buffer(buffer: BufferRepr<Self::Elem>) -> Self;
     #[doc(hidden)]
-    fn make_slice_mut(&mut self) -> Result<SliceMutRepr<Self::Elem>>;
+    fn make_slice_mut(&mut self) -> Result<SliceMutRepr<'_, Self::Elem>>;
 }
 
 /// [`Buffer`] representation.
@@ -1367,19 +1512,20 @@ pub struct BufferRepr<T> {
     _m: PhantomData<T>,
 }
 
-impl<T: Scalar> BufferRepr<T> {
+impl<T: Pod> BufferRepr<T> {
     fn from_vec(vec: Vec<T>) -> Self {
-        let width = std::mem::size_of::<T>();
+        let size = std::mem::size_of::<T>();
         let ptr = vec.as_ptr() as *mut u8;
-        let len = vec.len() * width;
-        let cap = vec.capacity() * width;
+        let len = vec.len() * size;
+        let cap = vec.capacity() * size;
         forget(vec);
+        // Structs are freed as their storage type, which has the same alignment.
         let raw = RawBuffer {
             slice: RawSlice {
                 inner: RawSliceInner::Host(RawHostSlice { ptr, len }),
             },
             cap,
-            width,
+            width: T::STORAGE_TYPE.size(),
         };
         Self {
             raw,
@@ -1387,6 +1533,13 @@ impl<T: Scalar> BufferRepr<T> {
         }
     }
     unsafe fn uninit(device: Device, len: usize) -> Result<Self> {
+        unsafe { Self::uninit_impl(device, len, false) }
+    }
+    unsafe fn uninit_pinned(device: Device, len: usize) -> Result<Self> {
+        unsafe { Self::uninit_impl(device, len, true) }
+    }
+    #[cfg_attr(not(feature = "device"), allow(unused_variables))]
+    unsafe fn uninit_impl(device: Device, len: usize, pinned: bool) -> Result<Self> {
         match device.inner() {
             DeviceInner::Host => {
                 let mut vec = Vec::with_capacity(len);
@@ -1398,15 +1551,18 @@ impl<T: Scalar> BufferRepr<T> {
             }
             #[cfg(feature = "device")]
             DeviceInner::Device(device) => {
-                let width = size_of::<T>();
-                let cap = len * width;
-                let device_buffer = unsafe { DeviceBuffer::uninit(device.clone(), cap)? };
+                let cap = len * size_of::<T>();
+                let device_buffer = if pinned {
+                    unsafe { DeviceBuffer::uninit_pinned(device.clone(), cap)? }
+                } else {
+                    unsafe { DeviceBuffer::uninit(device.clone(), cap)? }
+                };
                 let raw = RawBuffer {
                     slice: RawSlice {
                         inner: RawSliceInner::Device(device_buffer),
                     },
                     cap,
-                    width,
+                    width: T::STORAGE_TYPE.size(),
                 };
                 Ok(Self {
                     raw,
@@ -1430,7 +1586,7 @@ impl<T: Scalar> BufferRepr<T> {
         match self.raw.inner {
             RawSliceInner::Host(raw) => {
                 let width = size_of::<T>();
-                if width == self.raw.width {
+                if T::STORAGE_TYPE.size() == self.raw.width && self.raw.cap % width == 0 {
                     let vec = unsafe {
                         Vec::from_raw_parts(raw.ptr as _, raw.len / width, self.raw.cap / width)
                     };
@@ -1449,35 +1605,35 @@ impl<T: Scalar> BufferRepr<T> {
     }
 }
 
-impl<T: Scalar> ScalarData for BufferRepr<T> {
-    fn as_scalar_slice(&self) -> ScalarSliceRepr {
+impl<T: Pod> ScalarData for BufferRepr<T> {
+    fn as_scalar_slice(&self) -> ScalarSliceRepr<'_> {
         ScalarSliceRepr {
             raw: self.raw.clone(),
-            scalar_type: T::SCALAR_TYPE,
+            scalar_type: T::STORAGE_TYPE,
             _m: PhantomData,
         }
     }
 }
 
-impl<T: Scalar> ScalarDataMut for BufferRepr<T> {
-    fn as_scalar_slice_mut(&mut self) -> ScalarSliceMutRepr {
+impl<T: Pod> ScalarDataMut for BufferRepr<T> {
+    fn as_scalar_slice_mut(&mut self) -> ScalarSliceMutRepr<'_> {
         ScalarSliceMutRepr {
             raw: self.raw.clone(),
-            scalar_type: T::SCALAR_TYPE,
+            scalar_type: T::STORAGE_TYPE,
             _m: PhantomData,
         }
     }
 }
 
-impl<T: Scalar> Data for BufferRepr<T> {
+impl<T: Pod> Data for BufferRepr<T> {
     type Elem = T;
-    fn as_slice(&self) -> SliceRepr<Self::Elem> {
+    fn as_slice(&self) -> SliceRepr<'_, Self::Elem> {
         SliceRepr {
             raw: self.raw.slice.clone(),
             _m: PhantomData,
         }
     }
-    fn get_slice_mut(&mut self) -> Option<SliceMutRepr<T>> {
+    fn get_slice_mut(&mut self) -> Option<SliceMutRepr<'_, T>> {
         Some(self.as_slice_mut())
     }
     fn try_into_buffer(self) -> Result<Self, Self>
@@ -1488,8 +1644,8 @@ impl<T: Scalar> Data for BufferRepr<T> {
     }
 }
 
-impl<T: Scalar> DataMut for BufferRepr<T> {
-    fn as_slice_mut(&mut self) -> SliceMutRepr<Self::Elem> {
+impl<T: Pod> DataMut for BufferRepr<T> {
+    fn as_slice_mut(&mut self) -> SliceMutRepr<'_, Self::Elem> {
         SliceMutRepr {
             raw: self.raw.slice.clone(),
             _m: PhantomData,
@@ -1497,19 +1653,19 @@ impl<T: Scalar> DataMut for BufferRepr<T> {
     }
 }
 
-impl<T: Scalar> DataOwned for BufferRepr<T> {
+impl<T: Pod> DataOwned for BufferRepr<T> {
     fn from_buffer(buffer: Self) -> Self {
         buffer
     }
-    fn make_slice_mut(&mut self) -> Result<SliceMutRepr<T>> {
+    fn make_slice_mut(&mut self) -> Result<SliceMutRepr<'_, T>> {
         Ok(self.as_slice_mut())
     }
 }
 
-impl<T: Scalar> TryFrom<ScalarBufferRepr> for BufferRepr<T> {
+impl<T: Pod> TryFrom<ScalarBufferRepr> for BufferRepr<T> {
     type Error = ScalarBufferRepr;
     fn try_from(buffer: ScalarBufferRepr) -> Result<Self, Self::Error> {
-        if buffer.scalar_type() == T::SCALAR_TYPE {
+        if buffer.scalar_type() == T::STORAGE_TYPE && buffer.raw.len() % size_of::<T>() == 0 {
             Ok(Self {
                 raw: buffer.raw,
                 _m: Default::default(),
@@ -1527,7 +1683,7 @@ pub struct SliceRepr<'a, T> {
     _m: PhantomData<&'a T>,
 }
 
-impl<'a, T: Scalar> SliceRepr<'a, T> {
+impl<'a, T: Pod> SliceRepr<'a, T> {
     fn from_host_slice(host_slice: &'a [T]) -> Self {
         let ptr = host_slice.as_ptr() as *mut u8;
         let len = std::mem::size_of_val(host_slice);
@@ -1539,6 +1695,13 @@ impl<'a, T: Scalar> SliceRepr<'a, T> {
             _m: PhantomData,
         }
     }
+    // The length in elements, `ScalarData::len()` is in scalars of the storage type.
+    fn len(&self) -> usize {
+        self.raw.len() / size_of::<T>()
+    }
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
     fn into_host_slice(self) -> Option<&'a [T]> {
         match &self.raw.inner {
             RawSliceInner::Host(raw) => {
@@ -1574,33 +1737,37 @@ impl<'a, T: Scalar> SliceRepr<'a, T> {
     }
     fn slice(self, range: impl RangeBounds<usize>) -> Option<Self> {
         Some(Self {
-            raw: self.raw.slice(range, T::SCALAR_TYPE)?,
+            raw: self.raw.slice(range, size_of::<T>())?,
             ..self
         })
     }
+    fn split_at(self, mid: usize) -> Option<(Self, Self)> {
+        let (a, b) = self.raw.split_at(mid, size_of::<T>())?;
+        Some((Self { raw: a, ..self }, Self { raw: b, ..self }))
+    }
 }
 
-impl<'a, T: Scalar> ScalarData for SliceRepr<'a, T> {
-    fn as_scalar_slice(&self) -> ScalarSliceRepr {
+impl<'a, T: Pod> ScalarData for SliceRepr<'a, T> {
+    fn as_scalar_slice(&self) -> ScalarSliceRepr<'_> {
         ScalarSliceRepr {
             raw: self.raw.clone(),
-            scalar_type: T::SCALAR_TYPE,
+            scalar_type: T::STORAGE_TYPE,
             _m: PhantomData,
         }
     }
 }
 
-impl<T: Scalar> Data for SliceRepr<'_, T> {
+impl<T: Pod> Data for SliceRepr<'_, T> {
     type Elem = T;
-    fn as_slice(&self) -> SliceRepr<T> {
+    fn as_slice(&self) -> SliceRepr<'_, T> {
         self.clone()
     }
 }
 
-impl<'a, T: Scalar> TryFrom<ScalarSliceRepr<'a>> for SliceRepr<'a, T> {
+impl<'a, T: Pod> TryFrom<ScalarSliceRepr<'a>> for SliceRepr<'a, T> {
     type Error = ScalarSliceRepr<'a>;
     fn try_from(slice: ScalarSliceRepr<'a>) -> Result<Self, Self::Error> {
-        if slice.scalar_type() == T::SCALAR_TYPE {
+        if slice.scalar_type() == T::STORAGE_TYPE && slice.raw.len() % size_of::<T>() == 0 {
             Ok(Self {
                 raw: slice.raw,
                 _m: Default::default(),
@@ -1617,7 +1784,7 @@ pub struct SliceMutRepr<'a, T> {
     _m: PhantomData<&'a T>,
 }
 
-impl<'a, T: Scalar> SliceMutRepr<'a, T> {
+impl<'a, T: Pod> SliceMutRepr<'a, T> {
     fn from_host_slice_mut(host_slice: &'a mut [T]) -> Self {
         let ptr = host_slice.as_ptr() as *mut u8;
         let len = std::mem::size_of_val(host_slice);
@@ -1629,6 +1796,13 @@ impl<'a, T: Scalar> SliceMutRepr<'a, T> {
             _m: PhantomData,
         }
     }
+    // The length in elements, `ScalarData::len()` is in scalars of the storage type.
+    fn len(&self) -> usize {
+        self.raw.len() / size_of::<T>()
+    }
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
     fn into_host_slice_mut(self) -> Option<&'a mut [T]> {
         match &self.raw.inner {
             RawSliceInner::Host(raw) => {
@@ -1669,12 +1843,17 @@ impl<'a, T: Scalar> SliceMutRepr<'a, T> {
                 if dst.device() != src_buffer.device() {
                     return src_buffer.transfer(dst);
                 }
-                Slice { data: src.clone() }.cast_impl(&mut SliceMut::<T> {
-                    data: SliceMutRepr {
-                        raw: self.raw.clone(),
-                        _m: PhantomData,
+                if src_buffer.copy(dst)? {
+                    return Ok(());
+                }
+                device_scalar_buffer_cast_impl(
+                    ScalarSlice {
+                        data: src.as_scalar_slice(),
                     },
-                })
+                    ScalarSliceMut {
+                        data: self.as_scalar_slice_mut(),
+                    },
+                )
             }
         }
     }
@@ -1687,35 +1866,39 @@ impl<'a, T: Scalar> SliceMutRepr<'a, T> {
     }
     fn slice(self, range: impl RangeBounds<usize>) -> Option<Self> {
         Some(Self {
-            raw: self.raw.slice(range, T::SCALAR_TYPE)?,
+            raw: self.raw.slice(range, size_of::<T>())?,
             ..self
         })
     }
+    fn split_at(self, mid: usize) -> Option<(Self, Self)> {
+        let (a, b) = self.raw.split_at(mid, size_of::<T>())?;
+        Some((Self { raw: a, ..self }, Self { raw: b, ..self }))
+    }
 }
 
-impl<T: Scalar> ScalarData for SliceMutRepr<'_, T> {
-    fn as_scalar_slice(&self) -> ScalarSliceRepr {
+impl<T: Pod> ScalarData for SliceMutRepr<'_, T> {
+    fn as_scalar_slice(&self) -> ScalarSliceRepr<'_> {
         ScalarSliceRepr {
             raw: self.raw.clone(),
-            scalar_type: T::SCALAR_TYPE,
+            scalar_type: T::STORAGE_TYPE,
             _m: PhantomData,
         }
     }
 }
 
-impl<T: Scalar> ScalarDataMut for SliceMutRepr<'_, T> {
-    fn as_scalar_slice_mut(&mut self) -> ScalarSliceMutRepr {
+impl<T: Pod> ScalarDataMut for SliceMutRepr<'_, T> {
+    fn as_scalar_slice_mut(&mut self) -> ScalarSliceMutRepr<'_> {
         ScalarSliceMutRepr {
             raw: self.raw.clone(),
-            scalar_type: T::SCALAR_TYPE,
+            scalar_type: T::STORAGE_TYPE,
             _m: PhantomData,
         }
     }
 }
 
-impl<T: Scalar> Data for SliceMutRepr<'_, T> {
+impl<T: Pod> Data for SliceMutRepr<'_, T> {
     type Elem = T;
-    fn as_slice(&self) -> SliceRepr<T> {
+    fn as_slice(&self) -> SliceRepr<'_, T> {
         SliceRepr {
             raw: self.raw.clone(),
             _m: Default::default(),
@@ -1723,8 +1906,8 @@ impl<T: Scalar> Data for SliceMutRepr<'_, T> {
     }
 }
 
-impl<T: Scalar> DataMut for SliceMutRepr<'_, T> {
-    fn as_slice_mut(&mut self) -> SliceMutRepr<T> {
+impl<T: Pod> DataMut for SliceMutRepr<'_, T> {
+    fn as_slice_mut(&mut self) -> SliceMutRepr<'_, T> {
         SliceMutRepr {
             raw: self.raw.clone(),
             _m: Default::default(),
@@ -1732,10 +1915,10 @@ impl<T: Scalar> DataMut for SliceMutRepr<'_, T> {
     }
 }
 
-impl<'a, T: Scalar> TryFrom<ScalarSliceMutRepr<'a>> for SliceMutRepr<'a, T> {
+impl<'a, T: Pod> TryFrom<ScalarSliceMutRepr<'a>> for SliceMutRepr<'a, T> {
     type Error = ScalarSliceMutRepr<'a>;
     fn try_from(slice: ScalarSliceMutRepr<'a>) -> Result<Self, Self::Error> {
-        if slice.scalar_type() == T::SCALAR_TYPE {
+        if slice.scalar_type() == T::STORAGE_TYPE && slice.raw.len() % size_of::<T>() == 0 {
             Ok(Self {
                 raw: slice.raw,
                 _m: Default::default(),
@@ -1753,7 +1936,7 @@ pub struct ArcBufferRepr<T> {
     _m: PhantomData<T>,
 }
 
-impl<T: Scalar> From<BufferRepr<T>> for ArcBufferRepr<T> {
+impl<T: Pod> From<BufferRepr<T>> for ArcBufferRepr<T> {
     fn from(buffer: BufferRepr<T>) -> Self {
         Self {
             #[allow(clippy::arc_with_non_send_sync)]
@@ -1763,10 +1946,10 @@ impl<T: Scalar> From<BufferRepr<T>> for ArcBufferRepr<T> {
     }
 }
 
-impl<T: Scalar> TryFrom<ScalarArcBufferRepr> for ArcBufferRepr<T> {
+impl<T: Pod> TryFrom<ScalarArcBufferRepr> for ArcBufferRepr<T> {
     type Error = ScalarArcBufferRepr;
     fn try_from(buffer: ScalarArcBufferRepr) -> Result<Self, Self::Error> {
-        if T::SCALAR_TYPE == buffer.scalar_type {
+        if T::STORAGE_TYPE == buffer.scalar_type && buffer.raw.len() % size_of::<T>() == 0 {
             Ok(Self {
                 raw: buffer.raw,
                 _m: PhantomData,
@@ -1777,11 +1960,11 @@ impl<T: Scalar> TryFrom<ScalarArcBufferRepr> for ArcBufferRepr<T> {
     }
 }
 
-impl<T: Scalar> ScalarData for ArcBufferRepr<T> {
-    fn as_scalar_slice(&self) -> ScalarSliceRepr {
+impl<T: Pod> ScalarData for ArcBufferRepr<T> {
+    fn as_scalar_slice(&self) -> ScalarSliceRepr<'_> {
         self.as_slice().into()
     }
-    fn get_scalar_slice_mut(&mut self) -> Option<ScalarSliceMutRepr> {
+    fn get_scalar_slice_mut(&mut self) -> Option<ScalarSliceMutRepr<'_>> {
         self.get_slice_mut().map(Into::into)
     }
     fn try_into_scalar_buffer(self) -> Result<ScalarBufferRepr, Self> {
@@ -1795,15 +1978,15 @@ impl<T: Scalar> ScalarData for ArcBufferRepr<T> {
     }
 }
 
-impl<T: Scalar> Data for ArcBufferRepr<T> {
+impl<T: Pod> Data for ArcBufferRepr<T> {
     type Elem = T;
-    fn as_slice(&self) -> SliceRepr<T> {
+    fn as_slice(&self) -> SliceRepr<'_, T> {
         SliceRepr {
             raw: self.raw.slice.clone(),
             _m: PhantomData,
         }
     }
-    fn get_slice_mut(&mut self) -> Option<SliceMutRepr<T>> {
+    fn get_slice_mut(&mut self) -> Option<SliceMutRepr<'_, T>> {
         let raw = Arc::get_mut(&mut self.raw)?;
         Some(SliceMutRepr {
             raw: raw.slice.clone(),
@@ -1833,7 +2016,7 @@ impl<T: Scalar> Data for ArcBufferRepr<T> {
     }
 }
 
-impl<T: Scalar> DataOwned for ArcBufferRepr<T> {
+impl<T: Pod> DataOwned for ArcBufferRepr<T> {
     fn from_buffer(buffer: BufferRepr<T>) -> Self {
         Self {
             #[allow(clippy::arc_with_non_send_sync)]
@@ -1841,7 +2024,7 @@ impl<T: Scalar> DataOwned for ArcBufferRepr<T> {
             _m: PhantomData,
         }
     }
-    fn make_slice_mut(&mut self) -> Result<SliceMutRepr<T>> {
+    fn make_slice_mut(&mut self) -> Result<SliceMutRepr<'_, T>> {
         if let Some(raw) = Arc::get_mut(&mut self.raw) {
             return Ok(SliceMutRepr {
                 raw: raw.slice.clone(),
@@ -1859,6 +2042,98 @@ impl<T: Scalar> DataOwned for ArcBufferRepr<T> {
     }
 }
 
+/// [`ArcSlice`] representation.
+#[derive(Clone)]
+pub struct ArcSliceRepr<T> {
+    buffer: Arc<RawBuffer>,
+    raw: RawSlice,
+    _m: PhantomData<T>,
+}
+
+impl<T: Pod> ArcSliceRepr<T> {
+    fn slice(&self, range: impl RangeBounds<usize>) -> Option<Self> {
+        Some(Self {
+            buffer: self.buffer.clone(),
+            raw: self.raw.clone().slice(range, size_of::<T>())?,
+            _m: PhantomData,
+        })
+    }
+}
+
+impl<T: Pod> From<ArcBufferRepr<T>> for ArcSliceRepr<T> {
+    fn from(buffer: ArcBufferRepr<T>) -> Self {
+        Self {
+            raw: buffer.raw.slice.clone(),
+            buffer: buffer.raw,
+            _m: PhantomData,
+        }
+    }
+}
+
+impl<T: Pod> ScalarData for ArcSliceRepr<T> {
+    fn as_scalar_slice(&self) -> ScalarSliceRepr<'_> {
+        self.as_slice().into()
+    }
+    fn try_into_scalar_buffer(self) -> Result<ScalarBufferRepr, Self> {
+        self.try_into_buffer().map(Into::into)
+    }
+    fn try_into_scalar_arc_buffer(self) -> Result<ScalarArcBufferRepr, Self> {
+        self.try_into_arc_buffer().map(Into::into)
+    }
+}
+
+impl<T: Pod> Data for ArcSliceRepr<T> {
+    type Elem = T;
+    fn as_slice(&self) -> SliceRepr<'_, T> {
+        SliceRepr {
+            raw: self.raw.clone(),
+            _m: PhantomData,
+        }
+    }
+    fn try_into_buffer(self) -> Result<BufferRepr<T>, Self> {
+        self.try_into_arc_buffer()?
+            .try_into_buffer()
+            .map_err(Into::into)
+    }
+    fn try_into_arc_buffer(self) -> Result<ArcBufferRepr<T>, Self> {
+        // A subslice with the same length is the whole buffer.
+        if self.raw.len() == self.buffer.len() {
+            Ok(ArcBufferRepr {
+                raw: self.buffer,
+                _m: PhantomData,
+            })
+        } else {
+            Err(self)
+        }
+    }
+}
+
+/// [`MmapBuffer`] representation.
+#[cfg(feature = "mmap")]
+#[derive(Clone)]
+pub struct MmapRepr<T> {
+    mmap: Arc<memmap2::Mmap>,
+    _m: PhantomData<T>,
+}
+
+#[cfg(feature = "mmap")]
+impl<T: Pod> Sealed for MmapRepr<T> {}
+
+#[cfg(feature = "mmap")]
+impl<T: Pod> ScalarData for MmapRepr<T> {
+    fn as_scalar_slice(&self) -> ScalarSliceRepr<'_> {
+        self.as_slice().into()
+    }
+}
+
+#[cfg(feature = "mmap")]
+impl<T: Pod> Data for MmapRepr<T> {
+    type Elem = T;
+    fn as_slice(&self) -> SliceRepr<'_, T> {
+        SliceRepr::from_host_slice(bytemuck::cast_slice(&self.mmap))
+    }
+}
+
 /// [`CowBuffer`] representation.
 pub enum CowBufferRepr<'a, T> {
     #[doc(hidden)]
@@ -1879,7 +2154,7 @@ impl<'a, T> From<BufferRepr<T>> for CowBufferRepr<'a, T> {
     }
 }
 
-impl<'a, T: Scalar> TryFrom<ScalarCowBufferRepr<'a>> for CowBufferRepr<'a, T> {
+impl<'a, T: Pod> TryFrom<ScalarCowBufferRepr<'a>> for CowBufferRepr<'a, T> {
     type Error = ScalarCowBufferRepr<'a>;
     fn try_from(buffer: ScalarCowBufferRepr<'a>) -> Result<Self, Self::Error> {
         match buffer {
@@ -1893,11 +2168,11 @@ impl<'a, T: Scalar> TryFrom<ScalarCowBufferRepr<'a>> for CowBufferRepr<'a, T> {
     }
 }
 
-impl<'a, T: Scalar> ScalarData for CowBufferRepr<'a, T> {
-    fn as_scalar_slice(&self) -> ScalarSliceRepr {
+impl<'a, T: Pod> ScalarData for CowBufferRepr<'a, T> {
+    fn as_scalar_slice(&self) -> ScalarSliceRepr<'_> {
         self.as_slice().into()
     }
-    fn get_scalar_slice_mut(&mut self) -> Option<ScalarSliceMutRepr> {
+    fn get_scalar_slice_mut(&mut self) -> Option<ScalarSliceMutRepr<'_>> {
         self.get_slice_mut().map(Into::into)
     }
     fn try_into_scalar_buffer(self) -> Result<ScalarBufferRepr, Self> {
@@ -1905,15 +2180,15 @@ impl<'a, T: Scalar> ScalarData for CowBufferRepr<'a, T> {
     }
 }
 
-impl<'a, T: Scalar> Data for CowBufferRepr<'a, T> {
+impl<'a, T: Pod> Data for CowBufferRepr<'a, T> {
     type Elem = T;
-    fn as_slice(&self) -> SliceRepr<T> {
+    fn as_slice(&self) -> SliceRepr<'_, T> {
         match self {
             Self::Borrowed(slice) => slice.clone(),
             Self::Owned(buffer) => buffer.as_slice(),
         }
     }
-    fn get_slice_mut(&mut self) -> Option<SliceMutRepr<T>> {
+    fn get_slice_mut(&mut self) -> Option<SliceMutRepr<'_, T>> {
         match self {
             Self::Borrowed(_) => None,
             Self::Owned(buffer) => buffer.get_slice_mut(),
@@ -1927,11 +2202,11 @@ impl<'a, T: Scalar> Data for CowBufferRepr<'a, T> {
     }
 }
 
-impl<'a, T: Scalar> DataOwned for CowBufferRepr<'a, T> {
+impl<'a, T: Pod> DataOwned for CowBufferRepr<'a, T> {
     fn from_buffer(buffer: BufferRepr<T>) -> Self {
         Self::Owned(buffer)
     }
-    fn make_slice_mut(&mut self) -> Result<SliceMutRepr<T>> {
+    fn make_slice_mut(&mut self) -> Result<SliceMutRepr<'_, T>> {
         match self {
             Self::Borrowed(slice) => {
                 *self = Self::Owned(slice.to_buffer()?);
@@ -1969,32 +2244,46 @@ Like [`Arc`], a clonable shared buffer.
 
 See [`BufferBase`]. */
 pub type ArcBuffer<T> = BufferBase<ArcBufferRepr<T>>;
+/** ArcSlice
+
+A clonable shared slice of an [`ArcBuffer`], without a lifetime. See
+[`.slice_shared()`](ArcBuffer::slice_shared).
+
+See [`BufferBase`]. */
+pub type ArcSlice<T> = BufferBase<ArcSliceRepr<T>>;
 /** CowBuffer
 
 Like [`Cow`](::std::borrow::Cow), can be created from a [`Slice`] or [`Buffer`].
 
 See [`BufferBase`]. */
 pub type CowBuffer<'a, T> = BufferBase<CowBufferRepr<'a, T>>;
+/** MmapBuffer
 
-impl<T: Scalar, S: DataOwned<Elem = T>> From<Vec<T>> for BufferBase<S> {
+A read only host buffer backed by a memory mapped file, see [`MmapBuffer::from_mmap`].
+
+See [`BufferBase`]. */
+#[cfg(feature = "mmap")]
+pub type MmapBuffer<T> = BufferBase<MmapRepr<T>>;
+
+impl<T: Pod, S: DataOwned<Elem = T>> From<Vec<T>> for BufferBase<S> {
     fn from(vec: Vec<T>) -> Self {
         Self::from_vec(vec)
     }
 }
 
-impl<'a, T: Scalar> From<&'a [T]> for Slice<'a, T> {
+impl<'a, T: Pod> From<&'a [T]> for Slice<'a, T> {
     fn from(host_slice: &'a [T]) -> Self {
         Self::from_host_slice(host_slice)
     }
 }
 
-impl<'a, T: Scalar> From<&'a mut [T]> for SliceMut<'a, T> {
+impl<'a, T: Pod> From<&'a mut [T]> for SliceMut<'a, T> {
     fn from(host_slice: &'a mut [T]) -> Self {
         Self::from_host_slice_mut(host_slice)
     }
 }
 
-impl<T: Scalar> TryFrom<ScalarBuffer> for Buffer<T> {
+impl<T: Pod> TryFrom<ScalarBuffer> for Buffer<T> {
     type Error = ScalarBuffer;
     fn try_from(buffer: ScalarBuffer) -> Result<Self, Self::Error> {
         match buffer.data.try_into() {
@@ -2004,7 +2293,17 @@ impl<T: Scalar> TryFrom<ScalarBuffer> for Buffer<T> {
     }
 }
 
-impl<'a, T: Scalar> TryFrom<ScalarSlice<'a>> for Slice<'a, T> {
+impl<T: Pod> Buffer<T> {
+    /** Reinterpret as a buffer with type `Y`, without copying.
+
+    See [`.bitcast()`](BufferBase::bitcast). */
+    pub fn bitcast_into<Y: Scalar>(self) -> Result<Buffer<Y>, PodCastError> {
+        let buffer = ScalarBuffer::from(self).bitcast_into(Y::SCALAR_TYPE)?;
+        Ok(buffer.try_into().ok().unwrap())
+    }
+}
+
+impl<'a, T: Pod> TryFrom<ScalarSlice<'a>> for Slice<'a, T> {
     type Error = ScalarSlice<'a>;
     fn try_from(slice: ScalarSlice<'a>) -> Result<Self, Self::Error> {
         match slice.data.try_into() {
@@ -2014,7 +2313,7 @@ impl<'a, T: Scalar> TryFrom<ScalarSlice<'a>> for Slice<'a, T> {
     }
 }
 
-impl<'a, T: Scalar> TryFrom<ScalarSliceMut<'a>> for SliceMut<'a, T> {
+impl<'a, T: Pod> TryFrom<ScalarSliceMut<'a>> for SliceMut<'a, T> {
     type Error = ScalarSliceMut<'a>;
     fn try_from(slice: ScalarSliceMut<'a>) -> Result<Self, Self::Error> {
         match slice.data.try_into() {
@@ -2024,7 +2323,7 @@ impl<'a, T: Scalar> TryFrom<ScalarSliceMut<'a>> for SliceMut<'a, T> {
     }
 }
 
-impl<T: Scalar> TryFrom<ScalarArcBuffer> for ArcBuffer<T> {
+impl<T: Pod> TryFrom<ScalarArcBuffer> for ArcBuffer<T> {
     type Error = ScalarArcBuffer;
     fn try_from(buffer: ScalarArcBuffer) -> Result<Self, Self::Error> {
         match buffer.data.try_into() {
@@ -2034,7 +2333,7 @@ impl<T: Scalar> TryFrom<ScalarArcBuffer> for ArcBuffer<T> {
     }
 }
 
-impl<T: Scalar> From<Buffer<T>> for ArcBuffer<T> {
+impl<T: Pod> From<Buffer<T>> for ArcBuffer<T> {
     fn from(buffer: Buffer<T>) -> Self {
         Self {
             data: buffer.data.into(),
@@ -2042,7 +2341,37 @@ impl<T: Scalar> From<Buffer<T>> for ArcBuffer<T> {
     }
 }
 
-impl<'a, T: Scalar> From<Slice<'a, T>> for CowBuffer<'a, T> {
+impl<T: Pod> From<ArcBuffer<T>> for ArcSlice<T> {
+    fn from(buffer: ArcBuffer<T>) -> Self {
+        Self {
+            data: buffer.data.into(),
+        }
+    }
+}
+
+impl<T: Pod> ArcBuffer<T> {
+    /** A shared subslice with `range`.
+
+    The slice holds a reference to the buffer, so it can be sent to other threads and stored
+    without a lifetime. Returns None if range is out of bounds.
+
+    See [`.slice()`](BufferBase::slice). */
+    pub fn slice_shared(&self, range: impl RangeBounds<usize>) -> Option<ArcSlice<T>> {
+        ArcSlice::from(self.clone()).slice_shared(range)
+    }
+}
+
+impl<T: Pod> ArcSlice<T> {
+    /** A shared subslice with `range`.
+
+    See [`ArcBuffer::slice_shared()`]. */
+    pub fn slice_shared(&self, range: impl RangeBounds<usize>) -> Option<Self> {
+        let data = self.data.slice(range)?;
+        Some(Self { data })
+    }
+}
+
+impl<'a, T: Pod> From<Slice<'a, T>> for CowBuffer<'a, T> {
     fn from(slice: Slice<'a, T>) -> Self {
         Self {
             data: slice.data.into(),
@@ -2050,7 +2379,7 @@ impl<'a, T: Scalar> From<Slice<'a, T>> for CowBuffer<'a, T> {
     }
 }
 
-impl<T: Scalar> From<Buffer<T>> for CowBuffer<'_, T> {
+impl<T: Pod> From<Buffer<T>> for CowBuffer<'_, T> {
     fn from(buffer: Buffer<T>) -> Self {
         Self {
             data: buffer.data.into(),
@@ -2058,7 +2387,27 @@ impl<T: Scalar> From<Buffer<T>> for CowBuffer<'_, T> {
     }
 }
 
-impl<'a, T: Scalar> TryFrom<ScalarCowBuffer<'a>> for CowBuffer<'a, T> {
+impl<'a, T: Pod> CowBuffer<'a, T> {
+    /// Whether the buffer is a borrowed slice.
+    pub fn is_borrowed(&self) -> bool {
+        matches!(self.data, CowBufferRepr::Borrowed(_))
+    }
+    /// Whether the buffer is owned.
+    pub fn is_owned(&self) -> bool {
+        !self.is_borrowed()
+    }
+    /** Borrows as a mutable slice, copying a borrowed slice into an owned buffer first.
+
+    Like [`Cow::to_mut()`](::std::borrow::Cow::to_mut). Use
+    [`.into_owned()`](BufferBase::into_owned) to take the buffer, which only copies if borrowed.
+
+    See [`.make_slice_mut()`](BufferBase::make_slice_mut). */
+    pub fn to_mut(&mut self) -> Result<SliceMut<'_, T>> {
+        self.make_slice_mut()
+    }
+}
+
+impl<'a, T: Pod> TryFrom<ScalarCowBuffer<'a>> for CowBuffer<'a, T> {
     type Error = ScalarCowBuffer<'a>;
     fn try_from(buffer: ScalarCowBuffer<'a>) -> Result<Self, Self::Error> {
         match buffer.data.try_into() {
@@ -2068,17 +2417,32 @@ impl<'a, T: Scalar> TryFrom<ScalarCowBuffer<'a>> for CowBuffer<'a, T> {
     }
 }
 
-impl<T: Scalar, S: DataOwned<Elem = T>> Default for BufferBase<S> {
+impl<T: Pod, S: DataOwned<Elem = T>> Default for BufferBase<S> {
     fn default() -> Self {
-        Self::zeros(Device::host(), 0).unwrap()
+        Self::from_vec(Vec::new())
     }
 }
 
-impl<T: Scalar, S: DataOwned<Elem = T>> BufferBase<S> {
-    /// Allocate a buffer.
+impl<T: Pod, S: DataOwned<Elem = T>> BufferBase<S> {
+    /// Allocate a buffer, without initializing it.
+    ///
+    /// Avoids filling the buffer when every element will be overwritten, for example by a
+    /// kernel or [`.copy_from_slice()`](BufferBase::copy_from_slice).
+    /// ```
+    /// # use krnl::{anyhow::Result, buffer::{Buffer, Slice}, device::Device};
+    /// # fn main() -> Result<()> {
+    /// # let device = Device::host();
+    /// let x = vec![1f32, 2., 3.];
+    /// let mut y = unsafe { Buffer::<f32>::uninit(device, x.len())? };
+    /// y.copy_from_slice(&Slice::from(x.as_slice()))?;
+    /// # Ok(())
+    /// # }
+    /// ```
     ///
     /// # Safety
-    /// The buffer will not be initialized.
+    /// The elements are uninitialized. Every element must be written, by the host or a kernel,
+    /// before it is read. On the host, reading an uninitialized element is undefined behavior. On
+    /// a device, the buffer may contain data from a previous allocation.
     ///
     /// **Errors**
     ///
@@ -2091,6 +2455,63 @@ impl<T: Scalar, S: DataOwned<Elem = T>> BufferBase<S> {
         let data = S::from_buffer(unsafe { BufferRepr::uninit(device, len)? });
         Ok(Self { data })
     }
+    /** Allocate a buffer in pinned host memory.
+
+    Pinned buffers are on the device, but stored in host-visible memory. Transfers to and from
+    the host are copied directly, without staging through an internal buffer, and copies to
+    other buffers on the device use `vkCmdCopyBuffer`. Kernels can access them, but this is
+    slower than device memory.
+
+    Pinned buffers on the host or a device without Vulkan are allocated normally.
+
+    # Safety
+    The buffer will not be initialized.
+
+    **Errors**
+    See [`.uninit()`](BufferBase::uninit).
+
+    See [`.to_pinned()`](BufferBase::to_pinned) for a safe alternative. */
+    pub unsafe fn uninit_pinned(device: Device, len: usize) -> Result<Self> {
+        let data = S::from_buffer(unsafe { BufferRepr::uninit_pinned(device, len)? });
+        Ok(Self { data })
+    }
+    /// Create a buffer from a [`Vec`].
+    pub fn from_vec(vec: Vec<T>) -> Self {
+        let data = S::from_buffer(BufferRepr::from_vec(vec));
+        Self { data }
+    }
+    /// Create a buffer from a [`Buffer`].
+    pub fn from_buffer(buffer: Buffer<T>) -> Self {
+        let data = S::from_buffer(buffer.data);
+        Self { data }
+    }
+    /** Concatenates `slices` into a buffer on `device`.
+
+    The output is allocated once, and each slice is copied into it at its offset. Slices on
+    `device` are copied with `vkCmdCopyBuffer`, without a round trip to the host. Slices on other
+    devices are transferred.
+
+    **Errors**
+    See [`.uninit()`](BufferBase::uninit) and
+    [`.copy_from_slice()`](BufferBase::copy_from_slice).
+    */
+    pub fn concat(device: Device, slices: &[Slice<T>]) -> Result<Self> {
+        let len = slices.iter().map(|x| x.len()).sum();
+        let mut output = unsafe { Buffer::uninit(device, len)? };
+        let mut offset = 0;
+        for slice in slices {
+            let end = offset + slice.len();
+            output
+                .slice_mut(offset..end)
+                .unwrap()
+                .transfer_from_slice(slice)?;
+            offset = end;
+        }
+        Ok(Self::from_buffer(output))
+    }
+}
+
+impl<T: Scalar, S: DataOwned<Elem = T>> BufferBase<S> {
     /** Create a buffer filled with `elem`
 
     **Errors**
@@ -2109,29 +2530,24 @@ impl<T: Scalar, S: DataOwned<Elem = T>> BufferBase<S> {
     }
     /** Create a buffer filled with 0's.
 
+    On device, the buffer is filled with `vkCmdFillBuffer` without copying from the host.
+
     See [`from_elem`](BufferBase::from_elem). */
     pub fn zeros(device: Device, len: usize) -> Result<Self> {
         Self::from_elem(device, len, T::zero())
     }
     /** Create a buffer filled with 1's.
 
+    On device, buffers of types up to 32 bits are filled with `vkCmdFillBuffer` without copying
+    from the host.
+
     See [`from_elem`](BufferBase::from_elem). */
     pub fn ones(device: Device, len: usize) -> Result<Self> {
         Self::from_elem(device, len, T::one())
     }
-    /// Create a buffer from a [`Vec`].
-    pub fn from_vec(vec: Vec<T>) -> Self {
-        let data = S::from_buffer(BufferRepr::from_vec(vec));
-        Self { data }
-    }
-    /// Create a buffer from a [`Buffer`].
-    pub fn from_buffer(buffer: Buffer<T>) -> Self {
-        let data = S::from_buffer(buffer.data);
-        Self { data }
-    }
 }
 
-impl<'a, T: Scalar> Slice<'a, T> {
+impl<'a, T: Pod> Slice<'a, T> {
     /// Create a slice from a `&[T]`.
     pub fn from_host_slice(host_slice: &'a [T]) -> Self {
         let data = SliceRepr::from_host_slice(host_slice);
@@ -2139,7 +2555,57 @@ impl<'a, T: Scalar> Slice<'a, T> {
     }
 }
 
-impl<'a, T: Scalar> SliceMut<'a, T> {
+#[cfg(feature = "mmap")]
+impl<T: Pod> MmapBuffer<T> {
+    /** Maps the file at `path` into memory.
+
+    The file is read lazily by the OS as pages are accessed, without first copying it into
+    memory. Copying to a device with [`.to_device()`](BufferBase::to_device) uploads the data
+    in chunks, such that only a small portion of the file is resident at a time. Use
+    [`.slice()`](BufferBase::slice) to upload a portion of the file.
+
+    ```
+    # use krnl::{anyhow::Result, buffer::MmapBuffer, device::Device};
+    # fn main() -> Result<()> {
+    # let path = std::env::temp_dir().join("krnl-mmap-doctest.bin");
+    # std::fs::write(&path, bytemuck::cast_slice::<f32, u8>(&[1., 2., 3.]))?;
+    let x = unsafe { MmapBuffer::<f32>::from_mmap(&path)? };
+    # let device = Device::host();
+    let y = x.slice(1..).unwrap().to_device(device)?;
+    assert_eq!(y.into_vec()?, [2., 3.]);
+    # Ok(())
+    # }
+    ```
+
+    # Safety
+    The file must not be modified or truncated while the buffer, or any slice of it, is alive.
+    See [`memmap2::Mmap::map`].
+
+    **Errors**
+    - The file could not be opened or mapped.
+    - The length of the file is not a multiple of the size of `T`.
+    */
+    pub unsafe fn from_mmap(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = std::fs::File::open(path)?;
+        let mmap = unsafe { memmap2::Mmap::map(&file)? };
+        let width = size_of::<T>();
+        if mmap.len() % width != 0 {
+            bail!(
+                "Unable to map {path:?} as {}, length {} is not a multiple of {width}!",
+                T::STORAGE_TYPE.name(),
+                mmap.len(),
+            );
+        }
+        let data = MmapRepr {
+            mmap: Arc::new(mmap),
+            _m: PhantomData,
+        };
+        Ok(Self { data })
+    }
+}
+
+impl<'a, T: Pod> SliceMut<'a, T> {
     /// Create a mutable slice from a `&mut [T]`.
     pub fn from_host_slice_mut(host_slice: &'a mut [T]) -> Self {
         let data = SliceMutRepr::from_host_slice_mut(host_slice);
@@ -2147,30 +2613,61 @@ impl<'a, T: Scalar> SliceMut<'a, T> {
     }
 }
 
-impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
+impl<T: Pod, S: Data<Elem = T>> BufferBase<S> {
     /// The device.
     pub fn device(&self) -> Device {
         self.data.device()
     }
-    /// The scalar type.
+    /// The scalar type, or the storage type of [`Pod`] structs.
     pub fn scalar_type(&self) -> ScalarType {
         self.data.scalar_type()
     }
     /// The length.
     pub fn len(&self) -> usize {
-        self.data.len()
+        self.data.as_slice().raw.len() / size_of::<T>()
     }
     /// Is the buffer empty.
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
+    /** The offset of a device slice in its buffer, in elements.
+
+    Slices share the allocation of the buffer, and are passed to kernels as an offset and a
+    length. Buffers, and host slices, have an offset of 0.
+
+    See [`.alignment()`](BufferBase::alignment). */
+    pub fn offset(&self) -> usize {
+        self.data.as_slice().raw.offset() / size_of::<T>()
+    }
+    /** The alignment of the start of the buffer in bytes, up to 256.
+
+    Device buffers are allocated with an alignment of 256 bytes, so the alignment of a device
+    slice is determined by its [offset](BufferBase::offset). For example, kernels using
+    16 byte loads of `f32`'s need slices with offsets that are a multiple of 4. Host buffers are
+    aligned to the size of `T`.
+    ```
+    # use krnl::{anyhow::Result, buffer::Buffer, device::Device};
+    # fn main() -> Result<()> {
+    # let device = Device::host();
+    let x = Buffer::<f32>::zeros(device, 16)?;
+    let y = x.slice(4..).unwrap();
+    if y.device().is_device() {
+        assert_eq!(y.offset(), 4);
+        assert_eq!(y.alignment(), 16);
+    }
+    # Ok(())
+    # }
+    ``` */
+    pub fn alignment(&self) -> usize {
+        self.data.as_slice().raw.alignment()
+    }
     /// Borrow as a slice.
-    pub fn as_slice(&self) -> Slice<T> {
+    pub fn as_slice(&self) -> Slice<'_, T> {
         let data = self.data.as_slice();
         Slice { data }
     }
     /// Borrow as a mutable slice.
-    pub fn as_slice_mut(&mut self) -> SliceMut<T>
+    pub fn as_slice_mut(&mut self) -> SliceMut<'_, T>
     where
         S: DataMut,
     {
@@ -2178,13 +2675,13 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
         SliceMut { data }
     }
     /// Borrows as a mutable slice if possible.
-    pub fn get_slice_mut(&mut self) -> Option<SliceMut<T>> {
+    pub fn get_slice_mut(&mut self) -> Option<SliceMut<'_, T>> {
         Some(SliceMut {
             data: self.data.get_slice_mut()?,
         })
     }
     /// Borrows as a mutable slice, cloning if necessary.
-    pub fn make_slice_mut(&mut self) -> Result<SliceMut<T>>
+    pub fn make_slice_mut(&mut self) -> Result<SliceMut<'_, T>>
     where
         S: DataOwned,
     {
@@ -2192,6 +2689,20 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
             data: self.data.make_slice_mut()?,
         })
     }
+    /** The raw Vulkan buffer, for interop with [ash].
+
+    Returns None if not on a Vulkan device, or if empty. See
+    [`Device::vulkan_handles()`](crate::device::Device::vulkan_handles).
+
+    # Safety
+    The buffer must not be destroyed, and must not be used after the slice is dropped. Commands
+    must be synchronized with krnl, ie [`Device::wait()`](crate::device::Device::wait) before
+    accessing the buffer, and writes must complete before the buffer is used by krnl.
+    */
+    #[cfg(all(not(target_arch = "wasm32"), feature = "vulkan-interop"))]
+    pub unsafe fn vulkan_buffer(&self) -> Option<crate::device::VulkanBuffer> {
+        self.as_scalar_slice().device_buffer()?.vulkan_buffer()
+    }
     /** Borrow as a host slice.
 
     Returns [`None`] if not on the host. */
@@ -2207,13 +2718,64 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
     {
         self.data.as_host_slice_mut()
     }
+    /** Borrow as a host slice, mapping device memory if possible.
+
+    Device buffers in host visible memory, see
+    [`DeviceBuilder::host_mapped`](crate::device::builder::DeviceBuilder::host_mapped)
+    and [`.uninit_pinned()`](BufferBase::uninit_pinned), are accessed without a copy, after
+    waiting for the device to finish writing to them.
+
+    Returns [`None`] if on a device and not mapped.
+
+    **Errors**
+    - [`DeviceLost`] */
+    pub fn as_mapped_slice(&self) -> Result<Option<&[T]>> {
+        if self.device().is_host() {
+            return Ok(self.as_host_slice());
+        }
+        #[cfg(feature = "device")]
+        {
+            let slice = self.as_scalar_slice();
+            let buffer = slice.device_buffer().unwrap();
+            if let Some(ptr) = buffer.mapped_ptr(false)? {
+                let slice =
+                    unsafe { std::slice::from_raw_parts(ptr.as_ptr() as *const T, self.len()) };
+                return Ok(Some(slice));
+            }
+        }
+        Ok(None)
+    }
+    /** Borrow as a mutable host slice, mapping device memory if possible.
+
+    Waits for the device to finish reading and writing to the buffer.
+
+    See [`.as_mapped_slice()`](BufferBase::as_mapped_slice). */
+    pub fn as_mapped_slice_mut(&mut self) -> Result<Option<&mut [T]>>
+    where
+        S: DataMut,
+    {
+        if self.device().is_host() {
+            return Ok(self.as_host_slice_mut());
+        }
+        #[cfg(feature = "device")]
+        {
+            let len = self.len();
+            let slice = self.as_scalar_slice_mut();
+            let buffer = slice.device_buffer_mut().unwrap();
+            if let Some(ptr) = buffer.mapped_ptr(true)? {
+                let slice = unsafe { std::slice::from_raw_parts_mut(ptr.as_ptr() as *mut T, len) };
+                return Ok(Some(slice));
+            }
+        }
+        Ok(None)
+    }
     /// Borrow as a scalar slice.
-    pub fn as_scalar_slice(&self) -> ScalarSlice {
+    pub fn as_scalar_slice(&self) -> ScalarSlice<'_> {
         let data = self.data.as_scalar_slice();
         ScalarSlice { data }
     }
     /// Borrow as a mutable scalar slice.
-    pub fn as_scalar_slice_mut(&mut self) -> ScalarSliceMut
+    pub fn as_scalar_slice_mut(&mut self) -> ScalarSliceMut<'_>
     where
         S: DataMut,
     {
@@ -2237,7 +2799,7 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
     - [`OutOfDeviceMemory`]
     - Could not dispatch the kernel. */
     pub fn to_owned(&self) -> Result<Buffer<T>> {
-        self.cast()
+        self.to_device(self.device())
     }
     /** Moves into an arc buffer.
 
@@ -2311,6 +2873,47 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
             self.to_device(device).map(Into::into)
         }
     }
+    /** Whether the buffer is in pinned host memory.
+
+    See [`.uninit_pinned()`](BufferBase::uninit_pinned). */
+    pub fn is_pinned(&self) -> bool {
+        #[cfg(feature = "device")]
+        {
+            self.as_scalar_slice()
+                .device_buffer()
+                .is_some_and(|buffer| buffer.pinned())
+        }
+        #[cfg(not(feature = "device"))]
+        {
+            false
+        }
+    }
+    /** Copies into pinned host memory on `device`.
+
+    See [`.uninit_pinned()`](BufferBase::uninit_pinned).
+
+    **Errors**
+    See [`.to_device()`](BufferBase::to_device). */
+    pub fn to_pinned(&self, device: Device) -> Result<Buffer<T>> {
+        let mut output = unsafe { Buffer::uninit_pinned(device, self.len())? };
+        output.copy_from_slice(&self.as_slice())?;
+        Ok(output)
+    }
+    /** Moves into pinned host memory on `device`.
+
+    Avoids copying if already pinned on `device`.
+
+    See [`.to_pinned()`](BufferBase::to_pinned). */
+    pub fn into_pinned(self, device: Device) -> Result<Buffer<T>> {
+        if self.is_pinned() && device == self.device() {
+            match self.data.try_into_buffer() {
+                Ok(data) => Ok(Buffer { data }),
+                Err(data) => Self { data }.to_pinned(device),
+            }
+        } else {
+            self.to_pinned(device)
+        }
+    }
     /** Moves into a [`Vec`].
 
     See [`.into_device()`](BufferBase::into_device) */
@@ -2326,8 +2929,222 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
     pub fn to_vec(&self) -> Result<Vec<T>> {
         self.data.as_slice().to_vec()
     }
+    /** Copies to a [`Vec`] asynchronously.
+
+    Device buffers are copied into [pinned](BufferBase::uninit_pinned) memory with
+    `vkCmdCopyBuffer`, which is batched with other work on the device. The future waits for the
+    copy without blocking, so that kernels dispatched before it is awaited can overlap with the
+    download.
+
+    ```no_run
+    # use krnl::{anyhow::Result, buffer::Buffer, device::Device};
+    # async fn run() -> Result<()> {
+    let device = Device::builder().build()?;
+    let x = Buffer::<f32>::ones(device.clone(), 1_000)?;
+    let mut y = Buffer::<f32>::zeros(device.clone(), 1_000)?;
+    let x_future = x.to_vec_async();
+    y.fill(2f32)?;
+    let x = x_future.await?;
+    # Ok(())
+    # }
+    ```
+
+    See [`.to_vec()`](BufferBase::to_vec). */
+    pub fn to_vec_async(&self) -> impl Future<Output = Result<Vec<T>>> + Send + 'static {
+        let device = self.device();
+        let staged = if device.is_host() {
+            self.to_owned().map(|buffer| (buffer, None))
+        } else {
+            self.to_pinned(device.clone())
+                .map(|buffer| (buffer, Some(device.wait_async())))
+        };
+        async move {
+            let (buffer, wait) = staged?;
+            if let Some(wait) = wait {
+                wait.await?;
+            }
+            buffer.into_vec()
+        }
+    }
+    /** Moves into the device asynchronously.
+
+    Uploads are copied into [pinned](BufferBase::uninit_pinned) memory on the host, and then
+    into the device with `vkCmdCopyBuffer`, without waiting for the device. Downloads are
+    performed with [`.to_vec_async()`](BufferBase::to_vec_async). Transfers between devices are
+    not asynchronous.
+
+    See [`.into_device()`](BufferBase::into_device). */
+    pub fn into_device_async(
+        self,
+        device: Device,
+    ) -> impl Future<Output = Result<Buffer<T>>> + Send + 'static {
+        let this_device = self.device();
+        let staged = if this_device.is_device() && device.is_host() {
+            self.to_pinned(this_device.clone())
+                .map(|buffer| (buffer, Some(this_device.wait_async())))
+        } else if this_device.is_host() && device.is_device() {
+            self.to_pinned(device.clone()).and_then(|pinned| {
+                let mut output = unsafe { Buffer::uninit(device, pinned.len())? };
+                output.copy_from_slice(&pinned.as_slice())?;
+                Ok((output, None))
+            })
+        } else {
+            self.into_device(device).map(|buffer| (buffer, None))
+        };
+        async move {
+            let (buffer, wait) = staged?;
+            if let Some(wait) = wait {
+                wait.await?;
+                return buffer.into_vec().map(Buffer::from);
+            }
+            Ok(buffer)
+        }
+    }
+    /** Reinterpret as a slice with type `Y`.
+
+    See [`bytemuck::cast_slice`]. */
+    pub fn bitcast<Y: Scalar>(&self) -> Result<Slice<'_, Y>, bytemuck::PodCastError> {
+        let data = self.data.as_slice().bitcast()?;
+        Ok(Slice { data })
+    }
+    /** Reinterpret as a mutable slice with type `Y`.
+
+    See [`bytemuck::cast_slice_mut`]. */
+    pub fn bitcast_mut<Y: Scalar>(&mut self) -> Result<SliceMut<'_, Y>, bytemuck::PodCastError>
+    where
+        S: DataMut,
+    {
+        let data = self.data.as_slice_mut().bitcast()?;
+        Ok(SliceMut { data })
+    }
+    /** Copies from src.
+
+    Device slices on the same device are copied with `vkCmdCopyBuffer`. Host slices are copied
+    with `memcpy`, or uploaded and downloaded to and from a device.
+
+    **Errors**
+    - `src` is not the same length.
+    - `src` is on a different device, use [`.to_device()`](BufferBase::to_device) to transfer
+      between devices.
+    - [`DeviceLost`]
+    - The kernel could not be dispatched.
+    */
+    pub fn copy_from_slice(&mut self, src: &Slice<T>) -> Result<()>
+    where
+        S: DataMut,
+    {
+        let device = self.device();
+        let src_device = src.device();
+        if device.is_device() && src_device.is_device() && device != src_device {
+            bail!("Cannot copy from {src_device:?} to {device:?}, use `.to_device()` to transfer between devices!");
+        }
+        self.transfer_from_slice(src)
+    }
+    /// Copies from src, which may be on another device.
+    pub(crate) fn transfer_from_slice(&mut self, src: &Slice<T>) -> Result<()>
+    where
+        S: DataMut,
+    {
+        self.data.as_slice_mut().copy_from_slice(&src.data)
+    }
+    /** Copies from a host slice.
+
+    Uploads into the existing buffer without allocating, so a buffer can be reused, for example
+    to double buffer inputs.
+
+    See [`.copy_from_slice()`](BufferBase::copy_from_slice). */
+    pub fn copy_from_host_slice(&mut self, src: &[T]) -> Result<()>
+    where
+        S: DataMut,
+    {
+        self.copy_from_slice(&Slice::from_host_slice(src))
+    }
+    /** A subslice with `range`.
+
+    Returns None if range is out of bounds.
+
+    See [`<[_]>::get()`](https://doc.rust-lang.org/std/primitive.slice.html#method.get). */
+    pub fn slice(&self, range: impl RangeBounds<usize>) -> Option<Slice<'_, T>> {
+        let data = self.data.as_slice().slice(range)?;
+        Some(Slice { data })
+    }
+    /** A mutable subslice with `range`.
+
+    Returns None if range is out of bounds.
+
+    See [`<[_]>::get_mut()`](https://doc.rust-lang.org/std/primitive.slice.html#method.get_mut). */
+    pub fn slice_mut(&mut self, range: impl RangeBounds<usize>) -> Option<SliceMut<'_, T>>
+    where
+        S: DataMut,
+    {
+        let data = self.data.as_slice_mut().slice(range)?;
+        Some(SliceMut { data })
+    }
+    /** Divides into two slices at `mid`.
+
+    The first contains `[0, mid)` and the second `[mid, len)`. Returns None if `mid` is greater
+    than the length.
+
+    See [`<[_]>::split_at()`](https://doc.rust-lang.org/std/primitive.slice.html#method.split_at). */
+    pub fn split_at(&self, mid: usize) -> Option<(Slice<'_, T>, Slice<'_, T>)> {
+        let (a, b) = self.data.as_slice().split_at(mid)?;
+        Some((Slice { data: a }, Slice { data: b }))
+    }
+    /** Divides into two mutable slices at `mid`.
+
+    See [`.split_at()`](BufferBase::split_at). */
+    pub fn split_at_mut(&mut self, mid: usize) -> Option<(SliceMut<'_, T>, SliceMut<'_, T>)>
+    where
+        S: DataMut,
+    {
+        let (a, b) = self.data.as_slice_mut().split_at(mid)?;
+        Some((SliceMut { data: a }, SliceMut { data: b }))
+    }
+    /** Iterates over slices of `chunk_size` elements.
+
+    The last chunk may be shorter.
+
+    **Panics**
+    - `chunk_size` is 0.
+
+    See [`<[_]>::chunks()`](https://doc.rust-lang.org/std/primitive.slice.html#method.chunks). */
+    pub fn chunks(&self, chunk_size: usize) -> impl Iterator<Item = Slice<'_, T>> + '_ {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        let mut rest = Some(self.data.as_slice());
+        std::iter::from_fn(move || {
+            let slice = rest.take().filter(|x| !x.is_empty())?;
+            let mid = chunk_size.min(slice.len());
+            let (a, b) = slice.split_at(mid)?;
+            rest.replace(b);
+            Some(Slice { data: a })
+        })
+    }
+    /** Iterates over mutable slices of `chunk_size` elements.
+
+    See [`.chunks()`](BufferBase::chunks). */
+    pub fn chunks_mut(&mut self, chunk_size: usize) -> impl Iterator<Item = SliceMut<'_, T>> + '_
+    where
+        S: DataMut,
+    {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        let mut rest = Some(self.data.as_slice_mut());
+        std::iter::from_fn(move || {
+            let slice = rest.take().filter(|x| !x.is_empty())?;
+            let mid = chunk_size.min(slice.len());
+            let (a, b) = slice.split_at(mid)?;
+            rest.replace(b);
+            Some(SliceMut { data: a })
+        })
+    }
+}
+
+impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
     /** Fills with `elem`.
 
+    On device, slices aligned to 4 bytes are filled with `vkCmdFillBuffer`, otherwise with a kernel.
+    Buffers larger than the [max allocation size](crate::device::DeviceInfo::max_allocation_size)
+    are filled per allocation.
+
     **Errors**
     - [`DeviceLost`]
     - The kernel could not be dispatched.
@@ -2362,11 +3179,36 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
                 }
                 y
             }
+            let chunk_ranges = self
+                .as_scalar_slice()
+                .device_buffer()
+                .filter(|buffer| buffer.chunked())
+                .map(|buffer| buffer.chunk_ranges());
+            if let Some(chunk_ranges) = chunk_ranges {
+                let width = size_of::<T>();
+                for range in chunk_ranges {
+                    self.slice_mut(range.start / width..range.end / width)
+                        .unwrap()
+                        .fill(elem)?;
+                }
+                return Ok(());
+            }
+            let x: u64 = copied_bytes(elem);
+            let [x1, x2]: [u32; 2] = bytemuck::cast(x);
+            if x1 == x2 {
+                if let RawSliceInner::Device(buffer) = &self.data.as_slice_mut().raw.inner {
+                    if buffer.fill(x1)? {
+                        return Ok(());
+                    }
+                }
+            }
             let device = self.device();
             let features = device.info().unwrap().features();
-            if let Ok(y) = self.bitcast_mut::<u64>() {
-                let x = copied_bytes(elem);
-                return kernels::fill_u64::builder()?.build(device)?.dispatch(x, y);
+            if features.shader_int64() {
+                if let Ok(y) = self.bitcast_mut::<u64>() {
+                    let x = copied_bytes(elem);
+                    return kernels::fill_u64::builder()?.build(device)?.dispatch(x, y);
+                }
             }
             if let Ok(y) = self.bitcast_mut::<u32>() {
                 let x = copied_bytes(elem);
@@ -2393,6 +3235,19 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
     }
     /** Casts to `Y`.
 
+    On the host, conversions between [`f16`](struct@f16) or [`bf16`] and `f32` or `f64` use the vectorized
+    slice conversions of [`half`], for mixed precision:
+    ```
+    # use krnl::{anyhow::Result, buffer::Buffer, half::f16};
+    # fn main() -> Result<()> {
+    let x = Buffer::from(vec![1f32, 0.5, -2.]);
+    let y = x.cast::<f16>()?;
+    assert_eq!(y.cast::<f32>()?.into_vec()?, [1., 0.5, -2.]);
+    # Ok(())
+    # }
+    ```
+    On a device, a kernel is dispatched.
+
     **Errors**
     - DeviceLost
     - The kernel could not be dispatched.
@@ -2424,57 +3279,6 @@ impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
             self.cast().map(Into::into)
         }
     }
-    /** Reinterpret as a slice with type `Y`.
-
-    See [`bytemuck::cast_slice`]. */
-    pub fn bitcast<Y: Scalar>(&self) -> Result<Slice<Y>, bytemuck::PodCastError> {
-        let data = self.data.as_slice().bitcast()?;
-        Ok(Slice { data })
-    }
-    /** Reinterpret as a mutable slice with type `Y`.
-
-    See [`bytemuck::cast_slice_mut`]. */
-    pub fn bitcast_mut<Y: Scalar>(&mut self) -> Result<SliceMut<Y>, bytemuck::PodCastError>
-    where
-        S: DataMut,
-    {
-        let data = self.data.as_slice_mut().bitcast()?;
-        Ok(SliceMut { data })
-    }
-    /** Copies from src.
-
-    **Errors**
-    - `src` is not the same length.
-    - [`DeviceLost`]
-    - The kernel could not be dispatched.
-    */
-    pub fn copy_from_slice(&mut self, src: &Slice<T>) -> Result<()>
-    where
-        S: DataMut,
-    {
-        self.data.as_slice_mut().copy_from_slice(&src.data)
-    }
-    /** A subslice with `range`.
-
-    Returns None if range is out of bounds.
-
-    See [`<[_]>::get()`](https://doc.rust-lang.org/std/primitive.slice.html#method.get). */
-    pub fn slice(&self, range: impl RangeBounds<usize>) -> Option<Slice<T>> {
-        let data = self.data.as_slice().slice(range)?;
-        Some(Slice { data })
-    }
-    /** A mutable subslice with `range`.
-
-    Returns None if range is out of bounds.
-
-    See [`<[_]>::get_mut()`](https://doc.rust-lang.org/std/primitive.slice.html#method.get_mut). */
-    pub fn slice_mut(&mut self, range: impl RangeBounds<usize>) -> Option<SliceMut<T>>
-    where
-        S: DataMut,
-    {
-        let data = self.data.as_slice_mut().slice(range)?;
-        Some(SliceMut { data })
-    }
 }
 
 impl<T: Scalar> Slice<'_, T> {
@@ -2502,6 +3306,22 @@ impl<T: Scalar> Slice<'_, T> {
                     bytemuck::cast_slice::<_, bf16>(x)
                         .convert_to_f64_slice(bytemuck::cast_slice_mut(y));
                     return Ok(());
+                } else if T::SCALAR_TYPE == ScalarType::F32 && Y::SCALAR_TYPE == ScalarType::F16 {
+                    bytemuck::cast_slice_mut::<_, f16>(y)
+                        .convert_from_f32_slice(bytemuck::cast_slice(x));
+                    return Ok(());
+                } else if T::SCALAR_TYPE == ScalarType::F32 && Y::SCALAR_TYPE == ScalarType::BF16 {
+                    bytemuck::cast_slice_mut::<_, bf16>(y)
+                        .convert_from_f32_slice(bytemuck::cast_slice(x));
+                    return Ok(());
+                } else if T::SCALAR_TYPE == ScalarType::F64 && Y::SCALAR_TYPE == ScalarType::F16 {
+                    bytemuck::cast_slice_mut::<_, f16>(y)
+                        .convert_from_f64_slice(bytemuck::cast_slice(x));
+                    return Ok(());
+                } else if T::SCALAR_TYPE == ScalarType::F64 && Y::SCALAR_TYPE == ScalarType::BF16 {
+                    bytemuck::cast_slice_mut::<_, bf16>(y)
+                        .convert_from_f64_slice(bytemuck::cast_slice(x));
+                    return Ok(());
                 }
             }
             for (x, y) in x.iter().zip(y.iter_mut()) {
@@ -2522,6 +3342,13 @@ impl<T: Scalar> Slice<'_, T> {
 
 #[cfg(feature = "device")]
 fn device_scalar_buffer_cast_impl(x: ScalarSlice, y: ScalarSliceMut) -> Result<()> {
+    if x.scalar_type() == y.scalar_type() {
+        if let Some((x, y)) = x.device_buffer().zip(y.device_buffer_mut()) {
+            if x.copy(y)? {
+                return Ok(());
+            }
+        }
+    }
     macro_for!($X in [u8, i8, u16, i16, f16, bf16, u32, i32, f32, u64, i64, f64] {
         let x = match Slice::<$X>::try_from(x) {
             Ok(x) => {
@@ -2546,8 +3373,86 @@ fn device_scalar_buffer_cast_impl(x: ScalarSlice, y: ScalarSliceMut) -> Result<(
     unreachable!()
 }
 
+impl<T: Pod, S: Data<Elem = T>> BufferBase<S> {
+    /** Formats with the first and last elements.
+
+    [`Debug`] only shows the elements of host buffers. This copies up to
+    6 elements of device buffers to the host, which can be printed in tests or logs.
+    ```
+    # use krnl::{anyhow::Result, buffer::Buffer};
+    # fn main() -> Result<()> {
+    let x = Buffer::from((0..10).collect::<Vec<u32>>());
+    assert_eq!(
+        format!("{:?}", x.to_debug()?),
+        "BufferBase { device: Host, scalar_type: U32, len: 10, data: [0, 1, 2, ..., 7, 8, 9] }"
+    );
+    # Ok(())
+    # }
+    ```
+
+    **Errors**
+    See [`.to_vec()`](BufferBase::to_vec). */
+    pub fn to_debug(&self) -> Result<impl Debug + Send + Sync + 'static> {
+        let len = self.len();
+        let (head, tail) = if len > 2 * DEBUG_EDGE_ITEMS {
+            (
+                self.slice(..DEBUG_EDGE_ITEMS).unwrap().to_vec()?,
+                self.slice(len - DEBUG_EDGE_ITEMS..).unwrap().to_vec()?,
+            )
+        } else {
+            (self.to_vec()?, Vec::new())
+        };
+        Ok(BufferDebug {
+            device: self.device(),
+            len,
+            head,
+            tail,
+        })
+    }
+}
+
+// The number of elements shown at the start and end of a buffer.
+const DEBUG_EDGE_ITEMS: usize = 3;
+
+struct BufferDebug<T> {
+    device: Device,
+    len: usize,
+    head: Vec<T>,
+    tail: Vec<T>,
+}
+
+impl<T: Pod> Debug for BufferDebug<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        struct Elems<'a, T>(&'a [T], &'a [T]);
+
+        impl<T: Debug> Debug for Elems<'_, T> {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                let mut list = f.debug_list();
+                list.entries(self.0);
+                if !self.1.is_empty() {
+                    list.entry(&format_args!("..."));
+                    list.entries(self.1);
+                }
+                list.finish()
+            }
+        }
+
+        f.debug_struct("BufferBase")
+            .field("device", &self.device)
+            .field("scalar_type", &T::STORAGE_TYPE)
+            .field("len", &self.len)
+            .field("data", &Elems(&self.head, &self.tail))
+            .finish()
+    }
+}
+
 impl<S: Data> Debug for BufferBase<S> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.device().is_host() {
+            if let Ok(debug) = self.to_debug() {
+                return debug.fmt(f);
+            }
+        }
         f.debug_struct("BufferBase")
             .field("device", &self.device())
             .field("scalar_type", &self.scalar_type())
@@ -2567,7 +3472,7 @@ impl<S1: Data> Serialize for BufferBase<S1> {
 }
 
 #[cfg(feature = "serde")]
-impl<'de, T: Scalar, S: DataOwned<Elem = T>> Deserialize<'de> for BufferBase<S> {
+impl<'de, T: Pod, S: DataOwned<Elem = T>> Deserialize<'de> for BufferBase<S> {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: Deserializer<'de>,
@@ -2577,7 +3482,7 @@ impl<'de, T: Scalar, S: DataOwned<Elem = T>> Deserialize<'de> for BufferBase<S>
         let buffer = BufferRepr::try_from(scalar_buffer).map_err(|e| {
             D::Error::custom(format!(
                 "Expected {:?}, found {:?}!",
-                T::SCALAR_TYPE,
+                T::STORAGE_TYPE,
                 e.scalar_type()
             ))
         })?;
@@ -2590,7 +3495,7 @@ impl<'de, T: Scalar, S: DataOwned<Elem = T>> Deserialize<'de> for BufferBase<S>
 #[cfg(feature = "device")]
 #[module]
 #[krnl(crate=crate)]
-mod kernels {
+pub(crate) mod kernels {
     use dry::macro_for;
     #[cfg(not(target_arch = "spirv"))]
     use krnl_core;
@@ -2690,4 +3595,34 @@ mod tests {
             .unwrap();
         assert_eq!(x_vec, y_vec);
     }
+
+    #[test]
+    fn scalar_buffer_serde_json() {
+        let x_vec = vec![f16::from_f32(1.0), f16::from_f32(-2.5)];
+        let x = ScalarBuffer::from(Buffer::from_vec(x_vec.clone()));
+        let string = serde_json::to_string(&x).unwrap();
+        let y = serde_json::from_str::<ScalarBuffer>(&string).unwrap();
+        assert_eq!(y.scalar_type(), ScalarType::F16);
+        let y_vec = Buffer::<f16>::try_from(y).ok().unwrap().into_vec().unwrap();
+        assert_eq!(x_vec, y_vec);
+        assert!(serde_json::from_str::<Buffer<u16>>(&string).is_err());
+    }
+
+    #[test]
+    fn buffer_serde_padded() {
+        let x_vec = vec![1u8, 2, 3];
+        let bytes = bincode2::serialize(&Buffer::from_vec(x_vec.clone())).unwrap();
+        let y_vec = bincode2::deserialize::<Buffer<u8>>(&bytes)
+            .unwrap()
+            .into_vec()
+            .unwrap();
+        assert_eq!(x_vec, y_vec);
+        let x_vec = vec![1f64, -2.0];
+        let string = serde_json::to_string(&Buffer::from_vec(x_vec.clone())).unwrap();
+        let y_vec = serde_json::from_str::<Buffer<f64>>(&string)
+            .unwrap()
+            .into_vec()
+            .unwrap();
+        assert_eq!(x_vec, y_vec);
+    }
 }
@@ -0,0 +1,189 @@
+use super::{Buffer, Slice, SliceMut};
+use crate::{device::Device, scalar::Scalar};
+use anyhow::{bail, Result};
+use std::ops::Range;
+
+/** Streams a buffer through a device in chunks.
+
+Inputs larger than device memory can be processed by copying bounded-size chunks to the device,
+calling a fn to dispatch kernels on each chunk, and copying the outputs back:
+```no_run
+# use krnl::{anyhow::Result, buffer::{Buffer, ChunkedMap}, device::Device};
+# fn main() -> Result<()> {
+let device = Device::builder().build()?;
+let x = Buffer::from(vec![1f32; 100_000_000]);
+let y = ChunkedMap::new(device, 1_000_000)?.map(x.as_slice(), |_range, x, mut y| {
+    // x and y are on the device, dispatch kernels here
+    // _range is the range of the chunk in the input
+    y.copy_from_slice(&x)
+})?;
+assert_eq!(y.len(), x.len());
+# Ok(())
+# }
+```
+Chunks are double buffered, such that chunk N + 1 is uploaded while the device computes chunk N
+and chunk N - 1 is downloaded. Only 2 chunks of the input and output are allocated on the device.
+
+The input is typically on the host, for example a `MmapBuffer` (with the "mmap" feature) for
+datasets that don't fit in host memory.
+*/
+#[derive(Clone, Debug)]
+pub struct ChunkedMap {
+    device: Device,
+    chunk_len: usize,
+}
+
+impl ChunkedMap {
+    /** Creates a pipeline on `device` with chunks of `chunk_len` items.
+
+    **Errors**
+    - `chunk_len` is 0.
+    */
+    pub fn new(device: Device, chunk_len: usize) -> Result<Self> {
+        if chunk_len == 0 {
+            bail!("ChunkedMap chunk_len must be greater than 0!");
+        }
+        Ok(Self { device, chunk_len })
+    }
+    /// The device.
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+    /// The number of items per chunk. The last chunk may be shorter.
+    pub fn chunk_len(&self) -> usize {
+        self.chunk_len
+    }
+    /** Maps `x` with `f`, returning the output on the device of `x`.
+
+    See [`.map_into()`](ChunkedMap::map_into). */
+    pub fn map<T, Y, F>(&self, x: Slice<T>, f: F) -> Result<Buffer<Y>>
+    where
+        T: Scalar,
+        Y: Scalar,
+        F: FnMut(Range<usize>, Slice<T>, SliceMut<Y>) -> Result<()>,
+    {
+        let mut y = unsafe { Buffer::uninit(x.device(), x.len())? };
+        self.map_into(x, y.as_slice_mut(), f)?;
+        Ok(y)
+    }
+    /** Maps `x` into `y` with `f`.
+
+    `f` is called for each chunk, in order, with the range of the chunk and the chunk of `x` and `y`
+    on the device. `f` should dispatch kernels writing to the chunk of `y`, without waiting.
+
+    **Errors**
+    - `x` and `y` are not the same length.
+    - Errors returned by `f`.
+    - See [`.copy_from_slice()`](super::BufferBase::copy_from_slice).
+    */
+    pub fn map_into<T, Y, F>(&self, x: Slice<T>, mut y: SliceMut<Y>, mut f: F) -> Result<()>
+    where
+        T: Scalar,
+        Y: Scalar,
+        F: FnMut(Range<usize>, Slice<T>, SliceMut<Y>) -> Result<()>,
+    {
+        if x.len() != y.len() {
+            bail!(
+                "Input length ({}) does not match output length ({})!",
+                x.len(),
+                y.len()
+            );
+        }
+        let len = x.len();
+        let chunk_len = self.chunk_len.min(len);
+        let slots = if len > chunk_len { 2 } else { 1 };
+        let mut inputs = Vec::with_capacity(slots);
+        let mut outputs = Vec::with_capacity(slots);
+        for _ in 0..slots {
+            inputs.push(unsafe { Buffer::<T>::uninit(self.device.clone(), chunk_len)? });
+            outputs.push(unsafe { Buffer::<Y>::uninit(self.device.clone(), chunk_len)? });
+        }
+        let mut pending: Option<(usize, Range<usize>)> = None;
+        for (index, start) in (0..len).step_by(chunk_len.max(1)).enumerate() {
+            let range = start..len.min(start + chunk_len);
+            let n = range.len();
+            let slot = index % slots;
+            let mut input = inputs[slot].slice_mut(..n).unwrap();
+            input.transfer_from_slice(&x.slice(range.clone()).unwrap())?;
+            f(
+                range.clone(),
+                input.as_slice(),
+                outputs[slot].slice_mut(..n).unwrap(),
+            )?;
+            if let Some((slot, range)) = pending.replace((slot, range)) {
+                let n = range.len();
+                y.slice_mut(range)
+                    .unwrap()
+                    .transfer_from_slice(&outputs[slot].slice(..n).unwrap())?;
+            }
+        }
+        if let Some((slot, range)) = pending {
+            let n = range.len();
+            y.slice_mut(range)
+                .unwrap()
+                .transfer_from_slice(&outputs[slot].slice(..n).unwrap())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn chunked_map_host() {
+        let x = Buffer::from((0..10u32).collect::<Vec<_>>());
+        let mut ranges = Vec::new();
+        let y = ChunkedMap::new(Device::host(), 4)
+            .unwrap()
+            .map(x.as_slice(), |range, x, mut y| {
+                ranges.push(range);
+                for (x, y) in x
+                    .as_host_slice()
+                    .unwrap()
+                    .iter()
+                    .zip(y.as_host_slice_mut().unwrap())
+                {
+                    *y = *x as u64 * 2;
+                }
+                Ok(())
+            })
+            .unwrap();
+        assert_eq!(ranges, [0..4, 4..8, 8..10]);
+        assert_eq!(
+            y.into_vec().unwrap(),
+            (0..10u64).map(|x| x * 2).collect::<Vec<_>>()
+        );
+        assert!(ChunkedMap::new(Device::host(), 0).is_err());
+    }
+
+    #[cfg(feature = "device")]
+    #[test]
+    fn chunked_map_double_buffered() {
+        use crate::device::MockOp;
+
+        let device = Device::mock();
+        let x = Buffer::from(vec![1u32; 10]);
+        ChunkedMap::new(device.clone(), 4)
+            .unwrap()
+            .map::<u32, u32, _>(x.as_slice(), |_, _, _| Ok(()))
+            .unwrap();
+        let ops = device.take_mock_ops().unwrap();
+        assert_eq!(
+            ops,
+            [
+                MockOp::Alloc { bytes: 16 },
+                MockOp::Alloc { bytes: 16 },
+                MockOp::Alloc { bytes: 16 },
+                MockOp::Alloc { bytes: 16 },
+                MockOp::Upload { bytes: 16 },
+                MockOp::Upload { bytes: 16 },
+                MockOp::Download { bytes: 16 },
+                MockOp::Upload { bytes: 8 },
+                MockOp::Download { bytes: 16 },
+                MockOp::Download { bytes: 8 },
+            ]
+        );
+    }
+}
@@ -0,0 +1,279 @@
+use super::{Buffer, BufferBase, Data};
+use crate::{device::Device, scalar::Scalar};
+use anyhow::Result;
+#[cfg(feature = "device")]
+use krnl_macros::module;
+#[cfg(feature = "device")]
+use std::mem::size_of;
+
+// The size in bytes of the blocks that are compressed independently, each is decompressed by one
+// thread.
+#[cfg(feature = "device")]
+const BLOCK_SIZE: usize = 16 * 1024;
+
+/// Data compressed in blocks of `BLOCK_SIZE` bytes with LZ4.
+#[cfg(feature = "device")]
+struct Compressed {
+    // The compressed blocks, packed into little endian words.
+    words: Vec<u32>,
+    // The start of each block in bytes, and the end of the last block.
+    offsets: Vec<u32>,
+}
+
+#[cfg(feature = "device")]
+impl Compressed {
+    fn new(bytes: &[u8]) -> Self {
+        let mut data = Vec::new();
+        let mut offsets = vec![0];
+        for block in bytes.chunks(BLOCK_SIZE) {
+            data.extend_from_slice(&lz4_flex::block::compress(block));
+            offsets.push(data.len().try_into().unwrap());
+        }
+        let words = data
+            .chunks(4)
+            .map(|chunk| {
+                let mut word = [0u8; 4];
+                word[..chunk.len()].copy_from_slice(chunk);
+                u32::from_le_bytes(word)
+            })
+            .collect();
+        Self { words, offsets }
+    }
+    fn blocks(&self) -> usize {
+        self.offsets.len() - 1
+    }
+    fn bytes(&self) -> usize {
+        4 * (self.words.len() + self.offsets.len())
+    }
+}
+
+impl<T: Scalar, S: Data<Elem = T>> BufferBase<S> {
+    /** Moves into `device`, compressing the transfer with LZ4.
+
+    The data is split into blocks of 16 KiB, which are compressed independently on the host. The
+    compressed blocks are uploaded, and decompressed on the device by a kernel, with one thread
+    per block. This trades compute for bandwidth, for highly compressible data like sparse or
+    repetitive datasets.
+
+    Falls back to [`.into_device()`](BufferBase::into_device) if the data is not on the host,
+    `device` is the host, the data doesn't compress, or the output is larger than the
+    [max allocation size](crate::device::DeviceInfo::max_allocation_size). Downloads are not
+    compressed.
+    ```no_run
+    # use krnl::{anyhow::Result, buffer::Slice, device::Device};
+    # fn main() -> Result<()> {
+    let device = Device::builder().build()?;
+    let x = vec![0u32; 1_000_000];
+    let y = Slice::from(x.as_slice()).into_device_compressed(device)?;
+    # Ok(())
+    # }
+    ```
+
+    **Errors**
+    See [`.into_device()`](BufferBase::into_device).
+    - The kernel could not be dispatched. */
+    pub fn into_device_compressed(self, device: Device) -> Result<Buffer<T>> {
+        #[cfg(feature = "device")]
+        {
+            if let Some(x) = self.as_host_slice().filter(|_| device.is_device()) {
+                let bytes: &[u8] = bytemuck::cast_slice(x);
+                let max_allocation_size = device.info().unwrap().max_allocation_size();
+                if bytes.len() <= max_allocation_size {
+                    let compressed = Compressed::new(bytes);
+                    if compressed.bytes() < bytes.len() {
+                        return decompress(compressed, bytes.len(), device);
+                    }
+                }
+            }
+        }
+        self.into_device(device)
+    }
+}
+
+#[cfg(feature = "device")]
+fn decompress<T: Scalar>(compressed: Compressed, len: usize, device: Device) -> Result<Buffer<T>> {
+    let blocks = compressed.blocks().try_into().unwrap();
+    let offsets = Buffer::from(compressed.offsets).into_device(device.clone())?;
+    let x = Buffer::from(compressed.words).into_device(device.clone())?;
+    let mut y = unsafe { Buffer::<u32>::uninit(device.clone(), (len + 3) / 4)? };
+    kernels::lz4_decompress::builder()?
+        .build(device)?
+        .with_global_threads(blocks)
+        .dispatch(
+            blocks,
+            BLOCK_SIZE as u32,
+            offsets.as_slice(),
+            x.as_slice(),
+            y.as_slice_mut(),
+        )?;
+    if len % 4 == 0 {
+        Ok(y.bitcast_into().unwrap())
+    } else {
+        y.bitcast()
+            .unwrap()
+            .slice(..len / size_of::<T>())
+            .unwrap()
+            .to_owned()
+    }
+}
+
+#[cfg(feature = "device")]
+#[module]
+#[krnl(crate=crate)]
+mod kernels {
+    #[cfg(not(target_arch = "spirv"))]
+    use krnl_core;
+    use krnl_core::macros::kernel;
+
+    // Decompresses LZ4 blocks of `block_size` bytes, with bytes packed into little endian words.
+    #[kernel(host, grid_stride = blocks, require((blocks as usize) < offsets.len()))]
+    pub fn lz4_decompress(
+        blocks: u32,
+        block_size: u32,
+        #[global] offsets: Slice<u32>,
+        #[global] x: Slice<u32>,
+        #[global] y: UnsafeSlice<u32>,
+    ) {
+        use krnl_core::buffer::UnsafeIndex;
+
+        macro_rules! read_x {
+            ($i:expr) => {{
+                let i = $i;
+                (x[i / 4] >> (i % 4 * 8)) & 0xff
+            }};
+        }
+        macro_rules! read_y {
+            ($i:expr) => {{
+                let i = $i;
+                unsafe { (*y.unsafe_index(i / 4) >> (i % 4 * 8)) & 0xff }
+            }};
+        }
+        macro_rules! write_y {
+            ($i:expr, $byte:expr) => {{
+                let (i, byte) = ($i, $byte);
+                let shift = i % 4 * 8;
+                unsafe {
+                    let word = y.unsafe_index_mut(i / 4);
+                    *word = (*word & !(0xff << shift)) | (byte << shift);
+                }
+            }};
+        }
+        // Lengths of 15 are followed by bytes to add, until a byte is not 255.
+        macro_rules! read_len {
+            ($i:ident, $len:expr) => {{
+                let mut len = $len as usize;
+                if len == 15 {
+                    loop {
+                        let byte = read_x!($i);
+                        $i += 1;
+                        len += byte as usize;
+                        if byte != 255 {
+                            break;
+                        }
+                    }
+                }
+                len
+            }};
+        }
+
+        let block = kernel.item_id();
+        let mut i = offsets[block] as usize;
+        let end = offsets[block + 1] as usize;
+        let mut o = block * block_size as usize;
+        while i < end {
+            let token = read_x!(i);
+            i += 1;
+            let mut literals = read_len!(i, token >> 4);
+            while literals > 0 {
+                write_y!(o, read_x!(i));
+                i += 1;
+                o += 1;
+                literals -= 1;
+            }
+            // The last sequence only has literals.
+            if i >= end {
+                break;
+            }
+            let offset = (read_x!(i) | (read_x!(i + 1) << 8)) as usize;
+            i += 2;
+            let mut matched = read_len!(i, token & 15) + 4;
+            while matched > 0 {
+                write_y!(o, read_y!(o - offset));
+                o += 1;
+                matched -= 1;
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "device"))]
+mod tests {
+    use super::*;
+    use crate::{buffer::Slice, device::MockOp};
+
+    fn test_data() -> Vec<u32> {
+        (0..20_000u32).map(|i| i / 100 % 7).collect()
+    }
+
+    #[test]
+    fn lz4_decompress_host() {
+        let x = test_data();
+        let bytes: &[u8] = bytemuck::cast_slice(&x);
+        // Long runs of literals, followed by long matches.
+        let mut seed = 1u32;
+        let mut mixed: Vec<u8> = (0..1000)
+            .map(|_| {
+                seed = seed.wrapping_mul(1_103_515_245).wrapping_add(12345);
+                (seed >> 16) as u8
+            })
+            .collect();
+        mixed.resize(5000, 7);
+        for bytes in [bytes, &bytes[..BLOCK_SIZE + 3], &bytes[..1], &mixed] {
+            let compressed = Compressed::new(bytes);
+            let mut y = vec![0u32; (bytes.len() + 3) / 4];
+            kernels::lz4_decompress::host(
+                compressed.blocks().try_into().unwrap(),
+                BLOCK_SIZE as u32,
+                &compressed.offsets,
+                &compressed.words,
+                &mut y,
+            );
+            assert_eq!(&bytemuck::cast_slice::<u32, u8>(&y)[..bytes.len()], bytes);
+        }
+    }
+
+    #[test]
+    fn into_device_compressed_mock() {
+        let device = Device::mock();
+        let x = test_data();
+        let y = Slice::from(x.as_slice())
+            .into_device_compressed(device.clone())
+            .unwrap();
+        assert_eq!(y.len(), x.len());
+        let ops = device.take_mock_ops().unwrap();
+        let uploaded: usize = ops
+            .iter()
+            .map(|op| match op {
+                MockOp::Upload { bytes } => *bytes,
+                _ => 0,
+            })
+            .sum();
+        assert!(uploaded < x.len() * 4 / 10, "{uploaded}");
+        assert!(
+            matches!(
+                ops.last().unwrap(),
+                MockOp::Dispatch { kernel, .. } if kernel.contains("lz4_decompress")
+            ),
+            "{ops:?}"
+        );
+        // Incompressible data is uploaded directly.
+        let x: Vec<u8> = (0..100u32).map(|i| (i * 7919 % 251) as u8).collect();
+        Slice::from(x.as_slice())
+            .into_device_compressed(device.clone())
+            .unwrap();
+        assert_eq!(
+            device.take_mock_ops().unwrap(),
+            [MockOp::Alloc { bytes: 100 }, MockOp::Upload { bytes: 100 }]
+        );
+    }
+}
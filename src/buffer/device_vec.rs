@@ -0,0 +1,216 @@
+use super::{Buffer, Slice, SliceMut};
+use crate::{device::Device, scalar::Scalar};
+use anyhow::Result;
+use std::fmt::{self, Debug};
+
+/** A growable buffer.
+
+Like [`Vec`], the buffer has a capacity, and is reallocated to at least twice the capacity when
+full. On a device, the items are moved into the new buffer with a device-side copy, so appending
+doesn't upload the whole buffer again.
+```no_run
+# use krnl::{anyhow::Result, buffer::{DeviceVec, Slice}, device::Device};
+# fn main() -> Result<()> {
+let device = Device::builder().build()?;
+let mut detections = DeviceVec::<f32>::new(device);
+for frame in 0..10 {
+    let x = vec![frame as f32; 4];
+    detections.push_slice(&Slice::from(x.as_slice()))?;
+}
+assert_eq!(detections.len(), 40);
+let detections = detections.into_buffer()?;
+# Ok(())
+# }
+```
+*/
+pub struct DeviceVec<T: Scalar> {
+    buffer: Buffer<T>,
+    len: usize,
+}
+
+impl<T: Scalar> DeviceVec<T> {
+    /// Creates an empty vec on `device`, without allocating.
+    pub fn new(device: Device) -> Self {
+        Self {
+            buffer: unsafe { Buffer::uninit(device, 0).unwrap() },
+            len: 0,
+        }
+    }
+    /** Creates an empty vec on `device` with `capacity`.
+
+    **Errors**
+    See [`Buffer::uninit()`](super::BufferBase::uninit). */
+    pub fn with_capacity(device: Device, capacity: usize) -> Result<Self> {
+        Ok(Self {
+            buffer: unsafe { Buffer::uninit(device, capacity)? },
+            len: 0,
+        })
+    }
+    /// The device.
+    pub fn device(&self) -> Device {
+        self.buffer.device()
+    }
+    /// The number of items.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Whether the vec is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// The number of items that can be stored without reallocating.
+    pub fn capacity(&self) -> usize {
+        self.buffer.len()
+    }
+    /** Reserves capacity for at least `additional` more items.
+
+    The new capacity is at least twice the previous capacity.
+
+    **Errors**
+    See [`Buffer::uninit()`](super::BufferBase::uninit) and
+    [`.copy_from_slice()`](super::BufferBase::copy_from_slice). */
+    pub fn reserve(&mut self, additional: usize) -> Result<()> {
+        let required = self.len.checked_add(additional).unwrap();
+        if required <= self.capacity() {
+            return Ok(());
+        }
+        let capacity = required.max(2 * self.capacity());
+        let mut buffer = unsafe { Buffer::uninit(self.device(), capacity)? };
+        if self.len > 0 {
+            buffer
+                .slice_mut(..self.len)
+                .unwrap()
+                .copy_from_slice(&self.as_slice())?;
+        }
+        self.buffer = buffer;
+        Ok(())
+    }
+    /** Appends `elem`.
+
+    See [`.extend_from_slice()`](DeviceVec::extend_from_slice). */
+    pub fn push(&mut self, elem: T) -> Result<()> {
+        self.reserve(1)?;
+        self.buffer
+            .slice_mut(self.len..self.len + 1)
+            .unwrap()
+            .fill(elem)?;
+        self.len += 1;
+        Ok(())
+    }
+    /** Appends the items of `slice`, which may be on another device.
+
+    **Errors**
+    See [`.reserve()`](DeviceVec::reserve) and
+    [`.copy_from_slice()`](super::BufferBase::copy_from_slice). */
+    pub fn extend_from_slice(&mut self, slice: &Slice<T>) -> Result<()> {
+        self.reserve(slice.len())?;
+        let end = self.len + slice.len();
+        self.buffer
+            .slice_mut(self.len..end)
+            .unwrap()
+            .transfer_from_slice(slice)?;
+        self.len = end;
+        Ok(())
+    }
+    /** Appends the items of `slice`.
+
+    Equivalent to [`.extend_from_slice()`](DeviceVec::extend_from_slice). */
+    pub fn push_slice(&mut self, slice: &Slice<T>) -> Result<()> {
+        self.extend_from_slice(slice)
+    }
+    /// Shortens to `len` items, keeping the capacity.
+    ///
+    /// Does nothing if `len` is greater than the current length.
+    pub fn truncate(&mut self, len: usize) {
+        self.len = self.len.min(len);
+    }
+    /// Removes all items, keeping the capacity.
+    pub fn clear(&mut self) {
+        self.len = 0;
+    }
+    /// Borrow the items as a slice.
+    pub fn as_slice(&self) -> Slice<'_, T> {
+        self.buffer.slice(..self.len).unwrap()
+    }
+    /// Borrow the items as a mutable slice.
+    pub fn as_slice_mut(&mut self) -> SliceMut<'_, T> {
+        self.buffer.slice_mut(..self.len).unwrap()
+    }
+    /** Converts into a buffer of the items.
+
+    Avoids copying if the vec is full.
+
+    **Errors**
+    See [`.to_owned()`](super::BufferBase::to_owned). */
+    pub fn into_buffer(self) -> Result<Buffer<T>> {
+        if self.len == self.capacity() {
+            Ok(self.buffer)
+        } else {
+            self.as_slice().to_owned()
+        }
+    }
+}
+
+impl<T: Scalar> From<Buffer<T>> for DeviceVec<T> {
+    fn from(buffer: Buffer<T>) -> Self {
+        let len = buffer.len();
+        Self { buffer, len }
+    }
+}
+
+impl<T: Scalar> Debug for DeviceVec<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DeviceVec")
+            .field("device", &self.device())
+            .field("scalar_type", &T::SCALAR_TYPE)
+            .field("len", &self.len)
+            .field("capacity", &self.capacity())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn device_vec_host() {
+        let mut x = DeviceVec::<u32>::new(Device::host());
+        assert_eq!((x.len(), x.capacity()), (0, 0));
+        x.push(1).unwrap();
+        x.extend_from_slice(&Slice::from([2, 3].as_slice()))
+            .unwrap();
+        assert_eq!(x.as_slice().as_host_slice().unwrap(), [1, 2, 3]);
+        assert_eq!(x.capacity(), 3);
+        x.push(4).unwrap();
+        assert_eq!(x.capacity(), 6);
+        x.truncate(2);
+        assert_eq!(x.into_buffer().unwrap().into_vec().unwrap(), [1, 2]);
+        let x = DeviceVec::from(Buffer::from(vec![1u8, 2]));
+        assert_eq!(x.into_buffer().unwrap().into_vec().unwrap(), [1, 2]);
+    }
+
+    #[cfg(feature = "device")]
+    #[test]
+    fn device_vec_copies_on_device() {
+        use crate::device::MockOp;
+
+        let device = Device::mock();
+        let mut x = DeviceVec::<u32>::with_capacity(device.clone(), 2).unwrap();
+        let y = vec![1u32, 2];
+        x.extend_from_slice(&Slice::from(y.as_slice())).unwrap();
+        x.push_slice(&Slice::from(y.as_slice())).unwrap();
+        assert_eq!(x.capacity(), 4);
+        assert_eq!(
+            device.take_mock_ops().unwrap(),
+            [
+                MockOp::Alloc { bytes: 8 },
+                MockOp::Upload { bytes: 8 },
+                MockOp::Alloc { bytes: 16 },
+                MockOp::Copy { bytes: 8 },
+                MockOp::Upload { bytes: 8 },
+            ]
+        );
+        assert_eq!(x.as_slice().to_vec().unwrap(), [1, 2, 1, 2]);
+    }
+}
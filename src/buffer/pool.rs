@@ -0,0 +1,272 @@
+use super::{Buffer, ScalarBuffer};
+use crate::{device::Device, scalar::Scalar};
+use anyhow::Result;
+use parking_lot::Mutex;
+use std::{
+    collections::BTreeMap,
+    fmt::{self, Debug},
+    ops::{Deref, DerefMut},
+    sync::{Arc, Weak},
+};
+
+/** A pool of buffers on a device, recycled when dropped.
+
+Buffers are allocated by the pool, and returned to it instead of being freed. Later allocations
+of the same size in bytes reuse them, regardless of the scalar type, so temporaries allocated in
+a loop are only allocated on the first iteration:
+```no_run
+# use krnl::{anyhow::Result, buffer::BufferPool, device::Device};
+# fn main() -> Result<()> {
+let device = Device::builder().build()?;
+let pool = BufferPool::new(device);
+for _ in 0..10 {
+    let mut x = pool.zeros::<f32>(1_000)?;
+    x.fill(1f32)?;
+    // x is returned to the pool when dropped
+}
+assert_eq!(pool.len(), 1);
+pool.trim(0);
+# Ok(())
+# }
+```
+The pool can be cloned, which is equivalent to [`Arc::clone()`]. Buffers hold a weak reference
+to the pool, and are freed if the pool is dropped.
+*/
+#[derive(Clone)]
+pub struct BufferPool {
+    device: Device,
+    inner: Arc<Mutex<PoolInner>>,
+}
+
+#[derive(Default)]
+struct PoolInner {
+    // Free buffers by size in bytes.
+    buffers: BTreeMap<usize, Vec<ScalarBuffer>>,
+    bytes: usize,
+    max_bytes: Option<usize>,
+}
+
+impl PoolInner {
+    fn push(&mut self, buffer: ScalarBuffer) {
+        let bytes = buffer.len() * buffer.scalar_type().size();
+        if self
+            .max_bytes
+            .is_some_and(|max_bytes| self.bytes + bytes > max_bytes)
+        {
+            return;
+        }
+        self.bytes += bytes;
+        self.buffers.entry(bytes).or_default().push(buffer);
+    }
+    fn pop(&mut self, bytes: usize) -> Option<ScalarBuffer> {
+        let buffers = self.buffers.get_mut(&bytes)?;
+        let buffer = buffers.pop()?;
+        if buffers.is_empty() {
+            self.buffers.remove(&bytes);
+        }
+        self.bytes -= bytes;
+        Some(buffer)
+    }
+    fn trim(&mut self, max_bytes: usize) {
+        // Frees the largest buffers first.
+        while self.bytes > max_bytes {
+            let (&bytes, buffers) = self.buffers.iter_mut().next_back().unwrap();
+            buffers.pop();
+            if buffers.is_empty() {
+                self.buffers.remove(&bytes);
+            }
+            self.bytes -= bytes;
+        }
+    }
+}
+
+impl BufferPool {
+    /// Creates an empty pool on `device`.
+    pub fn new(device: Device) -> Self {
+        Self {
+            device,
+            inner: Arc::default(),
+        }
+    }
+    /** Limits the bytes of free buffers held by the pool.
+
+    Buffers returned to a full pool are freed. */
+    pub fn with_max_bytes(self, max_bytes: usize) -> Self {
+        {
+            let mut inner = self.inner.lock();
+            inner.max_bytes.replace(max_bytes);
+            inner.trim(max_bytes);
+        }
+        self
+    }
+    /// The device.
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+    /// The max bytes of free buffers, see [`.with_max_bytes()`](BufferPool::with_max_bytes).
+    pub fn max_bytes(&self) -> Option<usize> {
+        self.inner.lock().max_bytes
+    }
+    /// The total bytes of free buffers held by the pool.
+    pub fn bytes(&self) -> usize {
+        self.inner.lock().bytes
+    }
+    /// The number of free buffers held by the pool.
+    pub fn len(&self) -> usize {
+        self.inner.lock().buffers.values().map(Vec::len).sum()
+    }
+    /// Whether the pool holds no free buffers.
+    pub fn is_empty(&self) -> bool {
+        self.inner.lock().buffers.is_empty()
+    }
+    /// Frees buffers, largest first, until at most `max_bytes` are held by the pool.
+    pub fn trim(&self, max_bytes: usize) {
+        self.inner.lock().trim(max_bytes);
+    }
+    /** Takes a buffer from the pool, or allocates one.
+
+    # Safety
+    The buffer will not be initialized, and may contain data from a previous buffer.
+
+    **Errors**
+    See [`Buffer::uninit()`](super::BufferBase::uninit). */
+    pub unsafe fn uninit<T: Scalar>(&self, len: usize) -> Result<PooledBuffer<T>> {
+        let bytes = len * T::SCALAR_TYPE.size();
+        let buffer = self
+            .inner
+            .lock()
+            .pop(bytes)
+            .and_then(|buffer| buffer.bitcast_into(T::SCALAR_TYPE).ok())
+            .map(|buffer| Buffer::try_from(buffer).ok().unwrap());
+        let buffer = if let Some(buffer) = buffer {
+            buffer
+        } else {
+            unsafe { Buffer::uninit(self.device.clone(), len)? }
+        };
+        Ok(PooledBuffer {
+            buffer: Some(buffer),
+            pool: Arc::downgrade(&self.inner),
+        })
+    }
+    /** Takes a buffer from the pool filled with `elem`.
+
+    **Errors**
+    See [`Buffer::from_elem()`](super::BufferBase::from_elem). */
+    pub fn from_elem<T: Scalar>(&self, len: usize, elem: T) -> Result<PooledBuffer<T>> {
+        let mut buffer = unsafe { self.uninit(len)? };
+        buffer.fill(elem)?;
+        Ok(buffer)
+    }
+    /// Takes a buffer from the pool filled with 0's.
+    ///
+    /// See [`.from_elem()`](BufferPool::from_elem).
+    pub fn zeros<T: Scalar>(&self, len: usize) -> Result<PooledBuffer<T>> {
+        self.from_elem(len, T::zero())
+    }
+}
+
+impl Debug for BufferPool {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferPool")
+            .field("device", &self.device)
+            .field("len", &self.len())
+            .field("bytes", &self.bytes())
+            .field("max_bytes", &self.max_bytes())
+            .finish()
+    }
+}
+
+/** A buffer from a [`BufferPool`].
+
+Derefs to [`Buffer`], and is returned to the pool when dropped.
+*/
+pub struct PooledBuffer<T: Scalar> {
+    buffer: Option<Buffer<T>>,
+    pool: Weak<Mutex<PoolInner>>,
+}
+
+impl<T: Scalar> PooledBuffer<T> {
+    /// Takes the buffer, so that it is not returned to the pool.
+    pub fn into_inner(mut self) -> Buffer<T> {
+        self.buffer.take().unwrap()
+    }
+}
+
+impl<T: Scalar> Deref for PooledBuffer<T> {
+    type Target = Buffer<T>;
+    fn deref(&self) -> &Buffer<T> {
+        self.buffer.as_ref().unwrap()
+    }
+}
+
+impl<T: Scalar> DerefMut for PooledBuffer<T> {
+    fn deref_mut(&mut self) -> &mut Buffer<T> {
+        self.buffer.as_mut().unwrap()
+    }
+}
+
+impl<T: Scalar> Drop for PooledBuffer<T> {
+    fn drop(&mut self) {
+        if let Some((buffer, pool)) = self.buffer.take().zip(self.pool.upgrade()) {
+            pool.lock().push(buffer.into());
+        }
+    }
+}
+
+impl<T: Scalar> Debug for PooledBuffer<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.deref().fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buffer_pool_host() {
+        let pool = BufferPool::new(Device::host());
+        let x = pool.from_elem(4, 1u32).unwrap();
+        assert_eq!(x.as_host_slice().unwrap(), [1; 4]);
+        drop(x);
+        assert_eq!((pool.len(), pool.bytes()), (1, 16));
+        let y = pool.zeros::<f32>(4).unwrap();
+        assert_eq!(y.as_host_slice().unwrap(), [0f32; 4]);
+        assert!(pool.is_empty());
+        let z = pool.zeros::<u8>(3).unwrap();
+        drop((y, z));
+        assert_eq!((pool.len(), pool.bytes()), (2, 19));
+        pool.trim(4);
+        assert_eq!((pool.len(), pool.bytes()), (1, 3));
+        let pool = pool.with_max_bytes(8);
+        drop(pool.zeros::<u64>(2).unwrap());
+        assert_eq!((pool.len(), pool.bytes()), (1, 3));
+        let x = pool.zeros::<u8>(3).unwrap().into_inner();
+        drop(x);
+        assert!(pool.is_empty());
+    }
+
+    #[cfg(feature = "device")]
+    #[test]
+    fn buffer_pool_recycles() {
+        use crate::device::MockOp;
+
+        let device = Device::mock();
+        let pool = BufferPool::new(device.clone());
+        for _ in 0..3 {
+            let x = unsafe { pool.uninit::<u32>(4).unwrap() };
+            let y = unsafe { pool.uninit::<f32>(4).unwrap() };
+            drop((x, y));
+        }
+        let ops = device.take_mock_ops().unwrap();
+        assert_eq!(
+            ops,
+            [MockOp::Alloc { bytes: 16 }, MockOp::Alloc { bytes: 16 }]
+        );
+        assert_eq!(pool.len(), 2);
+        let stats = device.memory_stats().unwrap();
+        assert_eq!(stats.allocations(), 2);
+        drop(pool);
+        assert_eq!(device.memory_stats().unwrap().allocations(), 0);
+    }
+}
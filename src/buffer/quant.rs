@@ -0,0 +1,314 @@
+use super::{Buffer, BufferBase, Data, Slice};
+#[cfg(feature = "device")]
+use super::{ScalarSliceMut, SliceMut};
+use crate::scalar::Quantized;
+#[cfg(feature = "device")]
+use crate::scalar::Scalar;
+use anyhow::{bail, Result};
+#[cfg(feature = "device")]
+use dry::macro_for;
+#[cfg(feature = "device")]
+use krnl_macros::module;
+#[cfg(feature = "device")]
+use paste::paste;
+
+/// Per-channel quantization parameters, on the device of `x`.
+struct Channels<'a> {
+    len: usize,
+    scales: Slice<'a, f32>,
+    zero_points: Slice<'a, i32>,
+}
+
+impl<'a> Channels<'a> {
+    fn new(len: usize, scales: Slice<'a, f32>, zero_points: Slice<'a, i32>) -> Result<Self> {
+        let channels = scales.len();
+        if channels != zero_points.len() {
+            bail!(
+                "Expected {channels} zero_points for {channels} scales, found {}!",
+                zero_points.len()
+            );
+        }
+        if channels == 0 || len % channels != 0 {
+            bail!("Length {len} is not divisible by {channels} channels!");
+        }
+        Ok(Self {
+            len: len / channels,
+            scales,
+            zero_points,
+        })
+    }
+    fn host(&self) -> Option<(&[f32], &[i32])> {
+        self.scales
+            .as_host_slice()
+            .zip(self.zero_points.as_host_slice())
+    }
+}
+
+impl<S: Data<Elem = f32>> BufferBase<S> {
+    /** Quantizes to `Q` with `scale` and `zero_point`.
+
+    Items are quantized with [`Quantized::quantize`].
+
+    **Errors**
+    - The device does not support 8 bit operations.
+    - [`DeviceLost`](crate::device::error::DeviceLost): The device was lost.
+    - The kernel could not be dispatched.
+    */
+    pub fn quantize<Q: Quantized>(&self, scale: f32, zero_point: i32) -> Result<Buffer<Q>> {
+        let mut output = unsafe { Buffer::uninit(self.device(), self.len())? };
+        if let Some(x) = self.as_host_slice() {
+            let y = output.as_host_slice_mut().unwrap();
+            for (x, y) in x.iter().zip(y) {
+                *y = Q::quantize(*x, scale, zero_point);
+            }
+            return Ok(output);
+        }
+        #[cfg(feature = "device")]
+        {
+            let x = self.as_slice();
+            macro_for!($Q in [i8, u8] {
+                if Q::SCALAR_TYPE == $Q::SCALAR_TYPE {
+                    let y = SliceMut::<$Q>::try_from(ScalarSliceMut::from(output.as_slice_mut()))
+                        .ok()
+                        .unwrap();
+                    paste! {
+                        kernels::[<quantize_ $Q>]::builder()?
+                            .build(y.device())?
+                            .dispatch(scale, zero_point, x, y)?;
+                    }
+                    return Ok(output);
+                }
+            });
+        }
+        unreachable!()
+    }
+    /** Quantizes to `Q` with a `scale` and `zero_point` per channel.
+
+    The buffer is split into `scales.len()` contiguous channels of equal length, ie channel `i`
+    is quantized with `scales[i]` and `zero_points[i]`. `scales` and `zero_points` are copied to
+    the device if necessary.
+
+    **Errors**
+    - `scales` and `zero_points` have different lengths.
+    - The length is not divisible by the number of channels.
+    - See [`.quantize()`](BufferBase::quantize).
+    */
+    pub fn quantize_per_channel<Q: Quantized>(
+        &self,
+        scales: Slice<f32>,
+        zero_points: Slice<i32>,
+    ) -> Result<Buffer<Q>> {
+        let device = self.device();
+        let scales = scales.into_device_shared(device.clone())?;
+        let zero_points = zero_points.into_device_shared(device.clone())?;
+        let channels = Channels::new(self.len(), scales.as_slice(), zero_points.as_slice())?;
+        let mut output = unsafe { Buffer::uninit(device, self.len())? };
+        if let Some((x, (scales, zero_points))) = self.as_host_slice().zip(channels.host()) {
+            let y = output.as_host_slice_mut().unwrap();
+            for (((x, y), scale), zero_point) in x
+                .chunks(channels.len)
+                .zip(y.chunks_mut(channels.len))
+                .zip(scales)
+                .zip(zero_points)
+            {
+                for (x, y) in x.iter().zip(y) {
+                    *y = Q::quantize(*x, *scale, *zero_point);
+                }
+            }
+            return Ok(output);
+        }
+        #[cfg(feature = "device")]
+        {
+            let x = self.as_slice();
+            let channel_len = u32::try_from(channels.len).unwrap();
+            macro_for!($Q in [i8, u8] {
+                if Q::SCALAR_TYPE == $Q::SCALAR_TYPE {
+                    let y = SliceMut::<$Q>::try_from(ScalarSliceMut::from(output.as_slice_mut()))
+                        .ok()
+                        .unwrap();
+                    paste! {
+                        kernels::[<quantize_per_channel_ $Q>]::builder()?
+                            .build(y.device())?
+                            .dispatch(channel_len, channels.scales, channels.zero_points, x, y)?;
+                    }
+                    return Ok(output);
+                }
+            });
+        }
+        unreachable!()
+    }
+}
+
+impl<Q: Quantized, S: Data<Elem = Q>> BufferBase<S> {
+    /** Dequantizes to f32 with `scale` and `zero_point`.
+
+    Items are dequantized with [`Quantized::dequantize`].
+
+    **Errors**
+
+    See [`.quantize()`](BufferBase::quantize).
+    */
+    pub fn dequantize(&self, scale: f32, zero_point: i32) -> Result<Buffer<f32>> {
+        let mut output = unsafe { Buffer::uninit(self.device(), self.len())? };
+        if let Some(x) = self.as_host_slice() {
+            let y = output.as_host_slice_mut().unwrap();
+            for (x, y) in x.iter().zip(y) {
+                *y = x.dequantize(scale, zero_point);
+            }
+            return Ok(output);
+        }
+        #[cfg(feature = "device")]
+        {
+            let y = output.as_slice_mut();
+            macro_for!($Q in [i8, u8] {
+                if Q::SCALAR_TYPE == $Q::SCALAR_TYPE {
+                    let x = Slice::<$Q>::try_from(self.as_scalar_slice()).ok().unwrap();
+                    paste! {
+                        kernels::[<dequantize_ $Q>]::builder()?
+                            .build(y.device())?
+                            .dispatch(scale, zero_point, x, y)?;
+                    }
+                    return Ok(output);
+                }
+            });
+        }
+        unreachable!()
+    }
+    /** Dequantizes to f32 with a `scale` and `zero_point` per channel.
+
+    See [`.quantize_per_channel()`](BufferBase::quantize_per_channel).
+    */
+    pub fn dequantize_per_channel(
+        &self,
+        scales: Slice<f32>,
+        zero_points: Slice<i32>,
+    ) -> Result<Buffer<f32>> {
+        let device = self.device();
+        let scales = scales.into_device_shared(device.clone())?;
+        let zero_points = zero_points.into_device_shared(device.clone())?;
+        let channels = Channels::new(self.len(), scales.as_slice(), zero_points.as_slice())?;
+        let mut output = unsafe { Buffer::uninit(device, self.len())? };
+        if let Some((x, (scales, zero_points))) = self.as_host_slice().zip(channels.host()) {
+            let y = output.as_host_slice_mut().unwrap();
+            for (((x, y), scale), zero_point) in x
+                .chunks(channels.len)
+                .zip(y.chunks_mut(channels.len))
+                .zip(scales)
+                .zip(zero_points)
+            {
+                for (x, y) in x.iter().zip(y) {
+                    *y = x.dequantize(*scale, *zero_point);
+                }
+            }
+            return Ok(output);
+        }
+        #[cfg(feature = "device")]
+        {
+            let y = output.as_slice_mut();
+            let channel_len = u32::try_from(channels.len).unwrap();
+            macro_for!($Q in [i8, u8] {
+                if Q::SCALAR_TYPE == $Q::SCALAR_TYPE {
+                    let x = Slice::<$Q>::try_from(self.as_scalar_slice()).ok().unwrap();
+                    paste! {
+                        kernels::[<dequantize_per_channel_ $Q>]::builder()?
+                            .build(y.device())?
+                            .dispatch(channel_len, channels.scales, channels.zero_points, x, y)?;
+                    }
+                    return Ok(output);
+                }
+            });
+        }
+        unreachable!()
+    }
+}
+
+// Not yet in krnl-cache.rs, remove `no_build` when it is regenerated with krnlc.
+#[cfg(feature = "device")]
+#[module]
+#[krnl(crate=crate, no_build)]
+mod kernels {
+    use dry::macro_for;
+    #[cfg(not(target_arch = "spirv"))]
+    use krnl_core;
+    use krnl_core::macros::kernel;
+    #[cfg(target_arch = "spirv")]
+    use krnl_core::scalar::Quantized;
+    use paste::paste;
+
+    macro_for!($Q in [i8, u8] {
+        paste! {
+            #[kernel]
+            pub fn [<quantize_ $Q>](scale: f32, zero_point: i32, #[item] x: f32, #[item] y: &mut $Q) {
+                *y = $Q::quantize(x, scale, zero_point);
+            }
+
+            #[kernel]
+            pub fn [<quantize_per_channel_ $Q>](
+                channel_len: u32,
+                #[global] scales: Slice<f32>,
+                #[global] zero_points: Slice<i32>,
+                #[item] x: f32,
+                #[item] y: &mut $Q,
+            ) {
+                let channel = kernel.item_id() / channel_len as usize;
+                *y = $Q::quantize(x, scales[channel], zero_points[channel]);
+            }
+
+            #[kernel]
+            pub fn [<dequantize_ $Q>](scale: f32, zero_point: i32, #[item] x: $Q, #[item] y: &mut f32) {
+                *y = x.dequantize(scale, zero_point);
+            }
+
+            #[kernel]
+            pub fn [<dequantize_per_channel_ $Q>](
+                channel_len: u32,
+                #[global] scales: Slice<f32>,
+                #[global] zero_points: Slice<i32>,
+                #[item] x: $Q,
+                #[item] y: &mut f32,
+            ) {
+                let channel = kernel.item_id() / channel_len as usize;
+                *y = x.dequantize(scales[channel], zero_points[channel]);
+            }
+        }
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn quantize_host() {
+        let x = Buffer::from(vec![-1.0f32, 0.0, 0.26, 100.0]);
+        let y = x.quantize::<i8>(0.5, 1).unwrap();
+        assert_eq!(y.as_host_slice().unwrap(), [-1, 1, 2, 127]);
+        let y = y.dequantize(0.5, 1).unwrap();
+        assert_eq!(y.as_host_slice().unwrap(), [-1.0, 0.0, 0.5, 63.0]);
+        let y = x.quantize::<u8>(0.5, 1).unwrap();
+        assert_eq!(y.as_host_slice().unwrap(), [0, 1, 2, 201]);
+    }
+
+    #[test]
+    fn quantize_per_channel_host() {
+        let x = Buffer::from(vec![1.0f32, 2.0, 1.0, 2.0]);
+        let scales = Buffer::from(vec![1.0f32, 0.5]);
+        let zero_points = Buffer::from(vec![0i32, 10]);
+        let y = x
+            .quantize_per_channel::<u8>(scales.as_slice(), zero_points.as_slice())
+            .unwrap();
+        assert_eq!(y.as_host_slice().unwrap(), [1, 2, 12, 14]);
+        let y = y
+            .dequantize_per_channel(scales.as_slice(), zero_points.as_slice())
+            .unwrap();
+        assert_eq!(y.as_host_slice().unwrap(), x.as_host_slice().unwrap());
+        assert!(x
+            .quantize_per_channel::<u8>(scales.slice(..1).unwrap(), zero_points.as_slice())
+            .is_err());
+        let scales = Buffer::from(vec![1.0f32; 3]);
+        let zero_points = Buffer::from(vec![0i32; 3]);
+        assert!(x
+            .quantize_per_channel::<u8>(scales.as_slice(), zero_points.as_slice())
+            .is_err());
+    }
+}
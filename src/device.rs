@@ -13,23 +13,64 @@ dbg!(device.info());
 # Ok(())
 # }
 ```
+
+A [`DeviceGroup`](crate::device::DeviceGroup) splits buffers across multiple devices.
 */
 
+use crate::buffer::{ScalarBuffer, ScalarSlice};
 #[cfg(feature = "device")]
-use crate::kernel::{KernelDesc, KernelKey};
+use crate::{
+    kernel::{KernelDesc, KernelKey},
+    replay::Recorder,
+};
 use anyhow::Result;
+#[cfg(feature = "device")]
+use parking_lot::Mutex;
 use std::{
+    collections::BTreeMap,
     fmt::{self, Debug},
+    future::Future,
+    pin::Pin,
     sync::Arc,
+    task::{Context, Poll},
 };
 #[cfg(feature = "device")]
-use std::{ops::Range, sync::atomic::AtomicBool};
+use std::{ops::Range, ptr::NonNull, sync::atomic::AtomicBool, time::Instant};
 
 #[cfg(all(not(target_arch = "wasm32"), feature = "device"))]
 mod vulkan_engine;
 #[cfg(all(not(target_arch = "wasm32"), feature = "device"))]
 use vulkan_engine::Engine;
 
+#[cfg(feature = "device")]
+mod mock_engine;
+
+#[cfg(feature = "device")]
+pub mod backend;
+#[cfg(feature = "device")]
+use backend::Backend;
+
+mod group;
+pub use group::{DeviceGroup, Shard};
+
+mod telemetry;
+pub use telemetry::Telemetry;
+
+mod metrics;
+pub use metrics::{KernelMetrics, MemoryStats, PerformanceMetrics, TransferMetrics};
+#[cfg(feature = "device")]
+use metrics::{MemoryAllocation, MemoryTracker};
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "vulkan-interop"))]
+mod interop;
+#[cfg(all(not(target_arch = "wasm32"), feature = "vulkan-interop"))]
+pub use interop::{VulkanBuffer, VulkanHandles};
+
+mod debug;
+#[cfg(feature = "device")]
+use debug::MessageCallback;
+pub use debug::{DebugMessage, DebugMessageSeverity};
+
 #[cfg(all(target_arch = "wasm32", feature = "device"))]
 compile_error!("device feature not supported on wasm");
 
@@ -78,46 +119,580 @@ pub mod error {
         fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
             Debug::fmt(self, f)
         }
-    }
-}
-use error::*;
-
-/// Builders.
-pub mod builder {
-    use super::*;
-
-    /// Builder for creating a [`Device`].
-    pub struct DeviceBuilder {
-        #[cfg(feature = "device")]
-        pub(super) options: DeviceOptions,
-    }
-
-    impl DeviceBuilder {
-        /// Index of the device, defaults to 0.
-        pub fn index(self, index: usize) -> Self {
+    }
+
+    /// Data was corrupted in a transfer, see [`DeviceBuilder::verify_transfers`](super::DeviceBuilder::verify_transfers).
+    #[cfg(any(doc, feature = "device"))]
+    #[derive(Clone, Copy, Debug, thiserror::Error)]
+    #[cfg_attr(
+        feature = "device",
+        error("Transfer of {bytes} bytes is corrupted at byte {offset}!")
+    )]
+    #[cfg_attr(not(feature = "device"), error("unreachable!"))]
+    pub struct TransferCorrupted {
+        #[cfg(feature = "device")]
+        pub(super) bytes: usize,
+        #[cfg(feature = "device")]
+        pub(super) offset: usize,
+    }
+}
+use error::*;
+
+/// Builders.
+pub mod builder {
+    use super::*;
+
+    /// Builder for creating a [`Device`].
+    pub struct DeviceBuilder {
+        #[cfg(feature = "device")]
+        pub(super) options: DeviceOptions,
+    }
+
+    impl DeviceBuilder {
+        /// Index of the device, defaults to 0.
+        pub fn index(self, index: usize) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.index = index;
+                this.options.env = false;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = index;
+                self
+            }
+        }
+        /// Only select devices with a name containing `name`, ignoring case, defaults to None.
+        ///
+        /// Filters are applied before [`.index()`](DeviceBuilder::index), which selects among the
+        /// matching devices. This selects the same device even if the order of devices changes:
+        /// ```no_run
+        /// # use krnl::{anyhow::Result, device::{Device, DeviceType, Vendor}};
+        /// # fn main() -> Result<()> {
+        /// let device = Device::builder()
+        ///     .vendor(Vendor::Nvidia)
+        ///     .device_type(DeviceType::Discrete)
+        ///     .build()?;
+        /// # Ok(())
+        /// # }
+        /// ```
+        pub fn name_contains(self, name: impl Into<String>) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.name_contains.replace(name.into());
+                this.options.env = false;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = name;
+                self
+            }
+        }
+        /// Selects the device from the `KRNL_DEVICE` environment variable, if set.
+        ///
+        /// An integer is the [index](DeviceBuilder::index), otherwise it is a
+        /// [name](DeviceBuilder::name_contains) to match, ie `KRNL_DEVICE=1` or `KRNL_DEVICE=nvidia`.
+        ///
+        /// This is applied by [`.build()`](DeviceBuilder::build), unless the index, name, or
+        /// [uuid](DeviceBuilder::uuid) is selected explicitly.
+        pub fn from_env(self) -> Self {
+            #[cfg(feature = "device")]
+            {
+                match std::env::var("KRNL_DEVICE") {
+                    Ok(value) => self.with_device_env(&value),
+                    Err(_) => {
+                        let mut this = self;
+                        this.options.env = false;
+                        this
+                    }
+                }
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                self
+            }
+        }
+        #[cfg(feature = "device")]
+        pub(super) fn with_device_env(self, value: &str) -> Self {
+            let value = value.trim();
+            if value.is_empty() {
+                let mut this = self;
+                this.options.env = false;
+                this
+            } else if let Ok(index) = value.parse() {
+                self.index(index)
+            } else {
+                self.name_contains(value)
+            }
+        }
+        /// Only select devices from `vendor`, defaults to None.
+        ///
+        /// See [`.name_contains()`](DeviceBuilder::name_contains).
+        pub fn vendor(self, vendor: Vendor) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.vendor.replace(vendor);
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = vendor;
+                self
+            }
+        }
+        /// Only select devices of `device_type`, defaults to None.
+        ///
+        /// See [`.name_contains()`](DeviceBuilder::name_contains).
+        pub fn device_type(self, device_type: DeviceType) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.device_type.replace(device_type);
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = device_type;
+                self
+            }
+        }
+        /// Only select the device with `uuid`, defaults to None.
+        ///
+        /// The uuid is stable across processes and reboots, independent of the order of devices.
+        /// See [`DeviceInfo::uuid()`] and [`.name_contains()`](DeviceBuilder::name_contains).
+        /// ```no_run
+        /// # use krnl::{anyhow::Result, device::Device};
+        /// # fn main() -> Result<()> {
+        /// let uuid = Device::builder().index(1).build()?.info().unwrap().uuid();
+        /// // in another process
+        /// let device = Device::builder().uuid(uuid).build()?;
+        /// # Ok(())
+        /// # }
+        /// ```
+        pub fn uuid(self, uuid: [u8; 16]) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.uuid.replace(uuid);
+                this.options.env = false;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = uuid;
+                self
+            }
+        }
+        /// Allow software implementations, ie llvmpipe or SwiftShader, defaults to true.
+        ///
+        /// When false, software devices are excluded before [`.index()`](DeviceBuilder::index) is applied.
+        pub fn allow_software(self, allow_software: bool) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.allow_software = allow_software;
+                if !allow_software {
+                    this.options.prefer_software = false;
+                }
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = allow_software;
+                self
+            }
+        }
+        /// Orders software implementations, ie llvmpipe or SwiftShader, before other devices.
+        ///
+        /// This allows CI machines without a GPU to target a software device deliberately, while
+        /// [`.index()`](DeviceBuilder::index) selects among the devices in order.
+        pub fn prefer_software(self) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.allow_software = true;
+                this.options.prefer_software = true;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                self
+            }
+        }
+        /// Features the device must support, defaults to none.
+        ///
+        /// [`.build()`](DeviceBuilder::build) returns an error listing the required features the
+        /// device doesn't support. Otherwise, features are enabled if supported, and kernels
+        /// requiring unsupported features fail to build.
+        /// ```no_run
+        /// # use krnl::{anyhow::Result, device::{Device, Features}};
+        /// # fn main() -> Result<()> {
+        /// let device = Device::builder()
+        ///     .required_features(Features::empty().with_shader_int64(true))
+        ///     .build()?;
+        /// # Ok(())
+        /// # }
+        /// ```
+        pub fn required_features(self, required_features: Features) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.optimal_features =
+                    this.options.optimal_features.union(&required_features);
+                this.options.required_features = required_features;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = required_features;
+                self
+            }
+        }
+        /// Validate kernels with spirv-val before creating pipelines, defaults to false.
+        ///
+        /// Invalid kernels return an error naming the kernel and the offending instruction, instead of
+        /// an opaque error from the driver. This is intended for debugging, and adds overhead to the
+        /// first build of each kernel.
+        ///
+        /// Requires the "spirv-val" feature, otherwise [`.build()`](DeviceBuilder::build) returns an error.
+        pub fn validate_spirv(self, validate_spirv: bool) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.validate_spirv = validate_spirv;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = validate_spirv;
+                self
+            }
+        }
+        /// Verify uploads, downloads, and transfers, defaults to false.
+        ///
+        /// Uploaded data is read back from the device, and downloads are read twice, returning
+        /// [`TransferCorrupted`] if the data differs. This detects corruption from flaky hardware or
+        /// drivers in long running jobs, at the cost of additional transfers and waiting for each
+        /// transfer to finish.
+        pub fn verify_transfers(self, verify_transfers: bool) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.verify_transfers = verify_transfers;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = verify_transfers;
+                self
+            }
+        }
+        /// Dump the state of dispatches that panic to `dir`, defaults to None.
+        ///
+        /// When a [kernel panic](crate::kernel#panics) is detected, the buffers (up to
+        /// [`.panic_dump_max_bytes()`](DeviceBuilder::panic_dump_max_bytes)), push constants, and spec
+        /// constants are written to a new directory within `dir`, so that failures can be reproduced and
+        /// analyzed offline. See [Panic Dumps](crate::kernel#panic-dumps).
+        pub fn panic_dump(self, dir: impl Into<std::path::PathBuf>) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.panic_dump_dir.replace(dir.into());
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = dir;
+                self
+            }
+        }
+        /// The max total size of buffers in a [panic dump](DeviceBuilder::panic_dump), defaults to 64 MB.
+        pub fn panic_dump_max_bytes(self, max_bytes: usize) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.panic_dump_max_bytes = max_bytes;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = max_bytes;
+                self
+            }
+        }
+        /// Record dispatches to a trace file at `path`, defaults to None.
+        ///
+        /// Every dispatch is appended to the trace, including the buffers before and after the
+        /// dispatch (up to [`.record_max_bytes()`](DeviceBuilder::record_max_bytes)), so that it can
+        /// be replayed on another device with [`replay::run`](crate::replay::run). See [`replay`](crate::replay).
+        pub fn record(self, path: impl Into<std::path::PathBuf>) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.record_path.replace(path.into());
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = path;
+                self
+            }
+        }
+        /// The max total size of buffers in a [recorded](DeviceBuilder::record) trace, defaults to 64 MB.
+        pub fn record_max_bytes(self, max_bytes: usize) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.record_max_bytes = max_bytes;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = max_bytes;
+                self
+            }
+        }
+        /// Collect [performance metrics](PerformanceMetrics), defaults to false.
+        ///
+        /// Tracks uploads, downloads, and dispatches of each kernel. On devices that support
+        /// timestamp queries, the time each kernel executes on the device is measured. See
+        /// [`Device::performance_metrics()`].
+        pub fn performance_metrics(self, performance_metrics: bool) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.performance_metrics = performance_metrics;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = performance_metrics;
+                self
+            }
+        }
+        /// Enable DebugPrintf, defaults to the environment.
+        ///
+        /// When enabled, the validation layers are enabled with DebugPrintf, which requires the
+        /// [LunarG Vulkan SDK](https://www.lunarg.com/vulkan-sdk/). Otherwise, DebugPrintf is enabled
+        /// if the validation layers are configured with DebugPrintf via the environment, ie
+        /// `VK_LAYER_ENABLES=VK_VALIDATION_FEATURE_ENABLE_DEBUG_PRINTF_EXT`. When disabled,
+        /// DebugPrintf instructions are removed from kernels.
+        ///
+        /// See [DebugPrintf](crate::kernel#debugprintf).
+        pub fn debug_printf(self, debug_printf: bool) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.debug_printf.replace(debug_printf);
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = debug_printf;
+                self
+            }
+        }
+        /// Enable the Khronos validation layer, defaults to false.
+        ///
+        /// Layers enabled via the environment, ie `VK_INSTANCE_LAYERS`, are not disabled.
+        /// See [`InstanceConfig::with_validation`].
+        pub fn validation(self, validation: bool) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.instance.validation = validation;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = validation;
+                self
+            }
+        }
+        /// Configures the Vulkan instance, see [`InstanceConfig`].
+        pub fn instance(self, instance: InstanceConfig) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.instance = instance;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = instance;
+                self
+            }
+        }
+        /// Calls `f` with each [message](DebugMessage) from the validation layers, including DebugPrintf.
+        ///
+        /// DebugPrintf messages are still printed to stderr.
+        pub fn message_callback(self, f: impl Fn(&DebugMessage) + Send + Sync + 'static) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.message_callback.replace(Arc::new(f));
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = f;
+                self
+            }
+        }
+        /// Collect [messages](DebugMessage) from the validation layers, including DebugPrintf,
+        /// defaults to false.
+        ///
+        /// Messages are retained until taken with [`Device::take_debug_messages()`].
+        pub fn capture_messages(self, capture_messages: bool) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.capture_messages = capture_messages;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = capture_messages;
+                self
+            }
+        }
+        /// The relative priority of the compute queues in 0.0 ..= 1.0, defaults to the
+        /// [power profile](DeviceBuilder::power_profile).
+        ///
+        /// This is a hint to the driver, relative to other queues on the device, ie rendering.
+        /// [Stream] 1, which executes [`High`](Priority::High) priority kernels, has twice
+        /// this priority, up to 1.0.
+        pub fn queue_priority(self, queue_priority: f32) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.queue_priority.replace(queue_priority);
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = queue_priority;
+                self
+            }
+        }
+        /// The max size of a single allocation in bytes, defaults to the device limit.
+        ///
+        /// Some drivers fail to allocate large blocks of memory, despite reporting a higher
+        /// limit. This also limits the size of the blocks that smaller buffers are sub-allocated
+        /// from. Buffers larger than this are backed by multiple allocations,
+        /// see [`DeviceInfo::max_allocation_size()`].
+        pub fn max_allocation_size(self, max_allocation_size: usize) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options
+                    .max_allocation_size
+                    .replace(max_allocation_size);
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = max_allocation_size;
+                self
+            }
+        }
+        /// The size of the blocks of memory that buffers are sub-allocated from.
+        ///
+        /// Buffers are allocated from large blocks of device memory, which are kept while the
+        /// device is alive, so that allocating and freeing buffers doesn't call the driver. By
+        /// default, the block size is 64 MB, or up to the
+        /// [max allocation size](DeviceInfo::max_allocation_size) on devices with large heaps.
+        /// Buffers larger than half the block size are allocated on their own.
+        ///
+        /// The size is rounded up to a multiple of 256 and limited to the max allocation size.
+        pub fn memory_block_size(self, memory_block_size: usize) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.memory_block_size.replace(memory_block_size);
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = memory_block_size;
+                self
+            }
+        }
+        /// Allocate buffers in device local, host visible memory if available, defaults to false.
+        ///
+        /// Integrated GPUs and devices with resizable BAR can map device memory on the host,
+        /// so buffers can be accessed without a copy with
+        /// [`.as_mapped_slice()`](crate::buffer::BufferBase::as_mapped_slice). Buffers
+        /// are allocated in device memory otherwise, ie if there is no such memory or it is full.
+        pub fn host_mapped(self, host_mapped: bool) -> Self {
             #[cfg(feature = "device")]
             {
                 let mut this = self;
-                this.options.index = index;
+                this.options.host_mapped = host_mapped;
                 this
             }
             #[cfg(not(feature = "device"))]
             {
-                let _ = index;
+                let _ = host_mapped;
+                self
+            }
+        }
+        /// Hint whether the device is used for throughput or latency, defaults to
+        /// [`Balanced`](PowerProfile::Balanced).
+        ///
+        /// See [`PowerProfile`].
+        pub fn power_profile(self, power_profile: PowerProfile) -> Self {
+            #[cfg(feature = "device")]
+            {
+                let mut this = self;
+                this.options.power_profile = power_profile;
+                this
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = power_profile;
                 self
             }
         }
         /// Creates a device.
         ///
+        /// The device may be selected with `KRNL_DEVICE`, see [`.from_env()`](DeviceBuilder::from_env).
+        ///
         /// **Errors**
         ///
         /// - [DeviceUnavailable]
-        /// - [DeviceIndexOutOfRange]
+        /// - [DeviceIndexOutOfRange]: The index is out of range of the devices matching the filters.
+        /// - The device doesn't support the [required features](DeviceBuilder::required_features).
+        /// - The [queue priority](DeviceBuilder::queue_priority) is not in 0.0 ..= 1.0.
+        /// - The [memory block size](DeviceBuilder::memory_block_size) is 0.
+        /// - Validating kernels without the "spirv-val" feature.
+        /// - The [record](DeviceBuilder::record) file could not be created.
         /// - The device could not be created.
         pub fn build(self) -> Result<Device> {
             #[cfg(feature = "device")]
             {
-                let raw = RawDevice::new(self.options)?;
+                let this = if self.options.env {
+                    self.from_env()
+                } else {
+                    self
+                };
+                if this.options.validate_spirv && cfg!(not(feature = "spirv-val")) {
+                    anyhow::bail!("`validate_spirv` requires the \"spirv-val\" feature!");
+                }
+                if let Some(queue_priority) = this.options.queue_priority {
+                    if !(0.0..=1.0).contains(&queue_priority) {
+                        anyhow::bail!("Queue priority {queue_priority} is not in 0.0 ..= 1.0!");
+                    }
+                }
+                if this.options.memory_block_size == Some(0) {
+                    anyhow::bail!("Memory block size must be non-zero!");
+                }
+                let raw = RawDevice::new(this.options)?;
                 Ok(Device {
                     inner: DeviceInner::Device(raw),
                 })
@@ -127,6 +702,45 @@ pub mod builder {
                 Err(DeviceUnavailable.into())
             }
         }
+        /// Creates each device matching the filters, in order of [`.index()`](DeviceBuilder::index).
+        ///
+        /// The index and `KRNL_DEVICE` are ignored.
+        /// ```no_run
+        /// # use krnl::{anyhow::Result, device::{Device, DeviceGroup}};
+        /// # fn main() -> Result<()> {
+        /// let devices = Device::builder().build_all()?;
+        /// let group = DeviceGroup::new(devices)?;
+        /// # Ok(())
+        /// # }
+        /// ```
+        ///
+        /// **Errors**
+        ///
+        /// See [`.build()`](DeviceBuilder::build).
+        pub fn build_all(self) -> Result<Vec<Device>> {
+            #[cfg(feature = "device")]
+            {
+                let mut devices = Vec::new();
+                loop {
+                    let builder = DeviceBuilder {
+                        options: DeviceOptions {
+                            index: devices.len(),
+                            env: false,
+                            ..self.options.clone()
+                        },
+                    };
+                    match builder.build() {
+                        Ok(device) => devices.push(device),
+                        Err(e) if e.is::<DeviceIndexOutOfRange>() => return Ok(devices),
+                        Err(e) => return Err(e),
+                    }
+                }
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                Err(DeviceUnavailable.into())
+            }
+        }
     }
 }
 use builder::*;
@@ -138,13 +752,101 @@ trait DeviceEngine {
     fn new(options: DeviceOptions) -> Result<Arc<Self>>;
     fn id(&self) -> DeviceId;
     fn info(&self) -> &Arc<DeviceInfo>;
+    fn recreate(&self) -> Result<Arc<Self>>;
     fn wait(&self) -> Result<(), DeviceLost>;
+    fn wait_stream(&self, stream: usize) -> Result<(), DeviceLost>;
+    fn flush(&self) -> Result<(), DeviceLost>;
+    fn begin_batch(&self);
+    fn end_batch(&self);
+    fn gpu_time(&self) -> Result<Option<std::time::Duration>, DeviceLost>;
+    fn wait_async(&self) -> RawWaitFuture;
+    fn wait_stream_async(&self, stream: usize) -> RawWaitFuture;
+    fn trim(&self) -> Result<(), DeviceLost>;
+    fn memory_budget(&self) -> Option<Vec<MemoryBudget>>;
+    fn take_debug_messages(&self) -> Vec<DebugMessage>;
 }
 
 #[cfg(feature = "device")]
+type RawWaitFuture = Pin<Box<dyn Future<Output = Result<(), DeviceLost>> + Send>>;
+
+#[cfg(feature = "device")]
+#[derive(Clone)]
 struct DeviceOptions {
     index: usize,
+    name_contains: Option<String>,
+    vendor: Option<Vendor>,
+    device_type: Option<DeviceType>,
+    uuid: Option<[u8; 16]>,
+    // Select the device from `KRNL_DEVICE` when built.
+    env: bool,
+    allow_software: bool,
+    prefer_software: bool,
+    queue_priority: Option<f32>,
+    power_profile: PowerProfile,
+    max_allocation_size: Option<usize>,
+    memory_block_size: Option<usize>,
+    host_mapped: bool,
     optimal_features: Features,
+    required_features: Features,
+    validate_spirv: bool,
+    verify_transfers: bool,
+    panic_dump_dir: Option<std::path::PathBuf>,
+    panic_dump_max_bytes: usize,
+    record_path: Option<std::path::PathBuf>,
+    record_max_bytes: usize,
+    performance_metrics: bool,
+    debug_printf: Option<bool>,
+    instance: InstanceConfig,
+    message_callback: Option<MessageCallback>,
+    capture_messages: bool,
+    // Recreated devices continue recording to the same trace.
+    recorder: Option<Arc<Recorder>>,
+}
+
+#[cfg(feature = "device")]
+impl DeviceOptions {
+    fn panic_dump(&self) -> Option<PanicDump> {
+        self.panic_dump_dir.clone().map(|dir| PanicDump {
+            dir,
+            max_bytes: self.panic_dump_max_bytes,
+        })
+    }
+    fn recorder(&self) -> Result<Option<Arc<Recorder>>> {
+        if let Some(recorder) = self.recorder.as_ref() {
+            return Ok(Some(recorder.clone()));
+        }
+        self.record_path
+            .clone()
+            .map(|path| Ok(Arc::new(Recorder::create(path, self.record_max_bytes)?)))
+            .transpose()
+    }
+    fn metrics(&self) -> Option<Arc<Mutex<PerformanceMetrics>>> {
+        self.performance_metrics.then(Arc::default)
+    }
+    /// Priorities for `queues` compute queues, where queue 1 is high priority.
+    fn queue_priorities(&self, queues: usize) -> Vec<f32> {
+        let priority = self.queue_priority.unwrap_or(match self.power_profile {
+            PowerProfile::Balanced => 0.5,
+            PowerProfile::Throughput => 0.25,
+            PowerProfile::Latency => 1.0,
+        });
+        (0..queues.max(1))
+            .map(|i| {
+                if i == 1 {
+                    (2.0 * priority).min(1.0)
+                } else {
+                    priority
+                }
+            })
+            .collect()
+    }
+}
+
+#[cfg(feature = "device")]
+#[derive(Clone, Debug)]
+pub(crate) struct PanicDump {
+    pub(crate) dir: std::path::PathBuf,
+    pub(crate) max_bytes: usize,
 }
 
 #[cfg(feature = "device")]
@@ -169,12 +871,16 @@ trait DeviceEngineKernel: Sized {
         key: KernelKey,
         desc_fn: impl FnOnce() -> Result<Arc<KernelDesc>>,
     ) -> Result<Arc<Self>>;
+    #[allow(clippy::too_many_arguments)]
     unsafe fn dispatch(
         &self,
         groups: u32,
+        indirect: Option<&Arc<Self::DeviceBuffer>>,
         buffers: &[Arc<Self::DeviceBuffer>],
         push_consts: Vec<u8>,
         debug_printf_panic: Option<Arc<AtomicBool>>,
+        priority: Priority,
+        stream: usize,
     ) -> Result<()>;
     fn engine(&self) -> &Arc<Self::Engine>;
     fn desc(&self) -> &Arc<KernelDesc>;
@@ -194,6 +900,24 @@ assert_ne!(a, b);
 # Ok(())
 # }
 ```
+
+# Batching
+Operations are asynchronous. Transfers and kernel dispatches are recorded into a batch, which is
+submitted by a worker thread per [stream](Stream). While a batch executes, subsequent operations
+are recorded into the next batch, which is submitted as soon as the previous one finishes. A batch
+is also limited in the number of kernels and buffers, in which case recording blocks until it
+can be submitted. See [`.flush()`](Device::flush) and [`.wait()`](Device::wait).
+
+Small operations may be submitted one at a time when the device is idle. Within
+[`.begin_batch()`](Device::begin_batch) and [`.end_batch()`](Device::end_batch), batches are held
+until full, so that they are submitted together. Waiting, either explicitly or when reading a
+buffer, submits a held batch.
+
+Dropping a buffer does not block. A batch holds the buffers it uses, which are freed when the batch
+finishes, so [`.wait()`](Device::wait) ensures that dropped buffers have been freed. Freed memory is
+kept by the device for later allocations, until [`.trim()`](Device::trim), except for allocations
+larger than half the [memory block size](DeviceBuilder::memory_block_size), which are returned to
+the driver.
 */
 #[derive(Clone, Eq, PartialEq)]
 pub struct Device {
@@ -213,15 +937,103 @@ impl Device {
             #[cfg(feature = "device")]
             options: DeviceOptions {
                 index: 0,
+                name_contains: None,
+                vendor: None,
+                device_type: None,
+                uuid: None,
+                env: true,
+                allow_software: true,
+                prefer_software: false,
+                queue_priority: None,
+                power_profile: PowerProfile::default(),
+                max_allocation_size: None,
+                memory_block_size: None,
+                host_mapped: false,
                 optimal_features: Features::empty()
                     .with_shader_int8(true)
                     .with_shader_int16(true)
                     .with_shader_int64(true)
                     .with_shader_float16(true)
-                    .with_shader_float64(true),
+                    .with_shader_float64(true)
+                    .with_subgroup_basic(true)
+                    .with_subgroup_vote(true)
+                    .with_subgroup_arithmetic(true)
+                    .with_subgroup_ballot(true)
+                    .with_subgroup_shuffle(true)
+                    .with_subgroup_size_control(true)
+                    .with_shader_atomic_float(true),
+                required_features: Features::empty(),
+                validate_spirv: false,
+                verify_transfers: false,
+                panic_dump_dir: None,
+                panic_dump_max_bytes: 64_000_000,
+                record_path: None,
+                record_max_bytes: 64_000_000,
+                performance_metrics: false,
+                debug_printf: None,
+                instance: InstanceConfig::default(),
+                message_callback: None,
+                capture_messages: false,
+                recorder: None,
             },
         }
     }
+    /** A mock device.
+
+    A mock device doesn't require a GPU. Operations are recorded, and can be retrieved with
+    [`.take_mock_ops()`](Device::take_mock_ops) for assertions in tests.
+
+    - Allocations are zeroed.
+    - Uploads, downloads, and transfers copy data.
+    - Kernels are built without a pipeline, and dispatches do not execute, leaving buffers unchanged.
+
+    This means buffer contents are deterministic, but don't reflect the results of kernels.
+    ```
+    # use krnl::{anyhow::Result, buffer::Buffer, device::{Device, MockOp}};
+    # fn main() -> Result<()> {
+    let device = Device::mock();
+    let x = Buffer::from(vec![1u32, 2, 3]).into_device(device.clone())?;
+    assert_eq!(x.to_vec()?, [1, 2, 3]);
+    assert_eq!(
+        device.take_mock_ops().unwrap(),
+        [
+            MockOp::Alloc { bytes: 12 },
+            MockOp::Upload { bytes: 12 },
+            MockOp::Download { bytes: 12 },
+        ]
+    );
+    # Ok(())
+    # }
+    ```
+    */
+    #[cfg(feature = "device")]
+    pub fn mock() -> Self {
+        let raw = RawDevice::mock(Self::builder().options);
+        Self {
+            inner: DeviceInner::Device(raw),
+        }
+    }
+    /** A device implemented by `backend`.
+
+    See [`backend`]. **Unstable.**
+    */
+    #[cfg(feature = "device")]
+    pub fn from_backend(backend: Arc<dyn Backend>) -> Self {
+        let engine = RawEngine::Backend(backend::Engine::from_backend(backend));
+        Self {
+            inner: DeviceInner::Device(RawDevice { engine }),
+        }
+    }
+    /** Takes the operations recorded by a [mock](Device::mock) device.
+
+    Returns None if not a mock device. */
+    #[cfg(feature = "device")]
+    pub fn take_mock_ops(&self) -> Option<Vec<MockOp>> {
+        match self.inner() {
+            DeviceInner::Host => None,
+            DeviceInner::Device(raw) => raw.take_mock_ops(),
+        }
+    }
     /// Is the host.
     pub fn is_host(&self) -> bool {
         self.inner.is_host()
@@ -243,6 +1055,168 @@ impl Device {
             DeviceInner::Device(raw) => Some(raw.info()),
         }
     }
+    /** Reads the power draw, temperature, and clock of the device.
+
+    The host returns None. Readings that aren't available are None, see [`Telemetry`]. */
+    pub fn telemetry(&self) -> Option<Telemetry> {
+        let pci_address = self.info()?.pci_address.as_deref();
+        Some(
+            pci_address
+                .map(Telemetry::from_pci_address)
+                .unwrap_or_default(),
+        )
+    }
+    /** Queries the memory budget and usage of each heap.
+
+    The budget is an estimate of how much memory the process can allocate from the heap, accounting
+    for other processes, and changes over time. Entries correspond to
+    [`DeviceInfo::memory_heaps()`].
+
+    Returns None if the host, or if the device doesn't support VK_EXT_memory_budget.
+    ```no_run
+    # use krnl::{anyhow::Result, device::Device};
+    # fn main() -> Result<()> {
+    let device = Device::builder().build()?;
+    let info = device.info().unwrap();
+    if let Some(budgets) = device.memory_budget() {
+        for (heap, budget) in info.memory_heaps().iter().zip(budgets) {
+            println!("{heap:?} {budget:?}");
+        }
+    }
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn memory_budget(&self) -> Option<Vec<MemoryBudget>> {
+        match self.inner() {
+            DeviceInner::Host => None,
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.memory_budget(),
+        }
+    }
+    /** Returns free memory to the driver.
+
+    Waits for the device, then frees the memory kept for later allocations, see
+    [batching](Device#batching). Memory blocks still used by buffers are freed once those buffers
+    are dropped, and later allocations use new blocks. If host, this does nothing.
+
+    **Errors**
+
+    Returns an error if the device was lost. */
+    pub fn trim(&self) -> Result<(), DeviceLost> {
+        match self.inner() {
+            DeviceInner::Host => Ok(()),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.trim(),
+        }
+    }
+    /** Raw Vulkan handles, for interop with [ash].
+
+    Returns None if the host or not a Vulkan device.
+
+    # Safety
+    The handles must not be destroyed, and must not be used after the device is dropped. Commands
+    must be synchronized with krnl, ie [`.wait()`](Device::wait) before using the handles, and do
+    not submit to the queues concurrently with krnl operations on this device.
+    */
+    #[cfg(all(not(target_arch = "wasm32"), feature = "vulkan-interop"))]
+    pub unsafe fn vulkan_handles(&self) -> Option<VulkanHandles> {
+        match self.inner() {
+            DeviceInner::Host => None,
+            DeviceInner::Device(raw) => match &raw.engine {
+                RawEngine::Vulkan(engine) => Some(engine.vulkan_handles()),
+                RawEngine::Mock(_) | RawEngine::Backend(_) => None,
+            },
+        }
+    }
+    /** Memory usage of buffers.
+
+    Returns None if the host. See [`MemoryStats`]. */
+    pub fn memory_stats(&self) -> Option<MemoryStats> {
+        match self.inner() {
+            DeviceInner::Host => None,
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => Some(raw.info().memory.stats()),
+        }
+    }
+    /** Performance metrics.
+
+    Returns None if the host, or if not enabled with
+    [`DeviceBuilder::performance_metrics`]. See [`PerformanceMetrics`]. */
+    pub fn performance_metrics(&self) -> Option<PerformanceMetrics> {
+        match self.inner() {
+            DeviceInner::Host => None,
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => Some(raw.info().metrics.as_ref()?.lock().clone()),
+        }
+    }
+    /** Takes the [messages](DebugMessage) collected since the last call.
+
+    Messages are only collected by devices created with
+    [`DeviceBuilder::capture_messages`](builder::DeviceBuilder::capture_messages). DebugPrintf
+    messages are received when the kernel finishes, so [wait](Device::wait) first.
+    */
+    pub fn take_debug_messages(&self) -> Vec<DebugMessage> {
+        match self.inner() {
+            DeviceInner::Host => Vec::new(),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.take_debug_messages(),
+        }
+    }
+    /// Resets the [performance metrics](Device::performance_metrics), if enabled.
+    pub fn reset_performance_metrics(&self) {
+        match self.inner() {
+            DeviceInner::Host => (),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => {
+                if let Some(metrics) = raw.info().metrics.as_ref() {
+                    *metrics.lock() = PerformanceMetrics::default();
+                }
+            }
+        }
+    }
+    /** Creates a new device with the same options.
+
+    After a [`DeviceLost`] error, the device and its buffers and kernels can no longer be used. A
+    long running service can recover by recreating the device, and restoring buffers from a
+    [`Checkpoint`]. The lost device is destroyed when it is dropped.
+
+    If the device is [recording](DeviceBuilder::record), dispatches on the new device are appended
+    to the same trace.
+    ```no_run
+    # use krnl::{anyhow::Result, buffer::Buffer, device::{Checkpoint, Device, error::DeviceLost}};
+    # fn step(x: &mut Buffer<f32>) -> Result<()> { x.fill(1f32) }
+    # fn main() -> Result<()> {
+    let mut device = Device::builder().build()?;
+    let mut x = Buffer::<f32>::zeros(device.clone(), 1_000)?;
+    let mut checkpoint = Checkpoint::new();
+    checkpoint.save("x", x.as_scalar_slice())?;
+    for _ in 0 .. 100 {
+        match step(&mut x) {
+            Err(e) if e.is::<DeviceLost>() => {
+                device = device.recreate()?;
+                x = checkpoint.restore("x", device.clone())?.unwrap().try_into().ok().unwrap();
+            }
+            result => result?,
+        }
+        checkpoint.save("x", x.as_scalar_slice())?;
+    }
+    # Ok(())
+    # }
+    ```
+
+    **Errors**
+    - The device is the host.
+    - The device was created with [`Device::from_backend`].
+    - See [`DeviceBuilder::build`].
+    */
+    pub fn recreate(&self) -> Result<Self> {
+        match self.inner() {
+            DeviceInner::Host => anyhow::bail!("The host can not be recreated!"),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => Ok(raw.recreate()?.into()),
+        }
+    }
     /** Wait for previous work to finish.
 
     If host, this does nothing.
@@ -254,13 +1228,292 @@ impl Device {
 
     **Errors**
 
-    Returns an error if the device was lost while waiting. */
-    pub fn wait(&self) -> Result<(), DeviceLost> {
-        match self.inner() {
-            DeviceInner::Host => Ok(()),
-            #[cfg(feature = "device")]
-            DeviceInner::Device(raw) => raw.wait(),
+    Returns an error if the device was lost while waiting. */
+    pub fn wait(&self) -> Result<(), DeviceLost> {
+        match self.inner() {
+            DeviceInner::Host => Ok(()),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.wait(),
+        }
+    }
+    /** Submit previous work without waiting for it to finish.
+
+    Blocks until work recorded so far has been submitted to the device, ie after the current batch
+    finishes, see [batching](Device#batching). Subsequent operations are recorded into a new batch.
+    If host, this does nothing.
+
+    **Errors**
+
+    Returns an error if the device was lost. */
+    pub fn flush(&self) -> Result<(), DeviceLost> {
+        match self.inner() {
+            DeviceInner::Host => Ok(()),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.flush(),
+        }
+    }
+    /** Begin a batch scope, see [batching](Device#batching).
+
+    Until the matching [`.end_batch()`](Device::end_batch), batches are only submitted when full, or
+    when waited on. This coalesces many small operations into fewer submissions.
+    ```no_run
+    # use krnl::{anyhow::Result, buffer::Buffer, device::Device};
+    # fn main() -> Result<()> {
+    let device = Device::builder().build()?;
+    let mut y = Buffer::<u32>::zeros(device.clone(), 64)?;
+    device.begin_batch();
+    for i in 0..64 {
+        y.slice_mut(i..i + 1).unwrap().fill(i as u32)?;
+    }
+    device.end_batch();
+    # Ok(())
+    # }
+    ```
+    Scopes can be nested. The scope is device wide, not per thread or [stream](Stream): while any
+    scope is open, work recorded by all threads is held, and waiting on any thread submits it. If
+    host, this does nothing. */
+    pub fn begin_batch(&self) {
+        match self.inner() {
+            DeviceInner::Host => (),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.begin_batch(),
+        }
+    }
+    /** End a batch scope started with [`.begin_batch()`](Device::begin_batch).
+
+    Recorded work is submitted once all scopes have ended. Unmatched calls do nothing. */
+    pub fn end_batch(&self) {
+        match self.inner() {
+            DeviceInner::Host => (),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.end_batch(),
+        }
+    }
+    /** A [`Stream`] for the compute queue `index`.
+
+    Stream 0 is the default, where kernels are dispatched unless specified otherwise. The host has
+    a single stream.
+
+    **Errors**
+    - `index` is out of range of the [compute queues](DeviceInfo::compute_queues).
+    */
+    pub fn stream(&self, index: usize) -> Result<Stream> {
+        let streams = self.info().map_or(1, |info| info.compute_queues);
+        if index >= streams {
+            anyhow::bail!("Stream {index} is out of range, {self:?} has {streams} streams!");
+        }
+        Ok(Stream {
+            device: self.clone(),
+            index,
+        })
+    }
+    /** Wait for previous work to finish without blocking.
+
+    Like [`.wait()`](Device::wait), but returns a future that resolves when work submitted before
+    this method is called is finished. The device wakes the task when finished, so that async
+    applications don't need to block a thread.
+    ```no_run
+    # use krnl::{anyhow::Result, buffer::Buffer, device::Device};
+    # async fn run() -> Result<()> {
+    let device = Device::builder().build()?;
+    let mut y = Buffer::<f32>::zeros(device.clone(), 1_000_000)?;
+    y.fill(1f32)?;
+    device.wait_async().await?;
+    # Ok(())
+    # }
+    ```
+    */
+    pub fn wait_async(&self) -> WaitFuture {
+        match self.inner() {
+            DeviceInner::Host => WaitFuture {
+                #[cfg(feature = "device")]
+                inner: None,
+            },
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => WaitFuture {
+                inner: Some(raw.wait_async()),
+            },
+        }
+    }
+}
+
+/** A compute queue of a device.
+
+Kernels dispatched on different streams are submitted to different queues, and may execute
+concurrently. This allows independent sequences of kernels to overlap, for example when each
+doesn't fully utilize the device. Kernels on the same stream execute in order. Transfers are
+submitted to stream 0.
+
+Like [priorities](Priority), kernels on different streams that access the same buffers are
+synchronized on the host.
+
+See [`Device::stream()`].
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# use krnl::macros::kernel;
+# #[kernel] pub fn fill(#[item] y: &mut u32) { *y = 1; }
+# }
+# use kernels::fill;
+# use krnl::{anyhow::Result, buffer::Buffer, device::Device};
+# fn main() -> Result<()> {
+let device = Device::builder().build()?;
+let streams = device.info().unwrap().compute_queues();
+let stream = device.stream(streams - 1)?;
+let mut y = Buffer::<u32>::zeros(device.clone(), 1_000)?;
+fill::builder()?
+    .build(device.clone())?
+    .with_stream(&stream)?
+    .dispatch(y.as_slice_mut())?;
+stream.wait()?;
+# Ok(())
+# }
+```
+*/
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Stream {
+    device: Device,
+    index: usize,
+}
+
+impl Stream {
+    /// The device.
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+    /// The index of the stream.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    /** Wait for work submitted to the stream to finish.
+
+    See [`Device::wait()`].
+
+    **Errors**
+
+    Returns an error if the device was lost while waiting. */
+    pub fn wait(&self) -> Result<(), DeviceLost> {
+        match self.device.inner() {
+            DeviceInner::Host => Ok(()),
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => raw.wait_stream(self.index),
+        }
+    }
+    /** Wait for work submitted to the stream to finish without blocking.
+
+    See [`Device::wait_async()`]. */
+    pub fn wait_async(&self) -> WaitFuture {
+        match self.device.inner() {
+            DeviceInner::Host => WaitFuture {
+                #[cfg(feature = "device")]
+                inner: None,
+            },
+            #[cfg(feature = "device")]
+            DeviceInner::Device(raw) => WaitFuture {
+                inner: Some(raw.wait_stream_async(self.index)),
+            },
+        }
+    }
+}
+
+/** Host copies of buffers, to restore them on a [recreated](Device::recreate) device.
+
+Buffers are saved by name, replacing previous copies. Data on a lost device can't be read, so
+buffers should be saved periodically while the device is working.
+*/
+#[derive(Debug, Default)]
+pub struct Checkpoint {
+    buffers: BTreeMap<String, ScalarBuffer>,
+}
+
+impl Checkpoint {
+    /// Creates an empty checkpoint.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Saves a host copy of `slice` as `name`.
+    ///
+    /// **Errors**
+    /// - The slice could not be copied to the host.
+    pub fn save(&mut self, name: impl Into<String>, slice: ScalarSlice) -> Result<()> {
+        let buffer = slice.to_device(Device::host())?;
+        self.buffers.insert(name.into(), buffer);
+        Ok(())
+    }
+    /// Copies the buffer saved as `name` to `device`.
+    ///
+    /// Returns None if `name` was not saved.
+    ///
+    /// **Errors**
+    /// - The buffer could not be copied to `device`.
+    pub fn restore(&self, name: &str, device: Device) -> Result<Option<ScalarBuffer>> {
+        self.buffers
+            .get(name)
+            .map(|buffer| buffer.to_device(device))
+            .transpose()
+    }
+    /// Removes the buffer saved as `name`.
+    pub fn remove(&mut self, name: &str) -> Option<ScalarBuffer> {
+        self.buffers.remove(name)
+    }
+    /// The names of saved buffers.
+    pub fn names(&self) -> impl Iterator<Item = &str> + '_ {
+        self.buffers.keys().map(String::as_str)
+    }
+}
+
+/// A future that resolves when a device or stream is finished.
+///
+/// See [`Device::wait_async()`], [`Stream::wait_async()`], and
+/// [Dispatch](crate::kernel#dispatch).
+#[must_use = "futures do nothing unless you `.await` or poll them"]
+pub struct WaitFuture {
+    #[cfg(feature = "device")]
+    inner: Option<RawWaitFuture>,
+}
+
+impl WaitFuture {
+    /// Blocks the current thread until the device is finished.
+    pub fn block(self) -> Result<(), DeviceLost> {
+        block_on(self)
+    }
+}
+
+/// Blocks the current thread on `future`.
+pub(crate) fn block_on<F: Future>(future: F) -> F::Output {
+    struct ThreadWaker(std::thread::Thread);
+
+    impl std::task::Wake for ThreadWaker {
+        fn wake(self: Arc<Self>) {
+            self.0.unpark();
+        }
+    }
+
+    let waker = Arc::new(ThreadWaker(std::thread::current())).into();
+    let mut cx = Context::from_waker(&waker);
+    let mut future = std::pin::pin!(future);
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+        std::thread::park();
+    }
+}
+
+impl Future for WaitFuture {
+    type Output = Result<(), DeviceLost>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        #[cfg(feature = "device")]
+        if let Some(inner) = self.get_mut().inner.as_mut() {
+            return inner.as_mut().poll(cx);
         }
+        let _ = cx;
+        Poll::Ready(Ok(()))
+    }
+}
+
+impl Debug for WaitFuture {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("WaitFuture").finish_non_exhaustive()
     }
 }
 
@@ -316,30 +1569,155 @@ impl Debug for DeviceInner {
     }
 }
 
+/// An operation recorded by a [mock](Device::mock) device.
+#[cfg(feature = "device")]
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MockOp {
+    /// Allocated a buffer.
+    Alloc {
+        /// Size in bytes.
+        bytes: usize,
+    },
+    /// Copied from the host to the device.
+    Upload {
+        /// Size in bytes.
+        bytes: usize,
+    },
+    /// Copied from the device to the host.
+    Download {
+        /// Size in bytes.
+        bytes: usize,
+    },
+    /// Copied to another device.
+    Transfer {
+        /// Size in bytes.
+        bytes: usize,
+    },
+    /// Copied within the device.
+    Copy {
+        /// Size in bytes.
+        bytes: usize,
+    },
+    /// Dispatched a kernel.
+    Dispatch {
+        /// The name of the kernel.
+        kernel: String,
+        /// The number of groups.
+        groups: u32,
+        /// The byte range of the buffer the groups were read from, if dispatched indirectly.
+        indirect: Option<Range<usize>>,
+        /// The byte range of each buffer.
+        buffers: Vec<Range<usize>>,
+        /// The push constants.
+        push_consts: Vec<u8>,
+        /// The priority.
+        priority: Priority,
+        /// The [stream](Stream) index.
+        stream: usize,
+    },
+}
+
+#[cfg(feature = "device")]
+#[derive(Clone)]
+enum RawEngine {
+    Vulkan(Arc<Engine>),
+    Mock(Arc<mock_engine::Engine>),
+    Backend(Arc<backend::Engine>),
+}
+
+/// Evaluates `$body` with `$x` bound to the engine of each variant.
+#[cfg(feature = "device")]
+macro_rules! match_engine {
+    ($engine:expr, $x:ident => $body:expr) => {
+        match $engine {
+            RawEngine::Vulkan($x) => $body,
+            RawEngine::Mock($x) => $body,
+            RawEngine::Backend($x) => $body,
+        }
+    };
+}
+
 #[cfg(feature = "device")]
 #[derive(Clone)]
 pub(crate) struct RawDevice {
-    engine: Arc<Engine>,
+    engine: RawEngine,
 }
 
 #[cfg(feature = "device")]
 impl RawDevice {
     fn new(options: DeviceOptions) -> Result<Self> {
-        let engine = Engine::new(options)?;
+        let engine = RawEngine::Vulkan(Engine::new(options)?);
         Ok(Self { engine })
     }
+    fn mock(options: DeviceOptions) -> Self {
+        let engine = RawEngine::Mock(mock_engine::Engine::new(options).unwrap());
+        Self { engine }
+    }
+    pub(crate) fn id(&self) -> DeviceId {
+        match_engine!(&self.engine, engine => engine.id())
+    }
     pub(crate) fn info(&self) -> &Arc<DeviceInfo> {
-        self.engine.info()
+        match_engine!(&self.engine, engine => engine.info())
+    }
+    fn recreate(&self) -> Result<Self> {
+        let engine = match &self.engine {
+            RawEngine::Vulkan(engine) => RawEngine::Vulkan(engine.recreate()?),
+            RawEngine::Mock(engine) => RawEngine::Mock(engine.recreate()?),
+            RawEngine::Backend(engine) => RawEngine::Backend(engine.recreate()?),
+        };
+        Ok(Self { engine })
     }
     pub(crate) fn wait(&self) -> Result<(), DeviceLost> {
-        self.engine.wait()
+        match_engine!(&self.engine, engine => engine.wait())
+    }
+    fn wait_stream(&self, stream: usize) -> Result<(), DeviceLost> {
+        match_engine!(&self.engine, engine => engine.wait_stream(stream))
+    }
+    fn flush(&self) -> Result<(), DeviceLost> {
+        match_engine!(&self.engine, engine => engine.flush())
+    }
+    fn begin_batch(&self) {
+        match_engine!(&self.engine, engine => engine.begin_batch())
+    }
+    fn end_batch(&self) {
+        match_engine!(&self.engine, engine => engine.end_batch())
+    }
+    pub(crate) fn gpu_time(&self) -> Result<Option<std::time::Duration>, DeviceLost> {
+        match_engine!(&self.engine, engine => engine.gpu_time())
+    }
+    fn wait_async(&self) -> RawWaitFuture {
+        match_engine!(&self.engine, engine => engine.wait_async())
+    }
+    fn wait_stream_async(&self, stream: usize) -> RawWaitFuture {
+        match_engine!(&self.engine, engine => engine.wait_stream_async(stream))
+    }
+    fn trim(&self) -> Result<(), DeviceLost> {
+        match_engine!(&self.engine, engine => engine.trim())
+    }
+    fn memory_budget(&self) -> Option<Vec<MemoryBudget>> {
+        match_engine!(&self.engine, engine => engine.memory_budget())
+    }
+    fn take_debug_messages(&self) -> Vec<DebugMessage> {
+        match_engine!(&self.engine, engine => engine.take_debug_messages())
+    }
+    fn take_mock_ops(&self) -> Option<Vec<MockOp>> {
+        match &self.engine {
+            RawEngine::Vulkan(_) | RawEngine::Backend(_) => None,
+            RawEngine::Mock(engine) => Some(engine.take_ops()),
+        }
     }
 }
 
 #[cfg(feature = "device")]
 impl PartialEq for RawDevice {
     fn eq(&self, other: &Self) -> bool {
-        Arc::ptr_eq(&self.engine, &other.engine)
+        match (&self.engine, &other.engine) {
+            (RawEngine::Vulkan(a), RawEngine::Vulkan(b)) => Arc::ptr_eq(a, b),
+            (RawEngine::Mock(a), RawEngine::Mock(b)) => Arc::ptr_eq(a, b),
+            (RawEngine::Backend(a), RawEngine::Backend(b)) => Arc::ptr_eq(a, b),
+            _ => false,
+        }
     }
 }
 
@@ -363,61 +1741,576 @@ impl Debug for DeviceId {
 #[cfg(feature = "device")]
 impl Debug for RawDevice {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        self.engine.id().fmt(f)
+        self.id().fmt(f)
     }
 }
 
 #[cfg(feature = "device")]
-#[repr(transparent)]
 #[derive(Clone)]
-pub(crate) struct DeviceBuffer {
-    inner: Arc<<Engine as DeviceEngine>::DeviceBuffer>,
+enum DeviceBufferInner {
+    Vulkan(Arc<<Engine as DeviceEngine>::DeviceBuffer>),
+    Mock(Arc<<mock_engine::Engine as DeviceEngine>::DeviceBuffer>),
+    Backend(Arc<<backend::Engine as DeviceEngine>::DeviceBuffer>),
+}
+
+/// Evaluates `$body` with `$x` bound to the buffer of each variant.
+#[cfg(feature = "device")]
+macro_rules! match_buffer {
+    ($buffer:expr, $x:ident => $body:expr) => {
+        match $buffer {
+            DeviceBufferInner::Vulkan($x) => $body,
+            DeviceBufferInner::Mock($x) => $body,
+            DeviceBufferInner::Backend($x) => $body,
+        }
+    };
+}
+
+#[cfg(feature = "device")]
+fn verify_transfer(expected: &[u8], found: &[u8]) -> Result<(), TransferCorrupted> {
+    if let Some(offset) = expected.iter().zip(found).position(|(a, b)| a != b) {
+        return Err(TransferCorrupted {
+            bytes: expected.len(),
+            offset,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(feature = "device")]
+impl DeviceBufferInner {
+    fn offset(&self) -> usize {
+        match_buffer!(self, buffer => buffer.offset())
+    }
+    fn len(&self) -> usize {
+        match_buffer!(self, buffer => buffer.len())
+    }
+    fn slice(&self, range: Range<usize>) -> Option<Self> {
+        let inner = match self {
+            Self::Vulkan(buffer) => Self::Vulkan(buffer.slice(range)?),
+            Self::Mock(buffer) => Self::Mock(buffer.slice(range)?),
+            Self::Backend(buffer) => Self::Backend(buffer.slice(range)?),
+        };
+        Some(inner)
+    }
+    fn upload(&self, data: &[u8]) -> Result<()> {
+        match_buffer!(self, buffer => buffer.upload(data))
+    }
+    fn download(&self, data: &mut [u8]) -> Result<()> {
+        match_buffer!(self, buffer => buffer.download(data))
+    }
+    fn copy(&self, dst: &Self) -> Result<bool> {
+        match (self, dst) {
+            (Self::Vulkan(src), Self::Vulkan(dst)) => src.copy(dst)?,
+            (Self::Mock(src), Self::Mock(dst)) => src.copy(dst),
+            (Self::Backend(src), Self::Backend(dst)) => return src.copy(dst),
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
+    fn transfer(&self, dst: &Self) -> Result<bool> {
+        match (self, dst) {
+            (Self::Vulkan(src), Self::Vulkan(dst)) => src.transfer(dst)?,
+            (Self::Mock(src), Self::Mock(dst)) => src.transfer(dst)?,
+            (Self::Backend(src), Self::Backend(dst)) => src.transfer(dst)?,
+            _ => return Ok(false),
+        }
+        Ok(true)
+    }
 }
 
 #[cfg(feature = "device")]
-fn cast_device_buffers(buffers: &[DeviceBuffer]) -> &[Arc<<Engine as DeviceEngine>::DeviceBuffer>] {
-    // # Safety
-    // Safe because transparent
-    unsafe { std::slice::from_raw_parts(buffers.as_ptr() as _, buffers.len()) }
+#[derive(Clone)]
+pub(crate) struct DeviceBuffer {
+    inner: DeviceBufferInner,
+    // Buffers larger than the max allocation size are backed by multiple chunks, `inner` is the
+    // first and `chunks` are the rest.
+    chunks: Option<Arc<[DeviceBufferInner]>>,
+    // The offset of the chunk of `inner` in the buffer.
+    chunk_offset: usize,
+    // Shared by slices, tracks the allocation in `Device::memory_stats()`.
+    allocation: Option<Arc<MemoryAllocation>>,
+    pinned: bool,
 }
 
 #[cfg(feature = "device")]
 impl DeviceBuffer {
-    const MAX_SIZE: usize = i32::MAX as usize;
+    const MAX_SIZE: usize = isize::MAX as usize;
+    // Vulkan and mock allocations, such that a slice of one allocation fits in a u32.
+    const MAX_ALLOCATION_SIZE: usize = i32::MAX as usize;
     pub(crate) unsafe fn uninit(device: RawDevice, len: usize) -> Result<Self> {
+        unsafe { Self::uninit_impl(device, len, false) }
+    }
+    /// Allocates in pinned host memory if supported.
+    pub(crate) unsafe fn uninit_pinned(device: RawDevice, len: usize) -> Result<Self> {
+        unsafe { Self::uninit_impl(device, len, true) }
+    }
+    unsafe fn uninit_impl(device: RawDevice, len: usize, pinned: bool) -> Result<Self> {
         if len > Self::MAX_SIZE {
             return Err(DeviceBufferTooLarge { bytes: len }.into());
         }
-        let inner =
-            unsafe { <Engine as DeviceEngine>::DeviceBuffer::uninit(device.engine, len)?.into() };
-        Ok(Self { inner })
+        // Chunks are split on item boundaries.
+        let chunk_size = (device.info().max_allocation_size / 8 * 8).max(8);
+        let mut chunks = Vec::new();
+        let mut start = 0;
+        while chunks.is_empty() || start < len {
+            let chunk_len = chunk_size.min(len - start);
+            chunks.push(unsafe { Self::uninit_chunk(&device, chunk_len, pinned)? });
+            start += chunk_len;
+        }
+        let memory = device.info().memory.clone();
+        let allocation = (len > 0).then(|| Arc::new(memory.alloc(len)));
+        let inner = chunks.remove(0);
+        let chunks = (!chunks.is_empty()).then(|| chunks.into());
+        Ok(Self {
+            inner,
+            chunks,
+            chunk_offset: 0,
+            allocation,
+            pinned,
+        })
+    }
+    unsafe fn uninit_chunk(
+        device: &RawDevice,
+        len: usize,
+        pinned: bool,
+    ) -> Result<DeviceBufferInner> {
+        let inner = match device.engine.clone() {
+            RawEngine::Vulkan(engine) => {
+                let buffer = if pinned {
+                    unsafe { vulkan_engine::DeviceBuffer::uninit_pinned(engine, len)? }
+                } else {
+                    unsafe { <Engine as DeviceEngine>::DeviceBuffer::uninit(engine, len)? }
+                };
+                DeviceBufferInner::Vulkan(buffer.into())
+            }
+            RawEngine::Mock(engine) => DeviceBufferInner::Mock(
+                unsafe {
+                    <mock_engine::Engine as DeviceEngine>::DeviceBuffer::uninit(engine, len)?
+                }
+                .into(),
+            ),
+            RawEngine::Backend(engine) => DeviceBufferInner::Backend(
+                unsafe { <backend::Engine as DeviceEngine>::DeviceBuffer::uninit(engine, len)? }
+                    .into(),
+            ),
+        };
+        Ok(inner)
+    }
+    /// Whether allocated with [`.uninit_pinned()`](DeviceBuffer::uninit_pinned).
+    pub(crate) fn pinned(&self) -> bool {
+        self.pinned
+    }
+    /// Whether backed by more than one chunk, see [`DeviceInfo::max_allocation_size()`].
+    ///
+    /// Kernels can't bind chunked buffers.
+    pub(crate) fn chunked(&self) -> bool {
+        self.chunks.is_some()
+    }
+    /// The ranges in bytes of each chunk.
+    pub(crate) fn chunk_ranges(&self) -> Vec<Range<usize>> {
+        let mut start = 0;
+        self.chunks()
+            .map(|chunk| {
+                let end = start + chunk.len();
+                let range = start..end;
+                start = end;
+                range
+            })
+            .collect()
+    }
+    fn chunks(&self) -> impl Iterator<Item = &DeviceBufferInner> {
+        std::iter::once(&self.inner).chain(self.chunks.iter().flat_map(|chunks| chunks.iter()))
+    }
+    /// Calls `f` with each chunk of `self` and the matching slice of `dst`, which must have the
+    /// same length.
+    fn try_for_each_chunk_pair(
+        &self,
+        dst: &Self,
+        mut f: impl FnMut(&DeviceBufferInner, &DeviceBufferInner) -> Result<()>,
+    ) -> Result<()> {
+        debug_assert_eq!(self.len(), dst.len());
+        let mut start = 0;
+        for src_chunk in self.chunks() {
+            let end = start + src_chunk.len();
+            let dst = dst.slice(start..end).unwrap();
+            let mut src_start = 0;
+            for dst_chunk in dst.chunks() {
+                let src_end = src_start + dst_chunk.len();
+                f(&src_chunk.slice(src_start..src_end).unwrap(), dst_chunk)?;
+                src_start = src_end;
+            }
+            start = end;
+        }
+        Ok(())
+    }
+    /// The memory mapped on the host, if any.
+    ///
+    /// Waits for the device to finish writing, and reading if `mutable`.
+    pub(crate) fn mapped_ptr(&self, mutable: bool) -> Result<Option<NonNull<u8>>, DeviceLost> {
+        if self.chunked() {
+            return Ok(None);
+        }
+        match &self.inner {
+            DeviceBufferInner::Vulkan(buffer) => buffer.mapped_ptr(mutable),
+            DeviceBufferInner::Mock(_) | DeviceBufferInner::Backend(_) => Ok(None),
+        }
     }
     pub(crate) fn upload(&self, data: &[u8]) -> Result<()> {
-        self.inner.upload(data)
+        let start = Instant::now();
+        for (chunk, range) in self.chunks().zip(self.chunk_ranges()) {
+            chunk.upload(&data[range])?;
+        }
+        if let Some(metrics) = self.metrics() {
+            metrics.lock().record_upload(data.len(), start.elapsed());
+        }
+        if self.verify_transfers() {
+            let mut output = vec![0u8; data.len()];
+            self.read(&mut output)?;
+            verify_transfer(data, &output)?;
+        }
+        Ok(())
     }
     pub(crate) fn download(&self, data: &mut [u8]) -> Result<()> {
-        self.inner.download(data)
+        let start = Instant::now();
+        self.read(data)?;
+        if let Some(metrics) = self.metrics() {
+            metrics.lock().record_download(data.len(), start.elapsed());
+        }
+        if self.verify_transfers() {
+            let mut output = vec![0u8; data.len()];
+            self.read(&mut output)?;
+            verify_transfer(data, &output)?;
+        }
+        Ok(())
+    }
+    /// Downloads each chunk, without recording metrics or verifying.
+    fn read(&self, data: &mut [u8]) -> Result<()> {
+        for (chunk, range) in self.chunks().zip(self.chunk_ranges()) {
+            chunk.download(&mut data[range])?;
+        }
+        Ok(())
+    }
+    #[cfg(all(not(target_arch = "wasm32"), feature = "vulkan-interop"))]
+    pub(crate) fn vulkan_buffer(&self) -> Option<VulkanBuffer> {
+        if self.chunked() {
+            return None;
+        }
+        match &self.inner {
+            DeviceBufferInner::Vulkan(buffer) => buffer.vulkan_buffer(),
+            DeviceBufferInner::Mock(_) | DeviceBufferInner::Backend(_) => None,
+        }
+    }
+    /// Fills with `data` repeated, without a kernel.
+    ///
+    /// Returns false if not supported, in which case a kernel is used. Chunked buffers must be
+    /// filled per chunk.
+    pub(crate) fn fill(&self, data: u32) -> Result<bool> {
+        debug_assert!(!self.chunked());
+        // The end of the allocation is padded, so the whole buffer can be filled
+        // regardless of alignment.
+        let whole = self.offset() == 0
+            && self
+                .allocation
+                .as_ref()
+                .is_some_and(|allocation| allocation.bytes() == self.len());
+        match &self.inner {
+            DeviceBufferInner::Vulkan(buffer) => buffer.fill(data, whole),
+            DeviceBufferInner::Mock(_) | DeviceBufferInner::Backend(_) => Ok(false),
+        }
+    }
+    /// Copies to `dst` on the same device, without a kernel.
+    ///
+    /// Returns false if not supported, in which case a kernel is used.
+    pub(crate) fn copy(&self, dst: &Self) -> Result<bool> {
+        let mut copied = true;
+        self.try_for_each_chunk_pair(dst, |src, dst| {
+            copied &= src.copy(dst)?;
+            Ok(())
+        })?;
+        Ok(copied)
     }
     pub(crate) fn transfer(&self, dst: &Self) -> Result<()> {
-        self.inner.transfer(&dst.inner)
+        let mut transferred = true;
+        self.try_for_each_chunk_pair(dst, |src, dst| {
+            transferred &= src.transfer(dst)?;
+            Ok(())
+        })?;
+        if !transferred {
+            let mut data = vec![0u8; self.len()];
+            self.download(&mut data)?;
+            return dst.upload(&data);
+        }
+        if self.verify_transfers() || dst.verify_transfers() {
+            let mut input = vec![0u8; self.len()];
+            self.read(&mut input)?;
+            let mut output = vec![0u8; dst.len()];
+            dst.read(&mut output)?;
+            verify_transfer(&input, &output)?;
+        }
+        Ok(())
+    }
+    fn verify_transfers(&self) -> bool {
+        match_buffer!(&self.inner, buffer => buffer.engine().info().verify_transfers)
+    }
+    fn metrics(&self) -> Option<&Mutex<PerformanceMetrics>> {
+        match_buffer!(&self.inner, buffer => buffer.engine().info().metrics.as_deref())
     }
     pub(crate) fn offset(&self) -> usize {
+        self.chunk_offset + self.inner.offset()
+    }
+    /// The offset in the allocation bound by kernels, which is the chunk of the buffer.
+    pub(crate) fn binding_offset(&self) -> usize {
+        debug_assert!(!self.chunked());
         self.inner.offset()
     }
     pub(crate) fn len(&self) -> usize {
-        self.inner.len()
+        self.chunks().map(DeviceBufferInner::len).sum()
     }
     pub(crate) fn device(&self) -> RawDevice {
-        RawDevice {
-            engine: self.inner.engine().clone(),
-        }
+        let engine = match &self.inner {
+            DeviceBufferInner::Vulkan(buffer) => RawEngine::Vulkan(buffer.engine().clone()),
+            DeviceBufferInner::Mock(buffer) => RawEngine::Mock(buffer.engine().clone()),
+            DeviceBufferInner::Backend(buffer) => RawEngine::Backend(buffer.engine().clone()),
+        };
+        RawDevice { engine }
     }
     pub(crate) fn slice(&self, range: Range<usize>) -> Option<Self> {
-        let inner = self.inner.slice(range)?;
-        Some(Self { inner })
+        let Range { start, end } = range;
+        if start > end {
+            return None;
+        }
+        let mut chunks = Vec::new();
+        let mut chunk_offset = 0;
+        let mut chunk_start = 0;
+        for chunk in self.chunks() {
+            let chunk_end = chunk_start + chunk.len();
+            // Empty slices are within the first chunk that contains `start`.
+            let overlaps = if start == end {
+                chunks.is_empty() && start <= chunk_end
+            } else {
+                start < chunk_end && chunk_start < end
+            };
+            if overlaps {
+                let range = start.max(chunk_start) - chunk_start..end.min(chunk_end) - chunk_start;
+                let chunk = chunk.slice(range)?;
+                if chunks.is_empty() {
+                    chunk_offset = self.offset() + start.max(chunk_start) - chunk.offset();
+                }
+                chunks.push(chunk);
+            }
+            chunk_start = chunk_end;
+        }
+        if end > chunk_start || chunks.is_empty() {
+            return None;
+        }
+        let inner = chunks.remove(0);
+        let chunks = (!chunks.is_empty()).then(|| chunks.into());
+        Some(Self {
+            inner,
+            chunks,
+            chunk_offset,
+            allocation: self.allocation.clone(),
+            pinned: self.pinned,
+        })
+    }
+}
+
+/** Configuration of the Vulkan instance, see [`DeviceBuilder::instance`].
+
+Each device creates its own instance. The application and engine names identify krnl to drivers
+and tools, ie for profiles and debugging when other Vulkan users share the process.
+
+```no_run
+# use krnl::{anyhow::Result, device::{Device, InstanceConfig}};
+# fn main() -> Result<()> {
+let device = Device::builder()
+    .instance(
+        InstanceConfig::new()
+            .with_application_name("my-app")
+            .with_extensions(["VK_KHR_get_surface_capabilities2"]),
+    )
+    .build()?;
+# Ok(())
+# }
+```
+*/
+#[derive(Default, Clone, Debug, PartialEq, Eq)]
+pub struct InstanceConfig {
+    application_name: Option<String>,
+    engine_name: Option<String>,
+    extensions: Vec<String>,
+    layers: Vec<String>,
+    validation: bool,
+}
+
+impl InstanceConfig {
+    /// The default configuration.
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The application name, defaults to None.
+    pub fn application_name(&self) -> Option<&str> {
+        self.application_name.as_deref()
+    }
+    /// Sets the application name.
+    pub fn with_application_name(self, application_name: impl Into<String>) -> Self {
+        Self {
+            application_name: Some(application_name.into()),
+            ..self
+        }
+    }
+    /// The engine name, defaults to "krnl".
+    pub fn engine_name(&self) -> &str {
+        self.engine_name.as_deref().unwrap_or("krnl")
+    }
+    /// Sets the engine name.
+    pub fn with_engine_name(self, engine_name: impl Into<String>) -> Self {
+        Self {
+            engine_name: Some(engine_name.into()),
+            ..self
+        }
+    }
+    /// Additional instance extensions, ie "VK_KHR_surface".
+    pub fn extensions(&self) -> &[String] {
+        &self.extensions
+    }
+    /// Adds required instance extensions.
+    ///
+    /// Creating the device will fail if an extension is not supported.
+    pub fn with_extensions(
+        mut self,
+        extensions: impl IntoIterator<Item = impl Into<String>>,
+    ) -> Self {
+        self.extensions
+            .extend(extensions.into_iter().map(Into::into));
+        self
+    }
+    /// Additional layers, ie "VK_LAYER_KHRONOS_synchronization2".
+    pub fn layers(&self) -> &[String] {
+        &self.layers
+    }
+    /// Adds required layers.
+    ///
+    /// Creating the device will fail if a layer is not available.
+    pub fn with_layers(mut self, layers: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.layers.extend(layers.into_iter().map(Into::into));
+        self
+    }
+    /// Whether the Khronos validation layer is enabled, defaults to false.
+    pub fn validation(&self) -> bool {
+        self.validation
+    }
+    /// Enables the Khronos validation layer.
+    pub fn with_validation(self, validation: bool) -> Self {
+        Self { validation, ..self }
+    }
+}
+
+/// The type of a device, see [`DeviceBuilder::device_type`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum DeviceType {
+    /// A discrete GPU.
+    Discrete,
+    /// A GPU integrated with the CPU.
+    Integrated,
+    /// A virtual GPU, for example in a virtual machine.
+    Virtual,
+    /// A CPU, for example llvmpipe or SwiftShader.
+    Cpu,
+    /// Something else.
+    Other,
+}
+
+/// The vendor of a device, see [`DeviceBuilder::vendor`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Vendor {
+    /// AMD.
+    Amd,
+    /// Apple.
+    Apple,
+    /// ARM.
+    Arm,
+    /// Intel.
+    Intel,
+    /// NVIDIA.
+    Nvidia,
+    /// Qualcomm.
+    Qualcomm,
+    /// Another vendor, by PCI vendor id.
+    Other(u32),
+}
+
+impl Vendor {
+    /// The vendor for the PCI vendor id.
+    /// ```
+    /// # use krnl::device::Vendor;
+    /// assert_eq!(Vendor::from_id(0x10DE), Vendor::Nvidia);
+    /// assert_eq!(Vendor::from_id(0x1234).id(), 0x1234);
+    /// ```
+    pub fn from_id(id: u32) -> Self {
+        match id {
+            0x1002 => Self::Amd,
+            0x106B => Self::Apple,
+            0x13B5 => Self::Arm,
+            0x8086 => Self::Intel,
+            0x10DE => Self::Nvidia,
+            0x5143 => Self::Qualcomm,
+            id => Self::Other(id),
+        }
+    }
+    /// The PCI vendor id.
+    pub fn id(&self) -> u32 {
+        match self {
+            Self::Amd => 0x1002,
+            Self::Apple => 0x106B,
+            Self::Arm => 0x13B5,
+            Self::Intel => 0x8086,
+            Self::Nvidia => 0x10DE,
+            Self::Qualcomm => 0x5143,
+            Self::Other(id) => *id,
+        }
     }
 }
 
+/** The priority of a dispatch.
+
+Devices with multiple compute queues submit [`High`](Priority::High) priority kernels to a
+separate queue ([stream](Stream) 1), so that they can execute before (or concurrently with) previously submitted
+[`Normal`](Priority::Normal) priority work, for example a latency critical kernel after a
+long running batch. Otherwise all kernels are submitted to the same queue in order.
+
+Kernels of different priorities that access the same buffers are synchronized on the host,
+such that a high priority kernel waits for normal priority work using its buffers to finish,
+and vice versa.
+
+See [Dispatch](crate::kernel#priority).
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Priority {
+    /// The default priority.
+    #[default]
+    Normal,
+    /// Executed before normal priority work if possible.
+    High,
+}
+
+/** A hint whether a device is used for throughput or latency, see
+[`DeviceBuilder::power_profile`].
+
+This selects the [queue priority](DeviceBuilder::queue_priority) relative to other queues on the
+device, for example so that background compute does not starve rendering.
+*/
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PowerProfile {
+    /// Queue priority 0.5.
+    #[default]
+    Balanced,
+    /// Long running background work, queue priority 0.25.
+    Throughput,
+    /// Latency sensitive work, queue priority 1.0.
+    Latency,
+}
+
 /** Features
 
 Features supported by a device. See [`DeviceInfo::features`].
@@ -447,6 +2340,13 @@ pub struct Features {
     shader_int64: bool,
     shader_float16: bool,
     shader_float64: bool,
+    subgroup_basic: bool,
+    subgroup_vote: bool,
+    subgroup_arithmetic: bool,
+    subgroup_ballot: bool,
+    subgroup_shuffle: bool,
+    subgroup_size_control: bool,
+    shader_atomic_float: bool,
 }
 
 impl Features {
@@ -458,6 +2358,13 @@ impl Features {
             shader_int64: false,
             shader_float16: false,
             shader_float64: false,
+            subgroup_basic: false,
+            subgroup_vote: false,
+            subgroup_arithmetic: false,
+            subgroup_ballot: false,
+            subgroup_shuffle: false,
+            subgroup_size_control: false,
+            shader_atomic_float: false,
         }
     }
     /// 8 bit scalars.
@@ -505,6 +2412,69 @@ impl Features {
         self.shader_float64 = shader_float64;
         self
     }
+    /// Basic subgroup operations, ie elect and barriers.
+    pub const fn subgroup_basic(&self) -> bool {
+        self.subgroup_basic
+    }
+    /// Adds `subgroup_basic`.
+    pub const fn with_subgroup_basic(mut self, subgroup_basic: bool) -> Self {
+        self.subgroup_basic = subgroup_basic;
+        self
+    }
+    /// Subgroup vote operations, ie all and any.
+    pub const fn subgroup_vote(&self) -> bool {
+        self.subgroup_vote
+    }
+    /// Adds `subgroup_vote`.
+    pub const fn with_subgroup_vote(mut self, subgroup_vote: bool) -> Self {
+        self.subgroup_vote = subgroup_vote;
+        self
+    }
+    /// Subgroup arithmetic operations, ie reductions and scans.
+    pub const fn subgroup_arithmetic(&self) -> bool {
+        self.subgroup_arithmetic
+    }
+    /// Adds `subgroup_arithmetic`.
+    pub const fn with_subgroup_arithmetic(mut self, subgroup_arithmetic: bool) -> Self {
+        self.subgroup_arithmetic = subgroup_arithmetic;
+        self
+    }
+    /// Subgroup ballot and broadcast operations.
+    pub const fn subgroup_ballot(&self) -> bool {
+        self.subgroup_ballot
+    }
+    /// Adds `subgroup_ballot`.
+    pub const fn with_subgroup_ballot(mut self, subgroup_ballot: bool) -> Self {
+        self.subgroup_ballot = subgroup_ballot;
+        self
+    }
+    /// Subgroup shuffle operations.
+    pub const fn subgroup_shuffle(&self) -> bool {
+        self.subgroup_shuffle
+    }
+    /// Adds `subgroup_shuffle`.
+    pub const fn with_subgroup_shuffle(mut self, subgroup_shuffle: bool) -> Self {
+        self.subgroup_shuffle = subgroup_shuffle;
+        self
+    }
+    /// Control of the number of threads per subgroup (VK_EXT_subgroup_size_control).
+    pub const fn subgroup_size_control(&self) -> bool {
+        self.subgroup_size_control
+    }
+    /// Adds `subgroup_size_control`.
+    pub const fn with_subgroup_size_control(mut self, subgroup_size_control: bool) -> Self {
+        self.subgroup_size_control = subgroup_size_control;
+        self
+    }
+    /// f32 atomic add on buffers (VK_EXT_shader_atomic_float).
+    pub const fn shader_atomic_float(&self) -> bool {
+        self.shader_atomic_float
+    }
+    /// Adds `shader_atomic_float`.
+    pub const fn with_shader_atomic_float(mut self, shader_atomic_float: bool) -> Self {
+        self.shader_atomic_float = shader_atomic_float;
+        self
+    }
     /// Contains all features of `other`.
     pub const fn contains(&self, other: &Features) -> bool {
         (self.shader_int8 || !other.shader_int8)
@@ -512,6 +2482,13 @@ impl Features {
             && (self.shader_int64 || !other.shader_int64)
             && (self.shader_float16 || !other.shader_float16)
             && (self.shader_float64 || !other.shader_float64)
+            && (self.subgroup_basic || !other.subgroup_basic)
+            && (self.subgroup_vote || !other.subgroup_vote)
+            && (self.subgroup_arithmetic || !other.subgroup_arithmetic)
+            && (self.subgroup_ballot || !other.subgroup_ballot)
+            && (self.subgroup_shuffle || !other.subgroup_shuffle)
+            && (self.subgroup_size_control || !other.subgroup_size_control)
+            && (self.shader_atomic_float || !other.shader_atomic_float)
     }
     /// All features of `self` and `other`.
     pub const fn union(mut self, other: &Features) -> Self {
@@ -520,8 +2497,49 @@ impl Features {
         self.shader_int64 |= other.shader_int64;
         self.shader_float16 |= other.shader_float16;
         self.shader_float64 |= other.shader_float64;
+        self.subgroup_basic |= other.subgroup_basic;
+        self.subgroup_vote |= other.subgroup_vote;
+        self.subgroup_arithmetic |= other.subgroup_arithmetic;
+        self.subgroup_ballot |= other.subgroup_ballot;
+        self.subgroup_shuffle |= other.subgroup_shuffle;
+        self.subgroup_size_control |= other.subgroup_size_control;
+        self.shader_atomic_float |= other.shader_atomic_float;
+        self
+    }
+    /// Features of `self` not in `other`.
+    pub const fn difference(mut self, other: &Features) -> Self {
+        self.shader_int8 &= !other.shader_int8;
+        self.shader_int16 &= !other.shader_int16;
+        self.shader_int64 &= !other.shader_int64;
+        self.shader_float16 &= !other.shader_float16;
+        self.shader_float64 &= !other.shader_float64;
+        self.subgroup_basic &= !other.subgroup_basic;
+        self.subgroup_vote &= !other.subgroup_vote;
+        self.subgroup_arithmetic &= !other.subgroup_arithmetic;
+        self.subgroup_ballot &= !other.subgroup_ballot;
+        self.subgroup_shuffle &= !other.subgroup_shuffle;
+        self.subgroup_size_control &= !other.subgroup_size_control;
+        self.shader_atomic_float &= !other.shader_atomic_float;
         self
     }
+    pub(crate) fn names(&self) -> impl Iterator<Item = &'static str> {
+        [
+            (self.shader_int8, "shader_int8"),
+            (self.shader_int16, "shader_int16"),
+            (self.shader_int64, "shader_int64"),
+            (self.shader_float16, "shader_float16"),
+            (self.shader_float64, "shader_float64"),
+            (self.subgroup_basic, "subgroup_basic"),
+            (self.subgroup_vote, "subgroup_vote"),
+            (self.subgroup_arithmetic, "subgroup_arithmetic"),
+            (self.subgroup_ballot, "subgroup_ballot"),
+            (self.subgroup_shuffle, "subgroup_shuffle"),
+            (self.subgroup_size_control, "subgroup_size_control"),
+            (self.shader_atomic_float, "shader_atomic_float"),
+        ]
+        .into_iter()
+        .filter_map(|(enabled, name)| enabled.then_some(name))
+    }
 }
 
 /// Device info.
@@ -532,14 +2550,85 @@ pub struct DeviceInfo {
     name: String,
     device_id: u32,
     vendor_id: u32,
+    uuid: [u8; 16],
+    device_type: DeviceType,
+    driver_version: u32,
+    driver_name: Option<String>,
+    driver_info: Option<String>,
+    // ie "0000:03:00.0", used to find telemetry
+    pci_address: Option<String>,
     max_groups: u32,
     max_threads: u32,
+    max_group_size: [u32; 3],
+    max_group_threads: u32,
+    max_push_constant_size: u32,
     subgroup_threads: u32,
+    min_subgroup_threads: u32,
+    max_subgroup_threads: u32,
+    compute_queues: usize,
+    queue_family: Option<QueueFamily>,
+    max_allocation_size: usize,
     features: Features,
+    memory_heaps: Vec<MemoryHeap>,
     debug_printf: bool,
+    verify_transfers: bool,
+    #[cfg(feature = "device")]
+    panic_dump: Option<PanicDump>,
+    #[cfg(feature = "device")]
+    recorder: Option<Arc<Recorder>>,
+    #[cfg(feature = "device")]
+    metrics: Option<Arc<Mutex<PerformanceMetrics>>>,
+    #[cfg(feature = "device")]
+    memory: Arc<MemoryTracker>,
 }
 
 impl DeviceInfo {
+    /// The index of the device, see [`DeviceBuilder::index`].
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    /// The name of the device.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// The PCI device id.
+    pub fn device_id(&self) -> u32 {
+        self.device_id
+    }
+    /// The PCI vendor id.
+    pub fn vendor_id(&self) -> u32 {
+        self.vendor_id
+    }
+    /// The vendor.
+    pub fn vendor(&self) -> Vendor {
+        Vendor::from_id(self.vendor_id)
+    }
+    /// The type of the device.
+    pub fn device_type(&self) -> DeviceType {
+        self.device_type
+    }
+    /// The driver version, encoded by the vendor.
+    pub fn driver_version(&self) -> u32 {
+        self.driver_version
+    }
+    /// The name of the driver, ie "NVIDIA" or "radv".
+    pub fn driver_name(&self) -> Option<&str> {
+        self.driver_name.as_deref()
+    }
+    /// The driver version and other info, ie "Mesa 23.1.0".
+    pub fn driver_info(&self) -> Option<&str> {
+        self.driver_info.as_deref()
+    }
+    /// The PCI address, ie "0000:03:00.0".
+    pub fn pci_address(&self) -> Option<&str> {
+        self.pci_address.as_deref()
+    }
+    /// Universally unique identifier of the device.
+    ///
+    /// Select a device by uuid with [`DeviceBuilder::uuid`].
+    pub fn uuid(&self) -> [u8; 16] {
+        self.uuid
+    }
     /// Max groups per kernel dispatch.
     pub fn max_groups(&self) -> u32 {
         self.max_groups
@@ -548,32 +2637,202 @@ impl DeviceInfo {
     pub fn max_threads(&self) -> u32 {
         self.max_threads
     }
-    // TODO: Intel Mesa driver uses variable subgroup size
-    // Fixed in https://github.com/charles-r-earp/krnl/tree/update-vulkano
-    /*
-    /// Subgroup threads.
+    /// Max threads per group in each dimension.
+    pub fn max_group_size(&self) -> [u32; 3] {
+        self.max_group_size
+    }
+    /// Max total threads per group, ie the product of the group size.
+    pub fn max_group_threads(&self) -> u32 {
+        self.max_group_threads
+    }
+    /// Max size of push constants in bytes.
+    pub fn max_push_constant_size(&self) -> u32 {
+        self.max_push_constant_size
+    }
+    /// Default threads per subgroup.
+    ///
+    /// Some devices, like Intel GPU's, vary the threads per subgroup between
+    /// [`.min_subgroup_threads()`](DeviceInfo::min_subgroup_threads) and
+    /// [`.max_subgroup_threads()`](DeviceInfo::max_subgroup_threads) per kernel.
     pub fn subgroup_threads(&self) -> u32 {
         self.subgroup_threads
     }
-    */
+    /// Min threads per subgroup.
+    pub fn min_subgroup_threads(&self) -> u32 {
+        self.min_subgroup_threads
+    }
+    /// Max threads per subgroup.
+    pub fn max_subgroup_threads(&self) -> u32 {
+        self.max_subgroup_threads
+    }
+    /// The number of compute queues, ie [streams](Stream).
+    pub fn compute_queues(&self) -> usize {
+        self.compute_queues
+    }
+    /// The queue family of the compute queues, used for kernels and transfers.
+    ///
+    /// None for [mock](Device::mock) and [backend](Device::from_backend) devices.
+    pub fn queue_family(&self) -> Option<QueueFamily> {
+        self.queue_family
+    }
+    /// The max size of a single allocation in bytes.
+    ///
+    /// This is limited to [`i32::MAX`] and the device limit, see
+    /// [`DeviceBuilder::max_allocation_size`]. [Backends](Device::from_backend) may allow larger
+    /// allocations, see [`BackendInfo::with_max_allocation_size()`](backend::BackendInfo::with_max_allocation_size).
+    ///
+    /// Larger buffers are backed by multiple allocations. They can be transferred, copied, and
+    /// filled as usual, but kernels can only be dispatched with slices within one allocation.
+    pub fn max_allocation_size(&self) -> usize {
+        self.max_allocation_size
+    }
     /// Device features.
     pub fn features(&self) -> Features {
         self.features
     }
+    /// Memory heaps.
+    pub fn memory_heaps(&self) -> &[MemoryHeap] {
+        &self.memory_heaps
+    }
+    /// Total size of device local heaps in bytes.
+    pub fn device_local_bytes(&self) -> u64 {
+        self.memory_heaps
+            .iter()
+            .filter(|x| x.device_local)
+            .map(|x| x.size)
+            .sum()
+    }
+    /// Total size of host visible heaps in bytes.
+    pub fn host_visible_bytes(&self) -> u64 {
+        self.memory_heaps
+            .iter()
+            .filter(|x| x.host_visible)
+            .map(|x| x.size)
+            .sum()
+    }
     /// Default threads.
     pub fn default_threads(&self) -> u32 {
         256.min(self.max_threads)
     }
+    /// Identifies the device and driver, for caching results on disk.
+    #[cfg(feature = "device")]
+    pub(crate) fn cache_key(&self) -> String {
+        let uuid: String = self.uuid.iter().map(|x| format!("{x:02x}")).collect();
+        format!("{uuid}-{}", self.driver_version)
+    }
     #[allow(dead_code)]
     pub(crate) fn debug_printf(&self) -> bool {
         self.debug_printf
     }
+    #[cfg(feature = "device")]
+    pub(crate) fn panic_dump(&self) -> Option<&PanicDump> {
+        self.panic_dump.as_ref()
+    }
+    #[cfg(feature = "device")]
+    pub(crate) fn recorder(&self) -> Option<&Recorder> {
+        self.recorder.as_deref()
+    }
+}
+
+/// A memory heap, see [`DeviceInfo::memory_heaps()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryHeap {
+    size: u64,
+    device_local: bool,
+    host_visible: bool,
+}
+
+impl MemoryHeap {
+    /// Size in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+    /// Is local to the device, ie VRAM on discrete GPU's.
+    pub fn device_local(&self) -> bool {
+        self.device_local
+    }
+    /// Can be mapped by the host.
+    pub fn host_visible(&self) -> bool {
+        self.host_visible
+    }
+}
+
+/// A queue family, see [`DeviceInfo::queue_family()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct QueueFamily {
+    index: u32,
+    queues: u32,
+    graphics: bool,
+    compute: bool,
+    transfer: bool,
+    timestamp_valid_bits: Option<u32>,
+}
+
+impl QueueFamily {
+    /// The index of the queue family on the physical device.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+    /// The number of queues in the family.
+    ///
+    /// Up to [`DeviceInfo::compute_queues()`] are used.
+    pub fn queues(&self) -> u32 {
+        self.queues
+    }
+    /// Supports graphics operations.
+    pub fn graphics(&self) -> bool {
+        self.graphics
+    }
+    /// Supports compute operations.
+    pub fn compute(&self) -> bool {
+        self.compute
+    }
+    /// Supports transfer operations.
+    ///
+    /// Compute queues support transfers even if not reported.
+    pub fn transfer(&self) -> bool {
+        self.transfer
+    }
+    /// The number of valid bits of timestamps, or None if timestamps are not supported.
+    pub fn timestamp_valid_bits(&self) -> Option<u32> {
+        self.timestamp_valid_bits
+    }
+}
+
+/// The budget and usage of a memory heap, see [`Device::memory_budget()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryBudget {
+    budget: u64,
+    usage: u64,
+}
+
+impl MemoryBudget {
+    /// Estimated bytes the process can allocate.
+    pub fn budget(&self) -> u64 {
+        self.budget
+    }
+    /// Bytes allocated by the process.
+    pub fn usage(&self) -> u64 {
+        self.usage
+    }
+    /// Bytes that can be allocated before exceeding the budget.
+    pub fn available(&self) -> u64 {
+        self.budget.saturating_sub(self.usage)
+    }
+}
+
+#[cfg(feature = "device")]
+#[derive(Clone)]
+enum RawKernelInner {
+    Vulkan(Arc<<Engine as DeviceEngine>::Kernel>),
+    Mock(Arc<<mock_engine::Engine as DeviceEngine>::Kernel>),
+    Backend(Arc<<backend::Engine as DeviceEngine>::Kernel>),
 }
 
 #[cfg(feature = "device")]
 #[derive(Clone)]
 pub(crate) struct RawKernel {
-    inner: Arc<<Engine as DeviceEngine>::Kernel>,
+    inner: RawKernelInner,
 }
 
 #[cfg(feature = "device")]
@@ -583,32 +2842,107 @@ impl RawKernel {
         key: KernelKey,
         desc_fn: impl FnOnce() -> Result<Arc<KernelDesc>>,
     ) -> Result<Self> {
-        Ok(Self {
-            inner: <Engine as DeviceEngine>::Kernel::cached(device.engine, key, desc_fn)?,
-        })
+        let inner = match device.engine {
+            RawEngine::Vulkan(engine) => RawKernelInner::Vulkan(
+                <Engine as DeviceEngine>::Kernel::cached(engine, key, desc_fn)?,
+            ),
+            RawEngine::Mock(engine) => RawKernelInner::Mock(
+                <mock_engine::Engine as DeviceEngine>::Kernel::cached(engine, key, desc_fn)?,
+            ),
+            RawEngine::Backend(engine) => RawKernelInner::Backend(
+                <backend::Engine as DeviceEngine>::Kernel::cached(engine, key, desc_fn)?,
+            ),
+        };
+        Ok(Self { inner })
     }
+    #[allow(clippy::too_many_arguments)]
     pub(crate) unsafe fn dispatch(
         &self,
         groups: u32,
+        indirect: Option<&DeviceBuffer>,
         buffers: &[DeviceBuffer],
         push_consts: Vec<u8>,
         debug_printf_panic: Option<Arc<AtomicBool>>,
+        priority: Priority,
+        stream: usize,
     ) -> Result<()> {
-        unsafe {
-            self.inner.dispatch(
-                groups,
-                cast_device_buffers(buffers),
-                push_consts,
-                debug_printf_panic,
-            )
+        debug_assert!(!buffers.iter().chain(indirect).any(DeviceBuffer::chunked));
+        if let Some(metrics) = self.device().info().metrics.as_ref() {
+            metrics.lock().record_dispatch(&self.desc().name);
+        }
+        match &self.inner {
+            RawKernelInner::Vulkan(kernel) => {
+                let vulkan_buffer = |x: &DeviceBuffer| match &x.inner {
+                    DeviceBufferInner::Vulkan(buffer) => buffer.clone(),
+                    DeviceBufferInner::Mock(_) | DeviceBufferInner::Backend(_) => unreachable!(),
+                };
+                let indirect = indirect.map(vulkan_buffer);
+                let buffers: Vec<_> = buffers.iter().map(vulkan_buffer).collect();
+                unsafe {
+                    kernel.dispatch(
+                        groups,
+                        indirect.as_ref(),
+                        &buffers,
+                        push_consts,
+                        debug_printf_panic,
+                        priority,
+                        stream,
+                    )
+                }
+            }
+            RawKernelInner::Mock(kernel) => {
+                let mock_buffer = |x: &DeviceBuffer| match &x.inner {
+                    DeviceBufferInner::Mock(buffer) => buffer.clone(),
+                    DeviceBufferInner::Vulkan(_) | DeviceBufferInner::Backend(_) => unreachable!(),
+                };
+                let indirect = indirect.map(mock_buffer);
+                let buffers: Vec<_> = buffers.iter().map(mock_buffer).collect();
+                unsafe {
+                    kernel.dispatch(
+                        groups,
+                        indirect.as_ref(),
+                        &buffers,
+                        push_consts,
+                        debug_printf_panic,
+                        priority,
+                        stream,
+                    )
+                }
+            }
+            RawKernelInner::Backend(kernel) => {
+                let backend_buffer = |x: &DeviceBuffer| match &x.inner {
+                    DeviceBufferInner::Backend(buffer) => buffer.clone(),
+                    DeviceBufferInner::Vulkan(_) | DeviceBufferInner::Mock(_) => unreachable!(),
+                };
+                let indirect = indirect.map(backend_buffer);
+                let buffers: Vec<_> = buffers.iter().map(backend_buffer).collect();
+                unsafe {
+                    kernel.dispatch(
+                        groups,
+                        indirect.as_ref(),
+                        &buffers,
+                        push_consts,
+                        debug_printf_panic,
+                        priority,
+                        stream,
+                    )
+                }
+            }
         }
     }
     pub(crate) fn device(&self) -> RawDevice {
-        RawDevice {
-            engine: self.inner.engine().clone(),
-        }
+        let engine = match &self.inner {
+            RawKernelInner::Vulkan(kernel) => RawEngine::Vulkan(kernel.engine().clone()),
+            RawKernelInner::Mock(kernel) => RawEngine::Mock(kernel.engine().clone()),
+            RawKernelInner::Backend(kernel) => RawEngine::Backend(kernel.engine().clone()),
+        };
+        RawDevice { engine }
     }
     pub(crate) fn desc(&self) -> &Arc<KernelDesc> {
-        self.inner.desc()
+        match &self.inner {
+            RawKernelInner::Vulkan(kernel) => kernel.desc(),
+            RawKernelInner::Mock(kernel) => kernel.desc(),
+            RawKernelInner::Backend(kernel) => kernel.desc(),
+        }
     }
 }
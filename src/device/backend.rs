@@ -0,0 +1,582 @@
+/*!
+Unstable extension point for implementing devices out of tree.
+
+A [`Backend`] implements allocation, transfers, and kernel dispatch for a device, and is wrapped
+in a [`Device`] with [`Device::from_backend()`]. Buffers and kernels created on the device
+are then dispatched to the backend, such that the buffer and kernel api is shared with the
+builtin Vulkan backend.
+
+Kernels are provided as SPIR-V compiled by **krnlc**, see [`KernelInfo`].
+
+An experimental WebGPU backend is provided by the `webgpu` module, with the "webgpu" feature.
+
+**This api is unstable and may change in any release.**
+*/
+
+#[cfg(doc)]
+use super::Device;
+use super::{
+    DebugMessage, DeviceEngine, DeviceEngineBuffer, DeviceEngineKernel, DeviceId, DeviceInfo,
+    DeviceLost, DeviceOptions, DeviceType, Features, KernelDesc, KernelKey, MemoryBudget, Priority,
+    RawWaitFuture,
+};
+use anyhow::{bail, Result};
+use parking_lot::Mutex;
+use std::{
+    any::{Any, TypeId},
+    collections::HashMap,
+    fmt::{self, Debug},
+    ops::Range,
+    sync::{atomic::AtomicBool, Arc},
+    time::Duration,
+};
+
+#[cfg(all(not(target_arch = "wasm32"), feature = "webgpu"))]
+pub mod webgpu;
+
+/// A device implementation, see the [module](self) docs.
+pub trait Backend: Send + Sync + 'static {
+    /// Info about the device, called once when creating the [`Device`].
+    fn info(&self) -> BackendInfo;
+    /// Allocates a buffer of `len` bytes.
+    ///
+    /// # Safety
+    /// The contents of the buffer may be uninitialized.
+    unsafe fn alloc(&self, len: usize) -> Result<Arc<dyn BackendBuffer>>;
+    /// Creates a kernel.
+    ///
+    /// Kernels are cached, this is called once per kernel, specialization, and threads.
+    fn kernel(&self, info: &KernelInfo) -> Result<Arc<dyn BackendKernel>>;
+    /// Waits for all previously dispatched kernels to finish.
+    ///
+    /// Errors are returned as [`DeviceLost`].
+    fn wait(&self) -> Result<()>;
+}
+
+/// A buffer allocated by a [`Backend`].
+///
+/// Buffers passed to [`BackendKernel::dispatch()`] can be downcast with
+/// `<dyn BackendBuffer>::downcast_ref()`.
+pub trait BackendBuffer: Any + Send + Sync {
+    /// Copies `data` into the buffer, starting at `offset`.
+    ///
+    /// The copy must be ordered after previously dispatched kernels.
+    fn upload(&self, offset: usize, data: &[u8]) -> Result<()>;
+    /// Copies from the buffer into `data`, starting at `offset`.
+    ///
+    /// The copy must wait for previously dispatched kernels.
+    fn download(&self, offset: usize, data: &mut [u8]) -> Result<()>;
+    /// Copies `len` bytes starting at `offset` to `dst` starting at `dst_offset`.
+    ///
+    /// Returns false if not supported, in which case a kernel is used. Defaults to false.
+    fn copy(
+        &self,
+        offset: usize,
+        dst: &dyn BackendBuffer,
+        dst_offset: usize,
+        len: usize,
+    ) -> Result<bool> {
+        let _ = (offset, dst, dst_offset, len);
+        Ok(false)
+    }
+}
+
+impl dyn BackendBuffer {
+    /// Returns the buffer as `T`, or None if it is a different type.
+    pub fn downcast_ref<T: BackendBuffer>(&self) -> Option<&T> {
+        if Any::type_id(self) == TypeId::of::<T>() {
+            Some(unsafe { &*(self as *const dyn BackendBuffer as *const T) })
+        } else {
+            None
+        }
+    }
+}
+
+/// A kernel created by a [`Backend`].
+pub trait BackendKernel: Send + Sync {
+    /// Enqueues the kernel.
+    ///
+    /// # Safety
+    /// The kernel may not be memory safe, see [Safety](crate::kernel#safety).
+    unsafe fn dispatch(&self, dispatch: Dispatch) -> Result<()>;
+}
+
+/// Info about a [`Backend`].
+#[derive(Clone, Debug)]
+pub struct BackendInfo {
+    name: String,
+    features: Features,
+    max_groups: u32,
+    max_threads: u32,
+    subgroup_threads: u32,
+    max_allocation_size: usize,
+}
+
+impl BackendInfo {
+    /// Creates info for a device named `name`, without any features.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            features: Features::empty(),
+            max_groups: 65_535,
+            max_threads: 1024,
+            subgroup_threads: 32,
+            max_allocation_size: super::DeviceBuffer::MAX_ALLOCATION_SIZE,
+        }
+    }
+    /// Supported features.
+    pub fn with_features(self, features: Features) -> Self {
+        Self { features, ..self }
+    }
+    /// Max groups per kernel dispatch. Defaults to 65,535.
+    pub fn with_max_groups(self, max_groups: u32) -> Self {
+        Self { max_groups, ..self }
+    }
+    /// Max threads per group. Defaults to 1024.
+    pub fn with_max_threads(self, max_threads: u32) -> Self {
+        Self {
+            max_threads,
+            ..self
+        }
+    }
+    /// Threads per subgroup. Defaults to 32.
+    pub fn with_subgroup_threads(self, subgroup_threads: u32) -> Self {
+        Self {
+            subgroup_threads,
+            ..self
+        }
+    }
+    /// The max size of a single allocation in bytes. Defaults to [`i32::MAX`].
+    ///
+    /// Slices of allocations with more than [`u32::MAX`] elements can only be passed to kernels
+    /// as `Slice64` or `UnsafeSlice64`, see [Global Buffers](crate::kernel#global-buffers).
+    pub fn with_max_allocation_size(self, max_allocation_size: usize) -> Self {
+        Self {
+            max_allocation_size,
+            ..self
+        }
+    }
+}
+
+/// A kernel to create, see [`Backend::kernel()`].
+pub struct KernelInfo<'a> {
+    desc: &'a KernelDesc,
+}
+
+impl KernelInfo<'_> {
+    /// The name of the kernel, including spec constants.
+    pub fn name(&self) -> &str {
+        &self.desc.name
+    }
+    /// The SPIR-V, with spec constants and threads specialized.
+    pub fn spirv(&self) -> &[u32] {
+        &self.desc.spirv
+    }
+    /// The threads per group.
+    pub fn threads(&self) -> u32 {
+        self.desc.threads
+    }
+    /// The name of each slice, and whether it is mutable, in binding order.
+    pub fn slices(&self) -> impl ExactSizeIterator<Item = (&'static str, bool)> + '_ {
+        self.desc.slice_descs.iter().map(|x| (x.name, x.mutable))
+    }
+    /// The size of the push constants in bytes.
+    pub fn push_consts_size(&self) -> u32 {
+        self.desc.push_consts_range()
+    }
+}
+
+impl Debug for KernelInfo<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("KernelInfo")
+            .field("name", &self.name())
+            .field("threads", &self.threads())
+            .finish()
+    }
+}
+
+/// A range of a [`BackendBuffer`].
+#[derive(Clone, Copy)]
+pub struct BufferRange<'a> {
+    buffer: &'a dyn BackendBuffer,
+    offset: usize,
+    len: usize,
+}
+
+impl<'a> BufferRange<'a> {
+    /// The buffer.
+    pub fn buffer(&self) -> &'a dyn BackendBuffer {
+        self.buffer
+    }
+    /// The byte range of the buffer.
+    pub fn range(&self) -> Range<usize> {
+        self.offset..self.offset + self.len
+    }
+}
+
+impl Debug for BufferRange<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("BufferRange").field(&self.range()).finish()
+    }
+}
+
+/// A kernel dispatch, see [`BackendKernel::dispatch()`].
+#[derive(Debug)]
+pub struct Dispatch<'a> {
+    groups: u32,
+    indirect: Option<BufferRange<'a>>,
+    buffers: &'a [BufferRange<'a>],
+    push_consts: &'a [u8],
+    priority: Priority,
+}
+
+impl<'a> Dispatch<'a> {
+    /// The number of groups, 0 if [indirect](Dispatch::indirect).
+    pub fn groups(&self) -> u32 {
+        self.groups
+    }
+    /// The buffer to read the groups from, as 3 u32's, see [Indirect Dispatch](crate::kernel#indirect-dispatch).
+    pub fn indirect(&self) -> Option<BufferRange<'a>> {
+        self.indirect
+    }
+    /// The buffers of each slice, in binding order.
+    pub fn buffers(&self) -> &'a [BufferRange<'a>] {
+        self.buffers
+    }
+    /// The push constants.
+    pub fn push_consts(&self) -> &'a [u8] {
+        self.push_consts
+    }
+    /// The priority.
+    pub fn priority(&self) -> Priority {
+        self.priority
+    }
+}
+
+pub(super) struct Engine {
+    backend: Arc<dyn Backend>,
+    info: Arc<DeviceInfo>,
+    kernels: Mutex<HashMap<KernelKey, Arc<Kernel>>>,
+}
+
+impl Engine {
+    pub(super) fn from_backend(backend: Arc<dyn Backend>) -> Arc<Self> {
+        let BackendInfo {
+            name,
+            features,
+            max_groups,
+            max_threads,
+            subgroup_threads,
+            max_allocation_size,
+        } = backend.info();
+        let info = Arc::new(DeviceInfo {
+            index: 0,
+            name,
+            device_id: 0,
+            vendor_id: 0,
+            uuid: [0; 16],
+            device_type: DeviceType::Other,
+            driver_version: 0,
+            driver_name: None,
+            driver_info: None,
+            pci_address: None,
+            max_groups,
+            max_threads,
+            max_group_size: [max_threads, 1, 1],
+            max_group_threads: max_threads,
+            max_push_constant_size: 128,
+            subgroup_threads,
+            min_subgroup_threads: subgroup_threads,
+            max_subgroup_threads: subgroup_threads,
+            compute_queues: 1,
+            queue_family: None,
+            max_allocation_size,
+            features,
+            memory_heaps: Vec::new(),
+            debug_printf: false,
+            verify_transfers: false,
+            panic_dump: None,
+            recorder: None,
+            metrics: None,
+            memory: Arc::default(),
+        });
+        Arc::new(Self {
+            backend,
+            info,
+            kernels: Mutex::default(),
+        })
+    }
+}
+
+impl DeviceEngine for Engine {
+    type DeviceBuffer = DeviceBuffer;
+    type Kernel = Kernel;
+    fn new(_options: DeviceOptions) -> Result<Arc<Self>> {
+        bail!("Backend devices are created with `Device::from_backend`!");
+    }
+    fn recreate(&self) -> Result<Arc<Self>> {
+        bail!("Backend devices can not be recreated!");
+    }
+    fn id(&self) -> DeviceId {
+        let index = self.info.index;
+        let handle = self as *const Self as usize;
+        DeviceId { index, handle }
+    }
+    fn info(&self) -> &Arc<DeviceInfo> {
+        &self.info
+    }
+    fn wait(&self) -> Result<(), DeviceLost> {
+        self.backend.wait().map_err(|_| DeviceLost(self.id()))
+    }
+    fn wait_stream(&self, _stream: usize) -> Result<(), DeviceLost> {
+        self.wait()
+    }
+    fn flush(&self) -> Result<(), DeviceLost> {
+        Ok(())
+    }
+    fn begin_batch(&self) {}
+    fn end_batch(&self) {}
+    fn gpu_time(&self) -> Result<Option<Duration>, DeviceLost> {
+        Ok(None)
+    }
+    fn wait_async(&self) -> RawWaitFuture {
+        // Backends only support blocking waits.
+        let backend = self.backend.clone();
+        let id = self.id();
+        Box::pin(async move { backend.wait().map_err(|_| DeviceLost(id)) })
+    }
+    fn wait_stream_async(&self, _stream: usize) -> RawWaitFuture {
+        self.wait_async()
+    }
+    // Backends manage their own memory.
+    fn trim(&self) -> Result<(), DeviceLost> {
+        self.wait()
+    }
+    fn memory_budget(&self) -> Option<Vec<MemoryBudget>> {
+        None
+    }
+    fn take_debug_messages(&self) -> Vec<DebugMessage> {
+        Vec::new()
+    }
+}
+
+pub(super) struct DeviceBuffer {
+    inner: Arc<dyn BackendBuffer>,
+    engine: Arc<Engine>,
+    offset: usize,
+    len: usize,
+}
+
+impl DeviceBuffer {
+    pub(super) fn copy(&self, dst: &Self) -> Result<bool> {
+        self.inner
+            .copy(self.offset, &*dst.inner, dst.offset, self.len)
+    }
+    fn range(&self) -> BufferRange<'_> {
+        BufferRange {
+            buffer: &*self.inner,
+            offset: self.offset,
+            len: self.len,
+        }
+    }
+}
+
+impl DeviceEngineBuffer for DeviceBuffer {
+    type Engine = Engine;
+    unsafe fn uninit(engine: Arc<Engine>, len: usize) -> Result<Self> {
+        let inner = unsafe { engine.backend.alloc(len)? };
+        Ok(Self {
+            inner,
+            engine,
+            offset: 0,
+            len,
+        })
+    }
+    fn upload(&self, data: &[u8]) -> Result<()> {
+        self.inner.upload(self.offset, data)
+    }
+    fn download(&self, data: &mut [u8]) -> Result<()> {
+        self.inner.download(self.offset, data)
+    }
+    fn transfer(&self, dst: &Self) -> Result<()> {
+        let mut data = vec![0u8; self.len];
+        self.download(&mut data)?;
+        dst.upload(&data)
+    }
+    fn engine(&self) -> &Arc<Engine> {
+        &self.engine
+    }
+    fn offset(&self) -> usize {
+        self.offset
+    }
+    fn len(&self) -> usize {
+        self.len
+    }
+    fn slice(self: &Arc<Self>, range: Range<usize>) -> Option<Arc<Self>> {
+        let Range { start, end } = range;
+        if start > self.len || end > self.len {
+            return None;
+        }
+        let offset = self.offset.checked_add(start)?;
+        let len = end.checked_sub(start)?;
+        Some(Arc::new(Self {
+            inner: self.inner.clone(),
+            engine: self.engine.clone(),
+            offset,
+            len,
+        }))
+    }
+}
+
+pub(super) struct Kernel {
+    inner: Arc<dyn BackendKernel>,
+    engine: Arc<Engine>,
+    desc: Arc<KernelDesc>,
+}
+
+impl DeviceEngineKernel for Kernel {
+    type Engine = Engine;
+    type DeviceBuffer = DeviceBuffer;
+    fn cached(
+        engine: Arc<Engine>,
+        key: KernelKey,
+        desc_fn: impl FnOnce() -> Result<Arc<KernelDesc>>,
+    ) -> Result<Arc<Self>> {
+        let mut kernels = engine.kernels.lock();
+        if let Some(kernel) = kernels.get(&key) {
+            return Ok(kernel.clone());
+        }
+        let desc = desc_fn()?;
+        let inner = engine.backend.kernel(&KernelInfo { desc: &desc })?;
+        let kernel = Arc::new(Self {
+            inner,
+            engine: engine.clone(),
+            desc,
+        });
+        kernels.insert(key, kernel.clone());
+        Ok(kernel)
+    }
+    unsafe fn dispatch(
+        &self,
+        groups: u32,
+        indirect: Option<&Arc<DeviceBuffer>>,
+        buffers: &[Arc<DeviceBuffer>],
+        push_consts: Vec<u8>,
+        _debug_printf_panic: Option<Arc<AtomicBool>>,
+        priority: Priority,
+        _stream: usize,
+    ) -> Result<()> {
+        let buffers: Vec<_> = buffers.iter().map(|x| x.range()).collect();
+        let dispatch = Dispatch {
+            groups,
+            indirect: indirect.map(|x| x.range()),
+            buffers: &buffers,
+            push_consts: &push_consts,
+            priority,
+        };
+        unsafe { self.inner.dispatch(dispatch) }
+    }
+    fn engine(&self) -> &Arc<Engine> {
+        &self.engine
+    }
+    fn desc(&self) -> &Arc<KernelDesc> {
+        &self.desc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{buffer::Buffer, device::Device};
+
+    #[derive(Default)]
+    struct HostBackend {
+        dispatches: Mutex<Vec<(String, Vec<Range<usize>>)>>,
+    }
+
+    struct HostBuffer(Mutex<Vec<u8>>);
+
+    impl BackendBuffer for HostBuffer {
+        fn upload(&self, offset: usize, data: &[u8]) -> Result<()> {
+            self.0.lock()[offset..offset + data.len()].copy_from_slice(data);
+            Ok(())
+        }
+        fn download(&self, offset: usize, data: &mut [u8]) -> Result<()> {
+            data.copy_from_slice(&self.0.lock()[offset..offset + data.len()]);
+            Ok(())
+        }
+    }
+
+    struct HostKernel {
+        backend: Arc<HostBackend>,
+        name: String,
+    }
+
+    impl BackendKernel for HostKernel {
+        unsafe fn dispatch(&self, dispatch: Dispatch) -> Result<()> {
+            for buffer in dispatch.buffers() {
+                assert!(buffer.buffer().downcast_ref::<HostBuffer>().is_some());
+            }
+            let buffers = dispatch.buffers().iter().map(|x| x.range()).collect();
+            self.backend
+                .dispatches
+                .lock()
+                .push((self.name.clone(), buffers));
+            Ok(())
+        }
+    }
+
+    struct Host(Arc<HostBackend>);
+
+    impl Backend for Host {
+        fn info(&self) -> BackendInfo {
+            BackendInfo::new("host").with_max_threads(256)
+        }
+        unsafe fn alloc(&self, len: usize) -> Result<Arc<dyn BackendBuffer>> {
+            Ok(Arc::new(HostBuffer(Mutex::new(vec![0; len]))))
+        }
+        fn kernel(&self, info: &KernelInfo) -> Result<Arc<dyn BackendKernel>> {
+            assert!(!info.spirv().is_empty());
+            Ok(Arc::new(HostKernel {
+                backend: self.0.clone(),
+                name: info.name().to_string(),
+            }))
+        }
+        fn wait(&self) -> Result<()> {
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn backend_device() {
+        let backend = Arc::new(HostBackend::default());
+        let device = Device::from_backend(Arc::new(Host(backend.clone())));
+        assert!(device.is_device());
+        assert_ne!(
+            device,
+            Device::from_backend(Arc::new(Host(backend.clone())))
+        );
+        let info = device.info().unwrap();
+        assert_eq!(info.max_threads(), 256);
+        assert_eq!(info.features(), Features::empty());
+        assert!(device.take_mock_ops().is_none());
+        let x = Buffer::from(vec![1u32, 2, 3, 4])
+            .into_device(device.clone())
+            .unwrap();
+        assert_eq!(x.to_vec().unwrap(), [1, 2, 3, 4]);
+        let mut y = x.to_device(Device::host()).unwrap();
+        y.to_device_mut(device.clone()).unwrap();
+        y.slice_mut(1..).unwrap().fill(0).unwrap();
+        y.slice_mut(1..).unwrap().fill(0).unwrap();
+        device.wait().unwrap();
+        let dispatches = backend.dispatches.lock();
+        assert_eq!(dispatches.len(), 2);
+        assert!(dispatches[0].0.contains("fill"));
+        assert_eq!(dispatches[0].1, vec![Range { start: 4, end: 16 }]);
+        assert_eq!(
+            x.slice(1..3).unwrap().to_vec().unwrap(),
+            [2, 3],
+            "slices are offset into the backend buffer"
+        );
+    }
+}
@@ -0,0 +1,479 @@
+/*!
+Experimental [WebGPU](https://www.w3.org/TR/webgpu) backend, implemented with [wgpu](https://docs.rs/wgpu).
+
+Requires the "webgpu" feature.
+
+```no_run
+# use krnl::{anyhow::Result, device::{Device, backend::webgpu::WebGpu}};
+# use std::sync::Arc;
+# fn main() -> Result<()> {
+let device = Device::from_backend(Arc::new(WebGpu::new()?));
+# Ok(())
+# }
+```
+
+Kernels are created from the SPIR-V compiled by **krnlc**. If the adapter supports
+SPIR-V passthrough it is used directly, otherwise it is translated by wgpu (naga) for the
+native api, ie Vulkan, Metal, DX12, or OpenGL.
+
+# Limitations
+- Only runs natively, the "device" feature does not build for wasm. Downloads block on the
+  device, which is not possible in the browser.
+- Requires push constants, which are not part of the WebGPU spec.
+- Subgroup operations, 8 bit scalars, and 64 bit integers are not supported. Casts between
+  scalar types require 8 bit scalars, see [`Features`].
+- Without SPIR-V passthrough, kernels are limited to what naga can translate.
+- Dispatches are submitted immediately, without batching, and [`Priority`](crate::device::Priority)
+  is ignored.
+*/
+
+use super::{Backend, BackendBuffer, BackendInfo, BackendKernel, Dispatch, KernelInfo};
+use crate::device::{block_on, Features};
+use anyhow::{bail, format_err, Result};
+use std::{borrow::Cow, sync::Arc};
+
+struct Context {
+    device: wgpu::Device,
+    queue: wgpu::Queue,
+}
+
+impl Context {
+    // Runs `f`, returning validation and out of memory errors.
+    fn scope<T>(&self, f: impl FnOnce() -> T) -> Result<T> {
+        self.device.push_error_scope(wgpu::ErrorFilter::OutOfMemory);
+        self.device.push_error_scope(wgpu::ErrorFilter::Validation);
+        let output = f();
+        let validation = block_on(self.device.pop_error_scope());
+        let out_of_memory = block_on(self.device.pop_error_scope());
+        if let Some(error) = validation.or(out_of_memory) {
+            bail!("{error}");
+        }
+        Ok(output)
+    }
+}
+
+/// A WebGPU device, see the [module](self) docs.
+pub struct WebGpu {
+    context: Arc<Context>,
+    info: BackendInfo,
+}
+
+impl WebGpu {
+    /// Creates a backend with the default (high performance) adapter.
+    ///
+    /// **Errors**
+    /// - No adapter was found.
+    /// - The adapter does not support push constants.
+    /// - The device could not be created.
+    pub fn new() -> Result<Self> {
+        let instance = wgpu::Instance::new(wgpu::InstanceDescriptor::default());
+        let adapter = block_on(instance.request_adapter(&wgpu::RequestAdapterOptions {
+            power_preference: wgpu::PowerPreference::HighPerformance,
+            ..wgpu::RequestAdapterOptions::default()
+        }))
+        .ok_or_else(|| format_err!("No WebGPU adapter found!"))?;
+        let adapter_info = adapter.get_info();
+        let adapter_features = adapter.features();
+        if !adapter_features.contains(wgpu::Features::PUSH_CONSTANTS) {
+            bail!(
+                "WebGPU adapter {:?} does not support push constants!",
+                adapter_info.name
+            );
+        }
+        let required_features = adapter_features
+            & (wgpu::Features::PUSH_CONSTANTS
+                | wgpu::Features::SPIRV_SHADER_PASSTHROUGH
+                | wgpu::Features::SHADER_I16
+                | wgpu::Features::SHADER_F64);
+        let limits = adapter.limits();
+        let (device, queue) = block_on(adapter.request_device(
+            &wgpu::DeviceDescriptor {
+                label: None,
+                required_features,
+                required_limits: limits.clone(),
+            },
+            None,
+        ))?;
+        let features = Features::empty()
+            .with_shader_int16(required_features.contains(wgpu::Features::SHADER_I16))
+            .with_shader_float64(required_features.contains(wgpu::Features::SHADER_F64));
+        let info = BackendInfo::new(format!(
+            "{} ({:?})",
+            adapter_info.name, adapter_info.backend
+        ))
+        .with_features(features)
+        .with_max_groups(limits.max_compute_workgroups_per_dimension)
+        .with_max_threads(limits.max_compute_invocations_per_workgroup);
+        Ok(Self {
+            context: Arc::new(Context { device, queue }),
+            info,
+        })
+    }
+}
+
+impl Backend for WebGpu {
+    fn info(&self) -> BackendInfo {
+        self.info.clone()
+    }
+    unsafe fn alloc(&self, len: usize) -> Result<Arc<dyn BackendBuffer>> {
+        // Copies and bindings are in multiples of 4 bytes.
+        let size = ((len + 3) / 4 * 4).max(4);
+        let buffer = self.context.scope(|| {
+            self.context.device.create_buffer(&wgpu::BufferDescriptor {
+                label: None,
+                size: size as u64,
+                usage: wgpu::BufferUsages::STORAGE
+                    | wgpu::BufferUsages::INDIRECT
+                    | wgpu::BufferUsages::COPY_SRC
+                    | wgpu::BufferUsages::COPY_DST,
+                mapped_at_creation: false,
+            })
+        })?;
+        Ok(Arc::new(WebGpuBuffer {
+            context: self.context.clone(),
+            buffer,
+        }))
+    }
+    fn kernel(&self, info: &KernelInfo) -> Result<Arc<dyn BackendKernel>> {
+        let context = &self.context;
+        let device = &context.device;
+        let passthrough = device
+            .features()
+            .contains(wgpu::Features::SPIRV_SHADER_PASSTHROUGH);
+        // Parsed up front, as wgpu panics on invalid SPIR-V.
+        let naga_module = if !passthrough {
+            let spirv = freeze_spec_constants(info.spirv(), info.threads())?;
+            let options = naga::front::spv::Options {
+                adjust_coordinate_space: false,
+                ..naga::front::spv::Options::default()
+            };
+            let module =
+                naga::front::spv::parse_u8_slice(bytemuck::cast_slice(&spirv), &options)
+                    .map_err(|e| format_err!("Kernel `{}` is not supported: {e}", info.name()))?;
+            Some(module)
+        } else {
+            None
+        };
+        let pipeline = context.scope(|| {
+            let module = if let Some(naga_module) = naga_module {
+                device.create_shader_module(wgpu::ShaderModuleDescriptor {
+                    label: Some(info.name()),
+                    source: wgpu::ShaderSource::Naga(Cow::Owned(naga_module)),
+                })
+            } else {
+                unsafe {
+                    device.create_shader_module_spirv(&wgpu::ShaderModuleDescriptorSpirV {
+                        label: Some(info.name()),
+                        source: Cow::Borrowed(info.spirv()),
+                    })
+                }
+            };
+            let entries: Vec<_> = info
+                .slices()
+                .enumerate()
+                .map(|(binding, (_, mutable))| wgpu::BindGroupLayoutEntry {
+                    binding: binding as u32,
+                    visibility: wgpu::ShaderStages::COMPUTE,
+                    ty: wgpu::BindingType::Buffer {
+                        ty: wgpu::BufferBindingType::Storage {
+                            read_only: !mutable,
+                        },
+                        has_dynamic_offset: false,
+                        min_binding_size: None,
+                    },
+                    count: None,
+                })
+                .collect();
+            let bind_group_layout =
+                device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
+                    label: None,
+                    entries: &entries,
+                });
+            let push_consts_size = info.push_consts_size();
+            let push_constant_ranges: &[_] = if push_consts_size > 0 {
+                &[wgpu::PushConstantRange {
+                    stages: wgpu::ShaderStages::COMPUTE,
+                    range: 0..push_consts_size,
+                }]
+            } else {
+                &[]
+            };
+            let layout = device.create_pipeline_layout(&wgpu::PipelineLayoutDescriptor {
+                label: None,
+                bind_group_layouts: &[&bind_group_layout],
+                push_constant_ranges,
+            });
+            device.create_compute_pipeline(&wgpu::ComputePipelineDescriptor {
+                label: Some(info.name()),
+                layout: Some(&layout),
+                module: &module,
+                entry_point: "main",
+            })
+        })?;
+        Ok(Arc::new(WebGpuKernel {
+            context: context.clone(),
+            pipeline,
+        }))
+    }
+    fn wait(&self) -> Result<()> {
+        self.context.device.poll(wgpu::Maintain::Wait);
+        Ok(())
+    }
+}
+
+// Converts spec constants, which are already specialized, into constants, and the workgroup size
+// into an execution mode.
+fn freeze_spec_constants(spirv: &[u32], threads: u32) -> Result<Vec<u32>> {
+    use rspirv::{
+        binary::Assemble,
+        dr::{Instruction, Operand},
+        grammar::CoreInstructionTable,
+        spirv::{BuiltIn, Decoration, ExecutionMode, Op},
+    };
+    let mut module =
+        rspirv::dr::load_words(spirv).map_err(|e| format_err!("Invalid SPIR-V: {e}"))?;
+    module.annotations.retain(|inst| {
+        !matches!(
+            inst.operands.as_slice(),
+            [_, Operand::Decoration(Decoration::SpecId), ..]
+                | [
+                    _,
+                    Operand::Decoration(Decoration::BuiltIn),
+                    Operand::BuiltIn(BuiltIn::WorkgroupSize)
+                ]
+        )
+    });
+    let entry_point = module.entry_points[0].operands[1].unwrap_id_ref();
+    module.execution_modes.retain(|inst| {
+        !matches!(
+            inst.operands.as_slice(),
+            [_, Operand::ExecutionMode(ExecutionMode::LocalSize), ..]
+        )
+    });
+    module.execution_modes.push(Instruction::new(
+        Op::ExecutionMode,
+        None,
+        None,
+        vec![
+            Operand::IdRef(entry_point),
+            Operand::ExecutionMode(ExecutionMode::LocalSize),
+            Operand::LiteralInt32(threads),
+            Operand::LiteralInt32(1),
+            Operand::LiteralInt32(1),
+        ],
+    ));
+    for inst in module.types_global_values.iter_mut() {
+        inst.class = match inst.class.opcode {
+            Op::SpecConstant => CoreInstructionTable::get(Op::Constant),
+            Op::SpecConstantTrue => CoreInstructionTable::get(Op::ConstantTrue),
+            Op::SpecConstantFalse => CoreInstructionTable::get(Op::ConstantFalse),
+            Op::SpecConstantComposite => CoreInstructionTable::get(Op::ConstantComposite),
+            Op::SpecConstantOp => bail!("OpSpecConstantOp is not supported!"),
+            _ => continue,
+        };
+    }
+    Ok(module.assemble())
+}
+
+struct WebGpuBuffer {
+    context: Arc<Context>,
+    buffer: wgpu::Buffer,
+}
+
+impl BackendBuffer for WebGpuBuffer {
+    fn upload(&self, offset: usize, data: &[u8]) -> Result<()> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        let end = offset + data.len();
+        let (aligned_offset, aligned_end) = (offset / 4 * 4, (end + 3) / 4 * 4);
+        if (aligned_offset, aligned_end) == (offset, end) {
+            self.context
+                .queue
+                .write_buffer(&self.buffer, offset as u64, data);
+        } else {
+            // Writes must be aligned, so the partial words at either end are read back first.
+            let mut aligned = vec![0u8; aligned_end - aligned_offset];
+            self.download(aligned_offset, &mut aligned)?;
+            aligned[offset - aligned_offset..end - aligned_offset].copy_from_slice(data);
+            self.context
+                .queue
+                .write_buffer(&self.buffer, aligned_offset as u64, &aligned);
+        }
+        Ok(())
+    }
+    fn download(&self, offset: usize, data: &mut [u8]) -> Result<()> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        let Context { device, queue } = &*self.context;
+        let aligned_offset = offset / 4 * 4;
+        let aligned_len = (offset + data.len() + 3) / 4 * 4 - aligned_offset;
+        let staging = device.create_buffer(&wgpu::BufferDescriptor {
+            label: None,
+            size: aligned_len as u64,
+            usage: wgpu::BufferUsages::MAP_READ | wgpu::BufferUsages::COPY_DST,
+            mapped_at_creation: false,
+        });
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.copy_buffer_to_buffer(
+            &self.buffer,
+            aligned_offset as u64,
+            &staging,
+            0,
+            aligned_len as u64,
+        );
+        queue.submit([encoder.finish()]);
+        let slice = staging.slice(..);
+        let (sender, receiver) = std::sync::mpsc::channel();
+        slice.map_async(wgpu::MapMode::Read, move |result| {
+            sender.send(result).unwrap();
+        });
+        device.poll(wgpu::Maintain::Wait);
+        receiver.recv()??;
+        let start = offset - aligned_offset;
+        data.copy_from_slice(&slice.get_mapped_range()[start..start + data.len()]);
+        staging.unmap();
+        Ok(())
+    }
+    fn copy(
+        &self,
+        offset: usize,
+        dst: &dyn BackendBuffer,
+        dst_offset: usize,
+        len: usize,
+    ) -> Result<bool> {
+        // Copies must be aligned, and between different buffers.
+        let Some(dst) = dst.downcast_ref::<Self>() else {
+            return Ok(false);
+        };
+        if (offset | dst_offset | len) % 4 != 0 || std::ptr::eq(self, dst) {
+            return Ok(false);
+        }
+        let Context { device, queue } = &*self.context;
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        encoder.copy_buffer_to_buffer(
+            &self.buffer,
+            offset as u64,
+            &dst.buffer,
+            dst_offset as u64,
+            len as u64,
+        );
+        queue.submit([encoder.finish()]);
+        Ok(true)
+    }
+}
+
+fn webgpu_buffer(buffer: &dyn BackendBuffer) -> Result<&wgpu::Buffer> {
+    buffer
+        .downcast_ref::<WebGpuBuffer>()
+        .map(|x| &x.buffer)
+        .ok_or_else(|| format_err!("Expected a WebGPU buffer!"))
+}
+
+struct WebGpuKernel {
+    context: Arc<Context>,
+    pipeline: wgpu::ComputePipeline,
+}
+
+impl BackendKernel for WebGpuKernel {
+    unsafe fn dispatch(&self, dispatch: Dispatch) -> Result<()> {
+        let Context { device, queue } = &*self.context;
+        // Slices are offset with push constants, so the entire buffer is bound.
+        let buffers = dispatch
+            .buffers()
+            .iter()
+            .map(|x| webgpu_buffer(x.buffer()))
+            .collect::<Result<Vec<_>>>()?;
+        let entries: Vec<_> = buffers
+            .iter()
+            .enumerate()
+            .map(|(binding, buffer)| wgpu::BindGroupEntry {
+                binding: binding as u32,
+                resource: buffer.as_entire_binding(),
+            })
+            .collect();
+        let bind_group = device.create_bind_group(&wgpu::BindGroupDescriptor {
+            label: None,
+            layout: &self.pipeline.get_bind_group_layout(0),
+            entries: &entries,
+        });
+        let mut encoder = device.create_command_encoder(&wgpu::CommandEncoderDescriptor::default());
+        {
+            let mut pass = encoder.begin_compute_pass(&wgpu::ComputePassDescriptor::default());
+            pass.set_pipeline(&self.pipeline);
+            pass.set_bind_group(0, &bind_group, &[]);
+            let push_consts = dispatch.push_consts();
+            if !push_consts.is_empty() {
+                pass.set_push_constants(0, push_consts);
+            }
+            if let Some(indirect) = dispatch.indirect() {
+                let buffer = webgpu_buffer(indirect.buffer())?;
+                pass.dispatch_workgroups_indirect(buffer, indirect.range().start as u64);
+            } else {
+                pass.dispatch_workgroups(dispatch.groups(), 1, 1);
+            }
+        }
+        queue.submit([encoder.finish()]);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{buffer::Buffer, device::Device};
+
+    fn device() -> Option<Device> {
+        match WebGpu::new() {
+            Ok(backend) => Some(Device::from_backend(Arc::new(backend))),
+            Err(error) => {
+                eprintln!("skipped, {error}");
+                None
+            }
+        }
+    }
+
+    #[test]
+    fn webgpu_transfers() {
+        let Some(device) = device() else {
+            return;
+        };
+        let x: Vec<u8> = (1..=11).collect();
+        let mut y = Buffer::from(x.clone()).into_device(device.clone()).unwrap();
+        assert_eq!(y.to_vec().unwrap(), x);
+        assert_eq!(y.slice(3..6).unwrap().to_vec().unwrap(), [4, 5, 6]);
+        y.slice_mut(5..8)
+            .unwrap()
+            .copy_from_host_slice(&[0, 0, 0])
+            .unwrap();
+        assert_eq!(y.to_vec().unwrap(), [1, 2, 3, 4, 5, 0, 0, 0, 9, 10, 11]);
+        let mut x = Buffer::from(vec![1u32, 2, 3, 4])
+            .into_device(device.clone())
+            .unwrap();
+        let y = x.slice(2..).unwrap().to_owned().unwrap();
+        x.slice_mut(..2)
+            .unwrap()
+            .copy_from_slice(&y.as_slice())
+            .unwrap();
+        assert_eq!(x.to_vec().unwrap(), [3, 4, 3, 4]);
+    }
+
+    #[test]
+    fn webgpu_kernels() {
+        let Some(device) = device() else {
+            return;
+        };
+        let mut x = Buffer::<u32>::zeros(device.clone(), 100).unwrap();
+        x.slice_mut(10..20).unwrap().fill(7).unwrap();
+        let x = x.to_vec().unwrap();
+        assert_eq!(x[..10], [0; 10]);
+        assert_eq!(x[10..20], [7; 10]);
+        assert_eq!(x[20..], [0; 80]);
+        let x: Vec<u32> = (0..10_000u32).map(|i| i / 100 % 7).collect();
+        let y = crate::buffer::Slice::from(x.as_slice())
+            .into_device_compressed(device)
+            .unwrap();
+        assert_eq!(y.to_vec().unwrap(), x);
+    }
+}
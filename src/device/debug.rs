@@ -0,0 +1,162 @@
+/** A message from the Vulkan validation layers, including DebugPrintf.
+
+Messages are passed to the callback registered with
+[`DeviceBuilder::message_callback`](super::DeviceBuilder::message_callback).
+
+```no_run
+# use krnl::{anyhow::Result, device::Device};
+# fn main() -> Result<()> {
+let device = Device::builder()
+    .validation(true)
+    .message_callback(|msg| eprintln!("{:?} {}", msg.severity(), msg.text()))
+    .build()?;
+# Ok(())
+# }
+```
+
+DebugPrintf messages include the name of the kernel, and the formatted output of the kernel thread.
+With [`DeviceBuilder::capture_messages`](super::DeviceBuilder::capture_messages), messages are
+collected for [`Device::take_debug_messages`](super::Device::take_debug_messages).
+
+```no_run
+# use krnl::{anyhow::Result, device::Device};
+# fn main() -> Result<()> {
+let device = Device::builder()
+    .debug_printf(true)
+    .capture_messages(true)
+    .build()?;
+/* dispatch kernels */
+device.wait()?;
+for msg in device.take_debug_messages() {
+    if msg.is_debug_printf() {
+        println!("{:?} {:?}: {}", msg.kernel(), msg.global_id(), msg.message());
+    }
+}
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone)]
+pub struct DebugMessage {
+    pub(super) severity: DebugMessageSeverity,
+    pub(super) id: Option<String>,
+    pub(super) text: String,
+    pub(super) kernel: Option<String>,
+}
+
+impl DebugMessage {
+    /// The severity.
+    pub fn severity(&self) -> DebugMessageSeverity {
+        self.severity
+    }
+    /// The message id, ie "UNASSIGNED-DEBUG-PRINTF".
+    pub fn id(&self) -> Option<&str> {
+        self.id.as_deref()
+    }
+    /// The text of the message.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+    /// Whether the message was printed by a kernel with DebugPrintf.
+    pub fn is_debug_printf(&self) -> bool {
+        self.id
+            .as_ref()
+            .is_some_and(|id| id.contains("DEBUG-PRINTF"))
+    }
+    /// The name of the kernel that printed the message, for DebugPrintf.
+    pub fn kernel(&self) -> Option<&str> {
+        self.kernel.as_deref()
+    }
+    /// The global id of the kernel thread that printed the message, for DebugPrintf.
+    ///
+    /// The validation layer only includes the global id when `printf_verbose` is enabled, ie
+    /// `VK_LAYER_PRINTF_VERBOSE=1`.
+    pub fn global_id(&self) -> Option<u32> {
+        if !self.is_debug_printf() {
+            return None;
+        }
+        let (_, global_id) = self.text.split_once(Self::GLOBAL_ID)?;
+        let (global_id, _) = global_id.split_once(',')?;
+        global_id.trim().parse().ok()
+    }
+    /// The formatted output of the kernel thread for DebugPrintf, otherwise the text.
+    pub fn message(&self) -> &str {
+        if !self.is_debug_printf() {
+            return &self.text;
+        }
+        let Some((_, message)) = self
+            .text
+            .split_once("MessageID = ")
+            .and_then(|(_, message)| message.split_once(" | "))
+        else {
+            return &self.text;
+        };
+        if let Some((_, message)) = message
+            .split_once(Self::GLOBAL_ID)
+            .and_then(|(_, message)| message.split_once(')'))
+        {
+            message.trim_start()
+        } else {
+            message
+        }
+    }
+    const GLOBAL_ID: &'static str = "Global invocation ID (x, y, z) = (";
+}
+
+/// Severity of a [`DebugMessage`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum DebugMessageSeverity {
+    /// Diagnostic information.
+    Verbose,
+    /// Information, including DebugPrintf.
+    Info,
+    /// Potential misuse of the API.
+    Warning,
+    /// Invalid usage of the API.
+    Error,
+}
+
+#[cfg(feature = "device")]
+pub(super) type MessageCallback = std::sync::Arc<dyn Fn(&DebugMessage) + Send + Sync>;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn debug_printf(text: &str) -> DebugMessage {
+        DebugMessage {
+            severity: DebugMessageSeverity::Info,
+            id: Some("UNASSIGNED-DEBUG-PRINTF".to_string()),
+            text: text.to_string(),
+            kernel: Some("crate::kernels::foo<threads=1>".to_string()),
+        }
+    }
+
+    #[test]
+    fn debug_message_debug_printf() {
+        let msg = debug_printf("Validation Information: [ UNASSIGNED-DEBUG-PRINTF ] Object 0: handle = 0x7f6f3c9724d0, type = VK_OBJECT_TYPE_DEVICE; | MessageID = 0x92394c89 | Hello World!");
+        assert_eq!(msg.kernel(), Some("crate::kernels::foo<threads=1>"));
+        assert_eq!(msg.global_id(), None);
+        assert_eq!(msg.message(), "Hello World!");
+    }
+
+    #[test]
+    fn debug_message_debug_printf_verbose() {
+        let msg = debug_printf("Validation Information: [ UNASSIGNED-DEBUG-PRINTF ] Object 0: handle = 0x7f89289b6070, type = VK_OBJECT_TYPE_QUEUE; | MessageID = 0x92394c89 | Command buffer (0x7f892896d7f0). Compute Dispatch Index 0. Pipeline (0x7f8928a95fb0). Shader Module (0x7f8928a9d500). Shader Instruction Index = 137.  Stage = Compute.  Global invocation ID (x, y, z) = (17, 0, 0 )\nx = 1 | y = 2");
+        assert_eq!(msg.global_id(), Some(17));
+        assert_eq!(msg.message(), "x = 1 | y = 2");
+    }
+
+    #[test]
+    fn debug_message_validation() {
+        let msg = DebugMessage {
+            severity: DebugMessageSeverity::Error,
+            id: Some("VUID-vkCmdDispatch-None-02697".to_string()),
+            text: "Validation Error: Global invocation ID (x, y, z) = (1, 0, 0)".to_string(),
+            kernel: None,
+        };
+        assert_eq!(msg.kernel(), None);
+        assert_eq!(msg.global_id(), None);
+        assert_eq!(msg.message(), msg.text());
+    }
+}
@@ -0,0 +1,321 @@
+use super::Device;
+use crate::{
+    buffer::{Buffer, Slice},
+    scalar::Scalar,
+};
+use anyhow::{bail, Result};
+use std::ops::Range;
+
+/** A group of devices for data parallelism.
+
+Buffers are split into contiguous [shards](Shard), one per device. [`.map()`](DeviceGroup::map)
+scatters a slice to the devices, calls a fn on each shard in parallel, and gathers the outputs:
+```no_run
+# use krnl::{anyhow::Result, buffer::Buffer, device::{Device, DeviceGroup}};
+# fn main() -> Result<()> {
+let group = DeviceGroup::new([
+    Device::builder().index(0).build()?,
+    Device::builder().index(1).build()?,
+])?;
+let x = Buffer::from(vec![1f32; 1_000]);
+let y = group.map(x.as_slice(), |shard, x| {
+    // x is on shard.device(), dispatch kernels here
+    // shard.offset() is the index of x[0] in the input
+    x.cast::<f64>()
+})?;
+assert_eq!(y.len(), x.len());
+# Ok(())
+# }
+```
+Each device is waited on before the outputs are gathered, so that errors, including
+[`DeviceLost`](super::error::DeviceLost), are returned for the shard that caused them.
+*/
+#[derive(Clone, Debug)]
+pub struct DeviceGroup {
+    devices: Vec<Device>,
+}
+
+impl DeviceGroup {
+    /** Creates a group from `devices`.
+
+    A device may appear more than once, in which case it processes multiple shards.
+
+    **Errors**
+    - `devices` is empty.
+    */
+    pub fn new(devices: impl IntoIterator<Item = Device>) -> Result<Self> {
+        let devices: Vec<Device> = devices.into_iter().collect();
+        if devices.is_empty() {
+            bail!("DeviceGroup requires at least 1 device!");
+        }
+        Ok(Self { devices })
+    }
+    /// The devices.
+    pub fn devices(&self) -> &[Device] {
+        &self.devices
+    }
+    /// The number of devices.
+    pub fn len(&self) -> usize {
+        self.devices.len()
+    }
+    /// Always false, groups have at least 1 device.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+    /** Splits `len` items into shards, one per device.
+
+    Shards are contiguous, in order, and differ in length by at most 1. */
+    pub fn shards(&self, len: usize) -> Vec<Shard> {
+        let n = self.devices.len();
+        let mut start = 0;
+        self.devices
+            .iter()
+            .enumerate()
+            .map(|(index, device)| {
+                let shard_len = len / n + (index < len % n) as usize;
+                let range = start..start + shard_len;
+                start = range.end;
+                Shard {
+                    index,
+                    device: device.clone(),
+                    range,
+                }
+            })
+            .collect()
+    }
+    /** Copies each shard of `x` to its device.
+
+    **Errors**
+
+    See [`.to_device()`](crate::buffer::BufferBase::to_device). */
+    pub fn scatter<T: Scalar>(&self, x: Slice<T>) -> Result<Vec<Buffer<T>>> {
+        self.shards(x.len())
+            .into_iter()
+            .map(|shard| {
+                x.slice(shard.range.clone())
+                    .unwrap()
+                    .to_device(shard.device)
+            })
+            .collect()
+    }
+    /** Concatenates `shards` into a buffer on `device`.
+
+    Shards on `device` are copied with `vkCmdCopyBuffer`, and shards on other devices are
+    transferred through the host.
+
+    **Errors**
+    - `shards` does not have a buffer for each device.
+    - See [`.copy_from_slice()`](crate::buffer::BufferBase::copy_from_slice).
+    */
+    pub fn gather<T: Scalar>(&self, shards: &[Buffer<T>], device: Device) -> Result<Buffer<T>> {
+        if shards.len() != self.devices.len() {
+            bail!(
+                "Expected {} shards, found {}!",
+                self.devices.len(),
+                shards.len()
+            );
+        }
+        let len = shards.iter().map(Buffer::len).sum();
+        let mut output = unsafe { Buffer::uninit(device, len)? };
+        let mut start = 0;
+        for shard in shards {
+            let end = start + shard.len();
+            output
+                .slice_mut(start..end)
+                .unwrap()
+                .transfer_from_slice(&shard.as_slice())?;
+            start = end;
+        }
+        Ok(output)
+    }
+    /** Calls `f` for each shard of `len` items in parallel.
+
+    Each device is waited on after `f` returns. On wasm, shards are processed sequentially.
+
+    **Errors**
+    - Errors returned by `f`, the first shard to fail is returned.
+    - [`DeviceLost`](super::error::DeviceLost): A device was lost.
+    */
+    pub fn for_each_shard<F>(&self, len: usize, f: F) -> Result<()>
+    where
+        F: Fn(&Shard) -> Result<()> + Sync,
+    {
+        self.run(self.shards(len), |shard| f(&shard)).map(|_| ())
+    }
+    /** Scatters `x`, calls `f` for each shard in parallel, and gathers the outputs on the device of `x`.
+
+    `f` is passed the shard of `x` on the shard's device.
+
+    **Errors**
+    - Errors returned by `f`, the first shard to fail is returned.
+    - See [`.scatter()`](DeviceGroup::scatter) and [`.gather()`](DeviceGroup::gather).
+    */
+    pub fn map<T, Y, F>(&self, x: Slice<T>, f: F) -> Result<Buffer<Y>>
+    where
+        T: Scalar,
+        Y: Scalar,
+        F: Fn(&Shard, Slice<T>) -> Result<Buffer<Y>> + Sync,
+    {
+        let shards = self.shards(x.len());
+        let inputs = self.scatter(x.clone())?;
+        let outputs = self.run(shards.into_iter().zip(inputs), |(shard, x)| {
+            f(&shard, x.as_slice())
+        })?;
+        self.gather(&outputs, x.device())
+    }
+    fn run<I, R, F>(&self, shards: I, f: F) -> Result<Vec<R>>
+    where
+        I: IntoIterator,
+        I::Item: Send,
+        R: Send,
+        F: Fn(I::Item) -> Result<R> + Sync,
+    {
+        // Threads aren't available on wasm, shards are processed sequentially.
+        #[cfg(target_arch = "wasm32")]
+        {
+            shards
+                .into_iter()
+                .zip(self.devices.iter())
+                .map(|(shard, device)| {
+                    let output = f(shard)?;
+                    device.wait()?;
+                    Ok(output)
+                })
+                .collect()
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            self.run_threads(shards, f)
+        }
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    fn run_threads<I, R, F>(&self, shards: I, f: F) -> Result<Vec<R>>
+    where
+        I: IntoIterator,
+        I::Item: Send,
+        R: Send,
+        F: Fn(I::Item) -> Result<R> + Sync,
+    {
+        let f = &f;
+        let results: Vec<Result<R>> = std::thread::scope(|scope| {
+            let threads: Vec<_> = shards
+                .into_iter()
+                .zip(self.devices.iter())
+                .map(|(shard, device)| {
+                    scope.spawn(move || {
+                        let output = f(shard)?;
+                        device.wait()?;
+                        Ok(output)
+                    })
+                })
+                .collect();
+            threads
+                .into_iter()
+                .map(|thread| {
+                    thread
+                        .join()
+                        .unwrap_or_else(|payload| std::panic::resume_unwind(payload))
+                })
+                .collect()
+        });
+        results.into_iter().collect()
+    }
+}
+
+/// A contiguous portion of a buffer processed by a device in a [`DeviceGroup`].
+#[derive(Clone, Debug)]
+pub struct Shard {
+    index: usize,
+    device: Device,
+    range: Range<usize>,
+}
+
+impl Shard {
+    /// The index of the shard, and of the device in the group.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+    /// The device.
+    pub fn device(&self) -> &Device {
+        &self.device
+    }
+    /// The range of the shard in the buffer.
+    pub fn range(&self) -> Range<usize> {
+        self.range.clone()
+    }
+    /// The index of the first item of the shard in the buffer.
+    pub fn offset(&self) -> usize {
+        self.range.start
+    }
+    /// The number of items.
+    pub fn len(&self) -> usize {
+        self.range.len()
+    }
+    /// Whether the shard is empty.
+    pub fn is_empty(&self) -> bool {
+        self.range.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn device_group_shards() {
+        let group = DeviceGroup::new([Device::host(), Device::host(), Device::host()]).unwrap();
+        let ranges: Vec<_> = group.shards(8).iter().map(Shard::range).collect();
+        assert_eq!(ranges, [0..3, 3..6, 6..8]);
+        let ranges: Vec<_> = group.shards(1).iter().map(Shard::range).collect();
+        assert_eq!(ranges, [0..1, 1..1, 1..1]);
+        assert!(DeviceGroup::new([]).is_err());
+    }
+
+    #[test]
+    fn device_group_map() {
+        let group = DeviceGroup::new([Device::host(), Device::host()]).unwrap();
+        let x = Buffer::from((0..5u32).collect::<Vec<_>>());
+        let y = group
+            .map(x.as_slice(), |shard, x| {
+                let y: Vec<u32> = x
+                    .as_host_slice()
+                    .unwrap()
+                    .iter()
+                    .map(|x| x * 10 + shard.index() as u32)
+                    .collect();
+                Ok(Buffer::from(y))
+            })
+            .unwrap();
+        assert_eq!(y.into_vec().unwrap(), [0, 10, 20, 31, 41]);
+        let error = group
+            .for_each_shard(5, |shard| {
+                if shard.index() == 1 {
+                    bail!("shard {}", shard.index());
+                }
+                Ok(())
+            })
+            .unwrap_err();
+        assert_eq!(error.to_string(), "shard 1");
+    }
+
+    #[cfg(feature = "device")]
+    #[test]
+    fn device_group_mock() {
+        let group = DeviceGroup::new([Device::mock(), Device::mock()]).unwrap();
+        let x = Buffer::from(vec![1u32, 2, 3]);
+        let y = group
+            .map(x.as_slice(), |shard, x| {
+                assert_eq!(&x.device(), shard.device());
+                // mock devices don't execute kernels, so copy via the host
+                Buffer::from(x.to_vec()?).into_device(shard.device().clone())
+            })
+            .unwrap();
+        assert!(y.device().is_host());
+        assert_eq!(y.into_vec().unwrap(), [1, 2, 3]);
+        let shards = group.scatter(Slice::from([1u32, 2, 3].as_slice())).unwrap();
+        let device = group.devices()[0].clone();
+        let y = group.gather(&shards, device.clone()).unwrap();
+        assert_eq!(y.device(), device);
+        assert_eq!(y.into_vec().unwrap(), [1, 2, 3]);
+    }
+}
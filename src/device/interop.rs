@@ -0,0 +1,60 @@
+use ash::vk;
+
+/** Raw Vulkan handles of a [`Device`](super::Device), see [`Device::vulkan_handles()`](super::Device::vulkan_handles).
+
+The handles are owned by krnl, and are valid while the device is alive.
+*/
+#[derive(Clone, Debug)]
+pub struct VulkanHandles {
+    pub(super) instance: vk::Instance,
+    pub(super) physical_device: vk::PhysicalDevice,
+    pub(super) device: vk::Device,
+    pub(super) queue_family_index: u32,
+    pub(super) queues: Vec<vk::Queue>,
+}
+
+impl VulkanHandles {
+    /// The instance.
+    pub fn instance(&self) -> vk::Instance {
+        self.instance
+    }
+    /// The physical device.
+    pub fn physical_device(&self) -> vk::PhysicalDevice {
+        self.physical_device
+    }
+    /// The logical device.
+    pub fn device(&self) -> vk::Device {
+        self.device
+    }
+    /// The queue family of the queues.
+    pub fn queue_family_index(&self) -> u32 {
+        self.queue_family_index
+    }
+    /// The compute queues, indexed by [stream](super::Stream::index).
+    pub fn queues(&self) -> &[vk::Queue] {
+        &self.queues
+    }
+}
+
+/// The raw Vulkan buffer backing a slice, see [`Slice::vulkan_buffer()`](crate::buffer::BufferBase::vulkan_buffer).
+#[derive(Clone, Copy, Debug)]
+pub struct VulkanBuffer {
+    pub(super) buffer: vk::Buffer,
+    pub(super) offset: u64,
+    pub(super) size: u64,
+}
+
+impl VulkanBuffer {
+    /// The buffer.
+    pub fn buffer(&self) -> vk::Buffer {
+        self.buffer
+    }
+    /// The offset of the slice in bytes.
+    pub fn offset(&self) -> u64 {
+        self.offset
+    }
+    /// The size of the slice in bytes.
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+}
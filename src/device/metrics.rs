@@ -0,0 +1,209 @@
+use std::{collections::BTreeMap, time::Duration};
+
+/** Performance metrics of a device.
+
+Enabled with [`DeviceBuilder::performance_metrics`](super::DeviceBuilder::performance_metrics),
+see [`Device::performance_metrics()`](super::Device::performance_metrics).
+
+```no_run
+# use krnl::{anyhow::Result, buffer::Buffer, device::Device};
+# fn main() -> Result<()> {
+let device = Device::builder().performance_metrics(true).build()?;
+let mut y = Buffer::<f32>::zeros(device.clone(), 1_000_000)?;
+y.fill(1f32)?;
+let y = y.into_vec()?;
+let metrics = device.performance_metrics().unwrap();
+println!("download: {:?}", metrics.download());
+for (name, kernel) in metrics.kernels() {
+    println!("{name}: {kernel:?}");
+}
+device.reset_performance_metrics();
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, Default)]
+pub struct PerformanceMetrics {
+    upload: TransferMetrics,
+    download: TransferMetrics,
+    kernels: BTreeMap<String, KernelMetrics>,
+}
+
+impl PerformanceMetrics {
+    /// Uploads from the host.
+    pub fn upload(&self) -> TransferMetrics {
+        self.upload
+    }
+    /// Downloads to the host.
+    pub fn download(&self) -> TransferMetrics {
+        self.download
+    }
+    /// Metrics for the kernel `name`, if dispatched.
+    pub fn kernel(&self, name: &str) -> Option<KernelMetrics> {
+        self.kernels.get(name).copied()
+    }
+    /// Metrics for each dispatched kernel, sorted by name.
+    pub fn kernels(&self) -> impl Iterator<Item = (&str, KernelMetrics)> + '_ {
+        self.kernels.iter().map(|(name, x)| (name.as_str(), *x))
+    }
+    #[cfg(feature = "device")]
+    pub(super) fn record_upload(&mut self, bytes: usize, time: Duration) {
+        self.upload.record(bytes, time);
+    }
+    #[cfg(feature = "device")]
+    pub(super) fn record_download(&mut self, bytes: usize, time: Duration) {
+        self.download.record(bytes, time);
+    }
+    #[cfg(feature = "device")]
+    pub(super) fn record_dispatch(&mut self, name: &str) {
+        self.kernel_mut(name).dispatches += 1;
+    }
+    #[cfg(feature = "device")]
+    pub(super) fn record_gpu_time(&mut self, name: &str, time: Duration) {
+        self.kernel_mut(name).gpu_time += time;
+    }
+    #[cfg(feature = "device")]
+    fn kernel_mut(&mut self, name: &str) -> &mut KernelMetrics {
+        if !self.kernels.contains_key(name) {
+            self.kernels
+                .insert(name.to_string(), KernelMetrics::default());
+        }
+        self.kernels.get_mut(name).unwrap()
+    }
+}
+
+/// Transfer metrics, see [`PerformanceMetrics`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransferMetrics {
+    count: u64,
+    bytes: u64,
+    time: Duration,
+}
+
+impl TransferMetrics {
+    /// The number of transfers.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+    /// The total bytes transferred.
+    pub fn bytes(&self) -> u64 {
+        self.bytes
+    }
+    /// The total time spent transferring on the host, including waiting for the device.
+    pub fn time(&self) -> Duration {
+        self.time
+    }
+    #[cfg(feature = "device")]
+    fn record(&mut self, bytes: usize, time: Duration) {
+        self.count += 1;
+        self.bytes += bytes as u64;
+        self.time += time;
+    }
+}
+
+/// Kernel metrics, see [`PerformanceMetrics`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KernelMetrics {
+    dispatches: u64,
+    gpu_time: Duration,
+}
+
+impl KernelMetrics {
+    /// The number of dispatches.
+    pub fn dispatches(&self) -> u64 {
+        self.dispatches
+    }
+    /// The total time executing on the device.
+    ///
+    /// This is zero if the device doesn't support timestamp queries.
+    pub fn gpu_time(&self) -> Duration {
+        self.gpu_time
+    }
+}
+
+/** Device memory usage, see [`Device::memory_stats()`](super::Device::memory_stats).
+
+```no_run
+# use krnl::{anyhow::Result, buffer::Buffer, device::Device};
+# fn main() -> Result<()> {
+let device = Device::builder().build()?;
+let x = Buffer::<f32>::zeros(device.clone(), 1_000)?;
+let stats = device.memory_stats().unwrap();
+assert_eq!(stats.allocated_bytes(), 4_000);
+# Ok(())
+# }
+```
+*/
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MemoryStats {
+    allocated_bytes: u64,
+    allocations: u64,
+    peak_bytes: u64,
+    total_allocations: u64,
+}
+
+impl MemoryStats {
+    /// The bytes currently allocated for buffers.
+    pub fn allocated_bytes(&self) -> u64 {
+        self.allocated_bytes
+    }
+    /// The number of live allocations.
+    pub fn allocations(&self) -> u64 {
+        self.allocations
+    }
+    /// The max of [`.allocated_bytes()`](MemoryStats::allocated_bytes) since the device was created.
+    pub fn peak_bytes(&self) -> u64 {
+        self.peak_bytes
+    }
+    /// The number of allocations since the device was created.
+    pub fn total_allocations(&self) -> u64 {
+        self.total_allocations
+    }
+}
+
+#[cfg(feature = "device")]
+#[derive(Default, Debug)]
+pub(super) struct MemoryTracker {
+    stats: parking_lot::Mutex<MemoryStats>,
+}
+
+#[cfg(feature = "device")]
+impl MemoryTracker {
+    pub(super) fn stats(&self) -> MemoryStats {
+        *self.stats.lock()
+    }
+    pub(super) fn alloc(self: &std::sync::Arc<Self>, bytes: usize) -> MemoryAllocation {
+        let mut stats = self.stats.lock();
+        stats.allocated_bytes += bytes as u64;
+        stats.allocations += 1;
+        stats.total_allocations += 1;
+        stats.peak_bytes = stats.peak_bytes.max(stats.allocated_bytes);
+        MemoryAllocation {
+            bytes,
+            tracker: self.clone(),
+        }
+    }
+}
+
+/// Frees `bytes` from the tracker when dropped.
+#[cfg(feature = "device")]
+pub(super) struct MemoryAllocation {
+    bytes: usize,
+    tracker: std::sync::Arc<MemoryTracker>,
+}
+
+#[cfg(feature = "device")]
+impl MemoryAllocation {
+    pub(super) fn bytes(&self) -> usize {
+        self.bytes
+    }
+}
+
+#[cfg(feature = "device")]
+impl Drop for MemoryAllocation {
+    fn drop(&mut self) {
+        let mut stats = self.tracker.stats.lock();
+        stats.allocated_bytes -= self.bytes as u64;
+        stats.allocations -= 1;
+    }
+}
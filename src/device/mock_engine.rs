@@ -0,0 +1,988 @@
+use super::{
+    DebugMessage, DeviceEngine, DeviceEngineBuffer, DeviceEngineKernel, DeviceId, DeviceInfo,
+    DeviceLost, DeviceOptions, DeviceType, KernelDesc, KernelKey, MemoryBudget, MockOp, Priority,
+    RawWaitFuture,
+};
+use anyhow::Result;
+use parking_lot::Mutex;
+use std::{
+    ops::Range,
+    sync::{
+        atomic::{AtomicBool, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::Duration,
+};
+
+pub(super) struct Engine {
+    info: Arc<DeviceInfo>,
+    ops: Mutex<Vec<MockOp>>,
+    // Ops recorded within a batch scope, until submitted.
+    held: Mutex<Vec<MockOp>>,
+    batches: AtomicUsize,
+    options: DeviceOptions,
+}
+
+impl Engine {
+    pub(super) fn take_ops(&self) -> Vec<MockOp> {
+        std::mem::take(&mut *self.ops.lock())
+    }
+    fn record(&self, op: MockOp) {
+        let mut held = self.held.lock();
+        let mut ops = self.ops.lock();
+        let download = matches!(op, MockOp::Download { .. });
+        match op {
+            MockOp::Alloc { .. } => ops.push(op),
+            // Reading waits, which submits held ops.
+            _ if !download && self.batches.load(Ordering::SeqCst) > 0 => held.push(op),
+            _ => {
+                ops.append(&mut held);
+                ops.push(op);
+            }
+        }
+    }
+    fn submit(&self) {
+        let mut held = self.held.lock();
+        self.ops.lock().append(&mut held);
+    }
+}
+
+impl DeviceEngine for Engine {
+    type DeviceBuffer = DeviceBuffer;
+    type Kernel = Kernel;
+    fn new(options: DeviceOptions) -> Result<Arc<Self>> {
+        let panic_dump = options.panic_dump();
+        let recorder = options.recorder()?;
+        let metrics = options.metrics();
+        let DeviceOptions {
+            index,
+            max_allocation_size,
+            optimal_features,
+            verify_transfers,
+            ..
+        } = options;
+        let options = DeviceOptions {
+            recorder: recorder.clone(),
+            ..options
+        };
+        let info = Arc::new(DeviceInfo {
+            index,
+            name: "mock".to_string(),
+            device_id: 0,
+            vendor_id: 0,
+            uuid: [0; 16],
+            device_type: DeviceType::Other,
+            driver_version: 0,
+            driver_name: None,
+            driver_info: None,
+            pci_address: None,
+            max_groups: 65_535,
+            max_threads: 1024,
+            max_group_size: [1024, 1024, 64],
+            max_group_threads: 1024,
+            max_push_constant_size: 128,
+            subgroup_threads: 32,
+            min_subgroup_threads: 32,
+            max_subgroup_threads: 32,
+            // Simulates a device with a high priority queue.
+            compute_queues: 2,
+            queue_family: None,
+            max_allocation_size: max_allocation_size
+                .unwrap_or(usize::MAX)
+                .min(super::DeviceBuffer::MAX_ALLOCATION_SIZE),
+            features: optimal_features,
+            memory_heaps: Vec::new(),
+            debug_printf: false,
+            verify_transfers,
+            panic_dump,
+            recorder,
+            metrics,
+            memory: Arc::default(),
+        });
+        Ok(Arc::new(Self {
+            info,
+            ops: Mutex::default(),
+            held: Mutex::default(),
+            batches: AtomicUsize::default(),
+            options,
+        }))
+    }
+    fn recreate(&self) -> Result<Arc<Self>> {
+        Self::new(self.options.clone())
+    }
+    fn id(&self) -> DeviceId {
+        let index = self.info.index;
+        let handle = self as *const Self as usize;
+        DeviceId { index, handle }
+    }
+    fn info(&self) -> &Arc<DeviceInfo> {
+        &self.info
+    }
+    fn wait(&self) -> Result<(), DeviceLost> {
+        self.submit();
+        Ok(())
+    }
+    fn wait_stream(&self, _stream: usize) -> Result<(), DeviceLost> {
+        self.submit();
+        Ok(())
+    }
+    fn flush(&self) -> Result<(), DeviceLost> {
+        self.submit();
+        Ok(())
+    }
+    // Like the vulkan engine, the scope is device wide.
+    fn begin_batch(&self) {
+        self.batches.fetch_add(1, Ordering::SeqCst);
+    }
+    fn end_batch(&self) {
+        if self
+            .batches
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| x.checked_sub(1))
+            == Ok(1)
+        {
+            self.submit();
+        }
+    }
+    fn gpu_time(&self) -> Result<Option<Duration>, DeviceLost> {
+        Ok(None)
+    }
+    fn wait_async(&self) -> RawWaitFuture {
+        self.submit();
+        Box::pin(std::future::ready(Ok(())))
+    }
+    fn wait_stream_async(&self, _stream: usize) -> RawWaitFuture {
+        self.wait_async()
+    }
+    fn trim(&self) -> Result<(), DeviceLost> {
+        self.wait()
+    }
+    fn memory_budget(&self) -> Option<Vec<MemoryBudget>> {
+        None
+    }
+    fn take_debug_messages(&self) -> Vec<DebugMessage> {
+        Vec::new()
+    }
+}
+
+pub(super) struct DeviceBuffer {
+    data: Arc<Mutex<Vec<u8>>>,
+    engine: Arc<Engine>,
+    offset: usize,
+    len: usize,
+}
+
+impl DeviceBuffer {
+    pub(super) fn copy(&self, dst: &Self) {
+        self.engine.record(MockOp::Copy { bytes: self.len });
+        self.copy_data(dst);
+    }
+    fn copy_data(&self, dst: &Self) {
+        let src_range = self.offset..self.offset + self.len;
+        let dst_range = dst.offset..dst.offset + dst.len;
+        if Arc::ptr_eq(&self.data, &dst.data) {
+            self.data.lock().copy_within(src_range, dst_range.start);
+        } else {
+            dst.data.lock()[dst_range].copy_from_slice(&self.data.lock()[src_range]);
+        }
+    }
+}
+
+impl DeviceEngineBuffer for DeviceBuffer {
+    type Engine = Engine;
+    unsafe fn uninit(engine: Arc<Engine>, len: usize) -> Result<Self> {
+        engine.record(MockOp::Alloc { bytes: len });
+        Ok(Self {
+            data: Arc::new(Mutex::new(vec![0; len])),
+            engine,
+            offset: 0,
+            len,
+        })
+    }
+    fn upload(&self, data: &[u8]) -> Result<()> {
+        self.engine.record(MockOp::Upload { bytes: data.len() });
+        let range = self.offset..self.offset + self.len;
+        self.data.lock()[range].copy_from_slice(data);
+        Ok(())
+    }
+    fn download(&self, data: &mut [u8]) -> Result<()> {
+        self.engine.record(MockOp::Download { bytes: data.len() });
+        let range = self.offset..self.offset + self.len;
+        data.copy_from_slice(&self.data.lock()[range]);
+        Ok(())
+    }
+    fn transfer(&self, dst: &Self) -> Result<()> {
+        self.engine.record(MockOp::Transfer { bytes: self.len });
+        self.copy_data(dst);
+        Ok(())
+    }
+    fn engine(&self) -> &Arc<Engine> {
+        &self.engine
+    }
+    fn offset(&self) -> usize {
+        self.offset
+    }
+    fn len(&self) -> usize {
+        self.len
+    }
+    fn slice(self: &Arc<Self>, range: Range<usize>) -> Option<Arc<Self>> {
+        let Range { start, end } = range;
+        if start > self.len || end > self.len {
+            return None;
+        }
+        let offset = self.offset.checked_add(start)?;
+        let len = end.checked_sub(start)?;
+        Some(Arc::new(Self {
+            data: self.data.clone(),
+            engine: self.engine.clone(),
+            offset,
+            len,
+        }))
+    }
+}
+
+pub(super) struct Kernel {
+    engine: Arc<Engine>,
+    desc: Arc<KernelDesc>,
+}
+
+impl DeviceEngineKernel for Kernel {
+    type Engine = Engine;
+    type DeviceBuffer = DeviceBuffer;
+    fn cached(
+        engine: Arc<Engine>,
+        _key: KernelKey,
+        desc_fn: impl FnOnce() -> Result<Arc<KernelDesc>>,
+    ) -> Result<Arc<Self>> {
+        let desc = desc_fn()?;
+        Ok(Arc::new(Self { engine, desc }))
+    }
+    unsafe fn dispatch(
+        &self,
+        groups: u32,
+        indirect: Option<&Arc<DeviceBuffer>>,
+        buffers: &[Arc<DeviceBuffer>],
+        push_consts: Vec<u8>,
+        _debug_printf_panic: Option<Arc<AtomicBool>>,
+        priority: Priority,
+        stream: usize,
+    ) -> Result<()> {
+        let groups = if let Some(indirect) = indirect {
+            let data = indirect.data.lock();
+            u32::from_ne_bytes(
+                data[indirect.offset..indirect.offset + 4]
+                    .try_into()
+                    .unwrap(),
+            )
+        } else {
+            groups
+        };
+        self.engine.record(MockOp::Dispatch {
+            kernel: self.desc.name.to_string(),
+            groups,
+            indirect: indirect.map(|x| x.offset..x.offset + x.len),
+            buffers: buffers.iter().map(|x| x.offset..x.offset + x.len).collect(),
+            push_consts,
+            priority,
+            stream,
+        });
+        Ok(())
+    }
+    fn engine(&self) -> &Arc<Engine> {
+        &self.engine
+    }
+    fn desc(&self) -> &Arc<KernelDesc> {
+        &self.desc
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        buffer::Buffer,
+        device::{Device, DeviceInner, MockOp, Priority},
+    };
+    use std::time::Duration;
+
+    #[test]
+    fn mock_dispatch() {
+        let device = Device::mock();
+        let mut y = Buffer::<u32>::zeros(device.clone(), 8).unwrap();
+        y.slice_mut(2..).unwrap().fill(1).unwrap();
+        assert_eq!(y.to_vec().unwrap(), [0; 8]);
+        let ops = device.take_mock_ops().unwrap();
+        assert_eq!(ops[0], MockOp::Alloc { bytes: 32 });
+        assert!(matches!(
+            &ops[ops.len() - 2],
+            MockOp::Dispatch { kernel, buffers, priority, .. } if kernel.contains("fill") && buffers.len() == 1 && buffers[0] == (8..32) && *priority == Priority::Normal
+        ));
+        assert_eq!(ops.last().unwrap(), &MockOp::Download { bytes: 32 });
+        assert_eq!(device.take_mock_ops().unwrap(), []);
+    }
+
+    #[test]
+    fn mock_verify_transfers() {
+        use crate::device::{verify_transfer, RawDevice};
+
+        let options = Device::builder().verify_transfers(true).options;
+        let device = Device {
+            inner: DeviceInner::Device(RawDevice::mock(options)),
+        };
+        let x = Buffer::from(vec![1u8, 2, 3])
+            .into_device(device.clone())
+            .unwrap();
+        assert_eq!(x.to_vec().unwrap(), [1, 2, 3]);
+        assert_eq!(
+            device.take_mock_ops().unwrap(),
+            [
+                MockOp::Alloc { bytes: 3 },
+                MockOp::Upload { bytes: 3 },
+                MockOp::Download { bytes: 3 },
+                MockOp::Download { bytes: 3 },
+                MockOp::Download { bytes: 3 },
+            ]
+        );
+        let error = verify_transfer(&[1, 2, 3], &[1, 0, 3]).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "Transfer of 3 bytes is corrupted at byte 1!"
+        );
+    }
+
+    #[test]
+    fn mock_record() {
+        use crate::{device::RawDevice, replay::Trace};
+
+        let path = std::env::temp_dir().join(format!("krnl-record-{}.bin", std::process::id()));
+        let options = Device::builder().record(&path).record_max_bytes(30).options;
+        let device = Device {
+            inner: DeviceInner::Device(RawDevice::mock(options)),
+        };
+        let mut y = Buffer::from(vec![1u32, 2, 3, 4])
+            .into_device(device.clone())
+            .unwrap();
+        y.slice_mut(1..).unwrap().fill(0).unwrap();
+        y.fill(0).unwrap();
+        let trace = Trace::load(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+        let dispatches = trace.dispatches();
+        assert_eq!(dispatches.len(), 2);
+        assert!(dispatches[0].name().contains("fill"));
+        assert_eq!(dispatches[0].device(), "mock");
+        let slice = &dispatches[0].slices()[0];
+        assert!(slice.mutable());
+        assert_eq!((slice.offset(), slice.len()), (4, 12));
+        assert_eq!(
+            slice.input(),
+            Some(&[2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0][..])
+        );
+        assert_eq!(slice.input(), slice.output());
+        // Exceeds max_bytes.
+        assert_eq!(dispatches[1].slices()[0].input(), None);
+        let device = Device::mock();
+        let replayed = unsafe { crate::replay::run(&trace, device.clone()).unwrap() };
+        assert_eq!(replayed.dispatches().len(), 2);
+        assert_eq!(
+            replayed.dispatches()[0].slices()[0].output(),
+            slice.output()
+        );
+        assert_eq!(
+            replayed.dispatches()[1].slices()[0].output(),
+            Some(&[0; 16][..])
+        );
+        assert_eq!(
+            device
+                .take_mock_ops()
+                .unwrap()
+                .iter()
+                .filter(|x| matches!(x, MockOp::Dispatch { .. }))
+                .count(),
+            2
+        );
+    }
+
+    #[test]
+    fn mock_performance_metrics() {
+        use crate::device::RawDevice;
+
+        let options = Device::builder().performance_metrics(true).options;
+        let device = Device {
+            inner: DeviceInner::Device(RawDevice::mock(options)),
+        };
+        let mut y = Buffer::from(vec![1u32, 2, 3, 4])
+            .into_device(device.clone())
+            .unwrap();
+        y.fill(0).unwrap();
+        y.to_vec().unwrap();
+        let metrics = device.performance_metrics().unwrap();
+        assert_eq!(
+            (metrics.upload().count(), metrics.upload().bytes()),
+            (1, 16)
+        );
+        assert_eq!(
+            (metrics.download().count(), metrics.download().bytes()),
+            (1, 16)
+        );
+        let kernels: Vec<_> = metrics.kernels().collect();
+        assert_eq!(kernels.len(), 1);
+        assert!(kernels[0].0.contains("fill"));
+        assert_eq!(kernels[0].1.dispatches(), 1);
+        assert_eq!(kernels[0].1.gpu_time(), Duration::ZERO);
+        device.reset_performance_metrics();
+        let metrics = device.performance_metrics().unwrap();
+        assert_eq!(metrics.upload().count(), 0);
+        assert_eq!(metrics.kernels().count(), 0);
+        assert!(Device::mock().performance_metrics().is_none());
+        assert!(Device::host().performance_metrics().is_none());
+    }
+    #[test]
+    fn mock_wait_async() {
+        let device = Device::mock();
+        let mut y = Buffer::<u32>::zeros(device.clone(), 8).unwrap();
+        y.fill(1).unwrap();
+        device.wait_async().block().unwrap();
+        Device::host().wait_async().block().unwrap();
+    }
+    #[test]
+    fn mock_dispatch_async() {
+        use crate::buffer::kernels::fill_u32;
+
+        let device = Device::mock();
+        let mut y = Buffer::<u32>::zeros(device.clone(), 8).unwrap();
+        let kernel = fill_u32::builder().unwrap().build(device.clone()).unwrap();
+        kernel
+            .dispatch_async(1, y.as_slice_mut())
+            .unwrap()
+            .block()
+            .unwrap();
+        kernel
+            .with_priority(Priority::High)
+            .dispatch_async(1, y.as_slice_mut())
+            .unwrap()
+            .block()
+            .unwrap();
+        let ops = device.take_mock_ops().unwrap();
+        assert!(matches!(
+            &ops[ops.len() - 1],
+            MockOp::Dispatch {
+                priority: Priority::High,
+                ..
+            }
+        ));
+        device.stream(1).unwrap().wait_async().block().unwrap();
+        Device::host()
+            .stream(0)
+            .unwrap()
+            .wait_async()
+            .block()
+            .unwrap();
+    }
+    #[test]
+    fn mock_kernel_metadata() {
+        use crate::{buffer::kernels::fill_u32, scalar::ScalarType};
+
+        let builder = fill_u32::builder().unwrap().with_threads(64);
+        let metadata = builder.metadata();
+        assert!(metadata.name().ends_with("fill_u32"), "{}", metadata.name());
+        assert!(metadata.safe());
+        assert_eq!(metadata.threads(), Some(64));
+        let push_consts = metadata.push_consts();
+        assert_eq!(push_consts.len(), 1);
+        assert_eq!(push_consts[0].name(), "x");
+        assert_eq!(push_consts[0].scalar_type(), ScalarType::U32);
+        let slices = metadata.slices();
+        assert_eq!(slices.len(), 1);
+        assert_eq!(slices[0].name(), "y");
+        assert!(slices[0].mutable() && slices[0].item());
+        let kernel = builder.build(Device::mock()).unwrap();
+        assert_eq!(kernel.metadata().threads(), Some(64));
+    }
+    #[test]
+    fn mock_kernel_limits() {
+        use crate::buffer::kernels::fill_u32;
+
+        let device = Device::mock();
+        let error = fill_u32::builder()
+            .unwrap()
+            .with_threads(2048)
+            .build(device.clone())
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(
+            error.contains("threads 2048 is greater than max_threads 1024"),
+            "{error}"
+        );
+        let error = fill_u32::builder()
+            .unwrap()
+            .with_threads(0)
+            .build(device.clone())
+            .err()
+            .unwrap()
+            .to_string();
+        assert!(error.contains("threads must be greater than 0"), "{error}");
+        let kernel = fill_u32::builder()
+            .unwrap()
+            .with_threads(64)
+            .build(device.clone())
+            .unwrap();
+        assert_eq!(kernel.max_groups(), 65_535);
+        assert_eq!(kernel.recommended_global_threads(0), 0);
+        assert_eq!(kernel.recommended_global_threads(100), 128);
+        assert_eq!(kernel.recommended_global_threads(usize::MAX), 65_535 * 64);
+        let mut y = Buffer::<u32>::zeros(device, 8).unwrap();
+        let error = kernel
+            .with_groups(65_536)
+            .dispatch(1, y.as_slice_mut())
+            .unwrap_err()
+            .to_string();
+        assert!(
+            error.contains("groups 65536 is greater than max_groups 65535"),
+            "{error}"
+        );
+    }
+    #[test]
+    fn mock_batch() {
+        use crate::buffer::kernels::fill_u32;
+
+        let device = Device::mock();
+        let mut y = Buffer::<u32>::zeros(device.clone(), 8).unwrap();
+        let kernel = fill_u32::builder().unwrap().build(device.clone()).unwrap();
+        device.take_mock_ops().unwrap();
+        device.begin_batch();
+        for _ in 0..4 {
+            kernel.dispatch(1, y.as_slice_mut()).unwrap();
+        }
+        device.end_batch();
+        device.wait().unwrap();
+        let ops = device.take_mock_ops().unwrap();
+        let dispatches = ops
+            .iter()
+            .filter(|op| matches!(op, MockOp::Dispatch { .. }))
+            .count();
+        assert_eq!(dispatches, 4);
+        Device::host().begin_batch();
+        Device::host().end_batch();
+    }
+    #[test]
+    fn mock_batch_threads() {
+        use crate::buffer::kernels::fill_u32;
+
+        let dispatches = |ops: &[MockOp]| {
+            ops.iter()
+                .filter(|op| matches!(op, MockOp::Dispatch { .. }))
+                .count()
+        };
+        let device = Device::mock();
+        let mut x = Buffer::<u32>::zeros(device.clone(), 8).unwrap();
+        let mut y = Buffer::<u32>::zeros(device.clone(), 8).unwrap();
+        let kernel = fill_u32::builder().unwrap().build(device.clone()).unwrap();
+        device.take_mock_ops().unwrap();
+        device.begin_batch();
+        kernel.dispatch(1, x.as_slice_mut()).unwrap();
+        // The scope is device wide, so work from other threads is also held.
+        std::thread::scope(|s| {
+            s.spawn(|| kernel.dispatch(2, y.as_slice_mut()).unwrap());
+        });
+        assert_eq!(dispatches(&device.take_mock_ops().unwrap()), 0);
+        // Waiting on any thread submits it.
+        std::thread::scope(|s| {
+            s.spawn(|| device.wait().unwrap());
+        });
+        assert_eq!(dispatches(&device.take_mock_ops().unwrap()), 2);
+        kernel.dispatch(3, x.as_slice_mut()).unwrap();
+        assert_eq!(dispatches(&device.take_mock_ops().unwrap()), 0);
+        device.end_batch();
+        assert_eq!(dispatches(&device.take_mock_ops().unwrap()), 1);
+    }
+    #[test]
+    fn mock_trim() {
+        use crate::buffer::kernels::fill_u32;
+
+        let device = Device::mock();
+        let mut y = Buffer::<u32>::zeros(device.clone(), 8).unwrap();
+        let kernel = fill_u32::builder().unwrap().build(device.clone()).unwrap();
+        device.take_mock_ops().unwrap();
+        device.begin_batch();
+        kernel.dispatch(1, y.as_slice_mut()).unwrap();
+        // Trimming waits, which submits the held batch.
+        device.trim().unwrap();
+        let ops = device.take_mock_ops().unwrap();
+        assert!(
+            matches!(ops.as_slice(), [MockOp::Dispatch { .. }]),
+            "{ops:?}"
+        );
+        device.end_batch();
+        Device::host().trim().unwrap();
+    }
+    #[test]
+    fn mock_streams() {
+        let device = Device::mock();
+        assert_eq!(device.info().unwrap().compute_queues(), 2);
+        let stream = device.stream(1).unwrap();
+        assert_eq!(stream.device(), &device);
+        assert_eq!(stream.index(), 1);
+        stream.wait().unwrap();
+        assert!(device.stream(2).is_err());
+        Device::host().stream(0).unwrap().wait().unwrap();
+        assert!(Device::host().stream(1).is_err());
+    }
+    #[test]
+    fn mock_recreate() {
+        use crate::device::Checkpoint;
+
+        let device = Device::mock();
+        let x = Buffer::from(vec![1u32, 2, 3])
+            .into_device(device.clone())
+            .unwrap();
+        let mut checkpoint = Checkpoint::new();
+        checkpoint.save("x", x.as_scalar_slice()).unwrap();
+        assert_eq!(checkpoint.names().collect::<Vec<_>>(), ["x"]);
+        let device2 = device.recreate().unwrap();
+        assert_ne!(device, device2);
+        let x: Buffer<u32> = checkpoint
+            .restore("x", device2.clone())
+            .unwrap()
+            .unwrap()
+            .try_into()
+            .unwrap();
+        assert_eq!(x.device(), device2);
+        assert_eq!(x.to_vec().unwrap(), [1, 2, 3]);
+        assert!(checkpoint.restore("y", device2).unwrap().is_none());
+        assert!(Device::host().recreate().is_err());
+    }
+    #[test]
+    fn mock_memory() {
+        let device = Device::mock();
+        let info = device.info().unwrap();
+        assert!(info.memory_heaps().is_empty());
+        assert_eq!(info.device_local_bytes(), 0);
+        assert_eq!(info.host_visible_bytes(), 0);
+        assert_eq!(device.memory_budget(), None);
+        assert_eq!(Device::host().memory_budget(), None);
+    }
+    #[test]
+    fn mock_memory_stats() {
+        let device = Device::mock();
+        let x = Buffer::<u32>::zeros(device.clone(), 10).unwrap();
+        let y = Buffer::<u8>::zeros(device.clone(), 8).unwrap();
+        let _empty = Buffer::<u8>::zeros(device.clone(), 0).unwrap();
+        let stats = device.memory_stats().unwrap();
+        assert_eq!(stats.allocated_bytes(), 48);
+        assert_eq!(stats.allocations(), 2);
+        drop(x);
+        drop(y);
+        let stats = device.memory_stats().unwrap();
+        assert_eq!(stats.allocated_bytes(), 0);
+        assert_eq!(stats.allocations(), 0);
+        assert_eq!(stats.peak_bytes(), 48);
+        assert_eq!(stats.total_allocations(), 2);
+        assert_eq!(Device::host().memory_stats(), None);
+    }
+    #[test]
+    fn queue_priorities() {
+        use crate::device::PowerProfile;
+        let options = Device::builder().options;
+        assert_eq!(options.queue_priorities(1), [0.5]);
+        assert_eq!(options.queue_priorities(3), [0.5, 1.0, 0.5]);
+        let options = Device::builder()
+            .power_profile(PowerProfile::Throughput)
+            .options;
+        assert_eq!(options.queue_priorities(2), [0.25, 0.5]);
+        let options = Device::builder()
+            .power_profile(PowerProfile::Throughput)
+            .queue_priority(0.75)
+            .options;
+        assert_eq!(options.queue_priorities(2), [0.75, 1.0]);
+        assert!(Device::builder().queue_priority(1.5).build().is_err());
+    }
+    #[test]
+    fn memory_block_size() {
+        assert!(Device::builder().options.memory_block_size.is_none());
+        let options = Device::builder().memory_block_size(1 << 20).options;
+        assert_eq!(options.memory_block_size, Some(1 << 20));
+        let error = Device::builder().memory_block_size(0).build().unwrap_err();
+        assert!(error.to_string().contains("non-zero"));
+    }
+    #[test]
+    fn mock_max_allocation_size() {
+        use crate::{buffer::kernels::fill_u32, device::RawDevice};
+
+        let options = Device::builder().max_allocation_size(16).options;
+        let device = Device {
+            inner: DeviceInner::Device(RawDevice::mock(options)),
+        };
+        assert_eq!(device.info().unwrap().max_allocation_size(), 16);
+        let x = Buffer::from((0..10).collect::<Vec<u32>>())
+            .into_device(device.clone())
+            .unwrap();
+        assert_eq!(
+            device.take_mock_ops().unwrap(),
+            [
+                MockOp::Alloc { bytes: 16 },
+                MockOp::Alloc { bytes: 16 },
+                MockOp::Alloc { bytes: 8 },
+                MockOp::Upload { bytes: 16 },
+                MockOp::Upload { bytes: 16 },
+                MockOp::Upload { bytes: 8 },
+            ]
+        );
+        assert_eq!(x.slice(3..9).unwrap().to_vec().unwrap(), [3, 4, 5, 6, 7, 8]);
+        assert_eq!(x.slice(4..4).unwrap().len(), 0);
+        assert_eq!(x.slice(5..).unwrap().offset(), 5);
+        let mut y = Buffer::<u32>::zeros(device.clone(), 8).unwrap();
+        device.take_mock_ops();
+        y.copy_from_slice(&x.slice(1..9).unwrap()).unwrap();
+        assert_eq!(
+            device.take_mock_ops().unwrap(),
+            [
+                MockOp::Copy { bytes: 12 },
+                MockOp::Copy { bytes: 4 },
+                MockOp::Copy { bytes: 12 },
+                MockOp::Copy { bytes: 4 },
+            ]
+        );
+        assert_eq!(y.to_vec().unwrap(), [1, 2, 3, 4, 5, 6, 7, 8]);
+        let kernel = fill_u32::builder().unwrap().build(device.clone()).unwrap();
+        let error = kernel
+            .dispatch(0, y.slice_mut(2..6).unwrap())
+            .unwrap_err()
+            .to_string();
+        assert!(error.contains("spans multiple allocations"), "{error}");
+        device.take_mock_ops();
+        kernel.dispatch(0, y.slice_mut(5..8).unwrap()).unwrap();
+        let ops = device.take_mock_ops().unwrap();
+        let MockOp::Dispatch {
+            buffers,
+            push_consts,
+            ..
+        } = &ops[0]
+        else {
+            panic!("{ops:?}");
+        };
+        // The second allocation is bound, with the offset of the slice in it.
+        assert_eq!(buffers.len(), 1);
+        assert_eq!(buffers[0], 4..16);
+        assert_eq!(push_consts[4..8], 1u32.to_ne_bytes());
+        // mock devices don't execute kernels, so each allocation is filled with a kernel
+        y.fill(0).unwrap();
+        let dispatches = device
+            .take_mock_ops()
+            .unwrap()
+            .into_iter()
+            .filter(|op| matches!(op, MockOp::Dispatch { .. }))
+            .count();
+        assert_eq!(dispatches, 2);
+    }
+    #[test]
+    fn mock_copy() {
+        let device = Device::mock();
+        let x = Buffer::from(vec![1u32, 2, 3, 4])
+            .into_device(device.clone())
+            .unwrap();
+        let mut y = Buffer::<u32>::zeros(device.clone(), 2).unwrap();
+        device.take_mock_ops();
+        y.copy_from_slice(&x.slice(1..3).unwrap()).unwrap();
+        assert_eq!(device.take_mock_ops().unwrap(), [MockOp::Copy { bytes: 8 }]);
+        assert_eq!(y.into_vec().unwrap(), [2, 3]);
+    }
+    #[test]
+    fn mock_copy_other_device() {
+        let x = Buffer::from(vec![1u32, 2])
+            .into_device(Device::mock())
+            .unwrap();
+        let mut y = Buffer::<u32>::zeros(Device::mock(), 2).unwrap();
+        let error = y.copy_from_slice(&x.as_slice()).unwrap_err().to_string();
+        assert!(error.contains("use `.to_device()`"), "{error}");
+        assert_eq!(y.into_vec().unwrap(), [0, 0]);
+    }
+    #[test]
+    fn mock_debug() {
+        let device = Device::mock();
+        let x = Buffer::from((0..10).collect::<Vec<u32>>())
+            .into_device(device.clone())
+            .unwrap();
+        device.take_mock_ops();
+        assert!(!format!("{x:?}").contains("data"));
+        assert!(device.take_mock_ops().unwrap().is_empty());
+        let debug = format!("{:?}", x.to_debug().unwrap());
+        assert!(debug.ends_with("data: [0, 1, 2, ..., 7, 8, 9] }"));
+        assert_eq!(
+            device.take_mock_ops().unwrap(),
+            [
+                MockOp::Download { bytes: 12 },
+                MockOp::Download { bytes: 12 }
+            ]
+        );
+        let y = x.slice(..2).unwrap();
+        assert!(format!("{:?}", y.to_debug().unwrap()).ends_with("data: [0, 1] }"));
+    }
+    #[test]
+    fn mock_offset_alignment() {
+        let device = Device::mock();
+        let x = Buffer::<f32>::zeros(device, 100).unwrap();
+        assert_eq!((x.offset(), x.alignment()), (0, 256));
+        let y = x.slice(4..).unwrap();
+        assert_eq!((y.offset(), y.alignment()), (4, 16));
+        let z = y.slice(1..).unwrap();
+        assert_eq!((z.offset(), z.alignment()), (5, 4));
+        let w = x.slice(64..).unwrap();
+        assert_eq!((w.offset(), w.alignment()), (64, 256));
+    }
+    #[test]
+    fn mock_copy_from_host_slice() {
+        let device = Device::mock();
+        let mut y = Buffer::<u32>::zeros(device.clone(), 4).unwrap();
+        device.take_mock_ops();
+        y.slice_mut(2..)
+            .unwrap()
+            .copy_from_host_slice(&[1, 2])
+            .unwrap();
+        assert_eq!(
+            device.take_mock_ops().unwrap(),
+            [MockOp::Upload { bytes: 8 }]
+        );
+        assert_eq!(y.into_vec().unwrap(), [0, 0, 1, 2]);
+    }
+    #[test]
+    fn mock_pinned() {
+        let device = Device::mock();
+        let x = Buffer::from(vec![1u32, 2, 3, 4]);
+        assert!(!x.is_pinned());
+        let y = x.to_pinned(device.clone()).unwrap();
+        assert!(y.is_pinned());
+        assert!(y.slice(1..).unwrap().is_pinned());
+        let stats = device.memory_stats().unwrap();
+        let y = y.into_pinned(device.clone()).unwrap();
+        assert_eq!(device.memory_stats().unwrap(), stats);
+        let z = y.to_device(device.clone()).unwrap();
+        assert!(!z.is_pinned());
+        assert_eq!(
+            z.into_pinned(device).unwrap().into_vec().unwrap(),
+            [1, 2, 3, 4]
+        );
+    }
+    #[test]
+    fn mock_mapped() {
+        let mut x = Buffer::from(vec![1u32, 2, 3]);
+        x.as_mapped_slice_mut().unwrap().unwrap()[0] = 0;
+        assert_eq!(x.as_mapped_slice().unwrap().unwrap(), [0, 2, 3]);
+        let device = Device::mock();
+        let mut y = x.to_device(device).unwrap();
+        assert!(y.as_mapped_slice().unwrap().is_none());
+        assert!(y.as_mapped_slice_mut().unwrap().is_none());
+        assert!(!Device::builder().options.host_mapped);
+        assert!(Device::builder().host_mapped(true).options.host_mapped);
+    }
+    #[test]
+    fn mock_transfer_async() {
+        use crate::device::block_on;
+
+        let device = Device::mock();
+        let x = Buffer::from(vec![1u32, 2, 3, 4]);
+        let y = block_on(x.into_device_async(device.clone())).unwrap();
+        assert_eq!(y.device(), device);
+        assert!(!y.is_pinned());
+        let z = y.slice(1..).unwrap().to_vec_async();
+        let y = block_on(y.into_device_async(Device::host())).unwrap();
+        assert_eq!(y.as_host_slice().unwrap(), [1, 2, 3, 4]);
+        assert_eq!(block_on(z).unwrap(), [2, 3, 4]);
+        assert_eq!(block_on(y.to_vec_async()).unwrap(), [1, 2, 3, 4]);
+    }
+    #[test]
+    fn device_env() {
+        let options = Device::builder().with_device_env("1").options;
+        assert_eq!(options.index, 1);
+        assert!(options.name_contains.is_none());
+        assert!(!options.env);
+        let options = Device::builder().with_device_env(" NVIDIA ").options;
+        assert_eq!(options.index, 0);
+        assert_eq!(options.name_contains.as_deref(), Some("NVIDIA"));
+        let options = Device::builder().with_device_env("").options;
+        assert_eq!(options.index, 0);
+        assert!(options.name_contains.is_none());
+        assert!(!options.env);
+        assert!(!Device::builder().index(0).options.env);
+    }
+    #[test]
+    fn mock_subgroup_threads() {
+        let device = Device::mock();
+        let info = device.info().unwrap();
+        assert_eq!(info.subgroup_threads(), 32);
+        assert!(info.min_subgroup_threads() <= info.subgroup_threads());
+        assert!(info.subgroup_threads() <= info.max_subgroup_threads());
+    }
+    #[test]
+    fn mock_transfer() {
+        let device = Device::mock();
+        let device2 = Device::mock();
+        assert_ne!(device, device2);
+        let x = Buffer::from(vec![1u8, 2, 3]).into_device(device).unwrap();
+        let y = x.to_device(device2.clone()).unwrap();
+        assert_eq!(y.into_vec().unwrap(), [1, 2, 3]);
+        assert!(device2
+            .take_mock_ops()
+            .unwrap()
+            .contains(&MockOp::Alloc { bytes: 3 }));
+    }
+
+    #[test]
+    fn mock_float16_variant() {
+        use crate::{
+            device::{Features, RawDevice},
+            kernel::__private::{find_kernel, KernelBuilder, KernelDesc, KernelDescArgs},
+        };
+        use flate2::{write::GzEncoder, Compression};
+        use rspirv::{
+            binary::Assemble,
+            spirv::{AddressingModel, Capability, MemoryModel},
+        };
+        use std::io::Write;
+
+        // krnlc also compiles kernels using shader_float16 without it.
+        let variant = |float16: bool| {
+            let mut builder = rspirv::dr::Builder::new();
+            builder.capability(Capability::Shader);
+            if float16 {
+                builder.capability(Capability::Float16);
+            }
+            builder.memory_model(AddressingModel::Logical, MemoryModel::GLSL450);
+            let spirv = builder.module().assemble();
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(bytemuck::cast_slice(&spirv)).unwrap();
+            KernelDesc::from_args(KernelDescArgs {
+                name: "crate::kernels::float16",
+                spirv: encoder.finish().unwrap().leak(),
+                features: Features::empty()
+                    .with_shader_int16(true)
+                    .with_shader_float16(float16),
+                safe: true,
+                spec_descs: &[],
+                slice_descs: &[],
+                push_descs: &[],
+            })
+        };
+        let kernels = vec![variant(true), variant(false)].leak();
+        let builder =
+            KernelBuilder::from_desc(find_kernel("crate::kernels::float16", kernels).unwrap())
+                .unwrap();
+        let mut options = Device::builder().options;
+        options.optimal_features = options.optimal_features.with_shader_float16(false);
+        let device = Device {
+            inner: DeviceInner::Device(RawDevice::mock(options)),
+        };
+        let kernel = builder.build(device.clone()).unwrap();
+        assert!(!kernel.metadata().features().shader_float16());
+        unsafe {
+            kernel
+                .with_global_threads(1)
+                .dispatch(&[], &[], &[])
+                .unwrap();
+        }
+        assert!(matches!(
+            device.take_mock_ops().unwrap().as_slice(),
+            [MockOp::Dispatch { kernel, .. }] if kernel.starts_with("crate::kernels::float16")
+        ));
+        let kernel = builder.build(Device::mock()).unwrap();
+        assert!(kernel.metadata().features().shader_float16());
+    }
+}
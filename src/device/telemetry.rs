@@ -0,0 +1,112 @@
+use std::path::Path;
+
+/** Power draw, temperature, and clock of a device.
+
+See [`Device::telemetry()`](super::Device::telemetry).
+
+Readings are provided by the platform, currently the hwmon sysfs interface on Linux, which is
+implemented by drivers like amdgpu. Readings that aren't available are None.
+
+With [`time_dispatch`](crate::bench::time_dispatch), the energy of a kernel can be estimated:
+```no_run
+# use krnl::{anyhow::Result, device::Device};
+# fn main() -> Result<()> {
+# let device = Device::builder().build()?;
+# let duration = std::time::Duration::from_millis(1);
+if let Some(power) = device.telemetry().and_then(|x| x.power()) {
+    let joules = power * duration.as_secs_f32();
+    println!("{joules} J / iteration");
+}
+# Ok(())
+# }
+```
+*/
+#[derive(Clone, Default, Debug, PartialEq)]
+pub struct Telemetry {
+    power: Option<f32>,
+    temperature: Option<f32>,
+    clock: Option<u32>,
+}
+
+impl Telemetry {
+    /// Power draw in watts.
+    pub fn power(&self) -> Option<f32> {
+        self.power
+    }
+    /// Temperature in degrees celsius.
+    pub fn temperature(&self) -> Option<f32> {
+        self.temperature
+    }
+    /// Shader clock in MHz.
+    pub fn clock(&self) -> Option<u32> {
+        self.clock
+    }
+    pub(super) fn from_pci_address(pci_address: &str) -> Self {
+        if !cfg!(target_os = "linux") {
+            return Self::default();
+        }
+        let hwmon = Path::new("/sys/bus/pci/devices")
+            .join(pci_address)
+            .join("hwmon");
+        let Some(dir) = std::fs::read_dir(hwmon)
+            .ok()
+            .and_then(|mut dirs| dirs.next())
+            .and_then(Result::ok)
+        else {
+            return Self::default();
+        };
+        Self::from_hwmon(&dir.path())
+    }
+    fn from_hwmon(dir: &Path) -> Self {
+        let read = |name: &str| -> Option<u64> {
+            std::fs::read_to_string(dir.join(name))
+                .ok()?
+                .trim()
+                .parse()
+                .ok()
+        };
+        // microwatts, millidegrees, and hertz
+        let power = read("power1_average").or_else(|| read("power1_input"));
+        Self {
+            power: power.map(|x| x as f32 / 1e6),
+            temperature: read("temp1_input").map(|x| x as f32 / 1e3),
+            clock: read("freq1_input").map(|x| (x / 1_000_000) as u32),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn telemetry_hwmon() {
+        let dir = std::env::temp_dir().join(format!("krnl-hwmon-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("power1_average"), "35000000\n").unwrap();
+        std::fs::write(dir.join("temp1_input"), "54000\n").unwrap();
+        let telemetry = Telemetry::from_hwmon(&dir);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert_eq!(
+            telemetry,
+            Telemetry {
+                power: Some(35.),
+                temperature: Some(54.),
+                clock: None,
+            }
+        );
+        assert_eq!(
+            Telemetry::from_pci_address("ffff:ff:ff.f"),
+            Telemetry::default()
+        );
+    }
+
+    #[cfg(feature = "device")]
+    #[test]
+    fn telemetry_mock() {
+        use super::super::Device;
+
+        assert!(Device::host().telemetry().is_none());
+        assert_eq!(Device::mock().telemetry(), Some(Telemetry::default()));
+    }
+}
@@ -1,28 +1,37 @@
 use super::{
     error::{DeviceIndexOutOfRange, DeviceUnavailable, OutOfDeviceMemory},
-    DeviceEngine, DeviceEngineBuffer, DeviceEngineKernel, DeviceId, DeviceInfo, DeviceLost,
-    DeviceOptions, Features, KernelDesc, KernelKey,
+    DebugMessage, DebugMessageSeverity, DeviceEngine, DeviceEngineBuffer, DeviceEngineKernel,
+    DeviceId, DeviceInfo, DeviceLost, DeviceOptions, DeviceType, Features, KernelDesc, KernelKey,
+    MemoryBudget, MemoryHeap, MessageCallback, PerformanceMetrics, Priority, QueueFamily,
+    RawWaitFuture,
 };
+#[cfg(feature = "vulkan-interop")]
+use super::{VulkanBuffer, VulkanHandles};
 
-use anyhow::{Error, Result};
+use anyhow::{bail, Error, Result};
 use ash::vk::Handle;
 use crossbeam_channel::{Receiver, Sender};
 use dashmap::DashMap;
-use parking_lot::Mutex;
+use parking_lot::{Mutex, MutexGuard, RwLock};
 use std::{
+    future::Future,
     mem::MaybeUninit,
     ops::Range,
+    pin::Pin,
+    ptr::NonNull,
     sync::{
-        atomic::{AtomicBool, AtomicU64, Ordering},
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
         Arc,
     },
+    task::{Context, Poll, Waker},
+    time::Duration,
 };
 use vulkano::{
-    buffer::{Buffer, BufferCreateInfo, BufferUsage, Subbuffer},
+    buffer::{Buffer, BufferCreateInfo, BufferMemory, BufferUsage, Subbuffer},
     command_buffer::{
         pool::{CommandBufferAllocateInfo, CommandPool, CommandPoolAlloc, CommandPoolCreateInfo},
         sys::{CommandBufferBeginInfo, UnsafeCommandBuffer, UnsafeCommandBufferBuilder},
-        CommandBufferLevel, CommandBufferUsage, CopyBufferInfo,
+        CommandBufferLevel, CommandBufferUsage, CopyBufferInfo, DispatchIndirectCommand,
     },
     descriptor_set::{
         layout::{DescriptorSetLayout, DescriptorType},
@@ -30,46 +39,191 @@ use vulkano::{
         WriteDescriptorSet,
     },
     device::{
+        physical::{DriverId, PhysicalDevice, PhysicalDeviceType, SubgroupFeatures},
         Device, DeviceCreateInfo, DeviceOwned, Queue, QueueCreateInfo, QueueFlags, QueueGuard,
     },
     instance::{
         debug::{
             DebugUtilsMessageSeverity, DebugUtilsMessageType, DebugUtilsMessenger,
-            DebugUtilsMessengerCreateInfo,
+            DebugUtilsMessengerCreateInfo, Message, ValidationFeatureEnable,
         },
         Instance, InstanceCreateInfo, InstanceExtensions, Version,
     },
     library::VulkanLibrary,
-    memory::allocator::{
-        AllocationCreateInfo, GenericMemoryAllocatorCreateInfo, MemoryUsage,
-        StandardMemoryAllocator,
+    memory::{
+        allocator::{
+            AllocationCreateInfo, GenericMemoryAllocatorCreateInfo, MemoryUsage,
+            StandardMemoryAllocator,
+        },
+        MemoryHeapFlags, MemoryPropertyFlags,
     },
     pipeline::{ComputePipeline, Pipeline, PipelineBindPoint},
+    query::{QueryPool, QueryPoolCreateInfo, QueryResultFlags, QueryType},
     shader::{
         DescriptorBindingRequirements, DescriptorRequirements, ShaderExecution, ShaderInterface,
         ShaderModule, ShaderStages,
     },
-    sync::semaphore::Semaphore,
-    VulkanObject,
+    sync::{semaphore::Semaphore, PipelineStage},
+    DeviceSize, VulkanObject,
 };
 
 pub struct Engine {
     info: Arc<DeviceInfo>,
+    options: DeviceOptions,
+    lane: Lane,
+    // Lanes for streams 1.., if the device has multiple compute queues.
+    streams: Vec<Lane>,
+    // Open batch scopes of all threads, shared by the workers of each stream, see `Device::begin_batch`.
+    batches: Arc<AtomicUsize>,
+    host_buffer_sender: Sender<HostBuffer>,
+    host_buffer_receiver: Receiver<HostBuffer>,
+    kernels: DashMap<KernelKey, KernelInner>,
+    // Replaced by `Device::trim`, allocations keep the blocks of the previous allocator alive.
+    memory_allocator: RwLock<Arc<StandardMemoryAllocator>>,
+    block_sizes: Vec<(DeviceSize, DeviceSize)>,
+    // Memory types that are host-visible and host-coherent, as a mask.
+    coherent_memory_types: u32,
+    // Memory types that are also device-local, if `DeviceBuilder::host_mapped`.
+    mapped_memory_types: u32,
+    #[cfg(feature = "spirv-val")]
+    validate_spirv: bool,
+    // Messages collected for `Device::take_debug_messages`, if `DeviceBuilder::capture_messages`.
+    messages: Option<Arc<Mutex<Vec<DebugMessage>>>>,
+    // Forwards messages to the callback, see `DeviceBuilder::message_callback`.
+    _messenger: Option<DebugUtilsMessenger>,
+    _instance: Arc<Instance>,
+}
+
+impl Engine {
+    const MAX_STREAMS: u32 = 4;
+    unsafe fn transfer(
+        &self,
+        src: Subbuffer<[u8]>,
+        dst: Subbuffer<[u8]>,
+        host_buffer: &mut HostBuffer,
+        dst_device_buffer: Option<&DeviceBuffer>,
+    ) -> Result<()> {
+        unsafe { self.lane.transfer(src, dst, host_buffer, dst_device_buffer) }
+    }
+    unsafe fn copy(&self, src: &DeviceBuffer, dst: &DeviceBuffer) -> Result<()> {
+        unsafe { self.lane.copy(src, dst) }
+    }
+    unsafe fn fill(
+        &self,
+        dst: Subbuffer<[u32]>,
+        data: u32,
+        dst_device_buffer: &DeviceBuffer,
+    ) -> Result<()> {
+        unsafe { self.lane.fill(dst, data, dst_device_buffer) }
+    }
+    fn wait_pending(&self, epoch: u64) -> Result<(), DeviceLost> {
+        self.lane.wait_pending(epoch)
+    }
+    fn wait_epoch(&self, epoch: u64) -> Result<(), DeviceLost> {
+        self.lane.wait_epoch(epoch)
+    }
+    /// The lane of `stream`.
+    fn stream_lane(&self, stream: usize) -> &Lane {
+        if stream == 0 {
+            &self.lane
+        } else {
+            &self.streams[stream - 1]
+        }
+    }
+    #[cfg(feature = "vulkan-interop")]
+    pub(super) fn vulkan_handles(&self) -> VulkanHandles {
+        let queue = &self.lane.queue;
+        let device = queue.device();
+        VulkanHandles {
+            instance: device.instance().handle(),
+            physical_device: device.physical_device().handle(),
+            device: device.handle(),
+            queue_family_index: queue.queue_family_index(),
+            queues: std::iter::once(queue)
+                .chain(self.streams.iter().map(|lane| &lane.queue))
+                .map(|queue| queue.handle())
+                .collect(),
+        }
+    }
+}
+
+/// A queue and the worker submitting to it.
+///
+/// Each [stream](super::Stream) is a lane. Dispatches with [`Priority::High`] are submitted to
+/// stream 1, when the device supports multiple compute queues. Transfers are always submitted to
+/// stream 0.
+struct Lane {
+    id: DeviceId,
+    stream: usize,
     semaphore: Arc<Semaphore>,
     epoch: AtomicU64,
     pending: Arc<AtomicU64>,
+    finished: Arc<AtomicU64>,
+    wakers: Arc<Mutex<Vec<(u64, Waker)>>>,
+    // Threads and futures waiting on the lane, which submit held batches.
+    waiting: Arc<AtomicUsize>,
+    gpu_time: Option<Arc<AtomicU64>>,
     frame_outer: Mutex<FrameOuter>,
-    host_buffer_sender: Sender<HostBuffer>,
-    host_buffer_receiver: Receiver<HostBuffer>,
-    kernels: DashMap<KernelKey, KernelInner>,
-    memory_allocator: Arc<StandardMemoryAllocator>,
     queue: Arc<Queue>,
+    // The worker is spawned on first use.
+    worker: Mutex<Option<Worker>>,
     engine_exited: Arc<AtomicBool>,
     worker_exited: Arc<AtomicBool>,
-    _instance: Arc<Instance>,
 }
 
-impl Engine {
+impl Lane {
+    fn new(
+        queue: Arc<Queue>,
+        index: usize,
+        stream: usize,
+        metrics: Option<Arc<Mutex<PerformanceMetrics>>>,
+        batches: Arc<AtomicUsize>,
+        message_callback: Option<MessageCallback>,
+    ) -> Result<Self> {
+        let worker = Worker::new(
+            queue.clone(),
+            index,
+            stream,
+            metrics,
+            batches,
+            message_callback,
+        )?;
+        let id = worker.id();
+        let semaphore = worker.semaphore.clone();
+        let epoch = AtomicU64::default();
+        let pending = worker.pending.clone();
+        let finished = worker.finished.clone();
+        let wakers = worker.wakers.clone();
+        let waiting = worker.waiting.clone();
+        let gpu_time = worker.gpu_time.clone();
+        let frame_outer = Mutex::new(FrameOuter::new(
+            worker.ready_frame.clone(),
+            worker.empty.clone(),
+        ));
+        let engine_exited = worker.engine_exited.clone();
+        let worker_exited = worker.worker_exited.clone();
+        Ok(Self {
+            id,
+            stream,
+            semaphore,
+            epoch,
+            pending,
+            finished,
+            wakers,
+            waiting,
+            gpu_time,
+            frame_outer,
+            queue,
+            worker: Mutex::new(Some(worker)),
+            engine_exited,
+            worker_exited,
+        })
+    }
+    fn spawn_worker(&self) {
+        if let Some(mut worker) = self.worker.lock().take() {
+            std::thread::spawn(move || worker.run());
+        }
+    }
     unsafe fn transfer(
         &self,
         src: Subbuffer<[u8]>,
@@ -77,29 +231,50 @@ impl Engine {
         host_buffer: &mut HostBuffer,
         dst_device_buffer: Option<&DeviceBuffer>,
     ) -> Result<()> {
+        debug_assert_eq!(self.stream, 0);
+        self.spawn_worker();
         let mut frame_outer = self.frame_outer.lock();
         unsafe { frame_outer.transfer(&self.epoch, src, dst, host_buffer, dst_device_buffer) }
     }
+    unsafe fn copy(&self, src: &DeviceBuffer, dst: &DeviceBuffer) -> Result<()> {
+        debug_assert_eq!(self.stream, 0);
+        self.spawn_worker();
+        let mut frame_outer = self.frame_outer.lock();
+        unsafe { frame_outer.copy(&self.epoch, src, dst) }
+    }
+    unsafe fn fill(
+        &self,
+        dst: Subbuffer<[u32]>,
+        data: u32,
+        dst_device_buffer: &DeviceBuffer,
+    ) -> Result<()> {
+        debug_assert_eq!(self.stream, 0);
+        self.spawn_worker();
+        let mut frame_outer = self.frame_outer.lock();
+        unsafe { frame_outer.fill(&self.epoch, dst, data, dst_device_buffer) }
+    }
     unsafe fn compute(
         &self,
         kernel_desc: &Arc<KernelDesc>,
         pipeline: &Arc<ComputePipeline>,
-        groups: u32,
+        groups: Groups<'_>,
         buffers: &[Arc<DeviceBuffer>],
         push_consts: &[u8],
         debug_printf_panic: Option<Arc<AtomicBool>>,
     ) -> Result<()> {
+        self.spawn_worker();
         let mut frame_outer = self.frame_outer.lock();
-        let new_descriptors: u32 = buffers.len().try_into().unwrap();
+        let new_descriptors = Frame::descriptors(pipeline, buffers);
         if frame_outer.kernels >= Frame::MAX_KERNELS
             || frame_outer.descriptors + new_descriptors > Frame::MAX_DESCRIPTORS
         {
+            let _waiting = Waiting::new(&self.waiting);
             loop {
                 if frame_outer.empty.load(Ordering::SeqCst) {
                     break;
                 }
                 if self.worker_exited.load(Ordering::SeqCst) {
-                    return Err(DeviceLost(self.id()).into());
+                    return Err(DeviceLost(self.id).into());
                 }
                 std::hint::spin_loop();
             }
@@ -117,32 +292,114 @@ impl Engine {
         }
     }
     fn wait_pending(&self, epoch: u64) -> Result<(), DeviceLost> {
+        if self.pending.load(Ordering::SeqCst) >= epoch {
+            return Ok(());
+        }
+        let _waiting = Waiting::new(&self.waiting);
         while self.pending.load(Ordering::SeqCst) < epoch {
             if self.worker_exited.load(Ordering::SeqCst) {
-                return Err(DeviceLost(self.id()));
+                return Err(DeviceLost(self.id));
             }
             std::hint::spin_loop();
         }
         Ok(())
     }
     fn wait_epoch(&self, epoch: u64) -> Result<(), DeviceLost> {
+        let mut waiting = None;
         loop {
             let result = unsafe { wait_semaphore(self.queue.device(), &self.semaphore, epoch) };
             match result {
                 ash::vk::Result::SUCCESS => return Ok(()),
-                ash::vk::Result::TIMEOUT => (),
-                _ => return Err(DeviceLost(self.id())),
+                ash::vk::Result::TIMEOUT => {
+                    waiting.get_or_insert_with(|| Waiting::new(&self.waiting));
+                }
+                _ => return Err(DeviceLost(self.id)),
             }
             if self.worker_exited.load(Ordering::SeqCst) {
-                return Err(DeviceLost(self.id()));
+                return Err(DeviceLost(self.id));
             }
             std::hint::spin_loop();
         }
     }
+    fn wait(&self) -> Result<(), DeviceLost> {
+        let epoch = self.epoch.load(Ordering::SeqCst);
+        self.wait_epoch(epoch)
+    }
+    /// Waits for the worker to submit the recorded frame.
+    fn flush(&self) -> Result<(), DeviceLost> {
+        let epoch = self.epoch.load(Ordering::SeqCst);
+        self.wait_pending(epoch)
+    }
+    fn wait_async(&self) -> LaneWait {
+        LaneWait {
+            id: self.id,
+            epoch: self.epoch.load(Ordering::SeqCst),
+            finished: self.finished.clone(),
+            wakers: self.wakers.clone(),
+            worker_exited: self.worker_exited.clone(),
+            _waiting: Waiting::new(&self.waiting),
+        }
+    }
+}
+
+/// Marks a thread or future as waiting on a lane, until dropped.
+///
+/// The worker holds batches within [`Device::begin_batch`](super::Device::begin_batch) scopes,
+/// unless waited on.
+struct Waiting(Arc<AtomicUsize>);
+
+impl Waiting {
+    fn new(waiting: &Arc<AtomicUsize>) -> Self {
+        waiting.fetch_add(1, Ordering::SeqCst);
+        Self(waiting.clone())
+    }
 }
 
-impl Drop for Engine {
+impl Drop for Waiting {
     fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// Resolves when the worker has finished `epoch`.
+struct LaneWait {
+    id: DeviceId,
+    epoch: u64,
+    finished: Arc<AtomicU64>,
+    wakers: Arc<Mutex<Vec<(u64, Waker)>>>,
+    worker_exited: Arc<AtomicBool>,
+    _waiting: Waiting,
+}
+
+impl Future for LaneWait {
+    type Output = Result<(), DeviceLost>;
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let ready = || {
+            if self.finished.load(Ordering::SeqCst) >= self.epoch {
+                Some(Ok(()))
+            } else if self.worker_exited.load(Ordering::SeqCst) {
+                Some(Err(DeviceLost(self.id)))
+            } else {
+                None
+            }
+        };
+        if let Some(output) = ready() {
+            return Poll::Ready(output);
+        }
+        let mut wakers = self.wakers.lock();
+        // The worker stores finished before waking, so check again while locked.
+        if let Some(output) = ready() {
+            return Poll::Ready(output);
+        }
+        wakers.push((self.epoch, cx.waker().clone()));
+        Poll::Pending
+    }
+}
+
+impl Drop for Lane {
+    fn drop(&mut self) {
+        // Dropping an unspawned worker marks it as exited.
+        self.worker.get_mut().take();
         self.engine_exited.store(true, Ordering::SeqCst);
         while !self.worker_exited.load(Ordering::SeqCst) {}
         let result = self.queue.with(|mut x| x.wait_idle());
@@ -156,11 +413,77 @@ impl DeviceEngine for Engine {
     type DeviceBuffer = DeviceBuffer;
     type Kernel = Kernel;
     fn new(options: DeviceOptions) -> anyhow::Result<std::sync::Arc<Self>> {
+        let panic_dump = options.panic_dump();
+        let recorder = options.recorder()?;
+        let metrics = options.metrics();
+        let recreate_options = DeviceOptions {
+            recorder: recorder.clone(),
+            ..options.clone()
+        };
         let DeviceOptions {
             index,
+            name_contains,
+            vendor,
+            device_type,
+            uuid,
+            allow_software,
+            prefer_software,
+            max_allocation_size,
+            memory_block_size,
             optimal_features,
+            required_features,
+            #[cfg(feature = "spirv-val")]
+            validate_spirv,
+            verify_transfers,
+            debug_printf: debug_printf_option,
+            instance: instance_config,
+            message_callback,
+            capture_messages,
+            ..
         } = options;
         let library = VulkanLibrary::new().map_err(|e| Error::new(DeviceUnavailable).context(e))?;
+        const VALIDATION_LAYER: &str = "VK_LAYER_KHRONOS_validation";
+        let mut enabled_layers = instance_config.layers().to_vec();
+        if (instance_config.validation() || debug_printf_option == Some(true))
+            && !enabled_layers.iter().any(|layer| layer == VALIDATION_LAYER)
+        {
+            enabled_layers.push(VALIDATION_LAYER.to_string());
+        }
+        {
+            let available: Vec<_> = library
+                .layer_properties()?
+                .map(|layer| layer.name().to_string())
+                .collect();
+            for layer in enabled_layers.iter() {
+                if !available.contains(layer) {
+                    if layer == VALIDATION_LAYER {
+                        bail!("{VALIDATION_LAYER} not found, install the Vulkan SDK!");
+                    }
+                    bail!("Layer {layer:?} not found!");
+                }
+            }
+        }
+        let required_extensions = {
+            let supported = library
+                .supported_extensions_with_layers(enabled_layers.iter().map(String::as_str))?;
+            let mut required = InstanceExtensions::empty();
+            for name in instance_config.extensions() {
+                let extension = InstanceExtensions::from_iter([name.as_str()]);
+                if extension == InstanceExtensions::empty() {
+                    bail!("Unknown instance extension {name:?}!");
+                }
+                if !supported.contains(&extension) {
+                    bail!("Instance extension {name:?} is not supported!");
+                }
+                required = required.union(&extension);
+            }
+            required
+        };
+        let enabled_validation_features = if debug_printf_option == Some(true) {
+            vec![ValidationFeatureEnable::DebugPrintf]
+        } else {
+            Vec::new()
+        };
         let debug_printf = Arc::new(AtomicBool::default());
         let debug_printf2 = debug_printf.clone();
         let debug_create_info = DebugUtilsMessengerCreateInfo {
@@ -181,13 +504,27 @@ impl DeviceEngine for Engine {
                 }
             }))
         };
+        let default_create_info = InstanceCreateInfo::application_from_cargo_toml();
         let instance_create_info = InstanceCreateInfo {
+            application_name: instance_config
+                .application_name()
+                .map(Into::into)
+                .or(default_create_info.application_name.clone()),
             enabled_extensions: InstanceExtensions {
                 ext_debug_utils: true,
-                ..Default::default()
+                ext_validation_features: !enabled_validation_features.is_empty(),
+                ..required_extensions
             },
+            enabled_layers,
+            enabled_validation_features,
             enumerate_portability: true,
-            ..InstanceCreateInfo::application_from_cargo_toml()
+            engine_name: Some(instance_config.engine_name().to_string()),
+            engine_version: Version {
+                major: env!("CARGO_PKG_VERSION_MAJOR").parse().unwrap(),
+                minor: env!("CARGO_PKG_VERSION_MINOR").parse().unwrap(),
+                patch: env!("CARGO_PKG_VERSION_PATCH").parse().unwrap(),
+            },
+            ..default_create_info
         };
         let instance = unsafe {
             Instance::with_debug_utils_messengers(
@@ -196,17 +533,93 @@ impl DeviceEngine for Engine {
                 [debug_create_info],
             )?
         };
-        let debug_printf = debug_printf.load(Ordering::SeqCst);
-        let mut physical_devices = instance.enumerate_physical_devices()?;
+        let debug_printf = debug_printf_option.unwrap_or(debug_printf.load(Ordering::SeqCst));
+        let messages = capture_messages.then(Arc::<Mutex<Vec<DebugMessage>>>::default);
+        let message_callback: Option<MessageCallback> = match (message_callback, messages.clone()) {
+            (None, None) => None,
+            (callback, messages) => Some(Arc::new(move |msg: &DebugMessage| {
+                if let Some(callback) = callback.as_ref() {
+                    callback(msg);
+                }
+                if let Some(messages) = messages.as_ref() {
+                    messages.lock().push(msg.clone());
+                }
+            })),
+        };
+        let messenger = message_callback
+            .clone()
+            .map(|callback| unsafe {
+                // Panics in the callback are caught by vulkano.
+                let callback = std::panic::AssertUnwindSafe(callback);
+                DebugUtilsMessenger::new(
+                    instance.clone(),
+                    DebugUtilsMessengerCreateInfo {
+                        message_severity: DebugUtilsMessageSeverity::ERROR
+                            | DebugUtilsMessageSeverity::WARNING
+                            | DebugUtilsMessageSeverity::INFO
+                            | DebugUtilsMessageSeverity::VERBOSE,
+                        message_type: DebugUtilsMessageType::GENERAL
+                            | DebugUtilsMessageType::VALIDATION
+                            | DebugUtilsMessageType::PERFORMANCE,
+                        ..DebugUtilsMessengerCreateInfo::user_callback(Arc::new(move |msg| {
+                            let msg = debug_message(msg);
+                            // Forwarded by the worker with the kernel.
+                            if debug_printf && msg.is_debug_printf() {
+                                return;
+                            }
+                            (*callback)(&msg);
+                        }))
+                    },
+                )
+            })
+            .transpose()?;
+        let name_contains = name_contains.map(|x| x.to_lowercase());
+        let mut physical_devices: Vec<_> = instance
+            .enumerate_physical_devices()?
+            .filter(|physical_device| {
+                let properties = physical_device.properties();
+                if let Some(name) = name_contains.as_ref() {
+                    if !properties.device_name.to_lowercase().contains(name) {
+                        return false;
+                    }
+                }
+                if let Some(vendor) = vendor {
+                    if properties.vendor_id != vendor.id() {
+                        return false;
+                    }
+                }
+                if let Some(uuid) = uuid {
+                    if properties.device_uuid != Some(uuid) {
+                        return false;
+                    }
+                }
+                if !allow_software && is_software(physical_device) {
+                    return false;
+                }
+                if let Some(device_type) = device_type {
+                    if get_device_type(properties.device_type) != device_type {
+                        return false;
+                    }
+                }
+                true
+            })
+            .collect();
+        if prefer_software {
+            physical_devices.sort_by_key(|physical_device| !is_software(physical_device));
+        }
         let devices = physical_devices.len();
-        let physical_device = if let Some(physical_device) = physical_devices.nth(index) {
-            physical_device
+        let physical_device = if index < devices {
+            physical_devices.swap_remove(index)
         } else {
             return Err(DeviceIndexOutOfRange { index, devices }.into());
         };
         let name = physical_device.properties().device_name.clone();
         let optimal_device_extensions = vulkano::device::DeviceExtensions {
             khr_vulkan_memory_model: true,
+            khr_push_descriptor: true,
+            ext_memory_budget: true,
+            ext_subgroup_size_control: optimal_features.subgroup_size_control,
+            ext_shader_atomic_float: optimal_features.shader_atomic_float,
             ..vulkano::device::DeviceExtensions::empty()
         };
         let device_extensions = physical_device
@@ -220,18 +633,53 @@ impl DeviceEngine for Engine {
             shader_int64: optimal_features.shader_int64,
             shader_float16: optimal_features.shader_float16,
             shader_float64: optimal_features.shader_float64,
+            subgroup_size_control: optimal_features.subgroup_size_control,
+            shader_buffer_float32_atomics: optimal_features.shader_atomic_float,
+            shader_buffer_float32_atomic_add: optimal_features.shader_atomic_float,
             ..vulkano::device::Features::empty()
         };
         let device_features = physical_device
             .supported_features()
             .intersection(&optimal_device_features);
+        let subgroup_operations = {
+            let properties = physical_device.properties();
+            let compute = properties
+                .subgroup_supported_stages
+                .is_some_and(|stages| stages.contains(ShaderStages::COMPUTE));
+            if compute {
+                properties.subgroup_supported_operations.unwrap_or_default()
+            } else {
+                SubgroupFeatures::empty()
+            }
+        };
         let features = Features {
             shader_int8: device_features.shader_int8,
             shader_int16: device_features.shader_int16,
             shader_int64: device_features.shader_int64,
             shader_float16: device_features.shader_float16,
             shader_float64: device_features.shader_float64,
+            subgroup_basic: optimal_features.subgroup_basic
+                && subgroup_operations.contains(SubgroupFeatures::BASIC),
+            subgroup_vote: optimal_features.subgroup_vote
+                && subgroup_operations.contains(SubgroupFeatures::VOTE),
+            subgroup_arithmetic: optimal_features.subgroup_arithmetic
+                && subgroup_operations.contains(SubgroupFeatures::ARITHMETIC),
+            subgroup_ballot: optimal_features.subgroup_ballot
+                && subgroup_operations.contains(SubgroupFeatures::BALLOT),
+            subgroup_shuffle: optimal_features.subgroup_shuffle
+                && subgroup_operations.contains(SubgroupFeatures::SHUFFLE),
+            subgroup_size_control: device_features.subgroup_size_control
+                && device_extensions.ext_subgroup_size_control,
+            shader_atomic_float: device_features.shader_buffer_float32_atomic_add
+                && device_extensions.ext_shader_atomic_float,
         };
+        let missing: Vec<_> = required_features.difference(&features).names().collect();
+        if !missing.is_empty() {
+            bail!(
+                "Device {index} {name:?} does not support required features {}!",
+                missing.join(", ")
+            );
+        }
         let compute_family = physical_device
             .queue_family_properties()
             .iter()
@@ -247,9 +695,28 @@ impl DeviceEngine for Engine {
             })
             .map(|x| x as u32)
             .unwrap();
+        // Each queue is a stream. The second queue, if available, is used for high priority
+        // dispatches.
+        let queue_family_properties =
+            &physical_device.queue_family_properties()[compute_family as usize];
+        let queue_family = QueueFamily {
+            index: compute_family,
+            queues: queue_family_properties.queue_count,
+            graphics: queue_family_properties
+                .queue_flags
+                .contains(QueueFlags::GRAPHICS),
+            compute: true,
+            transfer: queue_family_properties
+                .queue_flags
+                .contains(QueueFlags::TRANSFER),
+            timestamp_valid_bits: queue_family_properties.timestamp_valid_bits,
+        };
+        let queue_count = queue_family_properties.queue_count.min(Self::MAX_STREAMS) as usize;
+        let queue_priorities = recreate_options.queue_priorities(queue_count);
+        let compute_queues = queue_priorities.len();
         let queue_create_infos = vec![QueueCreateInfo {
             queue_family_index: compute_family,
-            queues: vec![1f32],
+            queues: queue_priorities,
             ..Default::default()
         }];
         let (device, mut queues) = Device::new(
@@ -262,17 +729,28 @@ impl DeviceEngine for Engine {
             },
         )?;
         let queue = queues.next().unwrap();
-        let memory_allocator = Arc::new(StandardMemoryAllocator::new(
-            device.clone(),
-            GenericMemoryAllocatorCreateInfo {
-                block_sizes: &[
-                    (0, 64_000_000),
-                    (DeviceBuffer::MAX_SIZE as _, DeviceBuffer::MAX_SIZE as _),
-                ],
-                dedicated_allocation: false,
-                ..Default::default()
-            },
-        )?);
+        // Buffers are aligned, so that they do not exceed the limit.
+        let max_allocation_size = aligned_floor(
+            device
+                .physical_device()
+                .properties()
+                .max_memory_allocation_size
+                .map_or(usize::MAX, |x| x.try_into().unwrap_or(usize::MAX))
+                .min(max_allocation_size.unwrap_or(usize::MAX)),
+            DeviceBuffer::ALIGN,
+        )
+        .min(super::DeviceBuffer::MAX_ALLOCATION_SIZE);
+        let max_block_size = aligned_ceil(max_allocation_size, DeviceBuffer::ALIGN) as u64;
+        let block_sizes = if let Some(memory_block_size) = memory_block_size {
+            let block_size = aligned_ceil(memory_block_size, DeviceBuffer::ALIGN) as u64;
+            vec![(0, block_size.min(max_block_size))]
+        } else {
+            vec![
+                (0, max_block_size.min(64_000_000)),
+                (DeviceBuffer::MAX_SIZE as _, max_block_size),
+            ]
+        };
+        let memory_allocator = Arc::new(memory_allocator(device.clone(), &block_sizes)?);
         let (host_buffer_sender, host_buffer_receiver) = crossbeam_channel::bounded(2);
         for _ in 0..2 {
             let buffer_info = BufferCreateInfo {
@@ -299,56 +777,291 @@ impl DeviceEngine for Engine {
         }
         let kernels = DashMap::default();
         let properties = device.physical_device().properties();
+        let pci_address = match (
+            properties.pci_domain,
+            properties.pci_bus,
+            properties.pci_device,
+            properties.pci_function,
+        ) {
+            (Some(domain), Some(bus), Some(device), Some(function)) => {
+                Some(format!("{domain:04x}:{bus:02x}:{device:02x}.{function:x}"))
+            }
+            _ => None,
+        };
+        let memory_properties = device.physical_device().memory_properties();
+        let memory_heaps = memory_properties
+            .memory_heaps
+            .iter()
+            .enumerate()
+            .map(|(heap_index, heap)| MemoryHeap {
+                size: heap.size,
+                device_local: heap.flags.contains(MemoryHeapFlags::DEVICE_LOCAL),
+                host_visible: memory_properties.memory_types.iter().any(|x| {
+                    x.heap_index as usize == heap_index
+                        && x.property_flags.contains(MemoryPropertyFlags::HOST_VISIBLE)
+                }),
+            })
+            .collect();
+        let memory_types = |flags: MemoryPropertyFlags| {
+            memory_properties
+                .memory_types
+                .iter()
+                .enumerate()
+                .filter(|(_, x)| x.property_flags.contains(flags))
+                .fold(0u32, |mask, (i, _)| mask | 1 << i)
+        };
+        let coherent_memory_types =
+            memory_types(MemoryPropertyFlags::HOST_VISIBLE | MemoryPropertyFlags::HOST_COHERENT);
+        let mapped_memory_types = if options.host_mapped {
+            memory_types(
+                MemoryPropertyFlags::DEVICE_LOCAL
+                    | MemoryPropertyFlags::HOST_VISIBLE
+                    | MemoryPropertyFlags::HOST_COHERENT,
+            )
+        } else {
+            0
+        };
         let info = Arc::new(DeviceInfo {
             index,
             name,
             device_id: properties.device_id,
             vendor_id: properties.vendor_id,
+            uuid: properties.device_uuid.unwrap_or_default(),
+            device_type: get_device_type(properties.device_type),
+            driver_version: properties.driver_version,
+            driver_name: properties.driver_name.clone(),
+            driver_info: properties.driver_info.clone(),
+            pci_address,
             max_groups: properties.max_compute_work_group_count[0],
             max_threads: properties.max_compute_work_group_size[0],
+            max_group_size: properties.max_compute_work_group_size,
+            max_group_threads: properties.max_compute_work_group_invocations,
+            max_push_constant_size: properties.max_push_constants_size,
             subgroup_threads: properties.subgroup_size.unwrap(),
+            min_subgroup_threads: properties
+                .min_subgroup_size
+                .or(properties.subgroup_size)
+                .unwrap(),
+            max_subgroup_threads: properties
+                .max_subgroup_size
+                .or(properties.subgroup_size)
+                .unwrap(),
+            compute_queues,
+            queue_family: Some(queue_family),
+            max_allocation_size,
             features,
+            memory_heaps,
             debug_printf,
+            verify_transfers,
+            panic_dump,
+            recorder,
+            metrics: metrics.clone(),
+            memory: Arc::default(),
         });
-        let mut worker = Worker::new(queue.clone(), index)?;
-        let semaphore = worker.semaphore.clone();
-        let epoch = AtomicU64::default();
-        let pending = worker.pending.clone();
-        let frame_outer = Mutex::new(FrameOuter::new(
-            worker.ready_frame.clone(),
-            worker.empty.clone(),
-        ));
-        let engine_exited = worker.engine_exited.clone();
-        let worker_exited = worker.worker_exited.clone();
-        std::thread::spawn(move || worker.run());
+        let batches = Arc::<AtomicUsize>::default();
+        let lane = Lane::new(
+            queue,
+            index,
+            0,
+            metrics.clone(),
+            batches.clone(),
+            message_callback.clone(),
+        )?;
+        lane.spawn_worker();
+        let streams = queues
+            .enumerate()
+            .map(|(i, queue)| {
+                Lane::new(
+                    queue,
+                    index,
+                    i + 1,
+                    metrics.clone(),
+                    batches.clone(),
+                    message_callback.clone(),
+                )
+            })
+            .collect::<Result<_>>()?;
         Ok(Arc::new(Self {
             info,
-            semaphore,
-            epoch,
-            pending,
-            frame_outer,
+            options: recreate_options,
+            lane,
+            streams,
+            batches,
             host_buffer_sender,
             host_buffer_receiver,
             kernels,
-            memory_allocator,
-            engine_exited,
-            worker_exited,
-            queue,
+            memory_allocator: RwLock::new(memory_allocator),
+            block_sizes,
+            coherent_memory_types,
+            mapped_memory_types,
+            #[cfg(feature = "spirv-val")]
+            validate_spirv,
+            messages,
+            _messenger: messenger,
             _instance: instance,
         }))
     }
+    fn recreate(&self) -> Result<Arc<Self>> {
+        Self::new(self.options.clone())
+    }
     fn id(&self) -> DeviceId {
-        let index = self.info.index;
-        let handle = self.queue.device().handle().as_raw().try_into().unwrap();
-        DeviceId { index, handle }
+        self.lane.id
     }
     fn info(&self) -> &Arc<DeviceInfo> {
         &self.info
     }
     fn wait(&self) -> Result<(), DeviceLost> {
-        let epoch = self.epoch.load(Ordering::SeqCst);
-        self.wait_epoch(epoch)
+        self.lane.wait()?;
+        for lane in self.streams.iter() {
+            lane.wait()?;
+        }
+        Ok(())
+    }
+    fn wait_stream(&self, stream: usize) -> Result<(), DeviceLost> {
+        self.stream_lane(stream).wait()
+    }
+    fn flush(&self) -> Result<(), DeviceLost> {
+        for lane in std::iter::once(&self.lane).chain(self.streams.iter()) {
+            lane.flush()?;
+        }
+        Ok(())
+    }
+    fn take_debug_messages(&self) -> Vec<DebugMessage> {
+        self.messages
+            .as_ref()
+            .map(|messages| std::mem::take(&mut *messages.lock()))
+            .unwrap_or_default()
+    }
+    fn begin_batch(&self) {
+        self.batches.fetch_add(1, Ordering::SeqCst);
+    }
+    fn end_batch(&self) {
+        let _ = self
+            .batches
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |x| x.checked_sub(1));
+    }
+    fn gpu_time(&self) -> Result<Option<Duration>, DeviceLost> {
+        let lane = &self.lane;
+        let epoch = lane.epoch.load(Ordering::SeqCst);
+        lane.wait_epoch(epoch)?;
+        // The worker records the time of each frame after it completes.
+        while lane.finished.load(Ordering::SeqCst) < epoch {
+            if lane.worker_exited.load(Ordering::SeqCst) {
+                return Err(DeviceLost(self.id()));
+            }
+            std::hint::spin_loop();
+        }
+        Ok(lane
+            .gpu_time
+            .as_ref()
+            .map(|x| Duration::from_nanos(x.load(Ordering::SeqCst))))
     }
+    fn wait_async(&self) -> RawWaitFuture {
+        let lanes: Vec<_> = std::iter::once(&self.lane)
+            .chain(self.streams.iter())
+            .map(Lane::wait_async)
+            .collect();
+        Box::pin(async move {
+            for lane in lanes {
+                lane.await?;
+            }
+            Ok(())
+        })
+    }
+    fn wait_stream_async(&self, stream: usize) -> RawWaitFuture {
+        Box::pin(self.stream_lane(stream).wait_async())
+    }
+    fn trim(&self) -> Result<(), DeviceLost> {
+        self.wait()?;
+        let device = self.lane.queue.device().clone();
+        // The block sizes were validated when the device was created.
+        let memory_allocator = memory_allocator(device, &self.block_sizes).unwrap();
+        *self.memory_allocator.write() = Arc::new(memory_allocator);
+        Ok(())
+    }
+    fn memory_budget(&self) -> Option<Vec<MemoryBudget>> {
+        let device = self.lane.queue.device();
+        let physical_device = device.physical_device();
+        if !device.enabled_extensions().ext_memory_budget
+            || physical_device.instance().api_version() < Version::V1_1
+        {
+            return None;
+        }
+        let mut budget_properties = ash::vk::PhysicalDeviceMemoryBudgetPropertiesEXT::default();
+        let mut memory_properties = ash::vk::PhysicalDeviceMemoryProperties2::builder()
+            .push_next(&mut budget_properties)
+            .build();
+        unsafe {
+            (physical_device
+                .instance()
+                .fns()
+                .v1_1
+                .get_physical_device_memory_properties2)(
+                physical_device.handle(),
+                &mut memory_properties,
+            );
+        }
+        let heaps = memory_properties.memory_properties.memory_heap_count as usize;
+        Some(
+            budget_properties.heap_budget[..heaps]
+                .iter()
+                .zip(&budget_properties.heap_usage[..heaps])
+                .map(|(&budget, &usage)| MemoryBudget { budget, usage })
+                .collect(),
+        )
+    }
+}
+
+fn get_device_type(device_type: PhysicalDeviceType) -> DeviceType {
+    match device_type {
+        PhysicalDeviceType::DiscreteGpu => DeviceType::Discrete,
+        PhysicalDeviceType::IntegratedGpu => DeviceType::Integrated,
+        PhysicalDeviceType::VirtualGpu => DeviceType::Virtual,
+        PhysicalDeviceType::Cpu => DeviceType::Cpu,
+        _ => DeviceType::Other,
+    }
+}
+
+// llvmpipe, SwiftShader, or another CPU implementation.
+fn is_software(physical_device: &PhysicalDevice) -> bool {
+    let properties = physical_device.properties();
+    properties.device_type == PhysicalDeviceType::Cpu
+        || matches!(
+            properties.driver_id,
+            Some(DriverId::MesaLLVMpipe | DriverId::GoogleSwiftshader)
+        )
+}
+
+fn debug_message(msg: &Message) -> DebugMessage {
+    let severity = if msg.severity.intersects(DebugUtilsMessageSeverity::ERROR) {
+        DebugMessageSeverity::Error
+    } else if msg.severity.intersects(DebugUtilsMessageSeverity::WARNING) {
+        DebugMessageSeverity::Warning
+    } else if msg.severity.intersects(DebugUtilsMessageSeverity::INFO) {
+        DebugMessageSeverity::Info
+    } else {
+        DebugMessageSeverity::Verbose
+    };
+    DebugMessage {
+        severity,
+        id: msg.layer_prefix.map(Into::into),
+        text: msg.description.into(),
+        kernel: None,
+    }
+}
+
+fn memory_allocator(
+    device: Arc<Device>,
+    block_sizes: &[(DeviceSize, DeviceSize)],
+) -> Result<StandardMemoryAllocator> {
+    Ok(StandardMemoryAllocator::new(
+        device,
+        GenericMemoryAllocatorCreateInfo {
+            block_sizes,
+            dedicated_allocation: false,
+            ..Default::default()
+        },
+    )?)
 }
 
 fn new_semaphore(device: &Arc<Device>) -> Result<Semaphore> {
@@ -443,14 +1156,8 @@ impl FrameOuter {
             descriptors: 0,
         }
     }
-    unsafe fn transfer(
-        &mut self,
-        epoch: &AtomicU64,
-        src: Subbuffer<[u8]>,
-        dst: Subbuffer<[u8]>,
-        host_buffer: &mut HostBuffer,
-        dst_device_buffer: Option<&DeviceBuffer>,
-    ) -> Result<()> {
+    /// Locks the frame, beginning it if empty.
+    fn lock_frame(&mut self, epoch: &AtomicU64) -> Result<MutexGuard<'_, Frame>> {
         let mut frame = self.frame.lock();
         if frame.command_buffer_builder.is_none() {
             self.kernels = 0;
@@ -461,42 +1168,71 @@ impl FrameOuter {
             epoch.store(frame.epoch, Ordering::SeqCst);
             self.empty.store(false, Ordering::SeqCst);
         }
+        Ok(frame)
+    }
+    unsafe fn transfer(
+        &mut self,
+        epoch: &AtomicU64,
+        src: Subbuffer<[u8]>,
+        dst: Subbuffer<[u8]>,
+        host_buffer: &mut HostBuffer,
+        dst_device_buffer: Option<&DeviceBuffer>,
+    ) -> Result<()> {
+        let mut frame = self.lock_frame(epoch)?;
         unsafe {
             frame.transfer(src, dst, host_buffer, dst_device_buffer);
         }
         Ok(())
     }
+    unsafe fn copy(
+        &mut self,
+        epoch: &AtomicU64,
+        src: &DeviceBuffer,
+        dst: &DeviceBuffer,
+    ) -> Result<()> {
+        let mut frame = self.lock_frame(epoch)?;
+        unsafe {
+            frame.copy(src, dst);
+        }
+        Ok(())
+    }
+    unsafe fn fill(
+        &mut self,
+        epoch: &AtomicU64,
+        dst: Subbuffer<[u32]>,
+        data: u32,
+        dst_device_buffer: &DeviceBuffer,
+    ) -> Result<()> {
+        let mut frame = self.lock_frame(epoch)?;
+        unsafe {
+            frame.fill(dst, data, dst_device_buffer);
+        }
+        Ok(())
+    }
     #[allow(clippy::too_many_arguments)]
     unsafe fn compute(
         &mut self,
         kernel_desc: &Arc<KernelDesc>,
         epoch: &AtomicU64,
         pipeline: &Arc<ComputePipeline>,
-        groups: u32,
+        groups: Groups<'_>,
         buffers: &[Arc<DeviceBuffer>],
         push_consts: &[u8],
         debug_printf_panic: Option<Arc<AtomicBool>>,
     ) -> Result<()> {
-        let new_descriptors: u32 = buffers.len().try_into().unwrap();
-        let mut frame = self.frame.lock();
-        if frame.command_buffer_builder.is_none() {
-            self.kernels = 0;
-            self.descriptors = 0;
+        let new_descriptors = Frame::descriptors(pipeline, buffers);
+        {
+            let mut frame = self.lock_frame(epoch)?;
             unsafe {
-                frame.begin()?;
+                frame.compute(
+                    kernel_desc,
+                    pipeline,
+                    groups,
+                    buffers,
+                    push_consts,
+                    debug_printf_panic,
+                );
             }
-            epoch.store(frame.epoch, Ordering::SeqCst);
-            self.empty.store(false, Ordering::SeqCst);
-        }
-        unsafe {
-            frame.compute(
-                kernel_desc,
-                pipeline,
-                groups,
-                buffers,
-                push_consts,
-                debug_printf_panic,
-            );
         }
         self.kernels += 1;
         self.descriptors += new_descriptors;
@@ -504,12 +1240,25 @@ impl FrameOuter {
     }
 }
 
+/// The groups of a dispatch.
+#[derive(Clone, Copy)]
+enum Groups<'a> {
+    Direct(u32),
+    /// Read from a [`DispatchIndirectCommand`] at the start of the buffer.
+    Indirect(&'a Arc<DeviceBuffer>),
+}
+
 struct Frame {
     queue: Arc<Queue>,
+    stream: usize,
     _command_pool: CommandPool,
     command_pool_alloc: CommandPoolAlloc,
     command_buffer_builder: Option<UnsafeCommandBufferBuilder>,
     descriptor_pool: DescriptorPool,
+    timestamps: Option<Arc<QueryPool>>,
+    // Start and end timestamps of each kernel, for performance metrics.
+    kernel_timestamps: Option<Arc<QueryPool>>,
+    kernel_descs: Vec<Arc<KernelDesc>>,
     buffers: Vec<Subbuffer<[u8]>>,
     epoch: u64,
     debug_kernel_desc_panic: Option<(Arc<KernelDesc>, Arc<AtomicBool>)>,
@@ -518,7 +1267,25 @@ struct Frame {
 impl Frame {
     const MAX_KERNELS: u32 = 4;
     const MAX_DESCRIPTORS: u32 = 32;
-    fn new(queue: Arc<Queue>) -> Result<Self> {
+    /// The number of descriptors allocated from the pool to dispatch `pipeline`.
+    fn descriptors(pipeline: &ComputePipeline, buffers: &[Arc<DeviceBuffer>]) -> u32 {
+        let push_descriptor = pipeline
+            .layout()
+            .set_layouts()
+            .first()
+            .is_some_and(|x| x.push_descriptor());
+        if push_descriptor {
+            0
+        } else {
+            buffers.len().try_into().unwrap()
+        }
+    }
+    fn new(
+        queue: Arc<Queue>,
+        stream: usize,
+        timestamps: bool,
+        kernel_timestamps: bool,
+    ) -> Result<Self> {
         let device = queue.device();
         let command_pool = CommandPool::new(
             device.clone(),
@@ -549,14 +1316,40 @@ impl Frame {
                 ..Default::default()
             },
         )?;
+        let timestamps = if timestamps {
+            Some(QueryPool::new(
+                device.clone(),
+                QueryPoolCreateInfo {
+                    query_count: 2,
+                    ..QueryPoolCreateInfo::query_type(QueryType::Timestamp)
+                },
+            )?)
+        } else {
+            None
+        };
+        let kernel_timestamps = if kernel_timestamps {
+            Some(QueryPool::new(
+                device.clone(),
+                QueryPoolCreateInfo {
+                    query_count: 2 * Self::MAX_KERNELS,
+                    ..QueryPoolCreateInfo::query_type(QueryType::Timestamp)
+                },
+            )?)
+        } else {
+            None
+        };
         let buffers = Vec::new();
         let epoch = 0;
         Ok(Self {
             queue,
+            stream,
             _command_pool: command_pool,
             command_pool_alloc,
             command_buffer_builder,
             descriptor_pool,
+            timestamps,
+            kernel_timestamps,
+            kernel_descs: Vec::new(),
             buffers,
             epoch,
             debug_kernel_desc_panic: None,
@@ -572,7 +1365,7 @@ impl Frame {
             .result()?;
             self.descriptor_pool.reset()?;
         }
-        self.command_buffer_builder.replace(unsafe {
+        let builder = self.command_buffer_builder.insert(unsafe {
             UnsafeCommandBufferBuilder::new(
                 &self.command_pool_alloc,
                 CommandBufferBeginInfo {
@@ -581,8 +1374,66 @@ impl Frame {
                 },
             )?
         });
+        if let Some(timestamps) = self.timestamps.as_ref() {
+            unsafe {
+                builder.reset_query_pool(timestamps.queries_range(0..2).unwrap());
+                builder.write_timestamp(timestamps.query(0).unwrap(), PipelineStage::TopOfPipe);
+            }
+        }
+        if let Some(kernel_timestamps) = self.kernel_timestamps.as_ref() {
+            unsafe {
+                builder.reset_query_pool(
+                    kernel_timestamps
+                        .queries_range(0..2 * Self::MAX_KERNELS)
+                        .unwrap(),
+                );
+            }
+        }
         Ok(())
     }
+    /// Records the end timestamp, before the command buffer is built.
+    unsafe fn end(&mut self) {
+        if let Some(timestamps) = self.timestamps.as_ref() {
+            let builder = self.command_buffer_builder.as_mut().unwrap();
+            unsafe {
+                builder.write_timestamp(timestamps.query(1).unwrap(), PipelineStage::BottomOfPipe);
+            }
+        }
+    }
+    /// The elapsed time in ticks of the completed frame.
+    fn elapsed_ticks(&self) -> Option<u64> {
+        let timestamps = self.timestamps.as_ref()?;
+        let mut ticks = [0u64; 2];
+        timestamps
+            .queries_range(0..2)
+            .unwrap()
+            .get_results(&mut ticks, QueryResultFlags::WAIT)
+            .ok()?;
+        Some(ticks[1].saturating_sub(ticks[0]))
+    }
+    /// The elapsed ticks of each kernel in the completed frame.
+    fn kernel_elapsed_ticks(&self) -> Vec<(&Arc<KernelDesc>, u64)> {
+        let Some(kernel_timestamps) = self.kernel_timestamps.as_ref() else {
+            return Vec::new();
+        };
+        if self.kernel_descs.is_empty() {
+            return Vec::new();
+        }
+        let mut ticks = vec![0u64; 2 * self.kernel_descs.len()];
+        if kernel_timestamps
+            .queries_range(0..ticks.len() as u32)
+            .unwrap()
+            .get_results(&mut ticks, QueryResultFlags::WAIT)
+            .is_err()
+        {
+            return Vec::new();
+        }
+        self.kernel_descs
+            .iter()
+            .zip(ticks.chunks(2))
+            .map(|(kernel_desc, ticks)| (kernel_desc, ticks[1].saturating_sub(ticks[0])))
+            .collect()
+    }
     unsafe fn transfer(
         &mut self,
         src: Subbuffer<[u8]>,
@@ -600,11 +1451,41 @@ impl Frame {
             dst_device_buffer.epoch.store(self.epoch, Ordering::SeqCst);
         }
     }
+    unsafe fn copy(&mut self, src: &DeviceBuffer, dst: &DeviceBuffer) {
+        let src_slice = src
+            .inner
+            .clone()
+            .unwrap()
+            .slice(src.offset as u64..(src.offset + src.len) as u64);
+        let dst_slice = dst
+            .inner
+            .clone()
+            .unwrap()
+            .slice(dst.offset as u64..(dst.offset + dst.len) as u64);
+        let builder = self.command_buffer_builder.as_mut().unwrap();
+        unsafe {
+            builder.copy_buffer(&CopyBufferInfo::buffers(
+                src_slice.clone(),
+                dst_slice.clone(),
+            ));
+        }
+        self.buffers.extend_from_slice(&[src_slice, dst_slice]);
+        src.read_epoch.store(self.epoch, Ordering::SeqCst);
+        dst.epoch.store(self.epoch, Ordering::SeqCst);
+    }
+    unsafe fn fill(&mut self, dst: Subbuffer<[u32]>, data: u32, dst_device_buffer: &DeviceBuffer) {
+        let builder = self.command_buffer_builder.as_mut().unwrap();
+        unsafe {
+            builder.fill_buffer(&dst, data);
+        }
+        self.buffers.push(dst.into_bytes());
+        dst_device_buffer.epoch.store(self.epoch, Ordering::SeqCst);
+    }
     unsafe fn compute(
         &mut self,
         kernel_desc: &Arc<KernelDesc>,
         pipeline: &Arc<ComputePipeline>,
-        groups: u32,
+        groups: Groups<'_>,
         buffers: &[Arc<DeviceBuffer>],
         push_consts: &[u8],
         debug_printf_panic: Option<Arc<AtomicBool>>,
@@ -621,24 +1502,35 @@ impl Frame {
                 0,
                 buffers.iter().map(|x| x.inner.as_ref().unwrap().clone()),
             );
-            unsafe {
-                let mut descriptor_set = self
-                    .descriptor_pool
-                    .allocate_descriptor_sets([DescriptorSetAllocateInfo {
-                        layout: descriptor_set_layout,
-                        variable_descriptor_count: 0,
-                    }])
-                    .unwrap()
-                    .next()
-                    .unwrap();
-                descriptor_set.write(descriptor_set_layout, [&write_descriptor_set]);
-                builder.bind_descriptor_sets(
-                    PipelineBindPoint::Compute,
-                    pipeline_layout,
-                    0,
-                    &[descriptor_set],
-                    [],
-                );
+            if descriptor_set_layout.push_descriptor() {
+                unsafe {
+                    builder.push_descriptor_set(
+                        PipelineBindPoint::Compute,
+                        pipeline_layout,
+                        0,
+                        [&write_descriptor_set],
+                    );
+                }
+            } else {
+                unsafe {
+                    let mut descriptor_set = self
+                        .descriptor_pool
+                        .allocate_descriptor_sets([DescriptorSetAllocateInfo {
+                            layout: descriptor_set_layout,
+                            variable_descriptor_count: 0,
+                        }])
+                        .unwrap()
+                        .next()
+                        .unwrap();
+                    descriptor_set.write(descriptor_set_layout, [&write_descriptor_set]);
+                    builder.bind_descriptor_sets(
+                        PipelineBindPoint::Compute,
+                        pipeline_layout,
+                        0,
+                        &[descriptor_set],
+                        [],
+                    );
+                }
             }
         }
         if !push_consts.is_empty() {
@@ -652,14 +1544,63 @@ impl Frame {
                 );
             }
         }
-        unsafe {
-            builder.dispatch([groups, 1, 1]);
+        let kernel_query = self.kernel_timestamps.as_ref().map(|kernel_timestamps| {
+            let query = 2 * self.kernel_descs.len() as u32;
+            unsafe {
+                builder.write_timestamp(
+                    kernel_timestamps.query(query).unwrap(),
+                    PipelineStage::TopOfPipe,
+                );
+            }
+            self.kernel_descs.push(kernel_desc.clone());
+            (kernel_timestamps, query + 1)
+        });
+        match groups {
+            Groups::Direct(groups) => unsafe {
+                builder.dispatch([groups, 1, 1]);
+            },
+            Groups::Indirect(indirect) => {
+                let inner = indirect.inner.as_ref().unwrap();
+                let offset = indirect.offset as u64;
+                let command = inner
+                    .clone()
+                    .slice(offset..offset + std::mem::size_of::<DispatchIndirectCommand>() as u64)
+                    .try_cast_slice::<DispatchIndirectCommand>()
+                    .unwrap();
+                unsafe {
+                    builder.dispatch_indirect(&command);
+                }
+                self.buffers.push(inner.clone());
+                if self.stream > 0 {
+                    indirect.stream.store(self.stream, Ordering::SeqCst);
+                    indirect.stream_epoch.store(self.epoch, Ordering::SeqCst);
+                } else {
+                    indirect.read_epoch.store(self.epoch, Ordering::SeqCst);
+                }
+            }
+        }
+        if let Some((kernel_timestamps, query)) = kernel_query {
+            unsafe {
+                builder.write_timestamp(
+                    kernel_timestamps.query(query).unwrap(),
+                    PipelineStage::BottomOfPipe,
+                );
+            }
         }
         self.buffers
             .extend(buffers.iter().map(|x| x.inner.as_ref().unwrap().clone()));
-        for (buffer, slice_desc) in buffers.iter().zip(kernel_desc.slice_descs.iter()) {
-            if slice_desc.mutable {
-                buffer.epoch.store(self.epoch, Ordering::SeqCst);
+        if self.stream > 0 {
+            for buffer in buffers.iter() {
+                buffer.stream.store(self.stream, Ordering::SeqCst);
+                buffer.stream_epoch.store(self.epoch, Ordering::SeqCst);
+            }
+        } else {
+            for (buffer, slice_desc) in buffers.iter().zip(kernel_desc.slice_descs.iter()) {
+                if slice_desc.mutable {
+                    buffer.epoch.store(self.epoch, Ordering::SeqCst);
+                } else {
+                    buffer.read_epoch.store(self.epoch, Ordering::SeqCst);
+                }
             }
         }
         if let Some(debug_printf_panic) = debug_printf_panic {
@@ -668,6 +1609,7 @@ impl Frame {
         }
     }
     unsafe fn finish(&mut self) {
+        self.kernel_descs.clear();
         self.buffers.clear();
         self.debug_kernel_desc_panic.take();
     }
@@ -688,6 +1630,14 @@ struct Worker {
     semaphore: Arc<Semaphore>,
     empty: Arc<AtomicBool>,
     pending: Arc<AtomicU64>,
+    finished: Arc<AtomicU64>,
+    wakers: Arc<Mutex<Vec<(u64, Waker)>>>,
+    batches: Arc<AtomicUsize>,
+    waiting: Arc<AtomicUsize>,
+    gpu_time: Option<Arc<AtomicU64>>,
+    timestamp_period: f64,
+    metrics: Option<Arc<Mutex<PerformanceMetrics>>>,
+    message_callback: Option<MessageCallback>,
     ready_frame: Arc<Mutex<Frame>>,
     pending_frame: Frame,
     engine_exited: Arc<AtomicBool>,
@@ -695,14 +1645,31 @@ struct Worker {
 }
 
 impl Worker {
-    fn new(queue: Arc<Queue>, index: usize) -> Result<Self> {
+    fn new(
+        queue: Arc<Queue>,
+        index: usize,
+        stream: usize,
+        metrics: Option<Arc<Mutex<PerformanceMetrics>>>,
+        batches: Arc<AtomicUsize>,
+        message_callback: Option<MessageCallback>,
+    ) -> Result<Self> {
         let semaphore = Arc::new(new_semaphore(queue.device())?);
         let empty = Arc::new(AtomicBool::new(true));
         let pending = Arc::new(AtomicU64::default());
-        let mut ready_frame = Frame::new(queue.clone())?;
+        let physical_device = queue.device().physical_device();
+        let timestamps = physical_device.queue_family_properties()
+            [queue.queue_family_index() as usize]
+            .timestamp_valid_bits
+            .is_some();
+        let finished = Arc::new(AtomicU64::default());
+        let wakers = Arc::default();
+        let gpu_time = timestamps.then(|| Arc::new(AtomicU64::default()));
+        let timestamp_period = physical_device.properties().timestamp_period as f64;
+        let kernel_timestamps = timestamps && metrics.is_some();
+        let mut ready_frame = Frame::new(queue.clone(), stream, timestamps, kernel_timestamps)?;
         ready_frame.epoch = 1;
         let ready_frame = Arc::new(Mutex::new(ready_frame));
-        let pending_frame = Frame::new(queue.clone())?;
+        let pending_frame = Frame::new(queue.clone(), stream, timestamps, kernel_timestamps)?;
         let engine_exited = Arc::new(AtomicBool::default());
         let worker_exited = Arc::new(AtomicBool::default());
         Ok(Self {
@@ -711,19 +1678,34 @@ impl Worker {
             semaphore,
             empty,
             pending,
+            finished,
+            wakers,
+            batches,
+            waiting: Arc::default(),
+            gpu_time,
+            timestamp_period,
+            metrics,
+            message_callback,
             ready_frame,
             pending_frame,
             engine_exited,
             worker_exited,
         })
     }
-    fn run(&mut self) {
-        let id = DeviceId {
+    fn id(&self) -> DeviceId {
+        DeviceId {
             index: self.index,
             handle: self.queue.device().handle().as_raw().try_into().unwrap(),
-        };
+        }
+    }
+    fn run(&mut self) {
+        let id = self.id();
         loop {
-            while self.empty.load(Ordering::SeqCst) {
+            // Within a batch scope, the frame is submitted when full or waited on.
+            while self.empty.load(Ordering::SeqCst)
+                || (self.batches.load(Ordering::SeqCst) > 0
+                    && self.waiting.load(Ordering::SeqCst) == 0)
+            {
                 if self.engine_exited.load(Ordering::SeqCst) {
                     return;
                 }
@@ -737,6 +1719,9 @@ impl Worker {
             }
             self.pending
                 .store(self.pending_frame.epoch, Ordering::SeqCst);
+            unsafe {
+                self.pending_frame.end();
+            }
             let command_buffer = self
                 .pending_frame
                 .command_buffer_builder
@@ -747,6 +1732,8 @@ impl Worker {
             let _messenger = if let Some((kernel_desc, panicked)) =
                 self.pending_frame.debug_kernel_desc_panic.take()
             {
+                // Panics in the callback are caught by vulkano.
+                let message_callback = std::panic::AssertUnwindSafe(self.message_callback.clone());
                 Some(
                     unsafe {
                         DebugUtilsMessenger::new(
@@ -765,6 +1752,13 @@ impl Worker {
                                                 if msg.description.contains("[Rust panicked at ") {
                                                     panicked.store(true, Ordering::SeqCst);
                                                 }
+                                                if let Some(callback) = (*message_callback).as_ref()
+                                                {
+                                                    callback(&DebugMessage {
+                                                        kernel: Some(kernel_desc.name.to_string()),
+                                                        ..debug_message(msg)
+                                                    });
+                                                }
                                             }
                                         }
                                     },
@@ -801,17 +1795,51 @@ impl Worker {
                     _ => result.result().unwrap(),
                 }
             }
+            if let Some((gpu_time, ticks)) = self
+                .gpu_time
+                .as_ref()
+                .zip(self.pending_frame.elapsed_ticks())
+            {
+                let nanos = (ticks as f64 * self.timestamp_period) as u64;
+                gpu_time.fetch_add(nanos, Ordering::SeqCst);
+            }
+            if let Some(metrics) = self.metrics.as_ref() {
+                let mut metrics = metrics.lock();
+                for (kernel_desc, ticks) in self.pending_frame.kernel_elapsed_ticks() {
+                    let nanos = (ticks as f64 * self.timestamp_period) as u64;
+                    metrics.record_gpu_time(&kernel_desc.name, Duration::from_nanos(nanos));
+                }
+            }
             unsafe {
                 self.pending_frame.finish();
             }
+            self.finished
+                .store(self.pending_frame.epoch, Ordering::SeqCst);
+            self.wake(self.pending_frame.epoch);
         }
     }
+    /// Wakes futures waiting on epochs up to `epoch`.
+    fn wake(&self, epoch: u64) {
+        let mut wakers = self.wakers.lock();
+        if wakers.is_empty() {
+            return;
+        }
+        wakers.retain(|(waker_epoch, waker)| {
+            if *waker_epoch <= epoch {
+                waker.wake_by_ref();
+                false
+            } else {
+                true
+            }
+        });
+    }
 }
 
 impl Drop for Worker {
     fn drop(&mut self) {
         let _ = self.queue.with(|mut guard| guard.wait_idle());
         self.worker_exited.store(true, Ordering::SeqCst);
+        self.wake(u64::MAX);
     }
 }
 
@@ -838,6 +1866,11 @@ pub(super) struct DeviceBuffer {
     offset: usize,
     len: usize,
     epoch: Arc<AtomicU64>,
+    // Only used to synchronize with other streams.
+    read_epoch: Arc<AtomicU64>,
+    // The last stream other than 0 to use the buffer.
+    stream: Arc<AtomicUsize>,
+    stream_epoch: Arc<AtomicU64>,
 }
 
 impl DeviceBuffer {
@@ -852,26 +1885,94 @@ impl DeviceBuffer {
             false
         }
     }
-}
-
-impl DeviceEngineBuffer for DeviceBuffer {
-    type Engine = Engine;
-    fn engine(&self) -> &Arc<Self::Engine> {
-        &self.engine
+    #[cfg(feature = "vulkan-interop")]
+    pub(super) fn vulkan_buffer(&self) -> Option<VulkanBuffer> {
+        let inner = self.inner.as_ref()?;
+        Some(VulkanBuffer {
+            buffer: inner.buffer().handle(),
+            offset: inner.offset() + self.offset as u64,
+            size: self.len as u64,
+        })
     }
-    unsafe fn uninit(engine: Arc<Engine>, len: usize) -> Result<Self> {
+    /// Fills with `data` with vkCmdFillBuffer.
+    ///
+    /// If `whole`, the slice is the entire buffer, and the padding after it may be filled.
+    /// Returns false if the slice is not aligned to 4 bytes.
+    pub(super) fn fill(&self, data: u32, whole: bool) -> Result<bool> {
+        let len = if whole {
+            aligned_ceil(self.len, 4)
+        } else {
+            self.len
+        };
+        if self.offset % 4 != 0 || len % 4 != 0 {
+            return Ok(false);
+        }
+        let buffer = if let Some(buffer) = self.inner.as_ref() {
+            buffer
+                .clone()
+                .slice(self.offset as u64..(self.offset + len) as u64)
+                .try_cast_slice::<u32>()
+                .unwrap()
+        } else {
+            return Ok(true);
+        };
+        let engine = &self.engine;
+        self.wait_streams()?;
+        // Kernels reading the buffer must be submitted first.
+        let buffer_epoch = self
+            .epoch
+            .load(Ordering::SeqCst)
+            .max(self.read_epoch.load(Ordering::SeqCst));
+        engine.wait_pending(buffer_epoch)?;
+        unsafe {
+            engine.fill(buffer, data, self)?;
+        }
+        Ok(true)
+    }
+    /// Copies to `dst` on the same device with vkCmdCopyBuffer.
+    pub(super) fn copy(&self, dst: &Self) -> Result<()> {
+        debug_assert_eq!(dst.len, self.len);
+        debug_assert!(Arc::ptr_eq(&self.engine, &dst.engine));
+        if self.len == 0 {
+            return Ok(());
+        }
+        self.wait_streams()?;
+        dst.wait_streams()?;
+        // Kernels reading `dst` must be submitted first.
+        let epoch = self
+            .epoch
+            .load(Ordering::SeqCst)
+            .max(dst.epoch.load(Ordering::SeqCst))
+            .max(dst.read_epoch.load(Ordering::SeqCst));
+        self.engine.wait_pending(epoch)?;
+        unsafe { self.engine.copy(self, dst) }
+    }
+    /// Allocates in host-visible, host-cached memory, which is transferred without staging.
+    pub(super) unsafe fn uninit_pinned(engine: Arc<Engine>, len: usize) -> Result<Self> {
+        let memory_types = engine.coherent_memory_types;
+        unsafe { Self::uninit_with_usage(engine, len, MemoryUsage::Download, memory_types) }
+    }
+    /// Allocates with `memory_usage`, in one of `memory_types`.
+    unsafe fn uninit_with_usage(
+        engine: Arc<Engine>,
+        len: usize,
+        memory_usage: MemoryUsage,
+        memory_types: u32,
+    ) -> Result<Self> {
         use vulkano::{memory::allocator::AllocationCreationError, VulkanError};
         let inner = if len > 0 {
             let len = aligned_ceil(len, Self::ALIGN);
-            let usage =
-                BufferUsage::STORAGE_BUFFER | BufferUsage::TRANSFER_DST | BufferUsage::TRANSFER_SRC;
+            let usage = BufferUsage::STORAGE_BUFFER
+                | BufferUsage::TRANSFER_DST
+                | BufferUsage::TRANSFER_SRC
+                | BufferUsage::INDIRECT_BUFFER;
             let buffer_info = BufferCreateInfo {
                 usage,
                 size: len.try_into().unwrap(),
                 ..Default::default()
             };
             let allocation_info = AllocationCreateInfo {
-                usage: MemoryUsage::DeviceOnly,
+                usage: memory_usage,
                 ..Default::default()
             };
             use vulkano::{
@@ -881,14 +1982,16 @@ impl DeviceEngineBuffer for DeviceBuffer {
                     DeviceAlignment,
                 },
             };
-            let device = engine.queue.device();
+            let device = engine.lane.queue.device();
             let raw_buffer = RawBuffer::new(device.clone(), buffer_info)?;
             let align = DeviceAlignment::new(DeviceBuffer::ALIGN.try_into().unwrap()).unwrap();
             let mut requirements = *raw_buffer.memory_requirements();
             requirements.layout = requirements.layout.align_to(align).unwrap();
             requirements.prefers_dedicated_allocation = false;
+            requirements.memory_type_bits &= memory_types;
             let memory_alloc = engine
                 .memory_allocator
+                .read()
                 .allocate(requirements, AllocationType::Unknown, allocation_info, None)
                 .map_err(|e| {
                     if let AllocationCreationError::VulkanError(VulkanError::OutOfDeviceMemory) = e
@@ -912,8 +2015,70 @@ impl DeviceEngineBuffer for DeviceBuffer {
             offset: 0,
             len,
             epoch: Arc::new(AtomicU64::new(0)),
+            read_epoch: Arc::new(AtomicU64::new(0)),
+            stream: Arc::new(AtomicUsize::new(0)),
+            stream_epoch: Arc::new(AtomicU64::new(0)),
         })
     }
+    /// The mapped memory, if host-visible and host-coherent.
+    ///
+    /// Waits for the device to finish writing, and reading if `mutable`.
+    pub(super) fn mapped_ptr(&self, mutable: bool) -> Result<Option<NonNull<u8>>, DeviceLost> {
+        let inner = if let Some(inner) = self.inner.as_ref() {
+            inner
+        } else {
+            return Ok(None);
+        };
+        let memory_type = match inner.buffer().memory() {
+            BufferMemory::Normal(memory_alloc) => memory_alloc.device_memory().memory_type_index(),
+            BufferMemory::Sparse => return Ok(None),
+        };
+        if self.engine.coherent_memory_types & (1 << memory_type) == 0 {
+            return Ok(None);
+        }
+        let ptr = if let Some(ptr) = inner.mapped_ptr() {
+            ptr
+        } else {
+            return Ok(None);
+        };
+        self.wait_streams()?;
+        let mut epoch = self.epoch.load(Ordering::SeqCst);
+        if mutable {
+            epoch = epoch.max(self.read_epoch.load(Ordering::SeqCst));
+        }
+        self.engine.wait_epoch(epoch)?;
+        let ptr = unsafe { ptr.as_ptr().cast::<u8>().add(self.offset) };
+        Ok(NonNull::new(ptr))
+    }
+    /// Waits for kernels on streams other than 0 using the buffer to finish.
+    fn wait_streams(&self) -> Result<(), DeviceLost> {
+        let stream = self.stream.load(Ordering::SeqCst);
+        let epoch = self.stream_epoch.load(Ordering::SeqCst);
+        if stream > 0 && epoch > 0 {
+            self.engine.stream_lane(stream).wait_epoch(epoch)?;
+        }
+        Ok(())
+    }
+}
+
+impl DeviceEngineBuffer for DeviceBuffer {
+    type Engine = Engine;
+    fn engine(&self) -> &Arc<Self::Engine> {
+        &self.engine
+    }
+    unsafe fn uninit(engine: Arc<Engine>, len: usize) -> Result<Self> {
+        let memory_types = engine.mapped_memory_types;
+        if memory_types != 0 {
+            let result = unsafe {
+                Self::uninit_with_usage(engine.clone(), len, MemoryUsage::Upload, memory_types)
+            };
+            // Falls back to device memory, ie if the BAR heap is full.
+            if let Ok(buffer) = result {
+                return Ok(buffer);
+            }
+        }
+        unsafe { Self::uninit_with_usage(engine, len, MemoryUsage::DeviceOnly, u32::MAX) }
+    }
     fn upload(&self, data: &[u8]) -> Result<()> {
         debug_assert_eq!(data.len(), self.len);
         if self.len == 0 {
@@ -927,6 +2092,7 @@ impl DeviceEngineBuffer for DeviceBuffer {
             return Ok(());
         };
         let engine = &self.engine;
+        self.wait_streams()?;
         let buffer_epoch = self.epoch.load(Ordering::SeqCst);
         if self.host_visible() {
             engine.wait_epoch(buffer_epoch)?;
@@ -963,6 +2129,7 @@ impl DeviceEngineBuffer for DeviceBuffer {
             return Ok(());
         };
         let engine = &self.engine;
+        self.wait_streams()?;
         let buffer_epoch = self.epoch.load(Ordering::SeqCst);
         if self.host_visible() {
             engine.wait_epoch(buffer_epoch)?;
@@ -1025,6 +2192,8 @@ impl DeviceEngineBuffer for DeviceBuffer {
             } else {
                 return Ok(());
             };
+        self.wait_streams()?;
+        dst.wait_streams()?;
         let engine1 = &self.engine;
         let buffer1_epoch = self.epoch.load(Ordering::SeqCst);
         let engine2 = &dst.engine;
@@ -1054,8 +2223,7 @@ impl DeviceEngineBuffer for DeviceBuffer {
         loop {
             let size = buffer1
                 .size()
-                .checked_sub(offset)
-                .unwrap_or_default()
+                .saturating_sub(offset)
                 .min(Self::HOST_BUFFER_SIZE as u64);
             let prev_host_copy = host_copy.take();
             if size > 0 {
@@ -1144,15 +2312,21 @@ impl KernelInner {
             pipeline::layout::{PipelineLayout, PipelineLayoutCreateInfo, PushConstantRange},
             shader::{spirv::ExecutionModel, EntryPointInfo},
         };
-        let device = engine.queue.device();
-        let descriptor_binding_requirements = desc
+        let device = engine.lane.queue.device();
+        // Coverage counters are bound after the slices.
+        let bindings_mutable: Vec<bool> = desc
             .slice_descs
+            .iter()
+            .map(|desc| desc.mutable)
+            .chain((!desc.coverage.is_empty()).then_some(true))
+            .collect();
+        let descriptor_binding_requirements = bindings_mutable
             .iter()
             .enumerate()
-            .map(|(i, desc)| {
+            .map(|(i, mutable)| {
                 let set = 0;
                 let binding = i.try_into().unwrap();
-                let memory_write = if desc.mutable {
+                let memory_write = if *mutable {
                     ShaderStages::COMPUTE
                 } else {
                     ShaderStages::empty()
@@ -1190,6 +2364,10 @@ impl KernelInner {
             input_interface: ShaderInterface::empty(),
             output_interface: ShaderInterface::empty(),
         };
+        #[cfg(feature = "spirv-val")]
+        if engine.validate_spirv {
+            desc.validate_spirv()?;
+        }
         let version = Version::major_minor(1, 2);
         let entry_point = "main";
         let shader_module = unsafe {
@@ -1206,7 +2384,7 @@ impl KernelInner {
                 )],
             )?
         };
-        let bindings = (0..desc.slice_descs.len())
+        let bindings = (0..bindings_mutable.len())
             .map(|binding| {
                 let descriptor_set_layout_binding = DescriptorSetLayoutBinding {
                     descriptor_count: 1,
@@ -1216,9 +2394,18 @@ impl KernelInner {
                 (binding.try_into().unwrap(), descriptor_set_layout_binding)
             })
             .collect();
+        // Push descriptors avoid allocating and writing a descriptor set for each dispatch.
+        let push_descriptor = device.enabled_extensions().khr_push_descriptor
+            && !bindings_mutable.is_empty()
+            && bindings_mutable.len()
+                <= device
+                    .physical_device()
+                    .properties()
+                    .max_push_descriptors
+                    .unwrap_or_default() as usize;
         let descriptor_set_layout_create_info = DescriptorSetLayoutCreateInfo {
             bindings,
-            push_descriptor: device.enabled_features().descriptor_buffer_push_descriptors,
+            push_descriptor,
             ..DescriptorSetLayoutCreateInfo::default()
         };
         let descriptor_set_layout =
@@ -1278,16 +2465,74 @@ impl DeviceEngineKernel for Kernel {
     unsafe fn dispatch(
         &self,
         groups: u32,
+        indirect: Option<&Arc<Self::DeviceBuffer>>,
         buffers: &[Arc<Self::DeviceBuffer>],
         push_consts: Vec<u8>,
         debug_printf_panic: Option<Arc<AtomicBool>>,
+        priority: Priority,
+        stream: usize,
     ) -> Result<()> {
         let engine = &self.engine;
-        if let Some(epoch) = buffers.iter().map(|x| x.epoch.load(Ordering::SeqCst)).max() {
+        let stream = if stream == 0 && priority == Priority::High {
+            1
+        } else {
+            stream
+        };
+        let stream_lane = engine.streams.get(stream.wrapping_sub(1));
+        let groups = if let Some(indirect) = indirect {
+            Groups::Indirect(indirect)
+        } else {
+            Groups::Direct(groups)
+        };
+        if let Some(stream_lane) = stream_lane {
+            // Wait for stream 0 to finish writing, or reading if mutable.
+            for (i, buffer) in buffers.iter().enumerate() {
+                // Coverage counters are bound after the slices.
+                let mutable = self.desc.slice_descs.get(i).map_or(true, |x| x.mutable);
+                engine.wait_epoch(buffer.epoch.load(Ordering::SeqCst))?;
+                if mutable {
+                    engine.wait_epoch(buffer.read_epoch.load(Ordering::SeqCst))?;
+                }
+            }
+            if let Some(indirect) = indirect {
+                engine.wait_epoch(indirect.epoch.load(Ordering::SeqCst))?;
+            }
+            // Wait for other streams to finish, work on this stream executes in order.
+            let mut pending = None;
+            for buffer in buffers.iter().chain(indirect) {
+                if buffer.stream.load(Ordering::SeqCst) == stream {
+                    pending = pending.max(Some(buffer.stream_epoch.load(Ordering::SeqCst)));
+                } else {
+                    buffer.wait_streams()?;
+                }
+            }
+            if let Some(epoch) = pending {
+                stream_lane.wait_pending(epoch)?;
+            }
+            return unsafe {
+                stream_lane.compute(
+                    &self.desc,
+                    &self.compute_pipeline,
+                    groups,
+                    buffers,
+                    &push_consts,
+                    debug_printf_panic,
+                )
+            };
+        }
+        for buffer in buffers.iter().chain(indirect) {
+            buffer.wait_streams()?;
+        }
+        if let Some(epoch) = buffers
+            .iter()
+            .chain(indirect)
+            .map(|x| x.epoch.load(Ordering::SeqCst))
+            .max()
+        {
             engine.wait_pending(epoch)?;
         }
         unsafe {
-            engine.compute(
+            engine.lane.compute(
                 &self.desc,
                 &self.compute_pipeline,
                 groups,
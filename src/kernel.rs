@@ -20,14 +20,11 @@ mod kernels {
     use krnl::krnl_core;
     use krnl_core::macros::kernel;
 
-    pub fn saxpy_impl(alpha: f32, x: f32, y: &mut f32) {
-        *y += alpha * x;
-    }
-
     // Item kernels for iterator patterns.
-    #[kernel]
+    // `host` also generates `saxpy::host(..)` which executes on the host.
+    #[kernel(host)]
     pub fn saxpy(alpha: f32, #[item] x: f32, #[item] y: &mut f32) {
-        saxpy_impl(alpha, x, y);
+        *y += alpha * x;
     }
 
     // General purpose kernels like CUDA / OpenCL.
@@ -37,17 +34,16 @@ mod kernels {
 
         let global_id = kernel.global_id();
         if global_id < x.len().min(y.len()) {
-            saxpy_impl(alpha, x[global_id], unsafe { y.unsafe_index_mut(global_id) });
+            unsafe {
+                *y.unsafe_index_mut(global_id) += alpha * x[global_id];
+            }
         }
     }
 }
 
 fn saxpy(alpha: f32, x: Slice<f32>, mut y: SliceMut<f32>) -> Result<()> {
     if let Some((x, y)) = x.as_host_slice().zip(y.as_host_slice_mut()) {
-        x.iter()
-            .copied()
-            .zip(y.iter_mut())
-            .for_each(|(x, y)| kernels::saxpy_impl(alpha, x, y));
+        kernels::saxpy::host(alpha, x, y);
         return Ok(());
     }
     # if true {
@@ -141,6 +137,33 @@ baz = { path = "baz" }
 
 [krnl-core](krnl_core) is automatically included as a dependency.
 
+## Snapshots
+`krnlc --snapshot snapshots` writes the disassembly of each kernel to "snapshots/\<my_crate\>/\<module\>/\<kernel\>.spvasm",
+without debug info, so that changes in codegen can be reviewed in diffs. With `--check`, **krnlc** compares
+with the existing snapshots and writes changes to ".spvasm.new" files.
+
+## Coverage
+`krnlc --coverage` instruments kernels with a counter per basic block, which is incremented each time the
+block is executed. Instrumented kernels bind an additional buffer, and block on completion in order to
+accumulate the counts, which can be retrieved with [`coverage`](crate::kernel::coverage):
+```no_run
+# use krnl::{anyhow::Result, device::Device, kernel::coverage};
+# fn main() -> Result<()> {
+let device = Device::builder().build()?;
+/* dispatch kernels */
+for kernel in coverage(&device) {
+    println!("{kernel}");
+    for block in kernel.unreached() {
+        eprintln!("unreached: {block}");
+    }
+}
+# Ok(())
+# }
+```
+Blocks that are never executed are dead paths for the tested inputs, while blocks with counts that differ from
+their neighbors indicate divergence. Blocks have a source location if the kernel has debug info, for example with
+`--debug-printf`. Rebuild without `--coverage` to remove the instrumentation.
+
 # Modules
 The `module` macro declares a shared host and device scope that is visible to [krnlc](#krnlc).
 The [spirv](#spirv) arch will be used by **krnlc** when compiling modules to for the device.
@@ -175,6 +198,16 @@ mod kernels {
 }
 ```
 
+## No Build
+The "no-build" feature applies `#[krnl(no_build)]` to all modules, for example when building docs
+or when kernels are not needed. The "krnl-cache.rs" is not required, and kernel
+[builders](#kernelbuilder) will return an error that the module was not compiled.
+```toml
+[package.metadata.docs.rs]
+features = ["krnl/no-build"]
+```
+Note that **krnlc** will not find any modules with "no-build" enabled.
+
 ## Imports
 Functions and other items are visible to other modules, and can be imported:
 ```no_run
@@ -229,6 +262,102 @@ fn foo<
 # }
 ```
 
+## Features
+[Features](crate::device::Features) used by a kernel are detected by **krnlc**. They can also be declared,
+in which case **krnlc** will error if the kernel uses features that aren't declared:
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# use krnl::macros::kernel;
+#[kernel(features(shader_int64, shader_float64))]
+fn cast_u64_f64(#[item] x: u64, #[item] y: &mut f64) {
+    *y = x as f64;
+}
+# }
+```
+Building the kernel on a device that doesn't support all of the features will return an error
+naming the missing features.
+
+Kernels using atomic add on f32 buffers require
+[`shader_atomic_float`](crate::device::Features::shader_atomic_float), which is detected from the
+SPIR-V when the kernel is built.
+
+[`f16`](crate::half::f16) and [`bf16`](crate::half::bf16) are stored as u16 and computed as f32,
+which only requires `shader_int8` and `shader_int16`. Kernels that use `shader_float16`, in modules without
+[variants](#variants), are also compiled without it, such that `.build(..)` falls back to computing f16 as f32
+on devices that don't support `shader_float16`. Native f16 code can be selected with
+`cfg(target_feature = "Float16")`.
+
+On devices without `shader_float64`, [`Df64`](crate::scalar::Df64) emulates f64 with pairs of f32,
+such that the kernel only requires the features of f32:
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# use krnl::macros::kernel;
+#[kernel]
+fn axpy_df64(alpha: f32, #[global] x: Slice<f32>, #[global] y: UnsafeSlice<f32>) {
+    use krnl_core::{buffer::UnsafeIndex, scalar::Df64};
+
+    let i = kernel.global_id() * 2;
+    if i + 1 < x.len().min(y.len()) {
+        unsafe {
+            let [y_hi, y_lo] = [y.unsafe_index_mut(i), y.unsafe_index_mut(i + 1)];
+            let x = Df64::from_array([x[i], x[i + 1]]);
+            let [hi, lo] = (Df64::from_f32(alpha) * x + Df64::from_array([*y_hi, *y_lo])).to_array();
+            *y_hi = hi;
+            *y_lo = lo;
+        }
+    }
+}
+# }
+```
+See [`Df64::f32_pairs_from_f64`](crate::scalar::Df64::f32_pairs_from_f64) for converting to and from
+f64 on the host.
+
+## Requirements
+Relationships between arguments, like slice lengths, can be declared with `require(..)`. Each
+expression is checked by [`.dispatch(..)`](#dispatch), which returns an error if it is false. Arguments
+are referenced by name, as they are passed to [`.dispatch(..)`](#dispatch) or [`host`](#host).
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# use krnl::macros::kernel;
+#[kernel(require(x.len() == y.len(), n as usize <= x.len()))]
+fn add(n: u32, #[global] x: Slice<f32>, #[global] y: UnsafeSlice<f32>) {
+    use krnl_core::buffer::UnsafeIndex;
+
+    let global_id = kernel.global_id();
+    if global_id < n as usize {
+        unsafe {
+            *y.unsafe_index_mut(global_id) += x[global_id];
+        }
+    }
+}
+# }
+```
+
+## Generic
+Kernels can be instantiated for several [scalar types](crate::scalar::ScalarType) with
+`generic(T = [..])`. Each instantiation is a separate kernel named `{kernel}_{type}`, ie `saxpy_f32`.
+A `mod` with the name of the kernel selects the instantiation at runtime with
+[`ScalarType`](crate::scalar::ScalarType). Generic slices are passed as
+[ScalarSlice](crate::buffer::ScalarSlice) / [ScalarSliceMut](crate::buffer::ScalarSliceMut), and
+generic push constants as [ScalarElem](crate::scalar::ScalarElem).
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# use krnl::macros::kernel;
+#[kernel(generic(T = [f32, f64]))]
+fn saxpy(#[item] x: T, alpha: T, #[item] y: &mut T) {
+    *y += alpha * x;
+}
+# }
+# use krnl::{anyhow::Result, buffer::{ScalarSlice, ScalarSliceMut}, device::Device, scalar::{ScalarElem, ScalarType}};
+# use kernels::saxpy;
+fn saxpy(x: ScalarSlice, alpha: ScalarElem, y: ScalarSliceMut) -> Result<()> {
+    saxpy::builder(x.scalar_type())?
+        .build(y.device())?
+        .dispatch(x, alpha, y)
+}
+# fn main() {}
+```
+
 # Items
 Item kernels are a simple and safe abstraction for iterator patterns. Item kernels
 have an implcit [ItemKernel](krnl_core::kernel::ItemKernel) argument.
@@ -237,18 +366,15 @@ Mapping a buffer with a fn:
 ```no_run
 # #[krnl::macros::module] #[krnl(no_build)] mod kernels {
 # use krnl::{macros::kernel, buffer::{Buffer, Slice}, anyhow::Result};
-fn scale_to_f32_impl(x: u8) -> f32 {
-    x as f32 / 255.
-}
-
-#[kernel]
+#[kernel(host)]
 fn scale_to_f32(#[item] x: u8, #[item] y: &mut f32) {
-    *y = scale_to_f32_impl(x);
+    *y = x as f32 / 255.;
 }
 
 # fn foo(x: Slice<u8>) -> Result<Buffer<f32>> {
 if let Some(x) = x.as_host_slice() {
-    let y: Vec<f32> = x.iter().copied().map(scale_to_f32_impl).collect();
+    let mut y = vec![0f32; x.len()];
+    scale_to_f32::host(x, &mut y);
     Ok(Buffer::from(y))
 } else {
     let mut y = Buffer::zeros(x.device(), x.len())?;
@@ -261,6 +387,73 @@ if let Some(x) = x.as_host_slice() {
 # }
 ```
 
+## Item Lengths
+By default, item arguments are zipped like [`Iterator::zip`], and the number of items is the minimum
+length. This can be specified explicitly with `#[kernel(items = zip)]`. With `#[kernel(items = exact)]`,
+[`.dispatch(..)`](#dispatch) will return an error if the item arguments have different lengths.
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# use krnl::macros::kernel;
+#[kernel(items = exact)]
+fn add(#[item] a: f32, #[item] b: f32, #[item] c: &mut f32) {
+    *c = a + b;
+}
+# }
+```
+
+## Host
+With `#[kernel(host)]`, item kernels also generate a `host` fn that executes the kernel on
+the host, sequentially for each item. The arguments are the [spec constants](#specialization),
+followed by the kernel arguments, with items passed as slices:
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# use krnl::macros::kernel;
+#[kernel(host)]
+fn saxpy(alpha: f32, #[item] x: f32, #[item] y: &mut f32) {
+    *y += alpha * x;
+}
+
+# fn foo() {
+let x = vec![1f32; 10];
+let mut y = vec![0f32; 10];
+saxpy::host(2f32, &x, &mut y);
+# }
+# }
+```
+The kernel must compile for the host as well as the [spirv](#spir-v) arch, so it can't use
+items only available on the device. Kernels with [group buffers](#group-buffers) are not supported.
+
+Use [`race_check`](crate::testing::race_check) to check for data races in `unsafe` kernels.
+
+## Grid Stride
+With `#[kernel(grid_stride = n)]`, where `n` is a `u32` [push constant](#push-constants), the kernel
+is called for each item in `0 .. n` with an implicit [ItemKernel](krnl_core::kernel::ItemKernel) argument.
+Threads loop over the items, stepping by the number of global threads, so any number of groups can be
+dispatched. If not provided, the groups are inferred from `n`. Otherwise,
+`.recommended_global_threads(..)` of the [Kernel](#kernelbuilder) covers `n` items within the
+[max groups](crate::device::DeviceInfo::max_groups) of the device.
+```
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# use krnl::macros::kernel;
+#[kernel(host, grid_stride = n)]
+fn fill_index(n: u32, #[global] y: UnsafeSlice<u32>) {
+    use krnl_core::buffer::UnsafeIndex;
+
+    let index = kernel.item_id();
+    unsafe {
+        *y.unsafe_index_mut(index) = index as u32;
+    }
+}
+
+# pub fn foo() {
+let mut y = vec![0u32; 4];
+fill_index::host(3, &mut y);
+assert_eq!(y, [0, 1, 2, 0]);
+# }
+# }
+# fn main() { kernels::foo() }
+```
+
 # Push Constants
 Scalar arguments without an attribute. Unlike [SpecConstants](#Specialization), they are
 provided to [`.dispatch(..)`](#dispatch), and do not require rebuilding the kernel.
@@ -268,6 +461,33 @@ provided to [`.dispatch(..)`](#dispatch), and do not require rebuilding the kern
 At least 128 bytes of push constants can be used, depending on the device. Each [item](#items) or
 [global](#global-buffers)  argument requires 8 bytes of push constants.
 
+## Push Structs
+Instead of many scalar arguments, a single `#[push]` argument can be a `#[repr(C)]` struct defined in
+the module, shared between the host and the device. On the host it must implement [`Pod`](bytemuck::Pod),
+which `#[derive(PushConstants)]` implements, checking that the struct doesn't have padding.
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# #[cfg(not(target_arch = "spirv"))]
+# use krnl::krnl_core;
+use krnl_core::macros::{kernel, PushConstants};
+
+#[derive(Clone, Copy, PushConstants)]
+#[repr(C)]
+pub struct Params {
+    pub alpha: f32,
+    pub beta: f32,
+}
+
+#[kernel]
+pub fn axpby(#[push] params: Params, #[item] x: f32, #[item] y: &mut f32) {
+    *y = params.alpha * x + params.beta * *y;
+}
+# }
+```
+The struct is passed to [`.dispatch(..)`](#dispatch) like scalar push constants, and is placed before them.
+When the kernel is built, the layout is checked against the compiled kernel, and an error is returned if
+the offsets, size, or alignment don't match the host. With [`PushConstants`](crate::kernel::PushConstants), the error names the mismatched field.
+
 # Groups, Subgroups, and Threads
 Kernels without [items](#items) have an implicit [Kernel](krnl_core::kernel::Kernel) argument that uniquely
 identifies the group, subgroup, and thread.
@@ -278,13 +498,17 @@ typically on the same processor with a shared L1 cache. This is exposed via [Gro
 Thread groups are composed of subgroups of threads (CUDA warps), similar to SIMD vector registers on a CPU.
 The number of threads per subgroup is a power of 2 between 1 and 128. Typical values are 32 for NVIDIA and 64
 for AMD.
-*/
-//
-// TODO: Intel Mesa driver uses variable subgroup size
-// Fixed in https://github.com/charles-r-earp/krnl/tree/update-vulkano
-// It can be accessed in a kernel via [`Kernel::subgroup_threads`](krnl_core::kernel::Kernel::subgroup_threads),
-// or on the host via [`DeviceInfo::subgroup_threads()`](crate::device::DeviceInfo::subgroup_threads).
-/*!
+It can be accessed in a kernel via [`Kernel::subgroup_threads`](krnl_core::kernel::Kernel::subgroup_threads),
+or on the host via [`DeviceInfo::subgroup_threads()`](crate::device::DeviceInfo::subgroup_threads).
+Some devices, like Intel GPU's, may select the subgroup threads per kernel, between
+[`DeviceInfo::min_subgroup_threads()`](crate::device::DeviceInfo::min_subgroup_threads) and
+[`DeviceInfo::max_subgroup_threads()`](crate::device::DeviceInfo::max_subgroup_threads).
+
+Subgroup operations, like [`subgroup_add`](krnl_core::subgroup::subgroup_add), are provided in
+[`krnl_core::subgroup`].
+Kernels using subgroup operations require the corresponding subgroup [features](#features), ie
+[`Features::subgroup_arithmetic()`](crate::device::Features::subgroup_arithmetic). These are detected from
+the compiled SPIR-V and checked when the kernel is built.
 
 # Global Buffers
 Visible to all threads. [Slice](krnl_core::buffer::Slice) binds to [Slice](crate::buffer::Slice), [UnsafeSlice](krnl_core::buffer::UnsafeSlice) binds
@@ -293,6 +517,75 @@ to [SliceMut](crate::buffer::SliceMut), provided to [`.dispatch(..)`](#dispatch)
 For best performance, consecutive threads should access consecutive elements, allowing loads and stores to be coalesced
 into fewer memory transactions.
 
+Slices may be views of a larger buffer, ie from [`.slice(..)`](crate::buffer::Slice::slice) or
+[`.split_at_mut(..)`](crate::buffer::SliceMut::split_at_mut). The buffer is bound, and the offset
+and length of the view are passed as push constants, so one allocation can back many slices
+without copies. Indexing within the kernel is relative to the start of the view.
+
+The offset and length are `u32`, which covers any slice of a Vulkan allocation, see
+[`DeviceInfo::max_allocation_size()`](crate::device::DeviceInfo::max_allocation_size).
+[Slice64](krnl_core::buffer::Slice64) and [UnsafeSlice64](krnl_core::buffer::UnsafeSlice64) are
+indexed with `u64`, for slices with more than [`u32::MAX`] elements on backends with larger allocations.
+Kernels with 64 bit slices require [`Features::shader_int64()`](crate::device::Features::shader_int64).
+
+Elements of an [UnsafeSlice](krnl_core::buffer::UnsafeSlice) of `u32` or `i32` can be updated
+by many threads with [atomics](krnl_core::buffer::UnsafeSlice::atomic_add):
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# use krnl::macros::kernel;
+#[kernel]
+fn histogram(#[global] x: Slice<u32>, #[global] counts: UnsafeSlice<u32>) {
+    let global_id = kernel.global_id();
+    if global_id < x.len() {
+        counts.atomic_add(x[global_id] as usize, 1);
+    }
+}
+# }
+```
+
+Arrays of slices, like `#[global] xs: [Slice<f32>]`, are not supported. **rust-gpu** binds each
+storage buffer as a single block, so descriptor indexed arrays of buffers can't be declared. Each
+slice must be a separate argument.
+
+## Structs
+Slices of `#[repr(C)]` structs of scalars avoid splitting records, like particles, into a buffer per field.
+Like [push structs](#push-structs), the struct is defined in the module and shared between the host and
+the device. `#[derive(Pod)]` implements [`Pod`](crate::scalar::Pod), checking that each field is a scalar or
+a `Pod` struct, and that the struct doesn't have padding. On the host, the struct must also implement
+[`Copy`] and [`Debug`].
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# #[cfg(not(target_arch = "spirv"))]
+# use krnl::krnl_core;
+use krnl_core::macros::{kernel, Pod};
+
+#[derive(Clone, Copy, Debug, Pod)]
+#[repr(C)]
+pub struct Particle {
+    pub x: f32,
+    pub y: f32,
+    pub vx: f32,
+    pub vy: f32,
+}
+
+#[kernel]
+pub fn step(dt: f32, #[global] particles: UnsafeSlice<Particle>) {
+    use krnl_core::buffer::UnsafeIndex;
+
+    let global_id = kernel.global_id();
+    if global_id < particles.len() {
+        let particle = unsafe { particles.unsafe_index_mut(global_id) };
+        particle.x += particle.vx * dt;
+        particle.y += particle.vy * dt;
+    }
+}
+# }
+```
+[Buffers](crate::buffer::Buffer) of structs can be copied and transferred, but scalar operations like
+[`.fill()`](crate::buffer::BufferBase::fill) and [`.cast()`](crate::buffer::BufferBase::cast) are not
+supported. They are stored as the unsigned scalar with the alignment of the struct, for example a
+struct of `f32`'s has a [`.scalar_type()`](crate::buffer::BufferBase::scalar_type) of `U32`.
+
 # Group Buffers
 Shared with all threads in the group, initialized with zeros. Can be used to minimize accesses
 to [global buffers](#global-buffers).
@@ -355,7 +648,8 @@ pub mod saxpy {
     /// The builder is lazily created on first call.
     ///
     /// **Errors**
-    /// - The kernel wasn't compiled (with `#[krnl(no_build)]` applied to `#[module]`).
+    /// - The kernel wasn't compiled (with `#[krnl(no_build)]` applied to `#[module]`,
+    ///   or with the "no-build" feature).
     pub fn builder() -> Result<KernelBuilder>;
 
     impl KernelBuilder {
@@ -363,6 +657,8 @@ pub mod saxpy {
         ///
         /// Defaults to [`DeviceInfo::default_threads()`](DeviceInfo::default_threads).
         pub fn with_threads(self, threads: u32) -> Self;
+        /// Metadata of the kernel, ie arguments and spec constants.
+        pub fn metadata(&self) -> KernelMetadata;
         /// Builds the kernel for `device`.
         ///
         /// The kernel is cached, so subsequent calls to `.build()` with identical
@@ -382,6 +678,15 @@ pub mod saxpy {
     impl Kernel {
         /// Threads per group.
         pub fn threads(&self) -> u32;
+        /// Max groups per dispatch, see [`DeviceInfo::max_groups()`](DeviceInfo::max_groups).
+        pub fn max_groups(&self) -> u32;
+        /// Global threads to process `len` elements.
+        ///
+        /// Rounds up to the next multiple of threads, limited to [`.max_groups()`](Self::max_groups).
+        /// When limited, the kernel must loop over the remaining elements, ie with `grid_stride`.
+        pub fn recommended_global_threads(&self, len: usize) -> u32;
+        /// Metadata of the kernel, ie arguments and specialized spec constants.
+        pub fn metadata(&self) -> KernelMetadata;
         /// Global threads to dispatch.
         ///
         /// Implicitly declares groups by rounding up to the next multiple of threads.
@@ -390,6 +695,25 @@ pub mod saxpy {
         ///
         /// For item kernels, if not provided, is inferred based on item arguments.
         pub fn with_groups(self, groups: u32) -> Self;
+        /// Groups to dispatch, read by the device from the first element of `groups`.
+        ///
+        /// `groups` is `[x, 1, 1]`, and can be written by a previously dispatched kernel.
+        ///
+        /// **Errors**
+        /// - `groups` has fewer than 3 elements.
+        /// - `groups` is not on the device.
+        pub fn with_groups_indirect(self, groups: Slice<u32>) -> Result<Self>;
+        /// The priority of dispatches.
+        ///
+        /// Defaults to [`Priority::Normal`](device::Priority::Normal).
+        pub fn with_priority(self, priority: Priority) -> Self;
+        /// The [stream](device::Stream) to dispatch on.
+        ///
+        /// Defaults to stream 0. Overrides the [priority](Kernel::with_priority).
+        ///
+        /// **Errors**
+        /// - `stream` is not on the device.
+        pub fn with_stream(self, stream: &Stream) -> Result<Self>;
         /// Dispatches the kernel.
         ///
         /// - Waits for immutable access to slice arguments.
@@ -400,7 +724,25 @@ pub mod saxpy {
         /// - DeviceLost: The device was lost.
         /// - The kernel could not be queued.
         pub fn dispatch(&self, alpha: f32, x: Slice<f32>, y: SliceMut<f32>) -> Result<()>;
+        /// Dispatches the kernel, returning a future that resolves when it is finished.
+        ///
+        /// The future also waits for work previously submitted to the same
+        /// [stream](crate::device::Stream). See [`.dispatch(..)`](Self::dispatch).
+        pub fn dispatch_async(
+            &self,
+            alpha: f32,
+            x: Slice<f32>,
+            y: SliceMut<f32>,
+        ) -> Result<WaitFuture>;
     }
+
+    /// Executes the kernel on the host.
+    ///
+    /// Calls the kernel for each item sequentially, the number of items is the
+    /// minimum length of the item arguments.
+    ///
+    /// Generated with `#[kernel(host)]`.
+    pub fn host(alpha: f32, x: &[f32], y: &mut [f32]);
 }
 # fn main() {}
 ```
@@ -408,7 +750,7 @@ View the generated code and documentation with `cargo doc --open`.
 Also use `--document-private-items` if the item is private.
 
 The `builder()` method returns a KernelBuilder for creating a Kernel. This will fail if the
-kernel wasn't compiled with [no_build](#attributes). The builder is cached so
+kernel wasn't compiled with [no_build](#attributes) or [no-build](#no-build). The builder is cached so
 that subsequent calls are trivial.
 
 The number of threads per group can be set via `.with_threads(..)`. It will default to
@@ -417,6 +759,9 @@ The number of threads per group can be set via `.with_threads(..)`. It will defa
 Building a kernel is an expensive operation, so it is cached within [Device](crate::device::Device). Subsequent
 calls to `.build(..)` with identical builders (threads and [spec constants](#specialization)) may avoid recompiling.
 
+The `.metadata()` methods return [`KernelMetadata`](crate::kernel::KernelMetadata), describing the arguments, spec constants,
+push constant layout, and threads of the kernel.
+
 # Features
 Kernels implicitly declare [`Features`](device::Features) based on types and or operations used.
 If the [device](device::Device) does not support these features, `.build(..)` will return an
@@ -424,6 +769,38 @@ error.
 
 See [`DeviceInfo::features()`](device::DeviceInfo::features).
 
+## Variants
+A module can be compiled several times with different features, via `#[krnl(variants(..))]`.
+All variants are embedded in the "krnl-cache.rs", and `.build(..)` selects the supported variant
+with the most features, preferring the first. Modules without variants are compiled with all features.
+Only the kernels of modules declaring a variant are compiled with its features, so other modules may
+use types it doesn't support. Note that comparisons use [`Ordering`](core::cmp::Ordering), an `i8`,
+so most kernels require `shader_int8`.
+Within a variant, enabled features can be checked with `cfg(target_feature = "..")`, ie
+"Int8", "Int16", "Int64", "Float16", and "Float64".
+```no_run
+# use krnl::macros::module;
+#[module]
+# #[krnl(no_build)]
+#[krnl(variants([shader_int8, shader_int64], [shader_int8]))]
+mod kernels {
+    # use krnl::macros::kernel;
+    #[kernel]
+    fn sum(#[item] x: u32, #[item] y: &mut u32) {
+        #[cfg(target_feature = "Int64")]
+        {
+            *y = (*y as u64 + x as u64).min(u32::MAX as u64) as u32;
+        }
+        #[cfg(not(target_feature = "Int64"))]
+        {
+            let sum = y.wrapping_add(x);
+            *y = if sum < x { u32::MAX } else { sum };
+        }
+    }
+}
+# fn main() {}
+```
+
 # Specialization
 SpecConstants are declared like const generic parameters, but are not const when compiling
 in Rust. They may be used to define the length of a [Group Buffer](#group-buffers). At runtime,
@@ -477,6 +854,70 @@ in parallel, untill all groups have finished.
 Synchronization is automatically performed as necessary between kernels and when transfering buffers
 to and from devices. [`Device::wait()`](crate::device::Device::wait) can be used to explicitly wait for prior operations to complete.
 
+`.dispatch_async(..)` returns a [`WaitFuture`](crate::device::WaitFuture) that resolves when the
+kernel is finished, so that the host can wait for a particular kernel instead of the whole device:
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# use krnl::macros::kernel;
+# #[kernel] fn fill(#[item] y: &mut u32) { *y = 1; }
+# use krnl::{anyhow::Result, buffer::Buffer, device::Device};
+# fn fill_async(device: Device) -> Result<()> {
+let fill = fill::builder()?.build(device.clone())?;
+let mut a = Buffer::<u32>::zeros(device.clone(), 1_000)?;
+let mut b = Buffer::<u32>::zeros(device, 1_000)?;
+let a_finished = fill.dispatch_async(a.as_slice_mut())?;
+fill.dispatch(b.as_slice_mut())?;
+a_finished.block()?;
+# Ok(())
+# }
+# }
+# fn main() {}
+```
+The future also waits for work previously submitted to the same [stream](crate::device::Stream),
+see [`Stream::wait_async()`](crate::device::Stream::wait_async). Work is submitted in
+[batches](crate::device::Device#batching), so it may also wait for work dispatched after the kernel
+in the same batch.
+
+## Priority
+Kernels are executed in the order they are dispatched. A latency critical kernel can be dispatched
+with [`Priority::High`](crate::device::Priority::High) via `.with_priority(..)`, which on devices with
+multiple compute queues is submitted to a separate, higher priority queue, instead of waiting behind
+previously dispatched work.
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# use krnl::macros::kernel;
+# #[kernel] fn fill(#[item] y: &mut u32) { *y = 1; }
+# use krnl::{anyhow::Result, buffer::SliceMut, device::Priority};
+# fn fill_high(y: SliceMut<u32>) -> Result<()> {
+fill::builder()?
+    .build(y.device())?
+    .with_priority(Priority::High)
+    .dispatch(y)
+# }
+# }
+# fn main() {}
+```
+
+## Indirect Dispatch
+The groups of a dispatch can be computed on the device, without reading them back to the host.
+With `.with_groups_indirect(..)`, the device reads the groups from a buffer written by a previously
+dispatched kernel, like a `VkDispatchIndirectCommand`.
+```no_run
+# #[krnl::macros::module] #[krnl(no_build)] mod kernels {
+# use krnl::macros::kernel;
+# #[kernel] fn fill(#[item] y: &mut u32) { *y = 1; }
+# use krnl::{anyhow::Result, buffer::{Slice, SliceMut}};
+# fn fill_indirect(groups: Slice<u32>, y: SliceMut<u32>) -> Result<()> {
+// groups is [x, 1, 1]
+fill::builder()?
+    .build(y.device())?
+    .with_groups_indirect(groups)?
+    .dispatch(y)
+# }
+# }
+# fn main() {}
+```
+
 # SPIR-V
 [Binary intermediate representation](https://www.khronos.org/spir) for graphics shaders that can be used with [Vulkan](https://www.vulkan.org).
 [Kernels](#Kernels) are implemented as compute shaders targeting Vulkan 1.2.
@@ -510,6 +951,13 @@ debug info, significantly increasing the size of both the cache and kernels at r
 
 The [DebugPrintf Validation Layer](https://github.com/KhronosGroup/Vulkan-ValidationLayers/blob/main/docs/debug_printf.md)
 must be active when the [device](crate::device::Device) is created or DebugPrintf instructions will be removed.
+It can be enabled via the environment, or with
+[`DeviceBuilder::debug_printf`](crate::device::builder::DeviceBuilder::debug_printf).
+Messages can be captured with
+[`DeviceBuilder::message_callback`](crate::device::builder::DeviceBuilder::message_callback), or collected
+with [`DeviceBuilder::capture_messages`](crate::device::builder::DeviceBuilder::capture_messages) and
+taken with [`Device::take_debug_messages`](crate::device::Device::take_debug_messages), including the
+name of the kernel. See [`DebugMessage`](crate::device::DebugMessage).
 
 ```text
 [Device(0@7f6f3c9724d0) crate::kernels::foo<threads=1>] Validation Information: [ UNASSIGNED-DEBUG-PRINTF ]
@@ -547,10 +995,36 @@ called `Result::unwrap()` on an `Err` value: Kernel `crate::kernels::foo<threads
 
 Note: The validation layer can be configured to redirect messages to stdout. This will prevent **krnl** from receiving a callback
 and returning an error in case of a panic.
+
+## Panic Dumps
+Devices created with [`DeviceBuilder::panic_dump`](crate::device::builder::DeviceBuilder::panic_dump) will write the state of
+dispatches that panic to a new directory, named after the kernel, within the provided directory:
+```no_run
+# use krnl::{anyhow::Result, device::Device};
+# fn main() -> Result<()> {
+let device = Device::builder().panic_dump("target/krnl-dumps").build()?;
+# Ok(())
+# }
+```
+- `kernel.txt`: The kernel name, device, threads, groups, spec constants, push constants, and buffers.
+- `push_consts.bin`: The push constants, as passed to the device.
+- `<slice>.bin`: The contents of each buffer after the dispatch, if within
+[`panic_dump_max_bytes`](crate::device::builder::DeviceBuilder::panic_dump_max_bytes).
+
+The error returned by `.dispatch(..)` includes the path of the dump.
+
+## Record and Replay
+To reproduce failures that don't panic, like a miscompile on a particular driver, dispatches can be
+[recorded](crate::device::builder::DeviceBuilder::record) to a trace file and replayed on another
+device, see [`replay`](crate::replay).
 */
 
+#[cfg(feature = "device")]
+use crate::device::{DeviceBuffer, DeviceId, PanicDump, RawDevice, RawKernel};
+#[cfg(feature = "device")]
+use crate::replay::TraceDispatch;
 use crate::{
-    device::{Device, DeviceInner, Features},
+    device::{Device, DeviceInner, Features, Priority, Stream, WaitFuture},
     scalar::{ScalarElem, ScalarType},
 };
 use anyhow::{bail, Result};
@@ -558,41 +1032,774 @@ use anyhow::{bail, Result};
 use dry::macro_wrap;
 #[cfg(feature = "device")]
 use rspirv::{binary::Assemble, dr::Operand};
-use std::{borrow::Cow, sync::Arc};
+use std::{
+    borrow::Cow,
+    fmt::{self, Display},
+    sync::Arc,
+};
 #[cfg(feature = "device")]
 use std::{
     collections::HashMap,
     hash::Hash,
+    path::PathBuf,
     sync::atomic::{AtomicBool, Ordering},
 };
 
+/** A [`#[push]` struct](self#push-structs) with known field names.
+
+Implement with `#[derive(PushConstants)]`, which also implements [`Pod`](bytemuck::Pod). When the
+kernel is built, the offsets of the fields are checked against the compiled kernel, and errors
+name the mismatched field. */
+pub trait PushConstants: bytemuck::Pod {
+    /// The names and offsets in bytes of the fields.
+    const FIELDS: &'static [(&'static str, usize)];
+}
+
+/** Metadata of a kernel.
+
+Returned by `.metadata()` of the generated [KernelBuilder and Kernel](self#kernelbuilder), for
+wrapping kernels generically. */
+#[derive(Clone, Debug)]
+pub struct KernelMetadata {
+    name: String,
+    safe: bool,
+    features: Features,
+    threads: Option<u32>,
+    spec_consts: Vec<SpecConstMetadata>,
+    slices: Vec<SliceMetadata>,
+    push_consts: Vec<PushConstMetadata>,
+    push_struct_size: usize,
+    push_struct_fields: &'static [(&'static str, usize)],
+    push_consts_size: u32,
+}
+
+impl KernelMetadata {
+    /// The name of the kernel, ie "crate::kernels::saxpy".
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// Whether `.dispatch(..)` is safe, see [Safety](self#safety).
+    pub fn safe(&self) -> bool {
+        self.safe
+    }
+    /// The features of the kernel, see [Features](self#features).
+    ///
+    /// For a built kernel, these are the features of the selected [variant](self#variants).
+    pub fn features(&self) -> Features {
+        self.features
+    }
+    /// Threads per group.
+    ///
+    /// None for a builder without `.with_threads(..)`, which defaults to the device.
+    pub fn threads(&self) -> Option<u32> {
+        self.threads
+    }
+    /// The [spec constants](self#specialization), in order of declaration.
+    pub fn spec_consts(&self) -> &[SpecConstMetadata] {
+        &self.spec_consts
+    }
+    /// The slice and item arguments, in order of declaration.
+    pub fn slices(&self) -> &[SliceMetadata] {
+        &self.slices
+    }
+    /// The push constant arguments, in order of declaration.
+    pub fn push_consts(&self) -> &[PushConstMetadata] {
+        &self.push_consts
+    }
+    /// The size in bytes of the [`#[push]` struct](self#push-structs), or 0.
+    pub fn push_struct_size(&self) -> usize {
+        self.push_struct_size
+    }
+    /// The fields of the [`#[push]` struct](self#push-structs), if it implements [`PushConstants`].
+    pub fn push_struct_fields(&self) -> &'static [(&'static str, usize)] {
+        self.push_struct_fields
+    }
+    /// The size in bytes of the push constant block.
+    ///
+    /// The push struct is at offset 0, followed by the push constants, and the offset and length
+    /// of each slice as u32.
+    pub fn push_consts_size(&self) -> u32 {
+        self.push_consts_size
+    }
+}
+
+/// A [spec constant](self#specialization) of a [kernel](KernelMetadata).
+#[derive(Clone, Copy, Debug)]
+pub struct SpecConstMetadata {
+    name: &'static str,
+    scalar_type: ScalarType,
+    value: Option<ScalarElem>,
+}
+
+impl SpecConstMetadata {
+    /// The name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+    /// The scalar type.
+    pub fn scalar_type(&self) -> ScalarType {
+        self.scalar_type
+    }
+    /// The value, if specialized.
+    pub fn value(&self) -> Option<ScalarElem> {
+        self.value
+    }
+}
+
+/// A slice or item argument of a [kernel](KernelMetadata).
+#[derive(Clone, Copy, Debug)]
+pub struct SliceMetadata {
+    name: &'static str,
+    scalar_type: ScalarType,
+    mutable: bool,
+    item: bool,
+    pod: bool,
+}
+
+impl SliceMetadata {
+    /// The name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+    /// The scalar type.
+    pub fn scalar_type(&self) -> ScalarType {
+        self.scalar_type
+    }
+    /// Whether the slice is mutable, ie `SliceMut` or `UnsafeSlice`.
+    pub fn mutable(&self) -> bool {
+        self.mutable
+    }
+    /// Whether the argument is an [item](self#items).
+    pub fn item(&self) -> bool {
+        self.item
+    }
+    /// Whether the elements are [`Pod`](crate::scalar::Pod) [structs](self#structs), with scalar type `U8`.
+    pub fn pod(&self) -> bool {
+        self.pod
+    }
+}
+
+/// A push constant argument of a [kernel](KernelMetadata).
+#[derive(Clone, Copy, Debug)]
+pub struct PushConstMetadata {
+    name: &'static str,
+    scalar_type: ScalarType,
+    offset: u32,
+}
+
+impl PushConstMetadata {
+    /// The name.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+    /// The scalar type.
+    pub fn scalar_type(&self) -> ScalarType {
+        self.scalar_type
+    }
+    /// The offset in bytes in the push constant block.
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+}
+
 #[cfg_attr(not(feature = "device"), allow(dead_code))]
 #[derive(Clone, Debug)]
 pub(crate) struct KernelDesc {
     pub(crate) name: Cow<'static, str>,
     pub(crate) spirv: Vec<u32>,
     features: Features,
+    safe: bool,
     pub(crate) threads: u32,
     spec_descs: &'static [SpecDesc],
     pub(crate) slice_descs: &'static [SliceDesc],
     push_descs: &'static [PushDesc],
+    push_struct_size: usize,
+    push_struct_align: usize,
+    push_struct_fields: &'static [(&'static str, usize)],
+    spec_consts: Vec<(&'static str, ScalarElem)>,
+    pub(crate) coverage: Vec<BlockCoverage>,
+}
+
+/// Execution counts of a kernel instrumented for [coverage](self#coverage).
+#[derive(Clone, Debug)]
+pub struct KernelCoverage {
+    name: String,
+    blocks: Vec<BlockCoverage>,
+    #[cfg(feature = "device")]
+    device: DeviceId,
 }
 
+impl KernelCoverage {
+    /// The name of the kernel.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// The blocks of the kernel, in the order of the SPIR-V.
+    pub fn blocks(&self) -> &[BlockCoverage] {
+        &self.blocks
+    }
+    /// The blocks that were not executed.
+    pub fn unreached(&self) -> impl Iterator<Item = &BlockCoverage> {
+        self.blocks.iter().filter(|block| block.count == 0)
+    }
+}
+
+/// Prints the name, the number of blocks executed, and the count of each block.
+impl Display for KernelCoverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let reached = self.blocks.len() - self.unreached().count();
+        write!(f, "{}: {reached}/{} blocks", self.name, self.blocks.len())?;
+        for block in self.blocks.iter() {
+            write!(f, "\n  {block}")?;
+        }
+        Ok(())
+    }
+}
+
+/// The execution count of a basic block.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BlockCoverage {
+    label: u32,
+    location: Option<String>,
+    count: u64,
+}
+
+impl BlockCoverage {
+    /// The id of the `OpLabel` of the block.
+    pub fn label(&self) -> u32 {
+        self.label
+    }
+    /// The source location of the block, as "file:line:column", if available.
+    pub fn location(&self) -> Option<&str> {
+        self.location.as_deref()
+    }
+    /// The number of times the block was executed.
+    pub fn count(&self) -> u64 {
+        self.count
+    }
+}
+
+/// Prints `%label location: count`.
+impl Display for BlockCoverage {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "%{}", self.label)?;
+        if let Some(location) = self.location.as_ref() {
+            write!(f, " {location}")?;
+        }
+        write!(f, ": {}", self.count)
+    }
+}
+
+#[cfg(feature = "device")]
+static COVERAGE: std::sync::Mutex<Vec<KernelCoverage>> = std::sync::Mutex::new(Vec::new());
+
+/** Execution counts of kernels dispatched on `device`.
+
+Only kernels instrumented with `krnlc --coverage` are included, see [Coverage](self#coverage). Counts
+accumulate across dispatches of each kernel, until [`reset_coverage`] is called. The host returns an
+empty Vec. */
+pub fn coverage(device: &Device) -> Vec<KernelCoverage> {
+    match device.inner() {
+        DeviceInner::Host => Vec::new(),
+        #[cfg(feature = "device")]
+        DeviceInner::Device(device) => {
+            let id = device.id();
+            COVERAGE
+                .lock()
+                .unwrap()
+                .iter()
+                .filter(|kernel| kernel.device == id)
+                .cloned()
+                .collect()
+        }
+    }
+}
+
+/// Resets the [coverage] of kernels dispatched on `device`.
+pub fn reset_coverage(device: &Device) {
+    match device.inner() {
+        DeviceInner::Host => (),
+        #[cfg(feature = "device")]
+        DeviceInner::Device(device) => {
+            let id = device.id();
+            COVERAGE
+                .lock()
+                .unwrap()
+                .retain(|kernel| kernel.device != id);
+        }
+    }
+}
+
+/// Adds the counts in `buffer` to the coverage of the kernel.
+#[cfg(feature = "device")]
+fn record_coverage(device: &RawDevice, desc: &KernelDesc, buffer: &DeviceBuffer) -> Result<()> {
+    device.wait()?;
+    let mut bytes = vec![0u8; buffer.len()];
+    buffer.download(&mut bytes)?;
+    let id = device.id();
+    let mut coverage = COVERAGE.lock().unwrap();
+    let index = if let Some(index) = coverage
+        .iter()
+        .position(|kernel| kernel.device == id && kernel.name == desc.name)
+    {
+        index
+    } else {
+        coverage.push(KernelCoverage {
+            name: desc.name.to_string(),
+            blocks: desc.coverage.clone(),
+            device: id,
+        });
+        coverage.len() - 1
+    };
+    for (block, count) in coverage[index].blocks.iter_mut().zip(bytes.chunks_exact(4)) {
+        block.count += u32::from_ne_bytes(count.try_into().unwrap()) as u64;
+    }
+    Ok(())
+}
+
+/// The blocks instrumented by `krnlc --coverage`, ordered by counter.
 #[cfg(feature = "device")]
+fn coverage_blocks(module: &rspirv::dr::Module) -> Vec<BlockCoverage> {
+    use rspirv::spirv::Op;
+
+    let Some(var) = module.debug_names.iter().find_map(|inst| {
+        match (inst.class.opcode, inst.operands.as_slice()) {
+            (Op::Name, [Operand::IdRef(id), Operand::LiteralString(name)])
+                if name == "__krnl_coverage" =>
+            {
+                Some(*id)
+            }
+            _ => None,
+        }
+    }) else {
+        return Vec::new();
+    };
+    let constants: HashMap<u32, u32> = module
+        .types_global_values
+        .iter()
+        .filter_map(|inst| match (inst.class.opcode, inst.operands.as_slice()) {
+            (Op::Constant, [Operand::LiteralInt32(value)]) => Some((inst.result_id?, *value)),
+            _ => None,
+        })
+        .collect();
+    let strings: HashMap<u32, &str> = module
+        .debug_string_source
+        .iter()
+        .filter_map(|inst| match (inst.class.opcode, inst.operands.as_slice()) {
+            (Op::String, [Operand::LiteralString(string)]) => {
+                Some((inst.result_id?, string.as_str()))
+            }
+            _ => None,
+        })
+        .collect();
+    let mut blocks = Vec::new();
+    for block in module.functions.iter().flat_map(|f| f.blocks.iter()) {
+        let label = block
+            .label
+            .as_ref()
+            .and_then(|inst| inst.result_id)
+            .unwrap_or_default();
+        let mut location = None;
+        let mut index = None;
+        for inst in block.instructions.iter() {
+            match (inst.class.opcode, inst.operands.as_slice()) {
+                (
+                    Op::Line,
+                    [Operand::IdRef(file), Operand::LiteralInt32(line), Operand::LiteralInt32(column)],
+                ) if location.is_none() => {
+                    let file = strings.get(file).copied().unwrap_or("?");
+                    location.replace(format!("{file}:{line}:{column}"));
+                }
+                (Op::AccessChain, [Operand::IdRef(base), .., Operand::IdRef(i)])
+                    if *base == var && index.is_none() =>
+                {
+                    index = constants.get(i).copied();
+                }
+                _ => (),
+            }
+        }
+        if let Some(index) = index {
+            blocks.push((
+                index,
+                BlockCoverage {
+                    label,
+                    location,
+                    count: 0,
+                },
+            ));
+        }
+    }
+    blocks.sort_by_key(|(index, _)| *index);
+    blocks.into_iter().map(|(_, block)| block).collect()
+}
+
+#[cfg(feature = "device")]
+fn find_type(module: &rspirv::dr::Module, id: u32) -> Option<&rspirv::dr::Instruction> {
+    module
+        .types_global_values
+        .iter()
+        .find(|inst| inst.result_id == Some(id))
+}
+
+/// The offsets of the members of struct `id`, in order.
+#[cfg(feature = "device")]
+fn member_offsets(module: &rspirv::dr::Module, id: u32) -> Vec<u32> {
+    use rspirv::spirv::{Decoration, Op};
+
+    let mut offsets: Vec<(u32, u32)> = module
+        .annotations
+        .iter()
+        .filter_map(|inst| match (inst.class.opcode, inst.operands.as_slice()) {
+            (
+                Op::MemberDecorate,
+                [Operand::IdRef(struct_id), Operand::LiteralInt32(member), Operand::Decoration(Decoration::Offset), Operand::LiteralInt32(offset)],
+            ) if *struct_id == id => Some((*member, *offset)),
+            _ => None,
+        })
+        .collect();
+    offsets.sort_unstable();
+    offsets.into_iter().map(|(_, offset)| offset).collect()
+}
+
+/// Checks the `found` offsets on the device against the `expected` fields and offsets of the host.
+#[cfg(feature = "device")]
+fn validate_offsets(
+    name: &str,
+    what: &str,
+    found: &[u32],
+    expected: &[(&str, usize)],
+) -> Result<()> {
+    if found.len() != expected.len() {
+        bail!("Kernel `{name}` {what} fields have offsets {found:?} on the device, expected {expected:?}!");
+    }
+    for (found, (field, expected)) in found.iter().zip(expected) {
+        if *found as usize != *expected {
+            bail!("Kernel `{name}` {what} field `{field}` has offset {found} on the device, expected {expected}!");
+        }
+    }
+    Ok(())
+}
+
+/// The size and alignment of type `id` in the push constant block, or None if unknown.
+///
+/// Errors if the members of a struct overlap or are misaligned.
+#[cfg(feature = "device")]
+fn type_layout(module: &rspirv::dr::Module, name: &str, id: u32) -> Result<Option<(u32, u32)>> {
+    use rspirv::spirv::{Decoration, Op};
+
+    let Some(inst) = find_type(module, id) else {
+        return Ok(None);
+    };
+    let layout = match (inst.class.opcode, inst.operands.as_slice()) {
+        (Op::TypeInt, [Operand::LiteralInt32(width), ..])
+        | (Op::TypeFloat, [Operand::LiteralInt32(width), ..]) => Some((width / 8, width / 8)),
+        (Op::TypeVector, [Operand::IdRef(component), Operand::LiteralInt32(count)]) => {
+            type_layout(module, name, *component)?.map(|(size, align)| (size * count, align))
+        }
+        (Op::TypeArray, [Operand::IdRef(element), Operand::IdRef(len)]) => {
+            let len = find_type(module, *len).and_then(|inst| {
+                match (inst.class.opcode, inst.operands.as_slice()) {
+                    (Op::Constant, [Operand::LiteralInt32(len)]) => Some(*len),
+                    _ => None,
+                }
+            });
+            let stride = module.annotations.iter().find_map(|inst| {
+                match (inst.class.opcode, inst.operands.as_slice()) {
+                    (
+                        Op::Decorate,
+                        [Operand::IdRef(array), Operand::Decoration(Decoration::ArrayStride), Operand::LiteralInt32(stride)],
+                    ) if *array == id => Some(*stride),
+                    _ => None,
+                }
+            });
+            match (type_layout(module, name, *element)?, len) {
+                (Some((size, align)), Some(len)) => Some((stride.unwrap_or(size) * len, align)),
+                _ => None,
+            }
+        }
+        (Op::TypeStruct, members) => {
+            let offsets = member_offsets(module, id);
+            if offsets.len() != members.len() {
+                return Ok(None);
+            }
+            let (mut size, mut align) = (0, 1);
+            for (member, offset) in members.iter().zip(offsets) {
+                let Some(member) = member.id_ref_any() else {
+                    return Ok(None);
+                };
+                let Some((member_size, member_align)) = type_layout(module, name, member)? else {
+                    return Ok(None);
+                };
+                if offset < size || offset % member_align != 0 {
+                    bail!("Kernel `{name}` push constants have a misaligned member at offset {offset} on the device!");
+                }
+                size = offset + member_size;
+                align = align.max(member_align);
+            }
+            while size % align != 0 {
+                size += 1;
+            }
+            Some((size, align))
+        }
+        _ => None,
+    };
+    Ok(layout)
+}
+
 impl KernelDesc {
+    // The push constant block on the device is padded to its alignment, ie 8 with 64 bit push
+    // constants, and the range must cover it.
     pub(crate) fn push_consts_range(&self) -> u32 {
+        let mut size = self.push_struct_size;
+        let mut align = self.push_struct_align.max(4);
+        for push_desc in self.push_descs.iter() {
+            let width = push_desc.scalar_type.size();
+            while size % width != 0 {
+                size += 1;
+            }
+            size += width;
+            align = align.max(width);
+        }
+        while size % 4 != 0 {
+            size += 1;
+        }
+        for slice_desc in self.slice_descs.iter() {
+            let width = slice_desc.index_width();
+            while size % width != 0 {
+                size += 1;
+            }
+            size += 2 * width;
+            align = align.max(width);
+        }
+        while size % align != 0 {
+            size += 1;
+        }
+        size.try_into().unwrap()
+    }
+    fn push_consts_offsets(&self) -> Vec<u32> {
+        let mut offsets = Vec::with_capacity(
+            usize::from(self.push_struct_size > 0)
+                + self.push_descs.len()
+                + 2 * self.slice_descs.len(),
+        );
         let mut size = 0;
+        if self.push_struct_size > 0 {
+            offsets.push(0);
+            size += self.push_struct_size;
+        }
         for push_desc in self.push_descs.iter() {
-            while size % push_desc.scalar_type.size() != 0 {
+            let width = push_desc.scalar_type.size();
+            while size % width != 0 {
+                size += 1;
+            }
+            offsets.push(size.try_into().unwrap());
+            size += width;
+        }
+        while size % 4 != 0 {
+            size += 1;
+        }
+        for slice_desc in self.slice_descs.iter() {
+            let width = slice_desc.index_width();
+            while size % width != 0 {
                 size += 1;
             }
-            size += push_desc.scalar_type.size()
+            for _ in 0..2 {
+                offsets.push(size.try_into().unwrap());
+                size += width;
+            }
+        }
+        offsets
+    }
+    fn metadata(&self, threads: Option<u32>, spec_consts: &[ScalarElem]) -> KernelMetadata {
+        let spec_consts = self
+            .spec_descs
+            .iter()
+            .enumerate()
+            .map(|(i, desc)| SpecConstMetadata {
+                name: desc.name,
+                scalar_type: desc.scalar_type,
+                value: spec_consts
+                    .get(i)
+                    .copied()
+                    .or_else(|| self.spec_consts.get(i).map(|(_, value)| *value)),
+            })
+            .collect();
+        let slices = self
+            .slice_descs
+            .iter()
+            .map(|desc| SliceMetadata {
+                name: desc.name,
+                scalar_type: desc.scalar_type,
+                mutable: desc.mutable,
+                item: desc.item,
+                pod: desc.pod,
+            })
+            .collect();
+        let offsets = self.push_consts_offsets();
+        let push_consts = self
+            .push_descs
+            .iter()
+            .zip(
+                offsets
+                    .into_iter()
+                    .skip(usize::from(self.push_struct_size > 0)),
+            )
+            .map(|(desc, offset)| PushConstMetadata {
+                name: desc.name,
+                scalar_type: desc.scalar_type,
+                offset,
+            })
+            .collect();
+        KernelMetadata {
+            name: self.name.to_string(),
+            safe: self.safe,
+            features: self.features,
+            threads,
+            spec_consts,
+            slices,
+            push_consts,
+            push_struct_size: self.push_struct_size,
+            push_struct_fields: self.push_struct_fields,
+            push_consts_size: self.push_consts_range(),
+        }
+    }
+}
+
+#[cfg(feature = "device")]
+impl KernelDesc {
+    pub(crate) fn features(&self) -> Features {
+        self.features
+    }
+    // Checks that the push constant block matches the host, which may differ if the `#[push]`
+    // struct isn't laid out identically for the device.
+    fn validate_push_consts(&self, module: &rspirv::dr::Module) -> Result<()> {
+        use rspirv::spirv::{Op, StorageClass};
+
+        let Some(pointer_id) = module.types_global_values.iter().find_map(|inst| {
+            if inst.class.opcode == Op::Variable
+                && inst.operands.first() == Some(&Operand::StorageClass(StorageClass::PushConstant))
+            {
+                inst.result_type
+            } else {
+                None
+            }
+        }) else {
+            return Ok(());
+        };
+        let Some(mut struct_id) = find_type(module, pointer_id)
+            .and_then(|inst| inst.operands.get(1))
+            .and_then(|operand| operand.id_ref_any())
+        else {
+            return Ok(());
+        };
+        // The push constants may be wrapped in a block.
+        if let Some(inst) = find_type(module, struct_id) {
+            if let [Operand::IdRef(member_id)] = inst.operands.as_slice() {
+                if find_type(module, *member_id).map(|inst| inst.class.opcode)
+                    == Some(Op::TypeStruct)
+                {
+                    struct_id = *member_id;
+                }
+            }
+        }
+        let name = &*self.name;
+        let found = member_offsets(module, struct_id);
+        let mut offsets = self.push_consts_offsets();
+        let mut fields: Vec<String> = (self.push_struct_size > 0)
+            .then(|| "#[push]".to_string())
+            .into_iter()
+            .chain(self.push_descs.iter().map(|desc| desc.name.to_string()))
+            .collect();
+        // Without a push struct, the push constants are padded to 4 bytes with u8 fields.
+        let push_consts = self.push_descs.len();
+        if self.push_struct_size == 0 && push_consts > 0 {
+            let size: usize = self
+                .push_descs
+                .iter()
+                .map(|desc| desc.scalar_type.size())
+                .sum();
+            let pads = (4 - size % 4) % 4;
+            let start = offsets[push_consts - 1]
+                + self.push_descs[push_consts - 1].scalar_type.size() as u32;
+            offsets.splice(push_consts..push_consts, start..start + pads as u32);
+            fields.extend((0..pads).map(|i| format!("__krnl_pad{i}")));
+        }
+        fields.extend(self.slice_descs.iter().flat_map(|desc| {
+            [
+                format!("{}.offset", desc.name),
+                format!("{}.len", desc.name),
+            ]
+        }));
+        let expected: Vec<_> = fields
+            .iter()
+            .map(String::as_str)
+            .zip(offsets.into_iter().map(|x| x as usize))
+            .collect();
+        validate_offsets(name, "push constants", &found, &expected)?;
+        // The `#[push]` struct is the first member, check its fields if the names are known.
+        let push_struct_id = find_type(module, struct_id)
+            .and_then(|inst| inst.operands.first())
+            .and_then(|operand| operand.id_ref_any())
+            .filter(|id| {
+                find_type(module, *id).map(|inst| inst.class.opcode) == Some(Op::TypeStruct)
+            })
+            .filter(|_| self.push_struct_size > 0);
+        if let Some(push_struct_id) = push_struct_id {
+            if !self.push_struct_fields.is_empty() {
+                let found = member_offsets(module, push_struct_id);
+                validate_offsets(name, "push struct", &found, self.push_struct_fields)?;
+            }
+            if let Some((size, align)) = type_layout(module, name, push_struct_id)? {
+                let (expected_size, expected_align) =
+                    (self.push_struct_size, self.push_struct_align);
+                if size as usize != expected_size {
+                    bail!("Kernel `{name}` push struct is {size} bytes on the device, expected {expected_size}!");
+                }
+                if expected_align % align as usize != 0 {
+                    bail!("Kernel `{name}` push struct has alignment {align} on the device, expected {expected_align}!");
+                }
+            }
         }
-        while size % 4 != 0 {
-            size += 1;
+        // The range must cover the block, including its padding.
+        if let Some((size, _)) = type_layout(module, name, struct_id)? {
+            let range = self.push_consts_range();
+            if size > range {
+                bail!("Kernel `{name}` push constants are {size} bytes on the device, expected at most {range}!");
+            }
         }
-        size += self.slice_descs.len() * 2 * 4;
-        size.try_into().unwrap()
+        Ok(())
+    }
+    // Features required by the spirv, in addition to those detected by **krnlc**. **krnlc** declares
+    // the GroupNonUniform capabilities for all kernels, so subgroup operations are checked instead.
+    fn required_features(&self) -> Features {
+        const OP_CAPABILITY: u32 = 17;
+        const OP_GROUP_NON_UNIFORM_ELECT: u32 = 333;
+        const OP_GROUP_NON_UNIFORM_ALL_EQUAL: u32 = 336;
+        const OP_GROUP_NON_UNIFORM_FIND_MSB: u32 = 344;
+        const OP_GROUP_NON_UNIFORM_SHUFFLE_XOR: u32 = 346;
+        const OP_GROUP_NON_UNIFORM_I_ADD: u32 = 349;
+        const OP_GROUP_NON_UNIFORM_LOGICAL_XOR: u32 = 364;
+        const ATOMIC_FLOAT32_ADD_EXT: u32 = 6033;
+        let mut features = Features::empty();
+        let mut index = 5;
+        while let Some(word) = self.spirv.get(index).copied() {
+            let (words, opcode) = (word >> 16, word & 0xFFFF);
+            features = match opcode {
+                OP_CAPABILITY if self.spirv.get(index + 1) == Some(&ATOMIC_FLOAT32_ADD_EXT) => {
+                    features.with_shader_atomic_float(true)
+                }
+                OP_GROUP_NON_UNIFORM_ELECT => features.with_subgroup_basic(true),
+                334..=OP_GROUP_NON_UNIFORM_ALL_EQUAL => features.with_subgroup_vote(true),
+                337..=OP_GROUP_NON_UNIFORM_FIND_MSB => features.with_subgroup_ballot(true),
+                345..=OP_GROUP_NON_UNIFORM_SHUFFLE_XOR => features.with_subgroup_shuffle(true),
+                OP_GROUP_NON_UNIFORM_I_ADD..=OP_GROUP_NON_UNIFORM_LOGICAL_XOR => {
+                    features.with_subgroup_arithmetic(true)
+                }
+                _ => features,
+            };
+            index += (words as usize).max(1);
+        }
+        features
     }
     fn specialize(
         &self,
@@ -656,18 +1863,172 @@ impl KernelDesc {
                 }
             }
         }
+        self.validate_push_consts(&module)?;
+        let coverage = coverage_blocks(&module);
         if !debug_printf {
             strip_debug_printf(&mut module);
         }
         let spirv = module.assemble();
+        let spec_consts = self
+            .spec_descs
+            .iter()
+            .map(|desc| desc.name)
+            .zip(spec_consts.iter().copied())
+            .collect();
         Ok(Self {
             name,
             spirv,
             spec_descs: &[],
             threads,
+            spec_consts,
+            coverage,
             ..self.clone()
         })
     }
+    #[cfg(feature = "spirv-val")]
+    pub(crate) fn validate_spirv(&self) -> Result<()> {
+        use spirv_tools::{val::Validator, TargetEnv};
+        let validator = spirv_tools::val::create(Some(TargetEnv::Vulkan_1_2));
+        if let Err(e) = validator.validate(&self.spirv, None) {
+            let name = &self.name;
+            if let Some(diagnostic) = e.diagnostic.as_ref() {
+                let index = diagnostic.index;
+                let message = diagnostic.message.trim_end();
+                bail!("Kernel `{name}` failed validation at instruction {index}: {message}");
+            }
+            bail!("Kernel `{name}` failed validation: {:?}", e.inner);
+        }
+        Ok(())
+    }
+}
+
+/// Writes the state of a dispatch to a new directory in `panic_dump.dir`, returning the path.
+#[cfg(feature = "device")]
+fn panic_dump(
+    panic_dump: &PanicDump,
+    desc: &KernelDesc,
+    groups: u32,
+    buffers: &[DeviceBuffer],
+    push_consts: &[u8],
+) -> Result<PathBuf> {
+    use std::fmt::Write;
+
+    let kernel = desc.name.split('<').next().unwrap();
+    let kernel: String = kernel
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect();
+    std::fs::create_dir_all(&panic_dump.dir)?;
+    let path = (0..)
+        .map(|i| panic_dump.dir.join(format!("{kernel}-{i}")))
+        .find(|path| std::fs::create_dir(path).is_ok())
+        .unwrap();
+    let mut text = String::new();
+    writeln!(&mut text, "kernel: {}", desc.name)?;
+    if let Some(buffer) = buffers.first() {
+        writeln!(&mut text, "device: {:?}", buffer.device())?;
+    }
+    writeln!(&mut text, "threads: {}", desc.threads)?;
+    writeln!(&mut text, "groups: {groups}")?;
+    writeln!(&mut text, "spec_consts:")?;
+    for (name, value) in desc.spec_consts.iter() {
+        writeln!(&mut text, "  {name}: {value:?}")?;
+    }
+    writeln!(&mut text, "push_consts: {push_consts:02x?}")?;
+    std::fs::write(path.join("push_consts.bin"), push_consts)?;
+    writeln!(&mut text, "buffers:")?;
+    let mut bytes = 0;
+    for (buffer, slice_desc) in buffers.iter().zip(desc.slice_descs.iter()) {
+        let name = slice_desc.name;
+        let scalar_type = slice_desc.scalar_type.name();
+        let (offset, len) = (buffer.offset(), buffer.len());
+        write!(
+            &mut text,
+            "  {name}: {scalar_type}, offset = {offset}, len = {len} bytes"
+        )?;
+        if bytes + len > panic_dump.max_bytes {
+            writeln!(&mut text, ", skipped (exceeds max_bytes)")?;
+            continue;
+        }
+        bytes += len;
+        let mut data = vec![0u8; len];
+        buffer.download(&mut data)?;
+        std::fs::write(path.join(format!("{name}.bin")), data)?;
+        writeln!(&mut text, ", {name}.bin")?;
+    }
+    std::fs::write(path.join("kernel.txt"), text)?;
+    Ok(path)
+}
+
+/// Reads the groups of an indirect dispatch.
+#[cfg(feature = "device")]
+fn indirect_groups(indirect: &DeviceBuffer) -> Result<u32> {
+    let mut bytes = [0; 3 * 4];
+    indirect.download(&mut bytes)?;
+    Ok(u32::from_ne_bytes(bytes[..4].try_into().unwrap()))
+}
+
+/// Dispatches a [recorded](crate::replay) kernel, with spec constants already specialized.
+#[cfg(feature = "device")]
+pub(crate) unsafe fn replay(
+    device: RawDevice,
+    dispatch: &TraceDispatch,
+    slice_descs: &'static [SliceDesc],
+    buffers: &[DeviceBuffer],
+) -> Result<()> {
+    let info = device.info().clone();
+    let spirv = dispatch.spirv();
+    // Pipelines depend on the SPIR-V, threads, and the mutability of slices.
+    let spec_bytes = bytemuck::cast_slice(spirv)
+        .iter()
+        .copied()
+        .chain(dispatch.threads().to_ne_bytes())
+        .chain(slice_descs.iter().map(|x| u8::from(x.mutable)))
+        .collect();
+    let key = KernelKey { id: 0, spec_bytes };
+    let kernel = RawKernel::cached(device.clone(), key, || {
+        let mut module = rspirv::dr::load_words(spirv).map_err(|e| anyhow::anyhow!("{e}"))?;
+        let coverage = coverage_blocks(&module);
+        if !info.debug_printf() {
+            strip_debug_printf(&mut module);
+        }
+        let push_struct_size = dispatch.push_consts().len() - 2 * 4 * slice_descs.len();
+        Ok(Arc::new(KernelDesc {
+            name: dispatch.name().to_string().into(),
+            spirv: module.assemble(),
+            features: dispatch.features(),
+            safe: false,
+            threads: dispatch.threads(),
+            spec_descs: &[],
+            slice_descs,
+            push_descs: &[],
+            push_struct_size,
+            push_struct_align: 4,
+            push_struct_fields: &[],
+            spec_consts: Vec::new(),
+            coverage,
+        }))
+    })?;
+    let mut buffers = buffers.to_vec();
+    if !kernel.desc().coverage.is_empty() {
+        let len = kernel.desc().coverage.len() * 4;
+        let buffer = unsafe { DeviceBuffer::uninit(device.clone(), len)? };
+        buffer.upload(&vec![0; len])?;
+        buffers.push(buffer);
+    }
+    unsafe {
+        kernel.dispatch(
+            dispatch.groups(),
+            None,
+            &buffers,
+            dispatch.push_consts().to_vec(),
+            None,
+            Priority::Normal,
+            0,
+        )?;
+    }
+    device.wait()?;
+    Ok(())
 }
 
 #[cfg(feature = "device")]
@@ -729,8 +2090,32 @@ pub mod __private {
     use crate::device::{DeviceBuffer, RawKernel};
     use crate::{
         buffer::{ScalarSlice, ScalarSliceMut, Slice, SliceMut},
-        scalar::Scalar,
+        scalar::Pod,
     };
+    use std::mem::size_of;
+
+    // Autoref specialization, `#[push]` structs that only implement `Pod` have no field names.
+    pub struct PushStructFields<T>(pub std::marker::PhantomData<T>);
+
+    pub trait PushStructFieldsDerived {
+        fn push_struct_fields(&self) -> &'static [(&'static str, usize)];
+    }
+
+    impl<T: PushConstants> PushStructFieldsDerived for PushStructFields<T> {
+        fn push_struct_fields(&self) -> &'static [(&'static str, usize)] {
+            T::FIELDS
+        }
+    }
+
+    pub trait PushStructFieldsPod {
+        fn push_struct_fields(&self) -> &'static [(&'static str, usize)];
+    }
+
+    impl<T> PushStructFieldsPod for &PushStructFields<T> {
+        fn push_struct_fields(&self) -> &'static [(&'static str, usize)] {
+            &[]
+        }
+    }
 
     #[derive(Clone, Copy)]
     pub struct KernelDesc {
@@ -741,6 +2126,8 @@ pub mod __private {
         spec_descs: &'static [SpecDesc],
         slice_descs: &'static [SliceDesc],
         push_descs: &'static [PushDesc],
+        // Kernels of the module, including variants.
+        kernels: &'static [KernelDesc],
     }
 
     #[derive(Clone, Copy)]
@@ -768,11 +2155,14 @@ pub mod __private {
         true
     }
 
-    pub const fn find_kernel(name: &str, kernels: &[KernelDesc]) -> Option<KernelDesc> {
+    pub const fn find_kernel(name: &str, kernels: &'static [KernelDesc]) -> Option<KernelDesc> {
         let mut i = 0;
         while i < kernels.len() {
             if bytes_eq(name.as_bytes(), kernels[i].name.as_bytes()) {
-                return Some(kernels[i]);
+                return Some(KernelDesc {
+                    kernels,
+                    ..kernels[i]
+                });
             }
             i += 1;
         }
@@ -820,6 +2210,7 @@ pub mod __private {
                 spec_descs,
                 slice_descs,
                 push_descs,
+                kernels: &[],
             }
         }
         const fn check_declaration(
@@ -907,6 +2298,9 @@ pub mod __private {
         pub scalar_type: ScalarType,
         pub mutable: bool,
         pub item: bool,
+        pub index64: bool,
+        // A slice of `Pod` structs, with `scalar_type` U8.
+        pub pod: bool,
     }
 
     impl SliceDesc {
@@ -915,6 +2309,16 @@ pub mod __private {
                 && scalar_type_const_eq(self.scalar_type, other.scalar_type)
                 && self.mutable == other.mutable
                 && self.item == other.item
+                && self.index64 == other.index64
+                && self.pod == other.pod
+        }
+        // The offset and len push constants, u64 for `Slice64` and `UnsafeSlice64`.
+        pub(crate) fn index_width(&self) -> usize {
+            if self.index64 {
+                8
+            } else {
+                4
+            }
         }
     }
 
@@ -949,40 +2353,91 @@ pub mod __private {
     #[cfg_attr(not(feature = "device"), allow(dead_code))]
     #[derive(Clone)]
     pub struct KernelBuilder {
-        id: usize,
-        desc: Arc<super::KernelDesc>,
+        // Variants, keyed by spirv.
+        descs: Vec<(usize, Arc<super::KernelDesc>)>,
         spec_consts: Vec<ScalarElem>,
         threads: Option<u32>,
+        exact_items: bool,
     }
 
     impl KernelBuilder {
         pub fn from_desc(desc: KernelDesc) -> Result<Self, String> {
-            let KernelDesc {
-                name,
-                spirv,
-                features,
-                safe: _,
-                spec_descs,
-                slice_descs,
-                push_descs,
-            } = desc;
-            let spirv = decode_spirv(name, spirv)?;
-            let desc = super::KernelDesc {
-                name: name.into(),
-                spirv,
-                features,
-                threads: 0,
-                spec_descs,
-                slice_descs,
-                push_descs,
-            };
+            let variants = desc
+                .kernels
+                .iter()
+                .filter(|x| x.name == desc.name && x.spirv.as_ptr() != desc.spirv.as_ptr());
+            let descs = std::iter::once(&desc)
+                .chain(variants)
+                .map(|desc| {
+                    let KernelDesc {
+                        name,
+                        spirv,
+                        features,
+                        safe,
+                        spec_descs,
+                        slice_descs,
+                        push_descs,
+                        kernels: _,
+                    } = *desc;
+                    let id = spirv.as_ptr() as usize;
+                    let spirv = decode_spirv(name, spirv)?;
+                    let desc = super::KernelDesc {
+                        name: name.into(),
+                        spirv,
+                        features,
+                        safe,
+                        threads: 0,
+                        spec_descs,
+                        slice_descs,
+                        push_descs,
+                        push_struct_size: 0,
+                        push_struct_align: 1,
+                        push_struct_fields: &[],
+                        spec_consts: Vec::new(),
+                        coverage: Vec::new(),
+                    };
+                    #[cfg(feature = "device")]
+                    let desc = super::KernelDesc {
+                        features: desc.features.union(&desc.required_features()),
+                        ..desc
+                    };
+                    Ok((id, Arc::new(desc)))
+                })
+                .collect::<Result<_, String>>()?;
             Ok(Self {
-                id: name.as_ptr() as usize,
-                desc: desc.into(),
+                descs,
                 spec_consts: Vec::new(),
                 threads: None,
+                exact_items: false,
             })
         }
+        pub fn with_exact_items(self, exact_items: bool) -> Self {
+            Self {
+                exact_items,
+                ..self
+            }
+        }
+        pub fn with_push_struct(mut self, size: usize, align: usize) -> Self {
+            for (_, desc) in self.descs.iter_mut() {
+                let desc = Arc::make_mut(desc);
+                desc.push_struct_size = size;
+                desc.push_struct_align = align;
+            }
+            self
+        }
+        pub fn with_push_struct_fields(mut self, fields: &'static [(&'static str, usize)]) -> Self {
+            for (_, desc) in self.descs.iter_mut() {
+                Arc::make_mut(desc).push_struct_fields = fields;
+            }
+            self
+        }
+        pub fn with_features(mut self, features: Features) -> Self {
+            for (_, desc) in self.descs.iter_mut() {
+                let desc = Arc::make_mut(desc);
+                desc.features = desc.features.union(&features);
+            }
+            self
+        }
         pub fn with_threads(self, threads: u32) -> Self {
             Self {
                 threads: Some(threads),
@@ -990,10 +2445,9 @@ pub mod __private {
             }
         }
         pub fn specialize(self, spec_consts: &[ScalarElem]) -> Self {
-            assert_eq!(spec_consts.len(), self.desc.spec_descs.len());
-            for (spec_const, spec_desc) in
-                spec_consts.iter().copied().zip(self.desc.spec_descs.iter())
-            {
+            let desc = &self.descs[0].1;
+            assert_eq!(spec_consts.len(), desc.spec_descs.len());
+            for (spec_const, spec_desc) in spec_consts.iter().copied().zip(desc.spec_descs.iter()) {
                 assert_eq!(spec_const.scalar_type(), spec_desc.scalar_type);
             }
             Self {
@@ -1001,28 +2455,58 @@ pub mod __private {
                 ..self
             }
         }
+        pub fn metadata(&self) -> KernelMetadata {
+            self.descs[0].1.metadata(self.threads, &self.spec_consts)
+        }
         pub fn build(&self, device: Device) -> Result<Kernel> {
             match device.inner() {
                 DeviceInner::Host => {
-                    bail!("Kernel `{}` expected device, found host!", self.desc.name);
+                    bail!(
+                        "Kernel `{}` expected device, found host!",
+                        self.descs[0].1.name
+                    );
                 }
                 #[cfg(feature = "device")]
                 DeviceInner::Device(device) => {
-                    let desc = &self.desc;
-                    let name = &desc.name;
-                    let features = desc.features;
                     let info = device.info();
                     let device_features = info.features();
-                    if !device_features.contains(&features) {
-                        bail!("Kernel {name} requires {features:?}, {device:?} has {device_features:?}!");
-                    }
+                    // Select the supported variant with the most features,
+                    // preferring the first on ties.
+                    let variant = self
+                        .descs
+                        .iter()
+                        .rev()
+                        .filter(|(_, desc)| device_features.contains(&desc.features))
+                        .max_by_key(|(_, desc)| desc.features.names().count());
+                    let Some((id, desc)) = variant else {
+                        let (_, desc) = self
+                            .descs
+                            .iter()
+                            .min_by_key(|(_, desc)| {
+                                desc.features.difference(&device_features).names().count()
+                            })
+                            .unwrap();
+                        let name = &desc.name;
+                        let missing: Vec<_> =
+                            desc.features.difference(&device_features).names().collect();
+                        let missing = missing.join(", ");
+                        bail!("Kernel `{name}` requires {missing}, which is not supported by {device:?}!");
+                    };
+                    let name = &desc.name;
                     let threads = self.threads.unwrap_or(info.default_threads());
+                    if threads == 0 {
+                        bail!("Kernel `{name}` threads must be greater than 0!");
+                    }
                     let max_threads = info.max_threads();
                     if threads > max_threads {
-                        bail!("Kernel {name} threads {threads} is greater than max_threads {max_threads}!");
+                        bail!("Kernel `{name}` threads {threads} is greater than max_threads {max_threads}!");
+                    }
+                    let max_group_threads = info.max_group_threads();
+                    if threads > max_group_threads {
+                        bail!("Kernel `{name}` threads {threads} is greater than max_group_threads {max_group_threads}!");
                     }
                     let spec_bytes = {
-                        if !self.desc.spec_descs.is_empty() && self.spec_consts.is_empty() {
+                        if !desc.spec_descs.is_empty() && self.spec_consts.is_empty() {
                             bail!("Kernel `{name}` must be specialized!");
                         }
                         debug_assert_eq!(self.spec_consts.len(), desc.spec_descs.len());
@@ -1041,8 +2525,9 @@ pub mod __private {
                             .chain(threads.to_ne_bytes())
                             .collect()
                     };
+                    // Each variant has its own spirv, and id.
                     let key = KernelKey {
-                        id: self.id,
+                        id: *id,
                         spec_bytes,
                     };
                     let debug_printf = info.debug_printf();
@@ -1054,6 +2539,11 @@ pub mod __private {
                         inner,
                         threads,
                         groups: None,
+                        groups_indirect: None,
+                        exact_items: self.exact_items,
+                        items: None,
+                        priority: Priority::default(),
+                        stream: 0,
                     })
                 }
             }
@@ -1067,12 +2557,38 @@ pub mod __private {
         threads: u32,
         #[cfg(feature = "device")]
         groups: Option<u32>,
+        #[cfg(feature = "device")]
+        groups_indirect: Option<DeviceBuffer>,
+        #[cfg(feature = "device")]
+        exact_items: bool,
+        #[cfg(feature = "device")]
+        items: Option<u32>,
+        #[cfg(feature = "device")]
+        priority: Priority,
+        #[cfg(feature = "device")]
+        stream: usize,
     }
 
     impl Kernel {
         pub fn threads(&self) -> u32 {
             self.threads
         }
+        pub fn max_groups(&self) -> u32 {
+            #[cfg(feature = "device")]
+            {
+                self.inner.device().info().max_groups()
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                unreachable!()
+            }
+        }
+        pub fn recommended_global_threads(&self, len: usize) -> u32 {
+            let threads = self.threads;
+            let len = u32::try_from(len).unwrap_or(u32::MAX);
+            let groups = len / threads + u32::from(len % threads != 0);
+            groups.min(self.max_groups()).saturating_mul(threads)
+        }
         pub fn with_global_threads(self, global_threads: u32) -> Self {
             #[cfg(feature = "device")]
             {
@@ -1092,6 +2608,7 @@ pub mod __private {
             {
                 Self {
                     groups: Some(groups),
+                    groups_indirect: None,
                     ..self
                 }
             }
@@ -1101,9 +2618,93 @@ pub mod __private {
                 unreachable!()
             }
         }
+        pub fn with_groups_indirect(self, groups: Slice<u32>) -> Result<Self> {
+            #[cfg(feature = "device")]
+            {
+                let desc = &self.inner.desc();
+                let kernel_name = &desc.name;
+                if groups.len() < 3 {
+                    bail!(
+                        "Kernel `{kernel_name}` indirect groups expected 3 elements, found {}!",
+                        groups.len()
+                    );
+                }
+                let groups = ScalarSlice::from(groups);
+                let Some(buffer) = groups.device_buffer() else {
+                    bail!("Kernel `{kernel_name}` indirect groups expected device, found host!");
+                };
+                let device = self.inner.device();
+                let buffer_device = buffer.device();
+                if device != buffer_device {
+                    bail!("Kernel `{kernel_name}` indirect groups, expected `{device:?}`, found {buffer_device:?}!");
+                }
+                let groups_indirect = buffer.slice(0..3 * 4);
+                if groups_indirect.as_ref().is_some_and(DeviceBuffer::chunked) {
+                    bail!("Kernel `{kernel_name}` indirect groups span multiple allocations, see `DeviceInfo::max_allocation_size()`!");
+                }
+                Ok(Self {
+                    groups: None,
+                    groups_indirect,
+                    ..self
+                })
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = groups;
+                unreachable!()
+            }
+        }
+        pub fn with_priority(self, priority: Priority) -> Self {
+            #[cfg(feature = "device")]
+            {
+                Self { priority, ..self }
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = priority;
+                unreachable!()
+            }
+        }
+        pub fn with_stream(self, stream: &Stream) -> Result<Self> {
+            #[cfg(feature = "device")]
+            {
+                let device = Device::from(self.inner.device());
+                if stream.device() != &device {
+                    let kernel_name = &self.inner.desc().name;
+                    bail!(
+                        "Kernel `{kernel_name}` expected stream on `{device:?}`, found {:?}!",
+                        stream.device()
+                    );
+                }
+                Ok(Self {
+                    stream: stream.index(),
+                    ..self
+                })
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = stream;
+                unreachable!()
+            }
+        }
+        pub fn with_items(self, items: u32) -> Self {
+            #[cfg(feature = "device")]
+            {
+                Self {
+                    items: Some(items),
+                    ..self
+                }
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                let _ = items;
+                unreachable!()
+            }
+        }
         pub unsafe fn dispatch(
             &self,
             slices: &[KernelSliceArg],
+            push_struct: &[u8],
             push_consts: &[ScalarElem],
         ) -> Result<()> {
             #[cfg(feature = "device")]
@@ -1111,20 +2712,25 @@ pub mod __private {
                 let desc = &self.inner.desc();
                 let kernel_name = &desc.name;
                 let mut buffers = Vec::with_capacity(desc.slice_descs.len());
-                let mut items: Option<u32> = None;
+                let mut items: Option<u32> = self.items;
                 let device = self.inner.device();
-                let mut push_bytes = Vec::with_capacity(desc.push_consts_range() as usize);
+                let push_consts_range = desc.push_consts_range() as usize;
+                let mut push_bytes = Vec::with_capacity(push_consts_range);
+                debug_assert_eq!(push_struct.len(), desc.push_struct_size);
+                push_bytes.extend_from_slice(push_struct);
                 debug_assert_eq!(push_consts.len(), desc.push_descs.len());
                 for (push, push_desc) in push_consts.iter().zip(desc.push_descs.iter()) {
                     debug_assert_eq!(push.scalar_type(), push_desc.scalar_type);
-                    debug_assert_eq!(push_bytes.len() % push.scalar_type().size(), 0);
+                    while push_bytes.len() % push.scalar_type().size() != 0 {
+                        push_bytes.push(0);
+                    }
                     push_bytes.extend_from_slice(push.as_bytes());
                 }
                 while push_bytes.len() % 4 != 0 {
                     push_bytes.push(0);
                 }
                 for (slice, slice_desc) in slices.iter().zip(desc.slice_descs.iter()) {
-                    debug_assert_eq!(slice.scalar_type(), slice_desc.scalar_type);
+                    debug_assert!(slice_desc.pod || slice.scalar_type() == slice_desc.scalar_type);
                     debug_assert!(!slice_desc.mutable || slice.mutable());
                     let slice_name = &slice_desc.name;
                     if slice.len() == 0 {
@@ -1141,25 +2747,46 @@ pub mod __private {
                             "Kernel `{kernel_name}`.`{slice_name}`, expected `{device:?}`, found {buffer_device:?}!"
                         );
                     }
+                    if buffer.chunked() {
+                        bail!("Kernel `{kernel_name}`.`{slice_name}` spans multiple allocations, see `DeviceInfo::max_allocation_size()`!");
+                    }
                     buffers.push(buffer.clone());
                     if slice_desc.item {
-                        items.replace(if let Some(items) = items {
-                            items.min(slice.len() as u32)
+                        let len = slice.len() as u32;
+                        if let Some(items) = items.as_mut() {
+                            if self.exact_items && *items != len {
+                                bail!("Kernel `{kernel_name}`.`{slice_name}` has {len} items, expected {items} (`items = exact`)!");
+                            }
+                            *items = (*items).min(len);
                         } else {
-                            slice.len() as u32
-                        });
+                            items.replace(len);
+                        }
                     }
-                    let width = slice_desc.scalar_type.size();
-                    let offset = buffer.offset() / width;
+                    let width = slice.width();
+                    let offset = buffer.binding_offset() / width;
                     let len = buffer.len() / width;
-                    push_bytes.extend_from_slice(&offset.to_u32().unwrap().to_ne_bytes());
-                    push_bytes.extend_from_slice(&len.to_u32().unwrap().to_ne_bytes());
+                    if slice_desc.index64 {
+                        while push_bytes.len() % 8 != 0 {
+                            push_bytes.push(0);
+                        }
+                        push_bytes.extend_from_slice(&(offset as u64).to_ne_bytes());
+                        push_bytes.extend_from_slice(&(len as u64).to_ne_bytes());
+                    } else if let (Some(offset), Some(len)) = (offset.to_u32(), len.to_u32()) {
+                        push_bytes.extend_from_slice(&offset.to_ne_bytes());
+                        push_bytes.extend_from_slice(&len.to_ne_bytes());
+                    } else {
+                        bail!("Kernel `{kernel_name}`.`{slice_name}` exceeds u32::MAX elements, try `Slice64` or `UnsafeSlice64`!");
+                    }
                 }
+                push_bytes.resize(push_consts_range, 0);
                 let info = self.inner.device().info().clone();
                 let max_groups = info.max_groups();
-                let groups = if let Some(groups) = self.groups {
+                let groups = if self.groups_indirect.is_some() {
+                    // Read by the device.
+                    0
+                } else if let Some(groups) = self.groups {
                     if groups > max_groups {
-                        bail!("Kernel `{kernel_name}` groups {groups} is greater than max_groups {max_groups}!");
+                        bail!("Kernel `{kernel_name}` groups {groups} is greater than max_groups {max_groups}, see `.recommended_global_threads(..)`!");
                     }
                     groups
                 } else if let Some(items) = items {
@@ -1169,40 +2796,128 @@ pub mod __private {
                 } else {
                     bail!("Kernel `{kernel_name}` global_threads or groups not provided!");
                 };
+                if groups == 0 && self.groups_indirect.is_none() {
+                    return Ok(());
+                }
+                let recording = if let Some(recorder) = info.recorder() {
+                    let groups = if let Some(indirect) = self.groups_indirect.as_ref() {
+                        super::indirect_groups(indirect)?
+                    } else {
+                        groups
+                    };
+                    Some(recorder.start(desc, groups, &buffers, &push_bytes)?)
+                } else {
+                    None
+                };
                 let debug_printf_panic = if info.debug_printf() {
                     Some(Arc::new(AtomicBool::default()))
                 } else {
                     None
                 };
+                // Coverage counters are bound after the slices.
+                let coverage_buffer = if !desc.coverage.is_empty() {
+                    let len = desc.coverage.len() * 4;
+                    let buffer = unsafe { DeviceBuffer::uninit(device.clone(), len)? };
+                    buffer.upload(&vec![0; len])?;
+                    buffers.push(buffer.clone());
+                    Some(buffer)
+                } else {
+                    None
+                };
+                let panic_dump = info
+                    .panic_dump()
+                    .filter(|_| debug_printf_panic.is_some())
+                    .map(|panic_dump| (panic_dump, push_bytes.clone()));
                 unsafe {
                     self.inner.dispatch(
                         groups,
+                        self.groups_indirect.as_ref(),
                         &buffers,
                         push_bytes,
                         debug_printf_panic.clone(),
+                        self.priority,
+                        self.stream,
                     )?;
                 }
+                if let Some(recording) = recording {
+                    recording.finish(&buffers)?;
+                }
                 if let Some(debug_printf_panic) = debug_printf_panic {
                     device.wait()?;
                     while Arc::strong_count(&debug_printf_panic) > 1 {
                         std::thread::yield_now();
                     }
                     if debug_printf_panic.load(Ordering::SeqCst) {
+                        if let Some((panic_dump, push_bytes)) = panic_dump {
+                            let groups = if let Some(indirect) = self.groups_indirect.as_ref() {
+                                super::indirect_groups(indirect)?
+                            } else {
+                                groups
+                            };
+                            match super::panic_dump(panic_dump, desc, groups, &buffers, &push_bytes)
+                            {
+                                Ok(path) => {
+                                    bail!("Kernel `{kernel_name}` panicked! Dumped to {path:?}.")
+                                }
+                                Err(e) => {
+                                    bail!("Kernel `{kernel_name}` panicked! Failed to dump: {e}")
+                                }
+                            }
+                        }
                         bail!("Kernel `{kernel_name}` panicked!");
                     }
                 }
+                if let Some(coverage_buffer) = coverage_buffer {
+                    super::record_coverage(&device, desc, &coverage_buffer)?;
+                }
                 Ok(())
             }
             #[cfg(not(feature = "device"))]
             {
-                let _ = (slices, push_consts);
+                let _ = (slices, push_struct, push_consts);
+                unreachable!()
+            }
+        }
+        pub unsafe fn dispatch_async(
+            &self,
+            slices: &[KernelSliceArg],
+            push_struct: &[u8],
+            push_consts: &[ScalarElem],
+        ) -> Result<WaitFuture> {
+            unsafe {
+                self.dispatch(slices, push_struct, push_consts)?;
+            }
+            #[cfg(feature = "device")]
+            {
+                let device = Device::from(self.inner.device());
+                // High priority kernels are dispatched on stream 1, if available.
+                let stream = if self.stream == 0 && self.priority == Priority::High {
+                    device.stream(1).or_else(|_| device.stream(0))?
+                } else {
+                    device.stream(self.stream)?
+                };
+                Ok(stream.wait_async())
+            }
+            #[cfg(not(feature = "device"))]
+            {
                 unreachable!()
             }
         }
         pub fn features(&self) -> Features {
             #[cfg(feature = "device")]
             {
-                return self.inner.desc().features;
+                self.inner.desc().features
+            }
+            #[cfg(not(feature = "device"))]
+            {
+                unreachable!()
+            }
+        }
+        pub fn metadata(&self) -> KernelMetadata {
+            #[cfg(feature = "device")]
+            {
+                let desc = self.inner.desc();
+                desc.metadata(Some(desc.threads), &[])
             }
             #[cfg(not(feature = "device"))]
             {
@@ -1211,51 +2926,566 @@ pub mod __private {
         }
     }
 
+    // The slice and the size of its elements.
     #[doc(hidden)]
     pub enum KernelSliceArg<'a> {
-        Slice(ScalarSlice<'a>),
-        SliceMut(ScalarSliceMut<'a>),
+        Slice(ScalarSlice<'a>, usize),
+        SliceMut(ScalarSliceMut<'a>, usize),
     }
 
     #[cfg(feature = "device")]
     impl KernelSliceArg<'_> {
         fn scalar_type(&self) -> ScalarType {
             match self {
-                Self::Slice(x) => x.scalar_type(),
-                Self::SliceMut(x) => x.scalar_type(),
+                Self::Slice(x, _) => x.scalar_type(),
+                Self::SliceMut(x, _) => x.scalar_type(),
+            }
+        }
+        fn width(&self) -> usize {
+            match self {
+                Self::Slice(_, width) | Self::SliceMut(_, width) => *width,
             }
         }
         fn mutable(&self) -> bool {
             match self {
-                Self::Slice(_) => false,
-                Self::SliceMut(_) => true,
+                Self::Slice(..) => false,
+                Self::SliceMut(..) => true,
             }
         }
         fn device_buffer(&self) -> Option<&DeviceBuffer> {
             match self {
-                Self::Slice(x) => x.device_buffer(),
-                Self::SliceMut(x) => x.device_buffer_mut(),
+                Self::Slice(x, _) => x.device_buffer(),
+                Self::SliceMut(x, _) => x.device_buffer_mut(),
             }
         }
         fn len(&self) -> usize {
             match self {
-                Self::Slice(x) => x.len(),
-                Self::SliceMut(x) => x.len(),
+                Self::Slice(x, width) => x.len() * x.scalar_type().size() / width,
+                Self::SliceMut(x, width) => x.len() * x.scalar_type().size() / width,
             }
         }
     }
 
-    impl<'a, T: Scalar> From<Slice<'a, T>> for KernelSliceArg<'a> {
+    impl<'a, T: Pod> From<Slice<'a, T>> for KernelSliceArg<'a> {
         fn from(slice: Slice<'a, T>) -> Self {
-            Self::Slice(slice.into())
+            Self::Slice(slice.into(), size_of::<T>())
         }
     }
 
-    impl<'a, T: Scalar> From<SliceMut<'a, T>> for KernelSliceArg<'a> {
+    impl<'a, T: Pod> From<SliceMut<'a, T>> for KernelSliceArg<'a> {
         fn from(slice: SliceMut<'a, T>) -> Self {
-            Self::SliceMut(slice.into())
+            Self::SliceMut(slice.into(), size_of::<T>())
         }
     }
 }
 
 pub(crate) use __private::{PushDesc, SliceDesc, SpecDesc};
+
+#[cfg(all(test, feature = "device"))]
+mod tests {
+    use super::*;
+    use crate::buffer::{Buffer, ScalarSlice};
+
+    fn test_desc(name: &'static str, spirv: Vec<u32>) -> KernelDesc {
+        KernelDesc {
+            name: name.into(),
+            spirv,
+            features: Features::empty(),
+            safe: true,
+            threads: 1,
+            spec_descs: &[],
+            slice_descs: &[],
+            push_descs: &[],
+            push_struct_size: 0,
+            push_struct_align: 0,
+            push_struct_fields: &[],
+            spec_consts: Vec::new(),
+            coverage: Vec::new(),
+        }
+    }
+
+    #[cfg(feature = "spirv-val")]
+    #[test]
+    fn validate_spirv() {
+        // header only, missing OpCapability Shader and OpMemoryModel
+        let desc = test_desc("invalid", vec![0x0723_0203, 0x0001_0500, 0, 1, 0]);
+        let error = desc.validate_spirv().unwrap_err().to_string();
+        assert!(
+            error.starts_with("Kernel `invalid` failed validation"),
+            "{error}"
+        );
+    }
+
+    #[test]
+    fn required_features() {
+        use rspirv::spirv::{
+            AddressingModel, Capability, FunctionControl, GroupOperation, MemoryModel, Scope,
+        };
+
+        let spirv = |required: bool| {
+            let mut builder = rspirv::dr::Builder::new();
+            builder.capability(Capability::Shader);
+            if required {
+                builder.capability(Capability::AtomicFloat32AddEXT);
+            }
+            builder.capability(Capability::GroupNonUniform);
+            builder.capability(Capability::GroupNonUniformArithmetic);
+            builder.memory_model(AddressingModel::Logical, MemoryModel::GLSL450);
+            let void = builder.type_void();
+            let uint = builder.type_int(32, 0);
+            let fn_type = builder.type_function(void, []);
+            let scope = builder.constant_u32(uint, Scope::Subgroup as u32);
+            let one = builder.constant_u32(uint, 1);
+            builder
+                .begin_function(void, None, FunctionControl::NONE, fn_type)
+                .unwrap();
+            builder.begin_block(None).unwrap();
+            if required {
+                builder
+                    .group_non_uniform_i_add(uint, None, scope, GroupOperation::Reduce, one, None)
+                    .unwrap();
+            }
+            builder.ret().unwrap();
+            builder.end_function().unwrap();
+            builder.module().assemble()
+        };
+        let desc = test_desc("subgroup", spirv(false));
+        assert_eq!(desc.required_features(), Features::empty());
+        let desc = test_desc("subgroup", spirv(true));
+        assert_eq!(
+            desc.required_features(),
+            Features::empty()
+                .with_subgroup_arithmetic(true)
+                .with_shader_atomic_float(true)
+        );
+    }
+
+    fn push_consts_module(
+        push_struct: &[(rspirv::spirv::Word, u32)],
+        push_consts: &[(rspirv::spirv::Word, u32)],
+    ) -> rspirv::dr::Module {
+        use rspirv::spirv::{AddressingModel, Capability, Decoration, MemoryModel, StorageClass};
+
+        let mut builder = rspirv::dr::Builder::new();
+        builder.capability(Capability::Shader);
+        builder.memory_model(AddressingModel::Logical, MemoryModel::GLSL450);
+        let decorated_struct = |builder: &mut rspirv::dr::Builder, fields: &[(u32, u32)]| {
+            let id = builder.type_struct(fields.iter().map(|(ty, _)| *ty));
+            for (member, (_, offset)) in fields.iter().enumerate() {
+                builder.member_decorate(id, member as u32, Decoration::Offset, [(*offset).into()]);
+            }
+            id
+        };
+        let mut fields = Vec::new();
+        if !push_struct.is_empty() {
+            let push_struct = push_struct
+                .iter()
+                .map(|(width, offset)| (builder.type_int(*width, 0), *offset))
+                .collect::<Vec<_>>();
+            fields.push((decorated_struct(&mut builder, &push_struct), 0));
+        }
+        for (width, offset) in push_consts {
+            fields.push((builder.type_int(*width, 0), *offset));
+        }
+        let push_consts = decorated_struct(&mut builder, &fields);
+        let pointer = builder.type_pointer(None, StorageClass::PushConstant, push_consts);
+        builder.variable(pointer, None, StorageClass::PushConstant, None);
+        builder.module()
+    }
+
+    #[test]
+    fn push_struct_fields() {
+        let desc = KernelDesc {
+            push_descs: &[PushDesc {
+                name: "n",
+                scalar_type: ScalarType::U32,
+            }],
+            push_struct_size: 8,
+            push_struct_align: 4,
+            push_struct_fields: &[("a", 0), ("b", 4)],
+            ..test_desc("push_struct", Vec::new())
+        };
+        desc.validate_push_consts(&push_consts_module(&[(32, 0), (32, 4)], &[(32, 8)]))
+            .unwrap();
+        let error = desc
+            .validate_push_consts(&push_consts_module(&[(32, 0), (32, 8)], &[(32, 8)]))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            "Kernel `push_struct` push struct field `b` has offset 8 on the device, expected 4!"
+        );
+        let error = desc
+            .validate_push_consts(&push_consts_module(
+                &[(32, 0), (32, 4), (32, 8)],
+                &[(32, 8)],
+            ))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            r#"Kernel `push_struct` push struct fields have offsets [0, 4, 8] on the device, expected [("a", 0), ("b", 4)]!"#
+        );
+        let desc = KernelDesc {
+            push_struct_fields: &[],
+            ..desc
+        };
+        let error = desc
+            .validate_push_consts(&push_consts_module(&[(32, 0), (64, 8)], &[(32, 16)]))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            "Kernel `push_struct` push constants field `n` has offset 16 on the device, expected 8!"
+        );
+        let error = desc
+            .validate_push_consts(&push_consts_module(&[(32, 0), (64, 4)], &[(32, 8)]))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            "Kernel `push_struct` push constants have a misaligned member at offset 4 on the device!"
+        );
+        let error = desc
+            .validate_push_consts(&push_consts_module(
+                &[(32, 0), (32, 4)],
+                &[(32, 8), (32, 12)],
+            ))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            r##"Kernel `push_struct` push constants fields have offsets [0, 8, 12] on the device, expected [("#[push]", 0), ("n", 8)]!"##
+        );
+        let desc = KernelDesc {
+            push_struct_size: 16,
+            push_struct_align: 8,
+            ..desc
+        };
+        let error = desc
+            .validate_push_consts(&push_consts_module(&[(32, 0), (32, 4)], &[(32, 16)]))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            "Kernel `push_struct` push struct is 8 bytes on the device, expected 16!"
+        );
+        let desc = KernelDesc {
+            push_struct_size: 8,
+            push_struct_align: 4,
+            ..desc
+        };
+        let error = desc
+            .validate_push_consts(&push_consts_module(&[(64, 0)], &[(32, 8)]))
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            "Kernel `push_struct` push struct has alignment 8 on the device, expected 4!"
+        );
+    }
+
+    #[test]
+    fn push_consts_range() {
+        // 64 bit push constants align the block to 8, which is padded after the slices.
+        let desc = KernelDesc {
+            slice_descs: &[SliceDesc {
+                name: "y",
+                scalar_type: ScalarType::F64,
+                mutable: true,
+                item: false,
+                index64: false,
+                pod: false,
+            }],
+            push_descs: &[
+                PushDesc {
+                    name: "alpha",
+                    scalar_type: ScalarType::F64,
+                },
+                PushDesc {
+                    name: "n",
+                    scalar_type: ScalarType::U32,
+                },
+            ],
+            ..test_desc("push_consts_range", Vec::new())
+        };
+        assert_eq!(desc.push_consts_range(), 24);
+        desc.validate_push_consts(&push_consts_module(
+            &[],
+            &[(64, 0), (32, 8), (32, 12), (32, 16)],
+        ))
+        .unwrap();
+    }
+
+    #[test]
+    fn metadata() {
+        let desc = KernelDesc {
+            spec_descs: &[SpecDesc {
+                name: "N",
+                scalar_type: ScalarType::U32,
+            }],
+            slice_descs: &[
+                SliceDesc {
+                    name: "x",
+                    scalar_type: ScalarType::F32,
+                    mutable: false,
+                    item: true,
+                    index64: false,
+                    pod: false,
+                },
+                SliceDesc {
+                    name: "y",
+                    scalar_type: ScalarType::F32,
+                    mutable: true,
+                    item: false,
+                    index64: false,
+                    pod: false,
+                },
+            ],
+            push_descs: &[
+                PushDesc {
+                    name: "a",
+                    scalar_type: ScalarType::U8,
+                },
+                PushDesc {
+                    name: "b",
+                    scalar_type: ScalarType::F64,
+                },
+            ],
+            push_struct_size: 12,
+            push_struct_align: 4,
+            push_struct_fields: &[("c", 0), ("d", 4)],
+            ..test_desc("metadata", Vec::new())
+        };
+        let metadata = desc.metadata(None, &[]);
+        assert_eq!(metadata.name(), "metadata");
+        assert!(metadata.safe());
+        assert_eq!(metadata.threads(), None);
+        assert_eq!(metadata.spec_consts()[0].name(), "N");
+        assert_eq!(metadata.spec_consts()[0].value(), None);
+        let slices: Vec<_> = metadata
+            .slices()
+            .iter()
+            .map(|x| (x.name(), x.scalar_type(), x.mutable(), x.item()))
+            .collect();
+        assert_eq!(
+            slices,
+            [
+                ("x", ScalarType::F32, false, true),
+                ("y", ScalarType::F32, true, false)
+            ]
+        );
+        let push_consts: Vec<_> = metadata
+            .push_consts()
+            .iter()
+            .map(|x| (x.name(), x.scalar_type(), x.offset()))
+            .collect();
+        assert_eq!(
+            push_consts,
+            [("a", ScalarType::U8, 12), ("b", ScalarType::F64, 16)]
+        );
+        assert_eq!(metadata.push_struct_fields(), &[("c", 0), ("d", 4)]);
+        assert_eq!(metadata.push_consts_size(), 40);
+        let metadata = desc.metadata(Some(64), &[ScalarElem::U32(4)]);
+        assert_eq!(metadata.threads(), Some(64));
+        assert_eq!(metadata.spec_consts()[0].value(), Some(ScalarElem::U32(4)));
+    }
+
+    #[test]
+    fn panic_dump() {
+        let dir = std::env::temp_dir().join(format!("krnl-panic-dump-{}", std::process::id()));
+        let device = Device::mock();
+        let x = Buffer::from(vec![1u32, 2])
+            .into_device(device.clone())
+            .unwrap();
+        let y = Buffer::from(vec![3u32; 4]).into_device(device).unwrap();
+        let buffers: Vec<_> = [x.as_slice(), y.as_slice()]
+            .into_iter()
+            .map(|x| ScalarSlice::from(x).device_buffer().unwrap().clone())
+            .collect();
+        let desc = KernelDesc {
+            slice_descs: &[
+                SliceDesc {
+                    name: "x",
+                    scalar_type: ScalarType::U32,
+                    mutable: false,
+                    item: false,
+                    index64: false,
+                    pod: false,
+                },
+                SliceDesc {
+                    name: "y",
+                    scalar_type: ScalarType::U32,
+                    mutable: true,
+                    item: false,
+                    index64: false,
+                    pod: false,
+                },
+            ],
+            spec_consts: vec![("N", ScalarElem::U32(4))],
+            ..test_desc("crate::kernels::foo<threads=1, N=4>", Vec::new())
+        };
+        let panic_dump = PanicDump {
+            dir: dir.clone(),
+            max_bytes: 8,
+        };
+        let path = super::panic_dump(&panic_dump, &desc, 2, &buffers, &[1, 0, 0, 0]).unwrap();
+        assert_eq!(path, dir.join("crate__kernels__foo-0"));
+        let text = std::fs::read_to_string(path.join("kernel.txt")).unwrap();
+        assert!(text.contains("  N: U32(4)"), "{text}");
+        assert!(
+            text.contains("  y: u32, offset = 0, len = 16 bytes, skipped"),
+            "{text}"
+        );
+        assert_eq!(
+            std::fs::read(path.join("x.bin")).unwrap(),
+            bytemuck::cast_slice::<u32, u8>(&[1, 2])
+        );
+        assert!(!path.join("y.bin").exists());
+        assert_eq!(
+            std::fs::read(path.join("push_consts.bin")).unwrap(),
+            [1, 0, 0, 0]
+        );
+        let path2 = super::panic_dump(&panic_dump, &desc, 2, &buffers, &[]).unwrap();
+        assert_eq!(path2, dir.join("crate__kernels__foo-1"));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn coverage() {
+        use rspirv::{
+            dr::Builder,
+            spirv::{FunctionControl, StorageClass},
+        };
+
+        let mut b = Builder::new();
+        let void = b.type_void();
+        let u32 = b.type_int(32, 0);
+        let u32_ptr = b.type_pointer(None, StorageClass::StorageBuffer, u32);
+        let var = b.variable(u32_ptr, None, StorageClass::StorageBuffer, None);
+        b.name(var, "__krnl_coverage");
+        let zero = b.constant_u32(u32, 0);
+        let one = b.constant_u32(u32, 1);
+        let file = b.string("foo.rs");
+        let fn_ty = b.type_function(void, []);
+        b.begin_function(void, None, FunctionControl::NONE, fn_ty)
+            .unwrap();
+        b.begin_block(None).unwrap();
+        b.access_chain(u32_ptr, None, var, [zero, one]).unwrap();
+        let next = b.id();
+        b.branch(next).unwrap();
+        b.begin_block(Some(next)).unwrap();
+        b.line(file, 2, 5);
+        b.access_chain(u32_ptr, None, var, [zero, zero]).unwrap();
+        b.ret().unwrap();
+        b.end_function().unwrap();
+        let blocks = coverage_blocks(&b.module());
+        assert_eq!(blocks.len(), 2);
+        assert_eq!(blocks[0].label(), next);
+        assert_eq!(blocks[0].location(), Some("foo.rs:2:5"));
+        assert_eq!(blocks[1].location(), None);
+
+        let device = Device::mock();
+        let desc = KernelDesc {
+            coverage: blocks,
+            ..test_desc("foo", Vec::new())
+        };
+        let counts = Buffer::from(vec![3u32, 0])
+            .into_device(device.clone())
+            .unwrap();
+        let buffer = ScalarSlice::from(counts.as_slice())
+            .device_buffer()
+            .unwrap()
+            .clone();
+        let DeviceInner::Device(raw) = device.inner() else {
+            unreachable!()
+        };
+        record_coverage(raw, &desc, &buffer).unwrap();
+        record_coverage(raw, &desc, &buffer).unwrap();
+        let kernels = super::coverage(&device);
+        assert_eq!(kernels.len(), 1);
+        assert_eq!(kernels[0].blocks()[0].count(), 6);
+        assert_eq!(kernels[0].unreached().count(), 1);
+        assert!(super::coverage(&Device::mock()).is_empty());
+        reset_coverage(&device);
+        assert!(super::coverage(&device).is_empty());
+    }
+
+    #[test]
+    fn kernel_dispatch_subslice() {
+        use crate::device::MockOp;
+
+        let device = Device::mock();
+        let mut arena = Buffer::<u32>::zeros(device.clone(), 16).unwrap();
+        let kernel = crate::buffer::kernels::fill_u32::builder()
+            .unwrap()
+            .build(device.clone())
+            .unwrap();
+        device.take_mock_ops().unwrap();
+        let (mut a, mut b) = arena.split_at_mut(10).unwrap();
+        kernel.dispatch(1, a.slice_mut(4..).unwrap()).unwrap();
+        kernel.dispatch(2, b.as_slice_mut()).unwrap();
+        let ops = device.take_mock_ops().unwrap();
+        let push_consts = |x: u32, offset: u32, len: u32| {
+            [x, offset, len]
+                .iter()
+                .flat_map(|x| x.to_ne_bytes())
+                .collect::<Vec<_>>()
+        };
+        let [MockOp::Dispatch {
+            buffers: buffers_a,
+            push_consts: push_consts_a,
+            ..
+        }, MockOp::Dispatch {
+            buffers: buffers_b,
+            push_consts: push_consts_b,
+            ..
+        }] = ops.as_slice()
+        else {
+            panic!("{ops:?}");
+        };
+        assert!(matches!(buffers_a.as_slice(), [x] if *x == (16..40)));
+        assert!(push_consts_a.starts_with(&push_consts(1, 4, 6)));
+        assert!(matches!(buffers_b.as_slice(), [x] if *x == (40..64)));
+        assert!(push_consts_b.starts_with(&push_consts(2, 10, 6)));
+    }
+
+    #[test]
+    fn kernel_groups_indirect() {
+        use crate::device::MockOp;
+
+        let device = Device::mock();
+        let groups = Buffer::from(vec![0u32, 2, 7, 1, 1])
+            .into_device(device.clone())
+            .unwrap();
+        let mut y = Buffer::<u32>::zeros(device.clone(), 8).unwrap();
+        let kernel = crate::buffer::kernels::fill_u32::builder()
+            .unwrap()
+            .build(device.clone())
+            .unwrap();
+        device.take_mock_ops().unwrap();
+        kernel
+            .with_groups_indirect(groups.slice(2..).unwrap())
+            .unwrap()
+            .dispatch(1, y.as_slice_mut())
+            .unwrap();
+        let ops = device.take_mock_ops().unwrap();
+        assert!(matches!(
+            ops.as_slice(),
+            [MockOp::Dispatch { groups: 7, indirect: Some(range), .. }] if *range == (8..20)
+        ));
+        let kernel = crate::buffer::kernels::fill_u32::builder()
+            .unwrap()
+            .build(device.clone())
+            .unwrap();
+        assert!(kernel
+            .with_groups_indirect(groups.slice(3..).unwrap())
+            .is_err());
+        let kernel = crate::buffer::kernels::fill_u32::builder()
+            .unwrap()
+            .build(device)
+            .unwrap();
+        let groups = Buffer::from(vec![1u32, 1, 1]);
+        assert!(kernel.with_groups_indirect(groups.as_slice()).is_err());
+    }
+}
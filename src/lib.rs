@@ -37,6 +37,12 @@ For device functionality (kernels), install [Vulkan](https://www.vulkan.org) for
 - Alternatively, check that `cargo test --test integration_tests -- --exact none` shows your devices.
     - You can run all the tests with `cargo test`.
 
+## WebAssembly
+Without the default "device" feature, **krnl** builds for wasm32, where buffers and
+[host](kernel#host) kernels can be used. Device support on wasm is not yet implemented. The
+experimental "webgpu" feature provides a native WebGPU backend with [wgpu](https://docs.rs/wgpu),
+see [`device::backend`].
+
 # Getting Started
 - See [device] for creating devices.
 - See [buffer] for creating buffers.
@@ -58,14 +64,11 @@ mod kernels {
     use krnl::krnl_core;
     use krnl_core::macros::kernel;
 
-    pub fn saxpy_impl(alpha: f32, x: f32, y: &mut f32) {
-        *y += alpha * x;
-    }
-
     // Item kernels for iterator patterns.
-    #[kernel]
+    // `host` also generates `saxpy::host(..)` which executes on the host.
+    #[kernel(host)]
     pub fn saxpy(alpha: f32, #[item] x: f32, #[item] y: &mut f32) {
-        saxpy_impl(alpha, x, y);
+        *y += alpha * x;
     }
 
     // General purpose kernels like CUDA / OpenCL.
@@ -75,17 +78,16 @@ mod kernels {
 
         let global_id = kernel.global_id();
         if global_id < x.len().min(y.len()) {
-            saxpy_impl(alpha, x[global_id], unsafe { y.unsafe_index_mut(global_id) });
+            unsafe {
+                *y.unsafe_index_mut(global_id) += alpha * x[global_id];
+            }
         }
     }
 }
 
 fn saxpy(alpha: f32, x: Slice<f32>, mut y: SliceMut<f32>) -> Result<()> {
     if let Some((x, y)) = x.as_host_slice().zip(y.as_host_slice_mut()) {
-        x.iter()
-            .copied()
-            .zip(y.iter_mut())
-            .for_each(|(x, y)| kernels::saxpy_impl(alpha, x, y));
+        kernels::saxpy::host(alpha, x, y);
         return Ok(());
     }
     # if true {
@@ -121,6 +123,8 @@ fn main() -> Result<()> {
 
 /// anyhow
 pub extern crate anyhow;
+/// bytemuck
+pub extern crate bytemuck;
 /// krnl-core
 pub extern crate krnl_core;
 /// krnl-macros
@@ -129,12 +133,22 @@ pub extern crate krnl_macros as macros;
 /// half
 pub use krnl_core::half;
 
+/// ash
+#[cfg(all(not(target_arch = "wasm32"), feature = "vulkan-interop"))]
+pub extern crate ash;
+
 #[doc(inline)]
 pub use krnl_core::scalar;
 
+/// Benchmarking.
+pub mod bench;
 /// Buffers.
 pub mod buffer;
 /// Devices.
 pub mod device;
 /// Kernels.
 pub mod kernel;
+/// Record and replay.
+pub mod replay;
+/// Testing.
+pub mod testing;
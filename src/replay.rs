@@ -0,0 +1,659 @@
+/*!
+
+Record dispatches to a trace file, and replay them on another device.
+
+Devices created with [`DeviceBuilder::record`](crate::device::builder::DeviceBuilder::record)
+append every dispatch to a trace file: the kernel name (including spec constants), the
+specialized SPIR-V, groups, push constants, and the contents of each buffer before and after the
+dispatch (up to [`record_max_bytes`](crate::device::builder::DeviceBuilder::record_max_bytes)).
+```no_run
+# use krnl::{anyhow::Result, device::Device};
+# fn main() -> Result<()> {
+let device = Device::builder().record("target/krnl-trace.bin").build()?;
+# Ok(())
+# }
+```
+The trace is self contained, so it can be replayed without the crate that defined the kernels,
+for example to reproduce a miscompile on the machine of the driver that produced it:
+```no_run
+# use krnl::{anyhow::Result, device::Device, replay::{self, Trace}};
+# fn main() -> Result<()> {
+let trace = Trace::load("krnl-trace.bin")?;
+let device = Device::builder().build()?;
+let replayed = unsafe { replay::run(&trace, device)? };
+for (a, b) in trace.dispatches().iter().zip(replayed.dispatches()) {
+    for (x, y) in a.slices().iter().zip(b.slices()) {
+        if x.output() != y.output() {
+            println!("{}: {} differs", a.name(), x.name());
+        }
+    }
+}
+# Ok(())
+# }
+```
+Recording waits for the device and copies buffers to the host for every dispatch, and is intended
+for debugging only.
+*/
+
+use crate::{device::Device, device::Features, scalar::ScalarType};
+#[cfg(feature = "device")]
+use crate::{
+    device::{DeviceBuffer, DeviceInner},
+    kernel::{self, KernelDesc, SliceDesc},
+};
+use anyhow::{bail, Result};
+#[cfg(feature = "device")]
+use parking_lot::Mutex;
+#[cfg(feature = "device")]
+use std::{
+    collections::HashMap,
+    fs::File,
+    io::{BufWriter, Write},
+    path::PathBuf,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+use std::{
+    fmt::{self, Debug},
+    path::Path,
+    str::FromStr,
+};
+
+const MAGIC: &[u8; 12] = b"krnl-trace\0\0";
+const VERSION: u32 = 1;
+
+/// A recorded trace of dispatches.
+#[derive(Clone, Default, Debug)]
+pub struct Trace {
+    dispatches: Vec<TraceDispatch>,
+}
+
+impl Trace {
+    /** Loads a trace from `path`.
+
+    **Errors**
+    - The file could not be read.
+    - The file is not a trace, or is from an incompatible version of **krnl**.
+    */
+    pub fn load(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let bytes = std::fs::read(path)?;
+        let mut reader = Reader(&bytes);
+        if reader.take(MAGIC.len())? != MAGIC {
+            bail!("{path:?} is not a krnl trace!");
+        }
+        let version = reader.u32()?;
+        if version != VERSION {
+            bail!("Trace {path:?} has version {version}, expected {VERSION}!");
+        }
+        let mut dispatches = Vec::new();
+        while !reader.0.is_empty() {
+            dispatches.push(TraceDispatch::read(&mut reader)?);
+        }
+        Ok(Self { dispatches })
+    }
+    /// Saves the trace to `path`, for example a trace returned by [`run`].
+    pub fn save(&self, path: impl AsRef<Path>) -> Result<()> {
+        let mut bytes = header();
+        for dispatch in self.dispatches.iter() {
+            dispatch.write(&mut bytes);
+        }
+        std::fs::write(path, bytes)?;
+        Ok(())
+    }
+    /// The dispatches, in the order they were recorded.
+    pub fn dispatches(&self) -> &[TraceDispatch] {
+        &self.dispatches
+    }
+}
+
+fn header() -> Vec<u8> {
+    let mut bytes = MAGIC.to_vec();
+    bytes.extend_from_slice(&VERSION.to_le_bytes());
+    bytes
+}
+
+/// A recorded dispatch.
+#[derive(Clone)]
+pub struct TraceDispatch {
+    name: String,
+    device: String,
+    features: Features,
+    threads: u32,
+    groups: u32,
+    spirv: Vec<u32>,
+    push_consts: Vec<u8>,
+    slices: Vec<TraceSlice>,
+}
+
+impl TraceDispatch {
+    /// The name of the kernel, including spec constants.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// The name of the device.
+    pub fn device(&self) -> &str {
+        &self.device
+    }
+    /// The features of the kernel.
+    pub fn features(&self) -> Features {
+        self.features
+    }
+    /// The threads per group.
+    pub fn threads(&self) -> u32 {
+        self.threads
+    }
+    /// The number of groups.
+    ///
+    /// For [indirect](crate::kernel#indirect-dispatch) dispatches, the groups read from the
+    /// buffer.
+    pub fn groups(&self) -> u32 {
+        self.groups
+    }
+    /// The push constants, as passed to the device.
+    pub fn push_consts(&self) -> &[u8] {
+        &self.push_consts
+    }
+    /// The slices, in the order of the kernel arguments.
+    pub fn slices(&self) -> &[TraceSlice] {
+        &self.slices
+    }
+    #[cfg(feature = "device")]
+    pub(crate) fn spirv(&self) -> &[u32] {
+        &self.spirv
+    }
+    fn write(&self, bytes: &mut Vec<u8>) {
+        put_str(bytes, &self.name);
+        put_str(bytes, &self.device);
+        let features = [
+            self.features.shader_int8(),
+            self.features.shader_int16(),
+            self.features.shader_int64(),
+            self.features.shader_float16(),
+            self.features.shader_float64(),
+            self.features.subgroup_basic(),
+            self.features.subgroup_vote(),
+            self.features.subgroup_arithmetic(),
+            self.features.subgroup_ballot(),
+            self.features.subgroup_shuffle(),
+            self.features.subgroup_size_control(),
+            self.features.shader_atomic_float(),
+        ]
+        .iter()
+        .enumerate()
+        .fold(0u32, |bits, (i, x)| bits | (u32::from(*x) << i));
+        put_u32(bytes, features);
+        put_u32(bytes, self.threads);
+        put_u32(bytes, self.groups);
+        put_u64(bytes, self.spirv.len() as u64 * 4);
+        for word in self.spirv.iter() {
+            put_u32(bytes, *word);
+        }
+        put_bytes(bytes, &self.push_consts);
+        put_u32(bytes, self.slices.len().try_into().unwrap());
+        for slice in self.slices.iter() {
+            slice.write(bytes);
+        }
+    }
+    fn read(reader: &mut Reader) -> Result<Self> {
+        let name = reader.string()?;
+        let device = reader.string()?;
+        let bits = reader.u32()?;
+        let bit = |i: u32| bits & (1 << i) != 0;
+        let features = Features::empty()
+            .with_shader_int8(bit(0))
+            .with_shader_int16(bit(1))
+            .with_shader_int64(bit(2))
+            .with_shader_float16(bit(3))
+            .with_shader_float64(bit(4))
+            .with_subgroup_basic(bit(5))
+            .with_subgroup_vote(bit(6))
+            .with_subgroup_arithmetic(bit(7))
+            .with_subgroup_ballot(bit(8))
+            .with_subgroup_shuffle(bit(9))
+            .with_subgroup_size_control(bit(10))
+            .with_shader_atomic_float(bit(11));
+        let threads = reader.u32()?;
+        let groups = reader.u32()?;
+        let spirv = reader.bytes()?;
+        if spirv.len() % 4 != 0 {
+            bail!("Trace of `{name}` has invalid SPIR-V!");
+        }
+        let spirv = spirv
+            .chunks_exact(4)
+            .map(|x| u32::from_le_bytes(x.try_into().unwrap()))
+            .collect();
+        let push_consts = reader.bytes()?.to_vec();
+        let slices = (0..reader.u32()?)
+            .map(|_| TraceSlice::read(reader))
+            .collect::<Result<_>>()?;
+        Ok(Self {
+            name,
+            device,
+            features,
+            threads,
+            groups,
+            spirv,
+            push_consts,
+            slices,
+        })
+    }
+}
+
+impl Debug for TraceDispatch {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TraceDispatch")
+            .field("name", &self.name)
+            .field("device", &self.device)
+            .field("threads", &self.threads)
+            .field("groups", &self.groups)
+            .field("push_consts", &self.push_consts)
+            .field("slices", &self.slices)
+            .finish()
+    }
+}
+
+/// A slice of a [`TraceDispatch`].
+#[derive(Clone)]
+pub struct TraceSlice {
+    name: String,
+    scalar_type: ScalarType,
+    mutable: bool,
+    item: bool,
+    index64: bool,
+    pod: bool,
+    offset: usize,
+    len: usize,
+    input: Option<Vec<u8>>,
+    output: Option<Vec<u8>>,
+}
+
+impl TraceSlice {
+    /// The name of the argument.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+    /// The scalar type.
+    pub fn scalar_type(&self) -> ScalarType {
+        self.scalar_type
+    }
+    /// Whether the slice is mutable.
+    pub fn mutable(&self) -> bool {
+        self.mutable
+    }
+    /// The offset into the buffer in bytes.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+    /// The length in bytes.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+    /// Whether the slice is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+    /// The contents before the dispatch.
+    ///
+    /// None if skipped due to [`record_max_bytes`](crate::device::builder::DeviceBuilder::record_max_bytes).
+    pub fn input(&self) -> Option<&[u8]> {
+        self.input.as_deref()
+    }
+    /// The contents after the dispatch, if mutable.
+    ///
+    /// None if immutable, or skipped due to [`record_max_bytes`](crate::device::builder::DeviceBuilder::record_max_bytes).
+    pub fn output(&self) -> Option<&[u8]> {
+        self.output.as_deref()
+    }
+    fn write(&self, bytes: &mut Vec<u8>) {
+        put_str(bytes, &self.name);
+        put_str(bytes, self.scalar_type.name());
+        bytes.push(
+            u8::from(self.mutable)
+                | u8::from(self.item) << 1
+                | u8::from(self.index64) << 2
+                | u8::from(self.pod) << 3,
+        );
+        put_u64(bytes, self.offset as u64);
+        put_u64(bytes, self.len as u64);
+        for data in [&self.input, &self.output] {
+            if let Some(data) = data {
+                bytes.push(1);
+                put_bytes(bytes, data);
+            } else {
+                bytes.push(0);
+            }
+        }
+    }
+    fn read(reader: &mut Reader) -> Result<Self> {
+        let name = reader.string()?;
+        let scalar_type = reader.string()?;
+        let Ok(scalar_type) = ScalarType::from_str(&scalar_type) else {
+            bail!("Unknown scalar type {scalar_type:?} in trace!");
+        };
+        let flags = reader.take(1)?[0];
+        let offset = reader.u64()?.try_into()?;
+        let len = reader.u64()?.try_into()?;
+        let mut data = || -> Result<Option<Vec<u8>>> {
+            if reader.take(1)?[0] != 0 {
+                Ok(Some(reader.bytes()?.to_vec()))
+            } else {
+                Ok(None)
+            }
+        };
+        let input = data()?;
+        let output = data()?;
+        Ok(Self {
+            name,
+            scalar_type,
+            mutable: flags & 1 != 0,
+            item: flags & 2 != 0,
+            index64: flags & 4 != 0,
+            pod: flags & 8 != 0,
+            offset,
+            len,
+            input,
+            output,
+        })
+    }
+}
+
+impl Debug for TraceSlice {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("TraceSlice")
+            .field("name", &self.name)
+            .field("scalar_type", &self.scalar_type)
+            .field("mutable", &self.mutable)
+            .field("offset", &self.offset)
+            .field("len", &self.len)
+            .field("input", &self.input.is_some())
+            .field("output", &self.output.is_some())
+            .finish()
+    }
+}
+
+fn put_u32(bytes: &mut Vec<u8>, x: u32) {
+    bytes.extend_from_slice(&x.to_le_bytes());
+}
+
+fn put_u64(bytes: &mut Vec<u8>, x: u64) {
+    bytes.extend_from_slice(&x.to_le_bytes());
+}
+
+fn put_bytes(bytes: &mut Vec<u8>, data: &[u8]) {
+    put_u64(bytes, data.len() as u64);
+    bytes.extend_from_slice(data);
+}
+
+fn put_str(bytes: &mut Vec<u8>, s: &str) {
+    put_bytes(bytes, s.as_bytes());
+}
+
+struct Reader<'a>(&'a [u8]);
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8]> {
+        if len > self.0.len() {
+            bail!("Unexpected end of trace!");
+        }
+        let (bytes, rest) = self.0.split_at(len);
+        self.0 = rest;
+        Ok(bytes)
+    }
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_le_bytes(self.take(4)?.try_into().unwrap()))
+    }
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_le_bytes(self.take(8)?.try_into().unwrap()))
+    }
+    fn bytes(&mut self) -> Result<&'a [u8]> {
+        let len = self.u64()?.try_into()?;
+        self.take(len)
+    }
+    fn string(&mut self) -> Result<String> {
+        Ok(std::str::from_utf8(self.bytes()?)?.to_string())
+    }
+}
+
+/// Appends dispatches to a trace file, see [`DeviceBuilder::record`](crate::device::builder::DeviceBuilder::record).
+#[cfg(feature = "device")]
+pub(crate) struct Recorder {
+    path: PathBuf,
+    max_bytes: usize,
+    bytes: AtomicUsize,
+    writer: Mutex<BufWriter<File>>,
+}
+
+#[cfg(feature = "device")]
+impl Recorder {
+    pub(crate) fn create(path: PathBuf, max_bytes: usize) -> Result<Self> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut writer = BufWriter::new(File::create(&path)?);
+        writer.write_all(&header())?;
+        writer.flush()?;
+        Ok(Self {
+            path,
+            max_bytes,
+            bytes: AtomicUsize::default(),
+            writer: Mutex::new(writer),
+        })
+    }
+    /// Downloads `buffer`, or None if it exceeds `max_bytes`.
+    fn snapshot(&self, buffer: &DeviceBuffer) -> Result<Option<Vec<u8>>> {
+        let len = buffer.len();
+        let reserved = self
+            .bytes
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |bytes| {
+                Some(bytes + len).filter(|x| *x <= self.max_bytes)
+            });
+        if reserved.is_err() {
+            return Ok(None);
+        }
+        let mut data = vec![0u8; len];
+        buffer.download(&mut data)?;
+        Ok(Some(data))
+    }
+    /// Records the inputs of a dispatch, call [`Recording::finish`] after the dispatch.
+    pub(crate) fn start(
+        &self,
+        desc: &KernelDesc,
+        groups: u32,
+        buffers: &[DeviceBuffer],
+        push_consts: &[u8],
+    ) -> Result<Recording<'_>> {
+        let device = buffers
+            .first()
+            .map(|x| x.device().info().name().to_string())
+            .unwrap_or_default();
+        let slices = buffers
+            .iter()
+            .zip(desc.slice_descs.iter())
+            .map(|(buffer, slice_desc)| {
+                Ok(TraceSlice {
+                    name: slice_desc.name.to_string(),
+                    scalar_type: slice_desc.scalar_type,
+                    mutable: slice_desc.mutable,
+                    item: slice_desc.item,
+                    index64: slice_desc.index64,
+                    pod: slice_desc.pod,
+                    offset: buffer.binding_offset(),
+                    len: buffer.len(),
+                    input: self.snapshot(buffer)?,
+                    output: None,
+                })
+            })
+            .collect::<Result<_>>()?;
+        let dispatch = TraceDispatch {
+            name: desc.name.to_string(),
+            device,
+            features: desc.features(),
+            threads: desc.threads,
+            groups,
+            spirv: desc.spirv.clone(),
+            push_consts: push_consts.to_vec(),
+            slices,
+        };
+        Ok(Recording {
+            recorder: self,
+            dispatch,
+        })
+    }
+}
+
+#[cfg(feature = "device")]
+impl Debug for Recorder {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_tuple("Recorder").field(&self.path).finish()
+    }
+}
+
+#[cfg(feature = "device")]
+pub(crate) struct Recording<'a> {
+    recorder: &'a Recorder,
+    dispatch: TraceDispatch,
+}
+
+#[cfg(feature = "device")]
+impl Recording<'_> {
+    /// Records the outputs of the dispatch and appends it to the trace.
+    pub(crate) fn finish(mut self, buffers: &[DeviceBuffer]) -> Result<()> {
+        for (slice, buffer) in self.dispatch.slices.iter_mut().zip(buffers) {
+            if slice.mutable {
+                slice.output = self.recorder.snapshot(buffer)?;
+            }
+        }
+        let mut bytes = Vec::new();
+        self.dispatch.write(&mut bytes);
+        let mut writer = self.recorder.writer.lock();
+        writer.write_all(&bytes)?;
+        // Flush so that the trace is complete if the process crashes.
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+/** Replays `trace` on `device`.
+
+Each dispatch is executed with new buffers, initialized with the recorded inputs, or zeros if
+they were skipped. Returns a trace of the replay, with the outputs from `device`.
+
+**Errors**
+- `device` is the host.
+- The kernel could not be created or dispatched.
+- [`DeviceLost`](crate::device::error::DeviceLost): The device was lost.
+
+# Safety
+Kernels are dispatched as recorded, which may not be [safe](crate::kernel#safety), for example
+if the trace was modified, or recorded from an `unsafe` kernel.
+*/
+pub unsafe fn run(trace: &Trace, device: Device) -> Result<Trace> {
+    #[cfg(feature = "device")]
+    if let DeviceInner::Device(raw) = device.inner() {
+        let info = raw.info();
+        let mut output = trace.clone();
+        // KernelDesc requires 'static slice descs, which are leaked once per kernel.
+        let mut slice_descs =
+            HashMap::<Vec<(String, bool, bool, bool, bool)>, &'static [SliceDesc]>::new();
+        for dispatch in output.dispatches.iter_mut() {
+            let key: Vec<_> = dispatch
+                .slices
+                .iter()
+                .map(|x| (x.name.clone(), x.mutable, x.item, x.index64, x.pod))
+                .collect();
+            let slice_descs = *slice_descs.entry(key).or_insert_with(|| {
+                dispatch
+                    .slices
+                    .iter()
+                    .map(|x| SliceDesc {
+                        name: Box::leak(x.name.clone().into_boxed_str()),
+                        scalar_type: x.scalar_type,
+                        mutable: x.mutable,
+                        item: x.item,
+                        index64: x.index64,
+                        pod: x.pod,
+                    })
+                    .collect::<Vec<_>>()
+                    .leak()
+            });
+            let mut buffers = Vec::with_capacity(dispatch.slices.len());
+            for slice in dispatch.slices.iter() {
+                let (offset, len) = (slice.offset, slice.len);
+                let mut data = vec![0u8; offset + len];
+                if let Some(input) = slice.input.as_ref() {
+                    data[offset..].copy_from_slice(input);
+                }
+                let buffer = unsafe { DeviceBuffer::uninit(raw.clone(), data.len().max(1))? };
+                buffer.upload(&data)?;
+                buffers.push(buffer.slice(offset..offset + len).unwrap());
+            }
+            unsafe {
+                kernel::replay(raw.clone(), dispatch, slice_descs, &buffers)?;
+            }
+            for (slice, buffer) in dispatch.slices.iter_mut().zip(buffers) {
+                slice.output = if slice.mutable {
+                    let mut data = vec![0u8; slice.len];
+                    buffer.download(&mut data)?;
+                    Some(data)
+                } else {
+                    None
+                };
+            }
+            dispatch.device = info.name().to_string();
+        }
+        return Ok(output);
+    }
+    let _ = trace;
+    bail!("Replay expected device, found {device:?}!");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trace_save_load() {
+        let dispatch = TraceDispatch {
+            name: "foo<threads=64, N=2>".to_string(),
+            device: "mock".to_string(),
+            features: Features::empty().with_shader_int8(true),
+            threads: 64,
+            groups: 3,
+            spirv: vec![0x07230203, 1, 2],
+            push_consts: vec![1, 0, 0, 0],
+            slices: vec![TraceSlice {
+                name: "y".to_string(),
+                scalar_type: ScalarType::F32,
+                mutable: true,
+                item: true,
+                index64: true,
+                pod: false,
+                offset: 4,
+                len: 8,
+                input: Some(vec![1; 8]),
+                output: None,
+            }],
+        };
+        let trace = Trace {
+            dispatches: vec![dispatch.clone(), dispatch],
+        };
+        let path = std::env::temp_dir().join(format!("krnl-trace-{}.bin", std::process::id()));
+        trace.save(&path).unwrap();
+        let loaded = Trace::load(&path).unwrap();
+        std::fs::write(&path, b"krnl").unwrap();
+        assert!(Trace::load(&path).is_err());
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(format!("{loaded:?}"), format!("{trace:?}"));
+        let dispatch = &loaded.dispatches()[1];
+        assert_eq!(
+            dispatch.features(),
+            Features::empty().with_shader_int8(true)
+        );
+        assert_eq!(dispatch.spirv, [0x07230203, 1, 2]);
+        let slice = &dispatch.slices()[0];
+        assert!(slice.item && slice.index64);
+        assert_eq!(slice.input(), Some([1; 8].as_slice()));
+        assert_eq!(slice.output(), None);
+        assert!(unsafe { run(&loaded, Device::host()) }.is_err());
+    }
+}
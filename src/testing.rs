@@ -0,0 +1,678 @@
+/*!
+
+Utilities for testing kernels.
+
+The [`kernel_test`](crate::macros::kernel_test) macro runs a test on the host and every available device,
+comparing the output of each device with the host:
+```
+# use krnl::{anyhow::Result, buffer::Buffer, device::Device, macros::kernel_test};
+#[kernel_test(tolerance = 1e-6)]
+fn buffer_from_elem(device: Device) -> Result<Buffer<f32>> {
+    Buffer::from_elem(device, 16, 1.5)
+}
+# fn main() {}
+```
+The test fn takes a [`Device`](crate::device::Device) and returns a [`TestOutput`](crate::testing::TestOutput).
+Outputs are copied to the host and compared with [`TestOutput::is_close`](crate::testing::TestOutput::is_close).
+Floats are compared with a relative `tolerance`, which defaults to 0.
+Errors, including errors building kernels, fail the test. Devices that don't support the
+[features](crate::kernel#features) declared with `#[kernel_test(features(..))]` are skipped.
+
+# All Devices
+[`for_each_device`](crate::testing::for_each_device) runs a fn on the host and every device, continuing after
+failures, and returns [`DeviceResults`](crate::testing::DeviceResults):
+```
+# use krnl::{buffer::Buffer, device::Features, testing::for_each_device_with_features};
+let features = Features::empty().with_shader_int64(true);
+let results = for_each_device_with_features(features, |device| {
+    let x = Buffer::from_elem(device, 4, 1u64)?;
+    assert_eq!(x.into_vec()?, [1; 4]);
+    Ok(())
+});
+println!("{results}");
+results.unwrap();
+```
+
+# Comparing Buffers
+[`assert_buffers_relative_eq!`](crate::testing::assert_buffers_relative_eq) copies two buffers to the host and
+compares them within a relative tolerance, reporting the first mismatching indices:
+```
+# use krnl::{buffer::Buffer, testing::assert_buffers_relative_eq};
+let x = Buffer::from(vec![1f32, 2., 3.]);
+let y = Buffer::from(vec![1f32, 2.000001, 3.]);
+assert_buffers_relative_eq!(x, y, 1e-5);
+```
+See [`compare_buffers`](crate::testing::compare_buffers).
+
+# Race Checking
+[`race_check`](crate::testing::race_check) runs [host](crate::kernel#host) kernels with the items in a random
+order, tracking accesses via [`UnsafeIndex`](krnl_core::buffer::UnsafeIndex). Items accessing the same element,
+where at least one access is a write, are reported as a [`DataRace`](crate::testing::DataRace), with the index
+and source locations:
+```
+# use krnl::{macros::module, testing::race_check};
+#[module]
+# #[krnl(no_build)]
+mod kernels {
+    # use krnl::macros::kernel;
+    #[kernel(host)]
+    pub unsafe fn scatter(#[item] x: u32, #[global] y: UnsafeSlice<u32>) {
+        use krnl_core::buffer::UnsafeIndex;
+
+        unsafe {
+            *y.unsafe_index_mut(x as usize / 2) = x;
+        }
+    }
+}
+
+# fn main() {
+let x = [0, 1, 2, 3];
+let mut y = [0; 2];
+let ((), races) = race_check(0, || unsafe { kernels::scatter::host(&x, &mut y) });
+assert_eq!(races.len(), 2);
+for race in races {
+    eprintln!("{race}");
+}
+# }
+```
+The seed determines the order of the items, so that tests are reproducible.
+*/
+
+use crate::{
+    buffer::{Buffer, BufferBase, Data},
+    device::{Device, Features},
+    scalar::{Scalar, ScalarType},
+};
+use anyhow::{format_err, Error, Result};
+use dry::macro_for;
+use half::{bf16, f16};
+#[doc(inline)]
+pub use krnl_core::kernel::__private::DataRace;
+use std::{
+    fmt::{self, Debug, Display},
+    panic::{catch_unwind, AssertUnwindSafe},
+};
+
+/// Kernel test outputs.
+///
+/// See [`kernel_test`](crate::macros::kernel_test).
+pub trait TestOutput {
+    /// The output on the host.
+    type Host: Debug;
+    /// Copies the output to the host.
+    fn into_host(self) -> Result<Self::Host>;
+    /// Whether `output` is equal to `expected` within `tolerance`.
+    fn is_close(output: &Self::Host, expected: &Self::Host, tolerance: f64) -> bool;
+}
+
+impl TestOutput for () {
+    type Host = ();
+    fn into_host(self) -> Result<()> {
+        Ok(())
+    }
+    fn is_close(_: &(), _: &(), _: f64) -> bool {
+        true
+    }
+}
+
+fn scalar_is_close<T: Scalar>(output: T, expected: T, tolerance: f64) -> bool {
+    if output == expected {
+        return true;
+    }
+    let float = matches!(
+        T::SCALAR_TYPE,
+        ScalarType::F16 | ScalarType::BF16 | ScalarType::F32 | ScalarType::F64
+    );
+    if !float {
+        return false;
+    }
+    let (output, expected) = (output.cast::<f64>(), expected.cast::<f64>());
+    if output.is_nan() || expected.is_nan() {
+        return output.is_nan() && expected.is_nan();
+    }
+    (output - expected).abs() <= tolerance * expected.abs().max(1.)
+}
+
+macro_for!($T in [u8, i8, u16, i16, f16, bf16, u32, i32, f32, u64, i64, f64] {
+    impl TestOutput for $T {
+        type Host = $T;
+        fn into_host(self) -> Result<$T> {
+            Ok(self)
+        }
+        fn is_close(output: &$T, expected: &$T, tolerance: f64) -> bool {
+            scalar_is_close(*output, *expected, tolerance)
+        }
+    }
+});
+
+impl<T: Scalar> TestOutput for Vec<T> {
+    type Host = Vec<T>;
+    fn into_host(self) -> Result<Vec<T>> {
+        Ok(self)
+    }
+    fn is_close(output: &Vec<T>, expected: &Vec<T>, tolerance: f64) -> bool {
+        output.len() == expected.len()
+            && output
+                .iter()
+                .zip(expected.iter())
+                .all(|(output, expected)| scalar_is_close(*output, *expected, tolerance))
+    }
+}
+
+impl<T: Scalar> TestOutput for Buffer<T> {
+    type Host = Vec<T>;
+    fn into_host(self) -> Result<Vec<T>> {
+        self.into_vec()
+    }
+    fn is_close(output: &Vec<T>, expected: &Vec<T>, tolerance: f64) -> bool {
+        Vec::is_close(output, expected, tolerance)
+    }
+}
+
+impl<T: TestOutput> TestOutput for Result<T> {
+    type Host = T::Host;
+    fn into_host(self) -> Result<T::Host> {
+        self?.into_host()
+    }
+    fn is_close(output: &T::Host, expected: &T::Host, tolerance: f64) -> bool {
+        T::is_close(output, expected, tolerance)
+    }
+}
+
+macro_rules! impl_test_output_tuple {
+    ($($T:ident $i:tt),+) => {
+        impl<$($T: TestOutput),+> TestOutput for ($($T,)+) {
+            type Host = ($($T::Host,)+);
+            fn into_host(self) -> Result<Self::Host> {
+                Ok(($(self.$i.into_host()?,)+))
+            }
+            fn is_close(output: &Self::Host, expected: &Self::Host, tolerance: f64) -> bool {
+                $($T::is_close(&output.$i, &expected.$i, tolerance))&&+
+            }
+        }
+    };
+}
+
+impl_test_output_tuple!(A 0, B 1);
+impl_test_output_tuple!(A 0, B 1, C 2);
+impl_test_output_tuple!(A 0, B 1, C 2, D 3);
+
+// The number of mismatching indices reported by `compare_buffers`.
+const MAX_MISMATCHES: usize = 8;
+
+/// Compares `output` with `expected`, within a relative `tolerance`.
+///
+/// Both buffers are copied to the host. Returns an error if the lengths differ, or with the
+/// first mismatching indices and values. Floats are compared as in
+/// [`TestOutput::is_close`], other types must be equal.
+///
+/// See [`assert_buffers_relative_eq!`].
+pub fn compare_buffers<T: Scalar, S1: Data<Elem = T>, S2: Data<Elem = T>>(
+    output: &BufferBase<S1>,
+    expected: &BufferBase<S2>,
+    tolerance: f64,
+) -> Result<()> {
+    if output.len() != expected.len() {
+        return Err(format_err!(
+            "output length ({}) does not match expected length ({})!",
+            output.len(),
+            expected.len()
+        ));
+    }
+    let (output, expected) = (output.to_vec()?, expected.to_vec()?);
+    let mismatches: Vec<usize> = output
+        .iter()
+        .zip(expected.iter())
+        .enumerate()
+        .filter(|(_, (output, expected))| !scalar_is_close(**output, **expected, tolerance))
+        .map(|(i, _)| i)
+        .collect();
+    if mismatches.is_empty() {
+        return Ok(());
+    }
+    let mut msg = format!(
+        "buffers differ at {} of {} indices (tolerance = {tolerance}):",
+        mismatches.len(),
+        output.len()
+    );
+    for i in mismatches.iter().copied().take(MAX_MISMATCHES) {
+        msg.push_str(&format!(
+            "\n    [{i}]: {:?}, expected {:?}",
+            output[i], expected[i]
+        ));
+    }
+    if mismatches.len() > MAX_MISMATCHES {
+        msg.push_str("\n    ...");
+    }
+    Err(Error::msg(msg))
+}
+
+/// Asserts that two buffers are equal within a relative tolerance.
+///
+/// The tolerance defaults to 0. Panics with the first mismatching indices, see
+/// [`compare_buffers`](crate::testing::compare_buffers).
+/// ```
+/// # use krnl::{buffer::Buffer, testing::assert_buffers_relative_eq};
+/// let x = Buffer::from(vec![1u32, 2, 3]);
+/// assert_buffers_relative_eq!(x, x.slice(..).unwrap());
+/// ```
+#[doc(hidden)]
+#[macro_export]
+macro_rules! assert_buffers_relative_eq {
+    ($output:expr, $expected:expr $(,)?) => {
+        $crate::assert_buffers_relative_eq!($output, $expected, 0.)
+    };
+    ($output:expr, $expected:expr, $tolerance:expr $(,)?) => {
+        if let Err(e) = $crate::testing::compare_buffers(&$output, &$expected, $tolerance) {
+            panic!("{e}");
+        }
+    };
+}
+#[doc(inline)]
+pub use crate::assert_buffers_relative_eq;
+
+/// Runs `f`, checking [host](crate::kernel#host) kernels for data races.
+///
+/// Returns the output of `f` and the data races detected. Items are executed in a random order,
+/// determined by `seed`. Only kernels executed on the current thread are checked.
+///
+/// See [Race Checking](self#race-checking).
+pub fn race_check<R>(seed: u64, f: impl FnOnce() -> R) -> (R, Vec<DataRace>) {
+    krnl_core::kernel::__private::race_check(seed, f)
+}
+
+// Available devices, in index order.
+fn devices() -> Vec<Device> {
+    #[cfg(feature = "device")]
+    {
+        (0..)
+            .map_while(|index| Device::builder().index(index).build().ok())
+            .collect()
+    }
+    #[cfg(not(feature = "device"))]
+    {
+        Vec::new()
+    }
+}
+
+// The reason to skip `device`, if it doesn't support `features`.
+fn skip_reason(device: &Device, features: Features) -> Option<String> {
+    let device_features = device.info()?.features();
+    if device_features.contains(&features) {
+        return None;
+    }
+    let missing: Vec<_> = features.difference(&device_features).names().collect();
+    Some(format!("requires {}", missing.join(", ")))
+}
+
+/// The outcome of a test on a device.
+///
+/// See [`for_each_device`].
+#[derive(Debug)]
+pub enum DeviceOutcome {
+    /// The test passed.
+    Passed,
+    /// The device was skipped, with the reason.
+    Skipped(String),
+    /// The test returned an error or panicked.
+    Failed(Error),
+}
+
+/// The results of [`for_each_device`].
+#[derive(Debug)]
+pub struct DeviceResults {
+    results: Vec<(Device, DeviceOutcome)>,
+}
+
+impl DeviceResults {
+    /// Iterates over the devices and their outcomes.
+    pub fn iter(&self) -> impl Iterator<Item = (&Device, &DeviceOutcome)> {
+        self.results
+            .iter()
+            .map(|(device, outcome)| (device, outcome))
+    }
+    /// The number of devices that passed.
+    pub fn passed(&self) -> usize {
+        self.iter()
+            .filter(|(_, x)| matches!(x, DeviceOutcome::Passed))
+            .count()
+    }
+    /// The number of devices that were skipped.
+    pub fn skipped(&self) -> usize {
+        self.iter()
+            .filter(|(_, x)| matches!(x, DeviceOutcome::Skipped(_)))
+            .count()
+    }
+    /// The number of devices that failed.
+    pub fn failed(&self) -> usize {
+        self.iter()
+            .filter(|(_, x)| matches!(x, DeviceOutcome::Failed(_)))
+            .count()
+    }
+    /// Returns an error if any device failed.
+    pub fn into_result(self) -> Result<()> {
+        if self.failed() > 0 {
+            Err(format_err!("{self}"))
+        } else {
+            Ok(())
+        }
+    }
+    /// Panics if any device failed.
+    #[track_caller]
+    pub fn unwrap(self) {
+        if self.failed() > 0 {
+            panic!("{self}");
+        }
+    }
+}
+
+impl Display for DeviceResults {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "{} passed; {} failed; {} skipped",
+            self.passed(),
+            self.failed(),
+            self.skipped()
+        )?;
+        for (device, outcome) in self.iter() {
+            match outcome {
+                DeviceOutcome::Passed => writeln!(f, "{device:?}: passed")?,
+                DeviceOutcome::Skipped(reason) => writeln!(f, "{device:?}: skipped, {reason}")?,
+                DeviceOutcome::Failed(e) => writeln!(f, "{device:?}: failed, {e:?}")?,
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Runs `f` on the host and every device.
+///
+/// Failures, including panics, do not stop the remaining devices from running. See
+/// [All Devices](self#all-devices).
+pub fn for_each_device(f: impl FnMut(Device) -> Result<()>) -> DeviceResults {
+    for_each_device_with_features(Features::empty(), f)
+}
+
+/// Runs `f` on the host and every device, skipping devices that don't support `features`.
+///
+/// See [`for_each_device`].
+pub fn for_each_device_with_features(
+    features: Features,
+    mut f: impl FnMut(Device) -> Result<()>,
+) -> DeviceResults {
+    let results = std::iter::once(Device::host())
+        .chain(devices())
+        .map(|device| {
+            if let Some(reason) = skip_reason(&device, features) {
+                return (device, DeviceOutcome::Skipped(reason));
+            }
+            let outcome = match catch_unwind(AssertUnwindSafe(|| f(device.clone()))) {
+                Ok(Ok(())) => DeviceOutcome::Passed,
+                Ok(Err(e)) => DeviceOutcome::Failed(e),
+                Err(payload) => {
+                    let msg = payload
+                        .downcast_ref::<&str>()
+                        .map(|x| x.to_string())
+                        .or_else(|| payload.downcast_ref::<String>().cloned())
+                        .unwrap_or_default();
+                    DeviceOutcome::Failed(format_err!("panicked: {msg}"))
+                }
+            };
+            (device, outcome)
+        })
+        .collect();
+    DeviceResults { results }
+}
+
+// Runs `f` on the host and each device that supports `features`, comparing the outputs.
+// Returns the skipped devices.
+fn compare_devices<T: TestOutput>(
+    tolerance: f64,
+    features: Features,
+    f: impl Fn(Device) -> T,
+) -> Result<Vec<(Device, String)>, String> {
+    let expected = f(Device::host())
+        .into_host()
+        .map_err(|e| format!("failed on the host: {e:?}"))?;
+    let mut skipped = Vec::new();
+    for device in devices() {
+        if let Some(reason) = skip_reason(&device, features) {
+            skipped.push((device, reason));
+            continue;
+        }
+        let output = f(device.clone())
+            .into_host()
+            .map_err(|e| format!("failed on {device:?}: {e:?}"))?;
+        if !T::is_close(&output, &expected, tolerance) {
+            return Err(format!("output on {device:?} is {output:?}, expected {expected:?} (tolerance = {tolerance})!"));
+        }
+    }
+    Ok(skipped)
+}
+
+/// [proptest](https://docs.rs/proptest) strategies for fuzzing kernels.
+///
+/// Generates scalars, lengths, and vecs, biased to corner cases. [`check_devices`](self::proptest::check_devices)
+/// compares the host with every device:
+/// ```
+/// use krnl::{buffer::Slice, device::Device, testing::proptest::{check_devices, scalar, vec}};
+/// use proptest::proptest;
+///
+/// proptest! {
+///     fn buffer_fill(x in vec::<f32>(1000), elem in scalar::<f32>()) {
+///         check_devices(0., |device| {
+///             let mut y = Slice::from(x.as_slice()).to_device(device)?;
+///             y.fill(elem)?;
+///             Ok(y)
+///         })?;
+///     }
+/// }
+/// # fn main() { buffer_fill(); }
+/// ```
+/// Annotate the fns with `#[test]` to run them with `cargo test`. Requires the "proptest" feature.
+#[cfg(feature = "proptest")]
+pub mod proptest {
+    use super::*;
+    use ::proptest::{collection, sample::select, strategy::Strategy, test_runner::TestCaseError};
+    use std::mem::size_of;
+
+    fn from_bits<T: Scalar>(bits: u64) -> T {
+        let n = size_of::<T>();
+        if cfg!(target_endian = "little") {
+            bytemuck::pod_read_unaligned(&bits.to_le_bytes()[..n])
+        } else {
+            bytemuck::pod_read_unaligned(&bits.to_be_bytes()[8 - n..])
+        }
+    }
+
+    /// Scalars, biased to corner cases like 0, 1, min, max, infinity, and NaN.
+    pub fn scalar<T: Scalar>() -> impl Strategy<Value = T> {
+        let bits = 8 * size_of::<T>() as u32;
+        let high_bit = 1u64 << (bits - 1);
+        let corners: Vec<T> = [0., 1., -1., 0.5, f64::INFINITY, f64::NEG_INFINITY, f64::NAN]
+            .into_iter()
+            .filter_map(T::from_f64)
+            .chain(
+                [0, u64::MAX, high_bit, high_bit - 1, 1]
+                    .into_iter()
+                    .map(from_bits::<T>),
+            )
+            .collect();
+        ::proptest::prop_oneof![
+            1 => select(corners),
+            3 => ::proptest::num::u64::ANY.prop_map(from_bits::<T>),
+        ]
+    }
+
+    /// Lengths in `0 ..= max_len`, biased to 0, 1, `max_len`, and around multiples of the
+    /// default threads.
+    pub fn len(max_len: usize) -> impl Strategy<Value = usize> {
+        let corners: Vec<usize> = [0, 1, 2, 3, 63, 64, 65, 127, 128, 129, 255, 256, 257]
+            .into_iter()
+            .chain([max_len.saturating_sub(1), max_len])
+            .filter(|x| *x <= max_len)
+            .collect();
+        ::proptest::prop_oneof![
+            1 => select(corners),
+            1 => 0 ..= max_len,
+        ]
+    }
+
+    /// Vecs of [`scalar`]s, with lengths from [`len`].
+    pub fn vec<T: Scalar>(max_len: usize) -> impl Strategy<Value = Vec<T>> {
+        len(max_len).prop_flat_map(|len| collection::vec(scalar::<T>(), len))
+    }
+
+    /// Runs `f` on the host and every device, failing the test case if the outputs are not
+    /// equal within `tolerance`.
+    ///
+    /// See [`kernel_test`](crate::macros::kernel_test).
+    pub fn check_devices<T: TestOutput>(
+        tolerance: f64,
+        f: impl Fn(Device) -> T,
+    ) -> Result<(), TestCaseError> {
+        compare_devices(tolerance, Features::empty(), f)
+            .map(|_| ())
+            .map_err(TestCaseError::fail)
+    }
+}
+
+#[doc(hidden)]
+pub mod __private {
+    use super::*;
+
+    pub fn kernel_test<T: TestOutput>(
+        name: &str,
+        tolerance: f64,
+        features: Features,
+        f: impl Fn(Device) -> T,
+    ) {
+        match compare_devices(tolerance, features, f) {
+            Ok(skipped) => {
+                for (device, reason) in skipped {
+                    eprintln!("`{name}` skipped {device:?}, {reason}");
+                }
+            }
+            Err(e) => panic!("`{name}` {e}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[cfg(feature = "proptest")]
+    use crate::buffer::Slice;
+
+    #[test]
+    fn test_output_is_close() {
+        assert!(f32::is_close(&1., &1., 0.));
+        assert!(!f32::is_close(&1.000001, &1., 0.));
+        assert!(f32::is_close(&1.000001, &1., 1e-5));
+        assert!(f32::is_close(&f32::NAN, &f32::NAN, 0.));
+        assert!(!f32::is_close(&f32::NAN, &1., 1.));
+        assert!(!u32::is_close(&2, &1, 1.));
+        assert!(!Vec::<u32>::is_close(&vec![1], &vec![1, 2], 0.));
+        assert!(<(u32, Vec<f32>)>::is_close(
+            &(1, vec![1.000001]),
+            &(1, vec![1.]),
+            1e-5
+        ));
+    }
+
+    #[test]
+    fn compare_buffers_mismatches() {
+        let x = Buffer::from((0..20).map(|x| x as f32).collect::<Vec<_>>());
+        let y = Buffer::from(vec![0f32; 20]);
+        let e = compare_buffers(&x, &y, 0.).unwrap_err().to_string();
+        assert!(e.starts_with("buffers differ at 19 of 20 indices (tolerance = 0):"));
+        assert!(e.contains("[1]: 1.0, expected 0.0"));
+        assert!(!e.contains("[9]"));
+        assert!(e.ends_with("..."));
+        let e = compare_buffers(&x, &x.slice(1..).unwrap(), 0.).unwrap_err();
+        assert!(e.to_string().contains("length"));
+        assert_buffers_relative_eq!(x, x.slice(..).unwrap());
+    }
+
+    #[test]
+    #[should_panic(expected = "[0]: 1, expected 2")]
+    fn assert_buffers_relative_eq_panics() {
+        assert_buffers_relative_eq!(Buffer::from(vec![1u8]), Buffer::from(vec![2u8]), 1.);
+    }
+
+    #[test]
+    fn race_check_host_items() {
+        use krnl_core::{
+            buffer::{UnsafeIndex, UnsafeSlice},
+            kernel::__private::host_items,
+        };
+
+        let mut y = [0u32; 4];
+        let (mut items, races) = race_check(1, || {
+            let y = UnsafeSlice::from(y.as_mut_slice());
+            host_items(4)
+                .inspect(|item| unsafe {
+                    *y.unsafe_index_mut(*item) = 1;
+                })
+                .collect::<Vec<_>>()
+        });
+        assert!(races.is_empty());
+        items.sort();
+        assert_eq!(items, [0, 1, 2, 3]);
+        let ((), races) = race_check(1, || {
+            let y = UnsafeSlice::from(y.as_mut_slice());
+            for item in host_items(4) {
+                unsafe {
+                    *y.unsafe_index_mut(0) = item as u32;
+                }
+            }
+        });
+        assert_eq!(races.len(), 3);
+        assert!(races.iter().all(|race| race.index == 0 && race.write_write));
+    }
+
+    #[cfg(feature = "proptest")]
+    #[test]
+    fn proptest_strategies() {
+        use ::proptest::{
+            strategy::{Strategy, ValueTree},
+            test_runner::TestRunner,
+        };
+
+        let mut runner = TestRunner::deterministic();
+        runner
+            .run(&self::proptest::vec::<f32>(300), |x| {
+                assert!(x.len() <= 300);
+                self::proptest::check_devices(0., |device| {
+                    Slice::from(x.as_slice()).to_device(device)
+                })
+            })
+            .unwrap();
+        let strategy = self::proptest::scalar::<f32>();
+        let nan = (0..1000).any(|_| strategy.new_tree(&mut runner).unwrap().current().is_nan());
+        assert!(nan);
+    }
+
+    #[test]
+    fn for_each_device_results() {
+        let results = for_each_device(|device| {
+            if device.is_host() {
+                panic!("host");
+            }
+            Ok(())
+        });
+        assert_eq!(results.failed(), 1);
+        assert!(results.to_string().contains("panicked: host"));
+        assert!(results.into_result().is_err());
+        for_each_device_with_features(Features::empty().with_shader_int64(true), |_| Ok(()))
+            .unwrap();
+    }
+
+    #[test]
+    fn kernel_test_buffer() {
+        __private::kernel_test("kernel_test_buffer", 0., Features::empty(), |device| {
+            Buffer::from_elem(device, 4, 1u32)
+        });
+    }
+}
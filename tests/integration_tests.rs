@@ -2,7 +2,11 @@ use dry::macro_for;
 use half::{bf16, f16};
 #[cfg(feature = "device")]
 use krnl::buffer::Buffer;
-use krnl::{buffer::Slice, device::Device, scalar::Scalar};
+use krnl::{
+    buffer::Slice,
+    device::Device,
+    scalar::{Df64, Scalar},
+};
 #[cfg(not(target_arch = "wasm32"))]
 use krnl::{device::Features, scalar::ScalarType};
 #[cfg(not(target_arch = "wasm32"))]
@@ -41,7 +45,7 @@ fn main() {
         let device_index2 = usize::from(device_index == 0);
         println!("KRNL_DEVICE = {krnl_device:?}");
         println!("testing device {device_index}");
-        let device = devices.get(0).unwrap();
+        let device = devices.first().unwrap();
         let device2 = devices.get(1);
         if device2.is_some() {
             println!("using device {device_index2} for `buffer_device_to_device`");
@@ -71,7 +75,14 @@ fn device_test(device: &Device, name: &str, f: impl Fn(Device) + Send + Sync + '
 
 #[cfg(not(target_arch = "wasm32"))]
 fn tests(device: &Device, device2: Option<&Device>) -> impl IntoIterator<Item = Trial> {
-    buffer_tests(device, device2)
+    let mut tests: Vec<_> = buffer_tests(device, device2).into_iter().collect();
+    if device.is_host() {
+        tests.push(Trial::test("scalar_df64", || {
+            scalar_df64();
+            Ok(())
+        }));
+    }
+    tests
 }
 
 #[cfg(not(target_arch = "wasm32"))]
@@ -80,9 +91,23 @@ fn buffer_tests(device: &Device, device2: Option<&Device>) -> impl IntoIterator<
         .info()
         .map(|x| x.features())
         .unwrap_or(Features::empty());
-    let mut tests = Vec::new();
-
-    tests.push(device_test(device, "buffer_from_vec", buffer_from_vec));
+    let mut tests = vec![
+        device_test(device, "buffer_from_vec", buffer_from_vec),
+        device_test(device, "buffer_split_at", buffer_split_at),
+        device_test(device, "buffer_chunks", buffer_chunks),
+        device_test(device, "buffer_bitcast_into", buffer_bitcast_into),
+        device_test(device, "scalar_buffer", scalar_buffer),
+        device_test(device, "buffer_concat", buffer_concat),
+        device_test(
+            device,
+            "buffer_copy_from_host_slice",
+            buffer_copy_from_host_slice,
+        ),
+        device_test(device, "buffer_arc_slice", buffer_arc_slice),
+        device_test(device, "cow_buffer", cow_buffer),
+    ];
+    #[cfg(feature = "mmap")]
+    tests.push(device_test(device, "buffer_from_mmap", buffer_from_mmap));
 
     if device.is_device() {
         #[cfg(feature = "device")]
@@ -198,10 +223,31 @@ fn buffer_from_vec(device: Device) {
     }
 }
 
+#[cfg(feature = "mmap")]
+fn buffer_from_mmap(device: Device) {
+    use krnl::buffer::MmapBuffer;
+    let n = buffer_transfer_test_lengths().last().unwrap();
+    let x = (10..20u32).cycle().take(n).collect::<Vec<_>>();
+    let path = std::env::temp_dir().join(format!(
+        "krnl-buffer-from-mmap-{}-{}.bin",
+        std::process::id(),
+        device.is_host()
+    ));
+    std::fs::write(&path, bytemuck::cast_slice(&x)).unwrap();
+    let mmap = unsafe { MmapBuffer::<u32>::from_mmap(&path).unwrap() };
+    assert_eq!(mmap.as_host_slice().unwrap(), x.as_slice());
+    let y = mmap.to_device(device.clone()).unwrap().into_vec().unwrap();
+    assert!(x == y);
+    drop(mmap);
+    std::fs::write(&path, [1u8, 2, 3]).unwrap();
+    assert!(unsafe { MmapBuffer::<u32>::from_mmap(&path) }.is_err());
+    std::fs::remove_file(path).unwrap();
+}
+
 #[cfg(feature = "device")]
 fn device_buffer_too_large(device: Device) {
     use krnl::buffer::error::DeviceBufferTooLarge;
-    let error = unsafe { Buffer::<u32>::uninit(device, (i32::MAX / 4 + 1).try_into().unwrap()) }
+    let error = unsafe { Buffer::<u32>::uninit(device, isize::MAX as usize / 4 + 1) }
         .err()
         .unwrap();
     error.downcast_ref::<DeviceBufferTooLarge>().unwrap();
@@ -228,6 +274,148 @@ fn buffer_transfer(device: Device, device2: Device) {
     }
 }
 
+#[cfg(not(target_arch = "wasm32"))]
+fn buffer_split_at(device: Device) {
+    let x: Vec<u32> = (0..10).collect();
+    let mut y = Slice::from(x.as_slice()).to_device(device).unwrap();
+    for mid in 0..=x.len() {
+        let (a, b) = y.split_at(mid).unwrap();
+        assert_eq!(a.to_vec().unwrap(), &x[..mid]);
+        assert_eq!(b.to_vec().unwrap(), &x[mid..]);
+    }
+    assert!(y.split_at(x.len() + 1).is_none());
+    let (mut a, mut b) = y.split_at_mut(4).unwrap();
+    a.fill(1).unwrap();
+    b.fill(2).unwrap();
+    assert_eq!(y.to_vec().unwrap(), [1, 1, 1, 1, 2, 2, 2, 2, 2, 2]);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn buffer_chunks(device: Device) {
+    let x: Vec<u32> = (0..10).collect();
+    let mut y = Slice::from(x.as_slice()).to_device(device).unwrap();
+    for chunk_size in 1..=x.len() + 1 {
+        let chunks: Vec<Vec<u32>> = y.chunks(chunk_size).map(|x| x.to_vec().unwrap()).collect();
+        let expected: Vec<Vec<u32>> = x.chunks(chunk_size).map(|x| x.to_vec()).collect();
+        assert_eq!(chunks, expected);
+    }
+    for (i, mut chunk) in y.chunks_mut(3).enumerate() {
+        chunk.fill(i as u32).unwrap();
+    }
+    assert_eq!(y.to_vec().unwrap(), [0, 0, 0, 1, 1, 1, 2, 2, 2, 3]);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn scalar_buffer(device: Device) {
+    use krnl::buffer::{Buffer, ScalarBuffer};
+
+    let x: Vec<u32> = (0..10).collect();
+    let y = ScalarBuffer::from_buffer(x.clone().into())
+        .into_device(device)
+        .unwrap();
+    assert_eq!(y.scalar_type(), ScalarType::U32);
+    assert_eq!(y.len(), x.len());
+    assert!(y.as_slice::<f32>().is_none());
+    let chunks: Vec<Vec<u32>> = y
+        .chunks(4)
+        .map(|x| Slice::try_from(x).unwrap().to_vec().unwrap())
+        .collect();
+    let expected: Vec<Vec<u32>> = x.chunks(4).map(|x| x.to_vec()).collect();
+    assert_eq!(chunks, expected);
+    let z = y.slice(2..5).unwrap().to_owned().unwrap();
+    assert_eq!(z.as_slice::<u32>().unwrap().to_vec().unwrap(), [2, 3, 4]);
+    let y = Buffer::<u32>::try_from(y).unwrap();
+    assert_eq!(y.into_vec().unwrap(), x);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn buffer_bitcast_into(device: Device) {
+    let x: Vec<f32> = vec![1.5, -2.0, 0.25];
+    let x_bits: Vec<u32> = x.iter().map(|x| x.to_bits()).collect();
+    let y = Slice::from(x_bits.as_slice()).to_device(device).unwrap();
+    let y = y.bitcast_into::<f32>().unwrap();
+    assert_eq!(y.to_vec().unwrap(), x);
+    let y = y.bitcast_into::<u16>().unwrap();
+    assert_eq!(y.len(), 6);
+    let y = y.slice(1..).unwrap().to_owned().unwrap();
+    assert!(y.bitcast_into::<u32>().is_err());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn buffer_concat(device: Device) {
+    use krnl::buffer::Buffer;
+
+    let x = Slice::from([1u32, 2, 3].as_slice())
+        .to_device(device.clone())
+        .unwrap();
+    let y = vec![4u32, 5];
+    let slices = [
+        x.as_slice(),
+        Slice::from(y.as_slice()),
+        x.slice(..0).unwrap(),
+        x.slice(1..).unwrap(),
+    ];
+    let z = Buffer::concat(device.clone(), &slices).unwrap();
+    assert_eq!(z.device(), device);
+    assert_eq!(z.into_vec().unwrap(), [1, 2, 3, 4, 5, 2, 3]);
+    let z = Buffer::<u32>::concat(device, &[]).unwrap();
+    assert!(z.is_empty());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn buffer_copy_from_host_slice(device: Device) {
+    use krnl::buffer::Buffer;
+
+    let mut y = Buffer::<u32>::zeros(device, 6).unwrap();
+    y.slice_mut(1..4)
+        .unwrap()
+        .copy_from_host_slice(&[1, 2, 3])
+        .unwrap();
+    assert_eq!(y.to_vec().unwrap(), [0, 1, 2, 3, 0, 0]);
+    assert!(y.copy_from_host_slice(&[1]).is_err());
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn buffer_arc_slice(device: Device) {
+    use krnl::buffer::{ArcBuffer, ArcSlice, Buffer};
+
+    let mut x = Slice::from([1u32, 2, 3, 4].as_slice())
+        .to_device_shared(device)
+        .unwrap();
+    let y = x.slice_shared(1..).unwrap();
+    assert!(x.slice_shared(5..).is_none());
+    let z = std::thread::spawn({
+        let y = y.clone();
+        move || y.slice_shared(..2).unwrap()
+    })
+    .join()
+    .unwrap();
+    x.make_slice_mut().unwrap().fill(0).unwrap();
+    assert_eq!(x.to_vec().unwrap(), [0; 4]);
+    assert_eq!(y.to_vec().unwrap(), [2, 3, 4]);
+    assert_eq!(z.into_owned().unwrap().into_vec().unwrap(), [2, 3]);
+    let y = ArcSlice::from(ArcBuffer::from(Buffer::from(vec![5u32, 6])));
+    assert_eq!(y.into_owned().unwrap().into_vec().unwrap(), [5, 6]);
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn cow_buffer(device: Device) {
+    use krnl::buffer::{Buffer, CowBuffer};
+
+    let x = Slice::from([1u32, 2, 3].as_slice())
+        .to_device(device)
+        .unwrap();
+    let mut y = CowBuffer::from(x.as_slice());
+    assert!(y.is_borrowed());
+    y.to_mut().unwrap().fill(0).unwrap();
+    assert!(y.is_owned());
+    assert_eq!(x.to_vec().unwrap(), [1, 2, 3]);
+    assert_eq!(y.into_owned().unwrap().into_vec().unwrap(), [0; 3]);
+    let y = CowBuffer::from(Buffer::from(vec![4u32, 5]));
+    assert!(y.is_owned());
+    assert_eq!(y.into_owned().unwrap().into_vec().unwrap(), [4, 5]);
+}
+
 fn buffer_fill<T: Scalar>(device: Device) {
     let elem = T::one();
     let n = buffer_test_lengths().last().unwrap();
@@ -286,6 +474,37 @@ fn buffer_bitcast<X: Scalar, Y: Scalar>(device: Device) {
     }
 }
 
+fn scalar_df64() {
+    fn assert_close(x: Df64, y: f64) {
+        let error = ((x.to_f64() - y) / y).abs();
+        assert!(error < 1e-13, "{x:?} != {y} (relative error {error:e})");
+    }
+
+    let pi = std::f64::consts::PI;
+    let e = std::f64::consts::E;
+    assert_eq!(Df64::from_f64(pi).hi(), pi as f32);
+    assert_close(Df64::from_f64(pi), pi);
+    assert_close(Df64::from_f64(pi) + Df64::from_f64(e), pi + e);
+    assert_close(Df64::from_f64(pi) - Df64::from_f64(e), pi - e);
+    assert_close(Df64::from_f64(pi) * Df64::from_f64(e), pi * e);
+    assert_close(Df64::from_f64(pi) / Df64::from_f64(e), pi / e);
+    let third = Df64::ONE / Df64::from_f32(3.);
+    assert_close(third, 1. / 3.);
+    let mut sum = Df64::ZERO;
+    for _ in 0..1000 {
+        sum += Df64::from_f32(0.1);
+    }
+    assert_close(sum, 1000. * 0.1f32 as f64);
+    let x = [pi, -e, 1e-3, 12345.678];
+    let pairs = Df64::f32_pairs_from_f64(&x);
+    let y = Df64::f64_from_f32_pairs(bytemuck::cast_slice(bytemuck::cast_slice::<f32, Df64>(
+        &pairs,
+    )));
+    for (x, y) in x.iter().zip(y) {
+        assert!(((x - y) / x).abs() < 1e-13);
+    }
+}
+
 #[test]
 fn buffer_from_vec_host() {
     buffer_from_vec(Device::host());
@@ -315,3 +534,9 @@ macro_for!($X in [u8, i8, u16, i16, f16, bf16, u32, i32, f32, u64, i64, f64] {
         }
     });
 });
+
+#[cfg(target_arch = "wasm32")]
+#[test]
+fn scalar_df64_host() {
+    scalar_df64();
+}
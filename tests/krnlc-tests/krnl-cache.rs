@@ -1,15 +1,31 @@
 __krnl_cache!("0.1.0-alpha", "
-abZy8000000@}T[34b:(!T[]>p^wMNFqN=n5FOwm3[dQEk)sktv3gdcHlaV50TkrG3Yv%(deQ.Cli]U5rstL1H>bDVydknkSia!8WW9{Z*ROfXO=rWez/Pg$}6@lq[ooq0=yw$PB7:{g%c.$zSWWLyB/NB5t3)<UEajmVH7ETv=TAQuHI7mW+]5rXoVJghAZ]lP4emI}8i{yG<%E3a^Ol^vgD+Y(iCF[iWuKYkkc9XH8A%wPC)MU@&J&[Q?-j=q8MF$<9E6-+%4-*$mR7SxI[C{!4f5!mUKi(io#qal]KN#2U*q{AX{=Y<%7)ve/7mwT@M!CJ!:Y=&@M&GjS!ps2?=)%fbk!(r*tYHh+oxa%lPbJTNQtu4>/u0UCHVwpM&C:X]vgLMOogm^x>QVpEWfA0PJ$+LYt.{k*Qg5bZMZF)y*+?YYZi<]]Ng6ghRX1(WaOPU*dS.A:?Gr(^s#qjdUg]rRRB&b&nC)=w!08g8(e7#+7mmJ8:gGm!el<4p^g)!+-H6nK[<CLnm5a)fal8[b%hXEKwncNZjfCKyOcsSxO]Oo]c0S}RxKzlS@#MKK%JzWm6XP>j2noPRj=boPq>T7Q[2$izYXLKLyAfg@Mq@$9gH?j(9D@ro:#BBbWGA<z/yd}(cw+s@:45T[*8-$WJj>HX/eslD3noCrNvYFj!2hkX*o3>1h?1Fb45Ht4Fsv.0CqSf*rEgRAIYs*=QdDg.s%$CVTA7c8#MRbEYC^etMenTtVX:x.#qdDs4mTyL*!i!/K23xBH)Iw*]Bd[:1}.r[a/2%t[rN-KIg:AX=Cq+yVbgB]#![fj]zt:Zv@5Up:$)-bvNZLZd=%3!r-:NzgDB=f6QMZRSTY/}M.gF?+z>.I)*1S}/45xml-:LgwJ<zVkF:@o)VMKMKre!A.{@<PUrH.?A4v=gM=tyw%3zz^WMjU(32w^hrHOf]7/oxCzKKHv9pZT]rm#?]{G:k0n([g/E?&VD#wY4eClTY{oPtuCFtEu
-.@.u:HVybUUCFEelvc/{Z2/m30^-2(pbhBE68aE7{BBOfdM?{CKUp:2mPJCJBvFBmV:6^X&-1ova/=jsrsnVaccOZl=7ITLHo^}qKs^m7eq0?vjBnPg>C&M0u*yK3j[az/KhZQ[!jzrFpQ8B8)k0rCca[JstN0sRxfMmqm&^G#y4?^)+v-vxDKZ<oV/CwM)Q*3c!^n$hhzVFIp[]iOOTj^V.o(?mW98:(E(eAfYg8{o/newK8weGBbz9mql#.&W<MA3s4Zb5nB$IdCBRpiI}[FU@%6szZ5V#/luCMmCa4NvAdKdbp2ySqg(PlJDiFXhX.Q=ZiWsWp>^y/&Gt)>/E9vB4XIq!S+@#O]GlB]9$D.DFXV:J/g3c@Ls>J}VR&Q2Avij>J{/$AiFyBq9NbaLhuPr[di<)oULH85UkF&ms&6qUP6nXocGy1BYW!*eYOldWEip9J&7l^P$%pN}^djR@mrP<KeU(Z!kBcX62ts{RV22W6uf03MLRm{#.mc:m]Hg/V/nrF0c{9FYQtPP+M{]G:wWFq?*dOH9scl0od:uYY5e!jgQoJqBk9*XZ!g!P<yu]*X=[/.d/k:y>7}H[8j!l2$mgmDAn5ksA/}&/J:-AMCY1l^Ax4OrHY%A{=JW*)#KZ2k))slEaycA{=^eiRSECLjKKL)kwWP/Qqu<=[?U2GRd&G@cQo$Oe/w>:R1Dv9kP$y5=wds.o.!}pRv4FT)+=JD8b@Dn:#uE34v]T=N{1vC7o1?2FMGZ((R1^(.ij1gw}h:ST3nv4)d$^>I-@+?5?k!=QmWeZ#sP3S[teV0dsl@Bs8bbQKIp}}#/ll)F7@(9dDg(.{wo4E^Q2fE13Wte5GRTw:<+E}tu::f[dz+Lu8{Kx)$Iec&0Ibk7m@=:mw?tOTwu$3gp0S]MW%ddT%OpscT?5{FGt#(6>YtC!g8@=O}]M<lq3x2dC.U57pz+*xWA4m${0/rp[8K@Mt0H<JkclevoE>>NWr?&$B>@A/:R*
-(PUTMFUXSX.MPxCt<Q=[GNL&AA1EAK{lG!g(q^hDjs*uNY^M}((e?+yl=6!z0X#=kU2OD7.e$gcAETc&f=*wnyg7ft{bIQ{J[/}eH)l(zUNCrC3}3%4yF/*zurxARl&xLZxy.Dr]<63ssX7:F:y=9j7j5B%Y1oao[<rj3u[V!TSEpuv@U5@!e8HtL5Tu2UHsi:tLHJOhP}<Q#CNt=(V#@>d=WMZMie)G:2yp.(>SGhmx[Lx])J.iuW%wNUeU)+snD7sf-Gfj+DM!#X<zMrnku?^DPW32&(#J]:y}CT7w}waQ(T58w/PdlLVNzD}{G?P&>okuaK?Rt2]n-.W<s-Q=W&)#%:LRTFzaBOL:LR71E$vK(J@h?%%kIc10R@TNt>VUlWFq8K0^P(6]0oHy&(HBJEZQE=5aR=w@cSwDu?j36ZcFgJqU57Nz5bR]!mO4<}90c.tvlNgr#]s.uhcDZHRBCGUl1Qd9Sp5Lu=gnQ6z+YG@J&eDRLyt*)(ylfPSYymF8TSeHW#F+7K&dV]KvFTGz}^e@[<BHNwo^p]-9}m@$UwLjnKCS@{H)akskwXJ@A%<%dl1=jlVZ}^F(8@?/!Tw1n#N*}MAo^KFz*W]J#?muXej1J@yY8%b9PKBlC<S>(isdF9#K?Gz@iq@=uDz>JEz?Jq&27HIO7ebG6X)zYt?5jB>XyXvq:t-$Be1X<i^BcWh5]JKRHbX8oohZv<FIyY0cJdFUjf?%z-[M$jXa5Cj-FJI>A7^I0gktM3Tx}ha:m:]?@ceGo1%uLfd.br&zE8dcBrBrPTjN>v2&wyG>28}3CBTFPE&gc0&m0t<CY25]e*z:Vc^)@aAsuyK*jhH*Tz(YibLzQR?*(U:(5Hi{x5Yl9z{IJcrR*>%5pbhm/4?Rum%70-TrSaa{<9-xm:d)F.Y@[j&Em<d09L$f!aSC{ezf}6SRR[$DTPcqLC)nRFOe4Z)Q>k(X{}5=Jt}efP-jV%%dJwtM9D*>rHTO@]IPhKT/
-GR@m[ondBl=+J2&]o7hmhF&bJ?ABC9EKC2^{NzPNis:$@CDkXx<dD+xrT}wEVhj6(]Xr6=ZAz#9-3+M)/$=KlgXZCw<K)IL7PAvR%c01*dc0@^%6+i*75lKwgxdAYOzVt[6Pru8H1%bQI$3r[MfT!agNJN82d-nA1F3Zi]e+@k>mcUoK<oCT*XZ{:)1S%!Zd4=D}elSSPQY}:)qH{^gUA/@tGIxnZUTXij5!fc&HU7w>FCq?3&8GRUIRS-(*3lLY?u8@Kmq/y<IKNTlZh=$(hqWm9$)YTaA]KJ<m:-UE)?#DF=rpREy]:tuLz&$rk7Dwc&BwA@/139>%4L3pXBP3>Y3GHo.x}iFaY#&.2b.ps>-<[Xl5LUD&?#e]8yf1@h+xq@W4QkFP6PG[3]nrJ!Lk32&?LsgSEfLxn*tNa]ffz(0azLiao)s?9h>/)hm]]rpV0rf{ofXl?j/CT$?YR[iS6-[w=wUDpDf%xl=$(7/Z(f[#IN/BZOe[Ji?G7f>$@5=kt.?m:q&d2$ao0}b.Av!fo&j[w=yeDpDf%BV*%ZJax!!@cFux9#KUR<2)9DJpPgSxn^iXbwh@tGDZdc}D11Te*Myg[w=x9jr[G$-?5qGmIAU2@hfFz3(zXB<0andJeG?zxn/FCbwh$$GDZeT}D15jtUk?+&G2YC]nGg]f87r{f<kfz<x{>MT:$D}vygOI-/LzF*bG@pjr[G$rkK:{{wQrYJp}{AchYGLy/4:(.rx!{iB^dR49uobZPE^XrFn:a@URApxxk30-wqLO>T(XP?{5{s0y/2+{.J=Z-<X8Qxn*Q?bwh@z.JsY-@=GknX!A9R[w=w8CT/%}jV<%4JgLXC}8wdKfjrNa7#G)e-<<bDA(N)Sxxm:*TMZ03EA%Y&Ily?%&yp@CKLxrr!:AQJ^ky<M6j)ujAAULyeznShsu1a9MLTLxYXb8=LgLuSF]$tAu}Pn)<oXoWfS]GB8d.HFgQ]P0C]ZtI&U*KyZ}tY]}G&AF
-[>a#>7*RYjJG-^5WM<l&zu.!#a<q3O1#=TK/o1?*CM}mu!*0%AiS!tRc/6]32y3DZd>[dN+UlvvB{m3UwoU7!A3.X8D4Id=J5h]Mo14(].r?JLg@pl@8qHm/oE4e==!}BLi]*Ppd}2-c^YHPlKy@JhF.uFCB3b8A3E47iM*kI]:TYYHu]e9T3N:=2yZkp>W?QP35ATLI&i&Y03:N$E46FUab2o[Y0hC%CzBg^0j+IGeN9t[TM$Hxc1g5(#4FHpl<Gg>xu]d<MIJ.{yWJ^[!*xr()GQIoD*:o*ga?J(d.ej!muw<{OJqupgNqtxs2dBQ!(L5AwjChw*NdXNsV$Bt7UrhpTQB4Wfjl-hAjh%7^:?T#LM$5piN*^J{wlmXbt/>eOO)UI3mS*7:M27yg3#O^ErWVbCj+D@LNaoq@Om=^!UoirrbmbiLOPCH$J}S%1rBw&O+N6(STQrp{woS/wA3YAT[=W$&&^G{:Ki@u>OU0U(0&vf=Yf7.n1wzmKCy6J}]132vfG]PUq5bP1J4=S92kn#f(.>dBcWt{<7=Z>g/?IeG)LfV%JUoxb[R<QlI2O[vV!:VfD8r2%if6G[8tN%mM?W*#Bpb>8bd(C.]071QYW5y]V3TeX)<BY?7TNzGNRfS#q[V0=?7%90W}0]9O@}+Z(C]<bbpG4udEW2PXn&n5O-n@o==x$lw{M{8N-W&5wXB8CkIE#m9Y}Xls.ySn>xYPYO60!rgJG$aajCEXZgV(#gW2{2-)X<*)GgZ[ZyOlIw9zTSLOV]nP>AH)uv}0q6MU>1M}M1kM?]q?@jXE{}b.(Oj>k<F33qFUP%4}@c2m?)c7YUzDEyLsDA{2InUd:4z6EhvO($V>M(z:9NriQ%x1=Hu*31d:%5mns65L^3ca.7xe+cXrIao.n4R+8K9}LswPxZkuM0-9GCLP>J[u[hxA$vJS8Tgi1]!=rjbe9@Jc?E3p@oCG$4V]JPQ:LEefz:o[^0H]Eul}:qxzwrMY-#eS
-LhqX7c*IO-L4K(yZAQweQ:LEL[a+ID^0HMu?x$D?z#4h-k7}+$R1UF=:Vv.t<tNBVkAT!m^V:u$ZXMFe^ea^D9vi<KCLUN:2gimq]P269Ty&NF+P{)R>r:F^N=Wux)}qz%w:4ng6ltm>c*LZ3@hR)JS/ZQZ^VX*!tk?IvDBlo%NE>46U+3u{HH9a:{sx:}XhFQS{2vG.]h)pR{tQ>R[[CUdP/ba2.l#L?hp/^J<C=D%9wY<L=ao^NHUzYDhq#rUJ.OaJPg#YeoA[*u9DCmXztU-#xkK}&RChsYUlobp3<8yMpp.}duzF3Fo<?0H+.@)BTQikpwoS>*XrsrxC%I&uJlj)RIenU%GiG7TL)N4Wze=JSVOHjw=<0OxIJFZ[aB6=GLrIEvmn4GpLyPRr]d>f4LyPRr]kXX!c2T*{1MH):ZvkQXm*VI!hN{^s2yO)T=>Uo)9ZZ}t1>*rm2kn$OdhH}HfG%H8n65b#hN(nd}3yGr!DU:@fH3.)Cg$<}mz97:j{5-@O+bA.O2M}=5N+QafXZw}DJYZ=cC89KAf1y%vLMZA-!Ns=Ex{K:8@cH8}5%gzyLhG@fH3?{4+/&&GMh)*i1g:&O+cu@)wHGZ}[NPCfT5[e{KcC-olKxLGzN<LdhH@rv7+P%?Z3@K*tB1R>39I(sSuelZ<I7bX[D.y7#(r*[Huk0BjdmPzc7N[?VS7SGEKa0C1b^G:}Iy5W84e7&$NBw0X^@3^L3ZHX3S#16{{S^>c}+mEheb5K>)C0&j+7*IPaiUJdT(KkIoTZGzLZ74?-F#ze-?Z>0uq+p8?$8fIX>&DW$T[LIOWn/k32Jadb?q<{*55)G.[6wy<f/?2k>Ycv7{mDtl}ui]Y4Sq/p8%Oygt>V3F6u<ucikJY5{XppS}niGEaMTiA?ZN@>R6j-fmxpu![9@Y!l8VoE0-mC(a-Eyki(7<dj>ojyvLppTI%sD*=O?}7tn+HGxc/>q>M4]w1fre8GfbKXAt{-Bwlzw^Q}
-Oif6aVpWio@]l+ztUI.%*qIIOxn+Ov>?p3)64jD[Oahb&c=AeEC.TJI]5NG48k2Y:.$$HNJOJ9kWL>CCSOE+AhJhfbKqlaZAiqag.ZG%QoMuxV+BvKq&o}*IV(Mfa=^0km4BW^0Vt)Z(ZRH$]o/WH$B+zWzP^m?kmLod>)mug8Xn)/(X@{ZTgK(iW*{VfHLSs!LJIZ.#/+&kPxJ/:6TbU)#E*aMEwfn]K?2zlB:AauGzL$7f@bIY[9{1ke=![}y:$/jjwad=lzP^I>)8n)ivbqjl[Q#f7vm{KP@XUp@CCumwDZHRNZ87&j-[$rz<p/R(6.iTE{Xrut{}8sD}]uhKtIZ*QNm^j0fwt?7jCM*{}*+8czswiQBNM0}psA/Z=r8avps?$boA0lDztJeO]QAuj@:%KG}HyFAumRTt{Bu0B@a!jUv?@K^NmJm>)-R2[4y:PPES?>L[#?RON$(2P?x[E%ZbCMcW12(2++PG90RPqYiP^8wqS-Wjx3<=xuYevcnqEft5]D{we8-4j?Etofo!SqACD0HeI^Tq!*h!d=D)}h-+E9FA=+Qs<6Rb#)jtlp6I2G:0?V.?)!w5c7gJ7@>L@t{}Dr%SJPj3#h4p<VX%f+@EiK:nS!T$[snLCg=6RO==nuX!3SU>:7qEJ!j/ga55x(alSeLBDR&^TbXQ+0E-etxEFO!G:{Q<y{SDs6aDe.XoEq>4ZQm=6BbM2kl@ztu99%0ErAj&mkK*q)t]tr!{y?1w7a*wkk{fS1WT&Enqs)&{Nd*b{tBTCCWp>?r=tCgct*hHRy[QHFA-BzTlbM#<B$H7w%v-o}!=?I<QMjx(fNyo}pl5O/7eM)pxda>]Id-/B?zknW[L@J]]cgI#&#[XE0bZ@J=R}wC-%?L(I4kg&1>CUXrXalCCZPc@QXnJYJ5l?Y{mFQAJU[vJXJwXn$GtMhlOZdGR$e:Il5FXCCh/ZN77ahP@aA[&RF<*+4+)8%w<SkNTIu-g!M(}ptOSQKEU
-e8dgnuY.)1G!HKu-{Z{YasDA]rqssh7v2XdIHC1?^+cbz-w+AZ{:LpSDKtkOyskIdMLlfNOWsC]f5v2(9j0:RY&$CiPMw$Hjs+)]Xkb8]Vmj#i[-7p1i^xs{qKwMsSogoC*hWTBP4h.$/V&ZPh!j>F*L@wJ.XE9@}B?Swpm[v(h(>n9V76qbk{(RFp=]?u?MjA>Z5<+tcIi0w}xgqS:G{hJrRf[@cciBNy*UoD7S&0AtKGREJ4ZWJsk{*[D9h+3Ea<hMr(pIo[l.%ckUZ?3dL<{CWRFEr:Kci[&:RbNQSF?{XzH<EjGbeCG}3SAT8F:H1@hL.=QM$UHL6[hl6-lFR@?qzl.lUR*^kOp@h[9IW4@T+rK-S*jn+SP2QelA/f+Tjwah4:/[Q-W)xV#gW#{b1[v+e{E+rSOU}FtKEZU]umDWLazh4%EY3D{?PyQ:4MK&Cv4z8)U4$o#}cvY1&[}kFr2k%pSVY&Lq7RU5Uu*ItF>t}iWK3Z>%BOn1r2n>=MhKJipkJEEXZ7eIrnXLtn=.APcx]}$d{@/J)V[Bn%QaLDpU}E@^DPIjm=R@I6GS=KotUiQPF$96Efh{KHuTvTB1]M5=?</9H&UQXQrRT[T&7A}E5]]3#s>/oyC+z#<&lCzibyVEuHO#+#bob+4FC[!kpJ6b]//F{z<N%d^W&=RZwJo)2z&?fibyGCdA[xZ<)M>@z1b$<9@%4=hW-5o}j=zoX2!JED3#OX0xzX6u^7^^L((#iPJ/T#M%m[ql4<hSpr]H>p[1C*6GfN@5n1(bMl*Xz>?y9$Ya(L0Tw!BKlaa5*^3BDP6KBzT8<swy}*:7>4Z]kTPoLf*N4Em0S)96S&ot5Y<N*>kko{@[*]cn#G>Hn]EIdCrTWP(8nKp9m!aQ}l:nZLkl]E&s-vm#Fly*3intt1[j.XR^}1Z#X5-4X73vt+K)gb8y:=*9qV:?VlV4Pv#TPj<Bgxe?a5A6wWb3F#Ucimm!MB#TH=O%8SCKcaD&
-I2j<n8iek@3}EQ%wadOZov96@[{NY=ls+BrrXZ+4a[wOtIgs(B:Od.s[?.8MFI&.r=ZK0H:tq+jl/!a[<M/Bi)/QUqDnwbCOaHqo=VX&+qu2m#<<0[n*%YIY)H*.#fY4M9E8.t5:qCoj>CW(je$}2*m7iKoQaYy5}a>4fSIu{h?m(a1+f[(G?0VlrzX)J)hBH>q]@][&l@0$YTS<xMawh-DU7U3YUlA!PH#DtzA!UM/U^-!0eEb<0FyUH}Cxr@LB=^3S5--Hc{@{LyP:l)b1l#8@iX9Q859eOtDgiQ2B?whKh9KydQgWR-F2Gu@E4YL=CA#*LWIp<DVr=kL3blt=E3>R-MNJI]Ng^u54t>96m^%+fHC6QgE=DTI+o<bEuW!prsqQcXhGD2NxqInXg9cvgH<(uo!$-FYa]5wQ]EULJHjvQ}0xh{REgHw[gE]AQjVKwpgM-ZW?aVr=cBEAppRP8viYERw4Ve93Ngt<35$.6N[m^Q4O?bH&]ucmBYO=k[zO9vi5}6LL}lSY@PeIL:^<tVO+faY$bLXKi.(1rk@ZwiJRX:&n}bAVm2b(GP:Mls0RVvo]hBWMJzxr+^F>IO0?/tZ6m(fus1VG+vD^lYxBG09N.%@cP^J+zD0cGAR7BM-+ySAo?<8Bmsi9XH{XkX+11}WbcOz.*5)nUP/olnqt^+G.1x*C}/G9:ge^P?9r1EAB*tZv:sesfxxh^O&aCU52%hB?4P).a>SffGQ]zsF7$qKoSf@{>SpZ]bQI}3%36F{{s@m/@M2[m=$ctrXrgx+5@@xY&3d?Y<d99P=!PxNFb@Djj]!Gl/H4{?VUvE*F)G}F+--igJ2R4YwD&/ZSVtACyymR=EGBh.u]^E6oez?H6lByk7!%6p:w7Oz2fsq^hUeykSu)zaL-2gj*-7B2-&k^.CY9ijh+rq!4!femCxFJMa2H*7KvGu(7krEY?UV+B&=lrODH?>?.5qZ8V(M&bE%#4Ytu8{PWnIHWFn-S^IlE
-Ao=5rq]=(o!BvJrB/-GWv#jb*b@&q0Bg#j8yf{.aNPzjs+$Q&9TF78*phH-ZSJvv7Wn^vcuo]MRmfDb?B#V(s(9&UVphMGU!dXu4BheC:[s:M*2[AOlFJ=XB)/q+<&m3o7W%PW)^hGPuQk^{=P6{T[]Igfq[5C8dnkB{b1N@z^oZ}q#KX+Ca.(1gd[Ay5<jmtmZ{{s)&pB<qW8d&qKm!}C%T6w:NP9z@(NsuSpo$cYXhKhY(0EH6IRSxL)iaY*ZUKPE6VJu=yVya<<m}W*FB(QX7Mr+y&y&=>subn7cMr4K}AB!GLyo0d%o%}o04+[AtY.nhO!7!:q3nMrg<A)belGH[0d0]b=*0=g$5]p)MvohmAUYo$Tw=1lQYp{-nu5g6[dd::pn9N:^bB^*oU-=BXQKvA8NkcC:Bu#H-ov7u<Vm:q$6@IM9z8JJKa[d8mj<6ppuK)-m2B84{O{c2ni!KbV5I}jU9L/zWedEb9Cq!cSXl+[4UC!t[wLN}3ZeBDFQ@3ZR[y^$.bKzrME>].%bn+&(IODfb^]zj]R[^-Y[*yjhLmS.Hdrt&UeUxeOo#M/Eq16.uR5)aA[p^#sveJ^(jm65=ZU5BzF6V!YI/d+aeKK-#wUPlq>Np}:e[+uo>$*>/]7yCTl.u@@Jj2oB{sS$vMZxGfJbwm:LaDla}[?d<>Rd6hbU19$mpp5SmT0s83ehx!):Ftwe(/ef[gu2q+FqT3b[*35JBd#2u$K=i{oiZkB)v-Tt<.q>1gCP<Zi21NlH[<lxAbouT+g=qPhkDA:]sy&Gc*34P6xX=P3G3511A%Gva:XUZjYP3aU9:&Uu4[Yg{Q{PBzK{-L600*g@s-FNp1z6ZQLo3]RB-#^E}2o<C/}@*Fx{{vOLI0KcJxfNpC?T<C.hO+]wQNu}+g44<CcQ.366KsIC<b?@.fx{hTpni<s7}MT{6.d/pl$Ld]Ra0Ts]41Z1/$3vc&U=J}JfcCM2S%4.AyIw*8*@c*uI3[2!m
-0kl!U:M6jc(C1GS:CZ=&40ed$5H#O+t*YGUVj/9iF=bVX(U^@$>CR*R6eZhtQk125PnAE{5McDgH]s:.6e:PEQk55%zn^kyypC6hhvaeds:BbsS9}QPQfbfm1NB*vvihYf46(H3{jHCn0RitGlEJGl]Z88F{hw89cDnb!un<R3vLCp&Yy[($Xf(5LmgDO6Xw9p)[#PPa<wgjjThjv)5Hzh42DVlG.=(&{}Aw]#L&G7Q(#cmE)foi4psLx8.UCrU2pmC#me/cb@$m-gO8w=v!XL<P8wY13N$kOsD)^M-IJDHEL<Hgh(QY7s($:nmc[qI-EdUqsM#/I6eHO?vsk!LBSFIKZrW.Y1LY{tz*QwzVG(8VOaRun&6d^%tF}Zt(kr?NVx/!ugp>:xkW(VSs8W5?V}e*Z5!0}K*27=?pVhAGwB*8hCgGr.<.6TqtRW+QDlbjl-2?Ve](4vmC+tfcv5gm:JIm/={P<6{?v1S$&l%!u-EY!N1i*+YnS9(rqm6H6O+)P@Td(=^iHS@Ev2^v}-4m4.kL(dTaF6eArtK>%%.L@grp7TSFZm}Fm!&k>H]mVFWl6iSWBZMPPz5<r7IcH2C::V2D1XWR%r0Ku>2:*x/zV>!v&Q]RlU57^3EG22L&wi:]err/b.@(xR3Llu]7A(KF5k-7TD!c&OQW[W9JlO[8L[0!>D)F*e8kNy+C.B@{OE#RcwqsL<rQv(j)eQ#[Oq@SZT@aT3+%Z&IbT3#BQ4WYWcp8L]i?ZTBo9Wrmu1&3C6^8MnBjUzLm#>keyC-CrLqlq<gP%IR:bW(9[v}vUEu+mM%1#BfjQPQJ=2EG/QFU0i6PyeQ+Q{]Z9?b8zkwN^}9Vvf(vI(Dtd}$0(z@n]^rK3%t4oq=wRv:m{8KhjQb+k^(/R7RdH#yWExsG7k!5VDhGX#uPeRRE-t&X2-s2%b@[hBrCXf[iS)9>F)uXG(Y(<2o?S$0-Bo7OTEe0QB#6@wKvA1ys85Iw#DHbVzJmd{Q^
-8@d{Q10(^cs7R#8>?Q^W*AzV$L5@504Bv&LFr$>hyn/096SOk6Op>b=9<!u@zm=U5/l[%F6TQOY3GGCtEYqph9UetSSN%1:U0!9e5MQ7F0{Igt5N78=Dv-b}j*dsdAe$w2!j-)3EXkLp4xsxZK6}(NH5la(ln/qzrmnbA.v00gW9Yrqq(pp}dr^+XyXeU<Y!l9[HyMk3N$ZBRR%Ckg5[gVVGVn6!1D-NCB#Vca7v<T3345<AXi0]GDX%qUy8w*7XiMsJc.b<os=SxIJR!#Nw^c[%6y[j{H(+hKd}7kdM7DfRh[@}&wejE#@*EAv)aF]+lYqizOM@u<UEp$(zq[HX((I]FUV<!CBay!7C?ghC{)E4^5*aCloT5>?Qm67i+G4U[ABuh%4b.0Le!G+b*ixsT{df>=R[b3WmyygeOkzrxGl3K?:ns]zl4Z{.>i6?Ys[V^HAeL.ln)n39XImuO%3dN!pgmiHRFz$SE-vU]*qYn=q[^9G(fmGwS}pyQjhL+H5:M&=D7a+Y!7rz}y+U(!4YE&([a=}SYSE^7D=Avb<-LNub/=4g=t@L#={4foTY[/$D0y1uTI#.Lu./:Pw.KX%IAI{c)Ov=0+:@g4c!?8dLxIC2D-Co9dT4naUNmp@tJkS[d/W3U=.*faY6+y(KNI{76$UMoZLZ<dEN5Rcq9Q#r))<1E.S/!sdT15M3(cw@EhluKj}mHKh013}=*Dp>[-^ChOjW$JEb}nq^vmtl{Ps4#VWjlKJ&H:p]F-&0:i@-v>U3GyYu(<v*{{yrn7m7$di151D)s3vbWRe({m%Xrkc#S<e<>3r6lh{yZ/*[7Cn*r1@/:=e&%{uD((70U*sJG6Ut]cQ{GV^PC]wZpnA:*8Ehg/pYb5fClvoLmZ7d:F@FhJwWz$/iFBiH5*p{0R0]DTU>Dn4z@PUu(y!p49nOo<0a9G9LJZ6$L[oxunZt045lIm)W*e)&R[7p1iEunm!HaMiO)&U&RM.T0>F:DkF@MMJG
-<901Y}KtJ8{ZUfoCQxo!}5e-O)OqB8gR5A.c0#Xm?(T:u[gB5w{:[h/LJWiKbyZt^YwA.Sem(*>pEl%@@}%/a6!Xl*JWau6B9ApN7Q.@BUZWUWZGVAXg^3zq<AzI=ZZ6[0cO9@B.Uj#x.Hrqqa0F<vFX8qSKhgHk{<6}LzZSr%&cL.bk16&U.s[DmWsg3K=T%Mck4Q3#{!J22n0d!Tj]uY.E5(lLmR65zSFIk5x/43Sosh+7!GfL+I{(BSdkd%:BC=M.TGuMWan[??:z6dhcedxoyMwQFOl!2dItlvnX5XHHdA!Y*Z0cJS
+abZy8000000@}Uo2Cq*J{5ren3izyUF(h+^Q9pQ(IHXs90mAAVU8&quo:6/OJk!$elLO^#n:/}Z5Bo-p5Z/kshQ)AWO{OgWZDp)FEen]CpXdcd}@Un7C4L{ziKu5#}hLwn[7^i>O{9@sEGKJLrVdYN}0yd/k!!vOlWGmWi&4l6Zp:ZY>*:Px*orSC(EUG}K=/AmogXh=<=quCz1H^L]b}.2Jwzu+}?(qP?Yd$hu^=I>(Vm*&)rJPK*ujsFI6!Oynndgy@+0z:wbpyxa0yitb0zo%v?OUqXwWC^{V3gA[=Iv2J5-EJIBMXnQU&xn7MlBgQtDufBXlhLs[E5grb9GM+hli)B06=1k?UhEJoRPB9SUN>9cE#Vh:+puPv<2[d+#6xhNp{igT-C?yW9Bu(0GoSW0R=FlK9nHg35vbGs*2uGl]68[%%*WK6%[YCV[-Q(S{?^/Ffj-/!Ir{4n32JhMU+8O4iE*R0apukF90Ew:gIl>z*4CaaGkk+m!>:e?G/.O{2K%JpbJ#Rkr]y3ePUdEA@>5f(k+V>&vS=1bvt@a*1nOFAS!.MN^Ts4Gh[r@A*i0jsz2bJ.ud}2@}$Q}1lDKNKay8+[hJzxT@}+8)[x*+l7@8l{kMp(FR6<59+qged?+3Cxv9Mg6Hm-u<=.&6xq0Yt-M@O2g(Cu&kSbN&i9S[N}xGV<faIG33{Ggf)5]Bi>u3SqhW&kJCexX*(Rc?jsBnmHqelkA+>fv4SfNwi]Y-T*o<T]g@Afezf#AICuKNc[90)2{h6{eRp00XaBucFe@j*WHv-SA]5Q.q1-MXtMBF0yO$ZYXwsH$(W8GPMAYY!rK/kzol*(ISy>D:k/L7CThI(iz%5B[<bZHGa<1gb:dn+>93hZn1yBd/GQ}zPMud98Xl0Ggk)P(gcY@It8t[tb(3W@3X=C#m[-RA+}}}*wa:?MN}N*w>Eb>?BT:?Gf1EwDn4m0(yhYeq{*cXpExO*7Z*bSuP6XZ8sS(obszM/20w
+O!EJ>Z)o5g79g(0<c@O?2l6{rQb%41]1c3AV:xrP>UzA5W]1.?l*au/HvBv]:6v&Na?q(H?28?hM6n#6QG[U{E5C$KwO-{mXn8z+=$ed!RGMTfC9601g<AFd+1C0-g1nhhsoL8ktNFc2!opBWW/[i]]qB*sl4q8rP2/lp(l>#b9bTmsv]No*Hk&-fC@xF@?u@F(GwG>IN*d8Z.1h-D.{iwhVdLd!P8(L3DF+j4%5*ro?x)Z7533ILX!ic9<L@u:&FI9j)0dT:DrUE6A/%GlY{i&PlmGM?P+H?ApZO<Yd210uL!{6fo5VQxb0dfG[vBqQfmtQ$PzP8f]EmK{UL^>WHs#9mG53Q.77(b&vv7E(1sJf@c?U4?]/rx>l1f0NH#q/G7B1hnjOcWdQPo36&2mEUme/mB?38)%:Ax&*BXhi}A*#io(efjwZaG^DOTa$@)H<^ef65<8:))*j[ZuV8D*gfsC<j<DwWpo>HGgKw?q[yY9cV&6/2EdO6MR/=xFanGR&XKoo%&YbyC4}%ILLj0mByib[#4VGSLuka7Ybp}=eKSn.4>di2E@=pXj7Tz*Zd7%Ahs]uk95IisY#OUXcM$<qIRANlLl&!Qr@HH{%:$BMf!^+DmETzyV6@VKSj2S^$uw!cWotN{KWo3*9XG1shb&^*-@qaX:s)/eW$QgX-Gz=XAd41XAg9keVj.nf7{*c<XB)5*Mt.dFA[*HzN<6hMoce9eQd5/zRp[e+sr}.TjLFOMZKhpv<W]oC&[Sr=?}&DBfMBJXMxi+tR7Owe%:vg7FA.PI<6Z>dlN0iFOdfq<wk^VP1dmHxu[{YHH9/YD=5=^CXVGX?Dt9*&<!2uOVUFNIO&w+20IBOpE=vZ(<-D>R>XWy4)oH]cokJ5Gth=d*.Wof^lvb9bf8It)MtM.eiF1heiF1xZ++Zi=S?WmgIs-:RYS/jR!s4aR!ssi{Pv?nqO*OG}9*UgNG3uf<}?pdxwR-<RN7LeYq%5zlW7yLOKmPS
+NrdO{cuP*O?6zgg?2+{T?dj*8DR$%T0<sb%Y}Kf})Bx+9iDM>dF-/K.hWG4Obtn0.=S8bw7W^A9b7A<(js=PL2kFoBMVpJTpfC(JDiUkY47a$U/.km}<W0MY^uBzTH)TWhus4I*BD^}hX-La}A<PehS9R<P@1^hg[ia^$.0<DZ?&[p)El^KH7+fUB6w8hSS(4Vk.Xhe@zr7tn9{>h+MsL67W0eX9>2eGBJbgb0=81DPbd!k&wJ5b%4sNgAQ5GrH{}a{rP1VcnwMgt08=2N+N3auy4>1[1Ibt%QOnV1G5Q$hxA0jpxoVTt2dZ{po^G.1@?:XL&)bv6mPF99&QLMS0}uYkw!/O:JP[$CWI1fV.(@yBCJykoZg#x@@J6r<7)Fm:PrRjry@DlQ=*wr+)HKC&g9)&^FEp2nt5CgeG}SBJ37%{1#@QUNsbK11/BMX[/v)FuFY6#bpbiTpODj!I14cTQwul(hg1]X7zuj(?n0Su&Yd*CS!Ovli4D0E0cBx/eW:f!8riNs9Yl[OsiXzITqM0*U4v5(50rDvQ!bjYwiZha{04!sln[*bAdDluMc[*awkQl42v:E-?nQjEXn:E:r<P9j%RFQxV5>mcGFFpb6@j@ro=aFfRzXDqOR&[BeZXAA+${L:QQwCHc!%4KTo9PT%A<Icly>yJL$KPcI^@%nfq2j-<6@?z:TaMRz?<LLQ/.9F1*KLZ>}Zyy1N2L8^#@}eLQo#$6Y:qoIS0O?F*j[H8tG-o*^7jKc(:00Uq<)?z0Rf]SAN-J5p[?5{HQ$=HVf8a77>W90=/qd[aK?B%R1q{QJW&2SMEF[5Y/IoOkaM&y*CDgI![/J!{2FQaaSB96yE7bLet3=*Q:D@kquI9t<H>*w6iR!zw:O$+1<xh[KWn$9W}snp}1yCF/sJl#<*uowA@b<vWL%O?19RQQnA(sq6PBVD@bL{6U)@VWTdN4FsVNMP]3VdnKTy+D<NrPGUVQ{-*/<ZaSrH%loN0qV[s<RB{
+wJKYO[(:h5jziURF$srXeQny1fsO&{O(KCIO$ugh0RH}[VHRqpMug+iAGevGM6.0Hl6?H9BMIaclWe!hj54?w[*X-bLnBjlA?bL>hiJqhKB5=A{cw4u6vgT:-RF{S[PfY0oGYwY7J+I:{NfmidVa3gC?S!4YBysF?)mnO}:5M3i>^$76-FczR>GaQ60X{O]fI3-J5[YU*mQ<?Lrj)uYBRQxI*5N(u8(Hvu$P+#@S43[]c4U0LfuB?DZP{rgMk<d0O%ypR{DwT>LDc/UKB:)uX0DKpp:/Huq5Zfm7QnndLX}x{By0Fz/wE764.&=Z6e]/WBYR}}$r5NtviO@.ZPzI5bF?W}VlvgmrgQV{6nFjc*h-oaZuVA]dORXe69j1(/f!xENgFlSd5k}pM=Wxm#uK-pOIUWx!V1f/fLeCh<YJUsnot3l-cySgQ>[-MCbPe(BD+@Aj/i85vd}WdXjRakc=<t6quZo)4mXUOdY/))5r=Xs%o}*-Yh6&OIpD+Hvw00J-gEFggp(Fj&O)+McHkAtbs2Af#sGJ[-R}w7@V*wBL78Z5)1mQqpl}+f?J4j[jMnw8dnWQJiYXd7?V%EgE:y9BJb.87@wG[iW$c&oV0.lDYg?zdXepztCTbB6#yKYs^5HW<1e5b8$>ce9ijh3im/TJf@+?u[xW2M(CbxufLXxAw7m>Txn*1{(5nGefOz9yZ&+i9HCn2f9+gZUMcHltT<O8Z0BVnGP+Tedg:F41U/vN%sFW*[G&WC%^s:ibE]gqA1&-#:^g2Z=QQ:w=v{A&n=kq(VHvu<QJUYBuLRupT9+hN.n[Talr{tN<Ha[pgs-^)UJlqH!3Fn<Ee3mkqQPEJSw3^D?&Ks(dTanP?fGM#<v%27Ixn/O^(5s2TfOFsfi<5VR7S9V^{88*[T8#v>=mkwt^G&knu8aelfUd(^CI3z:9-?J:^BfNJfLhJ^>W*2i[PwETfn]faY3GMM@pM.bmVn#0eE=&=X]y(4EjPU@rJJvI
+)pLyPL<8lsGX8uhQsWBnRTRPRcM5z9ADLaCoUbUO&?=+FyqPsI6/MO?scD%uTgl2J1!Gbe1iatbXGf]AU.}fMM@:(iHe)3OZ}tM%23O$X?B^nuQyw[f56w-WCAt?WCeg+XfB7?3z3OqdnPfz+t&z^+pCsy4mVi{!3f.@cIRwu5eElA+rOPHFy^Ax+T(oXaiE3e61q<<=AUD>wvhnIHu]xsPKYDDP0CkoU8!x:=C*0(oJ0v7h6QfL#)R5Vho[p]MfMjsQ*xr[6nn<C!t&vmrpCuI=QI-]ho$An^-CCR0X}A8{dSbt@!*n1Hr=J&51u:J028}?kXF<Z)W#axeNahJmw#&CHFMXMozN26HYQMXIJ4hSw8Kdp:)YxJfS@YKRvCUPhW&c%3LhtObAFr0OjI-ARJ0.>YjN7Zh6$jJ7d?H<lr=LeuVJxy&.78RS*jyW?Rgqz&d3(dlku=2T56M!Z}6SkM<:*M?]Zo2xe4?Fi2$uTSOeduEK)X<z&*6Vdx[Q=vrOQG/jDo13V5g6AYtket.OwbSc(jk^ZGwDb2fZ0K]iOGc-EJjv(hxcZUjbREcM8VfKV$nSbI&l#&Uz-gE.@+PdSeW5<%N?ETL$2nVL.UDanqBdu6hVJf*zM@*UY9TgD/BijNKw[]SZka[VV2YRo35${j#-BC/*4emlZB3JPzj/o23!+^fS>B-Fnn6D!ZaHf=PqO6rPYhJ*ZvyMb^T}b=u4byf[B^D8Gqv^KK88yM.YKsGqy1SoV3m%h!saOi6Ijt{&GPR=]])RN/Ken!plR+F%wIBGsNI@xgPIe:7PPqslP+yw@R!DBUf:>tHdW/{L[7tN<3/gM5:9p%plis)5n{jK!j2!QKpy>C=h{B09o+-<O{feQsk5qSn27TT7CmbQZ[z/uc79*/?NV6}VAJA$8WhK<2.:=*9]=hpO!PsHxD-W/Gcjs3Q}]JPRN}tyv1C>oMU9n/HGwWU9&#=T7/+-%YbVLG@+CL9oKOfgT5kKB9zR
+{k>/**WybZW&&eD<O1!cjp=V:iU1By9G>=w<M5tskCEfu:O*+Lj%q}iE^%y@4AJ@&A!^gXO@m$xz40[TnBwgN:[7?!QVhQ2Ens(Nnkt=p:FucdJuJukE>Nee-D7J%>^Y?o9=/q-tZ?<1>s#hPQAFR8Kx=jSLJM6@n5)6ujdke!K^[JYd&].el9rh?L{ekl@-h6v+0r9^:^1klD9hv6Bx0!CNiU</<M7QBs[oicA+z@TEQgj.VQ-qHGKbWv=q*(fu*I-mE.xV#&NE0)^5/{Mt2aC(zYB0=xwh+BXU.F1]^/7B.*R=QtRn/gyDGIp0>X<){f&9l*n$BC6?CKh!a%t*6)LML1q>Z4AW/Cs>m>x/fV$3&}a+}M1g5B2!PV)3YP}z3vH#}q7l<-tzuD/DRgr?fvk-j+tmc:}KZoogXwzCJulPNO-WKi:fZgGsIDZHRRX4$]ph#f)]aYS+=)=c!Mv*L4}ghx%dcz%-C$y!J3J)ZTvp51eW>GGzL3R7+XwzCOO)ZQW-{(tP59U#[IKLYP-$J6aR)#LNC%7s%(>AJCotIp2bE*L5PNqA4S7SVu<]It9+UaI^<]It9+OA@[G%}gE.8I<Za8C#[fK.ziIHbG@?vys?Wq}dK1O7sVQ$srH)H!Ie9]^!=Z7eqvnvKNS*@3@9NLlyoIHchcuZ6vdph{^?pW]o]0opzU@0zQ]&s#1$fH3/ae1LVv0{jc>/vzzsa(>6[<9+r.5r#61?f8Lkklo)KGXtih)Kb*FFB{0+IU^.J6PYHv7ZCV-E:WSw6.^2-9W5W!pi2d@<]J]@<p}up<s8cW4>#&E+6MW*VS2VTZ<I4]YeW^=56RHN)}O=g}&N!3Jf/7+n(f$[BGYXwY9B.b5AZw}ARxF871f.tdEFnrdx=NtLib.Bww(jdq[t3mq{[[<TionllGZFcYhGP)q*HB1BdQ?e>@KIte%+?+L*jLDz(q}31K2n%Cs&V%aeu$.:K!3-m+@T?Dv+TKBF(w<>p7?H
+^Tw?9i&d?jiT!m]}CFs?Oympk9r[N3HJRlnAQ>lIB^my&}4[SxC)hq1B@BBPOgP4GaNwSJiv3Y8y5PmJGY]Ke<A(d+YRe9(LozLx){O#QZu{XqRqj=xhuHk$l(MD^+}]U/+bK.kIR*{5Of!7L1SGU/mI>yE<Kv^mIM$eZRmep)cs[Ba:2QmHkyTZxkQUpgpu=ld(!RW.=mr*QdTN8iPj^3yL<XA/h0NhTy#4>V[u+p724M]J(ZZ/nE/feXdq=+XW&>tv%lbXhNa*$7!onZzd)0V*DFy@0[ns2)N7id&ihMJG@%s1CZzJt/0P@^5C}SOk%f6*&a9vKu}*+iO@z7$T:A=0-Y:77Q)&V@/L0d!7eJK@8Y$WA>kA6WM])Exb57bU^G1!&g<524hc[>)8a3wW+{ed<U1GgNa)St-4<4!&>(3+Fp(hyu@X*B20Y[[NLPl9Y*xxtuvt.ShSBwfY1jCt+963rMn(Ve<Z]>+xbk0dpzY!%8p^[*Ol+%<<[jeGAZ]-DmB62^K2UZPHnf=/}Ii)TP.I{9-S4GfAZZmS.>*odK:xc#XK<j]3ccDfFoO:aHL@+Q(CDfB>gg.eUfRha9?ld=)t1Y09@J31gO3h/b[DMR837gJm1@:1PHhK.Uev.9@ZWTGd4kHxvCIbMFf}bg@(&v*yZ9}-S&Dm.NhiGp0MURf!LD%:3#o6r}XX>x1Qsd}p^@Z#9ut#<)fl$PenTCcS(ywh<:{EdFO[07LN}H0]]Dty9}y&&qz]&?[5ySUe86.TEER7XZW?R%+v*fdj0Z11MzQw^a])^2i>Cal%=a.9$krZUD}}#f0?=P5Qd+6^mY)Ud:S=YoBaxpX(3[MhCjCi2^nX4}R5WCy7[V1H6o?Opb&O!OK<oNJx[>tV{/[%}@oY.3GEyU<pPNo9?S]}qk.Io0ZFR[c!i)@=I44A{:+UI[(Y2S<XG9eLbcXaZK0fpuT>71-s1z]Iy{=rCpJp*9ZwTHEi2m@<rS.(}5T0nTf%
+&@nWUI-U:ruYj*r<zldlD!I9iZFsEcIc>]bV%t3O!WQ5PEL<(pngJXcJG-{OcvO%}kIx[6nQ/+}:@8M4Ma6?Ne$ECejBZa.<^6JRifc0DqbPNwa6zSb2E]ySpR$D^:]lgMosPg%>JKS3CxXM/{L*y/J/rO>-a%nJv$9zLWDI[EUH&nKTBs/BM-o<$:s*G^oi/lHK<#kWnHv=f@//#XRASVUfh[FENLw!wjrnJIRAL9<5!kb?=U5Y@fAou1==:M>SH6Qg0NFw?@$mJgju:qdLt7(QZarh&H}:zO<%61wgv-M/I>ODI]/)rM5kz{Pmy:bzogS@6asCTRd<hr>RDD%VEcl]xtB[?wuz+D+-ll+lO7ci-To0kUW=HT1tk}1D]<370k8wHlP6v:44mTnESPaf@f9f@kDQ*B?=]yBD0^U70x1qJ.G6@IGj-23Z*Ula.gRJH^eWjnxDKf2um)z{QChNj/@6&-[9DW([)AfhH@/@keGYuABi6cCp&[2l:+=wl(GFCS/f0AaxprnvcoYtRhN6[1<ke?^HBE#:H3eb%QZDFk2R1rqk>oUGZxzn8-z=7&MCI79ik71sw.rqmAh#t]n>E8hn{efV5FVURpTe6Rrk<bJtu8<y-<*x<qvdbkviSzZ&DU0e8/Dk<Po6(MWBww)H)Z<?5mb!sz=v:EQYhB$aSv/wtoxnr(@ZUEkPhmSBhU!5eZVB0UPBE*gD<A{#LDAef/.LWwq13=wGueADJe]aYg1%!A-A[Y<GG:.<Dsxy)NPnqwD^2N3ZF33wwW5S/1-kdkkFI%LuC[3roUx60h[hxGw/<*^-)QKTh1^5ZzdsEQMj&54u[ILMX8Pzxs+7@n8/?MT2(qAgphs[gKm61rNJ#HhI499?yMDYdhj?aw6pWJ35$w{um5Awfm5y8kgp-n7F=}[}b]!jfbPJVcDat89N=EcwF=}]zKF5V-5%Mz@<PF1*WN6#JejECDqWFsbbYeW9:Nrb1nzr58{x:Bimb<>/
+^KW{KQ-SjAC0BRiInVe6<B2@gEnyoR@zA4iim04t&)x$V&/O/>]YGj3nyQVy>E&RRHw--bd@Y2l3{Nd@[54BB@74wcpCzqGsguPBsqco]CP{%zf-h4SCDAsnEg!T}h&lPJnBv}@@loQA(oyXOU2x<5>qi]tX=/]mve4F>[1p@xA9G7r}4KIhWK3@R7cf%lmUyj>cF)O2+GQHS>hw!#CB4/Wy5#R73El9}zTY?buo)[Zc}b&B3>u$-*4mvoM4s0Y2+6{f0P8&=5@DAGKZ6C>ZtDtkkaDa!iiX*XJYJ?sxpj1hfFpX6=9*dSMCHaOkZS5%w>P#a2+GXp20pqX-(<He[EwZza6lQ9=w$e(LXFAsCT1jK7ysU*Kii[Gx61oSeiNHh6RqN63Eq*3G>z@fO##(ppJ]p^8oXd&XNjnj+L@WUJk8kqeq!&[+lDRQjc-nS]:K2{s>]dZ&T6V&/EhQQ>$vRN9nj+g2F*!l.*8+3jMNYHYOCLV@m@gJP]!V1CizOEO-q.WkB#{!7m]OX7m}<J&5QW$>pAm=iKv:a^#SDF!3F6{!3Fi$D!ktNgSKMG>@D}+[snPsC*t>ejM&c[3c#r72}DdIVM^H)}#@W#^/lMmLM%o#o-eoC]Qsi*EfcP$cFzKuHxmcyC*cOS{G*!{Qxj$Y.WxI-is=Ig>j3f4J(o?6p-Y![k4>5]T^4<6E%kjuwenG)r2e/D4%Z)q&o%3tVR(?n+-prwEgisjj3c=PCLnYiUSxhOx47LT@#Z%aL#q>Mo5@eb63U^H0}p@f:S1nQ&sh&X552CnI@-jX1(uPJ%fhkaj/j:wTCBI2/RM$=Qxq3g8fWBzZ7G1.&x:If?H0RQ5{m78g3E/bAnAIT9=$9llc50N^i$6d)c3&0<vPnIhsXB3fz+OoQgpL0Qt@MGQn7C6)M3-GxW[7Q3nldPiCR&%r[sQ=5:#Sgzr]Y]!#S.G7Tm%RT^Wa+r..KwTetxXxoMyVjb3-#c?9>[LkI(9Z(8mp
+[UePYBuEjmG+{>WXA+z!^x^?966#9sEkX+4iGS#wrqLU}:LII$j@rygBkPG93b2Fs{<kcCW)Ls3YR$:e+KIswod$P}7gcUbewgiwZ(aj2fiP%FI!p1/RxrZlRF0@cRF1kk{o4tfpbY/H3id[HV%0!&s$LBAT^l?ZO>0bSYCaXSDF?kcKBi3{&6?CzC*So)raZsxFeo!%@<:oyPY+ppb!e41{DiYE(x7-YrqJvJwDdfzU7pVdgW}5!2T4sy[B)}K?V.W{&6D*5C/pTk}G}>cF6bSX?J-^1Y#8bw>h5xBV$ts6jVY6FV?y4dah-.qOe&aU36^MG2tGDmV&N^q{%fx#@oa9-?-I@zbhVh>=7BMTbAqWOWNIOx=zN<nKV#OahH6hIqsY8[T?alKwDPXB:S%94jL346=$WhV3.Jg8hPX-ZjUo:[sl*A7OLjGg=:w0QEp=*PeTsHRj)O#zD!/xUUwq05X+Xt{}}}6$%7$Kg/t0gVk9lLEr]+6)*>L-xBhP#ESlzqu8n<iIhv]LQr]wo11yd10.iq5IhogbRcIRe6[3h>3BvVst[CQQe7L@@iaynZ/uNiv?eYI^K{/OFcEpa>38$)63e1s:Ul}Z3)T{)EuL/AG%4v]Y=Umy6Mk(U&[))Xz4F:-PA:[&0rfxTUsEH(8FFpxI1B0HamAD9}/=:RUj]6GsYn4@o.ALmioMS:^O?HZc4oa#!1CyJPL4d($4*}glMzIu19}ZoG4W<tzp3vPZ[*ZiCbnuKPk-RBh>C(o7!Pf-rL:P]ndz$Xkcny49H%gl}[:GQ.%nXOp3V/4:{?%<Gp>B/1Z&xm7/^yyR1]%%)e=Td7V6+p6pQnPZsZZ6:T{tr6A8))8-XJ3??h2Kw6)w758e*W13E/rMWZ73xXkB}eAaP6s]{Po5CMdc2sf*n*n52w6.F-kR?@%EX:6TORA:brW.(X4@o3(He!ef{UV6Nf6vJqjhW@(e%a6wuc{M^Hy>7W%]x.=JZ@o7t>Z@*/P2
+M.38(Y))jex%(v[{8t[nT[@2h9!5f<HTkIWU^DjP[sa)z.)+zR[b1[7D]&0>:5Zvs1ckXo7-}NY[y{iDna.Tc>w.NGRVdB/lw=s368-c(nHBYO.JP=!O=B/@GNr.d5KLwab7{K]GFpM!d#*>Oa(18a2<Xh:wz(B#LzwrbvmgUIJ0HRXZD/K!}1HOu5BJa4a-4AdGPeoJUNdVCii[zM9!zuIH}-v1}kC[?1PHvRr35+F3j(OIGLxJ2cx!vI^Jq3R@(+4xzyiFFcPRhJZw&99z1qEl{RXAEi2=hTE(hXTIpULFRWc.pIb$YJ0mfz7me2XBspynUY}mW#E$ryr2W[3QAEe!-<0?jy44=s<)aIO2r%fa4uLy=R{+(jT@W+v&N^vjAm7Tld/h9A/Qx>oj(B^VAnb2&i?R5V2ZFzb(A9$YFC::a<B}Eeduwpy0ie<mdkz%M7Uw$r5gw{qVG.0/N5pgh:pPK1?IOJRfpgfd%7))qk>FgFT}r$lXqKe3@4:+eAx4@.#scuQC[:%(X[fXtGE<e^MEROU!jsdiE}v=P]L!ZF]PpDP%7Dzu=FY3wM&+r%^>i2IDjEH]vP9eG2Z[szVZ}-5fdDEAOKP5jyDp?M-C=p:%zyCR?PaogTHN(X7D.g!]c5p@k6Zyteu[Qbpk>:s+0qqB/P]2VI76+ub[6mbio5O%Hx1gG{PaUmrgewkKdrKCKe68dC}ZY!/3iQK?nW1<DsviNF8Tt0u7#BkOb<ck%r*}mAyiQSMs+y}tsvs{SyEX78.oqsTG-vNWkLxf?zBof?pvyw.xFoz-LazM7%6K5npBo.DM2^=8AEV]19%!yUG^MQQzwe!>j]F9:8e^4+}efi@*^:pydC)]W9i]2lx.QF:GxC$y/{!+=E3ybk*th#j.E>HmgCQPFhKF/%]F7Y5:GU8?dco!fDO*hzQbh-7Je^tY7@3)B{D[^o}pRr..Cq!4:zp!D3MN1?fft7j[=]n$Bp^n[P>C$b+IY!lPOi$Q
+TRxe*TRxe*znJvLd[j.X@)26h(*.D*B=bT]%kaTjje1sqe}d06b/P[7L#tS=!*LamK6@7Aky71gHBxl&n-2=LfsW%KH-?[Vu22>U^<+DKc#e/KsYFsGX:ZU@@}Va(do=dch[itbI8EfWg[aC^h[ef#cv7e@?(2%F99B>$C-nw-2{%}G-P5p<FYe[Y<EY^!&ubI?c+mv6wLx:Q.Cwd7*L$ZuMmCYcUQ9Lg&ubHMXFVUKKMp29>JcrrtZ8v24w3{mf:wK#eU5hrgb2d67C0ZYTP-ld79)#S[Wdv:^yl7<hfqgCB$Z6nN=HB*%dFIfxRZHu^?>CBkWPy6UQ>PD{<&ZqMJ?2I^83q!Zk^J>fokk@*]y#3uiP9]Z.H1!0h}bMj3}J!rw@Xd1SCA4t]Zi>)D2CHTFfyPftHXHV9&l5q:/K3vF9*hlaBX6[81P3uRfT}K[@eOga*3OX%(lm3V#ir!(HilZu&A940pPHZRF@lJpCBB?JzJ-I5jY8ojNJM[i3dg^bM>Rq*QlVR-!B22dRo+a8hbYWkM(qWl9.E9OKSuy*V++46T7=y#JG1J^/:Ir%XWX/Yog9uPdDF**2q@}Ubc@Z5[mmJQCWLB:j7iLE+akMg=M&{xGe/57KLLVHuTOcF[T*A{h{0Bq7pMg4BaYt&5#I1WWY])P$>FW#x:q{+}8*+ZK5ZEJzw=Z5>!HFCQtBZhq(Qd02c2.Hili}*[ROA1rO::Jf%j[0!Menz+/{k(kO]9e9bA.1D/w!f=u9CRvd&vy@TwN&*7?W2nRSR-{?#{5r(V=<!K*6x<{tV}(l2YV}v-PaKK=LkMx>.K4uyh!X/.1&*KucIq?-s)XB6X)E/7aNSw=N0r5)aZywDUXU?a[e!T4WW3ZUe9MCL)LuhnPu5X4(J(uQ)dz&Khv5u6wCm4}?u{(::)H81?u{)KJ?oS+I)jG+<x/CdBOO8ha7OnELX5$}<W3[-:xV1qMh=6{]>zbpwPO>{Y?TX6gP9&LXxH@2
+fw>7O{NqIXylD5(fgI{jVenXl]Qeuo{S}W}=2@2GR9r>D{p/q(82?T?Q^<->37({^j5G+x8rY+dqLP#sG5gA)uLl>ze2t.DmzdiLjnMe#!UsI1rwi<N8Lcx^z@I7MP#/.G*SGzl25]GfPrkAGw(oX6Y7$]8o3oRLI2948<?#ugw8.Jrjni01u567T[ZPmmi7HDgadjKXalSgP}yk5h]4@Ztrk5$qM2lf$pRG1O2Y@<7I!G?hv?OILEgNN-UDrLmuS(GoDr5tr0]qG1cs1s19+f*^8e14OYdYj7:)5fpIl{WC^EF>Pjkylt)8#(%Ui$*$R)d/mj8?(V}8^MREoCzAKDe%A}j=h$yh%5!]yHz@sTDex{9<lg!iRaHtOB3Yu63VR&)jK*WyY19:gE&4BI5HtKk&$9}WmXGYPR=N.bW6(<<*(XEr!K{E>uU/-21j-mTPGfA4KQwglznIf$]!T^VY2$@Szcg-$A>f-/X)M!fT1k@c^3&7S.26xRjq9ypW/NIkHP!NDiQ:xh4(r.sKrq6hGCh0g*1}CexL1AOyzznJ*v-3P{8K*Oyz[CO94>{y+Q7o(.[t>Jc1[XT>8fX2]z97*-%K-IT=XqZ?R-lF%^%@h3ga{/-/AH3(ZQWteM&NaRVc-u2W*iK2W^ICUg=a9i1v7KiUT}+Ox5e{E=6I^e5RGt5CVH!*$G4Xd%iESfNZp>1hpt#fi/BpaW7^V)Oy6#fA1E!OG]J!%Uw?H#Hqjau8b)%.T*j0{KKi0!oxlHMQa9Iq]$^d.%ET:J-Gj$]36[fd=?^ez57tIn]M@ttwoSxAFt@@5kjKhI<Un+Wq&Zdt?xKNzoq]::v.ooW3s@4vjg=SkBg&]#5#fEByI4G0)!*&5[bwazZ#@NQht&p>2IoT)sBt43bco/#.J1l6j)YLWd?@@N3qJP?WXxhz>xEtIn8K}Y[Qc1E%A^JU2TD&xiAXVKo:NFme+K}M&:[+s]A<7}#7<6VIQIestMMf@kOls}T/
+<G?FMgabt07%g<Fo84HMZ]Dmgi]tKhZqr8?iTRNLcZ9)?r{/Cu9$1jm[k278q>LT<})E}.]{>!IPHD0hm]jM6yl!kwT1XvXlBI=FUVoEdEi!Hax@AXmlg/o1VzVv)VXht8H/{aV!({Hp?q>7#&2Cg]ay><S@yP@=@fG&BQUN(/I<v}Q^NIX/editk?.RiVG<MC[:]!#uJLA?:cEge5G:H<{l=:ZBT8]AGMb{Yed[r8A/SQlP5{[oeura<4:10Sm=B3nSJb11?@puUTiXX&7J2F}v7=wCz8adZ[S#kr{cjF>RHAC^.QyH.ZGfvjl?ZUfK(ry8vmGZHsE-69>qh*l.^hqM]&@Vo6-<<wBOIuMAHvu<NJ&6R4H+Lpl9+d4on[T9vT<O9E62CjVRp0V(7)+>8ow0:kr-XEz^B{7vg1)g[[gRa6RkUq#5YLM&-#4(YR87OBT8#u{m&o9Ya01z-QDJ4cFiy{oxQc<WOVHlSQCDr:ZE@VN4u8])AsIm?-<F>iJb+y*i+a@$Hvz*$/rZ4QL}Y!!9+j&Dn[T9Or{tN<I.G?wj36IHmSleN0RNuP?71%I}DfNImQK1zE.Uag4#+%<f-Z[:bF#14f:p*TgSyMO5Dl7+0MFJ:7Z+RGg0zbrf[@]egSxZq5Dkk<a&1{^7]7J2f(Bu<xn!MC(5oP}vf?v0BC{swsI+)=DYh%JrxsTBvWiJJ[-N2P(fw}R}MDdu26}VnXNbnU=]SGv6Arj?(nl^kMV6fH@cBb#TG*a)xx7Um?F0Z2CPzTc:J7z(cE9R].59iiq9/Eg(hG6W)OgFpmvnOgIHc0<n0hUlr=J&57cGzI1DjG&C[xlt/^=y}u>av7Yrg7M0oK<vYt(z7[yAaq7>3$.kpDmL?m)7h9r:Q=GN6+3*^e:xpKmI#IyVu&&0z>au%B!rDY^-Ar=O0r]lOo^?ZSIJu%VSP4{l^=?yw0$bmLHCF]R^]k4>Xu7CJDk>hio1{5X?+^Tf4+UjbFzcM5z9
+ADLaCoU7:s-z3D%!R9:OrOPAsuqk:bte.lk3H!g[9wBS6+i.:]f@}*PFQ4#spoYSg8jjG/?S4TRG%@3:.dS}f+y:*O^W+4^W&EdQc^i%t&<F%K<!RTDrB1Is1Ff1a0/N)X}4zbDwehM.fK29=D[1v/[h.gnU{vjpUjgd@cM2Nd^kimga(aOFAw4u8q]k5t755tGhyl4Jr=J&B7cGAD0e%xkXDg}&W#e]yN2Iw)He)1QZ?=z+VA%A+[oPWl7}1](hZ)o8uhp7H&5gNEubAaG6$jh$(YN>amkLW<Ytketyqu+?L=G}H(&[(FWsIFKfY#PTTQSItQ=mfXUjbREcM5b0q6^R(bhAA6AJobD3sLM]IDZKSry?4<rORugRg!2W**Ws}a%V/1[M#*ji^nc4]0/#D(0[Dbvx0m{6^&{A&wdFa[SXwMm)sx9^Wf3%^R(1gBFWJnqlSf1L#WU/+28kARtzn94768YT96R@A/FRZA!xikiL0Y<?pDCkrOyMP%9Ah/7omxsT8#TTh>)FvSihR>^L>0dh^rblBV>xF>}b@Zn=0=&A:3+^3Zf<STnfl=<dZ-4wC]+<a>ZZk>uIL?m!v3=?1lMQXHs6HDdBEow3+LpN*Gb9>Y!dJ=^zWp4+Qw&W:MZ=>yl*xo80hc9D[3LDHlq8P8T}3pJ?(AI&=][Po(UcZ0aVY&Rx9ETKv7yZg{Ve>yl*k]a?n<^EvCa}8W=Oy1joJpWxlg&Qq+^X1Hv1-[-?V>FRB1F455k}jCc#^Vl26FVVA5/8uw(?MYYo}jv+/fot7oT&.YT[P@Lij&Dby<lw!dB^3(cH&To-/c6}{!:E{^Pp=eV2IO1W%9Ojt-}8zplwBm?bwHPAE&GG30p%Y?%m)w((C{+Or+X6GD#tj{wbM!vfz)F#&$gNbnQnxoU>H1-D#21)7#FF)gSA>u5DqBK[DgFVcYxcQK1{SFMcHlDVpX+*bzmlr-biuH@[}k]>HpL.<z)>9Jndg)Jfhf17@wG&
+i/TD+AFfC<{823Dq-XAU&fqI7}3xB-1!}+mfBwxhIlL2qG}cNe<0pKA-WBH$.AQ4wp65wRGI.)C7]a3di+aYBL*gb+2T!yaQVjgs6EO<*vuicnihFA2nVcWIc}a#&fc86AFkI:?+ebifOz/ZT80dpo8kxr52(&cjR#R=j38nZhf?9E!BJgoCL*gaC2T!x1:UN*xOjaoc.YArT<clb(2KvRCgZuU<.APXmSSRoz.L^GZJ8w4Mj.UzSdlRwPMn0#0cPZI]QyH.ZGfvjl?ZHZ54+r6(x+3B.GX[EA8uDPRvkJZ4-<&/kj.UzSdlRwPMn0#wqfjR#-ZLNd4e7KkXL)?59ir7+^I%/D2(&upxAs5+38n*kf?9W>]RsGHR/XKgy2+C(ka8wX4d[Jr>w[{{@]T{zn:srb}sTpK6&81^G(dnm:luU^-s(MF&W9d*iMhY?ipEM>9wA]^<Tc!T=mefcZ?=Sg)vAly0)EKqCA(9i&JinpK>P]iqbzc}X/Es2huvzm.TcF2W*s-cKZoAkXwzCLulV&Xm+%.xN@*.N-CCR0X}F+Qdq/k}!*n2gO-f#{hf:#k4J9-GAY8pU-WL!xu@!V0yN%Fo24&2aYuR>d[k+8/7/=8-h7hJH?884t!QQ!/fUox+TP)1HQ$r<uUjbFzcM7@@q6vuOc/ah=AJo8C0+7!wIDZmK6?CKaq^wp-08Cpv0I-s+e>9v+Oz]?tw/nFL)g^2(fW2Nm]?GHXW&vJDc<CTVF]SP]Y$(dndpw-pNcvv=o9aSv^^b{cs9MVPMV$X3J8LsGvBBMe6T[]kpA^@hph#f)]aYTs)?w&F)z6x]@j<Y$L>gab0h)tXFMX6*gO0Cu24*fH8%v#qCX$ngJ6a)nj$bOq?9B!Q]8[Pe.w}[Rh:o0Sc+QV#AFr0TjI:2p/bj.Mbq^d&!g2nRu%pz!<}G-+fFcYyCzW]-Ytket.[=5-cykkR<{m({m>++J1i[/zBC+zFB1][v?ybqim><#6f$)Uc
+P}Iq!(5G}ADL&c92>HJ-XqbSXI1FXD-xxe+(jzku*#IBoww(iO@#7cA@]UQA>w/KL^S$?8YI/Yt%gc/iEluR.W(zTMGY?DmBXp&)Pwb)#I?p58W(ZBAYW6EzA7Y<+(pkJ>pIx:<pn-v-=&r{VdC-RwK4%yK%mmR/]+KOct#6TOjo3gv@uG>=zM0csSik>ORh6i(I1o/Bi6wUNz&$5B:FSw&/)xpL?&:4U?BAX2B1b<n{odlOtX6A}]J[(n:fk12B2(LTr#nu(@si}t=Ju{RW]i8=2?/MU@scc6df+duSQ6[F]b:+*pXY#s:d{e9:LQs#f@D{lqAM>Ndp3R){i0d/e81Y2Dh.rFq#gEcj(IR?.nS#Y0/cU}L2H3Yt%a+GLVbfZ.?RaLn6fs)S!WGDk]U=VXSrZ+(3/c$B4RU7Q4rp6!1a[(q}]M&WIeTlx!N$Ccxk<{pu%W<-pY+.L<W<41a3YR7)]w}C@]DWbtv7xKb:y<OSAV4OwNW>yGY:Y>eb$0R$r6(?Er=r@hlr13g6/H)y:j%Ap*Lc:sK4Rt.Y#86xq{OU]Gc-SsJ2?M0EeTufMEk)nK[/U5WbRoXokTR$YBQBwn&/Z11@C/e/=1t?7>@sk*uzJTW$e{3:<8p.{SHyR7fxA5DEjh-W.zRFy69(li*[Sk/WKBybM#svW*WeaKZW-$)e$(B-bwehCKt:fTcx-(mYJ)m#A3uRxbTXO2]1ZpYCM)ShcCPmN0F!sU6Z=.3$LU!}[M90.ygq!g5j9wof%m1c9A>rTm(?qqLO82a0v+pdo+JHeEypW&l1b4#F0}}B+s(d>CPq#l[3MfrDc]({VkDkU*Cx?VhA})3B-{VpZo(d?Ri{HSTkh{^[2%8)l>uezx*H%hEO/BdTv!B4mG?ffDFPhUP7(HR+LRmiu0+g/XQZj71q@:8[rkzx5WOyhQWuMTkV4@G0KEF{rEoSw3)OdCw!jD.>>BzP325XQaD(*GlTnCkiA{EI&QIRZFp}UQid
+b(tqOE<1..]U=^5+j)iam{>7]X?ngi]}etKp0AjaPVMVI/eGn=kA806DZ.ek^cm0cIsS=puG8vBi7d{M)f:O*M5?E2E*s^%G..(K?0V{YATo9rFT$bs[*v#x6q@pNFzFL))MUaR3!Ub}k7GgmfH<3oTonFlBOO8@dX(X9gA7s[r#Du^UAOJS-!.{zgm<uWQ/OCqadRgzzog8/ZRe{:[15ssuT0qRP1NPS3o-<kAtwa}fw^+})I?6ip[bTjF*G]g}g96K[/9xhoY*nNMO!l!H9V{-F*EQ&]2rwWnXPoN}jz%TAFqKjR5B*yU=giy<4@i.qXM]m/bTSNMP2ycg5w]GR3RIBHeJ8R@kJe^LL&B]/LpG@U{<[<0(jv7rWJpQn6nM<mG9T?z.?Y9?y$fMu/RU-}iw2IeC%{Y^kIF5pf]F7JxR2Fm}}PY3e1UN*A?&[AAAM*TSPchuja1pq18*(=+p+4o!T)kFC%o-Dh/jT>pWv>!aVTj*fw7<<M8yYN>yyI%5dR#@:$f0Ei7flN[X.Oo^Us}nIzhw[Ft.N18Ob6cY)czUQftgTQn!pI8-J>cF*TPwC#IBuHyyF8:FCcoUxFT:uq$jeLaf9DfsUQq/FsbA7pUhSI(OZYkjR]8^E=yO:9bF?R>T2]0Tzjsy[H7?R8>QI6Ah.+CD.qLAO25i$F]^o.JXsacer=GTQEa[YxA<Jb^kDE80wJrmlai<Q0p9J{*cr0}j(T3g]4(i-(Uvd^a8K>HgH7+vbkE>qNOy[[=})j?(7qEcw*3YLkfK4)j==ZBgt]bSqC.}hmLrCquIvz{(&8dFK13uOmRg.WDuQ(db8/um)DE[T(uc>kDyk=g#<49im[J!YRX2u-hP2RFt*klWCQ49YxXi^dAm$?tklG2Nyw:ml[sAc.Ka%=J]c9AUJRXoa*Kv:+4IkPXckpCiY6U&b9g8=T)dr&o/b8!@0{3/0o@X<?rdfl{Hvr&h^{LUpMK/3.WD]jLodL-KMVX.:dWo
+QX+Y34*LT@(.j</vV>@R8<??htn1rV^JBUy4S.@p%4v]FI!&PrTX]pxkjA!7]).&2FYOR0o*F+70H?]b/Rw=YE2{h/}w:5A!AqS=EuXb]:]20qa5{I9}a!%94MkjJ}cX25G0tE@Cv2iYIfxmmh{P-:gKXdcI>GJ6^kNbrVbFXv4-Xo1k4cehGe9y].A2#jo)xP!ir%/==q-C:IGTp:0xkGr[mI@n(b=1k9w^hx0<!y5&mA!=J[%a-0Q)kx)Kt6.DIeofe1eWw=%{5N}RuF>oV[p1d>Otcm.c.{%5L2y=!fc^9V:u]Sh@9Iq@{}Cv87/..s!2{ZsSp>hu%55)ULf.q!E6Ro1xA]km4i^5[v=gI8L+(pM:MD]L5PnHOrIHwpG^<%0Yzi:<Sy1DfS-AIoHq(?:.LpIs9$Bp:+bXx]6)z:<V.B*Wu(sN&}J3CIZo2!H0*2o.6mDYB01OLE}jIq>S{(!9&v}LU6Zm::TDDn0)wD{?HP5cp[4(3xZ/}qG4f9Pq/AI-gK2zIn5X7@XELXzNJcIxyW5i@qK6AFX-CbOjw.4tOzQTG*hW5bGQ9o?zoB2*46Fp}:*ft]FL%WgX46UI.On{lUy$(@3q:71zGOC2n{FtLub<X2k)U=@2S#T-rX7H3^ew[3Ju=M?.PJYU2<Eg}6$rC0iUhZx@e}:s8{*L=vA06-iG-U>rOGP[r>/-%8OI>tV)Da4Jiih@oVp@4BA]@@KSWrTeFfuqu3#g7hE.v[GaJkCpN!hS#{7!sno6Y/S#{Yjn4*i0e${$>}xPZjziUFKHwT)+kHEF[i3aG><+4}nW#T74A9BTCRrfWYbPu6<gYi^o]%J=r=zyEC7j3Zfm@t6Y:M#^x)/mI>b]A9OZ.qQk(R2W%1j#NkL(}QK[UH71@>)Kt#fi!&^CoyGz[Tfk5ltQuvA6<J]g}$kg<sSohKFkChE&qzlQTa)5(6fzlSu[[ijgnG-%{ZriFmJ?Ryzq%jN2}{mIpzsYK]KWq@2s
+%kn0x&)CoyXRfV8r80$UZMthy]zZd7}(z$<MVZyHyK=uA?#f*<]hzB>>tN?hMUnMSieIrjo^&=z*^/mPT-5RGPzB#3m@N.G9Y}R+}&-C!Zqf.nDY!(C.!]8#@n0H-FLJ2}.^@k1c(BxhbNNuBMqiLXnl.a<zn@jh=8yvNV}A>1IF2:luom8oKgHnRJ)^dO]i}xx/StVC{xJd0?^.]9B4l!&)><NaQT72bQ<[4:{^Y%#@Xs4^6rHU)k+T1ijX/Bq59YZpe@fB4G3H7&bW*F}%7h{e!-S3yJ7K2=)AE0NVNQO#JZ6N+VQ$d$n!m9B=-k=pL0u1}MGj80sRTXa@ax3nN.RU:D4y45hUW9rn>wlZwA#PX%my/mI9AuIZHLPZ-nHao}{h2a{V&7y9f@}y*WU<?{?NK=:<@k2/-2x3d?HM%k$B9Q}TPiJrTr4V{&8U{0BxT5Efn4!:jB-u:${1b(.$xh?*!LUHV=wYO6VT&HS{V]N<Y0bv=C.gnt$/vCf/66C6PnE=4/ZDp3blK{<R05?3{).y^:VA4X-?Z*]0iIHek6P2iB%J/:.DNgya7AQu$b2C3}DTP0%{l>wp##?ref)XRTloz*9msfnA?KHGx6#uP:aJF<fOVHDWSdPgCQUH@(XK([INIR2odTb5OkMBNVHQl.+ic<IphU4X%x4?Cj.C@wJ!b%1?F&-uoA9m8qu?uPAvCKrO{:u]36khCtpCfH.8NKB<LnPFYun*Lx@EbavanlBnBiyEwhbnUUqMG0@XGJ/u%uz3LYxC*CC#YDJ/.]=EMEkE.&G.6U+SL}?6^0Z4vf6&Bc>7Z{so-5BBCaltjpR#yw8q9+gv:%trfn<aEN<pg[MR-6!g12{/]dBn76}X@*c*aV%4>z5NP-.O$yQ):w.On+Gi??L7^-&d!j6C}hS5=yED*uNH!py^UX&^muiIZbn/[O%:P/un:86de1)Cqr?K08rM=1hH(.O*d269LxinD/8W14Eo5&U^EgqUZ9=t
+b?F7q<@OU]}CLA$vdoIsE}/qM&B6^7E/Tc[*439uf9.x7JhsAvMs+qaUY?g[76z[m:a1[3>6&{(PD[2<8r=Ll[cUvw/xX7fwKgP!p*MA*^?i0O@08{DAH(X-zBU3<r*Xd1CnoJFoyYR5ZSU1.d[&81(+u{*7KyXF2kz$c]4.#01t]AQidlA$?l0OuDM@YvZje7cE(jT%x6OnF2Qoj>Uc}ZDj+s!doB.%+U?IR:=Jn-K?%D9!ecrrzF:<Y-TVHFNToC=Z^@}ftg1#-:-aADoDe8YL<fuj=Ae0=zA%qqbLru3gjhuo--l3]WPVYH>rG/C1LLU61/EFgbdzG=W4}MPLZD(%m}FMDbCd-vq:hx6?c>K]%xNt/%[6}sAKN>c)Z6f}RKqbteNM]bDil9E8-=[gvup)Jrp2<JGbKX6W&G)mo^:u/.E$3S}j8v2PC0fYOIc*+D5b>thEv-dI0Q:&kj}DAEDYzr.[[bh[ZPj*:CdiT<B*eL1CdjttH?aZHk:6ajqgvW9B)*)zUy2QkWwSicz$&)wlRcp<Tf=@BWstq))uBlH8<]4AxJ^r8xS(%(qMHR&}2w[9%cIpz>sKOv(]694DxiJ*Hl#uw5)jfdsXrg/itldzgoSPIy+&gLXQZ#D3StS>fBI6uCCbsIi[2l$9arbvsqN*PBBCYjtPWh-8{E+{u9Z@}Jwu$!L?ur}1mmJ%btC&s/K)2oJ9?u/t+ZXRQrI}=fee?rL/kjzBs]/.AV%ISpd-4w{{FD+b%n:6kzRv9GTMlyb^g<f=$3FeYE))f]^j@EVYz#O&Mb:%]fT<I@w7M?E-9InxpY9(=P7<R<Q0nVj[z5PUy30eL+fV1i]{+-UtY(.jteTI<HQGeq@z@i:TlYSxRbrJpTwuu}2@gC<3OT[w/#c!s=FFExpj2iVs0t*8#rGb(H&xcp3hb)qFc>G]g!wP*i[]uiYlpV76rIB.Dohy3Z3Cz6^nGfY.lQS*eLz*&$HE1SFPk2fa1(Yg{@VQ
+9($=js]Uj>m1G=Fchn)c8o#Z}beif%}*bwhOGsYys.cJa]PlIq}Fd$X7N$gc&EjI{8NHr::(Z1/Su8ZTIrE(W7Eq%X&y%Nta}}cXkA3Ap@ekJJ)j%Sjk!MmR/Lv!:&Rfof]oXF>}sGQPGeYx}]$l>-dj^Nyoi*Zwflc}.qcAZ8hgb8OiCVe8d@jr?*wO!n)==+2IU}XrDRRiMtj3=+S=/Gvze:*g0Rfs3O&]qj[G^+76Z+pOK)%ajr8kXfWx?VeZi#Jd]kenv>I4hMi^juwT25>%OtU%SqnI)2mG4OAL6=s2*[gG#iC&8^iFU)}guW3WoQ%.%3Va06dQXBiOe9yCF*]=4NDcveksXJ&}P21GE=9hHOhhm1L5>lcq^kCDR-(*L)(7Ejf#rSrmb}UB+dHhd^AC9{V}jP0DeRtJ81VZPdgwkeM.aYg.NJ{9sh-5C?XH-&5.FZ(WIN+]{}4VKZF/dJ}a[B(OR@$ZdolM2:c?ger3v^7>BWZAsT%3kkV5ivyXWF<rhQx7YazdtlDg0Zxw]fWB%jZQG/Y3Ix+OmDqPuTJjy]1@eAB%Dz#yu?gsS!EtPOhD=MEKD8L7HLJ0-5qb7%nSP{3>Kr*v7wkVRM-SCXG}uma+M(0tY+i0M+qiojy?dVHGrXr*hINV!EY!kaAxM-D>9b]mwRym@X-*DW?h]B<*0i=8]5:YzaN=fDwC{+Vv)o4l{/uAL6=6^kQqmVJ?olVP1]=Cec3<)4$kx{7L1jJS!-)DT(mD{.b.&#wXo+]q76*x^f[E[H<m0a?>IG0046ol9Vy1aDod9l59D+cf(:wPiT00JVVVCxv5n!jl8yIRHSqbtyExx5V!{Ill5]^/!^flaHb{zF[E?*0=xbEEX=GBUQ]7mhS71[.jGA>8.f9Bs%-#wgG{N0+u7lr[:Bj^FoL}^o-fjpw#X=3:+]mWzZZXi)23!jGjHOMnXJ)k>^4$upK)uiRj#3{pM1N{WVn$!HeV1vue.2WU5Em^RR^>
+.Um$ebThluBGN%}*.15ecb#+oMPB9ZBSg47oM*S^<92${KiaBesnpxn:f&Z@Hh:ulWzop^Xjra{Zq]QF?xpQKYmGa/?bgVKXhT8hoN0HLpTyQ?u3/BOH2j%MU18=AQPY0qD{7+1h&]Rv@Ik7>/n[LJmB$rStTb]}B3n6Kfi{^^/alFeF6XCU}PjozE.+iaA@R^{asH)H{2x+m(g#oN(.CS9ObSg60d1&WIYk}Ctn:-iaZk?&L$.az2AZ2n*Dzh*2(M^!jEFMfl!NJYP+[:iqjT)WOV+:NYpb[uvBjF3yX?k:mB2)j-+!em^{Yse5/R>jUbwW.uc%U[@I#TDFIoN}{Iaajh&s+*OWH&W3Bh-2W+FYqtE8#<W+FZ5X6(mst4vF/4y)p.0BpfmR(5cNqFAF&/VKh-D%{&GE4+FoD)6>k{<Iri>%q(az-*?pdLAjHz{eO:%b1yYm2FTIwrmQ(Qp^dvKqBdjtXQNU=Kiuz?$}BO:{Wh>lgYW:KR:m#EAt#2b+6IC6fcN]5aUlm<u4otmJLx.&@m7^9-af71i{}b]mg)Fkqyj}dPmx+0@*ikpM5cWqxm>Msa/N>wrwKfPNrB)}ybBUp@pfDlH=R$G-#-1Tr67XqRVjP-wd4AF6yHrTV(W4}4R2#F#%=roz>NEa4!:1@M&7r..mok@=$.Sh3fwM=JL36g!b+kw5vyl7OMtF.Trb[XQXdne)/rSO06AqV<NCmLTbZ+9>h@z-]xnlfaRrW2q<F6Z7w4Nd2j-cs!t}74bk]#CnPSFZqEeG/{ue:t(ZIL5{V.)6r^GcEyO+6hK5dP{pMvXW*^uYa4GNhX(RkccH4@+?7aQr{$hC:-AMzLJ9?C%C@/yK)&OZbg1^d3Kj%$!jsic6zyrwck33#oXyYUxvbbKqpB)0&:gR%BUBt-1n2/i-=]Udu}fX>WCOj}33+A*$9D:ud--u-feKJYO(Hb!v)$d/=hrt08^lno[fka1-RmkiB&Zxy?]N.K8>^z>.
+?OwH!+>rwzX4GN*=nAq>(TvyS(%q#3tFLz^/.6z>.Ci+@(gEI!&z#RXgcZbJun*&*L/{@ktP!tj=[zfG^ct:Gjty]#XZ$y5izYO:GQ$y)YGxl8NMy9s(cI5b.yV^-}B^+*LTru-dz<TOs<U2ZWNMcJWNMcJWKf$-oC9-Ap6}KiY}IxLs+5>n}8OLiO])-$bo32.EhD8$W^baKTDchZFR&oUTRVUp]^Fs<3xfYNqQ{uM]R2I*GMqi>?]%1(Z%)}8[H}W.Jf9vmt=8e)[0jm5(FMYE]j}6ePoO7GEl2y(3[^c8YFmpYQdi3qSAjDsvnT3{2wP-*&i$OP<sFM]ou#nJr^OvZt<./3SyIe@YM)z0*@r9-VrO}(:x&tAw{^<H*MP&6})b1mJ]V8-DirZ6aIrTwJpVc{IsQH.9<mj-EQwx&Jt5nSh*qoQJzYXnfdlGwt2z3&DjqLDRQ@Xtbx3^(V[ImTH<*&qmm3Xv+!S]q(Ol+1ipcD[5ygdyu$}RH4[RBW<bPBX2jjlvYjFSd568@u<bG.p*A33Y&:^L6SVJ?og}[7cfu:1!K.O(Y9ps5{(EAXW>C6)488Ta[cGa:0Ir!{URcV2!VO)^?8s{T2}xs@F>]uGYIltF7k}pExbRIp{}&:tq<TEZxE0I-Gq:z<dQP(69(9@br[@gx-oD5n2Jj}LRH%05RyHW<]F->uFZJ)KA?)%S!fj!p3h<TlAz0-N$F5xm&NuWU#q{)Fj{Wb^#thE4(2FiF2D-G{#79VA=OKF^mnQi4!xm^w%g[QuCz8{*/nP1YF}sEwGMN:={8xeT=l*Iz)jtjgj<qKkLE#Aa9TTgI$b!87qfq!H<R^uId:[.^h5}n0p/L*z?.dn4<R/lZIs*kr0y!lQ=uZ:)?LnIGxhwcUkxk/CsHrZNm=:e>cIqinVgTyTuCy[EQ&UL8{v]BVM[^}dW56B/)9z.1$dvlNRp7}Lfc4thv@625U7MH):*v>^CWc^Tp4.{t+zfzN?m0ow.
+z?8e]MUF}Rmt@*$LE%--gsv$[s^h.duwm*F!w5gME&2JQ(^SX^{B^8m)I?$l2FaXwCq5g/uTs-a2vmuxK<%i}7#)nUoO%vf8#3K<&5unB[H%SY*&]BiwaxSU3svb?jp6.^3hlO/3YK%D)UIy?{QHk6zYs8-2Cujog5SoV?kz}Fqc!>PADH$e8HVC]AE(T3q}P&-X{T=^52iFBLq}SA^2SUe^zPxY]7z?XC6Yqrfg2s/HAL6nwwec3+x%+!(Q*jT^eA=?3+WIyE7#DF@wDyY]LmuYfpF6(53TVEBBLN(9$*2?g5(<[kDeHW&gb9g@LzRRwmd[s5bC}8nl*K?@rMTcb3Su8WW22=Y:@lDWP.O5bQYNU:o{m69+Q-Vv/zg0h1qBfytF<}}g4v(<GAYK*}lR3g4onWTHw26njH-HsF+X={rs9=zR>HNzEqusJe!Ow70?Fj}=S^f5ye*!G{eQ^DxFfyr{q-$pBt$Qkp5vFZ}P-W8tn%7?L&AMc#Lr}7]HJkgSFWja]z#2q4:Y(2+@a2f?b(TBJb.sMcHjT2$aG2l}omBOiX1Nvuid*V}s$&3rDzIqirL=uNX2=1euGqrdNiLilJ!xf@!ANgSFa3a]DzQ<6k#ZlM1uYK1[}^n[TaFr{tMuGd}%=gDGmKQB6B0HcrLPw0LsRB/<7fQAi?H%nq8V4#*8^QxfZomNKj#-+n[F=kqJEl??D=:a.ssFh0Cc9+eC=McHjvT<O8Z8dr/=D30p1Jok!k3g6y^Rb(&t^zvx7f<kiA[ls23PmLyEfXTq-v{ASh=kq)LR8caaGDZmzQOxy{T%w%HU/vQFT<O9EdYWWh>S*04)?^7oFyfGki(r1GBG[CJJh%8e8rhTL5Do8+Auc:oc5:Xkv#NM>(5s3mdXer96nD16l}p3X]Do90U/vP]T<O9EDAhecXpDt!PdW?=80M.37XOYvP9p=t}DG!uQXF[XrR*KIS6RrN4a5i5=H{-gl2Qr>)?t.A1l}dO<fFQV
+{&RV#TArX10z/{7tG4mZ3/SF{1ft$ew3iWUBo+pYPXM8WHG.Wg0HM1RBc]$.8?uR9]OcO?72zTN1MHzdtO&Tda(9-hAK#tAi(h>J6$lmUUoQ.Tr=J*L13BA-0e%VsXDz8RWU/zvfGP)eJAg550Ci.c8!fQ:C*<G4eCxcl*N@]$(e2}=W/Xts66rq%Xe*aMMH@g1-tdk^[dFW)Z?s:MtAXA0TghjhVO=gFy.=WKv74z}tDD/>zZ{0-^f{<d2JYM[D-Hf4MCye2fMjpP*y/>&LIUXcAFlsPjI+XtJ0>nUw756ddSd4IupZDcte+s.+Nm2h4{0X7hYj-]S0wpTlaF63+ib&eW>PP56xU{*&X?0MEBt:JdZ5m@Z.qUBZ]e0jyMN$RT(v[lw?y)j*Bv87vaDKnD-VilUq{&VAw/I@4A-sE)Q96P!=s<yGN6+3*-0%<M?pn(6<2U8Z.qV:Z]elqe0xsGr=MB&w[w&(?ZX6EZ]QjQ4{l^=ui/%5QBXaw1)94=}>c%AIOH=kN18D1tm)m:nBE.5Xwxf&O)X5#JflH5BKCP&dSex%Ml:WATM2JHw>yBmz@T1:usSEc<78Ki3cBSV)QGpqpx$Fz.KL0G{Jf^Ua?+{#&Th>jC&3FnW5LSiv/Ek8y![f7YprM{jNL!w]NO>0fV*us6Y-B:D=^*^fY^?GK=IUpw4s3?>%D<vOOy2?6F94Hw%CMlA[6tO>@}P?FCbdhA6ef-8clvKiMKn}]sm]^ZfXdE^GSRFxqqQ%SS3$u^M8JyI[midY3vSOPf):(z<qx{WsBnd>(>bln=0=$A[XzWZh$%j3:5NGkshKNwC]=Qm<LZ/(yu5r^ee&z&(a%YQpKmT]T4RAh&t>Cp&4GRRo#KR>DJEQB1tBx=:RNmT2cBFs.TdIBtCw#sW}@pd+J.QPvE0bus<TSC(zuTrL1J12n%cks-oO[=V+1wj!zYbnARJ3o!{3K=RpjE-I#7)Pv==E.RVaH=P(W[JK@&9O!GD6
+y}041}jD&K^TuEVG@]1e/7gtb)xxjC}jBjRfot6dTJG$b[W=)[j&DbF<8}&YY2=#1.z@@3/N&E4S]5.lkr]ht658f/%gAfk-}8A#k.!4/rCC[1Zzm&[1m[ys%9j.f(C{=hr+X6G)6fgCVa=N>3&^u)/A.p(q691+{tdcPCDxjgl:<)Tc+%$[fOB{fi]f$UlbE<*{88*ir{tOkFMj:vD}k9>%0P](2f6e^[D[Lp-?#(4JpAroi+9Lj7@Dqt>j%L$1Z@y5j&WotdXerdWN$g>zWn-9??(?&D}LrivuidSW[pp(afr)d<43@$@Y:%T6bZ-{J3CNE81M5rnpV&Pi(9aLi2u6gkuz%/C8vC)DYg7>-#8&SR8ccYpu^.1MOs8+ffh&dmN1Yg4%%mjZ:U*e3oC1t-!/u(@6F!J5?<U8mQ:}VJfx*X8rhBeBJbZ}7@v{A9J5$lc$9*xj&O]rL+6Yp(Y^$$2tk]Jfa/iJFL?1Mo2hMULY/U{NUb-PpNV[Z4b)/<9P./>24r:Kj&PFgdlRwP!*S:@4WF/4uNX2=1euGqrdNiLilJ:lx+5Yyj.Uy(fOB{JBES.$A%OJV([MI9pu+t>UUKBOBfHrv@f(2g3M7-cQC?D[mJFA0Q=zLPBE@Ji>AE4k%c%QCir8]0BE@JiFJuxUXgCxU?FRl=T0R}7XJ0u5>Oc*qeR>^2JHTC+zXdeI*ZUg:2iAf.X3n(!8O!F4fFvy5tlynQLhp)/AFls+YHlSxJljC%bi.19IHdlnd??5lpi1O:w[xza(j^rAgR=VACebHxL/]kcgPd.$FMX0!N(!P>2*vs+CD>7K&y%LgK>P]iwkCEXBt4S>tnAOL3g?5)XmHj]LjR{q-tdk^[c2G(fIS81mm:[:q@2&MC}9mhA/bi8fI:1d!>QjshX!D>i1V#P1)3y?W#Btwbeur$fFvH8tlHrvK(2g{AFlsPjI/8DJ1Z!-uEDK7dEJ5djB]h&BC+z?fI-?8WOy*@hYj-]AkoKn
+LPx1%BbDC6N7iKfY&i.0Z%2J0s2[kmuiUUh>C9?F@:?qpkt&0F.6#I?>d-r*[8$Fq0RoV]/u2FA[s1$S7#CtNYp/6h(2}lc}Y>khZ9y&Oph$^4E8g35CEpx8a2i/Qf*k&ZMQgB*^.iTH)L?@VK$:7ZIU^&pZA*IUOz]0I}5+W/aUV7&:@=^:NP51/F+.3eC6eYtiwrVAY(3O+kGAX.:9nyq)/?/%nW(rg*Z!#i9+1+YIU!LJ?Y]?aph@Ip@eX[J3YGH1C}XBQA/f-Df.Ve>z(pbl0=]EFugeH4uf1]Xx-vf0R[a5%{wK[+Y3{FE8^F]N](iQ!}jCw@wQaiLPhN!%%myJ7J%TdQ4ra@5}jw7[}j.nx*czMTK[Q)3aG6sNQRqSVDy:hF20p6WDhZq(iu7VPg$i$l>ynkWT##.u*:O!n>mKVUp]-?LR$w}Esnvyn:p@=BMgBg!pr#JdtJxc4]68bVD.Y6/tK(^YDLJ&ne[CvhJ=vbybj^T=cW63gwz#w9BBE[ZI}Z:d4zvR{xQ&2PfLWlIPRSs?x3=oe40ELc/(SbyzLYr*{{SYj)ykWf?^l3!R-2F(jA^S3Y3K$Z<WJUT.d=5T@]o5m>@fgvpFQd-:lRN=9pzAyjc^I5-Q:yWjeI*rYCkO6OOWaH{3/u6PBzaN*@E8rBD1X-i&h6SG7P0fB4U))zfFjLO:W.SJT5Bx2wsLB=1QE3fME4kA.u90ZAm=@ZzJsG19jPNk0>TNE(6hcGykIAu=Ul3AjRwd?O:?f)lf)4k9CnbbZWyTb08rGY^G+(c/D4)Ej4wznJLg}^08ls4Y7kgF.um-v2v[o:Yde%ps[jR(TFW:pu:}aX$wA.k0s[dI5AJROYo$?R/XJhNNMx?@mPEL2J)/XVbnbSH3)ND=unyOR{>P?O(o@BY5@dI9}H18JN?P<q[xdqw6yVbxcD*^SMb7&e3VGf!MDz#n5%99u*M!H@T#c@NPNh/X66DW^(oo[uplJ$qM49:Q5M$x
+>q8Y9qw]!-OZLz}fWW&Y<Y{+^YVx<W}9&8G1Hs9o@Cr>2XBnrPphfVuYSv#SF]x%jPPb&C*GHH%)^>OJE.]Cl9ud?o]@cF6LSc%R*6EOYcG1NVcz$$NorU%/}F[y*qTwr6P44rV!Ks2S3lF&GqObeCC3^XBxpWELq>E#Ln]Ez$MfjW=EF{Q&@X?7PD<Y:jOygFqu*&8e4@DfSZj9)ERQQ8y=whq[5T7#iBzP325XP=T{4Mh$nP}O(D./B!mEH&f}UQh)b(srmE<1..]Zl@1+hSrR}sYcR(agd0ApK2(QDaY[Ceh5F:&^DLz:?xVKMF61AK]=mZQ/>&7HZ!}*4z.=clsXvi[lB{?f<$LNP4#zM(<#^*POJuN)D<)VIRji*K0x6o%TTez106-z%7Y:C]p9>y@!Wbs3SbfQPVk>94*q!Km=}j(uc0OsFf%XER56H0LA[$D(W[w00000
 ");
\ No newline at end of file
@@ -26,7 +26,7 @@ pub mod kernels {
 
     macro_for!($A in [u8, i8, u16, i16, f16, bf16, u32, i32, f32, u64, i64, f64] {
         paste! {
-            #[kernel]
+            #[kernel(host)]
             fn [<basic_ $A>]<const A: $A>(
                 #[item] a: &mut $A,
                 a_push: $A
@@ -41,6 +41,16 @@ pub mod kernels {
                 use krnl::krnl_core::{num_traits::FromPrimitive, half::{f16, bf16}};
                 [<basic_ $A>]::builder().unwrap().specialize($A::from_u32(16).unwrap());
             }
+
+            #[cfg(test)]
+            #[test]
+            fn [<test_basic_host_ $A>]() {
+                #[allow(unused_imports)]
+                use krnl::krnl_core::{num_traits::FromPrimitive, half::{f16, bf16}};
+                let mut a = [$A::default(); 3];
+                [<basic_ $A>]::host($A::from_u32(16).unwrap(), &mut a, $A::from_u32(1).unwrap());
+                assert_eq!(a, [$A::from_u32(17).unwrap(); 3]);
+            }
         }
     });
 
@@ -80,6 +90,52 @@ pub mod kernels {
     #[allow(non_snake_case)]
     #[kernel]
     fn attribute(fooBar: u32) {}
+
+    #[kernel(host)]
+    fn subgroup(#[item] x: u32, #[item] y: &mut u32) {
+        use krnl_core::subgroup::{
+            subgroup_add, subgroup_all, subgroup_all_equal, subgroup_any, subgroup_ballot,
+            subgroup_broadcast_first, subgroup_elect, subgroup_shuffle,
+        };
+
+        let mut z = subgroup_add(x);
+        if subgroup_elect() {
+            z += 1;
+        }
+        if subgroup_all(x > 0) && subgroup_any(x > 0) && subgroup_all_equal(x) {
+            z += 1;
+        }
+        z += subgroup_broadcast_first(x) + subgroup_ballot(x > 0).x;
+        z += subgroup_shuffle(x, 0);
+        *y = z;
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_subgroup() {
+        subgroup::builder().unwrap();
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_subgroup_host() {
+        let mut y = [0; 2];
+        subgroup::host(&[1, 2], &mut y);
+        assert_eq!(y, [6, 9]);
+    }
+
+    // Also compiled without shader_float16.
+    #[kernel(features(shader_int8, shader_int16, shader_float16))]
+    fn float16(#[item] x: f16, #[item] y: &mut f16) {
+        *y += x;
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_float16() {
+        let builder = float16::builder().unwrap();
+        assert!(builder.metadata().features().shader_float16());
+    }
 }
 
 macro_for!($T in [u8, i8, u16, i16, f16, bf16, u32, i32, f32, u64, i64, f64] {
@@ -143,3 +199,303 @@ mod dependency {
         *y = add_one(x);
     }
 }
+
+#[module]
+mod push_struct {
+    #[cfg(not(target_arch = "spirv"))]
+    use krnl::krnl_core;
+    use krnl_core::macros::{kernel, PushConstants};
+
+    #[derive(Clone, Copy, PushConstants)]
+    #[repr(C)]
+    pub struct Scale {
+        pub alpha: f32,
+        pub beta: f32,
+    }
+
+    #[derive(Clone, Copy, PushConstants)]
+    #[repr(C)]
+    pub struct Params {
+        pub scale: Scale,
+        pub offset: u32,
+        pub n: u32,
+    }
+
+    #[kernel]
+    fn axpby(#[push] params: Params, #[item] x: f32, #[item] y: &mut f32, bias: f32) {
+        *y = params.scale.alpha * x + params.scale.beta * *y + bias;
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_axpby() {
+        let builder = axpby::builder().unwrap();
+        let metadata = builder.metadata();
+        assert_eq!(metadata.push_struct_size(), 16);
+        assert_eq!(
+            metadata.push_struct_fields(),
+            &[("scale", 0), ("offset", 8), ("n", 12)]
+        );
+    }
+}
+
+#[module]
+mod grid_stride {
+    #[cfg(not(target_arch = "spirv"))]
+    use krnl::krnl_core;
+    use krnl_core::macros::kernel;
+
+    #[kernel(host, grid_stride = n, require(n as usize <= y.len()))]
+    fn fill_index(n: u32, #[global] y: UnsafeSlice<u32>) {
+        use krnl_core::buffer::UnsafeIndex;
+
+        let index = kernel.item_id();
+        unsafe {
+            *y.unsafe_index_mut(index) = index as u32;
+        }
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_fill_index_host() {
+        let mut y = [0u32; 12];
+        fill_index::host(10, &mut y);
+        assert_eq!(y, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 0, 0]);
+    }
+
+    #[cfg(test)]
+    #[test]
+    #[should_panic(expected = "Kernel `fill_index` requires `n as usize <= y.len()`!")]
+    fn test_fill_index_host_require() {
+        fill_index::host(13, &mut [0u32; 12]);
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_fill_index() {
+        use krnl::{
+            buffer::Buffer,
+            device::{Device, MockOp},
+        };
+
+        let device = Device::mock();
+        let mut y = Buffer::<u32>::zeros(device.clone(), 1000).unwrap();
+        let kernel = fill_index::builder()
+            .unwrap()
+            .with_threads(32)
+            .build(device.clone())
+            .unwrap();
+        let error = kernel
+            .dispatch(1001, y.as_slice_mut())
+            .unwrap_err()
+            .to_string();
+        assert_eq!(
+            error,
+            "Kernel `fill_index` requires `n as usize <= y.len()`!"
+        );
+        device.take_mock_ops().unwrap();
+        // 1000 items, looped over by 64 threads.
+        kernel
+            .with_global_threads(64)
+            .dispatch(1000, y.as_slice_mut())
+            .unwrap();
+        let ops = device.take_mock_ops().unwrap();
+        assert!(
+            matches!(ops.as_slice(), [MockOp::Dispatch { groups: 2, push_consts, .. }] if push_consts[..4] == 1000u32.to_ne_bytes()),
+            "{ops:?}"
+        );
+    }
+}
+
+// Compiled without shader_int64, while `kernels` has u64 kernels.
+#[module]
+#[krnl(variants([shader_int8, shader_int64], [shader_int8]))]
+mod variants {
+    #[cfg(not(target_arch = "spirv"))]
+    use krnl::krnl_core;
+    use krnl_core::macros::kernel;
+
+    #[kernel]
+    fn add(#[item] x: u32, #[item] y: &mut u32) {
+        #[cfg(target_feature = "Int64")]
+        {
+            *y = (*y as u64 + x as u64).min(u32::MAX as u64) as u32;
+        }
+        #[cfg(not(target_feature = "Int64"))]
+        {
+            let sum = y.wrapping_add(x);
+            *y = if sum < x { u32::MAX } else { sum };
+        }
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_add() {
+        let builder = add::builder().unwrap();
+        assert!(builder.metadata().features().shader_int64());
+    }
+}
+
+#[module]
+mod index64 {
+    #[cfg(not(target_arch = "spirv"))]
+    use krnl::krnl_core;
+    use krnl_core::macros::kernel;
+
+    #[kernel(host, grid_stride = n)]
+    fn add_index64(n: u32, #[global] x: Slice64<u32>, #[global] y: UnsafeSlice64<u32>) {
+        use krnl_core::buffer::UnsafeIndex;
+
+        let index = kernel.item_id() as u64;
+        unsafe {
+            *y.unsafe_index_mut(index) = x[index] + index as u32;
+        }
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_add_index64_host() {
+        let x = [10u32; 4];
+        let mut y = [0u32; 4];
+        add_index64::host(3, &x, &mut y);
+        assert_eq!(y, [10, 11, 12, 0]);
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_add_index64() {
+        use krnl::{
+            buffer::Buffer,
+            device::{Device, MockOp},
+        };
+
+        let builder = add_index64::builder().unwrap();
+        let metadata = builder.metadata();
+        assert!(metadata.features().shader_int64());
+        // n, padded to 8, then the offset and len of x and y.
+        assert_eq!(metadata.push_consts_size(), 40);
+        let device = Device::mock();
+        let x = Buffer::<u32>::zeros(device.clone(), 10).unwrap();
+        let mut y = Buffer::<u32>::zeros(device.clone(), 10).unwrap();
+        let kernel = builder.build(device.clone()).unwrap();
+        device.take_mock_ops().unwrap();
+        kernel
+            .dispatch(4, x.slice(2..6).unwrap(), y.slice_mut(3..).unwrap())
+            .unwrap();
+        let ops = device.take_mock_ops().unwrap();
+        let push_consts = match ops.as_slice() {
+            [MockOp::Dispatch { push_consts, .. }] => push_consts,
+            _ => panic!("{ops:?}"),
+        };
+        let push_consts: Vec<_> = push_consts[8..]
+            .chunks(8)
+            .map(|x| u64::from_ne_bytes(x.try_into().unwrap()))
+            .collect();
+        assert_eq!(push_consts, [2, 4, 3, 7]);
+    }
+}
+
+#[module]
+mod pod_struct {
+    #[cfg(not(target_arch = "spirv"))]
+    use krnl::krnl_core;
+    use krnl_core::macros::{kernel, Pod};
+
+    #[derive(Clone, Copy, Debug, Default, PartialEq, Pod)]
+    #[repr(C)]
+    pub struct Particle {
+        pub x: f32,
+        pub v: f32,
+        pub id: u32,
+    }
+
+    #[kernel(host, grid_stride = n)]
+    fn step(n: u32, dt: f32, #[global] particles: UnsafeSlice<Particle>) {
+        use krnl_core::buffer::UnsafeIndex;
+
+        let particle = unsafe { particles.unsafe_index_mut(kernel.item_id()) };
+        particle.x += particle.v * dt;
+    }
+
+    #[cfg(test)]
+    fn test_particles(len: usize) -> Vec<Particle> {
+        (0..len)
+            .map(|i| Particle {
+                x: i as f32,
+                v: 2.,
+                id: i as u32,
+            })
+            .collect()
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_step_host() {
+        let mut particles = test_particles(3);
+        step::host(2, 0.5, &mut particles);
+        let x: Vec<_> = particles.iter().map(|p| p.x).collect();
+        assert_eq!(x, [1., 2., 2.]);
+        assert_eq!(particles[2].id, 2);
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_particle_buffer() {
+        use krnl::{
+            buffer::{Buffer, ScalarBuffer},
+            device::Device,
+            scalar::ScalarType,
+        };
+
+        let device = Device::mock();
+        let buffer = Buffer::from(test_particles(4))
+            .into_device(device.clone())
+            .unwrap();
+        assert_eq!(buffer.len(), 4);
+        assert_eq!(buffer.scalar_type(), ScalarType::U32);
+        assert_eq!(buffer.as_scalar_slice().len(), 12);
+        assert_eq!(
+            buffer.slice(1..3).unwrap().to_vec().unwrap(),
+            test_particles(4)[1..3]
+        );
+        let scalar_buffer = ScalarBuffer::from(buffer.into_device(Device::host()).unwrap());
+        let buffer = Buffer::<Particle>::try_from(scalar_buffer).unwrap();
+        assert_eq!(buffer.into_vec().unwrap(), test_particles(4));
+        let scalar_buffer = ScalarBuffer::from(Buffer::from(vec![0u32; 4]));
+        assert!(Buffer::<Particle>::try_from(scalar_buffer).is_err());
+    }
+
+    #[cfg(test)]
+    #[test]
+    fn test_step() {
+        use krnl::{
+            buffer::Buffer,
+            device::{Device, MockOp},
+        };
+
+        let builder = step::builder().unwrap();
+        let metadata = builder.metadata();
+        let slice = &metadata.slices()[0];
+        assert!(slice.pod());
+        let device = Device::mock();
+        let mut particles = Buffer::from(test_particles(10))
+            .into_device(device.clone())
+            .unwrap();
+        let kernel = builder.build(device.clone()).unwrap();
+        device.take_mock_ops().unwrap();
+        kernel
+            .dispatch(3, 0.5, particles.slice_mut(2..7).unwrap())
+            .unwrap();
+        let ops = device.take_mock_ops().unwrap();
+        let push_consts = match ops.as_slice() {
+            [MockOp::Dispatch { push_consts, .. }] => push_consts,
+            _ => panic!("{ops:?}"),
+        };
+        // n and dt, then the offset and len of particles in elements.
+        let push_consts: Vec<_> = push_consts[8..]
+            .chunks(4)
+            .map(|x| u32::from_ne_bytes(x.try_into().unwrap()))
+            .collect();
+        assert_eq!(push_consts, [2, 5]);
+    }
+}